@@ -406,7 +406,10 @@ fn run_with_service_with_extra_inputs(
                 let tx_id =
                     tx.id(&shared.config.chain_conf.consensus_parameters.chain_id);
 
-                let mut sub = shared.block_importer.block_importer.subscribe();
+                let mut sub = shared
+                    .block_importer
+                    .block_importer
+                    .subscribe::<fuel_core_importer::BlockCommitted>();
                 shared
                     .txpool
                     .insert(vec![std::sync::Arc::new(tx)])
@@ -420,8 +423,9 @@ fn run_with_service_with_extra_inputs(
                 assert_eq!(res.sealed_block.entity.transactions().len(), 2);
                 assert_eq!(res.tx_status[0].id, tx_id);
 
-                let TransactionExecutionResult::Failed { result, receipts } =
-                    &res.tx_status[0].result
+                let TransactionExecutionResult::Failed {
+                    result, receipts, ..
+                } = &res.tx_status[0].result
                 else {
                     panic!("The execution should fails with out of gas")
                 };
@@ -0,0 +1,126 @@
+use anyhow::Context;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use fuel_core::chain_config::ChainConfig;
+use fuel_core_benches::fixtures::{
+    record,
+    replay,
+    Fixture,
+    ReplayMode,
+    ReplayStats,
+};
+use std::path::PathBuf;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Records blocks from the node into the fixture.
+    Record {
+        /// The URL of the GraphQL API of the node.
+        #[arg(long, default_value = "http://127.0.0.1:4000")]
+        url: String,
+
+        /// Path to the chain config with the state of the chain at the height
+        /// before the first recorded block, for example, the output of the
+        /// `fuel-core snapshot everything` command.
+        #[arg(long)]
+        snapshot: PathBuf,
+
+        /// The number of blocks to record.
+        #[arg(long, default_value_t = 100)]
+        blocks: u32,
+
+        /// Path to store the fixture.
+        #[arg(short, long)]
+        output: PathBuf,
+    },
+    /// Replays the blocks from the fixture and prints the statistics.
+    Replay {
+        /// Path to the fixture.
+        #[arg(short, long)]
+        input: PathBuf,
+
+        /// The number of times to replay the fixture.
+        #[arg(long, default_value_t = 1)]
+        iterations: u32,
+
+        /// Print the statistics of each block as JSON.
+        #[arg(long)]
+        json: bool,
+
+        /// Skip the verification of signatures, predicates and UTXOs
+        /// to measure only the VM execution and storage access.
+        #[arg(long)]
+        unchecked: bool,
+    },
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+
+    match args.command {
+        Command::Record {
+            url,
+            snapshot,
+            blocks,
+            output,
+        } => {
+            let snapshot = std::fs::read(&snapshot).with_context(|| {
+                format!("failed to read the snapshot {}", snapshot.display())
+            })?;
+            let snapshot = ChainConfig::from_json_slice(&snapshot)?;
+            let fixture = record(&url, snapshot, blocks).await?;
+            fixture.write(&output)?;
+            println!(
+                "Recorded {} blocks after the height {} to {}",
+                fixture.blocks.len(),
+                fixture.start_height(),
+                output.display()
+            );
+        }
+        Command::Replay {
+            input,
+            iterations,
+            json,
+            unchecked,
+        } => {
+            let mode = if unchecked {
+                ReplayMode::Unchecked
+            } else {
+                ReplayMode::Checked
+            };
+            let fixture = Fixture::read(&input)?;
+            for iteration in 1..=iterations {
+                let stats = replay(&fixture, mode).await?;
+                if json {
+                    println!("{}", serde_json::to_string(&stats)?);
+                } else {
+                    print_summary(iteration, &stats);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn print_summary(iteration: u32, stats: &ReplayStats) {
+    println!("Iteration {iteration}:");
+    println!("  blocks:       {}", stats.blocks.len());
+    println!("  transactions: {}", stats.total_transactions());
+    println!("  gas used:     {}", stats.total_gas());
+    println!("  total time:   {:?}", stats.total_elapsed());
+    println!("  block p50:    {:?}", stats.percentile(50));
+    println!("  block p95:    {:?}", stats.percentile(95));
+    println!("  block max:    {:?}", stats.percentile(100));
+    println!("  gas/second:   {:.0}", stats.gas_per_second());
+}
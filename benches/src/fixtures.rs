@@ -0,0 +1,645 @@
+//! Fixtures of real blocks for benchmarking the block execution.
+//!
+//! The [`Fixture`] contains the state of the chain before the first recorded block
+//! and the recorded blocks. The fixture is recorded from a running network with
+//! [`record`] and is replayed in isolation with [`replay`], which imports the blocks
+//! one by one and measures the time and the gas spent on each of them.
+//! With [`ReplayMode::Unchecked`] the replay skips the verification of signatures
+//! and UTXOs to measure only the costs of the VM execution and storage access.
+//!
+//! The messages from the DA layer spent by the recorded blocks are stored in the fixture
+//! as the relayer events, so the replay imports them the same way as the validator does.
+
+use anyhow::{
+    anyhow,
+    ensure,
+    Context,
+};
+use fuel_core::{
+    chain_config::ChainConfig,
+    combined_database::CombinedDatabase,
+    database::{
+        database_description::relayer::Relayer,
+        Database,
+    },
+    relayer::storage::EventsHistory,
+    service::{
+        genesis::execute_genesis_block,
+        Config,
+    },
+};
+use fuel_core_client::client::{
+    types::{
+        block::{
+            Consensus as ClientConsensus,
+            Header as ClientHeader,
+        },
+        primitives::TransactionId,
+    },
+    FuelClient,
+};
+use fuel_core_executor::executor::{
+    Executor,
+    OnceTransactionsSource,
+};
+use fuel_core_importer::{
+    ports::{
+        self,
+        BlockVerifier,
+    },
+    BlockCommitted,
+    Importer,
+};
+use fuel_core_storage::{
+    transactional::{
+        StorageTransaction,
+        Transaction as _,
+    },
+    Result as StorageResult,
+    StorageAsMut,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        consensus::{
+            poa::PoAConsensus,
+            Consensus,
+        },
+        header::{
+            ApplicationHeader,
+            BlockHeader,
+            ConsensusHeader,
+            GeneratedApplicationFields,
+            GeneratedConsensusFields,
+        },
+        primitives::DaBlockHeight,
+        SealedBlock,
+    },
+    entities::message::{
+        Message,
+        MessageV1,
+    },
+    fuel_tx::{
+        field::Inputs,
+        Input,
+        Transaction,
+    },
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+    services::{
+        block_producer::Components,
+        executor::{
+            ExecutionTypes,
+            Result as ExecutorResult,
+            UncommittedResult,
+        },
+        relayer::Event,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    collections::{
+        BTreeMap,
+        HashSet,
+    },
+    path::Path,
+    sync::Arc,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The version of the fixture format.
+pub const FIXTURE_VERSION: u32 = 2;
+
+/// The recorded blocks with the state required to replay them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Fixture {
+    /// The version of the fixture format, see [`FIXTURE_VERSION`].
+    pub version: u32,
+    /// The chain configuration with the state of the chain
+    /// at the height before the first recorded block.
+    pub chain_config: ChainConfig,
+    /// The recorded blocks in the order of their heights.
+    pub blocks: Vec<SealedBlock>,
+    /// The events from the DA layer required by the recorded blocks.
+    pub relayer_events: Vec<Event>,
+}
+
+impl Fixture {
+    /// Reads the fixture from the JSON file.
+    pub fn read(path: &Path) -> anyhow::Result<Self> {
+        let file = std::fs::File::open(path)
+            .with_context(|| format!("failed to open fixture {}", path.display()))?;
+        let fixture: Fixture = serde_json::from_reader(std::io::BufReader::new(file))
+            .context("failed to decode fixture")?;
+        ensure!(
+            fixture.version == FIXTURE_VERSION,
+            "unsupported fixture version {}, expected {FIXTURE_VERSION}",
+            fixture.version
+        );
+        Ok(fixture)
+    }
+
+    /// Writes the fixture to the JSON file.
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        let file = std::fs::File::create(path)
+            .with_context(|| format!("failed to create fixture {}", path.display()))?;
+        serde_json::to_writer(std::io::BufWriter::new(file), self)
+            .context("failed to encode fixture")
+    }
+
+    /// The height of the chain state before the first recorded block.
+    pub fn start_height(&self) -> BlockHeight {
+        self.chain_config
+            .initial_state
+            .as_ref()
+            .and_then(|state| state.height)
+            .unwrap_or_default()
+    }
+
+    /// The height of the DA layer processed by the state before the first recorded block.
+    pub fn start_da_height(&self) -> DaBlockHeight {
+        self.chain_config
+            .initial_state
+            .as_ref()
+            .and_then(|state| state.da_block_height)
+            .unwrap_or_default()
+    }
+}
+
+/// Records `count` blocks following the height of the `snapshot` from the node at `url`.
+///
+/// The `snapshot` is the chain configuration with the state of the network
+/// at the height before the first recorded block, for example, the output
+/// of the `fuel-core snapshot everything` command.
+pub async fn record(
+    url: &str,
+    snapshot: ChainConfig,
+    count: u32,
+) -> anyhow::Result<Fixture> {
+    let client = FuelClient::new(url)?;
+    let state = snapshot.initial_state.as_ref();
+    let start_height = state.and_then(|state| state.height).unwrap_or_default();
+    let start_da_height = state
+        .and_then(|state| state.da_block_height)
+        .unwrap_or_default();
+    let mut known_messages: HashSet<Nonce> = state
+        .and_then(|state| state.messages.as_ref())
+        .into_iter()
+        .flatten()
+        .map(|message| message.nonce)
+        .collect();
+
+    let first_height = u32::from(start_height).saturating_add(1);
+    let mut blocks = vec![];
+    let mut relayer_events = vec![];
+    for height in (first_height..).take(count as usize) {
+        let block = client
+            .block_by_height(height.into())
+            .await?
+            .ok_or_else(|| anyhow!("block {height} is not available on the node"))?;
+
+        let mut transactions = vec![];
+        for id in &block.transactions {
+            transactions.push(transaction(&client, id).await?);
+        }
+        let header = header(&block.header)?;
+        for input in transactions.iter().flat_map(inputs) {
+            let Some(message) = message(input, start_da_height) else {
+                continue
+            };
+            if !known_messages.insert(*message.nonce()) {
+                continue
+            }
+            ensure!(
+                header.da_height > start_da_height,
+                "the message {} spent in the block {height} is not part of the snapshot",
+                message.nonce()
+            );
+            relayer_events.push(Event::Message(message));
+        }
+        let block_entity = Block::try_from_executed(header, transactions)
+            .ok_or_else(|| anyhow!("transactions don't match the header of {height}"))?;
+        let consensus = match block.consensus {
+            ClientConsensus::PoAConsensus(poa) => {
+                Consensus::PoA(PoAConsensus::new(poa.signature))
+            }
+            consensus => {
+                return Err(anyhow!(
+                    "unsupported consensus {consensus:?} of the block {height}"
+                ))
+            }
+        };
+
+        blocks.push(SealedBlock {
+            entity: block_entity,
+            consensus,
+        });
+    }
+
+    Ok(Fixture {
+        version: FIXTURE_VERSION,
+        chain_config: snapshot,
+        blocks,
+        relayer_events,
+    })
+}
+
+fn inputs(tx: &Transaction) -> &[Input] {
+    match tx {
+        Transaction::Script(tx) => tx.inputs().as_slice(),
+        Transaction::Create(tx) => tx.inputs().as_slice(),
+        Transaction::Mint(_) => &[],
+    }
+}
+
+/// Restores the message spent by the `input`.
+///
+/// The GraphQL API doesn't provide the DA height of spent messages, so the message
+/// is placed at the first DA height after the snapshot. Any block that spends
+/// the message is at or above this height, and the id of the message
+/// doesn't depend on the DA height.
+fn message(input: &Input, start_da_height: DaBlockHeight) -> Option<Message> {
+    let message = MessageV1 {
+        sender: *input.sender()?,
+        recipient: *input.recipient()?,
+        nonce: *input.nonce()?,
+        amount: input.amount()?,
+        data: input.input_data().unwrap_or_default().to_vec(),
+        da_height: start_da_height.0.saturating_add(1).into(),
+    };
+    Some(message.into())
+}
+
+async fn transaction(
+    client: &FuelClient,
+    id: &TransactionId,
+) -> anyhow::Result<Transaction> {
+    let response = client
+        .transaction(id)
+        .await?
+        .ok_or_else(|| anyhow!("transaction {id} is not available on the node"))?;
+    Ok(response.transaction)
+}
+
+/// Restores the full header from the header returned by the GraphQL API.
+fn header(header: &ClientHeader) -> anyhow::Result<BlockHeader> {
+    let mut full_header = BlockHeader::default();
+    full_header.set_application_header(ApplicationHeader {
+        da_height: header.da_height.into(),
+        generated: GeneratedApplicationFields {
+            transactions_count: header.transactions_count,
+            message_receipt_count: header.message_receipt_count,
+            transactions_root: header.transactions_root,
+            message_receipt_root: header.message_receipt_root,
+        },
+    });
+    full_header.set_consensus_header(ConsensusHeader {
+        prev_root: header.prev_root,
+        height: header.height.into(),
+        time: header.time,
+        generated: GeneratedConsensusFields {
+            application_hash: header.application_hash,
+        },
+    });
+    full_header.recalculate_metadata();
+    ensure!(
+        full_header.id() == header.id.into(),
+        "the restored header of the block {} doesn't match its id",
+        header.height
+    );
+    Ok(full_header)
+}
+
+/// The statistics of the replayed block.
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockStats {
+    /// The height of the block.
+    pub height: u32,
+    /// The number of transactions in the block, including the `Mint` transaction.
+    pub transactions: usize,
+    /// The gas used by all transactions of the block.
+    pub gas_used: u64,
+    /// The time spent on the execution and the commit of the block.
+    pub elapsed: Duration,
+}
+
+/// The statistics of the whole replay.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayStats {
+    pub blocks: Vec<BlockStats>,
+}
+
+impl ReplayStats {
+    pub fn total_elapsed(&self) -> Duration {
+        self.blocks.iter().map(|block| block.elapsed).sum()
+    }
+
+    pub fn total_gas(&self) -> u64 {
+        self.blocks
+            .iter()
+            .fold(0u64, |total, block| total.saturating_add(block.gas_used))
+    }
+
+    pub fn total_transactions(&self) -> usize {
+        self.blocks.iter().fold(0usize, |total, block| {
+            total.saturating_add(block.transactions)
+        })
+    }
+
+    /// Returns the block time at the `percentile` from `0` to `100`.
+    pub fn percentile(&self, percentile: usize) -> Duration {
+        let mut times: Vec<_> = self.blocks.iter().map(|block| block.elapsed).collect();
+        times.sort();
+        let index = times
+            .len()
+            .saturating_sub(1)
+            .saturating_mul(percentile.min(100))
+            .checked_div(100)
+            .unwrap_or_default();
+        times.get(index).copied().unwrap_or_default()
+    }
+
+    /// The gas used per second of the block execution.
+    pub fn gas_per_second(&self) -> f64 {
+        let seconds = self.total_elapsed().as_secs_f64();
+        if seconds > 0.0 {
+            self.total_gas() as f64 / seconds
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Imports the blocks on top of the state from the fixture,
+/// the consensus of the blocks is not verified.
+struct SkipVerification;
+
+impl BlockVerifier for SkipVerification {
+    fn verify_block_fields(&self, _: &Consensus, _: &Block) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// Defines how the blocks are executed during the replay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplayMode {
+    /// Executes the blocks with all checks like the validator does.
+    Checked,
+    /// Executes the blocks without the verification of signatures, predicates
+    /// and UTXOs, see [`Executor::execute_without_commit_unchecked`].
+    Unchecked,
+}
+
+/// The executor of the replayed blocks.
+struct ReplayExecutor {
+    executor: Executor<Database, Database<Relayer>>,
+    mode: ReplayMode,
+}
+
+impl ports::Executor for ReplayExecutor {
+    type Database = Database;
+
+    fn execute_without_commit(
+        &self,
+        block: Block,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Self::Database>>> {
+        let block =
+            ExecutionTypes::<Components<OnceTransactionsSource>, _>::Validation(block);
+        match self.mode {
+            ReplayMode::Checked => self.executor.execute_without_commit(block),
+            ReplayMode::Unchecked => {
+                self.executor.execute_without_commit_unchecked(block)
+            }
+        }
+    }
+
+    fn pending_view(
+        &self,
+        uncommitted: &Self::Database,
+    ) -> StorageResult<Self::Database> {
+        self.executor
+            .database_view_provider
+            .with_pending_changes(uncommitted)
+    }
+
+    fn execute_without_commit_on(
+        &self,
+        view: Self::Database,
+        block: Block,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Self::Database>>> {
+        let block =
+            ExecutionTypes::<Components<OnceTransactionsSource>, _>::Validation(block);
+        match self.mode {
+            ReplayMode::Checked => self.executor.execute_without_commit_on(view, block),
+            ReplayMode::Unchecked => self
+                .executor
+                .execute_without_commit_unchecked_on(view, block),
+        }
+    }
+}
+
+/// Replays the blocks of the `fixture` on the fresh database and
+/// returns the statistics of the execution.
+pub async fn replay(fixture: &Fixture, mode: ReplayMode) -> anyhow::Result<ReplayStats> {
+    let mut config = Config::local_node();
+    config.chain_conf = fixture.chain_config.clone();
+    config.block_importer = fuel_core_importer::Config::new(&config.chain_conf);
+
+    let mut database = CombinedDatabase::new(
+        Database::rocksdb(),
+        Database::in_memory(),
+        Database::in_memory(),
+    );
+    database.init(&fixture.start_height(), &0u64.into())?;
+    store_relayer_events(database.relayer(), &fixture.relayer_events)?;
+
+    let executor = ReplayExecutor {
+        executor: Executor {
+            database_view_provider: database.on_chain().clone(),
+            relayer_view_provider: database.relayer().clone(),
+            config: Arc::new(fuel_core_executor::Config {
+                consensus_parameters: config.chain_conf.consensus_parameters.clone(),
+                coinbase_recipient: Default::default(),
+                backtrace: false,
+                utxo_validation_default: true,
+                strict_header_verification: config
+                    .block_importer
+                    .strict_header_verification,
+                block_gas_limit: config.chain_conf.block_gas_limit,
+                fee_free_allowance: config.chain_conf.fee_free_allowance.clone(),
+                blob_pricing: config.chain_conf.blob_pricing.clone(),
+                reexecution_audit: false,
+                vm_metrics: false,
+            }),
+        },
+        mode,
+    };
+    let importer = Importer::new(
+        config.block_importer.clone(),
+        database.on_chain().clone(),
+        executor,
+        SkipVerification,
+    );
+
+    let genesis = execute_genesis_block(&config, database.on_chain())?;
+    importer.commit_result(genesis).await?;
+
+    let mut imported = importer.subscribe::<BlockCommitted>();
+    let mut blocks = vec![];
+    for block in &fixture.blocks {
+        let height = **block.entity.header().height();
+        let start = Instant::now();
+        importer
+            .execute_and_commit(block.clone())
+            .await
+            .map_err(|err| anyhow!("failed to import block {height}: {err}"))?;
+        let elapsed = start.elapsed();
+
+        let result = imported
+            .recv()
+            .await
+            .ok_or_else(|| anyhow!("the importer stopped at block {height}"))?;
+        let gas_used = result.tx_status.iter().fold(0u64, |total, status| {
+            total.saturating_add(status.result.fee().gas_used)
+        });
+
+        blocks.push(BlockStats {
+            height,
+            transactions: block.entity.transactions().len(),
+            gas_used,
+            elapsed,
+        });
+    }
+
+    Ok(ReplayStats { blocks })
+}
+
+fn store_relayer_events(
+    database: &Database<Relayer>,
+    events: &[Event],
+) -> anyhow::Result<()> {
+    let mut events_by_height = BTreeMap::<DaBlockHeight, Vec<Event>>::new();
+    for event in events {
+        events_by_height
+            .entry(event.da_height())
+            .or_default()
+            .push(event.clone());
+    }
+
+    let mut db_transaction = database.transaction();
+    for (da_height, events) in events_by_height {
+        db_transaction
+            .as_mut()
+            .storage::<EventsHistory>()
+            .insert(&da_height, &events)?;
+    }
+    db_transaction.commit()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_executor::ports::RelayerPort;
+    use fuel_core_storage::transactional::AtomicView;
+    use fuel_core_types::{
+        blockchain::header::PartialBlockHeader,
+        fuel_crypto::SecretKey,
+        fuel_tx::{
+            AssetId,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::ChainId,
+    };
+    use rand::{
+        rngs::StdRng,
+        Rng,
+        SeedableRng,
+    };
+
+    /// The relayer without the events, so the executor doesn't look up
+    /// the previous block to process the events.
+    #[derive(Clone, Debug)]
+    struct DisabledRelayer;
+
+    impl RelayerPort for DisabledRelayer {
+        fn enabled(&self) -> bool {
+            false
+        }
+
+        fn get_events(&self, _: &DaBlockHeight) -> anyhow::Result<Vec<Event>> {
+            unimplemented!()
+        }
+    }
+
+    impl AtomicView for DisabledRelayer {
+        type View = Self;
+        type Height = DaBlockHeight;
+
+        fn latest_height(&self) -> Self::Height {
+            0u64.into()
+        }
+
+        fn view_at(&self, _: &Self::Height) -> StorageResult<Self::View> {
+            Ok(self.latest_view())
+        }
+
+        fn latest_view(&self) -> Self::View {
+            self.clone()
+        }
+    }
+
+    #[test]
+    fn unchecked_execution_skips_signature_and_utxo_verification() {
+        let mut rng = StdRng::seed_from_u64(2322);
+        // The coin doesn't exist, and the signature is not set.
+        let tx: Transaction = TransactionBuilder::script(vec![], vec![])
+            .script_gas_limit(100)
+            .add_unsigned_coin_input(
+                SecretKey::random(&mut rng),
+                rng.gen(),
+                1000,
+                AssetId::BASE,
+                Default::default(),
+            )
+            .finalize_as_transaction();
+        let block = || {
+            ExecutionTypes::Production(Components {
+                header_to_produce: PartialBlockHeader::default(),
+                transactions_source: OnceTransactionsSource::new(vec![tx.clone()]),
+                gas_price: 0,
+                gas_limit: u64::MAX,
+            })
+        };
+        let executor: Executor<Database, _> = Executor {
+            database_view_provider: Database::in_memory(),
+            relayer_view_provider: DisabledRelayer,
+            config: Arc::new(fuel_core_executor::Config {
+                utxo_validation_default: true,
+                ..Default::default()
+            }),
+        };
+
+        let checked = executor
+            .execute_without_commit(block())
+            .unwrap()
+            .into_result();
+        assert_eq!(checked.skipped_transactions.len(), 1);
+
+        let unchecked = executor
+            .execute_without_commit_unchecked(block())
+            .unwrap()
+            .into_result();
+        assert!(unchecked.skipped_transactions.is_empty());
+        assert_eq!(unchecked.tx_status[0].id, tx.id(&ChainId::default()));
+    }
+}
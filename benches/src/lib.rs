@@ -1,4 +1,5 @@
 pub mod default_gas_costs;
+pub mod fixtures;
 pub mod import;
 
 pub use fuel_core::database::Database;
@@ -1,13 +1,18 @@
 use clap::Parser;
+use opentelemetry::sdk::trace::Tracer;
 use std::{
     env,
+    num::NonZeroUsize,
     path::PathBuf,
     str::FromStr,
+    sync::OnceLock,
 };
+use tracing_opentelemetry::OpenTelemetryLayer;
 use tracing_subscriber::{
     filter::EnvFilter,
     layer::SubscriberExt,
     registry,
+    reload,
     Layer,
 };
 
@@ -18,7 +23,10 @@ lazy_static::lazy_static! {
     pub static ref DEFAULT_DB_PATH: PathBuf = dirs::home_dir().unwrap().join(".fuel").join("db");
 }
 
+pub mod db;
+pub mod export_era;
 pub mod fee_contract;
+pub mod localnet;
 pub mod run;
 pub mod snapshot;
 
@@ -39,9 +47,19 @@ pub struct Opt {
 pub enum Fuel {
     Run(run::Command),
     Snapshot(snapshot::Command),
+    Db(db::Command),
+    ExportEra(export_era::Command),
     GenerateFeeContract(fee_contract::Command),
+    Localnet(localnet::Command),
 }
 
+/// The OpenTelemetry layer is installed after the CLI arguments are parsed,
+/// while the logging should be available before that.
+type TelemetryLayer = Option<OpenTelemetryLayer<registry::Registry, Tracer>>;
+
+static TELEMETRY_LAYER: OnceLock<reload::Handle<TelemetryLayer, registry::Registry>> =
+    OnceLock::new();
+
 pub const LOG_FILTER: &str = "RUST_LOG";
 pub const HUMAN_LOGGING: &str = "HUMAN_LOGGING";
 
@@ -91,7 +109,11 @@ pub async fn init_logging() -> anyhow::Result<()> {
             .boxed()
     };
 
+    let (telemetry, telemetry_handle) = reload::Layer::new(TelemetryLayer::None);
+    let _ = TELEMETRY_LAYER.set(telemetry_handle);
+
     let subscriber = registry::Registry::default() // provide underlying span data store
+        .with(telemetry) // export spans to OpenTelemetry, if enabled
         .with(filter) // filter out low-level debug tracing (eg tokio executor)
         .with(fmt); // log to stdout
 
@@ -100,6 +122,29 @@ pub async fn init_logging() -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Starts the export of spans with the `tracer`.
+pub fn init_telemetry_layer(tracer: Tracer) -> anyhow::Result<()> {
+    let handle = TELEMETRY_LAYER
+        .get()
+        .ok_or_else(|| anyhow::anyhow!("The logging is not initialized"))?;
+    handle.reload(Some(tracing_opentelemetry::layer().with_tracer(tracer)))?;
+    Ok(())
+}
+
+/// Returns the number of the async runtime worker threads from the arguments of the
+/// `run` command. The runtime is created before the arguments are parsed by [`run_cli`].
+pub fn async_threads() -> Option<NonZeroUsize> {
+    init_environment();
+    let command = match Opt::try_parse() {
+        Ok(Opt {
+            command: Fuel::Run(command),
+        }) => command,
+        Ok(_) => return None,
+        Err(_) => run::Command::try_parse().ok()?,
+    };
+    command.resources.async_threads()
+}
+
 pub async fn run_cli() -> anyhow::Result<()> {
     init_logging().await?;
     if let Some(path) = init_environment() {
@@ -119,7 +164,10 @@ pub async fn run_cli() -> anyhow::Result<()> {
         Ok(opt) => match opt.command {
             Fuel::Run(command) => run::exec(command).await,
             Fuel::Snapshot(command) => snapshot::exec(command).await,
+            Fuel::Db(command) => db::exec(command).await,
+            Fuel::ExportEra(command) => export_era::exec(command).await,
             Fuel::GenerateFeeContract(command) => fee_contract::exec(command).await,
+            Fuel::Localnet(command) => localnet::exec(command).await,
         },
         Err(e) => {
             // Prints the error and exits.
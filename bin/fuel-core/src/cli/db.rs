@@ -0,0 +1,145 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::{
+    Parser,
+    Subcommand,
+};
+use std::path::PathBuf;
+
+/// Inspects the database of the stopped node.
+/// The statistics of the running node are available through the admin GraphQL API.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The sub-command of the database operation.
+    #[command(subcommand)]
+    subcommand: SubCommands,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum SubCommands {
+    /// Prints the on-disk size, the estimated key count, the number of files on each level,
+    /// and the pending compaction bytes of each column of the databases.
+    Stats {
+        /// Prints the statistics in JSON instead of the table.
+        #[clap(long = "json")]
+        json: bool,
+    },
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::combined_database::CombinedDatabase;
+
+    let path = command.database_path;
+    let database = CombinedDatabase::open(&path, 0)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!(
+            "failed to open database at path {}",
+            path.display()
+        ))?;
+
+    match command.subcommand {
+        SubCommands::Stats { json } => {
+            let statistics = database.statistics()?;
+            if json {
+                let stdout = std::io::stdout().lock();
+                serde_json::to_writer_pretty(stdout, &statistics)
+                    .context("failed to dump the statistics to JSON")?;
+            } else {
+                for database in statistics {
+                    println!("{}", format_statistics(&database));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Formats the statistics of the database as a table with a row per column,
+/// sorted by the size from the biggest column.
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+fn format_statistics(
+    database: &fuel_core::combined_database::DatabaseStatistics,
+) -> String {
+    use std::fmt::Write;
+
+    let mut columns = database.columns.iter().collect::<Vec<_>>();
+    columns.sort_by(|a, b| b.size.cmp(&a.size));
+
+    let mut table = format!("Database `{}`\n", database.name);
+    let _ = writeln!(
+        table,
+        "{:<32} {:>16} {:>16} {:>20}  FILES PER LEVEL",
+        "COLUMN", "SIZE", "KEYS", "PENDING COMPACTION"
+    );
+    for column in columns {
+        let levels = column
+            .files_per_level
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("/");
+        let _ = writeln!(
+            table,
+            "{:<32} {:>16} {:>16} {:>20}  {}",
+            column.name,
+            column.size,
+            column.estimated_keys,
+            column.pending_compaction_bytes,
+            levels
+        );
+    }
+    table
+}
+
+#[cfg(all(test, any(feature = "rocksdb", feature = "rocksdb-production")))]
+mod tests {
+    use super::*;
+    use fuel_core::{
+        combined_database::DatabaseStatistics,
+        state::ColumnStatistics,
+    };
+
+    #[test]
+    fn format_statistics_sorts_columns_by_size() {
+        // Given
+        let column = |name: &str, size| ColumnStatistics {
+            name: name.to_string(),
+            size,
+            estimated_keys: 2,
+            pending_compaction_bytes: 0,
+            files_per_level: vec![1, 0, 3],
+        };
+        let database = DatabaseStatistics {
+            name: "on_chain".to_string(),
+            columns: vec![column("Coins", 10), column("Receipts", 1000)],
+        };
+
+        // When
+        let table = format_statistics(&database);
+
+        // Then
+        let rows = table.lines().skip(2).collect::<Vec<_>>();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].starts_with("Receipts"));
+        assert!(rows[1].starts_with("Coins"));
+        assert!(rows[0].ends_with("1/0/3"));
+    }
+}
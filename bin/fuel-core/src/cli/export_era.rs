@@ -0,0 +1,131 @@
+use crate::cli::DEFAULT_DB_PATH;
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Exports the sealed blocks of the stopped node into the era files.
+/// Other nodes can import them with `--era-source` before the sync with the network.
+#[derive(Debug, Clone, Parser)]
+pub struct Command {
+    /// The path to the database.
+    #[clap(
+        name = "DB_PATH",
+        long = "db-path",
+        value_parser,
+        default_value = (*DEFAULT_DB_PATH).to_str().unwrap()
+    )]
+    database_path: PathBuf,
+
+    /// The directory of the archive. The eras already listed in its manifest are skipped.
+    #[clap(name = "OUTPUT_DIR", long = "output-directory", value_parser)]
+    output_dir: PathBuf,
+
+    /// The number of blocks in each era. Only the complete eras are exported.
+    #[clap(long = "era-size", default_value = "8192", value_parser = clap::value_parser!(u32).range(1..))]
+    era_size: u32,
+}
+
+#[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!(
+        "Rocksdb must be enabled to use the database at {}",
+        command.database_path.display()
+    ))
+}
+
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use anyhow::Context;
+    use fuel_core::{
+        combined_database::CombinedDatabase,
+        era::{
+            self,
+            EraEntry,
+            EraManifest,
+        },
+    };
+
+    let path = command.database_path;
+    let database = CombinedDatabase::open(&path, 0)
+        .map_err(Into::<anyhow::Error>::into)
+        .context(format!(
+            "failed to open database at path {}",
+            path.display()
+        ))?;
+    let database = database.on_chain();
+
+    std::fs::create_dir_all(&command.output_dir)?;
+    let manifest_path = command.output_dir.join(era::MANIFEST_FILE);
+    let mut manifest = if manifest_path.exists() {
+        let manifest: EraManifest =
+            serde_json::from_slice(&std::fs::read(&manifest_path)?)
+                .context("failed to decode the manifest of the archive")?;
+        anyhow::ensure!(
+            manifest.era_size == command.era_size,
+            "The archive uses the era size {}",
+            manifest.era_size
+        );
+        manifest
+    } else {
+        EraManifest {
+            era_size: command.era_size,
+            eras: vec![],
+        }
+    };
+
+    let era_size = usize::try_from(command.era_size)?;
+    let latest_height = *database.latest_height()?;
+    for start in complete_eras(latest_height, command.era_size) {
+        if manifest.eras.iter().any(|era| *era.start == start) {
+            continue
+        }
+
+        let blocks = (start..)
+            .take(era_size)
+            .map(|height| {
+                database
+                    .get_sealed_block_by_height(&height.into())?
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("The block at the height {height} is missing")
+                    })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let bytes = era::encode_era(&blocks)?;
+        let file = era::era_file_name(start.into());
+        std::fs::write(command.output_dir.join(&file), &bytes)?;
+
+        manifest.insert(EraEntry {
+            start: start.into(),
+            count: command.era_size,
+            file: file.clone(),
+            checksum: era::checksum(&bytes),
+        });
+        // The manifest is updated after each era to keep the archive usable
+        // if the export is interrupted.
+        std::fs::write(&manifest_path, serde_json::to_vec_pretty(&manifest)?)?;
+        tracing::info!("Exported the era {file}");
+    }
+    Ok(())
+}
+
+/// Returns the start heights of the eras that contain only the blocks
+/// up to the `latest_height`.
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+fn complete_eras(latest_height: u32, era_size: u32) -> impl Iterator<Item = u32> {
+    let blocks = u64::from(latest_height).saturating_add(1);
+    let count = blocks.checked_div(u64::from(era_size)).unwrap_or_default();
+    (0..count).filter_map(move |era| {
+        u32::try_from(era.saturating_mul(u64::from(era_size))).ok()
+    })
+}
+
+#[cfg(all(test, any(feature = "rocksdb", feature = "rocksdb-production")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_complete_eras_are_exported() {
+        assert_eq!(complete_eras(8, 5).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(complete_eras(9, 5).collect::<Vec<_>>(), vec![0, 5]);
+        assert!(complete_eras(3, 5).next().is_none());
+    }
+}
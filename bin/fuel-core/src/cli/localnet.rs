@@ -0,0 +1,78 @@
+use clap::Parser;
+
+/// Spawns the block producer and the full nodes in this process, connected over the local p2p.
+/// The logs of all nodes go to the same output, tagged with the node name, and the state
+/// of the nodes is reported periodically. The nodes share the metrics registry,
+/// so the `/metrics` endpoint of any node shows the metrics of the whole network.
+#[derive(Debug, Clone, Parser)]
+#[cfg_attr(not(feature = "p2p"), allow(dead_code))]
+pub struct Command {
+    /// The number of the block producers. The PoA consensus supports only one producer.
+    #[clap(long = "validators", default_value = "1", value_parser = clap::value_parser!(u16).range(1..))]
+    validators: u16,
+
+    /// The number of the full nodes syncing the blocks from the producer.
+    #[clap(long = "full-nodes", default_value = "3")]
+    full_nodes: u16,
+
+    /// The GraphQL port of the producer. The full nodes use the next ports.
+    #[clap(long = "graphql-port", default_value = "4000")]
+    graphql_port: u16,
+
+    /// The p2p port of the producer. The full nodes bootstrap from it.
+    #[clap(long = "p2p-port", default_value = "30333", value_parser = clap::value_parser!(u16).range(1..))]
+    p2p_port: u16,
+
+    /// The time between the blocks of the producer.
+    #[clap(long = "block-time", default_value = "1s")]
+    block_time: humantime::Duration,
+
+    /// How often the state of the nodes is reported.
+    #[clap(long = "status-interval", default_value = "10s")]
+    status_interval: humantime::Duration,
+}
+
+#[cfg(not(feature = "p2p"))]
+pub async fn exec(_: Command) -> anyhow::Result<()> {
+    Err(anyhow::anyhow!("P2P must be enabled to run the localnet"))
+}
+
+#[cfg(feature = "p2p")]
+pub async fn exec(command: Command) -> anyhow::Result<()> {
+    use fuel_core::service::{
+        config::Trigger,
+        localnet::{
+            Localnet,
+            LocalnetConfig,
+        },
+    };
+
+    let config = LocalnetConfig {
+        validators: command.validators.into(),
+        full_nodes: command.full_nodes.into(),
+        graphql_port: command.graphql_port,
+        p2p_port: command.p2p_port,
+        block_production: Trigger::Interval {
+            block_time: command.block_time.into(),
+        },
+        ..Default::default()
+    };
+    let localnet = Localnet::start(config).await?;
+    tracing::info!("The localnet of {} nodes is started", localnet.nodes.len());
+
+    let mut status_timer = tokio::time::interval(command.status_interval.into());
+    let shutdown = super::run::shutdown_signal();
+    tokio::pin!(shutdown);
+    loop {
+        tokio::select! {
+            _ = &mut shutdown => break,
+            _ = status_timer.tick() => {
+                for node in localnet.status().await? {
+                    tracing::info!("{node}");
+                }
+            }
+        }
+    }
+
+    localnet.stop().await
+}
@@ -1,38 +1,60 @@
 #![allow(unused_variables)]
 use crate::{
     cli::{
-        run::consensus::PoATriggerArgs,
+        run::{
+            consensus::PoATriggerArgs,
+            resources::{
+                cap,
+                low_memory,
+                Profile,
+            },
+        },
         DEFAULT_DB_PATH,
     },
     FuelService,
 };
-use anyhow::{
-    anyhow,
-    Context,
-};
+use anyhow::Context;
 use clap::Parser;
 use fuel_core::{
     chain_config::{
         default_consensus_dev_key,
         ChainConfig,
+        PartitionedSnapshot,
     },
-    producer::Config as ProducerConfig,
+    fuel_core_graphql_api::AdminToken,
     service::{
-        config::Trigger,
+        config::{
+            BlockTimeSchedule,
+            HttpConfig,
+            LeaderLeaseConfig,
+            ReceiptsArchiveSink,
+            ReceiptsPruningConfig,
+            RequestSchedulerConfig,
+            TlsConfig,
+            Trigger,
+            WebSocketConfig,
+        },
         Config,
         DbType,
         RelayerConsensusConfig,
         ServiceTrait,
         VMConfig,
     },
+    snapshot_store::SnapshotLocation,
     txpool::Config as TxPoolConfig,
     types::{
         blockchain::primitives::SecretKeyWrapper,
-        fuel_tx::ContractId,
         fuel_vm::SecretKey,
-        secrecy::Secret,
+        secrecy::{
+            zeroize::Zeroizing,
+            Secret,
+        },
     },
 };
+use fuel_core_keygen::keystore::{
+    read_passphrase,
+    Keystore,
+};
 use pyroscope::{
     pyroscope::PyroscopeAgentRunning,
     PyroscopeAgent,
@@ -44,7 +66,11 @@ use pyroscope_pprofrs::{
 use std::{
     env,
     net,
-    path::PathBuf,
+    num::NonZeroUsize,
+    path::{
+        Path,
+        PathBuf,
+    },
     str::FromStr,
 };
 use tracing::{
@@ -56,14 +82,23 @@ use tracing::{
 pub const CONSENSUS_KEY_ENV: &str = "CONSENSUS_KEY_SECRET";
 // Default database cache is 1 GB
 const DEFAULT_DATABASE_CACHE_SIZE: usize = 1024 * 1024 * 1024;
+const DEFAULT_CONTRACT_CACHE_SIZE: usize = 64 * 1024 * 1024;
+const DEFAULT_COIN_CACHE_SIZE: usize = 100_000;
+const DEFAULT_API_ENTITY_CACHE_SIZE: usize = 64 * 1024 * 1024;
 
 #[cfg(feature = "p2p")]
 mod p2p;
 
+mod alerts;
 mod consensus;
+mod network;
 mod profiling;
 #[cfg(feature = "relayer")]
 mod relayer;
+mod resources;
+#[cfg(feature = "shared-sequencer")]
+mod shared_sequencer;
+mod telemetry;
 
 /// Run the Fuel client node locally.
 #[derive(Debug, Clone, Parser)]
@@ -74,6 +109,11 @@ pub struct Command {
     #[clap(long = "port", default_value = "4000", env)]
     pub port: u16,
 
+    /// The address of the gRPC server. The server is disabled if the address is not set.
+    #[cfg(feature = "grpc")]
+    #[clap(long = "grpc-addr", env)]
+    pub grpc_addr: Option<net::SocketAddr>,
+
     /// Vanity name for node, used in telemetry
     #[clap(long = "service-name", default_value = "fuel-core", value_parser, env)]
     pub service_name: String,
@@ -86,6 +126,23 @@ pub struct Command {
     )]
     pub max_database_cache_size: usize,
 
+    /// The maximum size in bytes of the in-memory cache of the contracts bytecode.
+    #[arg(
+        long = "contract-cache-size",
+        default_value_t = DEFAULT_CONTRACT_CACHE_SIZE,
+        env
+    )]
+    pub contract_cache_size: usize,
+
+    /// The maximum number of the unspent coins in the in-memory cache.
+    /// The cache is disabled if it is zero.
+    #[arg(
+        long = "coin-cache-size",
+        default_value_t = DEFAULT_COIN_CACHE_SIZE,
+        env
+    )]
+    pub coin_cache_size: usize,
+
     #[clap(
         name = "DB_PATH",
         long = "db-path",
@@ -113,6 +170,17 @@ pub struct Command {
     )]
     pub chain_config: String,
 
+    /// Selects the chain configuration bundled into the binary instead of `--chain`.
+    #[arg(long = "network", value_enum, conflicts_with = "CHAIN_CONFIG", env)]
+    pub network: Option<network::Network>,
+
+    /// The directory with the `manifest.json` of the partitioned snapshot of the state,
+    /// exported by `fuel-core snapshot export --format partitioned`. It replaces
+    /// the initial state of the chain config. With the `object-store` feature, it can be
+    /// the `http(s)://`, `s3://` or `gs://` URL, and the files are downloaded one by one.
+    #[arg(long = "state-snapshot", value_parser, env)]
+    pub state_snapshot: Option<SnapshotLocation>,
+
     /// Should be used for local development only. Enabling debug mode:
     /// - Allows GraphQL Endpoints to arbitrarily advance blocks.
     /// - Enables debugger GraphQL Endpoints.
@@ -124,34 +192,141 @@ pub struct Command {
     #[arg(long = "vm-backtrace", env)]
     pub vm_backtrace: bool,
 
+    /// Execute each produced and imported block twice with the same sequential executor
+    /// and halt the node if the results differ. It doesn't compare the sequential and
+    /// the parallel schedulers, because there is no parallel scheduler yet.
+    /// Doubles the cost of the execution, intended for testing only.
+    #[arg(long = "vm-reexecution-audit", env)]
+    pub vm_reexecution_audit: bool,
+
+    /// Count the instructions executed by the VM and the memory used by each
+    /// transaction of the produced and imported blocks. The counters are exposed
+    /// in the transaction status and the metrics. Slows down the execution.
+    #[arg(long = "vm-metrics", env)]
+    pub vm_metrics: bool,
+
     /// Enable full utxo stateful validation
     /// disabled by default until downstream consumers stabilize
     #[arg(long = "utxo-validation", env)]
     pub utxo_validation: bool,
 
+    /// Verify each root of the imported block headers against the recomputed values
+    /// and report the first mismatching field. Only affects the checks of this node.
+    #[arg(long = "strict-header-verification", env)]
+    pub strict_header_verification: bool,
+
     /// The minimum allowed gas price
     #[arg(long = "min-gas-price", default_value = "0", env)]
     pub min_gas_price: u64,
 
     /// The signing key used when producing blocks.
     /// Setting via the `CONSENSUS_KEY_SECRET` ENV var is preferred.
-    #[arg(long = "consensus-key", env)]
+    #[arg(long = "consensus-key", env, conflicts_with = "consensus_keystore")]
     pub consensus_key: Option<String>,
 
+    /// The keystore with the signing key encrypted by a passphrase.
+    /// It can be created with the `fuel-core-keygen encrypt` command.
+    #[arg(long = "consensus-keystore", env)]
+    pub consensus_keystore: Option<PathBuf>,
+
+    /// The file with the passphrase of the `consensus-keystore`.
+    /// The passphrase is prompted on the start if not set.
+    #[arg(
+        long = "consensus-keystore-passphrase-file",
+        env,
+        requires = "consensus_keystore"
+    )]
+    pub consensus_keystore_passphrase_file: Option<PathBuf>,
+
+    /// The token authorizing the admin GraphQL endpoints, e.g. pausing the block production.
+    /// The endpoints are disabled if not set. Setting via the `ADMIN_TOKEN` ENV var is preferred.
+    #[arg(long = "admin-token", env)]
+    pub admin_token: Option<String>,
+
+    /// Accepts only the GraphQL queries registered by the admin with `registerPersistedQuery`.
+    /// The requests with the admin token are always accepted.
+    #[arg(long = "api-persisted-queries-only", env)]
+    pub api_persisted_queries_only: bool,
+
+    /// The file storing the GraphQL queries registered with `registerPersistedQuery`.
+    /// The queries are restored from the file on startup. If not set,
+    /// the queries are kept only in memory and are lost after a restart.
+    #[arg(long = "api-persisted-queries-path", env)]
+    pub api_persisted_queries_path: Option<PathBuf>,
+
+    /// The maximum size in bytes of the GraphQL cache of the blocks and of the transactions
+    /// requested by id. The cache is disabled if it is zero.
+    #[arg(
+        long = "api-entity-cache-size",
+        default_value_t = DEFAULT_API_ENTITY_CACHE_SIZE,
+        env
+    )]
+    pub api_entity_cache_size: usize,
+
+    /// Enables the `lockCoins` GraphQL mutation with the maximum time to live of the leases.
+    /// The coins locked by a lease are excluded from the `coinsToSpend` selections
+    /// of the other clients until the lease expires. The mutation requires the admin token.
+    #[arg(long = "api-coin-lease-max-ttl", env)]
+    pub api_coin_lease_max_ttl: Option<humantime::Duration>,
+
     /// A new block is produced instantly when transactions are available.
     #[clap(flatten)]
     pub poa_trigger: PoATriggerArgs,
 
+    /// Rejects the imported PoA blocks with the timestamp ahead of the local clock,
+    /// or earlier than the expected block time after the previous block, by more
+    /// than the tolerance. The timestamps are not checked if it is not set.
+    #[arg(long = "poa-time-drift-tolerance", env)]
+    pub poa_time_drift_tolerance: Option<humantime::Duration>,
+
+    /// The expected interval between the PoA blocks checked with the drift tolerance.
+    /// Defaults to the `--poa-interval-period` of this node.
+    #[arg(long = "poa-expected-block-time", env)]
+    pub poa_expected_block_time: Option<humantime::Duration>,
+
+    /// The path to the leader lease file on the storage shared with the standby producers.
+    /// The node produces the blocks only while it holds the lease, and the standby node
+    /// takes over the block production if the lease is not renewed.
+    #[arg(long = "poa-leader-lease", requires = "poa_leader_lease_holder", env)]
+    pub poa_leader_lease: Option<PathBuf>,
+
+    /// The unique identifier of the node holding the leader lease.
+    #[arg(long = "poa-leader-lease-holder", requires = "poa_leader_lease", env)]
+    pub poa_leader_lease_holder: Option<String>,
+
+    /// The time after which the leader lease expires if it is not renewed.
+    /// It should be longer than the block time.
+    #[arg(long = "poa-leader-lease-ttl", default_value = "10s", env)]
+    pub poa_leader_lease_ttl: humantime::Duration,
+
+    /// The file storing the pause of the block production set by the admin API.
+    /// The pause is restored from the file on startup. If not set, the block
+    /// production resumes after a restart.
+    #[arg(long = "poa-pause-path", env)]
+    pub poa_pause_path: Option<PathBuf>,
+
     /// The block's fee recipient public key.
     ///
     /// If not set, `consensus_key` is used as the provider of the `Address`.
+    #[cfg(feature = "producer")]
     #[arg(long = "coinbase-recipient", env)]
     pub coinbase_recipient: Option<String>,
 
+    /// The wall-clock budget for producing a block. The producer stops including
+    /// the transactions from the TxPool when the budget is nearly exhausted, instead
+    /// of missing the block time. The time is not limited if it is not set.
+    #[cfg(feature = "producer")]
+    #[arg(long = "block-production-budget", env)]
+    pub block_production_budget: Option<humantime::Duration>,
+
     #[cfg_attr(feature = "relayer", clap(flatten))]
     #[cfg(feature = "relayer")]
     pub relayer_args: relayer::RelayerArgs,
 
+    #[cfg_attr(feature = "shared-sequencer", clap(flatten))]
+    #[cfg(feature = "shared-sequencer")]
+    pub shared_sequencer_args: shared_sequencer::SharedSequencerArgs,
+
     #[cfg_attr(feature = "p2p", clap(flatten))]
     #[cfg(feature = "p2p")]
     pub p2p_args: p2p::P2PArgs,
@@ -177,6 +352,17 @@ pub struct Command {
     #[clap(long = "tx-max-number", default_value = "4064", env)]
     pub tx_max_number: usize,
 
+    /// The max total size in bytes of the transactions that the `TxPool` can simultaneously store.
+    /// The size is unlimited if not set.
+    #[clap(long = "tx-max-bytes", env)]
+    pub tx_max_bytes: Option<usize>,
+
+    /// The max total size in bytes of the contract deployments that the `TxPool` can
+    /// simultaneously store. They don't count against `tx-max-bytes`.
+    /// The size is unlimited if not set.
+    #[clap(long = "tx-max-blob-bytes", env)]
+    pub tx_max_blob_bytes: Option<usize>,
+
     /// The max depth of the dependent transactions that supported by the `TxPool`.
     #[clap(long = "tx-max-depth", default_value = "10", env)]
     pub tx_max_depth: usize,
@@ -197,33 +383,209 @@ pub struct Command {
     #[clap(long = "query-log-threshold-time", default_value = "2s", env)]
     pub query_log_threshold_time: humantime::Duration,
 
+    /// Logs the storage reads exceeding the threshold with the name of the table.
+    /// The slow reads aren't logged if not set.
+    #[clap(long = "storage-log-threshold-time", env)]
+    pub storage_log_threshold_time: Option<humantime::Duration>,
+
     /// Timeout before drop the request.
     #[clap(long = "api-request-timeout", default_value = "30m", env)]
     pub api_request_timeout: humantime::Duration,
 
+    /// The maximum number of messages buffered for one GraphQL WebSocket connection.
+    /// Cannot be zero.
+    #[clap(long = "api-ws-max-queued-messages", default_value = "128", env)]
+    pub api_ws_max_queued_messages: NonZeroUsize,
+
+    /// The interval between pings sent to GraphQL WebSocket clients.
+    /// Clients that don't answer until the next ping are disconnected.
+    #[clap(long = "api-ws-ping-interval", default_value = "30s", env)]
+    pub api_ws_ping_interval: humantime::Duration,
+
+    /// The time to wait for a slow GraphQL WebSocket client to consume
+    /// the buffered messages before it is disconnected.
+    #[clap(long = "api-ws-slow-consumer-timeout", default_value = "10s", env)]
+    pub api_ws_slow_consumer_timeout: humantime::Duration,
+
+    /// The comma-separated list of origins allowed to make cross-origin requests
+    /// to the GraphQL API. Any origin is allowed if it is not set.
+    #[clap(long = "api-cors-origins", value_delimiter = ',', env)]
+    pub api_cors_origins: Vec<String>,
+
+    /// The maximum size of the GraphQL request body in bytes. The size is unlimited if it is not set.
+    #[clap(long = "api-max-body-size", env)]
+    pub api_max_body_size: Option<usize>,
+
+    /// The path to the PEM encoded certificate chain to serve the GraphQL API over TLS.
+    /// The certificate is reloaded when the file is rotated.
+    #[clap(long = "api-tls-cert", requires = "api_tls_key", env)]
+    pub api_tls_cert: Option<PathBuf>,
+
+    /// The path to the PEM encoded private key of the `api-tls-cert` certificate.
+    #[clap(long = "api-tls-key", requires = "api_tls_cert", env)]
+    pub api_tls_key: Option<PathBuf>,
+
+    /// The maximum number of the GraphQL requests executed at the same time.
+    /// The other requests wait in the queue.
+    #[clap(long = "api-max-concurrent-requests", default_value = "64", env)]
+    pub api_max_concurrent_requests: usize,
+
+    /// The maximum number of the GraphQL requests executed at the same time
+    /// while the node executes a block, to keep the capacity for the block import.
+    #[clap(
+        long = "api-max-concurrent-requests-while-executing",
+        default_value = "16",
+        env
+    )]
+    pub api_max_concurrent_requests_while_executing: usize,
+
+    /// The maximum number of the GraphQL requests waiting in the queue.
+    /// The requests above the limit are rejected with `503 Service Unavailable`.
+    #[clap(long = "api-max-queued-requests", default_value = "1024", env)]
+    pub api_max_queued_requests: usize,
+
+    /// The maximum time the GraphQL request waits in the queue before it is rejected.
+    #[clap(long = "api-queue-timeout", default_value = "30s", env)]
+    pub api_queue_timeout: humantime::Duration,
+
+    /// The number of the latest blocks keeping the receipts of their transactions.
+    /// The receipts of the older blocks are pruned, except the `MessageOut` receipts
+    /// used by the message proofs. The receipts are kept forever if not set.
+    #[clap(long = "receipts-retention", env)]
+    pub receipts_retention: Option<u32>,
+
+    /// The local directory or the HTTP(S) endpoint receiving the receipts before
+    /// they are pruned, one JSON document per block.
+    #[clap(long = "receipts-archive", requires = "receipts_retention", env)]
+    pub receipts_archive: Option<ReceiptsArchiveSink>,
+
+    #[clap(flatten)]
+    pub resources: resources::ResourcesArgs,
+
     #[clap(flatten)]
     pub profiling: profiling::ProfilingArgs,
+
+    #[clap(flatten)]
+    pub telemetry: telemetry::TelemetryArgs,
+
+    #[clap(flatten)]
+    pub alerts: alerts::AlertsArgs,
 }
 
 impl Command {
-    pub fn get_config(self) -> anyhow::Result<Config> {
+    /// Caps the caches, the `TxPool`, the concurrent GraphQL requests, and
+    /// the sync pipeline by the limits of the resource profile.
+    fn apply_profile(&mut self) {
+        if self.resources.profile != Profile::LowMemory {
+            return
+        }
+        info!("Using the low-memory resource profile");
+
+        cap(
+            &mut self.max_database_cache_size,
+            low_memory::DATABASE_CACHE_SIZE,
+        );
+        cap(
+            &mut self.contract_cache_size,
+            low_memory::CONTRACT_CACHE_SIZE,
+        );
+        cap(&mut self.coin_cache_size, low_memory::COIN_CACHE_SIZE);
+        cap(
+            &mut self.api_entity_cache_size,
+            low_memory::API_ENTITY_CACHE_SIZE,
+        );
+
+        cap(&mut self.tx_max_number, low_memory::TX_MAX_NUMBER);
+        let tx_max_bytes = self.tx_max_bytes.get_or_insert(low_memory::TX_MAX_BYTES);
+        cap(tx_max_bytes, low_memory::TX_MAX_BYTES);
+        let tx_max_blob_bytes = self
+            .tx_max_blob_bytes
+            .get_or_insert(low_memory::TX_MAX_BLOB_BYTES);
+        cap(tx_max_blob_bytes, low_memory::TX_MAX_BLOB_BYTES);
+        cap(
+            &mut self.tx_number_active_subscriptions,
+            low_memory::TX_NUMBER_ACTIVE_SUBSCRIPTIONS,
+        );
+
+        cap(
+            &mut self.api_max_concurrent_requests,
+            low_memory::API_MAX_CONCURRENT_REQUESTS,
+        );
+        cap(
+            &mut self.api_max_concurrent_requests_while_executing,
+            low_memory::API_MAX_CONCURRENT_REQUESTS_WHILE_EXECUTING,
+        );
+
+        #[cfg(feature = "p2p")]
+        {
+            let sync = &mut self.sync_args;
+            cap(
+                &mut sync.block_stream_buffer_size,
+                low_memory::SYNC_BLOCK_STREAM_BUFFER_SIZE,
+            );
+            cap(
+                &mut sync.header_batch_size,
+                low_memory::SYNC_HEADER_BATCH_SIZE,
+            );
+            cap(&mut sync.body_batch_size, low_memory::SYNC_BODY_BATCH_SIZE);
+            cap(
+                &mut sync.header_stream_concurrency,
+                low_memory::SYNC_HEADER_STREAM_CONCURRENCY,
+            );
+            cap(
+                &mut sync.max_in_flight_per_peer,
+                low_memory::SYNC_MAX_IN_FLIGHT_PER_PEER,
+            );
+        }
+    }
+
+    pub fn get_config(mut self) -> anyhow::Result<Config> {
+        self.apply_profile();
+        let memory_report_interval = self.resources.memory_report_interval();
         let Command {
             ip,
             port,
+            #[cfg(feature = "grpc")]
+            grpc_addr,
             service_name: name,
             max_database_cache_size,
+            contract_cache_size,
+            coin_cache_size,
             database_path,
             database_type,
             chain_config,
+            network,
+            state_snapshot,
             vm_backtrace,
+            vm_reexecution_audit,
+            vm_metrics,
             debug,
             utxo_validation,
+            strict_header_verification,
             min_gas_price,
             consensus_key,
+            consensus_keystore,
+            consensus_keystore_passphrase_file,
+            admin_token,
+            api_persisted_queries_only,
+            api_persisted_queries_path,
+            api_entity_cache_size,
+            api_coin_lease_max_ttl,
             poa_trigger,
+            poa_time_drift_tolerance,
+            poa_expected_block_time,
+            poa_leader_lease,
+            poa_leader_lease_holder,
+            poa_leader_lease_ttl,
+            poa_pause_path,
+            #[cfg(feature = "producer")]
             coinbase_recipient,
+            #[cfg(feature = "producer")]
+            block_production_budget,
             #[cfg(feature = "relayer")]
             relayer_args,
+            #[cfg(feature = "shared-sequencer")]
+            shared_sequencer_args,
             #[cfg(feature = "p2p")]
             p2p_args,
             #[cfg(feature = "p2p")]
@@ -233,21 +595,62 @@ impl Command {
             max_wait_time,
             tx_pool_ttl,
             tx_max_number,
+            tx_max_bytes,
+            tx_max_blob_bytes,
             tx_max_depth,
             tx_number_active_subscriptions,
             min_connected_reserved_peers,
             time_until_synced,
             query_log_threshold_time,
+            storage_log_threshold_time,
             api_request_timeout,
+            api_ws_max_queued_messages,
+            api_ws_ping_interval,
+            api_ws_slow_consumer_timeout,
+            api_cors_origins,
+            api_max_body_size,
+            api_tls_cert,
+            api_tls_key,
+            api_max_concurrent_requests,
+            api_max_concurrent_requests_while_executing,
+            api_max_queued_requests,
+            api_queue_timeout,
+            receipts_retention,
+            receipts_archive,
+            resources,
             profiling: _,
+            telemetry: _,
+            alerts,
         } = self;
 
+        let mut caches = vec![
+            ("database cache", max_database_cache_size),
+            ("contract cache", contract_cache_size),
+            ("GraphQL entity cache", api_entity_cache_size),
+        ];
+        if let Some(tx_max_bytes) = tx_max_bytes {
+            caches.push(("TxPool", tx_max_bytes));
+        }
+        if let Some(tx_max_blob_bytes) = tx_max_blob_bytes {
+            caches.push(("TxPool deployments", tx_max_blob_bytes));
+        }
+        resources.validate(&caches)?;
+
         let addr = net::SocketAddr::new(ip, port);
 
-        let chain_conf: ChainConfig = chain_config.as_str().parse()?;
+        let mut chain_conf: ChainConfig = match network {
+            Some(network) => network.chain_config()?,
+            None => chain_config.as_str().parse()?,
+        };
+        let state_snapshot = state_snapshot
+            .map(|location| PartitionedSnapshot::open_storage(location.reader()))
+            .transpose()?;
+        if let Some(state_snapshot) = &state_snapshot {
+            chain_conf.initial_state = Some(state_snapshot.state_config()?);
+        }
 
         #[cfg(feature = "relayer")]
-        let relayer_cfg = relayer_args.into_config();
+        let relayer_cfg = relayer_args.into_config(metrics);
 
         #[cfg(feature = "p2p")]
         let p2p_cfg = p2p_args.into_config(chain_conf.chain_name.clone(), metrics)?;
@@ -260,7 +663,36 @@ impl Command {
             info!("Block production disabled");
         }
 
-        let consensus_key = load_consensus_key(consensus_key)?;
+        let block_time_schedule = poa_time_drift_tolerance.map(|tolerance| {
+            let block_time = poa_expected_block_time.map(Into::into).or(match trigger {
+                Trigger::Interval { block_time } => Some(block_time),
+                _ => None,
+            });
+            BlockTimeSchedule {
+                block_time,
+                tolerance: tolerance.into(),
+            }
+        });
+
+        let leader_lease =
+            poa_leader_lease
+                .zip(poa_leader_lease_holder)
+                .map(|(path, holder)| LeaderLeaseConfig {
+                    path,
+                    holder,
+                    ttl: poa_leader_lease_ttl.into(),
+                });
+
+        let alerts = alerts.into_config(trigger)?;
+
+        let consensus_key = if let Some(keystore) = consensus_keystore {
+            Some(load_consensus_keystore(
+                &keystore,
+                consensus_keystore_passphrase_file.as_deref(),
+            )?)
+        } else {
+            load_consensus_key(consensus_key)?
+        };
         if consensus_key.is_some() && trigger == Trigger::Never {
             warn!("Consensus key configured but block production is disabled!");
         }
@@ -279,10 +711,13 @@ impl Command {
             }
         });
 
+        #[cfg(feature = "producer")]
         let coinbase_recipient = if let Some(coinbase_recipient) = coinbase_recipient {
             Some(
-                ContractId::from_str(coinbase_recipient.as_str())
-                    .map_err(|err| anyhow!(err))?,
+                fuel_core::types::fuel_tx::ContractId::from_str(
+                    coinbase_recipient.as_str(),
+                )
+                .map_err(|err| anyhow::anyhow!(err))?,
             )
         } else {
             tracing::warn!("The coinbase recipient `ContractId` is not set!");
@@ -294,24 +729,33 @@ impl Command {
             max_wait_time: max_wait_time.into(),
         };
 
-        let block_importer =
+        let mut block_importer =
             fuel_core::service::config::fuel_core_importer::Config::new(&chain_conf);
+        block_importer.strict_header_verification = strict_header_verification;
 
         let config = Config {
             addr,
             api_request_timeout: api_request_timeout.into(),
             max_database_cache_size,
+            contract_cache_size,
+            coin_cache_size,
+            api_entity_cache_size,
             database_path,
             database_type,
             chain_conf: chain_conf.clone(),
+            state_snapshot,
             debug,
             utxo_validation,
             block_production: trigger,
             vm: VMConfig {
                 backtrace: vm_backtrace,
+                reexecution_audit: vm_reexecution_audit,
+                metrics: vm_metrics,
             },
             txpool: TxPoolConfig::new(
                 tx_max_number,
+                tx_max_bytes.unwrap_or(usize::MAX),
+                tx_max_blob_bytes.unwrap_or(usize::MAX),
                 tx_max_depth,
                 chain_conf,
                 min_gas_price,
@@ -320,25 +764,69 @@ impl Command {
                 tx_pool_ttl.into(),
                 tx_number_active_subscriptions,
             ),
-            block_producer: ProducerConfig {
+            #[cfg(feature = "producer")]
+            block_producer: fuel_core::producer::Config {
                 utxo_validation,
                 coinbase_recipient,
                 gas_price: min_gas_price,
                 metrics,
+                block_production_budget: block_production_budget.map(Into::into),
             },
             block_importer,
             #[cfg(feature = "relayer")]
             relayer: relayer_cfg,
+            #[cfg(feature = "shared-sequencer")]
+            shared_sequencer: shared_sequencer_args.into_config(),
             #[cfg(feature = "p2p")]
             p2p: p2p_cfg,
             #[cfg(feature = "p2p")]
+            era_source: sync_args.era_source.clone(),
+            #[cfg(feature = "p2p")]
             sync: sync_args.into(),
             consensus_key,
+            admin_token: admin_token.as_deref().map(AdminToken::new),
+            api_persisted_queries_only,
+            api_persisted_queries_path,
+            api_coin_lease_max_ttl: api_coin_lease_max_ttl.map(Into::into),
             name,
             relayer_consensus_config: verifier,
+            block_time_schedule,
+            leader_lease,
+            poa_pause_path,
             min_connected_reserved_peers,
             time_until_synced: time_until_synced.into(),
             query_log_threshold_time: query_log_threshold_time.into(),
+            storage_log_threshold_time: storage_log_threshold_time.map(Into::into),
+            graphql_websocket: WebSocketConfig {
+                max_queued_messages: api_ws_max_queued_messages,
+                ping_interval: api_ws_ping_interval.into(),
+                slow_consumer_timeout: api_ws_slow_consumer_timeout.into(),
+            },
+            graphql_http: HttpConfig {
+                cors_origins: api_cors_origins,
+                max_body_size: api_max_body_size,
+                tls: api_tls_cert.zip(api_tls_key).map(|(cert_path, key_path)| {
+                    TlsConfig {
+                        cert_path,
+                        key_path,
+                    }
+                }),
+            },
+            graphql_scheduler: RequestSchedulerConfig {
+                max_concurrent_requests: api_max_concurrent_requests,
+                max_concurrent_requests_while_executing:
+                    api_max_concurrent_requests_while_executing,
+                max_queued_requests: api_max_queued_requests,
+                queue_timeout: api_queue_timeout.into(),
+            },
+            receipts_pruning: receipts_retention.map(|retention| ReceiptsPruningConfig {
+                retention,
+                archive: receipts_archive,
+            }),
+            alerts,
+            memory_report_interval,
+            #[cfg(feature = "grpc")]
+            grpc_addr,
         };
         Ok(config)
     }
@@ -346,13 +834,22 @@ impl Command {
 
 pub async fn exec(command: Command) -> anyhow::Result<()> {
     let profiling = command.profiling.clone();
+    let telemetry = command.telemetry.clone();
+    command.resources.init_executor_threads()?;
     let config = command.get_config()?;
 
     // start profiling agent if url is configured
     let _profiling_agent = start_pyroscope_agent(profiling, &config)?;
+    // start exporting traces if the OTLP endpoint is configured
+    let _telemetry = telemetry::init_telemetry(telemetry)?;
 
     // log fuel-core version
-    info!("Fuel Core version v{}", env!("CARGO_PKG_VERSION"));
+    let fingerprint = fuel_core::fingerprint::NodeFingerprint::current();
+    info!(
+        "Fuel Core version {}, fingerprint {}",
+        fingerprint,
+        fingerprint.id()
+    );
     trace!("Initializing in TRACE mode.");
     // initialize the server
     let server = FuelService::new_node(config).await?;
@@ -381,6 +878,7 @@ fn load_consensus_key(
     };
 
     if let Some(key) = secret_string {
+        let key = Zeroizing::new(key);
         let key =
             SecretKey::from_str(&key).context("failed to parse consensus signing key")?;
         Ok(Some(Secret::new(key.into())))
@@ -389,6 +887,22 @@ fn load_consensus_key(
     }
 }
 
+fn load_consensus_keystore(
+    path: &Path,
+    passphrase_file: Option<&Path>,
+) -> anyhow::Result<Secret<SecretKeyWrapper>> {
+    let keystore = Keystore::load(path)?;
+    let passphrase = read_passphrase(passphrase_file)?;
+    let key = keystore
+        .decrypt(&passphrase)
+        .with_context(|| format!("failed to decrypt the keystore {}", path.display()))?;
+    info!(
+        "Loaded the consensus key of {} from the keystore",
+        keystore.address
+    );
+    Ok(key)
+}
+
 fn start_pyroscope_agent(
     profiling_args: profiling::ProfilingArgs,
     config: &Config,
@@ -414,7 +928,7 @@ fn start_pyroscope_agent(
         .transpose()
 }
 
-async fn shutdown_signal() -> anyhow::Result<()> {
+pub(crate) async fn shutdown_signal() -> anyhow::Result<()> {
     #[cfg(unix)]
     {
         let mut sigterm =
@@ -0,0 +1,173 @@
+//! Clap configuration related to the alerts of the node operator
+
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core::service::{
+    alerts::{
+        pagerduty_template,
+        GENERIC_TEMPLATE,
+        SLACK_TEMPLATE,
+    },
+    config::{
+        AlertsConfig,
+        Trigger,
+        Webhook,
+    },
+};
+use std::path::PathBuf;
+
+/// The number of the missed block intervals before the alert.
+const MISSED_BLOCK_INTERVALS: u32 = 3;
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct AlertsArgs {
+    /// The webhooks receiving the alerts about the critical events: the missed block
+    /// production, the stalled DA sync, the conflicting blocks, and the database corruption.
+    /// The alerts are disabled if no webhook is set.
+    #[clap(long = "alert-webhook", value_delimiter = ',', env)]
+    pub alert_webhook: Vec<String>,
+
+    /// The format of the JSON payload posted to the webhooks. The `pagerduty` format
+    /// is accepted by `https://events.pagerduty.com/v2/enqueue`.
+    #[clap(long = "alert-format", value_enum, default_value = "generic", env)]
+    pub alert_format: AlertFormat,
+
+    /// The file with the template of the JSON payload, overriding the `alert-format`.
+    /// The `{{kind}}`, `{{node}}`, `{{summary}}`, `{{timestamp}}`, and `{{details}}`
+    /// placeholders are replaced with the fields of the alert.
+    #[clap(long = "alert-template", env)]
+    pub alert_template: Option<PathBuf>,
+
+    /// The routing key of the PagerDuty integration used by the `pagerduty` format.
+    #[clap(long = "alert-pagerduty-routing-key", env)]
+    pub alert_pagerduty_routing_key: Option<String>,
+
+    /// Alerts if the producing node doesn't import a block for this time.
+    /// Defaults to three block intervals if the blocks are produced on the interval.
+    #[clap(long = "alert-block-production-timeout", env)]
+    pub alert_block_production_timeout: Option<humantime::Duration>,
+
+    /// Alerts if the finalized DA height doesn't advance for this time.
+    #[clap(long = "alert-da-stall-timeout", default_value = "10m", env)]
+    pub alert_da_stall_timeout: humantime::Duration,
+
+    /// Alerts about the received blocks conflicting with the imported blocks at least
+    /// this number of blocks deep. The conflicting block at the tip is one block deep.
+    #[clap(long = "alert-min-reorg-depth", default_value = "1", env)]
+    pub alert_min_reorg_depth: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum AlertFormat {
+    /// All fields of the alert.
+    Generic,
+    /// The Slack incoming webhook.
+    Slack,
+    /// The PagerDuty Events API v2.
+    Pagerduty,
+}
+
+impl AlertsArgs {
+    pub fn into_config(self, trigger: Trigger) -> anyhow::Result<Option<AlertsConfig>> {
+        if self.alert_webhook.is_empty() {
+            return Ok(None)
+        }
+
+        let template = match (self.alert_template, self.alert_format) {
+            (Some(path), _) => std::fs::read_to_string(&path).with_context(|| {
+                format!("Failed to read the alert template {}", path.display())
+            })?,
+            (None, AlertFormat::Generic) => GENERIC_TEMPLATE.to_string(),
+            (None, AlertFormat::Slack) => SLACK_TEMPLATE.to_string(),
+            (None, AlertFormat::Pagerduty) => {
+                let routing_key = self.alert_pagerduty_routing_key.ok_or_else(|| {
+                    anyhow!("The `pagerduty` alert format requires the routing key")
+                })?;
+                pagerduty_template(&routing_key)
+            }
+        };
+
+        let block_production_timeout = self
+            .alert_block_production_timeout
+            .map(Into::into)
+            .or(match trigger {
+                Trigger::Interval { block_time } => {
+                    block_time.checked_mul(MISSED_BLOCK_INTERVALS)
+                }
+                Trigger::Instant | Trigger::Never => None,
+            });
+
+        Ok(Some(AlertsConfig {
+            webhooks: self
+                .alert_webhook
+                .into_iter()
+                .map(|url| Webhook {
+                    url,
+                    template: template.clone(),
+                })
+                .collect(),
+            block_production_timeout,
+            da_sync_stall_timeout: self.alert_da_stall_timeout.into(),
+            min_reorg_depth: self.alert_min_reorg_depth,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use std::time::Duration;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        alerts: AlertsArgs,
+    }
+
+    fn parse(args: &[&str]) -> AlertsArgs {
+        Command::try_parse_from(std::iter::once("fuel-core").chain(args.iter().copied()))
+            .unwrap()
+            .alerts
+    }
+
+    #[test]
+    fn alerts_are_disabled_without_webhooks() {
+        let config = parse(&[]).into_config(Trigger::Instant).unwrap();
+
+        assert_eq!(config, None);
+    }
+
+    #[test]
+    fn block_production_timeout_defaults_to_three_intervals() {
+        // Given
+        let args = parse(&["--alert-webhook", "http://localhost/a,http://localhost/b"]);
+        let trigger = Trigger::Interval {
+            block_time: Duration::from_secs(2),
+        };
+
+        // When
+        let config = args.into_config(trigger).unwrap().unwrap();
+
+        // Then
+        assert_eq!(config.webhooks.len(), 2);
+        assert_eq!(
+            config.block_production_timeout,
+            Some(Duration::from_secs(6))
+        );
+    }
+
+    #[test]
+    fn pagerduty_format_requires_routing_key() {
+        let args = parse(&[
+            "--alert-webhook",
+            "https://events.pagerduty.com/v2/enqueue",
+            "--alert-format",
+            "pagerduty",
+        ]);
+
+        assert!(args.into_config(Trigger::Instant).is_err());
+    }
+}
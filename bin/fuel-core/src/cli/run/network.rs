@@ -0,0 +1,55 @@
+use fuel_core::chain_config::ChainConfig;
+
+/// The network with the chain configuration bundled into the binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Network {
+    /// The public testnet.
+    Testnet,
+    /// The development network.
+    Devnet,
+    /// The local network with the pre-funded development accounts.
+    Local,
+}
+
+const TESTNET: &str =
+    include_str!("../../../../../deployment/scripts/chainspec/beta_chainspec.json");
+
+const DEVNET: &str =
+    include_str!("../../../../../deployment/scripts/chainspec/dev_chainspec.json");
+
+impl Network {
+    /// Returns the bundled chain configuration of the network.
+    pub fn chain_config(self) -> anyhow::Result<ChainConfig> {
+        let json = match self {
+            Network::Testnet => TESTNET,
+            Network::Devnet => DEVNET,
+            Network::Local => return Ok(ChainConfig::local_testnet()),
+        };
+        ChainConfig::from_json_slice(json.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core::types::fuel_crypto::Hasher;
+    use test_case::test_case;
+
+    #[test_case(Network::Testnet; "testnet")]
+    #[test_case(Network::Devnet; "devnet")]
+    #[test_case(Network::Local; "local")]
+    fn bundled_chain_config_is_valid(network: Network) {
+        network
+            .chain_config()
+            .expect("The bundled config should be valid");
+    }
+
+    /// The bundled configurations must match the released ones.
+    /// Update the checksum only together with the release of the new configuration.
+    #[test_case(TESTNET, "0f8b1ca623e61af649d2f523a99ca01d044ba46ab60d45d20c013b585a98fef7"; "testnet")]
+    #[test_case(DEVNET, "68ea010e8786fbd794465d63d7ba5d83d99676435941944c7e3b7315be31e1e3"; "devnet")]
+    fn bundled_chain_config_matches_checksum(json: &str, expected: &str) {
+        let checksum = hex::encode(Hasher::hash(json.as_bytes()));
+        assert_eq!(checksum, expected);
+    }
+}
@@ -9,6 +9,7 @@ use fuel_core::{
             convert_to_libp2p_keypair,
             Config,
             NotInitialized,
+            PreSharedKey,
             MAX_RESPONSE_SIZE,
         },
         gossipsub_config::default_gossipsub_builder,
@@ -26,7 +27,10 @@ use std::{
         Ipv4Addr,
     },
     num::NonZeroU32,
-    path::PathBuf,
+    path::{
+        Path,
+        PathBuf,
+    },
     str::FromStr,
     time::Duration,
 };
@@ -58,6 +62,12 @@ pub struct P2PArgs {
     #[clap(long = "peering-port", default_value = "30333", env)]
     pub peering_port: u16,
 
+    /// The UDP port for the QUIC transport. If it is set, the node accepts and
+    /// dials the QUIC addresses in addition to the TCP ones.
+    /// The QUIC transport can't be used with the `--p2p-psk-file`.
+    #[clap(long = "quic-peering-port", env)]
+    pub quic_peering_port: Option<u16>,
+
     /// Max Block size
     #[clap(long = "max-block-size", default_value = MAX_RESPONSE_SIZE_STR, env)]
     pub max_block_size: usize,
@@ -80,6 +90,12 @@ pub struct P2PArgs {
     #[clap(long = "reserved-nodes-only-mode", env)]
     pub reserved_nodes_only_mode: bool,
 
+    /// The path to the pre-shared key of the private network in the `swarm.key` format:
+    /// `/key/swarm/psk/1.0.0/`, `/base16/` and the hex encoded 32 bytes key on separate lines.
+    /// Only the nodes with the same key can connect to the node.
+    #[clap(long = "p2p-psk-file", env)]
+    pub private_network_key_file: Option<PathBuf>,
+
     /// Allow nodes to be discoverable on the local network
     #[clap(long = "enable-mdns", env)]
     pub enable_mdns: bool,
@@ -102,6 +118,12 @@ pub struct P2PArgs {
     #[clap(long = "random-walk", default_value = "0", env)]
     pub random_walk: u64,
 
+    /// Set the interval between the peer exchanges of the known addresses with
+    /// the connected peers in seconds. The peer exchange is disabled if it is `0`
+    /// or if `reserved_nodes_only_mode` is set to `true`.
+    #[clap(long = "peer-exchange-interval", default_value = "60", env)]
+    pub peer_exchange_interval: u64,
+
     /// Choose to include private IPv4/IPv6 addresses as discoverable
     /// except for the ones stored in `bootstrap_nodes`
     #[clap(long = "allow-private-addresses", env)]
@@ -148,6 +170,16 @@ pub struct P2PArgs {
     #[clap(long = "max-transmit-size", default_value = MAX_RESPONSE_SIZE_STR, env)]
     pub max_transmit_size: usize,
 
+    /// Gossip the ids of the transactions instead of the full transactions.
+    /// The peers request the bodies of the transactions they don't know yet.
+    #[clap(long = "tx-announcements", env)]
+    pub tx_announcements: bool,
+
+    /// How long the gossiped transactions are kept to serve the requests of the peers
+    /// and to deduplicate them
+    #[clap(long = "tx-gossip-window", default_value = "60s", env)]
+    pub tx_gossip_window: humantime::Duration,
+
     /// Choose timeout for sent requests in RequestResponse protocol
     #[clap(long = "request-timeout", default_value = "20", env)]
     pub request_timeout: u64,
@@ -191,6 +223,31 @@ pub struct SyncArgs {
     /// The maximum number of headers to request in a single batch.
     #[clap(long = "sync-header-batch-size", default_value = "10", env)]
     pub header_batch_size: u32,
+    /// The maximum number of blocks to request the transactions for in a single batch.
+    #[clap(long = "sync-body-batch-size", default_value = "10", env)]
+    pub body_batch_size: u32,
+    /// The number of the batches of headers requested concurrently.
+    #[clap(long = "sync-header-stream-concurrency", default_value = "1", env)]
+    pub header_stream_concurrency: usize,
+    /// The maximum number of the get transaction requests in flight to a single peer.
+    #[clap(long = "sync-max-in-flight-per-peer", default_value = "10", env)]
+    pub max_in_flight_per_peer: usize,
+    /// The peer responding slower than the threshold gets the next requests delayed.
+    /// The delay doubles with each slow response and resets after the fast one.
+    #[clap(long = "sync-slow-peer-threshold", default_value = "5s", env)]
+    pub slow_peer_threshold: humantime::Duration,
+    /// The maximum delay of the requests to the slow peer.
+    #[clap(long = "sync-max-peer-backoff", default_value = "10s", env)]
+    pub max_peer_backoff: humantime::Duration,
+    /// The number of blocks below the block that failed to import, searched for
+    /// the conflicting blocks sealed by the authority. Zero disables the search.
+    #[clap(long = "sync-max-fork-depth", default_value = "10", env)]
+    pub max_fork_depth: u32,
+    /// The directory or the HTTP URL of the archive with the era files exported by
+    /// `fuel-core export-era`. The blocks from the archive are imported before the sync
+    /// with the network. Each block is still checked and executed.
+    #[clap(long = "era-source", env)]
+    pub era_source: Option<fuel_core::era::EraSource>,
 }
 
 #[derive(Clone, Debug)]
@@ -223,6 +280,12 @@ impl From<SyncArgs> for fuel_core::sync::Config {
         Self {
             block_stream_buffer_size: value.block_stream_buffer_size,
             header_batch_size: value.header_batch_size as usize,
+            body_batch_size: value.body_batch_size as usize,
+            header_stream_concurrency: value.header_stream_concurrency,
+            max_in_flight_per_peer: value.max_in_flight_per_peer,
+            slow_peer_threshold: value.slow_peer_threshold.into(),
+            max_peer_backoff: value.max_peer_backoff.into(),
+            max_fork_depth: value.max_fork_depth,
         }
     }
 }
@@ -256,6 +319,11 @@ impl P2PArgs {
             }
         };
 
+        let private_network_key = self
+            .private_network_key_file
+            .map(|path| read_private_network_key(&path))
+            .transpose()?;
+
         let gossipsub_config = default_gossipsub_builder()
             .mesh_n(self.ideal_mesh_size)
             .mesh_n_low(self.min_mesh_size)
@@ -273,6 +341,12 @@ impl P2PArgs {
             Some(Duration::from_secs(self.random_walk))
         };
 
+        let peer_exchange_interval = if self.peer_exchange_interval == 0 {
+            None
+        } else {
+            Some(Duration::from_secs(self.peer_exchange_interval))
+        };
+
         let heartbeat_config = {
             let send_duration = Duration::from_secs(self.heartbeat_send_duration);
             let idle_duration = Duration::from_secs(self.heartbeat_idle_duration);
@@ -283,6 +357,9 @@ impl P2PArgs {
             )
         };
 
+        let defaults = Config::default(&network_name);
+        // The in-memory network of the `test-helpers` feature keeps its default.
+        #[allow(clippy::needless_update)]
         let config = Config {
             keypair: local_keypair,
             network_name,
@@ -292,20 +369,25 @@ impl P2PArgs {
                 .unwrap_or_else(|| IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0]))),
             public_address: self.public_address,
             tcp_port: self.peering_port,
+            quic_port: self.quic_peering_port,
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
             bootstrap_nodes: self.bootstrap_nodes,
             reserved_nodes: self.reserved_nodes,
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
+            private_network_key,
             enable_mdns: self.enable_mdns,
             max_peers_connected: self.max_peers_connected,
             max_connections_per_peer: self.max_connections_per_peer,
             allow_private_addresses: self.allow_private_addresses,
             random_walk,
+            peer_exchange_interval,
             connection_idle_timeout: Some(Duration::from_secs(
                 self.connection_idle_timeout,
             )),
             gossipsub_config,
+            tx_announcements: self.tx_announcements,
+            tx_gossip_window: self.tx_gossip_window.into(),
             heartbeat_config,
             set_request_timeout: Duration::from_secs(self.request_timeout),
             set_connection_keep_alive: Duration::from_secs(self.connection_keep_alive),
@@ -320,7 +402,23 @@ impl P2PArgs {
             identify_interval: Some(Duration::from_secs(self.identify_interval)),
             metrics,
             state: NotInitialized,
+            ..defaults
         };
         Ok(Some(config))
     }
 }
+
+fn read_private_network_key(path: &Path) -> anyhow::Result<PreSharedKey> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        anyhow!(
+            "Failed to read the pre-shared key file `{}`: {e}",
+            path.display()
+        )
+    })?;
+    PreSharedKey::from_str(&content).map_err(|e| {
+        anyhow!(
+            "Invalid pre-shared key in the file `{}`: {e}",
+            path.display()
+        )
+    })
+}
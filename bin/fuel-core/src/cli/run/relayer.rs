@@ -51,6 +51,11 @@ pub struct RelayerArgs {
 
     #[clap(long = "relayer-eth-sync-log-freq-s", default_value_t = Config::DEFAULT_SYNCING_LOG_FREQ.as_secs(), env)]
     pub syncing_log_frequency_secs: u64,
+
+    /// The relayer logs a warning when it is behind the finalized
+    /// DA height by more than this number of blocks.
+    #[clap(long = "relayer-lag-warning-threshold", default_value_t = Config::DEFAULT_LAG_WARNING_THRESHOLD, env)]
+    pub lag_warning_threshold: u64,
 }
 
 pub fn parse_h160(input: &str) -> Result<H160, <H160 as FromStr>::Err> {
@@ -58,7 +63,7 @@ pub fn parse_h160(input: &str) -> Result<H160, <H160 as FromStr>::Err> {
 }
 
 impl RelayerArgs {
-    pub fn into_config(self) -> Option<Config> {
+    pub fn into_config(self, metrics: bool) -> Option<Config> {
         if !self.enable_relayer {
             tracing::info!("Relayer service disabled");
             return None
@@ -72,7 +77,8 @@ impl RelayerArgs {
             sync_minimum_duration: Duration::from_secs(self.sync_minimum_duration_secs),
             syncing_call_frequency: Duration::from_secs(self.syncing_call_frequency_secs),
             syncing_log_frequency: Duration::from_secs(self.syncing_log_frequency_secs),
-            metrics: false,
+            lag_warning_threshold: self.lag_warning_threshold,
+            metrics,
         };
         Some(config)
     }
@@ -0,0 +1,255 @@
+use clap::Args;
+use std::{
+    num::NonZeroUsize,
+    time::Duration,
+};
+
+/// The thread pools and the memory budget of the node, so it has predictable
+/// performance on a machine shared with other services.
+#[derive(Debug, Clone, Args)]
+pub struct ResourcesArgs {
+    /// The number of threads executing the blocks and verifying the transactions.
+    /// Uses the number of CPUs if not set.
+    #[clap(long = "executor-threads", env)]
+    pub executor_threads: Option<NonZeroUsize>,
+
+    /// The number of the async runtime worker threads. The GraphQL API and the P2P
+    /// network are served by this runtime. Uses the number of CPUs if not set.
+    #[clap(long = "async-threads", env)]
+    pub async_threads: Option<NonZeroUsize>,
+
+    /// The memory budget in bytes for the database cache, the contract cache, and the `TxPool`.
+    /// The node doesn't start if their sum exceeds the budget. Defaults to the host memory.
+    #[clap(long = "memory-budget", env)]
+    pub memory_budget: Option<usize>,
+
+    /// The resource profile of the node. The `low-memory` profile caps the caches,
+    /// the `TxPool`, the concurrent VMs, and the sync pipeline to run a follower node
+    /// with about 1 GiB of RAM. The lower values set explicitly are kept.
+    #[clap(long = "profile", value_enum, default_value = "default", env)]
+    pub profile: Profile,
+
+    /// Logs the memory used by the caches, the storage engine, and the `TxPool`
+    /// with this interval. Defaults to one minute with the `low-memory` profile,
+    /// otherwise the memory isn't logged.
+    #[clap(long = "memory-report-interval", env)]
+    pub memory_report_interval: Option<humantime::Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Profile {
+    /// Uses the limits set by the arguments.
+    Default,
+    /// Caps the limits set by the arguments to fit about 1 GiB of RAM.
+    LowMemory,
+}
+
+/// The limits of the `low-memory` profile.
+pub mod low_memory {
+    use std::time::Duration;
+
+    const MIB: usize = 1024 * 1024;
+
+    /// Each of the on-chain, off-chain, and relayer databases has its own cache.
+    pub const DATABASE_CACHE_SIZE: usize = 128 * MIB;
+    pub const CONTRACT_CACHE_SIZE: usize = 16 * MIB;
+    pub const COIN_CACHE_SIZE: usize = 10_000;
+    pub const API_ENTITY_CACHE_SIZE: usize = 8 * MIB;
+    pub const TX_MAX_NUMBER: usize = 1024;
+    pub const TX_MAX_BYTES: usize = 32 * MIB;
+    pub const TX_MAX_BLOB_BYTES: usize = 16 * MIB;
+    pub const TX_NUMBER_ACTIVE_SUBSCRIPTIONS: usize = 256;
+    /// Each executed request may run the VM, e.g. the dry run or the gas estimation.
+    pub const API_MAX_CONCURRENT_REQUESTS: usize = 4;
+    pub const API_MAX_CONCURRENT_REQUESTS_WHILE_EXECUTING: usize = 1;
+    /// Each executor thread runs its own VM.
+    pub const EXECUTOR_THREADS: usize = 2;
+    pub const ASYNC_THREADS: usize = 2;
+    #[cfg(feature = "p2p")]
+    pub const SYNC_BLOCK_STREAM_BUFFER_SIZE: usize = 2;
+    #[cfg(feature = "p2p")]
+    pub const SYNC_HEADER_BATCH_SIZE: u32 = 5;
+    #[cfg(feature = "p2p")]
+    pub const SYNC_BODY_BATCH_SIZE: u32 = 5;
+    #[cfg(feature = "p2p")]
+    pub const SYNC_HEADER_STREAM_CONCURRENCY: usize = 1;
+    #[cfg(feature = "p2p")]
+    pub const SYNC_MAX_IN_FLIGHT_PER_PEER: usize = 2;
+    pub const MEMORY_REPORT_INTERVAL: Duration = Duration::from_secs(60);
+}
+
+/// Lowers the `value` to the `limit` if it exceeds it.
+pub fn cap<T: Ord + Copy>(value: &mut T, limit: T) {
+    *value = core::cmp::min(*value, limit);
+}
+
+impl ResourcesArgs {
+    /// The number of the executor threads capped by the profile.
+    pub fn executor_threads(&self) -> Option<NonZeroUsize> {
+        self.capped_threads(self.executor_threads, low_memory::EXECUTOR_THREADS)
+    }
+
+    /// The number of the async runtime worker threads capped by the profile.
+    pub fn async_threads(&self) -> Option<NonZeroUsize> {
+        self.capped_threads(self.async_threads, low_memory::ASYNC_THREADS)
+    }
+
+    /// The interval of the memory reports, if they are enabled.
+    pub fn memory_report_interval(&self) -> Option<Duration> {
+        match (self.memory_report_interval, self.profile) {
+            (Some(interval), _) => Some(interval.into()),
+            (None, Profile::LowMemory) => Some(low_memory::MEMORY_REPORT_INTERVAL),
+            (None, Profile::Default) => None,
+        }
+    }
+
+    fn capped_threads(
+        &self,
+        threads: Option<NonZeroUsize>,
+        limit: usize,
+    ) -> Option<NonZeroUsize> {
+        match self.profile {
+            Profile::Default => threads,
+            Profile::LowMemory => {
+                let limit = NonZeroUsize::new(limit).expect("The limit is not zero");
+                let threads = threads
+                    .or_else(|| std::thread::available_parallelism().ok())
+                    .unwrap_or(limit);
+                Some(threads.min(limit))
+            }
+        }
+    }
+
+    /// Builds the global thread pool used by the executor and the transactions verification.
+    pub fn init_executor_threads(&self) -> anyhow::Result<()> {
+        if let Some(threads) = self.executor_threads() {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads.get())
+                .thread_name(|index| format!("executor-{index}"))
+                .build_global()?;
+        }
+        Ok(())
+    }
+
+    /// Checks that the thread pools and the `caches` with their sizes in bytes fit the host.
+    pub fn validate(&self, caches: &[(&str, usize)]) -> anyhow::Result<()> {
+        let host_threads = std::thread::available_parallelism()?.get();
+        self.validate_with_host(caches, host_threads, host_memory())
+    }
+
+    fn validate_with_host(
+        &self,
+        caches: &[(&str, usize)],
+        host_threads: usize,
+        host_memory: Option<usize>,
+    ) -> anyhow::Result<()> {
+        let threads = [self.executor_threads, self.async_threads]
+            .into_iter()
+            .flatten()
+            .fold(0usize, |sum, threads| sum.saturating_add(threads.get()));
+        anyhow::ensure!(
+            threads <= host_threads,
+            "The configured {threads} executor and async threads exceed \
+            the {host_threads} CPUs of the host"
+        );
+
+        if let (Some(budget), Some(host_memory)) = (self.memory_budget, host_memory) {
+            anyhow::ensure!(
+                budget <= host_memory,
+                "The memory budget of {budget} bytes exceeds the {host_memory} bytes \
+                of the host memory"
+            );
+        }
+        if let Some(budget) = self.memory_budget.or(host_memory) {
+            let total = caches
+                .iter()
+                .fold(0usize, |sum, (_, size)| sum.saturating_add(*size));
+            if total > budget {
+                let caches = caches
+                    .iter()
+                    .map(|(name, size)| format!("{name}: {size}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                anyhow::bail!(
+                    "The caches take {total} bytes ({caches}) and exceed \
+                    the memory budget of {budget} bytes"
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the total memory of the host in bytes, if it is known.
+fn host_memory() -> Option<usize> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let kib = meminfo
+        .lines()
+        .find_map(|line| line.strip_prefix("MemTotal:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse::<usize>()
+        .ok()?;
+    kib.checked_mul(1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::Parser;
+    use test_case::test_case;
+
+    #[derive(Debug, Clone, Parser)]
+    pub struct Command {
+        #[clap(flatten)]
+        resources: ResourcesArgs,
+    }
+
+    #[test_case(&[""] => true; "defaults fit the host")]
+    #[test_case(&["", "--executor-threads=2", "--async-threads=2"] => true; "threads fit the host")]
+    #[test_case(&["", "--executor-threads=3", "--async-threads=2"] => false; "threads exceed the host")]
+    #[test_case(&["", "--memory-budget=1000"] => true; "caches fit the budget")]
+    #[test_case(&["", "--memory-budget=500"] => false; "caches exceed the budget")]
+    #[test_case(&["", "--memory-budget=5000"] => false; "budget exceeds the host memory")]
+    fn validate(args: &[&str]) -> bool {
+        let command = Command::try_parse_from(args).unwrap();
+        command
+            .resources
+            .validate_with_host(&[("database", 400), ("txpool", 200)], 4, Some(2000))
+            .is_ok()
+    }
+
+    #[test]
+    fn validate_uses_host_memory_as_default_budget() {
+        let command = Command::try_parse_from([""]).unwrap();
+
+        let result =
+            command
+                .resources
+                .validate_with_host(&[("database", 3000)], 4, Some(2000));
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn low_memory_profile_caps_the_threads() {
+        let command =
+            Command::try_parse_from(["", "--profile=low-memory", "--async-threads=1"])
+                .unwrap();
+
+        let executor_threads = command.resources.executor_threads().unwrap().get();
+        let async_threads = command.resources.async_threads().unwrap().get();
+
+        assert!(executor_threads <= low_memory::EXECUTOR_THREADS);
+        assert_eq!(async_threads, 1);
+    }
+
+    #[test_case(&[""] => None; "not reported by default")]
+    #[test_case(&["", "--profile=low-memory"] => Some(low_memory::MEMORY_REPORT_INTERVAL); "reported with the low-memory profile")]
+    #[test_case(&["", "--profile=low-memory", "--memory-report-interval=5s"] => Some(Duration::from_secs(5)); "explicit interval")]
+    fn memory_report_interval(args: &[&str]) -> Option<Duration> {
+        let command = Command::try_parse_from(args).unwrap();
+        command.resources.memory_report_interval()
+    }
+}
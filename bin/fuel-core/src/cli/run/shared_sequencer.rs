@@ -0,0 +1,47 @@
+use clap::{
+    builder::ArgPredicate::IsPresent,
+    Args,
+};
+use fuel_core::shared_sequencer::Config;
+
+#[derive(Debug, Clone, Args)]
+pub struct SharedSequencerArgs {
+    /// Enable the publishing of the sealed block headers to the shared sequencer
+    /// or the settlement layer. By default, the publishing is disabled.
+    #[clap(long = "enable-shared-sequencer", action)]
+    pub enable_shared_sequencer: bool,
+
+    /// The endpoint that accepts the sealed block headers as a JSON body of the `POST` request.
+    #[arg(long = "shared-sequencer-endpoint", env)]
+    #[arg(required_if_eq("enable_shared_sequencer", "true"))]
+    #[arg(requires_if(IsPresent, "enable_shared_sequencer"))]
+    pub shared_sequencer_endpoint: Option<url::Url>,
+
+    /// The delay before the first retry of the failed publication.
+    #[clap(long = "shared-sequencer-min-retry-backoff", default_value = "1s", env)]
+    pub min_retry_backoff: humantime::Duration,
+
+    /// The maximum delay between retries of the failed publication.
+    #[clap(
+        long = "shared-sequencer-max-retry-backoff",
+        default_value = "60s",
+        env
+    )]
+    pub max_retry_backoff: humantime::Duration,
+}
+
+impl SharedSequencerArgs {
+    pub fn into_config(self) -> Option<Config> {
+        if !self.enable_shared_sequencer {
+            tracing::info!("Shared sequencer publishing disabled");
+            return None
+        }
+
+        let endpoint = self.shared_sequencer_endpoint?;
+        Some(Config {
+            endpoint,
+            min_retry_backoff: self.min_retry_backoff.into(),
+            max_retry_backoff: self.max_retry_backoff.into(),
+        })
+    }
+}
@@ -0,0 +1,76 @@
+use anyhow::Context;
+use clap::Args;
+use opentelemetry::{
+    global,
+    sdk::{
+        propagation::TraceContextPropagator,
+        trace::{
+            self,
+            Sampler,
+        },
+        Resource,
+    },
+    KeyValue,
+};
+use opentelemetry_otlp::WithExportConfig;
+
+#[derive(Debug, Clone, Args)]
+pub struct TelemetryArgs {
+    /// Enables the export of traces to the OpenTelemetry collector if set.
+    /// The traces are exported via OTLP over gRPC to the endpoint, for example
+    /// `http://localhost:4317`.
+    #[clap(long = "otlp-endpoint", env)]
+    pub otlp_endpoint: Option<String>,
+
+    /// The name of the service reported with exported traces.
+    #[clap(long = "otlp-service-name", default_value = "fuel-core", env)]
+    pub otlp_service_name: String,
+
+    /// The ratio of traces to export, from `0.0` to `1.0`. Traces continued from
+    /// the caller follow the sampling decision of the caller.
+    #[clap(long = "otlp-sample-ratio", default_value = "1.0", env)]
+    pub otlp_sample_ratio: f64,
+}
+
+/// Flushes the exported traces and stops the exporter on drop.
+pub struct TelemetryGuard;
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        global::shutdown_tracer_provider();
+    }
+}
+
+/// Starts the export of traces if the OTLP endpoint is configured.
+pub fn init_telemetry(args: TelemetryArgs) -> anyhow::Result<Option<TelemetryGuard>> {
+    let Some(endpoint) = args.otlp_endpoint else {
+        return Ok(None)
+    };
+
+    let tracer = opentelemetry_otlp::new_pipeline()
+        .tracing()
+        .with_exporter(
+            opentelemetry_otlp::new_exporter()
+                .tonic()
+                .with_endpoint(endpoint.clone()),
+        )
+        .with_trace_config(
+            trace::config()
+                .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+                    args.otlp_sample_ratio,
+                ))))
+                .with_resource(Resource::new(vec![KeyValue::new(
+                    "service.name",
+                    args.otlp_service_name,
+                )])),
+        )
+        .install_batch(opentelemetry::runtime::Tokio)
+        .context("failed to start the OTLP exporter")?;
+
+    // Continue traces of callers that pass the W3C `traceparent` header.
+    global::set_text_map_propagator(TraceContextPropagator::new());
+    crate::cli::init_telemetry_layer(tracer)?;
+
+    tracing::info!("Exporting traces to {endpoint}");
+    Ok(Some(TelemetryGuard))
+}
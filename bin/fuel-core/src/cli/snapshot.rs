@@ -3,9 +3,20 @@ use clap::{
     Parser,
     Subcommand,
 };
-use fuel_core::types::fuel_types::ContractId;
+use fuel_core::{
+    chain_config::StateFilter,
+    snapshot_store::SnapshotLocation,
+    types::fuel_types::{
+        Address,
+        AssetId,
+        ContractId,
+    },
+};
 use std::path::PathBuf;
 
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production", test))]
+mod csv;
+
 /// Print a snapshot of blockchain state to stdout.
 #[derive(Debug, Clone, Parser)]
 pub struct Command {
@@ -31,6 +42,35 @@ pub enum SubCommands {
         /// Specify either an alias to a built-in configuration or filepath to a JSON file.
         #[clap(name = "CHAIN_CONFIG", long = "chain", default_value = "local_testnet")]
         chain_config: String,
+
+        /// The filters of the exported state, without them the whole state is exported.
+        #[clap(flatten)]
+        filter: FilterArgs,
+    },
+    /// Exports the state into the `OUTPUT_DIRECTORY` in the `format`.
+    #[command(arg_required_else_help = true)]
+    Export {
+        /// The format of the exported state.
+        #[clap(long = "format", value_enum)]
+        format: ExportFormat,
+
+        /// The directory of the exported files, created if missing. With the `object-store`
+        /// feature, the `partitioned` format can be uploaded to the `http(s)://`, `s3://`
+        /// or `gs://` URL, each file once it is full.
+        #[clap(name = "OUTPUT_DIRECTORY", long = "output-directory")]
+        output_directory: SnapshotLocation,
+
+        /// The filters of the exported state, without them the whole state is exported.
+        #[clap(flatten)]
+        filter: FilterArgs,
+
+        /// The maximum number of the rows in one file of the `partitioned` format.
+        #[clap(long = "max-rows-per-file", default_value = "1000000", value_parser = clap::value_parser!(u64).range(1..))]
+        max_rows_per_file: u64,
+
+        /// The maximum size in bytes of one file of the `partitioned` format.
+        #[clap(long = "max-bytes-per-file", default_value = "268435456")]
+        max_bytes_per_file: u64,
     },
     /// Creates a config for the contract.
     #[command(arg_required_else_help = true)]
@@ -41,6 +81,47 @@ pub enum SubCommands {
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// The `state_config.json` file with the whole `StateConfig`.
+    Json,
+    /// The flat `coins.csv`, `messages.csv` and `contract_balances.csv` files
+    /// without the contract code and state, and without the relayer state.
+    Csv,
+    /// The `state_config.bin` file with the whole `StateConfig` in the compact
+    /// binary format, loaded by `StateConfig::from_binary_slice`.
+    Binary,
+    /// The `manifest.json` file and the tables of the binary format split into
+    /// the files by the size, loaded by `fuel-core run --state-snapshot`.
+    Partitioned,
+}
+
+/// Each filter restricts only the related entries, and can be repeated.
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct FilterArgs {
+    /// Exports only the coins and the messages of the owner.
+    #[clap(long = "owner")]
+    owners: Vec<Address>,
+
+    /// Exports only the coins and the contract balances of the asset.
+    #[clap(long = "asset-id")]
+    asset_ids: Vec<AssetId>,
+
+    /// Exports only the contract with its state and balances.
+    #[clap(long = "contract-id")]
+    contract_ids: Vec<ContractId>,
+}
+
+impl From<FilterArgs> for StateFilter {
+    fn from(args: FilterArgs) -> Self {
+        Self {
+            owners: args.owners,
+            asset_ids: args.asset_ids,
+            contract_ids: args.contract_ids,
+        }
+    }
+}
+
 #[cfg(not(any(feature = "rocksdb", feature = "rocksdb-production")))]
 pub async fn exec(command: Command) -> anyhow::Result<()> {
     Err(anyhow::anyhow!(
@@ -55,9 +136,13 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     use fuel_core::{
         chain_config::{
             ChainConfig,
+            PartitionLimits,
             StateConfig,
         },
-        database::Database,
+        database::{
+            database_description::relayer::Relayer,
+            Database,
+        },
     };
     let path = command.database_path;
     let data_source = fuel_core::state::rocks_db::RocksDb::default_open(&path, None)
@@ -67,11 +152,29 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
             path.display()
         ))?;
     let db = Database::new(std::sync::Arc::new(data_source));
+    let relayer_config = || -> anyhow::Result<_> {
+        let data_source =
+            fuel_core::state::rocks_db::RocksDb::<Relayer>::default_open(&path, None)
+                .map_err(Into::<anyhow::Error>::into)
+                .context(format!(
+                    "failed to open relayer database at path {}",
+                    path.display()
+                ))?;
+        let db = Database::<Relayer>::new(std::sync::Arc::new(data_source));
+        Ok(db.get_relayer_config()?)
+    };
 
     match command.subcommand {
-        SubCommands::Everything { chain_config } => {
+        SubCommands::Everything {
+            chain_config,
+            filter,
+        } => {
             let config: ChainConfig = chain_config.parse()?;
-            let state_conf = StateConfig::generate_state_config(db)?;
+            let state_conf = StateConfig {
+                relayer: relayer_config()?,
+                ..StateConfig::generate_state_config(db)?
+            }
+            .filter(&filter.into());
 
             let chain_conf = ChainConfig {
                 initial_state: Some(state_conf),
@@ -83,6 +186,76 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
             serde_json::to_writer_pretty(stdout, &chain_conf)
                 .context("failed to dump snapshot to JSON")?;
         }
+        SubCommands::Export {
+            format,
+            output_directory,
+            filter,
+            max_rows_per_file,
+            max_bytes_per_file,
+        } => {
+            let filter: StateFilter = filter.into();
+            let state_conf = || -> anyhow::Result<StateConfig> {
+                Ok(StateConfig {
+                    relayer: relayer_config()?,
+                    ..StateConfig::generate_state_config(db.clone())?
+                }
+                .filter(&filter))
+            };
+            let local_directory = || {
+                output_directory.local_directory().ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Only the partitioned format can be exported to {output_directory}"
+                    )
+                })
+            };
+
+            match format {
+                ExportFormat::Json => {
+                    let output_directory = local_directory()?;
+                    let state_conf = state_conf()?;
+                    std::fs::create_dir_all(output_directory)?;
+                    let file = std::fs::File::create(
+                        output_directory.join("state_config.json"),
+                    )?;
+                    serde_json::to_writer_pretty(
+                        std::io::BufWriter::new(file),
+                        &state_conf,
+                    )
+                    .context("failed to dump state to JSON")?;
+                }
+                ExportFormat::Csv => csv::write_all(&state_conf()?, local_directory()?)?,
+                ExportFormat::Binary => {
+                    let output_directory = local_directory()?;
+                    let state_conf = state_conf()?;
+                    std::fs::create_dir_all(output_directory)?;
+                    std::fs::write(
+                        output_directory.join("state_config.bin"),
+                        state_conf.to_binary()?,
+                    )
+                    .context("failed to dump state to the binary format")?;
+                }
+                ExportFormat::Partitioned => {
+                    let limits = PartitionLimits {
+                        max_rows: max_rows_per_file.try_into()?,
+                        max_bytes: max_bytes_per_file.try_into()?,
+                    };
+                    let manifest = export_partitioned(
+                        &db,
+                        relayer_config()?,
+                        &filter,
+                        output_directory.writer()?,
+                        limits,
+                    )?;
+                    for table in manifest.tables {
+                        tracing::info!(
+                            "Exported the table {} into {} files",
+                            table.name,
+                            table.files.len()
+                        );
+                    }
+                }
+            }
+        }
         SubCommands::Contract { contract_id } => {
             let config = db.get_contract_config_by_id(contract_id)?;
             let stdout = std::io::stdout().lock();
@@ -93,3 +266,103 @@ pub async fn exec(command: Command) -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+/// Streams the state from the `db` into the partitioned snapshot in the `storage`,
+/// so the state slots and the balances of the contracts are never held in memory.
+#[cfg(any(feature = "rocksdb", feature = "rocksdb-production"))]
+fn export_partitioned(
+    db: &fuel_core::database::Database,
+    relayer: Option<fuel_core::chain_config::RelayerConfig>,
+    filter: &StateFilter,
+    storage: std::sync::Arc<dyn fuel_core::chain_config::SnapshotStorage>,
+    limits: fuel_core::chain_config::PartitionLimits,
+) -> anyhow::Result<fuel_core::chain_config::SnapshotManifest> {
+    use fuel_core::chain_config::{
+        ChainConfigDb,
+        PartitionedSnapshotWriter,
+        StateConfig,
+    };
+
+    let state = StateConfig {
+        coins: ChainConfigDb::get_coin_config(db)?,
+        // The contracts are streamed below.
+        contracts: None,
+        messages: ChainConfigDb::get_message_config(db)?,
+        spent_messages: ChainConfigDb::get_spent_message_config(db)?,
+        da_block_height: Some(ChainConfigDb::get_da_block_height(db)?),
+        height: Some(ChainConfigDb::get_block_height(db)?),
+        timestamp: Some(ChainConfigDb::get_block_time(db)?),
+        relayer,
+    }
+    .filter(filter);
+
+    let mut writer = PartitionedSnapshotWriter::with_storage(storage, limits)?;
+    if let Some(coins) = &state.coins {
+        writer.write_coins(coins.iter().cloned().map(Ok))?;
+    }
+    let contracts = db
+        .contract_configs_without_slots()
+        .filter(|contract| {
+            contract
+                .as_ref()
+                .map_or(true, |contract| filter.contract_id(&contract.contract_id))
+        })
+        .map(|contract| contract.map_err(Into::into));
+    writer.write_contracts(contracts)?;
+    let rows = db
+        .contract_state_rows()
+        .filter(|row| {
+            row.as_ref()
+                .map_or(true, |row| filter.contract_id(&row.contract_id))
+        })
+        .map(|row| row.map_err(Into::into));
+    writer.write_contract_state(rows)?;
+    let rows = db
+        .contract_balance_rows()
+        .filter(|row| {
+            row.as_ref().map_or(true, |row| {
+                filter.contract_id(&row.contract_id) && filter.asset_id(&row.asset_id)
+            })
+        })
+        .map(|row| row.map_err(Into::into));
+    writer.write_contract_balances(rows)?;
+    if let Some(messages) = &state.messages {
+        writer.write_messages(messages.iter().cloned().map(Ok))?;
+    }
+    writer.finish(&state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn everything_parses_repeated_filters() {
+        let owner = Address::from([1; 32]);
+        let asset_id = AssetId::from([2; 32]);
+        let command = Command::try_parse_from([
+            "snapshot".to_string(),
+            "everything".to_string(),
+            "--owner".to_string(),
+            owner.to_string(),
+            "--owner".to_string(),
+            Address::zeroed().to_string(),
+            "--asset-id".to_string(),
+            asset_id.to_string(),
+        ])
+        .unwrap();
+
+        let SubCommands::Everything { filter, .. } = command.subcommand else {
+            panic!("Expected the `everything` sub-command")
+        };
+        let filter: StateFilter = filter.into();
+        assert_eq!(
+            filter,
+            StateFilter {
+                owners: vec![owner, Address::zeroed()],
+                asset_ids: vec![asset_id],
+                contract_ids: vec![],
+            }
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Flat CSV tables of the state for the analytics. The contract code and
+//! the contract state are not exported. The hashes are `0x`-prefixed hex
+//! strings, the amounts and the heights are decimal numbers, and the unknown
+//! optional values are empty cells.
+
+use anyhow::Context;
+use fuel_core::chain_config::StateConfig;
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{
+        BufWriter,
+        Write,
+    },
+    path::Path,
+};
+
+pub const COINS_FILE: &str = "coins.csv";
+pub const MESSAGES_FILE: &str = "messages.csv";
+pub const CONTRACT_BALANCES_FILE: &str = "contract_balances.csv";
+
+/// Writes the [`COINS_FILE`], [`MESSAGES_FILE`] and [`CONTRACT_BALANCES_FILE`]
+/// into the `directory`, overwriting the existing files.
+pub fn write_all(state: &StateConfig, directory: &Path) -> anyhow::Result<()> {
+    std::fs::create_dir_all(directory)
+        .with_context(|| format!("failed to create directory {}", directory.display()))?;
+
+    let create = |name: &str| {
+        let path = directory.join(name);
+        File::create(&path)
+            .map(BufWriter::new)
+            .with_context(|| format!("failed to create {}", path.display()))
+    };
+    write_coins(state, create(COINS_FILE)?)?;
+    write_messages(state, create(MESSAGES_FILE)?)?;
+    write_contract_balances(state, create(CONTRACT_BALANCES_FILE)?)?;
+    Ok(())
+}
+
+pub fn write_coins<W: Write>(state: &StateConfig, mut writer: W) -> anyhow::Result<()> {
+    writeln!(
+        writer,
+        "tx_id,output_index,tx_pointer_block_height,tx_pointer_tx_idx,owner,asset_id,amount"
+    )?;
+    for coin in state.coins.iter().flatten() {
+        writeln!(
+            writer,
+            "{},{},{},{},{:#x},{:#x},{}",
+            optional(coin.tx_id.map(|tx_id| format!("{tx_id:#x}"))),
+            optional(coin.output_index),
+            optional(coin.tx_pointer_block_height.map(u32::from)),
+            optional(coin.tx_pointer_tx_idx),
+            coin.owner,
+            coin.asset_id,
+            coin.amount,
+        )?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn write_messages<W: Write>(
+    state: &StateConfig,
+    mut writer: W,
+) -> anyhow::Result<()> {
+    writeln!(writer, "sender,recipient,nonce,amount,data,da_height")?;
+    for message in state.messages.iter().flatten() {
+        writeln!(
+            writer,
+            "{:#x},{:#x},{:#x},{},0x{},{}",
+            message.sender,
+            message.recipient,
+            message.nonce,
+            message.amount,
+            hex::encode(&message.data),
+            *message.da_height,
+        )?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn write_contract_balances<W: Write>(
+    state: &StateConfig,
+    mut writer: W,
+) -> anyhow::Result<()> {
+    writeln!(writer, "contract_id,asset_id,amount")?;
+    for contract in state.contracts.iter().flatten() {
+        for (asset_id, amount) in contract.balances.iter().flatten() {
+            writeln!(
+                writer,
+                "{:#x},{:#x},{}",
+                contract.contract_id, asset_id, amount
+            )?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+fn optional<T: Display>(value: Option<T>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core::chain_config::{
+        CoinConfig,
+        ContractConfig,
+        MessageConfig,
+    };
+
+    fn state() -> StateConfig {
+        StateConfig {
+            coins: Some(vec![
+                CoinConfig {
+                    tx_id: Some([1; 32].into()),
+                    output_index: Some(2),
+                    tx_pointer_block_height: Some(3u32.into()),
+                    tx_pointer_tx_idx: Some(4),
+                    owner: [5; 32].into(),
+                    amount: 6,
+                    asset_id: [7; 32].into(),
+                },
+                CoinConfig {
+                    owner: [8; 32].into(),
+                    amount: 9,
+                    ..Default::default()
+                },
+            ]),
+            contracts: Some(vec![ContractConfig {
+                contract_id: [10; 32].into(),
+                code: vec![11; 4],
+                state: Some(vec![([12; 32].into(), vec![13])]),
+                balances: Some(vec![([14; 32].into(), 15), ([16; 32].into(), 17)]),
+                ..Default::default()
+            }]),
+            messages: Some(vec![MessageConfig {
+                sender: [18; 32].into(),
+                recipient: [19; 32].into(),
+                nonce: [20; 32].into(),
+                amount: 21,
+                data: vec![0xab, 0xcd],
+                da_height: 22u64.into(),
+            }]),
+            ..Default::default()
+        }
+    }
+
+    fn to_string(write: impl FnOnce(&mut Vec<u8>) -> anyhow::Result<()>) -> String {
+        let mut buffer = vec![];
+        write(&mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    fn hex(byte: u8) -> String {
+        format!("0x{}", hex::encode([byte; 32]))
+    }
+
+    #[test]
+    fn coins_are_flattened_with_empty_optional_values() {
+        let csv = to_string(|buffer| write_coins(&state(), buffer));
+
+        let expected = format!(
+            "tx_id,output_index,tx_pointer_block_height,tx_pointer_tx_idx,owner,asset_id,amount\n\
+             {},2,3,4,{},{},6\n\
+             ,,,,{},{},9\n",
+            hex(1),
+            hex(5),
+            hex(7),
+            hex(8),
+            hex(0),
+        );
+        assert_eq!(csv, expected);
+    }
+
+    #[test]
+    fn messages_are_flattened() {
+        let csv = to_string(|buffer| write_messages(&state(), buffer));
+
+        let expected = format!(
+            "sender,recipient,nonce,amount,data,da_height\n{},{},{},21,0xabcd,22\n",
+            hex(18),
+            hex(19),
+            hex(20),
+        );
+        assert_eq!(csv, expected);
+    }
+
+    #[test]
+    fn contract_balances_have_a_row_per_asset_without_code_and_state() {
+        let csv = to_string(|buffer| write_contract_balances(&state(), buffer));
+
+        let expected = format!(
+            "contract_id,asset_id,amount\n{},{},15\n{},{},17\n",
+            hex(10),
+            hex(14),
+            hex(10),
+            hex(16),
+        );
+        assert_eq!(csv, expected);
+    }
+
+    #[test]
+    fn write_all_creates_the_files_in_the_directory() {
+        let directory =
+            std::env::temp_dir().join(format!("fuel-core-csv-{}", std::process::id()));
+
+        write_all(&state(), &directory).unwrap();
+
+        for (name, rows) in [
+            (COINS_FILE, 3),
+            (MESSAGES_FILE, 2),
+            (CONTRACT_BALANCES_FILE, 3),
+        ] {
+            let content = std::fs::read_to_string(directory.join(name)).unwrap();
+            assert_eq!(content.lines().count(), rows, "{name}");
+        }
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn empty_state_has_only_headers() {
+        let csv =
+            to_string(|buffer| write_contract_balances(&Default::default(), buffer));
+
+        assert_eq!(csv, "contract_id,asset_id,amount\n");
+    }
+}
@@ -11,7 +11,11 @@ use fuel_core::service::FuelService;
 
 mod cli;
 
-#[tokio::main]
-async fn main() -> anyhow::Result<()> {
-    cli::run_cli().await
+fn main() -> anyhow::Result<()> {
+    let mut runtime = tokio::runtime::Builder::new_multi_thread();
+    runtime.enable_all();
+    if let Some(threads) = cli::async_threads() {
+        runtime.worker_threads(threads.get());
+    }
+    runtime.build()?.block_on(cli::run_cli())
 }
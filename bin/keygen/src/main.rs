@@ -3,15 +3,25 @@ use atty::Stream;
 use clap::Parser;
 use crossterm::terminal;
 use fuel_core_keygen::{
+    convert_address,
+    keystore::{
+        read_new_passphrase,
+        Keystore,
+    },
     new_key,
     parse_secret,
+    read_keystore,
+    sign_digest,
     KeyType,
 };
-use std::io::{
-    stdin,
-    stdout,
-    Read,
-    Write,
+use std::{
+    io::{
+        stdin,
+        stdout,
+        Read,
+        Write,
+    },
+    path::PathBuf,
 };
 use termion::screen::IntoAlternateScreen;
 
@@ -49,12 +59,78 @@ pub struct NewKey {
     pub key_type: KeyType,
 }
 
+/// Convert the address between the hex and the bech32 encodings
+#[derive(Debug, clap::Args)]
+pub struct ConvertAddress {
+    /// An address in hex or bech32 format
+    address: String,
+    /// Print the JSON in pretty format
+    #[clap(long = "pretty", short = 'p')]
+    pub pretty: bool,
+}
+
+/// Sign a 32-byte digest with the block production key from the keystore file
+#[derive(Debug, clap::Args)]
+pub struct SignDigest {
+    /// A digest in hex format
+    digest: String,
+    /// The file with the output of the `new` command, the private key in hex format,
+    /// or the encrypted keystore
+    #[clap(long = "keystore", short = 'k')]
+    keystore: PathBuf,
+    /// The file with the passphrase of the encrypted keystore.
+    /// The passphrase is prompted if not set.
+    #[clap(long = "passphrase-file")]
+    passphrase_file: Option<PathBuf>,
+    /// Print the JSON in pretty format
+    #[clap(long = "pretty", short = 'p')]
+    pub pretty: bool,
+}
+
+/// Encrypt the block production key with a passphrase into the keystore file
+#[derive(Debug, clap::Args)]
+pub struct EncryptKey {
+    /// The file with the output of the `new` command or with the private key in hex format
+    secret_file: PathBuf,
+    /// The path of the encrypted keystore to create
+    #[clap(long = "output", short = 'o')]
+    output: PathBuf,
+    /// The file with the passphrase. The passphrase is prompted if not set.
+    #[clap(long = "passphrase-file")]
+    passphrase_file: Option<PathBuf>,
+    /// Print the JSON in pretty format
+    #[clap(long = "pretty", short = 'p')]
+    pub pretty: bool,
+}
+
+impl EncryptKey {
+    fn exec(&self) -> anyhow::Result<serde_json::Value> {
+        let secret = read_keystore(&self.secret_file, None)?;
+        anyhow::ensure!(
+            !self.output.exists(),
+            "the keystore {} already exists",
+            self.output.display()
+        );
+        let passphrase = read_new_passphrase(self.passphrase_file.as_deref())?;
+
+        let keystore = Keystore::encrypt(&secret, &passphrase)?;
+        keystore.save(&self.output)?;
+        Ok(serde_json::json!({
+            "address": keystore.address,
+            "keystore": self.output,
+        }))
+    }
+}
+
 /// Key management utilities for configuring fuel-core
 #[derive(Debug, Parser)]
 #[clap(name = "fuel-core-keygen", author, version, about)]
 pub(crate) enum Command {
     New(NewKey),
     Parse(ParseSecret),
+    Address(ConvertAddress),
+    Sign(SignDigest),
+    Encrypt(EncryptKey),
 }
 
 impl Command {
@@ -67,14 +143,38 @@ impl Command {
                 serde_json::to_value(parse_secret(cmd.key_type, &cmd.secret)?)?,
                 cmd.pretty,
             )),
+            Command::Address(cmd) => Ok((
+                serde_json::to_value(convert_address(&cmd.address)?)?,
+                cmd.pretty,
+            )),
+            Command::Sign(cmd) => {
+                let secret =
+                    read_keystore(&cmd.keystore, cmd.passphrase_file.as_deref())?;
+                Ok((
+                    serde_json::to_value(sign_digest(&secret, &cmd.digest)?)?,
+                    cmd.pretty,
+                ))
+            }
+            Command::Encrypt(cmd) => Ok((cmd.exec()?, cmd.pretty)),
         }
     }
+
+    /// Returns `true` if the command gets the private key as an argument
+    /// or prints it, so the output is shown discreetly.
+    fn is_secret(&self) -> bool {
+        matches!(self, Command::New(_) | Command::Parse(_))
+    }
 }
 
 fn main() -> anyhow::Result<()> {
     let cmd = Command::parse();
     let (result, is_pretty) = cmd.exec()?;
-    print_value(result, is_pretty)
+    if cmd.is_secret() {
+        print_value(result, is_pretty)
+    } else {
+        println!("{}", to_string(&result, is_pretty)?);
+        Ok(())
+    }
 }
 
 fn wait_for_keypress() {
@@ -100,13 +200,17 @@ fn display_string_discreetly(
     Ok(())
 }
 
-fn print_value(output: serde_json::Value, pretty: bool) -> anyhow::Result<()> {
-    let output = if pretty {
-        serde_json::to_string_pretty(&output)
+fn to_string(output: &serde_json::Value, pretty: bool) -> anyhow::Result<String> {
+    if pretty {
+        serde_json::to_string_pretty(output)
     } else {
-        serde_json::to_string(&output)
+        serde_json::to_string(output)
     }
-    .map_err(anyhow::Error::msg);
+    .map_err(anyhow::Error::msg)
+}
+
+fn print_value(output: serde_json::Value, pretty: bool) -> anyhow::Result<()> {
+    let output = to_string(&output, pretty);
 
     let _ = display_string_discreetly(
         &output?,
@@ -1,15 +1,35 @@
+mod binary;
+mod blob;
+mod builder;
 mod chain;
 mod coin;
 mod consensus;
 mod contract;
+mod fee_free;
+mod filter;
 mod message;
+#[cfg(feature = "std")]
+mod migration;
+#[cfg(feature = "std")]
+mod partitioned;
+mod relayer;
 mod state;
 
+pub use binary::*;
+pub use blob::*;
+pub use builder::*;
 pub use chain::*;
 pub use coin::*;
 pub use consensus::*;
 pub use contract::*;
+pub use fee_free::*;
+pub use filter::*;
 pub use message::*;
+#[cfg(feature = "std")]
+pub use migration::*;
+#[cfg(feature = "std")]
+pub use partitioned::*;
+pub use relayer::*;
 pub use state::*;
 
 #[cfg(test)]
@@ -26,6 +46,7 @@ mod tests {
             Bytes32,
         },
         fuel_vm::Contract,
+        tai64::Tai64,
     };
     use rand::{
         prelude::StdRng,
@@ -41,10 +62,15 @@ mod tests {
     };
 
     use super::{
+        binary::STATE_BINARY_MAGIC,
         chain::ChainConfig,
         coin::CoinConfig,
         contract::ContractConfig,
         message::MessageConfig,
+        relayer::{
+            MessageOriginConfig,
+            RelayerConfig,
+        },
         state::StateConfig,
     };
 
@@ -109,6 +135,24 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn can_roundtrip_serialize_genesis_time_and_da_height_config() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let config = ChainConfig {
+            initial_state: Some(StateConfig {
+                height: Some(rng.next_u32().into()),
+                da_block_height: Some(DaBlockHeight(rng.gen())),
+                timestamp: Some(Tai64(rng.gen())),
+                ..Default::default()
+            }),
+            ..ChainConfig::local_testnet()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized_config: ChainConfig =
+            serde_json::from_str(json.as_str()).unwrap();
+        assert_eq!(config, deserialized_config);
+    }
+
     #[test]
     fn snapshot_simple_contract() {
         let config = test_config_contract(false, false, false, false);
@@ -221,6 +265,99 @@ mod tests {
         assert_eq!(config, deserialized_config);
     }
 
+    #[test]
+    fn can_roundtrip_binary_state() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let state = StateConfig {
+            coins: test_config_coin_state().initial_state.unwrap().coins,
+            contracts: test_config_contract(true, true, true, true)
+                .initial_state
+                .unwrap()
+                .contracts,
+            messages: test_message_config().initial_state.unwrap().messages,
+            spent_messages: Some(vec![rng.gen()]),
+            da_block_height: Some(DaBlockHeight(rng.gen())),
+            height: Some(rng.next_u32().into()),
+            timestamp: Some(Tai64(rng.gen())),
+            relayer: Some(test_relayer_config(&mut rng)),
+        };
+
+        let bytes = state.to_binary().unwrap();
+        let decoded = StateConfig::from_binary_slice(&bytes).unwrap();
+        assert_eq!(state, decoded);
+    }
+
+    #[test]
+    fn can_roundtrip_binary_state_with_missing_fields() {
+        let state = StateConfig {
+            contracts: test_config_contract(false, false, false, false)
+                .initial_state
+                .unwrap()
+                .contracts,
+            ..Default::default()
+        };
+
+        let bytes = state.to_binary().unwrap();
+        let decoded = StateConfig::from_binary_slice(&bytes).unwrap();
+        assert_eq!(state, decoded);
+    }
+
+    #[test]
+    fn can_roundtrip_relayer_state() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let config = ChainConfig {
+            initial_state: Some(StateConfig {
+                relayer: Some(test_relayer_config(&mut rng)),
+                ..Default::default()
+            }),
+            ..ChainConfig::local_testnet()
+        };
+        let json = serde_json::to_string(&config).unwrap();
+        let deserialized_config: ChainConfig =
+            serde_json::from_str(json.as_str()).unwrap();
+        assert_eq!(config, deserialized_config);
+    }
+
+    #[test]
+    fn binary_state_rejects_unknown_version() {
+        let mut bytes = STATE_BINARY_MAGIC.to_vec();
+        bytes.push(2);
+
+        let err = StateConfig::from_binary_slice(&bytes).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("unsupported binary state version 2"));
+    }
+
+    #[test]
+    fn binary_state_rejects_json() {
+        let json = serde_json::to_vec(&StateConfig::default()).unwrap();
+
+        assert!(StateConfig::from_binary_slice(&json).is_err());
+    }
+
+    fn test_relayer_config(rng: &mut StdRng) -> RelayerConfig {
+        let da_height = DaBlockHeight(rng.gen());
+        let message = MessageConfig {
+            sender: rng.gen(),
+            recipient: rng.gen(),
+            nonce: rng.gen(),
+            amount: rng.gen(),
+            data: vec![rng.gen()],
+            da_height,
+        };
+        let origin = MessageOriginConfig {
+            nonce: message.nonce,
+            da_height,
+            transaction_hash: rng.gen(),
+        };
+        RelayerConfig {
+            da_block_height: da_height,
+            messages: vec![message],
+            message_origins: vec![origin],
+        }
+    }
+
     fn test_config_contract(
         state: bool,
         balances: bool,
@@ -0,0 +1,365 @@
+//! The compact binary encoding of the [`StateConfig`].
+//!
+//! The JSON format of the state encodes each value as a hex string and skips
+//! the missing optional fields, so it can't be decoded by non-self-describing
+//! formats. The binary format mirrors the state with the native types and encodes
+//! it with `postcard` after the [`STATE_BINARY_MAGIC`] and the [`STATE_BINARY_VERSION`].
+//! The optional relayer state is appended after the state, so the states
+//! exported without it are still decoded by the same version.
+
+use super::{
+    coin::CoinConfig,
+    contract::ContractConfig,
+    message::MessageConfig,
+    relayer::{
+        MessageOriginConfig,
+        RelayerConfig,
+    },
+    state::StateConfig,
+};
+use anyhow::{
+    anyhow,
+    ensure,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        ContractId,
+        Nonce,
+        Salt,
+    },
+    tai64::Tai64,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// The prefix of the state encoded in the binary format.
+pub const STATE_BINARY_MAGIC: [u8; 8] = *b"FUELSTAT";
+
+/// The version of the binary format of the state.
+pub const STATE_BINARY_VERSION: u32 = 1;
+
+impl StateConfig {
+    /// Encodes the state into the binary format.
+    pub fn to_binary(&self) -> anyhow::Result<Vec<u8>> {
+        let state = BinaryState::from(self);
+        let bytes = postcard::to_extend(
+            &(STATE_BINARY_VERSION, state),
+            STATE_BINARY_MAGIC.to_vec(),
+        )
+        .map_err(|e| anyhow!("failed to encode the state: {e}"))?;
+        match &self.relayer {
+            Some(relayer) => {
+                postcard::to_extend(&BinaryRelayer::from(relayer.clone()), bytes)
+                    .map_err(|e| anyhow!("failed to encode the relayer state: {e}"))
+            }
+            None => Ok(bytes),
+        }
+    }
+
+    /// Decodes the state from the binary format.
+    pub fn from_binary_slice(bytes: &[u8]) -> anyhow::Result<Self> {
+        let bytes = bytes
+            .strip_prefix(STATE_BINARY_MAGIC.as_slice())
+            .ok_or_else(|| anyhow!("the state is not in the binary format"))?;
+        let (version, bytes): (u32, _) = postcard::take_from_bytes(bytes)
+            .map_err(|e| anyhow!("failed to decode the version of the state: {e}"))?;
+        ensure!(
+            version == STATE_BINARY_VERSION,
+            "unsupported binary state version {version}, expected {STATE_BINARY_VERSION}"
+        );
+        let (state, bytes): (BinaryState, _) = postcard::take_from_bytes(bytes)
+            .map_err(|e| anyhow!("failed to decode the state: {e}"))?;
+        let mut state = StateConfig::from(state);
+        if !bytes.is_empty() {
+            let relayer: BinaryRelayer = postcard::from_bytes(bytes)
+                .map_err(|e| anyhow!("failed to decode the relayer state: {e}"))?;
+            state.relayer = Some(relayer.into());
+        }
+        Ok(state)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct BinaryState {
+    coins: Option<Vec<BinaryCoin>>,
+    contracts: Option<Vec<BinaryContract>>,
+    messages: Option<Vec<BinaryMessage>>,
+    spent_messages: Option<Vec<Nonce>>,
+    da_block_height: Option<DaBlockHeight>,
+    height: Option<BlockHeight>,
+    timestamp: Option<Tai64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct BinaryRelayer {
+    da_block_height: DaBlockHeight,
+    messages: Vec<BinaryMessage>,
+    message_origins: Vec<(Nonce, DaBlockHeight, Bytes32)>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct BinaryCoin {
+    tx_id: Option<Bytes32>,
+    output_index: Option<u8>,
+    tx_pointer_block_height: Option<BlockHeight>,
+    tx_pointer_tx_idx: Option<u16>,
+    owner: Address,
+    amount: u64,
+    asset_id: AssetId,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct BinaryContract {
+    contract_id: ContractId,
+    code: Vec<u8>,
+    salt: Salt,
+    state: Option<Vec<(Bytes32, Vec<u8>)>>,
+    balances: Option<Vec<(AssetId, u64)>>,
+    tx_id: Option<Bytes32>,
+    output_index: Option<u8>,
+    tx_pointer_block_height: Option<BlockHeight>,
+    tx_pointer_tx_idx: Option<u16>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub(super) struct BinaryMessage {
+    sender: Address,
+    recipient: Address,
+    nonce: Nonce,
+    amount: u64,
+    data: Vec<u8>,
+    da_height: DaBlockHeight,
+}
+
+impl From<&StateConfig> for BinaryState {
+    fn from(state: &StateConfig) -> Self {
+        Self {
+            coins: state
+                .coins
+                .as_ref()
+                .map(|coins| coins.iter().cloned().map(Into::into).collect()),
+            contracts: state
+                .contracts
+                .as_ref()
+                .map(|contracts| contracts.iter().cloned().map(Into::into).collect()),
+            messages: state
+                .messages
+                .as_ref()
+                .map(|messages| messages.iter().cloned().map(Into::into).collect()),
+            spent_messages: state.spent_messages.clone(),
+            da_block_height: state.da_block_height,
+            height: state.height,
+            timestamp: state.timestamp,
+        }
+    }
+}
+
+impl From<BinaryState> for StateConfig {
+    fn from(state: BinaryState) -> Self {
+        Self {
+            coins: state
+                .coins
+                .map(|coins| coins.into_iter().map(Into::into).collect()),
+            contracts: state
+                .contracts
+                .map(|contracts| contracts.into_iter().map(Into::into).collect()),
+            messages: state
+                .messages
+                .map(|messages| messages.into_iter().map(Into::into).collect()),
+            spent_messages: state.spent_messages,
+            da_block_height: state.da_block_height,
+            height: state.height,
+            timestamp: state.timestamp,
+            relayer: None,
+        }
+    }
+}
+
+impl From<RelayerConfig> for BinaryRelayer {
+    fn from(relayer: RelayerConfig) -> Self {
+        let RelayerConfig {
+            da_block_height,
+            messages,
+            message_origins,
+        } = relayer;
+        Self {
+            da_block_height,
+            messages: messages.into_iter().map(Into::into).collect(),
+            message_origins: message_origins
+                .into_iter()
+                .map(|origin| (origin.nonce, origin.da_height, origin.transaction_hash))
+                .collect(),
+        }
+    }
+}
+
+impl From<BinaryRelayer> for RelayerConfig {
+    fn from(relayer: BinaryRelayer) -> Self {
+        let BinaryRelayer {
+            da_block_height,
+            messages,
+            message_origins,
+        } = relayer;
+        Self {
+            da_block_height,
+            messages: messages.into_iter().map(Into::into).collect(),
+            message_origins: message_origins
+                .into_iter()
+                .map(|(nonce, da_height, transaction_hash)| MessageOriginConfig {
+                    nonce,
+                    da_height,
+                    transaction_hash,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<CoinConfig> for BinaryCoin {
+    fn from(coin: CoinConfig) -> Self {
+        let CoinConfig {
+            tx_id,
+            output_index,
+            tx_pointer_block_height,
+            tx_pointer_tx_idx,
+            owner,
+            amount,
+            asset_id,
+        } = coin;
+        Self {
+            tx_id,
+            output_index,
+            tx_pointer_block_height,
+            tx_pointer_tx_idx,
+            owner,
+            amount,
+            asset_id,
+        }
+    }
+}
+
+impl From<BinaryCoin> for CoinConfig {
+    fn from(coin: BinaryCoin) -> Self {
+        let BinaryCoin {
+            tx_id,
+            output_index,
+            tx_pointer_block_height,
+            tx_pointer_tx_idx,
+            owner,
+            amount,
+            asset_id,
+        } = coin;
+        Self {
+            tx_id,
+            output_index,
+            tx_pointer_block_height,
+            tx_pointer_tx_idx,
+            owner,
+            amount,
+            asset_id,
+        }
+    }
+}
+
+impl From<ContractConfig> for BinaryContract {
+    fn from(contract: ContractConfig) -> Self {
+        let ContractConfig {
+            contract_id,
+            code,
+            salt,
+            state,
+            balances,
+            tx_id,
+            output_index,
+            tx_pointer_block_height,
+            tx_pointer_tx_idx,
+        } = contract;
+        Self {
+            contract_id,
+            code,
+            salt,
+            state,
+            balances,
+            tx_id,
+            output_index,
+            tx_pointer_block_height,
+            tx_pointer_tx_idx,
+        }
+    }
+}
+
+impl From<BinaryContract> for ContractConfig {
+    fn from(contract: BinaryContract) -> Self {
+        let BinaryContract {
+            contract_id,
+            code,
+            salt,
+            state,
+            balances,
+            tx_id,
+            output_index,
+            tx_pointer_block_height,
+            tx_pointer_tx_idx,
+        } = contract;
+        Self {
+            contract_id,
+            code,
+            salt,
+            state,
+            balances,
+            tx_id,
+            output_index,
+            tx_pointer_block_height,
+            tx_pointer_tx_idx,
+        }
+    }
+}
+
+impl From<MessageConfig> for BinaryMessage {
+    fn from(message: MessageConfig) -> Self {
+        let MessageConfig {
+            sender,
+            recipient,
+            nonce,
+            amount,
+            data,
+            da_height,
+        } = message;
+        Self {
+            sender,
+            recipient,
+            nonce,
+            amount,
+            data,
+            da_height,
+        }
+    }
+}
+
+impl From<BinaryMessage> for MessageConfig {
+    fn from(message: BinaryMessage) -> Self {
+        let BinaryMessage {
+            sender,
+            recipient,
+            nonce,
+            amount,
+            data,
+            da_height,
+        } = message;
+        Self {
+            sender,
+            recipient,
+            nonce,
+            amount,
+            data,
+            da_height,
+        }
+    }
+}
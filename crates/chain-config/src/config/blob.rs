@@ -0,0 +1,60 @@
+use fuel_core_types::{
+    blockchain::rules::LimitError,
+    fuel_asm::Word,
+    fuel_tx::{
+        field::{
+            BytecodeWitnessIndex,
+            Witnesses,
+        },
+        Create,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// The pricing of the transactions carrying the large payloads.
+///
+/// The payload of the `Create` transaction is the bytecode of the deployed contract.
+/// The transaction pays at least `byte_price` per byte of the payload with the tip,
+/// and the payloads of all transactions of one block are at most `max_bytes_per_block`,
+/// so the deployments don't crowd out the regular transactions.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct BlobPricing {
+    pub byte_price: Word,
+    pub max_bytes_per_block: u64,
+}
+
+impl BlobPricing {
+    /// Returns the size of the payload carried by the transaction.
+    pub fn payload_size(tx: &Create) -> u64 {
+        tx.witnesses()
+            .get(*tx.bytecode_witness_index() as usize)
+            .map(|witness| witness.as_vec().len() as u64)
+            .unwrap_or_default()
+    }
+
+    /// Returns the minimal tip of the transaction with the payload of the `payload_size`.
+    pub fn min_tip(&self, payload_size: u64) -> Word {
+        payload_size.saturating_mul(self.byte_price)
+    }
+
+    /// Checks that the `tip` pays for the payload of the `payload_size`.
+    pub fn check_fee(&self, payload_size: u64, tip: Word) -> Result<(), LimitError> {
+        let required = self.min_tip(payload_size);
+        if tip < required {
+            return Err(LimitError::BlobFeeTooLow { tip, required })
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the payload of the `payload_size` fits into the budget
+    /// of the block, where the previous transactions used `used_bytes`.
+    pub fn fits_budget(&self, used_bytes: u64, payload_size: u64) -> bool {
+        used_bytes
+            .checked_add(payload_size)
+            .map(|bytes| bytes <= self.max_bytes_per_block)
+            .unwrap_or(false)
+    }
+}
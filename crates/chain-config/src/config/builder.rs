@@ -0,0 +1,258 @@
+use super::{
+    blob::BlobPricing,
+    chain::ChainConfig,
+    coin::CoinConfig,
+    consensus::ConsensusConfig,
+    contract::ContractConfig,
+    fee_free::FeeFreeAllowance,
+    message::MessageConfig,
+    state::StateConfig,
+};
+use fuel_core_types::{
+    fuel_tx::ConsensusParameters,
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        Bytes32,
+        Salt,
+    },
+};
+
+/// The builder of the [`ChainConfig`] with its initial state.
+///
+/// ```
+/// # use fuel_core_chain_config::ChainConfigBuilder;
+/// let config = ChainConfigBuilder::local_testnet()
+///     .with_coin(Default::default(), 1_000, Default::default())
+///     .with_contract(vec![])
+///     .with_state(Default::default(), vec![0; 32])
+///     .with_balance(Default::default(), 100)
+///     .with_consensus_param(|params| params.tx_params.max_inputs = 10)
+///     .build();
+/// assert_eq!(config.consensus_parameters.tx_params.max_inputs, 10);
+/// ```
+#[derive(Default, Clone, Debug)]
+pub struct ChainConfigBuilder {
+    config: ChainConfig,
+    state: StateConfig,
+    /// The indexes of the contracts added with the [`Self::with_contract`],
+    /// their ids are calculated by the [`Self::build`].
+    generated_ids: Vec<usize>,
+}
+
+impl ChainConfigBuilder {
+    /// Starts from the [`ChainConfig::default`] without the initial state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Starts from the [`ChainConfig::local_testnet`] with its pre-funded accounts.
+    pub fn local_testnet() -> Self {
+        Self::from_config(ChainConfig::local_testnet())
+    }
+
+    /// Starts from the `config`, keeping its initial state.
+    pub fn from_config(mut config: ChainConfig) -> Self {
+        let state = config.initial_state.take().unwrap_or_default();
+        Self {
+            config,
+            state,
+            generated_ids: vec![],
+        }
+    }
+
+    pub fn with_chain_name(mut self, chain_name: impl Into<String>) -> Self {
+        self.config.chain_name = chain_name.into();
+        self
+    }
+
+    pub fn with_block_gas_limit(mut self, block_gas_limit: u64) -> Self {
+        self.config.block_gas_limit = block_gas_limit;
+        self
+    }
+
+    pub fn with_fee_free_allowance(mut self, allowance: FeeFreeAllowance) -> Self {
+        self.config.fee_free_allowance = Some(allowance);
+        self
+    }
+
+    pub fn with_blob_pricing(mut self, pricing: BlobPricing) -> Self {
+        self.config.blob_pricing = Some(pricing);
+        self
+    }
+
+    pub fn with_consensus(mut self, consensus: ConsensusConfig) -> Self {
+        self.config.consensus = consensus;
+        self
+    }
+
+    pub fn with_consensus_parameters(mut self, params: ConsensusParameters) -> Self {
+        self.config.consensus_parameters = params;
+        self
+    }
+
+    /// Modifies the consensus parameters in place, for example:
+    /// `.with_consensus_param(|params| params.tx_params.max_gas_per_tx = 1_000)`.
+    pub fn with_consensus_param(
+        mut self,
+        update: impl FnOnce(&mut ConsensusParameters),
+    ) -> Self {
+        update(&mut self.config.consensus_parameters);
+        self
+    }
+
+    /// Sets the height of the genesis block.
+    pub fn with_height(mut self, height: BlockHeight) -> Self {
+        self.state.height = Some(height);
+        self
+    }
+
+    /// Adds the coin with the generated `UtxoId`.
+    pub fn with_coin(self, owner: Address, amount: u64, asset_id: AssetId) -> Self {
+        self.with_coin_config(CoinConfig {
+            owner,
+            amount,
+            asset_id,
+            ..Default::default()
+        })
+    }
+
+    pub fn with_coin_config(mut self, coin: CoinConfig) -> Self {
+        self.state.coins.get_or_insert_with(Vec::new).push(coin);
+        self
+    }
+
+    pub fn with_message(mut self, message: MessageConfig) -> Self {
+        self.state
+            .messages
+            .get_or_insert_with(Vec::new)
+            .push(message);
+        self
+    }
+
+    /// Adds the contract with the `code` and the default salt. The following
+    /// [`Self::with_state`], [`Self::with_balance`] and [`Self::with_salt`]
+    /// apply to this contract. The contract id is calculated by the [`Self::build`].
+    pub fn with_contract(mut self, code: Vec<u8>) -> Self {
+        let contracts = self.state.contracts.get_or_insert_with(Vec::new);
+        self.generated_ids.push(contracts.len());
+        contracts.push(ContractConfig {
+            code,
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Adds the contract as is, without the calculation of its id.
+    pub fn with_contract_config(mut self, contract: ContractConfig) -> Self {
+        self.state
+            .contracts
+            .get_or_insert_with(Vec::new)
+            .push(contract);
+        self
+    }
+
+    /// Sets the salt of the last added contract.
+    pub fn with_salt(mut self, salt: Salt) -> Self {
+        self.last_contract().salt = salt;
+        self
+    }
+
+    /// Adds the storage slot to the last added contract.
+    pub fn with_state(mut self, key: Bytes32, value: Vec<u8>) -> Self {
+        self.last_contract()
+            .state
+            .get_or_insert_with(Vec::new)
+            .push((key, value));
+        self
+    }
+
+    /// Adds the balance of the `asset_id` to the last added contract.
+    pub fn with_balance(mut self, asset_id: AssetId, amount: u64) -> Self {
+        self.last_contract()
+            .balances
+            .get_or_insert_with(Vec::new)
+            .push((asset_id, amount));
+        self
+    }
+
+    fn last_contract(&mut self) -> &mut ContractConfig {
+        self.state
+            .contracts
+            .as_mut()
+            .and_then(|contracts| contracts.last_mut())
+            .expect("A contract should be added before its state or balances")
+    }
+
+    pub fn build(mut self) -> ChainConfig {
+        if let Some(contracts) = self.state.contracts.as_mut() {
+            for index in self.generated_ids {
+                if let Some(contract) = contracts.get_mut(index) {
+                    contract.calculate_contract_id();
+                }
+            }
+        }
+
+        self.config.initial_state = if self.state == StateConfig::default() {
+            None
+        } else {
+            Some(self.state)
+        };
+        self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_asm::op;
+
+    #[test]
+    fn build_without_state_has_no_initial_state() {
+        let config = ChainConfigBuilder::new().with_chain_name("test").build();
+
+        assert_eq!(config.chain_name, "test");
+        assert_eq!(config.initial_state, None);
+    }
+
+    #[test]
+    fn with_coin_appends_to_existing_coins() {
+        let testnet = ChainConfig::local_testnet();
+        let testnet_coins = testnet.initial_state.unwrap().coins.unwrap();
+        let owner = Address::from([1; 32]);
+
+        let config = ChainConfigBuilder::local_testnet()
+            .with_coin(owner, 100, AssetId::BASE)
+            .build();
+
+        let coins = config.initial_state.unwrap().coins.unwrap();
+        assert_eq!(coins.len(), testnet_coins.len() + 1);
+        let coin = coins.last().unwrap();
+        assert_eq!(coin.owner, owner);
+        assert_eq!(coin.amount, 100);
+    }
+
+    #[test]
+    fn contract_id_includes_state() {
+        let code: Vec<u8> = op::ret(0x10).to_bytes().to_vec();
+        let key = Bytes32::from([2; 32]);
+        let value = vec![3; 32];
+
+        let config = ChainConfigBuilder::new()
+            .with_contract(code.clone())
+            .with_state(key, value.clone())
+            .with_balance(AssetId::BASE, 7)
+            .build();
+
+        let mut expected = ContractConfig {
+            code,
+            state: Some(vec![(key, value)]),
+            balances: Some(vec![(AssetId::BASE, 7)]),
+            ..Default::default()
+        };
+        expected.calculate_contract_id();
+        let contracts = config.initial_state.unwrap().contracts.unwrap();
+        assert_eq!(contracts, vec![expected]);
+    }
+}
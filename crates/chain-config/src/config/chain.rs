@@ -34,9 +34,13 @@ use std::{
     path::PathBuf,
 };
 
+#[cfg(feature = "std")]
+use crate::config::migration::migrate_chain_config;
 use crate::{
     config::{
+        blob::BlobPricing,
         coin::CoinConfig,
+        fee_free::FeeFreeAllowance,
         state::StateConfig,
     },
     genesis::GenesisCommitment,
@@ -47,6 +51,8 @@ use crate::{
 pub const FUEL_BECH32_HRP: &str = "fuel";
 pub const LOCAL_TESTNET: &str = "local_testnet";
 pub const TESTNET_INITIAL_BALANCE: u64 = 10_000_000;
+/// The version of the chain config format produced by this release.
+pub const CHAIN_CONFIG_VERSION: u32 = 3;
 
 #[serde_as]
 // TODO: Remove not consensus/network fields from `ChainConfig` or create a new config only
@@ -54,22 +60,39 @@ pub const TESTNET_INITIAL_BALANCE: u64 = 10_000_000;
 #[skip_serializing_none]
 #[derive(Clone, Debug, Deserialize, Serialize, Eq, PartialEq)]
 pub struct ChainConfig {
+    /// The version of the config format, see [`CHAIN_CONFIG_VERSION`].
+    /// The unversioned configs are written without the field, the same as they were read.
+    #[serde(default, skip_serializing_if = "is_unversioned")]
+    pub version: u32,
     pub chain_name: String,
     pub block_gas_limit: u64,
     #[serde(default)]
     pub initial_state: Option<StateConfig>,
     pub consensus_parameters: ConsensusParameters,
     pub consensus: ConsensusConfig,
+    /// The network-operated transactions that pay zero gas price, disabled if `None`.
+    #[serde(default)]
+    pub fee_free_allowance: Option<FeeFreeAllowance>,
+    /// The pricing of the transactions carrying the large payloads, disabled if `None`.
+    #[serde(default)]
+    pub blob_pricing: Option<BlobPricing>,
+}
+
+fn is_unversioned(version: &u32) -> bool {
+    *version == 0
 }
 
 impl Default for ChainConfig {
     fn default() -> Self {
         Self {
+            version: CHAIN_CONFIG_VERSION,
             chain_name: "local".into(),
             block_gas_limit: TxParameters::DEFAULT.max_gas_per_tx * 10, /* TODO: Pick a sensible default */
             consensus_parameters: ConsensusParameters::default(),
             initial_state: None,
             consensus: ConsensusConfig::default_poa(),
+            fee_free_allowance: None,
+            blob_pricing: None,
         }
     }
 }
@@ -178,10 +201,10 @@ impl FromStr for ChainConfig {
                 // Attempt to load chain config from path
                 let path = PathBuf::from(s.to_string());
                 let contents = std::fs::read(path)?;
-                serde_json::from_slice(&contents).map_err(|e| {
+                Self::from_json_slice(&contents).map_err(|e| {
                     std::io::Error::new(
                         ErrorKind::InvalidData,
-                        anyhow::Error::new(e).context(format!(
+                        e.context(format!(
                             "an error occurred while loading the chain config file {s}"
                         )),
                     )
@@ -191,27 +214,50 @@ impl FromStr for ChainConfig {
     }
 }
 
+#[cfg(feature = "std")]
+impl ChainConfig {
+    /// Decodes the chain config from JSON, migrating configs
+    /// of older versions to the [`CHAIN_CONFIG_VERSION`].
+    pub fn from_json_slice(bytes: &[u8]) -> anyhow::Result<Self> {
+        let json: serde_json::Value = serde_json::from_slice(bytes)?;
+        let json = migrate_chain_config(json)?;
+        let config = serde_json::from_value(json)?;
+        Ok(config)
+    }
+}
+
 impl GenesisCommitment for ChainConfig {
     fn root(&self) -> anyhow::Result<MerkleRoot> {
         // # Dev-note: If `ChainConfig` got a new field, maybe we need to hash it too.
         // Avoid using the `..` in the code below. Use `_` instead if you don't need to hash
         // the field. Explicit fields help to prevent a bug of missing fields in the hash.
         let ChainConfig {
+            // The version describes the format of the file, not the chain itself.
+            version: _,
             chain_name,
             block_gas_limit,
             // Skip the `initial_state` bec
             initial_state: _,
             consensus_parameters,
             consensus,
+            fee_free_allowance,
+            blob_pricing,
         } = self;
 
         // TODO: Hash settlement configuration when it will be available.
-        let config_hash = *Hasher::default()
+        let mut hasher = Hasher::default()
             .chain(chain_name.as_bytes())
             .chain(block_gas_limit.to_be_bytes())
             .chain(consensus_parameters.root()?)
-            .chain(consensus.root()?)
-            .finalize();
+            .chain(consensus.root()?);
+        // The chains without the allowance keep the same root as before.
+        if let Some(fee_free_allowance) = fee_free_allowance {
+            hasher = hasher.chain(fee_free_allowance.root()?);
+        }
+        if let Some(blob_pricing) = blob_pricing {
+            hasher = hasher.chain(blob_pricing.root()?);
+        }
+        let config_hash = *hasher.finalize();
 
         Ok(config_hash)
     }
@@ -246,3 +292,21 @@ impl GenesisCommitment for ConsensusConfig {
         Ok(hash.into())
     }
 }
+
+impl GenesisCommitment for FeeFreeAllowance {
+    fn root(&self) -> anyhow::Result<MerkleRoot> {
+        let bytes = postcard::to_allocvec(&self).map_err(anyhow::Error::msg)?;
+        let hash = Hasher::default().chain(bytes).finalize();
+
+        Ok(hash.into())
+    }
+}
+
+impl GenesisCommitment for BlobPricing {
+    fn root(&self) -> anyhow::Result<MerkleRoot> {
+        let bytes = postcard::to_allocvec(&self).map_err(anyhow::Error::msg)?;
+        let hash = Hasher::default().chain(bytes).finalize();
+
+        Ok(hash.into())
+    }
+}
@@ -0,0 +1,96 @@
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_tx::Input,
+    fuel_types::Address,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// The whitelist of the network-operated transactions that pay zero gas price.
+///
+/// The transaction is whitelisted if it spends a predicate input with one of the
+/// `predicate_roots` as the owner. Only the operator can satisfy these predicates,
+/// so other users can't spend the budget of the whitelisted transactions.
+/// The whitelisted transactions of one block use at most `gas_budget_per_block`
+/// gas for free, the rest of them pay the gas price of the block.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct FeeFreeAllowance {
+    #[serde(default)]
+    pub predicate_roots: Vec<Address>,
+    pub gas_budget_per_block: Word,
+}
+
+impl FeeFreeAllowance {
+    /// Returns `true` if the transaction with the `inputs` is whitelisted.
+    pub fn is_whitelisted(&self, inputs: &[Input]) -> bool {
+        inputs.iter().any(|input| match input {
+            Input::CoinPredicate(coin) => self.predicate_roots.contains(&coin.owner),
+            Input::MessageCoinPredicate(message) => {
+                self.predicate_roots.contains(&message.recipient)
+            }
+            Input::MessageDataPredicate(message) => {
+                self.predicate_roots.contains(&message.recipient)
+            }
+            _ => false,
+        })
+    }
+
+    /// Returns `true` if the transaction with the `max_gas` fits into the budget
+    /// of the block, where the previous whitelisted transactions used `used_gas`.
+    pub fn fits_budget(&self, used_gas: Word, max_gas: Word) -> bool {
+        used_gas
+            .checked_add(max_gas)
+            .map(|gas| gas <= self.gas_budget_per_block)
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        fuel_asm::{
+            op,
+            RegId,
+        },
+        fuel_tx::ContractId,
+    };
+
+    #[test]
+    fn only_the_predicates_of_the_whitelisted_roots_are_whitelisted() {
+        // Given
+        let predicate: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+        let owner = Input::predicate_owner(&predicate);
+        let allowance = FeeFreeAllowance {
+            predicate_roots: vec![owner],
+            gas_budget_per_block: 1000,
+        };
+        let predicate_input = Input::coin_predicate(
+            Default::default(),
+            owner,
+            100,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            predicate,
+            vec![],
+        );
+        let contract_input = Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            ContractId::default(),
+        );
+
+        // When
+        let with_predicate = allowance.is_whitelisted(&[predicate_input]);
+        let with_contract = allowance.is_whitelisted(&[contract_input]);
+
+        // Then
+        assert!(with_predicate);
+        assert!(!with_contract);
+    }
+}
@@ -0,0 +1,162 @@
+use super::state::StateConfig;
+use fuel_core_types::fuel_types::{
+    Address,
+    AssetId,
+    ContractId,
+};
+
+/// Selects the part of the [`StateConfig`] to export. Each empty list of the
+/// filter doesn't restrict anything, so the default filter keeps the whole state.
+///
+/// Each list restricts only the entries it is related to:
+/// - `owners` keeps the coins of these owners and the messages to these recipients.
+/// - `asset_ids` keeps the coins of these assets and the contract balances of these assets.
+/// - `contract_ids` keeps these contracts with their state and balances.
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct StateFilter {
+    pub owners: Vec<Address>,
+    pub asset_ids: Vec<AssetId>,
+    pub contract_ids: Vec<ContractId>,
+}
+
+impl StateFilter {
+    /// Returns `true` if the filter keeps the whole state.
+    pub fn is_empty(&self) -> bool {
+        self.owners.is_empty()
+            && self.asset_ids.is_empty()
+            && self.contract_ids.is_empty()
+    }
+
+    /// Returns `true` if the filter keeps the coins and the messages of the `owner`.
+    pub fn owner(&self, owner: &Address) -> bool {
+        self.owners.is_empty() || self.owners.contains(owner)
+    }
+
+    /// Returns `true` if the filter keeps the coins and the balances of the `asset_id`.
+    pub fn asset_id(&self, asset_id: &AssetId) -> bool {
+        self.asset_ids.is_empty() || self.asset_ids.contains(asset_id)
+    }
+
+    /// Returns `true` if the filter keeps the contract with its state and balances.
+    pub fn contract_id(&self, contract_id: &ContractId) -> bool {
+        self.contract_ids.is_empty() || self.contract_ids.contains(contract_id)
+    }
+}
+
+impl StateConfig {
+    /// Removes the entries not selected by the `filter`. The heights, the timestamp
+    /// and the spent messages are kept as is.
+    pub fn filter(mut self, filter: &StateFilter) -> Self {
+        if filter.is_empty() {
+            return self
+        }
+
+        if let Some(coins) = self.coins.as_mut() {
+            coins.retain(|coin| {
+                filter.owner(&coin.owner) && filter.asset_id(&coin.asset_id)
+            });
+        }
+        if let Some(messages) = self.messages.as_mut() {
+            messages.retain(|message| filter.owner(&message.recipient));
+        }
+        if let Some(contracts) = self.contracts.as_mut() {
+            contracts.retain(|contract| filter.contract_id(&contract.contract_id));
+            for contract in contracts.iter_mut() {
+                if let Some(balances) = contract.balances.as_mut() {
+                    balances.retain(|(asset_id, _)| filter.asset_id(asset_id));
+                }
+            }
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        CoinConfig,
+        ContractConfig,
+        MessageConfig,
+    };
+
+    fn state() -> StateConfig {
+        let coin = |owner: u8, asset: u8| CoinConfig {
+            owner: [owner; 32].into(),
+            asset_id: [asset; 32].into(),
+            amount: 10,
+            ..Default::default()
+        };
+        let contract = |id: u8| ContractConfig {
+            contract_id: [id; 32].into(),
+            state: Some(vec![([id; 32].into(), vec![id])]),
+            balances: Some(vec![([1; 32].into(), 1), ([2; 32].into(), 2)]),
+            ..Default::default()
+        };
+        let message = |recipient: u8| MessageConfig {
+            recipient: [recipient; 32].into(),
+            nonce: [recipient; 32].into(),
+            ..Default::default()
+        };
+        StateConfig {
+            coins: Some(vec![coin(1, 1), coin(1, 2), coin(2, 1)]),
+            contracts: Some(vec![contract(1), contract(2)]),
+            messages: Some(vec![message(1), message(2)]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn empty_filter_keeps_the_whole_state() {
+        assert_eq!(state().filter(&StateFilter::default()), state());
+    }
+
+    #[test]
+    fn owners_filter_coins_and_messages() {
+        let filter = StateFilter {
+            owners: vec![[1; 32].into()],
+            ..Default::default()
+        };
+
+        let filtered = state().filter(&filter);
+
+        let coins = filtered.coins.unwrap();
+        assert_eq!(coins.len(), 2);
+        assert!(coins.iter().all(|coin| coin.owner == [1; 32].into()));
+        let messages = filtered.messages.unwrap();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].recipient, [1; 32].into());
+        assert_eq!(filtered.contracts, state().contracts);
+    }
+
+    #[test]
+    fn asset_ids_filter_coins_and_balances() {
+        let filter = StateFilter {
+            asset_ids: vec![[2; 32].into()],
+            ..Default::default()
+        };
+
+        let filtered = state().filter(&filter);
+
+        let coins = filtered.coins.unwrap();
+        assert_eq!(coins.len(), 1);
+        assert_eq!(coins[0].asset_id, [2; 32].into());
+        for contract in filtered.contracts.unwrap() {
+            assert_eq!(contract.balances, Some(vec![([2; 32].into(), 2)]));
+        }
+    }
+
+    #[test]
+    fn contract_ids_keep_contracts_with_their_state() {
+        let filter = StateFilter {
+            contract_ids: vec![[2; 32].into()],
+            ..Default::default()
+        };
+
+        let filtered = state().filter(&filter);
+
+        let contracts = filtered.contracts.unwrap();
+        assert_eq!(contracts, vec![state().contracts.unwrap()[1].clone()]);
+        assert_eq!(filtered.coins, state().coins);
+    }
+}
@@ -57,6 +57,19 @@ impl From<MessageConfig> for Message {
     }
 }
 
+impl From<Message> for MessageConfig {
+    fn from(msg: Message) -> Self {
+        Self {
+            sender: *msg.sender(),
+            recipient: *msg.recipient(),
+            nonce: *msg.nonce(),
+            amount: msg.amount(),
+            data: msg.data().clone(),
+            da_height: msg.da_height(),
+        }
+    }
+}
+
 impl GenesisCommitment for Message {
     fn root(&self) -> anyhow::Result<MerkleRoot> {
         let sender = self.sender();
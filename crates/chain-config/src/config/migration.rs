@@ -0,0 +1,227 @@
+//! Versioning of the chain config format.
+//!
+//! Every chain config file carries a `version` field. Files written by an older
+//! `fuel-core` are upgraded in place at load time by applying the registered
+//! migrations one after another, while files produced by a newer release are
+//! rejected with an explicit error instead of being misinterpreted.
+
+use anyhow::{
+    anyhow,
+    bail,
+    Context,
+};
+use serde_json::Value;
+
+use crate::CHAIN_CONFIG_VERSION;
+
+/// The name of the field holding the format version.
+const VERSION_FIELD: &str = "version";
+
+/// Upgrades the JSON representation of a chain config by one version.
+type Migration = fn(&mut serde_json::Map<String, Value>) -> anyhow::Result<()>;
+
+/// The registry of migrations. The migration at index `i` upgrades
+/// a config of version `i` into version `i + 1`.
+const MIGRATIONS: [Migration; CHAIN_CONFIG_VERSION as usize] =
+    [migrate_v0_to_v1, migrate_v1_to_v2, migrate_v2_to_v3];
+
+/// Returns the format version of the chain config JSON.
+/// Configs without the `version` field predate versioning and are treated as version `0`.
+pub fn chain_config_version(config: &Value) -> anyhow::Result<u32> {
+    let Some(version) = config.get(VERSION_FIELD) else {
+        return Ok(0)
+    };
+    let version = version
+        .as_u64()
+        .ok_or_else(|| anyhow!("the `{VERSION_FIELD}` field must be a number"))?;
+    u32::try_from(version)
+        .map_err(|_| anyhow!("the chain config version {version} is out of range"))
+}
+
+/// Upgrades the JSON representation of a chain config to [`CHAIN_CONFIG_VERSION`].
+pub fn migrate_chain_config(config: Value) -> anyhow::Result<Value> {
+    migrate_chain_config_to(config, CHAIN_CONFIG_VERSION)
+}
+
+/// Upgrades the JSON representation of a chain config to the `latest` version,
+/// as the release supporting the versions up to `latest` does.
+fn migrate_chain_config_to(mut config: Value, latest: u32) -> anyhow::Result<Value> {
+    let version = chain_config_version(&config)?;
+
+    if version > latest {
+        bail!(
+            "the chain config has version {version}, but this release of `fuel-core` \
+            only supports versions up to {latest}. \
+            Please upgrade `fuel-core` to a release that supports the newer format."
+        )
+    }
+
+    let object = config
+        .as_object_mut()
+        .ok_or_else(|| anyhow!("the chain config must be a JSON object"))?;
+
+    let migrations = MIGRATIONS
+        .get(..latest as usize)
+        .ok_or_else(|| anyhow!("the chain config version {latest} is unknown"))?;
+    for (from, migration) in migrations.iter().enumerate().skip(version as usize) {
+        migration(object).with_context(|| {
+            format!("failed to migrate the chain config from version {from}")
+        })?;
+        let to = from.saturating_add(1);
+        object.insert(VERSION_FIELD.to_string(), Value::from(to));
+        tracing::info!("Migrated the chain config from version {from} to {to}");
+    }
+
+    Ok(config)
+}
+
+/// Version `0` is the unversioned format. Version `1` only introduces
+/// the `version` field, which is set by the caller.
+fn migrate_v0_to_v1(_: &mut serde_json::Map<String, Value>) -> anyhow::Result<()> {
+    Ok(())
+}
+
+/// Version `2` introduces the `fee_free_allowance`, which is part of the root
+/// of the chain config. The releases supporting only version `1` would ignore it
+/// and compute a different root, so the configs of version `1` can't have it.
+fn migrate_v1_to_v2(config: &mut serde_json::Map<String, Value>) -> anyhow::Result<()> {
+    reject_field(config, "fee_free_allowance", 2)
+}
+
+/// Version `3` introduces the `blob_pricing`, which is part of the root
+/// of the chain config, the same as the `fee_free_allowance` in version `2`.
+fn migrate_v2_to_v3(config: &mut serde_json::Map<String, Value>) -> anyhow::Result<()> {
+    reject_field(config, "blob_pricing", 3)
+}
+
+fn reject_field(
+    config: &serde_json::Map<String, Value>,
+    field: &str,
+    since: u32,
+) -> anyhow::Result<()> {
+    if config.contains_key(field) {
+        bail!("the `{field}` field requires the chain config version {since}")
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        BlobPricing,
+        ChainConfig,
+        FeeFreeAllowance,
+    };
+
+    fn unversioned_local_testnet() -> Value {
+        let mut json = serde_json::to_value(ChainConfig::local_testnet()).unwrap();
+        json.as_object_mut().unwrap().remove(VERSION_FIELD);
+        json
+    }
+
+    #[test]
+    fn missing_version_is_treated_as_v0() {
+        let json = unversioned_local_testnet();
+        assert_eq!(chain_config_version(&json).unwrap(), 0);
+    }
+
+    #[test]
+    fn unversioned_config_is_migrated_to_latest() {
+        let json = unversioned_local_testnet();
+
+        let migrated = migrate_chain_config(json).unwrap();
+
+        assert_eq!(
+            chain_config_version(&migrated).unwrap(),
+            CHAIN_CONFIG_VERSION
+        );
+        let config: ChainConfig = serde_json::from_value(migrated).unwrap();
+        assert_eq!(config, ChainConfig::local_testnet());
+    }
+
+    #[test]
+    fn latest_config_is_unchanged_by_migration() {
+        let json = serde_json::to_value(ChainConfig::local_testnet()).unwrap();
+
+        let migrated = migrate_chain_config(json.clone()).unwrap();
+
+        assert_eq!(json, migrated);
+    }
+
+    #[test]
+    fn future_version_is_rejected() {
+        let mut json = serde_json::to_value(ChainConfig::local_testnet()).unwrap();
+        json[VERSION_FIELD] = Value::from(CHAIN_CONFIG_VERSION.saturating_add(1));
+
+        let err = migrate_chain_config(json).unwrap_err();
+
+        assert!(err.to_string().contains("Please upgrade `fuel-core`"));
+    }
+
+    #[test]
+    fn v1_release_rejects_config_with_fee_free_allowance() {
+        // Given
+        let config = ChainConfig {
+            fee_free_allowance: Some(FeeFreeAllowance::default()),
+            ..ChainConfig::local_testnet()
+        };
+        let json = serde_json::to_value(config).unwrap();
+
+        // When
+        let err = migrate_chain_config_to(json, 1).unwrap_err();
+
+        // Then
+        assert!(err.to_string().contains("only supports versions up to 1"));
+    }
+
+    #[test]
+    fn v1_config_with_fee_free_allowance_is_rejected() {
+        // Given
+        let config = ChainConfig {
+            fee_free_allowance: Some(FeeFreeAllowance::default()),
+            ..ChainConfig::local_testnet()
+        };
+        let mut json = serde_json::to_value(config).unwrap();
+        json[VERSION_FIELD] = Value::from(1);
+
+        // When
+        let err = migrate_chain_config(json).unwrap_err();
+
+        // Then
+        assert!(format!("{err:#}").contains("`fee_free_allowance`"));
+    }
+
+    #[test]
+    fn v2_release_rejects_config_with_blob_pricing() {
+        // Given
+        let config = ChainConfig {
+            blob_pricing: Some(BlobPricing::default()),
+            ..ChainConfig::local_testnet()
+        };
+        let json = serde_json::to_value(config).unwrap();
+
+        // When
+        let err = migrate_chain_config_to(json, 2).unwrap_err();
+
+        // Then
+        assert!(err.to_string().contains("only supports versions up to 2"));
+    }
+
+    #[test]
+    fn v2_config_with_blob_pricing_is_rejected() {
+        // Given
+        let config = ChainConfig {
+            blob_pricing: Some(BlobPricing::default()),
+            ..ChainConfig::local_testnet()
+        };
+        let mut json = serde_json::to_value(config).unwrap();
+        json[VERSION_FIELD] = Value::from(2);
+
+        // When
+        let err = migrate_chain_config(json).unwrap_err();
+
+        // Then
+        assert!(format!("{err:#}").contains("`blob_pricing`"));
+    }
+}
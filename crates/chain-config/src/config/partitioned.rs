@@ -0,0 +1,971 @@
+//! The snapshot of the state split into multiple files.
+//!
+//! The coins, the contracts and the messages are written into the table files
+//! of the binary format, and each table rolls over to a new file once the
+//! current one reaches the [`PartitionLimits`]. The state slots and the balances
+//! of the contracts are the rows of their own tables, so a contract with
+//! millions of slots is split into many files. The rest of the state is
+//! written into the [`STATE_FILE`]. The [`MANIFEST_FILE`] lists the files with
+//! their row counts and checksums, so the reader verifies each file. The files of
+//! the coins, the contracts and the messages are decoded in parallel, while the
+//! rows of the contract slots are streamed one file at a time.
+//!
+//! The files are read and written through the [`SnapshotStorage`], so the snapshot
+//! may live in the local [`SnapshotDirectory`] or in the remote store, where each
+//! file is uploaded once it is closed.
+
+use super::{
+    binary::{
+        BinaryCoin,
+        BinaryContract,
+        BinaryMessage,
+    },
+    coin::CoinConfig,
+    contract::ContractConfig,
+    message::MessageConfig,
+    state::StateConfig,
+};
+use anyhow::{
+    anyhow,
+    ensure,
+    Context,
+};
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::{
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+};
+use itertools::Itertools;
+use serde::{
+    de::DeserializeOwned,
+    Deserialize,
+    Serialize,
+};
+use std::{
+    fmt,
+    fs,
+    marker::PhantomData,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Arc,
+};
+
+/// The name of the manifest of the partitioned snapshot.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// The name of the file with the state without the tables.
+pub const STATE_FILE: &str = "state.bin";
+
+/// The version of the manifest of the partitioned snapshot.
+pub const MANIFEST_VERSION: u32 = 1;
+
+const COINS_TABLE: &str = "coins";
+const CONTRACTS_TABLE: &str = "contracts";
+const MESSAGES_TABLE: &str = "messages";
+const CONTRACT_STATE_TABLE: &str = "contract_state";
+const CONTRACT_BALANCES_TABLE: &str = "contract_balances";
+const TABLES: [&str; 5] = [
+    COINS_TABLE,
+    CONTRACTS_TABLE,
+    MESSAGES_TABLE,
+    CONTRACT_STATE_TABLE,
+    CONTRACT_BALANCES_TABLE,
+];
+
+/// The state slot of the contract, the row of the `contract_state` table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractStateRow {
+    pub contract_id: ContractId,
+    pub key: Bytes32,
+    pub value: Vec<u8>,
+}
+
+/// The balance of the contract, the row of the `contract_balances` table.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractBalanceRow {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub amount: u64,
+}
+
+/// The limits of one table file. The file is closed when it reaches any of them,
+/// but it always has at least one row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartitionLimits {
+    /// The maximum number of the rows in the file.
+    pub max_rows: usize,
+    /// The maximum size of the file in bytes.
+    pub max_bytes: usize,
+}
+
+impl Default for PartitionLimits {
+    fn default() -> Self {
+        Self {
+            max_rows: 1_000_000,
+            max_bytes: 256 * 1024 * 1024,
+        }
+    }
+}
+
+/// The list of the files of the partitioned snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotManifest {
+    pub version: u32,
+    /// The file with the state without the tables.
+    pub state: SnapshotFile,
+    /// The tables of the state. The missing table is `None` in the state.
+    pub tables: Vec<SnapshotTable>,
+}
+
+/// The files of one table in the order of the rows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotTable {
+    pub name: String,
+    pub files: Vec<SnapshotFile>,
+}
+
+/// The file of the partitioned snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotFile {
+    /// The name of the file relative to the directory of the manifest.
+    pub name: String,
+    pub rows: u64,
+    pub bytes: u64,
+    /// The SHA-256 hash of the content of the file.
+    pub checksum: Bytes32,
+}
+
+impl SnapshotFile {
+    fn new(name: String, rows: usize, content: &[u8]) -> Self {
+        Self {
+            name,
+            rows: rows as u64,
+            bytes: content.len() as u64,
+            checksum: Hasher::hash(content),
+        }
+    }
+
+    /// Reads the file from the `storage` and verifies its size and checksum.
+    fn read(&self, storage: &dyn SnapshotStorage) -> anyhow::Result<Vec<u8>> {
+        let content = storage.read(&self.name)?;
+        ensure!(
+            content.len() as u64 == self.bytes,
+            "the size of {} is {}, expected {}",
+            self.name,
+            content.len(),
+            self.bytes
+        );
+        ensure!(
+            Hasher::hash(&content) == self.checksum,
+            "the checksum of {} doesn't match the manifest",
+            self.name
+        );
+        Ok(content)
+    }
+}
+
+/// The storage of the files of the partitioned snapshot.
+pub trait SnapshotStorage: fmt::Debug + Send + Sync {
+    /// Reads the whole file.
+    fn read(&self, name: &str) -> anyhow::Result<Vec<u8>>;
+
+    /// Writes the whole file, replacing the previous content.
+    fn write(&self, name: &str, content: &[u8]) -> anyhow::Result<()>;
+}
+
+/// The files of the snapshot in the local directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnapshotDirectory(PathBuf);
+
+impl SnapshotDirectory {
+    pub fn new(directory: &Path) -> Self {
+        Self(directory.to_path_buf())
+    }
+
+    /// Creates the `directory` if it is missing.
+    pub fn create(directory: &Path) -> anyhow::Result<Self> {
+        fs::create_dir_all(directory)
+            .with_context(|| format!("failed to create {}", directory.display()))?;
+        Ok(Self::new(directory))
+    }
+}
+
+impl SnapshotStorage for SnapshotDirectory {
+    fn read(&self, name: &str) -> anyhow::Result<Vec<u8>> {
+        let path = self.0.join(name);
+        fs::read(&path).with_context(|| format!("failed to read {}", path.display()))
+    }
+
+    fn write(&self, name: &str, content: &[u8]) -> anyhow::Result<()> {
+        let path = self.0.join(name);
+        fs::write(&path, content)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+/// Writes the tables of the partitioned snapshot one by one, so the rows are
+/// streamed into the files and the whole state is never held in memory.
+pub struct PartitionedSnapshotWriter {
+    storage: Arc<dyn SnapshotStorage>,
+    limits: PartitionLimits,
+    tables: Vec<SnapshotTable>,
+}
+
+impl PartitionedSnapshotWriter {
+    /// Writes the snapshot into the local `directory`.
+    pub fn new(directory: &Path, limits: PartitionLimits) -> anyhow::Result<Self> {
+        let storage = SnapshotDirectory::create(directory)?;
+        Self::with_storage(Arc::new(storage), limits)
+    }
+
+    /// Writes the snapshot into the `storage`. Each file is written once it is full.
+    pub fn with_storage(
+        storage: Arc<dyn SnapshotStorage>,
+        limits: PartitionLimits,
+    ) -> anyhow::Result<Self> {
+        ensure!(
+            limits.max_rows > 0,
+            "the maximum number of the rows per file should be positive"
+        );
+        Ok(Self {
+            storage,
+            limits,
+            tables: vec![],
+        })
+    }
+
+    pub fn write_coins(
+        &mut self,
+        coins: impl Iterator<Item = anyhow::Result<CoinConfig>>,
+    ) -> anyhow::Result<()> {
+        self.write_table(COINS_TABLE, coins.map_ok(BinaryCoin::from))
+    }
+
+    /// Writes the contracts without their state slots and balances,
+    /// which are written by [`Self::write_contract_state`] and
+    /// [`Self::write_contract_balances`].
+    pub fn write_contracts(
+        &mut self,
+        contracts: impl Iterator<Item = anyhow::Result<ContractConfig>>,
+    ) -> anyhow::Result<()> {
+        let rows =
+            contracts.map_ok(|contract| BinaryContract::from(without_slots(&contract)));
+        self.write_table(CONTRACTS_TABLE, rows)
+    }
+
+    /// Writes the state slots of the contracts. The slots of one contract
+    /// should follow each other.
+    pub fn write_contract_state(
+        &mut self,
+        rows: impl Iterator<Item = anyhow::Result<ContractStateRow>>,
+    ) -> anyhow::Result<()> {
+        self.write_table(CONTRACT_STATE_TABLE, rows)
+    }
+
+    /// Writes the balances of the contracts. The balances of one contract
+    /// should follow each other.
+    pub fn write_contract_balances(
+        &mut self,
+        rows: impl Iterator<Item = anyhow::Result<ContractBalanceRow>>,
+    ) -> anyhow::Result<()> {
+        self.write_table(CONTRACT_BALANCES_TABLE, rows)
+    }
+
+    pub fn write_messages(
+        &mut self,
+        messages: impl Iterator<Item = anyhow::Result<MessageConfig>>,
+    ) -> anyhow::Result<()> {
+        self.write_table(MESSAGES_TABLE, messages.map_ok(BinaryMessage::from))
+    }
+
+    /// Writes the rest of the `state` without the tables into the [`STATE_FILE`],
+    /// and the manifest of the written files into the [`MANIFEST_FILE`].
+    pub fn finish(self, state: &StateConfig) -> anyhow::Result<SnapshotManifest> {
+        let rest = StateConfig {
+            coins: None,
+            contracts: None,
+            messages: None,
+            ..state.clone()
+        }
+        .to_binary()?;
+        self.storage.write(STATE_FILE, &rest)?;
+
+        let manifest = SnapshotManifest {
+            version: MANIFEST_VERSION,
+            state: SnapshotFile::new(STATE_FILE.to_string(), 1, &rest),
+            tables: self.tables,
+        };
+        let json = serde_json::to_vec_pretty(&manifest)?;
+        self.storage.write(MANIFEST_FILE, &json)?;
+        Ok(manifest)
+    }
+
+    fn write_table<T: Serialize>(
+        &mut self,
+        table: &str,
+        rows: impl Iterator<Item = anyhow::Result<T>>,
+    ) -> anyhow::Result<()> {
+        ensure!(
+            self.tables.iter().all(|written| written.name != table),
+            "the table {table} is already written"
+        );
+        let table = write_table(self.storage.as_ref(), table, rows, self.limits)?;
+        self.tables.push(table);
+        Ok(())
+    }
+}
+
+impl StateConfig {
+    /// Writes the state into the `directory` split into the files by the `limits`,
+    /// and returns the manifest written into the [`MANIFEST_FILE`].
+    pub fn write_partitioned(
+        &self,
+        directory: &Path,
+        limits: PartitionLimits,
+    ) -> anyhow::Result<SnapshotManifest> {
+        let mut writer = PartitionedSnapshotWriter::new(directory, limits)?;
+        if let Some(coins) = &self.coins {
+            writer.write_coins(coins.iter().cloned().map(Ok))?;
+        }
+        if let Some(contracts) = &self.contracts {
+            writer.write_contracts(contracts.iter().cloned().map(Ok))?;
+            let rows = contracts.iter().flat_map(|contract| {
+                contract
+                    .state
+                    .iter()
+                    .flatten()
+                    .map(|(key, value)| ContractStateRow {
+                        contract_id: contract.contract_id,
+                        key: *key,
+                        value: value.clone(),
+                    })
+                    .map(Ok)
+            });
+            writer.write_contract_state(rows)?;
+            let rows = contracts.iter().flat_map(|contract| {
+                contract
+                    .balances
+                    .iter()
+                    .flatten()
+                    .map(|(asset_id, amount)| ContractBalanceRow {
+                        contract_id: contract.contract_id,
+                        asset_id: *asset_id,
+                        amount: *amount,
+                    })
+                    .map(Ok)
+            });
+            writer.write_contract_balances(rows)?;
+        }
+        if let Some(messages) = &self.messages {
+            writer.write_messages(messages.iter().cloned().map(Ok))?;
+        }
+        writer.finish(self)
+    }
+}
+
+/// The partitioned snapshot opened from its [`MANIFEST_FILE`].
+///
+/// The coins, the contracts and the messages are decoded into the [`StateConfig`],
+/// while the state slots and the balances of the contracts are only streamed
+/// file by file, so the reader never holds all of them in memory.
+#[derive(Debug, Clone)]
+pub struct PartitionedSnapshot {
+    storage: Arc<dyn SnapshotStorage>,
+    manifest: SnapshotManifest,
+}
+
+impl PartitionedSnapshot {
+    /// Reads the manifest of the snapshot written by [`PartitionedSnapshotWriter`]
+    /// from the local `directory`.
+    pub fn open(directory: &Path) -> anyhow::Result<Self> {
+        Self::open_storage(Arc::new(SnapshotDirectory::new(directory)))
+    }
+
+    /// Reads the manifest of the snapshot from the `storage`.
+    pub fn open_storage(storage: Arc<dyn SnapshotStorage>) -> anyhow::Result<Self> {
+        let manifest = storage.read(MANIFEST_FILE)?;
+        let manifest: SnapshotManifest = serde_json::from_slice(&manifest)
+            .with_context(|| format!("failed to decode {MANIFEST_FILE}"))?;
+        ensure!(
+            manifest.version == MANIFEST_VERSION,
+            "unsupported snapshot manifest version {}, expected {MANIFEST_VERSION}",
+            manifest.version
+        );
+        if let Some(name) = manifest
+            .tables
+            .iter()
+            .map(|table| table.name.as_str())
+            .find(|name| !TABLES.contains(name))
+        {
+            return Err(anyhow!("unknown snapshot table {name}"))
+        }
+        Ok(Self { storage, manifest })
+    }
+
+    pub fn manifest(&self) -> &SnapshotManifest {
+        &self.manifest
+    }
+
+    /// Reads the state with the coins, the contracts and the messages. The contracts
+    /// don't have the state slots and the balances, they are returned by
+    /// [`Self::contract_state`] and [`Self::contract_balances`].
+    /// The files of the tables are verified and decoded in parallel.
+    pub fn state_config(&self) -> anyhow::Result<StateConfig> {
+        let storage = self.storage.as_ref();
+        let mut state =
+            StateConfig::from_binary_slice(&self.manifest.state.read(storage)?)?;
+        if let Some(table) = self.table(COINS_TABLE) {
+            let rows = read_table::<BinaryCoin>(storage, table)?;
+            state.coins = Some(rows.into_iter().map(Into::into).collect());
+        }
+        if let Some(table) = self.table(CONTRACTS_TABLE) {
+            let rows = read_table::<BinaryContract>(storage, table)?;
+            state.contracts = Some(rows.into_iter().map(Into::into).collect());
+        }
+        if let Some(table) = self.table(MESSAGES_TABLE) {
+            let rows = read_table::<BinaryMessage>(storage, table)?;
+            state.messages = Some(rows.into_iter().map(Into::into).collect());
+        }
+        Ok(state)
+    }
+
+    /// Streams the state slots of the contracts in the order they were written.
+    pub fn contract_state(&self) -> TableRows<ContractStateRow> {
+        self.rows(CONTRACT_STATE_TABLE)
+    }
+
+    /// Streams the balances of the contracts in the order they were written.
+    pub fn contract_balances(&self) -> TableRows<ContractBalanceRow> {
+        self.rows(CONTRACT_BALANCES_TABLE)
+    }
+
+    fn table(&self, name: &str) -> Option<&SnapshotTable> {
+        self.manifest.tables.iter().find(|table| table.name == name)
+    }
+
+    fn rows<T>(&self, name: &str) -> TableRows<T> {
+        let files = self
+            .table(name)
+            .map(|table| table.files.clone())
+            .unwrap_or_default();
+        TableRows {
+            storage: self.storage.clone(),
+            files: files.into_iter(),
+            file: None,
+            failed: false,
+            _row: PhantomData,
+        }
+    }
+}
+
+/// The file of the table being decoded by the [`TableRows`].
+struct OpenedFile {
+    name: String,
+    content: Vec<u8>,
+    offset: usize,
+    rows_left: u64,
+}
+
+/// The iterator over the rows of the table, which reads and verifies
+/// one file at a time. It stops after the first error.
+pub struct TableRows<T> {
+    storage: Arc<dyn SnapshotStorage>,
+    files: std::vec::IntoIter<SnapshotFile>,
+    file: Option<OpenedFile>,
+    failed: bool,
+    _row: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> TableRows<T> {
+    fn next_row(&mut self) -> anyhow::Result<Option<T>> {
+        loop {
+            if let Some(file) = &mut self.file {
+                if file.rows_left > 0 {
+                    let bytes = file.content.get(file.offset..).unwrap_or_default();
+                    let (row, rest) = postcard::take_from_bytes(bytes).map_err(|e| {
+                        anyhow!("failed to decode the row of {}: {e}", file.name)
+                    })?;
+                    file.offset = file.content.len().saturating_sub(rest.len());
+                    file.rows_left = file.rows_left.saturating_sub(1);
+                    return Ok(Some(row))
+                }
+                ensure!(
+                    file.offset == file.content.len(),
+                    "{} has more rows than the manifest lists",
+                    file.name
+                );
+            }
+            let Some(next) = self.files.next() else {
+                self.file = None;
+                return Ok(None)
+            };
+            self.file = Some(OpenedFile {
+                content: next.read(self.storage.as_ref())?,
+                name: next.name,
+                offset: 0,
+                rows_left: next.rows,
+            });
+        }
+    }
+}
+
+impl<T: DeserializeOwned> Iterator for TableRows<T> {
+    type Item = anyhow::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None
+        }
+        let row = self.next_row();
+        self.failed = row.is_err();
+        row.transpose()
+    }
+}
+
+/// The contract without the state slots and the balances, which are written
+/// into their own tables. The missing state and balances stay missing.
+fn without_slots(contract: &ContractConfig) -> ContractConfig {
+    ContractConfig {
+        contract_id: contract.contract_id,
+        code: contract.code.clone(),
+        salt: contract.salt,
+        state: contract.state.as_ref().map(|_| vec![]),
+        balances: contract.balances.as_ref().map(|_| vec![]),
+        tx_id: contract.tx_id,
+        output_index: contract.output_index,
+        tx_pointer_block_height: contract.tx_pointer_block_height,
+        tx_pointer_tx_idx: contract.tx_pointer_tx_idx,
+    }
+}
+
+/// Writes the rows into the files `{table}.{index}.bin`. Each file is the concatenation
+/// of the rows encoded with `postcard`.
+fn write_table<T: Serialize>(
+    storage: &dyn SnapshotStorage,
+    table: &str,
+    rows: impl Iterator<Item = anyhow::Result<T>>,
+    limits: PartitionLimits,
+) -> anyhow::Result<SnapshotTable> {
+    let mut files = vec![];
+    let mut content = vec![];
+    let mut content_rows = 0usize;
+    let mut flush = |content: &mut Vec<u8>, content_rows: &mut usize| {
+        let name = format!("{table}.{:05}.bin", files.len());
+        storage.write(&name, content)?;
+        files.push(SnapshotFile::new(name, *content_rows, content));
+        content.clear();
+        *content_rows = 0;
+        Ok::<_, anyhow::Error>(())
+    };
+
+    for row in rows {
+        let row = postcard::to_allocvec(&row?)
+            .map_err(|e| anyhow!("failed to encode the row of {table}: {e}"))?;
+        let full = content_rows >= limits.max_rows
+            || content.len().saturating_add(row.len()) > limits.max_bytes;
+        if content_rows > 0 && full {
+            flush(&mut content, &mut content_rows)?;
+        }
+        content.extend_from_slice(&row);
+        content_rows = content_rows.saturating_add(1);
+    }
+    if content_rows > 0 {
+        flush(&mut content, &mut content_rows)?;
+    }
+
+    Ok(SnapshotTable {
+        name: table.to_string(),
+        files,
+    })
+}
+
+/// Reads the files of the table, each thread decodes its own range of the files.
+fn read_table<T: DeserializeOwned + Send>(
+    storage: &dyn SnapshotStorage,
+    table: &SnapshotTable,
+) -> anyhow::Result<Vec<T>> {
+    let threads = std::thread::available_parallelism()
+        .map(usize::from)
+        .unwrap_or(1);
+    let chunk_size = table
+        .files
+        .len()
+        .saturating_add(threads.saturating_sub(1))
+        .checked_div(threads)
+        .unwrap_or(1)
+        .max(1);
+
+    let chunks = std::thread::scope(|scope| {
+        let handles: Vec<_> = table
+            .files
+            .chunks(chunk_size)
+            .map(|files| {
+                scope.spawn(move || {
+                    let mut rows = vec![];
+                    for file in files {
+                        rows.extend(read_rows::<T>(storage, file)?);
+                    }
+                    Ok::<_, anyhow::Error>(rows)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .map_err(|_| anyhow!("the reader of {} panicked", table.name))?
+            })
+            .collect::<anyhow::Result<Vec<_>>>()
+    })?;
+
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+fn read_rows<T: DeserializeOwned>(
+    storage: &dyn SnapshotStorage,
+    file: &SnapshotFile,
+) -> anyhow::Result<Vec<T>> {
+    let content = file.read(storage)?;
+    let mut bytes = content.as_slice();
+    let mut rows = vec![];
+    for _ in 0..file.rows {
+        let (row, rest) = postcard::take_from_bytes(bytes)
+            .map_err(|e| anyhow!("failed to decode the row of {}: {e}", file.name))?;
+        rows.push(row);
+        bytes = rest;
+    }
+    ensure!(
+        bytes.is_empty(),
+        "{} has more rows than the manifest lists",
+        file.name
+    );
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        CoinConfig,
+        ContractConfig,
+    };
+
+    fn directory(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "fuel-core-partitioned-{name}-{}",
+            std::process::id()
+        ))
+    }
+
+    /// Reads the snapshot and attaches the streamed slots to their contracts.
+    fn read(directory: &Path) -> StateConfig {
+        let snapshot = PartitionedSnapshot::open(directory).unwrap();
+        let mut state = snapshot.state_config().unwrap();
+        let contracts = state.contracts.iter_mut().flatten();
+        let mut contracts: std::collections::HashMap<_, _> = contracts
+            .map(|contract| (contract.contract_id, contract))
+            .collect();
+        for row in snapshot.contract_state() {
+            let row = row.unwrap();
+            let contract = contracts.get_mut(&row.contract_id).unwrap();
+            contract
+                .state
+                .get_or_insert_with(Vec::new)
+                .push((row.key, row.value));
+        }
+        for row in snapshot.contract_balances() {
+            let row = row.unwrap();
+            let contract = contracts.get_mut(&row.contract_id).unwrap();
+            contract
+                .balances
+                .get_or_insert_with(Vec::new)
+                .push((row.asset_id, row.amount));
+        }
+        state
+    }
+
+    fn state() -> StateConfig {
+        StateConfig {
+            coins: Some(
+                (0..5u8)
+                    .map(|i| CoinConfig {
+                        owner: [i; 32].into(),
+                        amount: i.into(),
+                        ..Default::default()
+                    })
+                    .collect(),
+            ),
+            contracts: Some(vec![]),
+            height: Some(10u32.into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn tables_roll_over_to_new_files() {
+        // Given
+        let directory = directory("roll-over");
+        let limits = PartitionLimits {
+            max_rows: 2,
+            ..Default::default()
+        };
+
+        // When
+        let manifest = state().write_partitioned(&directory, limits).unwrap();
+
+        // Then
+        let coins = &manifest.tables[0];
+        assert_eq!(coins.name, COINS_TABLE);
+        let rows: Vec<_> = coins.files.iter().map(|file| file.rows).collect();
+        assert_eq!(rows, vec![2, 2, 1]);
+        let contracts = &manifest.tables[1];
+        assert!(contracts.files.is_empty());
+        assert_eq!(read(&directory), state());
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn single_row_larger_than_limit_gets_own_file() {
+        let directory = directory("large-row");
+        let state = StateConfig {
+            contracts: Some(vec![
+                ContractConfig {
+                    code: vec![1; 64],
+                    ..Default::default()
+                },
+                ContractConfig {
+                    code: vec![2; 64],
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        };
+        let limits = PartitionLimits {
+            max_bytes: 32,
+            ..Default::default()
+        };
+
+        let manifest = state.write_partitioned(&directory, limits).unwrap();
+
+        assert_eq!(manifest.tables[0].files.len(), 2);
+        assert_eq!(read(&directory), state);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn contract_slots_are_split_into_own_tables() {
+        // Given
+        let directory = directory("contract-slots");
+        let state = StateConfig {
+            contracts: Some(vec![
+                ContractConfig {
+                    contract_id: [1; 32].into(),
+                    state: Some((0..5u8).map(|i| ([i; 32].into(), vec![i])).collect()),
+                    balances: Some(vec![([2; 32].into(), 3)]),
+                    ..Default::default()
+                },
+                ContractConfig {
+                    contract_id: [4; 32].into(),
+                    state: Some(vec![]),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        };
+        let limits = PartitionLimits {
+            max_rows: 2,
+            ..Default::default()
+        };
+
+        // When
+        let manifest = state.write_partitioned(&directory, limits).unwrap();
+
+        // Then
+        let rows = |name: &str| -> Vec<u64> {
+            let table = manifest.tables.iter().find(|t| t.name == name).unwrap();
+            table.files.iter().map(|file| file.rows).collect()
+        };
+        assert_eq!(rows(CONTRACTS_TABLE), vec![2]);
+        assert_eq!(rows(CONTRACT_STATE_TABLE), vec![2, 2, 1]);
+        assert_eq!(rows(CONTRACT_BALANCES_TABLE), vec![1]);
+        assert_eq!(read(&directory), state);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn corrupted_file_is_rejected() {
+        // Given
+        let directory = directory("corrupted");
+        let limits = PartitionLimits {
+            max_rows: 2,
+            ..Default::default()
+        };
+        let manifest = state().write_partitioned(&directory, limits).unwrap();
+        let file = &manifest.tables[0].files[1];
+        let mut content = fs::read(directory.join(&file.name)).unwrap();
+        content[0] ^= 1;
+        fs::write(directory.join(&file.name), content).unwrap();
+
+        // When
+        let err = PartitionedSnapshot::open(&directory)
+            .unwrap()
+            .state_config()
+            .unwrap_err();
+
+        // Then
+        assert!(err.to_string().contains("checksum"), "{err}");
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn contract_slots_are_streamed_until_the_corrupted_file() {
+        // Given
+        let directory = directory("streamed-slots");
+        let state = StateConfig {
+            contracts: Some(vec![ContractConfig {
+                contract_id: [1; 32].into(),
+                state: Some((0..4u8).map(|i| ([i; 32].into(), vec![i])).collect()),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        };
+        let limits = PartitionLimits {
+            max_rows: 2,
+            ..Default::default()
+        };
+        let manifest = state.write_partitioned(&directory, limits).unwrap();
+        let table = manifest
+            .tables
+            .iter()
+            .find(|table| table.name == CONTRACT_STATE_TABLE)
+            .unwrap();
+        let file = &table.files[1];
+        let mut content = fs::read(directory.join(&file.name)).unwrap();
+        content[0] ^= 1;
+        fs::write(directory.join(&file.name), content).unwrap();
+
+        // When
+        let rows: Vec<_> = PartitionedSnapshot::open(&directory)
+            .unwrap()
+            .contract_state()
+            .collect();
+
+        // Then
+        assert_eq!(rows.len(), 3);
+        let keys: Vec<_> = rows[..2]
+            .iter()
+            .map(|row| row.as_ref().unwrap().key)
+            .collect();
+        assert_eq!(keys, vec![[0; 32].into(), [1; 32].into()]);
+        let err = rows[2].as_ref().unwrap_err();
+        assert!(err.to_string().contains("checksum"), "{err}");
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    #[test]
+    fn tables_are_written_from_streams() {
+        // Given
+        let directory = directory("streams");
+        let contract_id: ContractId = [1; 32].into();
+        let mut writer =
+            PartitionedSnapshotWriter::new(&directory, PartitionLimits::default())
+                .unwrap();
+
+        // When
+        writer
+            .write_contracts(std::iter::once(Ok(ContractConfig {
+                contract_id,
+                ..Default::default()
+            })))
+            .unwrap();
+        writer
+            .write_contract_balances((0..3u8).map(|i| {
+                Ok(ContractBalanceRow {
+                    contract_id,
+                    asset_id: [i; 32].into(),
+                    amount: i.into(),
+                })
+            }))
+            .unwrap();
+        let rewritten = writer.write_contracts(std::iter::empty());
+        writer.finish(&StateConfig::default()).unwrap();
+
+        // Then
+        assert!(rewritten.is_err());
+        let snapshot = PartitionedSnapshot::open(&directory).unwrap();
+        let state = snapshot.state_config().unwrap();
+        assert_eq!(state.contracts.unwrap().len(), 1);
+        assert!(state.coins.is_none());
+        let amounts: Vec<_> = snapshot
+            .contract_balances()
+            .map(|row| row.unwrap().amount)
+            .collect();
+        assert_eq!(amounts, vec![0, 1, 2]);
+        assert_eq!(snapshot.contract_state().count(), 0);
+        fs::remove_dir_all(directory).unwrap();
+    }
+
+    /// The storage keeping the files in memory, like the remote store.
+    #[derive(Debug, Default)]
+    struct MemoryStorage(std::sync::Mutex<std::collections::HashMap<String, Vec<u8>>>);
+
+    impl SnapshotStorage for MemoryStorage {
+        fn read(&self, name: &str) -> anyhow::Result<Vec<u8>> {
+            let files = self.0.lock().unwrap();
+            files
+                .get(name)
+                .cloned()
+                .ok_or_else(|| anyhow!("missing {name}"))
+        }
+
+        fn write(&self, name: &str, content: &[u8]) -> anyhow::Result<()> {
+            self.0
+                .lock()
+                .unwrap()
+                .insert(name.to_string(), content.to_vec());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn snapshot_is_written_to_and_read_from_the_storage() {
+        // Given
+        let storage = Arc::new(MemoryStorage::default());
+        let limits = PartitionLimits {
+            max_rows: 2,
+            ..Default::default()
+        };
+        let mut writer =
+            PartitionedSnapshotWriter::with_storage(storage.clone(), limits).unwrap();
+
+        // When
+        writer
+            .write_coins(state().coins.unwrap().into_iter().map(Ok))
+            .unwrap();
+        let manifest = writer.finish(&state()).unwrap();
+
+        // Then
+        let mut names: Vec<_> = storage.0.lock().unwrap().keys().cloned().collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "coins.00000.bin",
+                "coins.00001.bin",
+                "coins.00002.bin",
+                MANIFEST_FILE,
+                STATE_FILE
+            ]
+        );
+        let snapshot = PartitionedSnapshot::open_storage(storage).unwrap();
+        assert_eq!(snapshot.manifest(), &manifest);
+        let decoded = snapshot.state_config().unwrap();
+        assert_eq!(decoded.coins, state().coins);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::serialization::{
+    HexNumber,
+    HexType,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::{
+        Bytes32,
+        Nonce,
+    },
+    services::relayer::MessageOrigin,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use serde_with::serde_as;
+
+use super::message::MessageConfig;
+
+/// The state of the relayer ingested from the DA layer.
+/// The node started with it continues to sync the DA layer from the `da_block_height`
+/// instead of re-scanning the logs from the deployment of the bridge.
+#[serde_as]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct RelayerConfig {
+    /// The finalized height of the DA layer synced by the relayer.
+    #[serde_as(as = "HexNumber")]
+    pub da_block_height: DaBlockHeight,
+    /// The messages sent to the bridge up to the `da_block_height`,
+    /// including the ones already imported into the blocks.
+    pub messages: Vec<MessageConfig>,
+    /// The origins of the messages on the DA layer.
+    pub message_origins: Vec<MessageOriginConfig>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Eq, PartialEq)]
+pub struct MessageOriginConfig {
+    #[serde_as(as = "HexType")]
+    pub nonce: Nonce,
+    /// The height of the DA block with the transaction that sent the message.
+    #[serde_as(as = "HexNumber")]
+    pub da_height: DaBlockHeight,
+    /// The hash of the DA transaction that sent the message.
+    #[serde_as(as = "HexType")]
+    pub transaction_hash: Bytes32,
+}
+
+impl From<(Nonce, MessageOrigin)> for MessageOriginConfig {
+    fn from((nonce, origin): (Nonce, MessageOrigin)) -> Self {
+        Self {
+            nonce,
+            da_height: origin.da_height,
+            transaction_hash: origin.transaction_hash,
+        }
+    }
+}
+
+impl From<MessageOriginConfig> for (Nonce, MessageOrigin) {
+    fn from(config: MessageOriginConfig) -> Self {
+        let origin = MessageOrigin {
+            da_height: config.da_height,
+            transaction_hash: config.transaction_hash,
+        };
+        (config.nonce, origin)
+    }
+}
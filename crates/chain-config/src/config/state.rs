@@ -1,7 +1,17 @@
-use crate::serialization::HexNumber;
+use crate::serialization::{
+    HexNumber,
+    HexType,
+};
 
 use fuel_core_storage::Result as StorageResult;
-use fuel_core_types::fuel_types::BlockHeight;
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+    tai64::Tai64,
+};
 
 use serde::{
     Deserialize,
@@ -16,6 +26,7 @@ use super::{
     coin::CoinConfig,
     contract::ContractConfig,
     message::MessageConfig,
+    relayer::RelayerConfig,
 };
 
 // TODO: do streaming deserialization to handle large state configs
@@ -29,10 +40,29 @@ pub struct StateConfig {
     pub contracts: Option<Vec<ContractConfig>>,
     /// Messages from Layer 1
     pub messages: Option<Vec<MessageConfig>>,
+    /// Nonces of messages from Layer 1 that were already spent.
+    /// Prevents the regenesis chain from accepting the same messages again.
+    #[serde_as(as = "Option<Vec<HexType>>")]
+    #[serde(default)]
+    pub spent_messages: Option<Vec<Nonce>>,
+    /// The last processed block height of the DA layer.
+    /// Messages up to this height are already part of the state.
+    #[serde_as(as = "Option<HexNumber>")]
+    #[serde(default)]
+    pub da_block_height: Option<DaBlockHeight>,
     /// Starting block height (useful for flattened fork networks)
     #[serde_as(as = "Option<HexNumber>")]
     #[serde(default)]
     pub height: Option<BlockHeight>,
+    /// The timestamp of the genesis block. Blocks produced after the genesis
+    /// can't have a lower timestamp. If `None` then the unix epoch is used.
+    #[serde_as(as = "Option<HexNumber>")]
+    #[serde(default)]
+    pub timestamp: Option<Tai64>,
+    /// The state of the relayer, so the node doesn't re-scan the DA layer
+    /// up to the height already synced by the exported node.
+    #[serde(default)]
+    pub relayer: Option<RelayerConfig>,
 }
 
 impl StateConfig {
@@ -44,7 +74,12 @@ impl StateConfig {
             coins: db.get_coin_config()?,
             contracts: db.get_contract_config()?,
             messages: db.get_message_config()?,
+            spent_messages: db.get_spent_message_config()?,
+            da_block_height: Some(db.get_da_block_height()?),
             height: Some(db.get_block_height()?),
+            timestamp: Some(db.get_block_time()?),
+            // The relayer state is kept in the separate database.
+            relayer: None,
         })
     }
 }
@@ -56,6 +91,12 @@ pub trait ChainConfigDb {
     fn get_contract_config(&self) -> StorageResult<Option<Vec<ContractConfig>>>;
     /// Returns *all* unspent message configs available in the database.
     fn get_message_config(&self) -> StorageResult<Option<Vec<MessageConfig>>>;
+    /// Returns the nonces of *all* spent messages available in the database.
+    fn get_spent_message_config(&self) -> StorageResult<Option<Vec<Nonce>>>;
+    /// Returns the DA block height of the last available block.
+    fn get_da_block_height(&self) -> StorageResult<DaBlockHeight>;
     /// Returns the last available block height.
     fn get_block_height(&self) -> StorageResult<BlockHeight>;
+    /// Returns the timestamp of the last available block.
+    fn get_block_time(&self) -> StorageResult<Tai64>;
 }
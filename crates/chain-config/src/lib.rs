@@ -6,6 +6,7 @@
 pub mod config;
 pub mod fee_collection_contract;
 mod genesis;
+pub mod multisig_predicate;
 mod serialization;
 
 pub use config::*;
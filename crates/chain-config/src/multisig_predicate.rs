@@ -0,0 +1,371 @@
+//! The k-of-n multisig predicate shipped with the node.
+//!
+//! The coin owned by the predicate is spent if at least `threshold` of the `signers`
+//! signed the id of the transaction. The threshold and the signers are appended to the
+//! code of the predicate, so each set of the signers has its own address.
+//!
+//! The predicate data of the coin input contains one word per signer: the index of the
+//! witness with the signature of the signer, or `u64::MAX` if the signer didn't sign
+//! the transaction.
+
+use anyhow::{
+    anyhow,
+    bail,
+    ensure,
+};
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        GMArgs,
+        GTFArgs,
+        Instruction,
+        RegId,
+        Word,
+    },
+    fuel_tx::{
+        Address,
+        Input,
+        Witness,
+    },
+};
+
+/// The maximum number of the signers supported by the predicate.
+pub const MAX_SIGNERS: usize = 16;
+
+/// The word of the predicate data for the signer without the signature.
+const NO_SIGNATURE: Word = Word::MAX;
+
+const WORD_SIZE: usize = core::mem::size_of::<Word>();
+const SIGNATURE_SIZE: usize = 64;
+
+/// The number of the instructions of the predicate. The configuration follows them.
+const CODE_LEN: usize = 32;
+
+/// The code of the predicate without the configuration.
+fn code() -> Vec<u8> {
+    let config = 0x10;
+    let threshold = 0x11;
+    let signers_count = 0x12;
+    let signer = 0x13;
+    let input_index = 0x14;
+    let witness_indexes = 0x15;
+    let public_key = 0x16;
+    let hash = 0x17;
+    let index = 0x18;
+    let signatures = 0x19;
+    let public_key_size = 0x1a;
+    let hash_size = 0x1b;
+    let no_signature = 0x1c;
+    let condition = 0x1d;
+    let witness_index = 0x1e;
+    let signature = 0x1f;
+
+    // The indexes of the instructions used by the jumps.
+    let loop_start = 14;
+    let next_signer = 25;
+    let end = 28;
+
+    let code = vec![
+        // The configuration is placed right after the code.
+        op::addi(
+            config,
+            RegId::IS,
+            imm(CODE_LEN.saturating_mul(Instruction::SIZE)),
+        ),
+        op::lw(threshold, config, 0),
+        op::lw(signers_count, config, 1),
+        op::addi(signer, config, imm(WORD_SIZE.saturating_mul(2))),
+        // The predicate data with the witness indexes.
+        op::gm_args(input_index, GMArgs::GetVerifyingPredicate),
+        op::gtf_args(
+            witness_indexes,
+            input_index,
+            GTFArgs::InputCoinPredicateData,
+        ),
+        // The memory for the recovered public key and its hash.
+        op::move_(public_key, RegId::SP),
+        op::cfei(96),
+        op::addi(hash, public_key, imm(SIGNATURE_SIZE)),
+        op::movi(index, 0),
+        op::movi(signatures, 0),
+        op::movi(public_key_size, 64),
+        op::movi(hash_size, 32),
+        op::not(no_signature, RegId::ZERO),
+        // loop_start: stop after the last signer.
+        op::eq(condition, index, signers_count),
+        op::jnzi(condition, end),
+        op::lw(witness_index, witness_indexes, 0),
+        op::addi(witness_indexes, witness_indexes, imm(WORD_SIZE)),
+        op::eq(condition, witness_index, no_signature),
+        op::jnzi(condition, next_signer),
+        // The signature of the transaction id, which is placed at the start of the memory.
+        op::gtf_args(signature, witness_index, GTFArgs::WitnessData),
+        op::eck1(public_key, signature, RegId::ZERO),
+        op::s256(hash, public_key, public_key_size),
+        op::meq(condition, hash, signer, hash_size),
+        op::add(signatures, signatures, condition),
+        // next_signer:
+        op::addi(signer, signer, imm(Address::LEN)),
+        op::addi(index, index, 1),
+        op::ji(loop_start),
+        // end: the predicate is valid if enough signers signed the transaction.
+        op::lt(condition, signatures, threshold),
+        op::eq(condition, condition, RegId::ZERO),
+        op::ret(condition),
+        op::noop(),
+    ];
+    debug_assert_eq!(code.len(), CODE_LEN);
+
+    code.into_iter().collect()
+}
+
+/// The instance of the multisig predicate for the set of the signers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MultisigPredicate {
+    threshold: usize,
+    signers: Vec<Address>,
+}
+
+impl MultisigPredicate {
+    /// Creates the predicate accepting the signatures of at least
+    /// `threshold` of the `signers`.
+    pub fn new(threshold: usize, signers: Vec<Address>) -> anyhow::Result<Self> {
+        ensure!(
+            signers.len() <= MAX_SIGNERS,
+            "The predicate supports at most {MAX_SIGNERS} signers, but got {}",
+            signers.len()
+        );
+        ensure!(
+            threshold > 0 && threshold <= signers.len(),
+            "The threshold {threshold} must be between 1 and the number of the signers {}",
+            signers.len()
+        );
+        for (index, signer) in signers.iter().enumerate() {
+            ensure!(
+                !signers[..index].contains(signer),
+                "The signer {signer} is duplicated"
+            );
+        }
+
+        Ok(Self { threshold, signers })
+    }
+
+    /// Recognizes the instance of the multisig predicate by its bytecode.
+    pub fn from_bytecode(bytecode: &[u8]) -> Option<Self> {
+        let config = bytecode.strip_prefix(code().as_slice())?;
+        let (threshold, config) = read_word(config)?;
+        let (signers_count, signers) = read_word(config)?;
+        let signers_count = usize::try_from(signers_count).ok()?;
+        if signers.len() != signers_count.checked_mul(Address::LEN)? {
+            return None
+        }
+        let signers = signers
+            .chunks_exact(Address::LEN)
+            .map(|signer| {
+                Address::try_from(signer).expect("The chunk has the size of the address")
+            })
+            .collect();
+
+        Self::new(usize::try_from(threshold).ok()?, signers).ok()
+    }
+
+    /// The number of the signatures required to spend the coins.
+    pub fn threshold(&self) -> usize {
+        self.threshold
+    }
+
+    /// The addresses of the signers.
+    pub fn signers(&self) -> &[Address] {
+        &self.signers
+    }
+
+    /// The bytecode of the predicate with the threshold and the signers.
+    pub fn bytecode(&self) -> Vec<u8> {
+        let mut bytecode = code();
+        bytecode.extend((self.threshold as Word).to_be_bytes());
+        bytecode.extend((self.signers.len() as Word).to_be_bytes());
+        for signer in &self.signers {
+            bytecode.extend(signer.as_ref());
+        }
+        bytecode
+    }
+
+    /// The address owning the coins of the predicate.
+    pub fn address(&self) -> Address {
+        Input::predicate_owner(self.bytecode())
+    }
+
+    /// Returns the predicate data for the `witness_indexes`, where the `i`-th element is
+    /// the index of the witness with the signature of the `i`-th signer, if it signed
+    /// the transaction.
+    pub fn predicate_data(
+        &self,
+        witness_indexes: &[Option<u8>],
+    ) -> anyhow::Result<Vec<u8>> {
+        ensure!(
+            witness_indexes.len() == self.signers.len(),
+            "Expected a witness index for each of {} signers, but got {}",
+            self.signers.len(),
+            witness_indexes.len()
+        );
+
+        Ok(witness_indexes
+            .iter()
+            .flat_map(|index| index.map(Word::from).unwrap_or(NO_SIGNATURE).to_be_bytes())
+            .collect())
+    }
+
+    /// Checks the layout of the `predicate_data` and the `witnesses` of the transaction:
+    /// each referenced witness exists and has the size of the signature, and at least
+    /// `threshold` signers are referenced. The signatures are verified by the predicate.
+    pub fn check_witnesses(
+        &self,
+        predicate_data: &[u8],
+        witnesses: &[Witness],
+    ) -> anyhow::Result<()> {
+        let expected_size = self.signers.len().saturating_mul(WORD_SIZE);
+        ensure!(
+            predicate_data.len() == expected_size,
+            "The predicate data must contain {} witness indexes, but has {} bytes",
+            self.signers.len(),
+            predicate_data.len()
+        );
+
+        let mut signatures = 0usize;
+        for (signer, index) in self
+            .signers
+            .iter()
+            .zip(predicate_data.chunks_exact(WORD_SIZE))
+        {
+            let index = Word::from_be_bytes(
+                index
+                    .try_into()
+                    .expect("The chunk has the size of the word"),
+            );
+            if index == NO_SIGNATURE {
+                continue
+            }
+            let witness = usize::try_from(index)
+                .ok()
+                .and_then(|index| witnesses.get(index))
+                .ok_or_else(|| {
+                    anyhow!("The witness {index} of the signer {signer} doesn't exist")
+                })?;
+            if witness.as_vec().len() != SIGNATURE_SIZE {
+                bail!(
+                    "The witness {index} of the signer {signer} must be a signature of \
+                     {SIGNATURE_SIZE} bytes, but has {} bytes",
+                    witness.as_vec().len()
+                )
+            }
+            signatures = signatures.saturating_add(1);
+        }
+
+        ensure!(
+            signatures >= self.threshold,
+            "Only {signatures} signers provided the signatures, but the threshold is {}",
+            self.threshold
+        );
+        Ok(())
+    }
+}
+
+fn imm(value: usize) -> u16 {
+    u16::try_from(value).expect("The immediate value is small")
+}
+
+fn read_word(bytes: &[u8]) -> Option<(Word, &[u8])> {
+    if bytes.len() < WORD_SIZE {
+        return None
+    }
+    let (word, rest) = bytes.split_at(WORD_SIZE);
+    Some((Word::from_be_bytes(word.try_into().ok()?), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signers(count: u8) -> Vec<Address> {
+        (1..=count).map(|i| Address::new([i; 32])).collect()
+    }
+
+    fn signature() -> Witness {
+        vec![0; SIGNATURE_SIZE].into()
+    }
+
+    #[test]
+    fn predicate_is_recognized_by_bytecode() {
+        // Given
+        let predicate = MultisigPredicate::new(2, signers(3)).unwrap();
+
+        // When
+        let recognized = MultisigPredicate::from_bytecode(&predicate.bytecode());
+
+        // Then
+        assert_eq!(recognized, Some(predicate));
+    }
+
+    #[test]
+    fn different_signers_have_different_addresses() {
+        let first = MultisigPredicate::new(2, signers(3)).unwrap();
+        let second = MultisigPredicate::new(1, signers(3)).unwrap();
+        let third = MultisigPredicate::new(2, signers(4)).unwrap();
+
+        assert_ne!(first.address(), second.address());
+        assert_ne!(first.address(), third.address());
+    }
+
+    #[test]
+    fn invalid_threshold_is_rejected() {
+        assert!(MultisigPredicate::new(0, signers(3)).is_err());
+        assert!(MultisigPredicate::new(4, signers(3)).is_err());
+        assert!(MultisigPredicate::new(1, vec![Address::zeroed(); 2]).is_err());
+    }
+
+    #[test]
+    fn other_predicates_are_not_recognized() {
+        assert_eq!(MultisigPredicate::from_bytecode(&[]), None);
+        assert_eq!(MultisigPredicate::from_bytecode(&code()), None);
+    }
+
+    #[test]
+    fn witnesses_with_enough_signatures_pass_the_check() {
+        // Given
+        let predicate = MultisigPredicate::new(2, signers(3)).unwrap();
+        let data = predicate.predicate_data(&[Some(1), None, Some(0)]).unwrap();
+
+        // When
+        let result = predicate.check_witnesses(&data, &[signature(), signature()]);
+
+        // Then
+        assert!(result.is_ok(), "{result:?}");
+    }
+
+    #[test]
+    fn witnesses_below_threshold_fail_the_check() {
+        // Given
+        let predicate = MultisigPredicate::new(2, signers(3)).unwrap();
+        let data = predicate.predicate_data(&[Some(0), None, None]).unwrap();
+
+        // When
+        let result = predicate.check_witnesses(&data, &[signature()]);
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn missing_or_malformed_witnesses_fail_the_check() {
+        let predicate = MultisigPredicate::new(1, signers(2)).unwrap();
+        let data = predicate.predicate_data(&[Some(1), None]).unwrap();
+
+        assert!(predicate.check_witnesses(&data, &[signature()]).is_err());
+        assert!(predicate
+            .check_witnesses(&data, &[signature(), vec![0; 32].into()])
+            .is_err());
+        assert!(predicate
+            .check_witnesses(&data[..8], &[signature()])
+            .is_err());
+    }
+}
@@ -5,6 +5,7 @@ use fuel_core_types::{
         bytes::WORD_SIZE,
         BlockHeight,
     },
+    tai64::Tai64,
 };
 use serde::{
     de::Error,
@@ -60,6 +61,25 @@ impl<'de> DeserializeAs<'de, DaBlockHeight> for HexNumber {
     }
 }
 
+impl SerializeAs<Tai64> for HexNumber {
+    fn serialize_as<S>(value: &Tai64, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        HexNumber::serialize_as(&value.0, serializer)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Tai64> for HexNumber {
+    fn deserialize_as<D>(deserializer: D) -> Result<Tai64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let number: u64 = HexNumber::deserialize_as(deserializer)?;
+        Ok(Tai64(number))
+    }
+}
+
 pub(crate) struct HexType;
 
 impl<T: AsRef<[u8]>> SerializeAs<T> for HexType {
@@ -1,4 +1,9 @@
 use crate::client::{
+    error::{
+        ErrorExtensions,
+        ResponseError,
+        ResponseErrors,
+    },
     schema::{
         block::BlockByHeightArgs,
         coins::{
@@ -14,7 +19,10 @@ use crate::client::{
     },
     types::{
         gas_price::LatestGasPrice,
-        message::MessageStatus,
+        message::{
+            MessageStatus,
+            MessageStatusDetails,
+        },
         primitives::{
             Address,
             AssetId,
@@ -28,13 +36,14 @@ use anyhow::Context;
 #[cfg(feature = "subscriptions")]
 use cynic::StreamingOperation;
 use cynic::{
-    http::ReqwestExt,
     GraphQlResponse,
     Id,
     MutationBuilder,
     Operation,
     QueryBuilder,
 };
+#[cfg(feature = "subscriptions")]
+use fuel_core_types::services::txpool::EvictedTransaction;
 use fuel_core_types::{
     fuel_asm::{
         Instruction,
@@ -52,7 +61,10 @@ use fuel_core_types::{
         Nonce,
     },
     services::{
-        executor::TransactionExecutionStatus,
+        executor::{
+            StateOverrides,
+            TransactionExecutionStatus,
+        },
         p2p::PeerInfo,
     },
 };
@@ -70,6 +82,9 @@ use schema::{
     coins::CoinByIdArgs,
     contract::ContractByIdArgs,
     tx::{
+        transparent_receipt::ReceiptsFilter,
+        StatusChangeArgs,
+        SubmitAndAwaitArgs,
         TxArg,
         TxIdArgs,
     },
@@ -120,6 +135,7 @@ use self::schema::{
     },
 };
 
+pub mod error;
 pub mod pagination;
 pub mod schema;
 pub mod types;
@@ -132,6 +148,7 @@ pub struct FuelClient {
     #[cfg(feature = "subscriptions")]
     cookie: std::sync::Arc<reqwest::cookie::Jar>,
     url: reqwest::Url,
+    admin_token: Option<String>,
 }
 
 impl FromStr for FuelClient {
@@ -159,13 +176,18 @@ impl FromStr for FuelClient {
                 client,
                 cookie,
                 url,
+                admin_token: None,
             })
         }
 
         #[cfg(not(feature = "subscriptions"))]
         {
             let client = reqwest::Client::new();
-            Ok(Self { client, url })
+            Ok(Self {
+                client,
+                url,
+                admin_token: None,
+            })
         }
     }
 }
@@ -183,14 +205,11 @@ where
 }
 
 pub fn from_strings_errors_to_std_error(errors: Vec<String>) -> io::Error {
-    let e = errors
+    let errors = errors
         .into_iter()
-        .fold(String::from("Response errors"), |mut s, e| {
-            s.push_str("; ");
-            s.push_str(e.as_str());
-            s
-        });
-    io::Error::new(io::ErrorKind::Other, e)
+        .map(|message| ResponseError::new(message, None))
+        .collect();
+    ResponseErrors(errors).into()
 }
 
 impl FuelClient {
@@ -198,6 +217,12 @@ impl FuelClient {
         Self::from_str(url.as_ref())
     }
 
+    /// Sets the token authorizing the admin endpoints.
+    pub fn with_admin_token(mut self, token: impl Into<String>) -> Self {
+        self.admin_token = Some(token.into());
+        self
+    }
+
     /// Send the GraphQL query to the client.
     pub async fn query<ResponseData, Vars>(
         &self,
@@ -207,25 +232,52 @@ impl FuelClient {
         Vars: serde::Serialize,
         ResponseData: serde::de::DeserializeOwned + 'static,
     {
-        let response = self
-            .client
-            .post(self.url.clone())
-            .run_graphql(q)
+        let mut request = self.client.post(self.url.clone());
+        if let Some(token) = &self.admin_token {
+            request = request.bearer_auth(token);
+        }
+        // The request is sent without `cynic::http::ReqwestExt` to keep the
+        // extensions of the errors with their codes.
+        let response = request
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(serde_json::to_vec(&q)?)
+            .send()
             .await
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+        let response = match serde_json::from_str::<
+            GraphQlResponse<ResponseData, ErrorExtensions>,
+        >(&body)
+        {
+            Ok(response) => response,
+            Err(_) if !status.is_success() => {
+                return Err(io::Error::new(
+                    io::ErrorKind::Other,
+                    format!("Server returned {status}: {body}"),
+                ))
+            }
+            Err(e) => return Err(io::Error::new(io::ErrorKind::Other, e)),
+        };
 
         Self::decode_response(response)
     }
 
-    fn decode_response<R>(response: GraphQlResponse<R>) -> io::Result<R>
+    fn decode_response<R>(response: GraphQlResponse<R, ErrorExtensions>) -> io::Result<R>
     where
         R: serde::de::DeserializeOwned + 'static,
     {
         match (response.data, response.errors) {
             (Some(d), _) => Ok(d),
-            (_, Some(e)) => Err(from_strings_errors_to_std_error(
-                e.into_iter().map(|e| e.message).collect(),
-            )),
+            (_, Some(e)) => Err(ResponseErrors(
+                e.into_iter()
+                    .map(|e| ResponseError::new(e.message, e.extensions))
+                    .collect(),
+            )
+            .into()),
             _ => Err(io::Error::new(io::ErrorKind::Other, "Invalid response")),
         }
     }
@@ -299,7 +351,9 @@ impl FuelClient {
                 tracing::debug!("Got result: {result:?}");
                 let r = match result {
                     Ok(es::SSE::Event(es::Event { data, .. })) => {
-                        match serde_json::from_str::<GraphQlResponse<ResponseData>>(&data)
+                        match serde_json::from_str::<
+                            GraphQlResponse<ResponseData, ErrorExtensions>,
+                        >(&data)
                         {
                             Ok(resp) => {
                                 match Self::decode_response(resp) {
@@ -398,6 +452,7 @@ impl FuelClient {
             schema::tx::DryRun::build(DryRunArg {
                 txs,
                 utxo_validation,
+                state_overrides: None,
             });
         let tx_statuses = self.query(query).await.map(|r| r.dry_run)?;
         tx_statuses
@@ -406,6 +461,66 @@ impl FuelClient {
             .collect()
     }
 
+    /// Dry run the transactions in order on the shared temporary state and return
+    /// the changes of the state made by each of them
+    pub async fn dry_run_with_state_diff(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+    ) -> io::Result<Vec<types::DryRunResult>> {
+        self.dry_run_with_state_overrides(txs, utxo_validation, None)
+            .await
+    }
+
+    /// Dry run the transactions like [`Self::dry_run_with_state_diff`], but apply the
+    /// `state_overrides` to the temporary state first. It allows simulating the
+    /// transactions without funding the accounts or deploying the contracts.
+    pub async fn dry_run_with_state_overrides(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        state_overrides: Option<StateOverrides>,
+    ) -> io::Result<Vec<types::DryRunResult>> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query = schema::tx::DryRunWithStateDiff::build(DryRunArg {
+            txs,
+            utxo_validation,
+            state_overrides: state_overrides.map(Into::into),
+        });
+        let results = self.query(query).await.map(|r| r.dry_run)?;
+        results
+            .into_iter()
+            .map(|result| result.try_into().map_err(Into::into))
+            .collect()
+    }
+
+    /// Dry run the transactions like [`Self::dry_run_with_state_overrides`], and return
+    /// the gas spent by them, aggregated per contract and opcode.
+    /// Requires the node to be started in the debug mode.
+    pub async fn profile_gas(
+        &self,
+        txs: &[Transaction],
+        // Disable utxo input checks (exists, unspent, and valid signature)
+        utxo_validation: Option<bool>,
+        state_overrides: Option<StateOverrides>,
+    ) -> io::Result<types::GasProfile> {
+        let txs = txs
+            .iter()
+            .map(|tx| HexString(Bytes(tx.to_bytes())))
+            .collect::<Vec<HexString>>();
+        let query = schema::tx::ProfileGasQuery::build(DryRunArg {
+            txs,
+            utxo_validation,
+            state_overrides: state_overrides.map(Into::into),
+        });
+        self.query(query).await.map(|r| r.profile_gas.into())
+    }
+
     /// Estimate predicates for the transaction
     pub async fn estimate_predicates(&self, tx: &mut Transaction) -> io::Result<()> {
         let serialized_tx = tx.to_bytes();
@@ -418,6 +533,18 @@ impl FuelClient {
         Ok(())
     }
 
+    /// Estimates the predicates, the script gas limit and the max fee of the transaction
+    /// and replaces it with the provisioned version that is ready to be signed.
+    pub async fn estimate_and_provision(&self, tx: &mut Transaction) -> io::Result<()> {
+        let serialized_tx = tx.to_bytes();
+        let query = schema::tx::EstimateAndProvision::build(TxArg {
+            tx: HexString(Bytes(serialized_tx)),
+        });
+        let provisioned_tx = self.query(query).await.map(|r| r.estimate_and_provision)?;
+        *tx = provisioned_tx.try_into()?;
+        Ok(())
+    }
+
     pub async fn submit(
         &self,
         tx: &Transaction,
@@ -431,6 +558,26 @@ impl FuelClient {
         Ok(id)
     }
 
+    /// Submits the transaction with the hint ordering it among the pending
+    /// transactions of the owner of its first coin or message input.
+    /// The node includes it after the owner's transactions with the lower hints.
+    pub async fn submit_with_ordering_hint(
+        &self,
+        tx: &Transaction,
+        ordering_hint: u64,
+    ) -> io::Result<types::primitives::TransactionId> {
+        let tx = tx.clone().to_bytes();
+        let query = schema::tx::SubmitWithOrderingHint::build(
+            schema::tx::TxWithOrderingHintArg {
+                tx: HexString(Bytes(tx)),
+                ordering_hint: Some(ordering_hint.into()),
+            },
+        );
+
+        let id = self.query(query).await.map(|r| r.submit)?.id.into();
+        Ok(id)
+    }
+
     /// Submit the transaction and wait for it either to be included in
     /// a block or removed from `TxPool`.
     ///
@@ -440,11 +587,24 @@ impl FuelClient {
     pub async fn submit_and_await_commit(
         &self,
         tx: &Transaction,
+    ) -> io::Result<TransactionStatus> {
+        self.submit_and_await_commit_with_receipts_filter(tx, None)
+            .await
+    }
+
+    /// Similar to [`Self::submit_and_await_commit`], but the final status contains
+    /// only the receipts matching the `receipts_filter`.
+    #[cfg(feature = "subscriptions")]
+    pub async fn submit_and_await_commit_with_receipts_filter(
+        &self,
+        tx: &Transaction,
+        receipts_filter: Option<ReceiptsFilter>,
     ) -> io::Result<TransactionStatus> {
         use cynic::SubscriptionBuilder;
         let tx = tx.clone().to_bytes();
-        let s = schema::tx::SubmitAndAwaitSubscription::build(TxArg {
+        let s = schema::tx::SubmitAndAwaitSubscription::build(SubmitAndAwaitArgs {
             tx: HexString(Bytes(tx)),
+            receipts_filter,
         });
 
         let mut stream = self.subscribe(s).await?.map(
@@ -577,6 +737,34 @@ impl FuelClient {
         Ok(transaction.map(|tx| tx.try_into()).transpose()?)
     }
 
+    /// Get the transaction that spent the coin with the `utxo_id`
+    pub async fn spending_transaction(
+        &self,
+        utxo_id: &UtxoId,
+    ) -> io::Result<Option<TransactionResponse>> {
+        let query = schema::tx::SpendingTransactionQuery::build(CoinByIdArgs {
+            utxo_id: (*utxo_id).into(),
+        });
+
+        let transaction = self.query(query).await?.spending_transaction;
+
+        Ok(transaction.map(|tx| tx.try_into()).transpose()?)
+    }
+
+    /// Get the transaction that consumed the message with the `nonce`
+    pub async fn message_spending_transaction(
+        &self,
+        nonce: &Nonce,
+    ) -> io::Result<Option<TransactionResponse>> {
+        let query = schema::tx::MessageSpendingTransactionQuery::build(NonceArgs {
+            nonce: (*nonce).into(),
+        });
+
+        let transaction = self.query(query).await?.message_spending_transaction;
+
+        Ok(transaction.map(|tx| tx.try_into()).transpose()?)
+    }
+
     /// Get the status of a transaction
     pub async fn transaction_status(&self, id: &TxId) -> io::Result<TransactionStatus> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
@@ -606,10 +794,26 @@ impl FuelClient {
     pub async fn subscribe_transaction_status(
         &self,
         id: &TxId,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<TransactionStatus>>> {
+        self.subscribe_transaction_status_with_receipts_filter(id, None)
+            .await
+    }
+
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the status of a transaction. The final status contains
+    /// only the receipts matching the `receipts_filter`.
+    pub async fn subscribe_transaction_status_with_receipts_filter(
+        &self,
+        id: &TxId,
+        receipts_filter: Option<ReceiptsFilter>,
     ) -> io::Result<impl futures::Stream<Item = io::Result<TransactionStatus>>> {
         use cynic::SubscriptionBuilder;
         let tx_id: TransactionId = (*id).into();
-        let s = schema::tx::StatusChangeSubscription::build(TxIdArgs { id: tx_id });
+        let s = schema::tx::StatusChangeSubscription::build(StatusChangeArgs {
+            id: tx_id,
+            receipts_filter,
+        });
 
         tracing::debug!("subscribing");
         let stream = self.subscribe(s).await?.map(|tx| {
@@ -622,6 +826,73 @@ impl FuelClient {
         Ok(stream)
     }
 
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the transfers of the `owner`, optionally only of the `asset_id`,
+    /// from the blocks imported after the subscription.
+    pub async fn subscribe_transfer_events(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::TransferEvent>>> {
+        use cynic::SubscriptionBuilder;
+        let filter = schema::transfer::TransferEventFilterInput {
+            owner: (*owner).into(),
+            asset_id: asset_id.map(|id| (*id).into()),
+        };
+        let s = schema::transfer::TransferEventsSubscription::build(
+            schema::transfer::TransferEventsSubscriptionArgs { filter },
+        );
+
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|event| Ok(event?.transfer_events.into()));
+
+        Ok(stream)
+    }
+
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the transactions touching the `address` from the blocks
+    /// imported after the subscription.
+    pub async fn subscribe_address_activity(
+        &self,
+        address: &Address,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<types::AddressActivity>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::activity::AddressActivitySubscription::build(
+            schema::activity::AddressActivitySubscriptionArgs {
+                address: (*address).into(),
+            },
+        );
+
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|activity| Ok(activity?.activity_by_address.into()));
+
+        Ok(stream)
+    }
+
+    #[tracing::instrument(skip(self), level = "debug")]
+    #[cfg(feature = "subscriptions")]
+    /// Subscribe to the transactions evicted from the txpool without the inclusion
+    /// into the block after the subscription.
+    pub async fn subscribe_evicted_transactions(
+        &self,
+    ) -> io::Result<impl futures::Stream<Item = io::Result<EvictedTransaction>>> {
+        use cynic::SubscriptionBuilder;
+        let s = schema::tx::EvictedTransactionsSubscription::build(());
+
+        let stream = self
+            .subscribe(s)
+            .await?
+            .map(|event| Ok(event?.evicted_transactions.into()));
+
+        Ok(stream)
+    }
+
     #[cfg(feature = "subscriptions")]
     /// Awaits for the transaction to be committed into a block
     ///
@@ -675,6 +946,48 @@ impl FuelClient {
         Ok(transactions)
     }
 
+    /// Returns a paginated set of transactions waiting in the pool, optionally only
+    /// the ones touching the `owner` and the `contract`.
+    pub async fn mempool(
+        &self,
+        owner: Option<&Address>,
+        contract: Option<&ContractId>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::MempoolTransaction, String>> {
+        let filter = (owner.is_some() || contract.is_some()).then(|| {
+            schema::mempool::MempoolFilterInput {
+                owner: owner.map(|owner| (*owner).into()),
+                contract: contract.map(|contract| (*contract).into()),
+            }
+        });
+        let query = schema::mempool::MempoolQuery::build((filter, request).into());
+
+        let transactions = self.query(query).await?.mempool.try_into()?;
+        Ok(transactions)
+    }
+
+    /// Returns the pending transactions of the `owner` submitted with the ordering hint,
+    /// in the order of their inclusion into the blocks.
+    pub async fn pending_chain(
+        &self,
+        owner: &Address,
+    ) -> io::Result<Vec<types::MempoolTransaction>> {
+        let query = schema::mempool::PendingChainQuery::build(
+            schema::mempool::PendingChainArgs {
+                owner: (*owner).into(),
+            },
+        );
+
+        let transactions = self
+            .query(query)
+            .await?
+            .pending_chain
+            .into_iter()
+            .map(types::MempoolTransaction::try_from)
+            .collect::<Result<_, _>>()?;
+        Ok(transactions)
+    }
+
     pub async fn receipts(&self, id: &TxId) -> io::Result<Option<Vec<Receipt>>> {
         let query = schema::tx::TransactionQuery::build(TxIdArgs { id: (*id).into() });
 
@@ -723,11 +1036,24 @@ impl FuelClient {
         &self,
         blocks_to_produce: u32,
         start_timestamp: Option<u64>,
+    ) -> io::Result<BlockHeight> {
+        self.produce_blocks_with_interval(blocks_to_produce, start_timestamp, None)
+            .await
+    }
+
+    /// Produces `blocks_to_produce` blocks with the timestamps increasing by `interval` seconds.
+    /// Requires the node to run with the `debug` flag.
+    pub async fn produce_blocks_with_interval(
+        &self,
+        blocks_to_produce: u32,
+        start_timestamp: Option<u64>,
+        interval: Option<u64>,
     ) -> io::Result<BlockHeight> {
         let query = schema::block::BlockMutation::build(ProduceBlockArgs {
             blocks_to_produce: blocks_to_produce.into(),
             start_timestamp: start_timestamp
                 .map(|timestamp| Tai64Timestamp::from(Tai64(timestamp))),
+            interval: interval.map(Into::into),
         });
 
         let new_height = self.query(query).await?.produce_blocks;
@@ -735,6 +1061,63 @@ impl FuelClient {
         Ok(new_height.into())
     }
 
+    /// Overrides the timestamp of the next produced block.
+    /// Requires the node to run with the `debug` flag.
+    pub async fn set_next_block_timestamp(&self, timestamp: u64) -> io::Result<()> {
+        let query = schema::block::SetNextBlockTimestampMutation::build(
+            schema::block::SetNextBlockTimestampArgs {
+                timestamp: Tai64Timestamp::from(Tai64(timestamp)),
+            },
+        );
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Pauses the block production. Requires the admin token.
+    pub async fn pause_block_production(
+        &self,
+        reason: types::PauseReason,
+    ) -> io::Result<()> {
+        let query = schema::admin::PauseBlockProductionMutation::build(
+            schema::admin::PauseBlockProductionArgs { reason },
+        );
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Resumes the paused block production. Requires the admin token.
+    pub async fn resume_block_production(&self) -> io::Result<()> {
+        let query = schema::admin::ResumeBlockProductionMutation::build(());
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Produces one block without transactions, even if the block production is paused.
+    /// Requires the admin token.
+    pub async fn produce_heartbeat_block(&self) -> io::Result<BlockHeight> {
+        let query = schema::admin::ProduceHeartbeatBlockMutation::build(());
+        let new_height = self.query(query).await?.produce_heartbeat_block;
+
+        Ok(new_height.into())
+    }
+
+    pub async fn block_production_status(
+        &self,
+    ) -> io::Result<types::BlockProductionStatus> {
+        let query = schema::admin::BlockProductionQuery::build(());
+        self.query(query).await.map(|r| r.block_production.into())
+    }
+
+    /// Returns the on-disk statistics of the columns of each database.
+    /// Requires the admin token.
+    pub async fn database_statistics(
+        &self,
+    ) -> io::Result<Vec<types::DatabaseStatistics>> {
+        let query = schema::admin::DatabaseStatisticsQuery::build(());
+        let statistics = self.query(query).await?.database_statistics;
+        Ok(statistics.into_iter().map(Into::into).collect())
+    }
+
     pub async fn block(&self, id: &BlockId) -> io::Result<Option<types::Block>> {
         let query = schema::block::BlockByIdQuery::build(BlockByIdArgs {
             id: Some((*id).into()),
@@ -758,6 +1141,61 @@ impl FuelClient {
         Ok(block)
     }
 
+    /// Returns the block at the `height` compressed for posting to the DA layer.
+    pub async fn da_compressed_block(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<Vec<u8>>> {
+        let query = schema::da_compression::DaCompressedBlockByHeightQuery::build(
+            schema::da_compression::DaCompressedBlockByHeightArgs {
+                height: U32(height.into()),
+            },
+        );
+
+        Ok(self
+            .query(query)
+            .await?
+            .da_compressed_block
+            .map(|block| block.bytes.into()))
+    }
+
+    /// Returns the fees paid by the transactions of the block at the `height`.
+    pub async fn block_fees(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::BlockFees>> {
+        let query = schema::block::BlockFeesByHeightQuery::build(BlockByHeightArgs {
+            height: Some(U32(height.into())),
+        });
+
+        Ok(self
+            .query(query)
+            .await?
+            .block
+            .and_then(|block| block.fees)
+            .map(Into::into))
+    }
+
+    /// Returns the fingerprint of the binary that produced the block at the `height`.
+    /// It is `None` if the block was not produced by the node.
+    pub async fn block_producer_fingerprint(
+        &self,
+        height: BlockHeight,
+    ) -> io::Result<Option<types::NodeFingerprint>> {
+        let query = schema::block::BlockProducerFingerprintByHeightQuery::build(
+            BlockByHeightArgs {
+                height: Some(U32(height.into())),
+            },
+        );
+
+        Ok(self
+            .query(query)
+            .await?
+            .block
+            .and_then(|block| block.producer_fingerprint)
+            .map(Into::into))
+    }
+
     /// Retrieve multiple blocks
     pub async fn blocks(
         &self,
@@ -770,6 +1208,33 @@ impl FuelClient {
         Ok(blocks)
     }
 
+    /// Retrieve the commitments of multiple blocks. The cursor is the height
+    /// of the block, so the commitments after the height `h` are requested
+    /// with `cursor: Some(h.to_string())`.
+    pub async fn block_commitments(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::BlockCommitment, String>> {
+        let query = schema::block::BlockCommitmentsQuery::build(request.into());
+
+        let commitments = self.query(query).await?.block_commitments.into();
+
+        Ok(commitments)
+    }
+
+    /// Retrieve the proofs that the authority sealed two different blocks
+    /// at the same height. The cursor is the height of the blocks.
+    pub async fn equivocations(
+        &self,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::Equivocation, String>> {
+        let query = schema::block::EquivocationsQuery::build(request.into());
+
+        let equivocations = self.query(query).await?.equivocations.into();
+
+        Ok(equivocations)
+    }
+
     pub async fn coin(&self, id: &UtxoId) -> io::Result<Option<types::Coin>> {
         let query = schema::coins::CoinByIdQuery::build(CoinByIdArgs {
             utxo_id: (*id).into(),
@@ -803,6 +1268,20 @@ impl FuelClient {
         spend_query: Vec<(AssetId, u64, Option<u32>)>,
         // (Utxos, Messages Nonce)
         excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+    ) -> io::Result<Vec<Vec<types::CoinType>>> {
+        self.coins_to_spend_with_lease(owner, spend_query, excluded_ids, None)
+            .await
+    }
+
+    /// Retrieve coins to spend in a transaction, selecting also the coins locked
+    /// by the `lease_id` and skipping the coins locked by other leases.
+    pub async fn coins_to_spend_with_lease(
+        &self,
+        owner: &Address,
+        spend_query: Vec<(AssetId, u64, Option<u32>)>,
+        // (Utxos, Messages Nonce)
+        excluded_ids: Option<(Vec<UtxoId>, Vec<Nonce>)>,
+        lease_id: Option<fuel_types::Bytes32>,
     ) -> io::Result<Vec<Vec<types::CoinType>>> {
         let owner: schema::Address = (*owner).into();
         let spend_query: Vec<SpendQueryElementInput> = spend_query
@@ -825,8 +1304,9 @@ impl FuelClient {
                 },
             )
             .map(Into::into);
+        let lease_id = lease_id.map(Into::into);
         let query = schema::coins::CoinsToSpendQuery::build(
-            (owner, spend_query, excluded_ids).into(),
+            (owner, spend_query, excluded_ids, lease_id).into(),
         );
 
         let coins_per_asset = self
@@ -839,6 +1319,40 @@ impl FuelClient {
         Ok(coins_per_asset)
     }
 
+    /// Locks the coins for `ttl` seconds and returns the id of the lease. The locked
+    /// coins are not returned by `coins_to_spend` to the requests without the lease.
+    /// All coins must be owned by the `owner`. Requires the admin token.
+    pub async fn lock_coins(
+        &self,
+        owner: &Address,
+        utxos: Vec<UtxoId>,
+        nonces: Vec<Nonce>,
+        ttl: u32,
+    ) -> io::Result<fuel_types::Bytes32> {
+        let ids = (
+            utxos.into_iter().map(Into::into).collect(),
+            nonces.into_iter().map(Into::into).collect(),
+        )
+            .into();
+        let query =
+            schema::coins::LockCoinsMutation::build(schema::coins::LockCoinsArgs {
+                owner: (*owner).into(),
+                ids,
+                ttl: ttl.into(),
+            });
+        let lease_id = self.query(query).await?.lock_coins;
+        Ok(lease_id.into())
+    }
+
+    /// Releases the coins of the lease. Returns `false` if the lease has already expired.
+    pub async fn unlock_coins(&self, lease_id: &fuel_types::Bytes32) -> io::Result<bool> {
+        let query =
+            schema::coins::UnlockCoinsMutation::build(schema::coins::UnlockCoinsArgs {
+                lease_id: (*lease_id).into(),
+            });
+        self.query(query).await.map(|r| r.unlock_coins)
+    }
+
     pub async fn contract(&self, id: &ContractId) -> io::Result<Option<types::Contract>> {
         let query = schema::contract::ContractByIdQuery::build(ContractByIdArgs {
             id: (*id).into(),
@@ -868,6 +1382,110 @@ impl FuelClient {
         Ok(balance.amount)
     }
 
+    /// Returns the transaction that deployed the contract.
+    pub async fn contract_deployment(
+        &self,
+        id: &ContractId,
+    ) -> io::Result<Option<types::ContractDeployment>> {
+        let query = schema::contract::ContractDeploymentQuery::build(ContractByIdArgs {
+            id: (*id).into(),
+        });
+        let deployment = self
+            .query(query)
+            .await?
+            .contract
+            .and_then(|contract| contract.deployment)
+            .map(Into::into);
+        Ok(deployment)
+    }
+
+    /// Returns the verified-source metadata attached to the contract.
+    pub async fn contract_verification(
+        &self,
+        id: &ContractId,
+    ) -> io::Result<Option<Vec<u8>>> {
+        let query = schema::contract::ContractDeploymentQuery::build(ContractByIdArgs {
+            id: (*id).into(),
+        });
+        let verification = self
+            .query(query)
+            .await?
+            .contract
+            .and_then(|contract| contract.verification)
+            .map(Into::into);
+        Ok(verification)
+    }
+
+    /// Attaches the verified-source metadata to the contract. Requires the admin token.
+    pub async fn set_contract_verification(
+        &self,
+        id: &ContractId,
+        metadata: Vec<u8>,
+    ) -> io::Result<()> {
+        let query = schema::admin::SetContractVerificationMutation::build(
+            schema::admin::SetContractVerificationArgs {
+                contract_id: (*id).into(),
+                metadata: HexString(Bytes(metadata)),
+            },
+        );
+        self.query(query).await?;
+        Ok(())
+    }
+
+    /// Registers the query as persisted and returns its hash. Requires the admin token.
+    pub async fn register_persisted_query(
+        &self,
+        query: &str,
+    ) -> io::Result<fuel_types::Bytes32> {
+        let query = schema::admin::RegisterPersistedQueryMutation::build(
+            schema::admin::RegisterPersistedQueryArgs {
+                query: query.to_string(),
+            },
+        );
+        let hash = self.query(query).await?.register_persisted_query;
+        Ok(hash.into())
+    }
+
+    /// Removes the persisted query. Requires the admin token.
+    pub async fn remove_persisted_query(
+        &self,
+        hash: &fuel_types::Bytes32,
+    ) -> io::Result<bool> {
+        let query = schema::admin::RemovePersistedQueryMutation::build(
+            schema::admin::RemovePersistedQueryArgs {
+                hash: (*hash).into(),
+            },
+        );
+        self.query(query).await.map(|r| r.remove_persisted_query)
+    }
+
+    /// Returns the minted and burned totals of the asset minted by a contract.
+    pub async fn asset_info(&self, id: &AssetId) -> io::Result<Option<types::AssetInfo>> {
+        let query =
+            schema::assets::AssetInfoQuery::build(schema::assets::AssetInfoArgs {
+                id: (*id).into(),
+            });
+        let asset_info = self.query(query).await?.asset_info.map(Into::into);
+        Ok(asset_info)
+    }
+
+    /// Returns the activity of the blocks from the `from` height up to and including
+    /// the `to` height, or `None` if any of the ends is not indexed yet.
+    pub async fn chain_statistics(
+        &self,
+        from: BlockHeight,
+        to: BlockHeight,
+    ) -> io::Result<Option<types::ChainStatistics>> {
+        let query = schema::statistics::ChainStatisticsQuery::build(
+            schema::statistics::ChainStatisticsArgs {
+                from: from.into(),
+                to: to.into(),
+            },
+        );
+        let statistics = self.query(query).await?.chain_statistics.map(Into::into);
+        Ok(statistics)
+    }
+
     pub async fn balance(
         &self,
         owner: &Address,
@@ -896,6 +1514,24 @@ impl FuelClient {
         Ok(balances)
     }
 
+    /// Returns the transfers of the `owner`, optionally only of the `asset_id`.
+    pub async fn transfer_events(
+        &self,
+        owner: &Address,
+        asset_id: Option<&AssetId>,
+        request: PaginationRequest<String>,
+    ) -> io::Result<PaginatedResult<types::TransferEvent, String>> {
+        let filter = schema::transfer::TransferEventFilterInput {
+            owner: (*owner).into(),
+            asset_id: asset_id.map(|id| (*id).into()),
+        };
+        let query =
+            schema::transfer::TransferEventsQuery::build((filter, request).into());
+
+        let events = self.query(query).await?.transfer_events.into();
+        Ok(events)
+    }
+
     pub async fn contract_balances(
         &self,
         contract: &ContractId,
@@ -941,6 +1577,19 @@ impl FuelClient {
         Ok(status)
     }
 
+    /// Returns the status of the message with its origin on the DA layer.
+    pub async fn message_status_details(
+        &self,
+        nonce: &Nonce,
+    ) -> io::Result<MessageStatusDetails> {
+        let query = schema::message::MessageStatusQuery::build(MessageStatusArgs {
+            nonce: (*nonce).into(),
+        });
+        let status = self.query(query).await?.message_status.into();
+
+        Ok(status)
+    }
+
     /// Request a merkle proof of an output message.
     pub async fn message_proof(
         &self,
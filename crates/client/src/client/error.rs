@@ -0,0 +1,117 @@
+use fuel_core_types::services::graphql_api::ErrorCode;
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fmt,
+    io,
+};
+
+/// The error of the GraphQL response. The `code` is `None` if the node doesn't report
+/// it, or if the code is unknown to this version of the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseError {
+    pub message: String,
+    pub code: Option<ErrorCode>,
+    pub details: BTreeMap<String, String>,
+}
+
+impl ResponseError {
+    /// Returns `true` if the same request may succeed later.
+    pub fn is_retryable(&self) -> bool {
+        self.code.map_or(false, |code| code.is_retryable())
+    }
+}
+
+/// The errors of the GraphQL response, wrapped into the [`io::Error`] returned by the client:
+///
+/// ```ignore
+/// if let Some(errors) = ResponseErrors::from_io(&err) {
+///     if errors.has_code(ErrorCode::GasPriceTooLow) { /* ... */ }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseErrors(pub Vec<ResponseError>);
+
+impl ResponseErrors {
+    /// Returns the errors of the response if the `error` is caused by them.
+    pub fn from_io(error: &io::Error) -> Option<&Self> {
+        error.get_ref()?.downcast_ref::<Self>()
+    }
+
+    /// Returns `true` if any error of the response has the `code`.
+    pub fn has_code(&self, code: ErrorCode) -> bool {
+        self.0.iter().any(|error| error.code == Some(code))
+    }
+}
+
+impl fmt::Display for ResponseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Response errors")?;
+        for error in &self.0 {
+            write!(f, "; {}", error.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ResponseErrors {}
+
+impl From<ResponseErrors> for io::Error {
+    fn from(errors: ResponseErrors) -> Self {
+        io::Error::new(io::ErrorKind::Other, errors)
+    }
+}
+
+/// The extensions of the GraphQL error set by the node.
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct ErrorExtensions {
+    code: Option<String>,
+    #[serde(default)]
+    details: BTreeMap<String, String>,
+}
+
+impl ResponseError {
+    pub(crate) fn new(message: String, extensions: Option<ErrorExtensions>) -> Self {
+        let extensions = extensions.unwrap_or_default();
+        Self {
+            message,
+            code: extensions.code.and_then(|code| code.parse().ok()),
+            details: extensions.details,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn response_errors_are_found_in_io_error() {
+        // Given
+        let extensions: ErrorExtensions = serde_json::from_str(
+            r#"{"code": "POOL_FULL", "retryable": true, "details": {"transactionId": "0x01"}}"#,
+        )
+        .unwrap();
+        let error = ResponseError::new("The pool is full".to_string(), Some(extensions));
+        let io_error: io::Error = ResponseErrors(vec![error]).into();
+
+        // When
+        let errors = ResponseErrors::from_io(&io_error).unwrap();
+
+        // Then
+        assert!(errors.has_code(ErrorCode::PoolFull));
+        assert!(errors.0[0].is_retryable());
+        assert_eq!(errors.0[0].details["transactionId"], "0x01");
+        assert_eq!(io_error.to_string(), "Response errors; The pool is full");
+    }
+
+    #[test]
+    fn unknown_code_is_ignored() {
+        let extensions: ErrorExtensions =
+            serde_json::from_str(r#"{"code": "FROM_THE_FUTURE"}"#).unwrap();
+
+        let error = ResponseError::new("message".to_string(), Some(extensions));
+
+        assert_eq!(error.code, None);
+    }
+}
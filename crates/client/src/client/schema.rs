@@ -26,16 +26,23 @@ use crate::client::pagination::{
 };
 pub use primitives::*;
 
+pub mod activity;
+pub mod admin;
+pub mod assets;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
+pub mod da_compression;
+pub mod mempool;
 pub mod message;
 pub mod node_info;
 
 pub mod gas_price;
 pub mod primitives;
+pub mod statistics;
+pub mod transfer;
 pub mod tx;
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -0,0 +1,41 @@
+use crate::client::schema::{
+    schema,
+    Address,
+    TransactionId,
+    U32,
+};
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum ActivityKind {
+    CoinCreated,
+    CoinSpent,
+    MessageCreated,
+    MessageSpent,
+    ContractCalled,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AddressActivity {
+    pub address: Address,
+    pub kind: ActivityKind,
+    pub transaction_id: TransactionId,
+    pub block_height: U32,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AddressActivitySubscriptionArgs {
+    pub address: Address,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "AddressActivitySubscriptionArgs"
+)]
+pub struct AddressActivitySubscription {
+    #[arguments(address: $address)]
+    pub activity_by_address: AddressActivity,
+}
@@ -0,0 +1,201 @@
+use crate::client::schema::{
+    schema,
+    Bytes32,
+    ContractId,
+    HexString,
+    Tai64Timestamp,
+    U32,
+    U64,
+};
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum PauseReason {
+    Incident,
+    Maintenance,
+    Upgrade,
+    Other,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockProductionStatus {
+    pub paused: bool,
+    pub reason: Option<PauseReason>,
+    pub since: Option<Tai64Timestamp>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct BlockProductionQuery {
+    pub block_production: BlockProductionStatus,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ColumnStatistics {
+    pub name: String,
+    pub size: U64,
+    pub estimated_keys: U64,
+    pub pending_compaction_bytes: U64,
+    pub files_per_level: Vec<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DatabaseStatistics {
+    pub name: String,
+    pub columns: Vec<ColumnStatistics>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Query")]
+pub struct DatabaseStatisticsQuery {
+    pub database_statistics: Vec<DatabaseStatistics>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PauseBlockProductionArgs {
+    pub reason: PauseReason,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "PauseBlockProductionArgs",
+    graphql_type = "Mutation"
+)]
+pub struct PauseBlockProductionMutation {
+    #[arguments(reason: $reason)]
+    pub pause_block_production: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Mutation")]
+pub struct ResumeBlockProductionMutation {
+    pub resume_block_production: bool,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Mutation")]
+pub struct ProduceHeartbeatBlockMutation {
+    pub produce_heartbeat_block: U32,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SetContractVerificationArgs {
+    pub contract_id: ContractId,
+    pub metadata: HexString,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "SetContractVerificationArgs",
+    graphql_type = "Mutation"
+)]
+pub struct SetContractVerificationMutation {
+    #[arguments(contractId: $contract_id, metadata: $metadata)]
+    pub set_contract_verification: bool,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct RegisterPersistedQueryArgs {
+    pub query: String,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "RegisterPersistedQueryArgs",
+    graphql_type = "Mutation"
+)]
+pub struct RegisterPersistedQueryMutation {
+    #[arguments(query: $query)]
+    pub register_persisted_query: Bytes32,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct RemovePersistedQueryArgs {
+    pub hash: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "RemovePersistedQueryArgs",
+    graphql_type = "Mutation"
+)]
+pub struct RemovePersistedQueryMutation {
+    #[arguments(hash: $hash)]
+    pub remove_persisted_query: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::schema::Bytes;
+    use cynic::{
+        MutationBuilder,
+        QueryBuilder,
+    };
+
+    #[test]
+    fn block_production_query_gql_output() {
+        let operation = BlockProductionQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn database_statistics_query_gql_output() {
+        let operation = DatabaseStatisticsQuery::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn pause_block_production_mutation_gql_output() {
+        let operation = PauseBlockProductionMutation::build(PauseBlockProductionArgs {
+            reason: PauseReason::Incident,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn resume_block_production_mutation_gql_output() {
+        let operation = ResumeBlockProductionMutation::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn produce_heartbeat_block_mutation_gql_output() {
+        let operation = ProduceHeartbeatBlockMutation::build(());
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn set_contract_verification_mutation_gql_output() {
+        let operation =
+            SetContractVerificationMutation::build(SetContractVerificationArgs {
+                contract_id: ContractId::default(),
+                metadata: HexString(Bytes(vec![1, 2, 3])),
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn register_persisted_query_mutation_gql_output() {
+        let operation =
+            RegisterPersistedQueryMutation::build(RegisterPersistedQueryArgs {
+                query: "{ chain { name } }".to_string(),
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn remove_persisted_query_mutation_gql_output() {
+        let operation = RemovePersistedQueryMutation::build(RemovePersistedQueryArgs {
+            hash: Bytes32::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
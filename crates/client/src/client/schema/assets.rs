@@ -0,0 +1,48 @@
+use crate::client::schema::{
+    schema,
+    AssetId,
+    Bytes32,
+    ContractId,
+    U128,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AssetInfo {
+    pub asset_id: AssetId,
+    pub contract_id: ContractId,
+    pub sub_id: Bytes32,
+    pub minted: U128,
+    pub burned: U128,
+    pub total_supply: U128,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct AssetInfoArgs {
+    pub id: AssetId,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "AssetInfoArgs"
+)]
+pub struct AssetInfoQuery {
+    #[arguments(id: $id)]
+    pub asset_info: Option<AssetInfo>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn asset_info_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = AssetInfoQuery::build(AssetInfoArgs {
+            id: AssetId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
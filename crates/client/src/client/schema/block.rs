@@ -14,6 +14,7 @@ use fuel_core_types::{
 };
 
 use super::{
+    node_info::NodeFingerprint,
     tx::TransactionIdFragment,
     Bytes32,
 };
@@ -50,6 +51,50 @@ pub struct BlockByHeightQuery {
     pub block: Option<Block>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockByHeightArgs"
+)]
+pub struct BlockFeesByHeightQuery {
+    #[arguments(height: $height)]
+    pub block: Option<BlockFeesFragment>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Block")]
+pub struct BlockFeesFragment {
+    pub fees: Option<BlockFees>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "BlockByHeightArgs"
+)]
+pub struct BlockProducerFingerprintByHeightQuery {
+    #[arguments(height: $height)]
+    pub block: Option<BlockProducerFingerprintFragment>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Block")]
+pub struct BlockProducerFingerprintFragment {
+    pub producer_fingerprint: Option<NodeFingerprint>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockFees {
+    pub tips: U64,
+    pub base_fees: U64,
+    pub coinbase: U64,
+    pub burned: U64,
+    pub refunds: U64,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -84,6 +129,81 @@ pub struct Block {
     pub transactions: Vec<TransactionIdFragment>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConnectionArgs"
+)]
+pub struct BlockCommitmentsQuery {
+    #[arguments(after: $after, before: $before, first: $first, last: $last)]
+    pub block_commitments: BlockCommitmentConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockCommitmentConnection {
+    pub edges: Vec<BlockCommitmentEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockCommitmentEdge {
+    pub cursor: String,
+    pub node: BlockCommitment,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct BlockCommitment {
+    pub height: U32,
+    pub block_id: BlockId,
+    pub consensus: Consensus,
+    pub message_receipt_root: Bytes32,
+    pub da_compressed_block_digest: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ConnectionArgs"
+)]
+pub struct EquivocationsQuery {
+    #[arguments(after: $after, before: $before, first: $first, last: $last)]
+    pub equivocations: EquivocationConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct EquivocationConnection {
+    pub edges: Vec<EquivocationEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct EquivocationEdge {
+    pub cursor: String,
+    pub node: Equivocation,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct Equivocation {
+    pub height: U32,
+    pub first: SealedHeader,
+    pub second: SealedHeader,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct SealedHeader {
+    pub header: Header,
+    pub consensus: Consensus,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Block")]
 pub struct BlockIdFragment {
@@ -100,6 +220,7 @@ pub struct BlockHeightFragment {
 pub struct ProduceBlockArgs {
     pub start_timestamp: Option<Tai64Timestamp>,
     pub blocks_to_produce: U32,
+    pub interval: Option<U64>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -109,10 +230,26 @@ pub struct ProduceBlockArgs {
     graphql_type = "Mutation"
 )]
 pub struct BlockMutation {
-    #[arguments(blocksToProduce: $blocks_to_produce, startTimestamp: $start_timestamp)]
+    #[arguments(blocksToProduce: $blocks_to_produce, startTimestamp: $start_timestamp, interval: $interval)]
     pub produce_blocks: U32,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct SetNextBlockTimestampArgs {
+    pub timestamp: Tai64Timestamp,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    variables = "SetNextBlockTimestampArgs",
+    graphql_type = "Mutation"
+)]
+pub struct SetNextBlockTimestampMutation {
+    #[arguments(timestamp: $timestamp)]
+    pub set_next_block_timestamp: bool,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct Header {
@@ -196,12 +333,40 @@ mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn block_fees_by_height_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlockFeesByHeightQuery::build(BlockByHeightArgs {
+            height: Some(U32(0)),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn block_producer_fingerprint_by_height_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlockProducerFingerprintByHeightQuery::build(BlockByHeightArgs {
+            height: Some(U32(0)),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn block_mutation_query_gql_output() {
         use cynic::MutationBuilder;
         let operation = BlockMutation::build(ProduceBlockArgs {
             blocks_to_produce: U32(0),
             start_timestamp: None,
+            interval: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn set_next_block_timestamp_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = SetNextBlockTimestampMutation::build(SetNextBlockTimestampArgs {
+            timestamp: Tai64Timestamp(tai64::Tai64(0)),
         });
         insta::assert_snapshot!(operation.query)
     }
@@ -217,4 +382,28 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn block_commitments_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = BlockCommitmentsQuery::build(ConnectionArgs {
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn equivocations_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = EquivocationsQuery::build(ConnectionArgs {
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -3,6 +3,7 @@ use crate::client::{
         schema,
         Address,
         AssetId,
+        Bytes32,
         Nonce,
         PageInfo,
         UtxoId,
@@ -137,6 +138,22 @@ impl From<(Vec<UtxoId>, Vec<Nonce>)> for ExcludeInput {
     }
 }
 
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinIdsInput {
+    /// The ids of the coins.
+    utxos: Vec<UtxoId>,
+    /// The nonces of the message coins.
+    messages: Vec<Nonce>,
+}
+
+impl From<(Vec<UtxoId>, Vec<Nonce>)> for CoinIdsInput {
+    fn from(value: (Vec<UtxoId>, Vec<Nonce>)) -> Self {
+        let (utxos, messages) = value;
+        Self { utxos, messages }
+    }
+}
+
 #[derive(cynic::InputObject, Clone, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct SpendQueryElementInput {
@@ -185,10 +202,16 @@ pub struct CoinsToSpendArgs {
     query_per_asset: Vec<SpendQueryElementInput>,
     /// A list of ids to exclude from the selection.
     excluded_ids: Option<ExcludeInput>,
+    /// The lease returned by `lockCoins`.
+    lease_id: Option<Bytes32>,
 }
 
-pub(crate) type CoinsToSpendArgsTuple =
-    (Address, Vec<SpendQueryElementInput>, Option<ExcludeInput>);
+pub(crate) type CoinsToSpendArgsTuple = (
+    Address,
+    Vec<SpendQueryElementInput>,
+    Option<ExcludeInput>,
+    Option<Bytes32>,
+);
 
 impl From<CoinsToSpendArgsTuple> for CoinsToSpendArgs {
     fn from(r: CoinsToSpendArgsTuple) -> Self {
@@ -196,6 +219,7 @@ impl From<CoinsToSpendArgsTuple> for CoinsToSpendArgs {
             owner: r.0,
             query_per_asset: r.1,
             excluded_ids: r.2,
+            lease_id: r.3,
         }
     }
 }
@@ -207,10 +231,44 @@ impl From<CoinsToSpendArgsTuple> for CoinsToSpendArgs {
     variables = "CoinsToSpendArgs"
 )]
 pub struct CoinsToSpendQuery {
-    #[arguments(owner: $ owner, queryPerAsset: $ query_per_asset, excludedIds: $ excluded_ids)]
+    #[arguments(owner: $ owner, queryPerAsset: $ query_per_asset, excludedIds: $ excluded_ids, leaseId: $ lease_id)]
     pub coins_to_spend: Vec<Vec<CoinType>>,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct LockCoinsArgs {
+    pub owner: Address,
+    pub ids: CoinIdsInput,
+    pub ttl: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "LockCoinsArgs"
+)]
+pub struct LockCoinsMutation {
+    #[arguments(owner: $ owner, ids: $ ids, ttl: $ ttl)]
+    pub lock_coins: Bytes32,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct UnlockCoinsArgs {
+    pub lease_id: Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "UnlockCoinsArgs"
+)]
+pub struct UnlockCoinsMutation {
+    #[arguments(leaseId: $ lease_id)]
+    pub unlock_coins: bool,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,4 +297,24 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn lock_coins_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = LockCoinsMutation::build(LockCoinsArgs {
+            owner: Address::default(),
+            ids: (vec![UtxoId::default()], vec![]).into(),
+            ttl: U32(60),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn unlock_coins_mutation_gql_output() {
+        use cynic::MutationBuilder;
+        let operation = UnlockCoinsMutation::build(UnlockCoinsArgs {
+            lease_id: Bytes32::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
@@ -2,10 +2,13 @@ use crate::client::{
     schema::{
         schema,
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         PageInfo,
         Salt,
+        TransactionId,
+        U32,
         U64,
     },
     PageDirection,
@@ -61,6 +64,33 @@ pub struct Contract {
     pub salt: Salt,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractDeployment {
+    pub transaction_id: TransactionId,
+    pub block_height: U32,
+    pub bytecode_hash: Bytes32,
+    pub salt: Salt,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Contract")]
+pub struct ContractDeploymentFragment {
+    pub deployment: Option<ContractDeployment>,
+    pub verification: Option<HexString>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ContractByIdArgs"
+)]
+pub struct ContractDeploymentQuery {
+    #[arguments(id: $id)]
+    pub contract: Option<ContractDeploymentFragment>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Contract")]
 pub struct ContractIdFragment {
@@ -147,4 +177,13 @@ mod tests {
         });
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn contract_deployment_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ContractDeploymentQuery::build(ContractByIdArgs {
+            id: ContractId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
 }
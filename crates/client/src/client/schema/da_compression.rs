@@ -0,0 +1,42 @@
+use crate::client::schema::{
+    schema,
+    HexString,
+    U32,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct DaCompressedBlock {
+    pub bytes: HexString,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct DaCompressedBlockByHeightArgs {
+    pub height: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DaCompressedBlockByHeightArgs"
+)]
+pub struct DaCompressedBlockByHeightQuery {
+    #[arguments(height: $height)]
+    pub da_compressed_block: Option<DaCompressedBlock>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn da_compressed_block_by_height_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation =
+            DaCompressedBlockByHeightQuery::build(DaCompressedBlockByHeightArgs {
+                height: U32(0),
+            });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::client::{
+    schema::{
+        schema,
+        Address,
+        ContractId,
+        ConversionError,
+        HexString,
+        PageInfo,
+        Tai64Timestamp,
+        U64,
+    },
+    PageDirection,
+    PaginationRequest,
+};
+use fuel_core_types::{
+    fuel_tx,
+    fuel_types::canonical::Deserialize,
+};
+
+#[derive(cynic::InputObject, Clone, Debug, Default)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MempoolFilterInput {
+    /// Filter transactions based on the owner of the coins they spend or create
+    pub owner: Option<Address>,
+    /// Filter transactions based on the contract they call or create
+    pub contract: Option<ContractId>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct MempoolConnectionArgs {
+    /// Filter transactions based on a filter
+    filter: Option<MempoolFilterInput>,
+    /// Skip until the cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until the cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transactions in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transactions in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(Option<MempoolFilterInput>, PaginationRequest<String>)>
+    for MempoolConnectionArgs
+{
+    fn from(r: (Option<MempoolFilterInput>, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => MempoolConnectionArgs {
+                filter: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => MempoolConnectionArgs {
+                filter: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "MempoolConnectionArgs"
+)]
+pub struct MempoolQuery {
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub mempool: MempoolTransactionConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MempoolTransactionConnection {
+    pub edges: Vec<MempoolTransactionEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MempoolTransactionEdge {
+    pub cursor: String,
+    pub node: MempoolTransaction,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct MempoolTransaction {
+    pub transaction: RawTransaction,
+    pub tip: U64,
+    pub max_gas: U64,
+    pub size: U64,
+    pub submitted_at: Tai64Timestamp,
+    pub time_in_pool_ms: U64,
+    pub ordering_hint: Option<U64>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct PendingChainArgs {
+    pub owner: Address,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "PendingChainArgs"
+)]
+pub struct PendingChainQuery {
+    #[arguments(owner: $owner)]
+    pub pending_chain: Vec<MempoolTransaction>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Transaction")]
+pub struct RawTransaction {
+    pub raw_payload: HexString,
+}
+
+impl TryFrom<RawTransaction> for fuel_tx::Transaction {
+    type Error = ConversionError;
+
+    fn try_from(value: RawTransaction) -> Result<Self, Self::Error> {
+        let bytes = value.raw_payload.0 .0;
+        fuel_tx::Transaction::from_bytes(bytes.as_slice())
+            .map_err(ConversionError::TransactionFromBytesError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mempool_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = MempoolQuery::build(MempoolConnectionArgs {
+            filter: Some(MempoolFilterInput {
+                owner: Some(Address::default()),
+                contract: None,
+            }),
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn pending_chain_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = PendingChainQuery::build(PendingChainArgs {
+            owner: Address::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
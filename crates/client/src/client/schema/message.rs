@@ -51,6 +51,8 @@ pub struct MessageQuery {
 #[cynic(schema_path = "./assets/schema.sdl")]
 pub struct MessageStatus {
     pub(crate) state: MessageState,
+    pub(crate) da_height: Option<U64>,
+    pub(crate) da_transaction_hash: Option<Bytes32>,
 }
 
 #[derive(cynic::Enum, Debug)]
@@ -225,4 +227,15 @@ mod tests {
 
         insta::assert_snapshot!(operation.query)
     }
+
+    #[test]
+    fn message_status_query_gql_output() {
+        use cynic::QueryBuilder;
+
+        let operation = MessageStatusQuery::build(MessageStatusArgs {
+            nonce: Nonce::default(),
+        });
+
+        insta::assert_snapshot!(operation.query)
+    }
 }
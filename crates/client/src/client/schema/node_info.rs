@@ -1,5 +1,6 @@
 use crate::client::schema::{
     schema,
+    Bytes32,
     U32,
     U64,
 };
@@ -24,6 +25,21 @@ pub struct NodeInfo {
     pub max_tx: U64,
     pub max_depth: U64,
     pub node_version: String,
+    pub peer_id: Option<String>,
+    pub advertised_addresses: Vec<String>,
+    pub protocol_versions: Vec<String>,
+    pub relayer_enabled: bool,
+    pub consensus_parameters_root: Bytes32,
+    pub fingerprint: NodeFingerprint,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct NodeFingerprint {
+    pub id: Bytes32,
+    pub version: String,
+    pub git_commit: String,
+    pub features: Vec<String>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
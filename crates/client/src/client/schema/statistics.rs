@@ -0,0 +1,50 @@
+use crate::client::schema::{
+    schema,
+    U32,
+    U64,
+};
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ChainStatistics {
+    pub from_height: U32,
+    pub to_height: U32,
+    pub tx_count: U64,
+    pub gas_used: U64,
+    pub new_addresses: U64,
+    pub total_addresses: U64,
+    pub fees_paid: U64,
+    pub fees_burned: U64,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct ChainStatisticsArgs {
+    pub from: U32,
+    pub to: U32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "ChainStatisticsArgs"
+)]
+pub struct ChainStatisticsQuery {
+    #[arguments(from: $from, to: $to)]
+    pub chain_statistics: Option<ChainStatistics>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chain_statistics_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = ChainStatisticsQuery::build(ChainStatisticsArgs {
+            from: U32(0),
+            to: U32(0),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
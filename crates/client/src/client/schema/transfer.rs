@@ -0,0 +1,149 @@
+use crate::client::{
+    schema::{
+        schema,
+        Address,
+        AssetId,
+        PageInfo,
+        TransactionId,
+        U32,
+        U64,
+    },
+    PageDirection,
+    PaginationRequest,
+};
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TransferDirection {
+    Incoming,
+    Outgoing,
+}
+
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum TransferSource {
+    Coin,
+    Message,
+    MessageOut,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransferEventFilterInput {
+    /// Filter transfers based on the `owner` field
+    pub owner: Address,
+    /// Filter transfers based on the `asset_id` field
+    pub asset_id: Option<AssetId>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransferEventsConnectionArgs {
+    /// Filter transfers based on a filter
+    filter: TransferEventFilterInput,
+    /// Skip until the cursor (forward pagination)
+    pub after: Option<String>,
+    /// Skip until the cursor (backward pagination)
+    pub before: Option<String>,
+    /// Retrieve the first n transfers in order (forward pagination)
+    pub first: Option<i32>,
+    /// Retrieve the last n transfers in order (backward pagination).
+    /// Can't be used at the same time as `first`.
+    pub last: Option<i32>,
+}
+
+impl From<(TransferEventFilterInput, PaginationRequest<String>)>
+    for TransferEventsConnectionArgs
+{
+    fn from(r: (TransferEventFilterInput, PaginationRequest<String>)) -> Self {
+        match r.1.direction {
+            PageDirection::Forward => TransferEventsConnectionArgs {
+                filter: r.0,
+                after: r.1.cursor,
+                before: None,
+                first: Some(r.1.results),
+                last: None,
+            },
+            PageDirection::Backward => TransferEventsConnectionArgs {
+                filter: r.0,
+                after: None,
+                before: r.1.cursor,
+                first: None,
+                last: Some(r.1.results),
+            },
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TransferEventsConnectionArgs"
+)]
+pub struct TransferEventsQuery {
+    #[arguments(filter: $filter, after: $after, before: $before, first: $first, last: $last)]
+    pub transfer_events: TransferEventConnection,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransferEventConnection {
+    pub edges: Vec<TransferEventEdge>,
+    pub page_info: PageInfo,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransferEventEdge {
+    pub cursor: String,
+    pub node: TransferEvent,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct TransferEvent {
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub amount: U64,
+    pub direction: TransferDirection,
+    pub source: TransferSource,
+    pub block_height: U32,
+    pub transaction_id: Option<TransactionId>,
+}
+
+#[derive(cynic::QueryVariables, Debug)]
+pub struct TransferEventsSubscriptionArgs {
+    pub filter: TransferEventFilterInput,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Subscription",
+    variables = "TransferEventsSubscriptionArgs"
+)]
+pub struct TransferEventsSubscription {
+    #[arguments(filter: $filter)]
+    pub transfer_events: TransferEvent,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transfer_events_connection_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = TransferEventsQuery::build(TransferEventsConnectionArgs {
+            filter: TransferEventFilterInput {
+                owner: Address::default(),
+                asset_id: None,
+            },
+            after: None,
+            before: None,
+            first: None,
+            last: None,
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+}
@@ -1,15 +1,32 @@
 use super::block::BlockHeightFragment;
 use crate::client::{
     schema::{
+        coins::{
+            Coin,
+            CoinByIdArgs,
+        },
+        message::{
+            Message,
+            NonceArgs,
+        },
         schema,
-        tx::transparent_receipt::Receipt,
+        tx::transparent_receipt::{
+            Receipt,
+            ReceiptsFilter,
+        },
         Address,
+        AssetId,
+        Bytes,
         ConnectionArgs,
+        ContractId,
         ConversionError,
         HexString,
+        Nonce,
         PageInfo,
         Tai64Timestamp,
         TransactionId,
+        UtxoId,
+        U64,
     },
     types::TransactionResponse,
     PageDirection,
@@ -23,9 +40,13 @@ use fuel_core_types::{
         Bytes32,
     },
     fuel_vm,
-    services::executor::{
-        TransactionExecutionResult,
-        TransactionExecutionStatus,
+    services::{
+        executor::{
+            self,
+            TransactionExecutionResult,
+            TransactionExecutionStatus,
+        },
+        txpool,
     },
 };
 use std::convert::{
@@ -53,6 +74,30 @@ pub struct TransactionQuery {
     pub transaction: Option<OpaqueTransaction>,
 }
 
+/// Retrieves the transaction that spent the coin
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "CoinByIdArgs"
+)]
+pub struct SpendingTransactionQuery {
+    #[arguments(utxoId: $utxo_id)]
+    pub spending_transaction: Option<OpaqueTransaction>,
+}
+
+/// Retrieves the transaction that consumed the message
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "NonceArgs"
+)]
+pub struct MessageSpendingTransactionQuery {
+    #[arguments(nonce: $nonce)]
+    pub message_spending_transaction: Option<OpaqueTransaction>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -182,6 +227,8 @@ pub struct SuccessStatus {
     pub time: Tai64Timestamp,
     pub program_state: Option<ProgramState>,
     pub receipts: Vec<Receipt>,
+    pub fee: Option<FeeBreakdown>,
+    pub vm_metrics: Option<VmMetrics>,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -193,6 +240,73 @@ pub struct FailureStatus {
     pub reason: String,
     pub program_state: Option<ProgramState>,
     pub receipts: Vec<Receipt>,
+    pub fee: Option<FeeBreakdown>,
+    pub vm_metrics: Option<VmMetrics>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct FeeBreakdown {
+    pub gas_used: U64,
+    pub gas_price: U64,
+    pub tip: U64,
+    pub base_fee: U64,
+    pub total_fee: U64,
+    pub refunds: Vec<ChangeRefund>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ChangeRefund {
+    pub output_index: i32,
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+impl TryFrom<FeeBreakdown> for executor::FeeBreakdown {
+    type Error = ConversionError;
+
+    fn try_from(fee: FeeBreakdown) -> Result<Self, Self::Error> {
+        let refunds = fee
+            .refunds
+            .into_iter()
+            .map(|refund| {
+                Ok(executor::ChangeRefund {
+                    output_index: refund
+                        .output_index
+                        .try_into()
+                        .map_err(|_| ConversionError::IntegerConversion)?,
+                    asset_id: refund.asset_id.into(),
+                    amount: refund.amount.into(),
+                })
+            })
+            .collect::<Result<Vec<_>, ConversionError>>()?;
+
+        Ok(executor::FeeBreakdown {
+            gas_used: fee.gas_used.into(),
+            gas_price: fee.gas_price.into(),
+            tip: fee.tip.into(),
+            base_fee: fee.base_fee.into(),
+            total_fee: fee.total_fee.into(),
+            refunds,
+        })
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct VmMetrics {
+    pub instructions: U64,
+    pub memory_watermark: U64,
+}
+
+impl From<VmMetrics> for executor::VmMetrics {
+    fn from(metrics: VmMetrics) -> Self {
+        executor::VmMetrics {
+            instructions: metrics.instructions.into(),
+            memory_watermark: metrics.memory_watermark.into(),
+        }
+    }
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -225,6 +339,7 @@ impl TryFrom<DryRunTransactionStatus> for TransactionExecutionResult {
                 TransactionExecutionResult::Success {
                     result: s.program_state.map(TryInto::try_into).transpose()?,
                     receipts,
+                    fee: s.fee.try_into()?,
                 }
             }
             DryRunTransactionStatus::FailureStatus(s) => {
@@ -236,6 +351,7 @@ impl TryFrom<DryRunTransactionStatus> for TransactionExecutionResult {
                 TransactionExecutionResult::Failed {
                     result: s.program_state.map(TryInto::try_into).transpose()?,
                     receipts,
+                    fee: s.fee.try_into()?,
                 }
             }
             DryRunTransactionStatus::Unknown => {
@@ -250,6 +366,7 @@ impl TryFrom<DryRunTransactionStatus> for TransactionExecutionResult {
 pub struct DryRunSuccessStatus {
     pub program_state: Option<ProgramState>,
     pub receipts: Vec<Receipt>,
+    pub fee: FeeBreakdown,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -257,6 +374,7 @@ pub struct DryRunSuccessStatus {
 pub struct DryRunFailureStatus {
     pub program_state: Option<ProgramState>,
     pub receipts: Vec<Receipt>,
+    pub fee: FeeBreakdown,
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -273,7 +391,101 @@ impl TryFrom<DryRunTransactionExecutionStatus> for TransactionExecutionStatus {
         let id = schema.id.into();
         let status = schema.status.try_into()?;
 
-        Ok(TransactionExecutionStatus { id, result: status })
+        Ok(TransactionExecutionStatus {
+            id,
+            result: status,
+            // The dry run doesn't meter the VM.
+            vm_metrics: None,
+        })
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "DryRunTransactionExecutionStatus"
+)]
+pub struct DryRunTransactionStateDiff {
+    pub id: TransactionId,
+    pub status: DryRunTransactionStatus,
+    pub state_diff: StateDiff,
+    pub access_list: AccessList,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateDiff {
+    pub consumed_coins: Vec<Coin>,
+    pub created_coins: Vec<Coin>,
+    pub consumed_messages: Vec<Message>,
+    pub contracts: Vec<ContractStateDiff>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractStateDiff {
+    pub contract_id: ContractId,
+    pub balance_root: super::Bytes32,
+    pub state_root: super::Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct AccessList {
+    pub contracts: Vec<ContractId>,
+    pub read_slots: Vec<ContractSlot>,
+    pub written_slots: Vec<ContractSlot>,
+    pub contract_balances: Vec<ContractAsset>,
+    pub coins: Vec<UtxoId>,
+    pub messages: Vec<Nonce>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractSlot {
+    pub contract_id: ContractId,
+    pub key: super::Bytes32,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractAsset {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+}
+
+impl From<AccessList> for executor::AccessList {
+    fn from(value: AccessList) -> Self {
+        Self {
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+            read_slots: value.read_slots.into_iter().map(Into::into).collect(),
+            written_slots: value.written_slots.into_iter().map(Into::into).collect(),
+            contract_balances: value
+                .contract_balances
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            coins: value.coins.into_iter().map(Into::into).collect(),
+            messages: value.messages.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<ContractSlot> for executor::ContractSlot {
+    fn from(value: ContractSlot) -> Self {
+        Self {
+            contract_id: value.contract_id.into(),
+            key: value.key.into(),
+        }
+    }
+}
+
+impl From<ContractAsset> for executor::ContractAsset {
+    fn from(value: ContractAsset) -> Self {
+        Self {
+            contract_id: value.contract_id.into(),
+            asset_id: value.asset_id.into(),
+        }
     }
 }
 
@@ -324,17 +536,67 @@ pub struct TransactionsByOwnerQuery {
     pub transactions_by_owner: TransactionConnection,
 }
 
+#[derive(cynic::QueryVariables, Debug)]
+pub struct StatusChangeArgs {
+    pub id: TransactionId,
+    pub receipts_filter: Option<ReceiptsFilter>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
     graphql_type = "Subscription",
-    variables = "TxIdArgs"
+    variables = "StatusChangeArgs"
 )]
 pub struct StatusChangeSubscription {
-    #[arguments(id: $id)]
+    #[arguments(id: $id, receiptsFilter: $receipts_filter)]
     pub status_change: TransactionStatus,
 }
 
+#[derive(cynic::Enum, Clone, Copy, Debug, PartialEq, Eq)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub enum EvictionReason {
+    Conflict,
+    Expired,
+    LowFee,
+    Invalid,
+}
+
+impl From<EvictionReason> for txpool::EvictionReason {
+    fn from(reason: EvictionReason) -> Self {
+        match reason {
+            EvictionReason::Conflict => txpool::EvictionReason::Conflict,
+            EvictionReason::Expired => txpool::EvictionReason::Expired,
+            EvictionReason::LowFee => txpool::EvictionReason::LowFee,
+            EvictionReason::Invalid => txpool::EvictionReason::Invalid,
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct EvictedTransaction {
+    pub transaction_id: TransactionId,
+    pub reason: EvictionReason,
+    pub message: String,
+}
+
+impl From<EvictedTransaction> for txpool::EvictedTransaction {
+    fn from(evicted: EvictedTransaction) -> Self {
+        txpool::EvictedTransaction {
+            tx_id: evicted.transaction_id.into(),
+            reason: evicted.reason.into(),
+            message: evicted.message,
+        }
+    }
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl", graphql_type = "Subscription")]
+pub struct EvictedTransactionsSubscription {
+    pub evicted_transactions: EvictedTransaction,
+}
+
 // mutations
 
 #[derive(cynic::QueryVariables)]
@@ -353,10 +615,106 @@ pub struct EstimatePredicates {
     pub estimate_predicates: OpaqueTransaction,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "TxArg"
+)]
+pub struct EstimateAndProvision {
+    #[arguments(tx: $tx)]
+    pub estimate_and_provision: OpaqueTransaction,
+}
+
 #[derive(cynic::QueryVariables)]
 pub struct DryRunArg {
     pub txs: Vec<HexString>,
     pub utxo_validation: Option<bool>,
+    pub state_overrides: Option<StateOverridesInput>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct StateOverridesInput {
+    pub coins: Vec<CoinOverrideInput>,
+    pub contract_balances: Vec<ContractBalanceOverrideInput>,
+    pub contract_slots: Vec<ContractSlotOverrideInput>,
+    pub contract_code: Vec<ContractCodeOverrideInput>,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct CoinOverrideInput {
+    pub utxo_id: UtxoId,
+    pub owner: Address,
+    pub amount: U64,
+    pub asset_id: AssetId,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractBalanceOverrideInput {
+    pub contract_id: ContractId,
+    pub asset_id: AssetId,
+    pub amount: U64,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractSlotOverrideInput {
+    pub contract_id: ContractId,
+    pub key: super::Bytes32,
+    pub value: super::Bytes32,
+}
+
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractCodeOverrideInput {
+    pub contract_id: ContractId,
+    pub bytecode: HexString,
+}
+
+impl From<executor::StateOverrides> for StateOverridesInput {
+    fn from(value: executor::StateOverrides) -> Self {
+        Self {
+            coins: value
+                .coins
+                .into_iter()
+                .map(|coin| CoinOverrideInput {
+                    utxo_id: coin.utxo_id.into(),
+                    owner: coin.owner.into(),
+                    amount: coin.amount.into(),
+                    asset_id: coin.asset_id.into(),
+                })
+                .collect(),
+            contract_balances: value
+                .contract_balances
+                .into_iter()
+                .map(|balance| ContractBalanceOverrideInput {
+                    contract_id: balance.contract_id.into(),
+                    asset_id: balance.asset_id.into(),
+                    amount: balance.amount.into(),
+                })
+                .collect(),
+            contract_slots: value
+                .contract_slots
+                .into_iter()
+                .map(|slot| ContractSlotOverrideInput {
+                    contract_id: slot.contract_id.into(),
+                    key: slot.key.into(),
+                    value: slot.value.into(),
+                })
+                .collect(),
+            contract_code: value
+                .contract_code
+                .into_iter()
+                .map(|code| ContractCodeOverrideInput {
+                    contract_id: code.contract_id.into(),
+                    bytecode: HexString(Bytes(code.bytecode)),
+                })
+                .collect(),
+        }
+    }
 }
 
 #[derive(cynic::QueryFragment, Debug)]
@@ -366,10 +724,57 @@ pub struct DryRunArg {
     variables = "DryRunArg"
 )]
 pub struct DryRun {
-    #[arguments(txs: $txs, utxoValidation: $utxo_validation)]
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, stateOverrides: $state_overrides)]
     pub dry_run: Vec<DryRunTransactionExecutionStatus>,
 }
 
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "DryRunArg"
+)]
+pub struct DryRunWithStateDiff {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, stateOverrides: $state_overrides)]
+    pub dry_run: Vec<DryRunTransactionStateDiff>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct OpcodeGasProfile {
+    pub opcode: String,
+    pub count: U64,
+    pub gas: U64,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ContractGasProfile {
+    pub contract_id: Option<ContractId>,
+    pub gas: U64,
+    pub instructions: U64,
+    pub opcodes: Vec<OpcodeGasProfile>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct GasProfile {
+    pub total_gas: U64,
+    pub contracts: Vec<ContractGasProfile>,
+    pub opcodes: Vec<OpcodeGasProfile>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Query",
+    variables = "DryRunArg"
+)]
+pub struct ProfileGasQuery {
+    #[arguments(txs: $txs, utxoValidation: $utxo_validation, stateOverrides: $state_overrides)]
+    pub profile_gas: GasProfile,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
@@ -381,14 +786,37 @@ pub struct Submit {
     pub submit: TransactionIdFragment,
 }
 
+#[derive(cynic::QueryVariables)]
+pub struct TxWithOrderingHintArg {
+    pub tx: HexString,
+    pub ordering_hint: Option<U64>,
+}
+
+#[derive(cynic::QueryFragment, Debug)]
+#[cynic(
+    schema_path = "./assets/schema.sdl",
+    graphql_type = "Mutation",
+    variables = "TxWithOrderingHintArg"
+)]
+pub struct SubmitWithOrderingHint {
+    #[arguments(tx: $tx, orderingHint: $ordering_hint)]
+    pub submit: TransactionIdFragment,
+}
+
+#[derive(cynic::QueryVariables)]
+pub struct SubmitAndAwaitArgs {
+    pub tx: HexString,
+    pub receipts_filter: Option<ReceiptsFilter>,
+}
+
 #[derive(cynic::QueryFragment, Debug)]
 #[cynic(
     schema_path = "./assets/schema.sdl",
     graphql_type = "Subscription",
-    variables = "TxArg"
+    variables = "SubmitAndAwaitArgs"
 )]
 pub struct SubmitAndAwaitSubscription {
-    #[arguments(tx: $tx)]
+    #[arguments(tx: $tx, receiptsFilter: $receipts_filter)]
     pub submit_and_await: TransactionStatus,
 }
 
@@ -422,6 +850,24 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn spending_transaction_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = SpendingTransactionQuery::build(CoinByIdArgs {
+            utxo_id: UtxoId::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
+    #[test]
+    fn message_spending_transaction_query_gql_output() {
+        use cynic::QueryBuilder;
+        let operation = MessageSpendingTransactionQuery::build(NonceArgs {
+            nonce: Nonce::default(),
+        });
+        insta::assert_snapshot!(operation.query)
+    }
+
     #[test]
     fn transactions_connection_query_gql_output() {
         use cynic::QueryBuilder;
@@ -448,6 +894,16 @@ pub mod tests {
         insta::assert_snapshot!(operation.query)
     }
 
+    #[test]
+    fn estimate_and_provision_tx_gql_output() {
+        use cynic::QueryBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = EstimateAndProvision::build(TxArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
     #[test]
     fn dry_run_tx_gql_output() {
         use cynic::MutationBuilder;
@@ -455,6 +911,31 @@ pub mod tests {
         let query = DryRun::build(DryRunArg {
             txs: vec![HexString(Bytes(tx.to_bytes()))],
             utxo_validation: None,
+            state_overrides: None,
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn dry_run_with_state_diff_tx_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = DryRunWithStateDiff::build(DryRunArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: None,
+            state_overrides: None,
+        });
+        insta::assert_snapshot!(query.query)
+    }
+
+    #[test]
+    fn profile_gas_gql_output() {
+        use cynic::QueryBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = ProfileGasQuery::build(DryRunArg {
+            txs: vec![HexString(Bytes(tx.to_bytes()))],
+            utxo_validation: None,
+            state_overrides: None,
         });
         insta::assert_snapshot!(query.query)
     }
@@ -468,4 +949,15 @@ pub mod tests {
         });
         insta::assert_snapshot!(query.query)
     }
+
+    #[test]
+    fn submit_with_ordering_hint_gql_output() {
+        use cynic::MutationBuilder;
+        let tx = fuel_tx::Transaction::default_test_tx();
+        let query = SubmitWithOrderingHint::build(TxWithOrderingHintArg {
+            tx: HexString(Bytes(tx.to_bytes())),
+            ordering_hint: Some(1u64.into()),
+        });
+        insta::assert_snapshot!(query.query)
+    }
 }
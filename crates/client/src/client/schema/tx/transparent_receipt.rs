@@ -66,6 +66,15 @@ pub enum ReceiptType {
     Burn,
 }
 
+#[derive(cynic::InputObject, Clone, Debug)]
+#[cynic(schema_path = "./assets/schema.sdl")]
+pub struct ReceiptsFilter {
+    /// Returns only the receipts emitted in the context of the contract
+    pub contract_id: Option<ContractId>,
+    /// Returns only the receipts of the types
+    pub receipt_types: Option<Vec<ReceiptType>>,
+}
+
 impl TryFrom<Receipt> for fuel_tx::Receipt {
     type Error = ConversionError;
 
@@ -1,19 +1,41 @@
+pub mod activity;
+pub mod admin;
+pub mod assets;
 pub mod balance;
 pub mod block;
 pub mod chain_info;
 pub mod coins;
 pub mod contract;
+pub mod dry_run;
 pub mod gas_costs;
 
 pub mod gas_price;
+pub mod mempool;
 pub mod merkle_proof;
 pub mod message;
 pub mod node_info;
+pub mod statistics;
+pub mod transfer;
 
+pub use activity::{
+    ActivityKind,
+    AddressActivity,
+};
+pub use admin::{
+    BlockProductionStatus,
+    ColumnStatistics,
+    DatabaseStatistics,
+    PauseReason,
+};
+pub use assets::AssetInfo;
 pub use balance::Balance;
 pub use block::{
     Block,
+    BlockCommitment,
+    BlockFees,
     Consensus,
+    Equivocation,
+    SealedHeader,
 };
 pub use chain_info::ChainInfo;
 pub use coins::{
@@ -24,17 +46,36 @@ pub use coins::{
 pub use contract::{
     Contract,
     ContractBalance,
+    ContractDeployment,
+};
+pub use dry_run::{
+    ContractGasProfile,
+    ContractStateDiff,
+    DryRunResult,
+    GasProfile,
+    OpcodeGasProfile,
+    StateDiff,
 };
 pub use gas_costs::{
     DependentCost,
     GasCosts,
 };
+pub use mempool::MempoolTransaction;
 pub use merkle_proof::MerkleProof;
 pub use message::{
     Message,
     MessageProof,
 };
-pub use node_info::NodeInfo;
+pub use node_info::{
+    NodeFingerprint,
+    NodeInfo,
+};
+pub use statistics::ChainStatistics;
+pub use transfer::{
+    TransferDirection,
+    TransferEvent,
+    TransferSource,
+};
 
 use crate::client::schema::{
     tx::{
@@ -53,6 +94,10 @@ use fuel_core_types::{
         BlockHeight,
     },
     fuel_vm::ProgramState,
+    services::executor::{
+        FeeBreakdown,
+        VmMetrics,
+    },
 };
 use tai64::Tai64;
 
@@ -99,6 +144,8 @@ pub enum TransactionStatus {
         time: Tai64,
         program_state: Option<ProgramState>,
         receipts: Vec<Receipt>,
+        fee: Option<FeeBreakdown>,
+        vm_metrics: Option<VmMetrics>,
     },
     SqueezedOut {
         reason: String,
@@ -109,6 +156,8 @@ pub enum TransactionStatus {
         reason: String,
         program_state: Option<ProgramState>,
         receipts: Vec<Receipt>,
+        fee: Option<FeeBreakdown>,
+        vm_metrics: Option<VmMetrics>,
     },
 }
 
@@ -129,6 +178,8 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
                     .into_iter()
                     .map(TryInto::try_into)
                     .collect::<Result<Vec<_>, _>>()?,
+                fee: s.fee.map(TryInto::try_into).transpose()?,
+                vm_metrics: s.vm_metrics.map(Into::into),
             },
             SchemaTxStatus::FailureStatus(s) => TransactionStatus::Failure {
                 block_height: s.block.height.into(),
@@ -140,6 +191,8 @@ impl TryFrom<SchemaTxStatus> for TransactionStatus {
                     .into_iter()
                     .map(TryInto::try_into)
                     .collect::<Result<Vec<_>, _>>()?,
+                fee: s.fee.map(TryInto::try_into).transpose()?,
+                vm_metrics: s.vm_metrics.map(Into::into),
             },
             SchemaTxStatus::SqueezedOutStatus(s) => {
                 TransactionStatus::SqueezedOut { reason: s.reason }
@@ -0,0 +1,32 @@
+use crate::client::{
+    schema,
+    types::primitives::{
+        Address,
+        TransactionId,
+    },
+};
+use fuel_core_types::fuel_types::BlockHeight;
+
+pub use schema::activity::ActivityKind;
+
+/// The transaction of the imported block that touched the address.
+pub struct AddressActivity {
+    pub address: Address,
+    /// The way the transaction touched the address.
+    pub kind: ActivityKind,
+    pub transaction_id: TransactionId,
+    pub block_height: BlockHeight,
+}
+
+// GraphQL Translation
+
+impl From<schema::activity::AddressActivity> for AddressActivity {
+    fn from(value: schema::activity::AddressActivity) -> Self {
+        AddressActivity {
+            address: value.address.into(),
+            kind: value.kind,
+            transaction_id: value.transaction_id.into(),
+            block_height: value.block_height.into(),
+        }
+    }
+}
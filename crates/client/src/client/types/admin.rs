@@ -0,0 +1,60 @@
+use crate::client::schema;
+use tai64::Tai64;
+
+pub use schema::admin::PauseReason;
+
+pub struct BlockProductionStatus {
+    /// The reason and the time of the pause if the block production is paused.
+    pub paused: Option<(PauseReason, Tai64)>,
+}
+
+/// The on-disk statistics of the columns of one of the node databases.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DatabaseStatistics {
+    pub name: String,
+    pub columns: Vec<ColumnStatistics>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnStatistics {
+    pub name: String,
+    /// The total size of the SST files of the column in bytes.
+    pub size: u64,
+    pub estimated_keys: u64,
+    pub pending_compaction_bytes: u64,
+    /// The number of SST files on each level, starting from the level 0.
+    pub files_per_level: Vec<u64>,
+}
+
+// GraphQL Translation
+
+impl From<schema::admin::BlockProductionStatus> for BlockProductionStatus {
+    fn from(value: schema::admin::BlockProductionStatus) -> Self {
+        let paused = match (value.paused, value.reason, value.since) {
+            (true, Some(reason), Some(since)) => Some((reason, since.0)),
+            _ => None,
+        };
+        Self { paused }
+    }
+}
+
+impl From<schema::admin::DatabaseStatistics> for DatabaseStatistics {
+    fn from(value: schema::admin::DatabaseStatistics) -> Self {
+        Self {
+            name: value.name,
+            columns: value.columns.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::admin::ColumnStatistics> for ColumnStatistics {
+    fn from(value: schema::admin::ColumnStatistics) -> Self {
+        Self {
+            name: value.name,
+            size: value.size.into(),
+            estimated_keys: value.estimated_keys.into(),
+            pending_compaction_bytes: value.pending_compaction_bytes.into(),
+            files_per_level: value.files_per_level.into_iter().map(Into::into).collect(),
+        }
+    }
+}
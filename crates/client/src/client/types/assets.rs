@@ -0,0 +1,38 @@
+use crate::client::{
+    schema,
+    types::primitives::{
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetInfo {
+    pub asset_id: AssetId,
+    /// The contract that minted the asset.
+    pub contract_id: ContractId,
+    /// The sub identifier of the asset within the contract.
+    pub sub_id: Bytes32,
+    /// The total amount minted by the contract.
+    pub minted: u128,
+    /// The total amount burned by the contract.
+    pub burned: u128,
+    /// The amount of the asset in circulation.
+    pub total_supply: u128,
+}
+
+// GraphQL Translation
+
+impl From<schema::assets::AssetInfo> for AssetInfo {
+    fn from(value: schema::assets::AssetInfo) -> Self {
+        Self {
+            asset_id: value.asset_id.into(),
+            contract_id: value.contract_id.into(),
+            sub_id: value.sub_id.into(),
+            minted: value.minted.into(),
+            burned: value.burned.into(),
+            total_supply: value.total_supply.into(),
+        }
+    }
+}
@@ -61,6 +61,50 @@ pub struct PoAConsensus {
     pub signature: Signature,
 }
 
+/// The fees paid by the transactions of the block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockFees {
+    /// The sum of the tips paid to the block producer.
+    pub tips: u64,
+    /// The sum of the fees charged for the used gas, without tips.
+    pub base_fees: u64,
+    /// The amount minted to the coinbase recipient by the `Mint` transaction.
+    pub coinbase: u64,
+    /// The part of the fees that is not minted to anyone,
+    /// because the block producer has no coinbase recipient.
+    pub burned: u64,
+    /// The sum of the unused fees returned to the change outputs.
+    pub refunds: u64,
+}
+
+/// Everything required to commit the block to the L1.
+#[derive(Debug)]
+pub struct BlockCommitment {
+    pub height: u32,
+    pub block_id: BlockId,
+    pub consensus: Consensus,
+    pub message_receipt_root: MerkleRoot,
+    /// The SHA-256 hash of the block compressed for posting to the DA layer.
+    pub da_compressed_block_digest: Hash,
+}
+
+/// The block header with the signature of the producer.
+#[derive(Debug)]
+pub struct SealedHeader {
+    pub header: Header,
+    pub consensus: Consensus,
+}
+
+/// The proof that the authority sealed two different blocks at the same height.
+#[derive(Debug)]
+pub struct Equivocation {
+    pub height: u32,
+    /// The header known to the node first, usually the one from the local chain.
+    pub first: SealedHeader,
+    /// The conflicting header received later.
+    pub second: SealedHeader,
+}
+
 // GraphQL Translation
 
 impl From<schema::block::Header> for Header {
@@ -143,3 +187,72 @@ impl From<schema::block::BlockConnection> for PaginatedResult<Block, String> {
         }
     }
 }
+
+impl From<schema::block::BlockFees> for BlockFees {
+    fn from(value: schema::block::BlockFees) -> Self {
+        Self {
+            tips: value.tips.into(),
+            base_fees: value.base_fees.into(),
+            coinbase: value.coinbase.into(),
+            burned: value.burned.into(),
+            refunds: value.refunds.into(),
+        }
+    }
+}
+
+impl From<schema::block::BlockCommitment> for BlockCommitment {
+    fn from(value: schema::block::BlockCommitment) -> Self {
+        Self {
+            height: value.height.into(),
+            block_id: value.block_id.into(),
+            consensus: value.consensus.into(),
+            message_receipt_root: value.message_receipt_root.into(),
+            da_compressed_block_digest: value.da_compressed_block_digest.into(),
+        }
+    }
+}
+
+impl From<schema::block::BlockCommitmentConnection>
+    for PaginatedResult<BlockCommitment, String>
+{
+    fn from(conn: schema::block::BlockCommitmentConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
+
+impl From<schema::block::SealedHeader> for SealedHeader {
+    fn from(value: schema::block::SealedHeader) -> Self {
+        Self {
+            header: value.header.into(),
+            consensus: value.consensus.into(),
+        }
+    }
+}
+
+impl From<schema::block::Equivocation> for Equivocation {
+    fn from(value: schema::block::Equivocation) -> Self {
+        Self {
+            height: value.height.into(),
+            first: value.first.into(),
+            second: value.second.into(),
+        }
+    }
+}
+
+impl From<schema::block::EquivocationConnection>
+    for PaginatedResult<Equivocation, String>
+{
+    fn from(conn: schema::block::EquivocationConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
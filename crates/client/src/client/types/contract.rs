@@ -3,11 +3,14 @@ use crate::client::{
     types::primitives::{
         AssetId,
         Bytes,
+        Bytes32,
         ContractId,
         Salt,
+        TransactionId,
     },
     PaginatedResult,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 
 pub struct Contract {
     pub id: ContractId,
@@ -15,6 +18,17 @@ pub struct Contract {
     pub salt: Salt,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractDeployment {
+    /// The `Create` transaction that deployed the contract.
+    pub transaction_id: TransactionId,
+    /// The height of the block with the deployment transaction.
+    pub block_height: BlockHeight,
+    /// The SHA-256 hash of the contract bytecode.
+    pub bytecode_hash: Bytes32,
+    pub salt: Salt,
+}
+
 #[derive(Debug)]
 pub struct ContractBalance {
     pub contract: ContractId,
@@ -34,6 +48,17 @@ impl From<schema::contract::Contract> for Contract {
     }
 }
 
+impl From<schema::contract::ContractDeployment> for ContractDeployment {
+    fn from(value: schema::contract::ContractDeployment) -> Self {
+        Self {
+            transaction_id: value.transaction_id.into(),
+            block_height: value.block_height.into(),
+            bytecode_hash: value.bytecode_hash.into(),
+            salt: value.salt.into(),
+        }
+    }
+}
+
 impl From<schema::contract::ContractBalance> for ContractBalance {
     fn from(value: schema::contract::ContractBalance) -> Self {
         Self {
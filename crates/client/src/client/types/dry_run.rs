@@ -0,0 +1,142 @@
+use crate::client::{
+    schema::{
+        self,
+        ConversionError,
+    },
+    types::{
+        primitives::{
+            Bytes32,
+            ContractId,
+        },
+        Coin,
+        Message,
+    },
+};
+use fuel_core_types::services::executor::{
+    AccessList,
+    TransactionExecutionStatus,
+};
+
+#[derive(Debug)]
+pub struct DryRunResult {
+    pub status: TransactionExecutionStatus,
+    pub state_diff: StateDiff,
+    pub access_list: AccessList,
+}
+
+/// The gas spent by the VM, aggregated per contract and opcode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasProfile {
+    pub total_gas: u64,
+    pub contracts: Vec<ContractGasProfile>,
+    pub opcodes: Vec<OpcodeGasProfile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractGasProfile {
+    /// The contract, or `None` for the scripts.
+    pub contract_id: Option<ContractId>,
+    pub gas: u64,
+    pub instructions: u64,
+    pub opcodes: Vec<OpcodeGasProfile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcodeGasProfile {
+    pub opcode: String,
+    pub count: u64,
+    pub gas: u64,
+}
+
+#[derive(Debug)]
+pub struct StateDiff {
+    pub consumed_coins: Vec<Coin>,
+    pub created_coins: Vec<Coin>,
+    pub consumed_messages: Vec<Message>,
+    pub contracts: Vec<ContractStateDiff>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractStateDiff {
+    pub contract_id: ContractId,
+    pub balance_root: Bytes32,
+    pub state_root: Bytes32,
+}
+
+// GraphQL Translation
+
+impl TryFrom<schema::tx::DryRunTransactionStateDiff> for DryRunResult {
+    type Error = ConversionError;
+
+    fn try_from(
+        value: schema::tx::DryRunTransactionStateDiff,
+    ) -> Result<Self, Self::Error> {
+        let status = TransactionExecutionStatus {
+            id: value.id.into(),
+            result: value.status.try_into()?,
+            // The dry run doesn't meter the VM.
+            vm_metrics: None,
+        };
+        Ok(Self {
+            status,
+            state_diff: value.state_diff.into(),
+            access_list: value.access_list.into(),
+        })
+    }
+}
+
+impl From<schema::tx::StateDiff> for StateDiff {
+    fn from(value: schema::tx::StateDiff) -> Self {
+        Self {
+            consumed_coins: value.consumed_coins.into_iter().map(Into::into).collect(),
+            created_coins: value.created_coins.into_iter().map(Into::into).collect(),
+            consumed_messages: value
+                .consumed_messages
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::tx::ContractStateDiff> for ContractStateDiff {
+    fn from(value: schema::tx::ContractStateDiff) -> Self {
+        Self {
+            contract_id: value.contract_id.into(),
+            balance_root: value.balance_root.into(),
+            state_root: value.state_root.into(),
+        }
+    }
+}
+
+impl From<schema::tx::GasProfile> for GasProfile {
+    fn from(value: schema::tx::GasProfile) -> Self {
+        Self {
+            total_gas: value.total_gas.into(),
+            contracts: value.contracts.into_iter().map(Into::into).collect(),
+            opcodes: value.opcodes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::tx::ContractGasProfile> for ContractGasProfile {
+    fn from(value: schema::tx::ContractGasProfile) -> Self {
+        Self {
+            contract_id: value.contract_id.map(Into::into),
+            gas: value.gas.into(),
+            instructions: value.instructions.into(),
+            opcodes: value.opcodes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<schema::tx::OpcodeGasProfile> for OpcodeGasProfile {
+    fn from(value: schema::tx::OpcodeGasProfile) -> Self {
+        Self {
+            opcode: value.opcode,
+            count: value.count.into(),
+            gas: value.gas.into(),
+        }
+    }
+}
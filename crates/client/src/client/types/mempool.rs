@@ -0,0 +1,58 @@
+use crate::client::{
+    schema,
+    schema::ConversionError,
+    PaginatedResult,
+};
+use fuel_core_types::fuel_tx::Transaction;
+use tai64::Tai64;
+
+pub struct MempoolTransaction {
+    pub transaction: Transaction,
+    pub tip: u64,
+    pub max_gas: u64,
+    /// The metered size of the transaction in bytes.
+    pub size: u64,
+    pub submitted_at: Tai64,
+    /// The time in ms that the transaction spent in the pool.
+    pub time_in_pool_ms: u64,
+    /// The hint ordering the transaction among the pending transactions of the same owner.
+    pub ordering_hint: Option<u64>,
+}
+
+// GraphQL Translation
+
+impl TryFrom<schema::mempool::MempoolTransaction> for MempoolTransaction {
+    type Error = ConversionError;
+
+    fn try_from(value: schema::mempool::MempoolTransaction) -> Result<Self, Self::Error> {
+        Ok(MempoolTransaction {
+            transaction: value.transaction.try_into()?,
+            tip: value.tip.into(),
+            max_gas: value.max_gas.into(),
+            size: value.size.into(),
+            submitted_at: value.submitted_at.0,
+            time_in_pool_ms: value.time_in_pool_ms.into(),
+            ordering_hint: value.ordering_hint.map(Into::into),
+        })
+    }
+}
+
+impl TryFrom<schema::mempool::MempoolTransactionConnection>
+    for PaginatedResult<MempoolTransaction, String>
+{
+    type Error = ConversionError;
+
+    fn try_from(
+        conn: schema::mempool::MempoolTransactionConnection,
+    ) -> Result<Self, Self::Error> {
+        let results: Result<Vec<MempoolTransaction>, Self::Error> =
+            conn.edges.into_iter().map(|e| e.node.try_into()).collect();
+
+        Ok(PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: results?,
+        })
+    }
+}
@@ -5,6 +5,7 @@ use crate::client::{
         primitives::{
             Address,
             Bytes,
+            Bytes32,
             Nonce,
         },
         MerkleProof,
@@ -53,9 +54,19 @@ pub enum MessageStatus {
     NotFound,
 }
 
-impl From<schema::message::MessageStatus> for MessageStatus {
-    fn from(value: schema::message::MessageStatus) -> Self {
-        match value.state {
+/// The status of the message with its origin on the DA layer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MessageStatusDetails {
+    pub status: MessageStatus,
+    /// The height of the DA block with the transaction that sent the message.
+    pub da_height: Option<u64>,
+    /// The hash of the DA transaction that sent the message.
+    pub da_transaction_hash: Option<Bytes32>,
+}
+
+impl From<schema::message::MessageState> for MessageStatus {
+    fn from(value: schema::message::MessageState) -> Self {
+        match value {
             schema::message::MessageState::Unspent => Self::Unspent,
             schema::message::MessageState::Spent => Self::Spent,
             schema::message::MessageState::NotFound => Self::NotFound,
@@ -63,6 +74,22 @@ impl From<schema::message::MessageStatus> for MessageStatus {
     }
 }
 
+impl From<schema::message::MessageStatus> for MessageStatus {
+    fn from(value: schema::message::MessageStatus) -> Self {
+        value.state.into()
+    }
+}
+
+impl From<schema::message::MessageStatus> for MessageStatusDetails {
+    fn from(value: schema::message::MessageStatus) -> Self {
+        Self {
+            status: value.state.into(),
+            da_height: value.da_height.map(Into::into),
+            da_transaction_hash: value.da_transaction_hash.map(Into::into),
+        }
+    }
+}
+
 // GraphQL Translation
 
 impl From<schema::message::Message> for Message {
@@ -1,4 +1,7 @@
-use crate::client::schema;
+use crate::client::{
+    schema,
+    types::primitives::Bytes32,
+};
 
 pub struct NodeInfo {
     pub utxo_validation: bool,
@@ -7,6 +10,23 @@ pub struct NodeInfo {
     pub max_tx: u64,
     pub max_depth: u64,
     pub node_version: String,
+    /// The id of the node in the p2p network. It is `None` if the p2p is disabled.
+    pub peer_id: Option<String>,
+    pub advertised_addresses: Vec<String>,
+    pub protocol_versions: Vec<String>,
+    pub relayer_enabled: bool,
+    /// The commitment to the consensus parameters used by the node.
+    pub consensus_parameters_root: Bytes32,
+    /// The fingerprint of the node binary.
+    pub fingerprint: NodeFingerprint,
+}
+
+pub struct NodeFingerprint {
+    /// The hash of the fingerprint.
+    pub id: Bytes32,
+    pub version: String,
+    pub git_commit: String,
+    pub features: Vec<String>,
 }
 
 // GraphQL Translation
@@ -20,6 +40,23 @@ impl From<schema::node_info::NodeInfo> for NodeInfo {
             max_tx: value.max_tx.into(),
             max_depth: value.max_depth.into(),
             node_version: value.node_version,
+            peer_id: value.peer_id,
+            advertised_addresses: value.advertised_addresses,
+            protocol_versions: value.protocol_versions,
+            relayer_enabled: value.relayer_enabled,
+            consensus_parameters_root: value.consensus_parameters_root.into(),
+            fingerprint: value.fingerprint.into(),
+        }
+    }
+}
+
+impl From<schema::node_info::NodeFingerprint> for NodeFingerprint {
+    fn from(value: schema::node_info::NodeFingerprint) -> Self {
+        Self {
+            id: value.id.into(),
+            version: value.version,
+            git_commit: value.git_commit,
+            features: value.features,
         }
     }
 }
@@ -0,0 +1,38 @@
+use crate::client::schema;
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// The aggregated activity of the range of blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainStatistics {
+    pub from_height: BlockHeight,
+    pub to_height: BlockHeight,
+    /// The number of transactions, including the `Mint` transactions.
+    pub tx_count: u64,
+    /// The gas used by the transactions.
+    pub gas_used: u64,
+    /// The number of addresses that were active for the first time within the range.
+    pub new_addresses: u64,
+    /// The number of distinct addresses that have ever been active up to the end of the range.
+    pub total_addresses: u64,
+    /// The fees paid by the transactions, including the tips.
+    pub fees_paid: u64,
+    /// The part of the fees that is not minted to anyone.
+    pub fees_burned: u64,
+}
+
+// GraphQL Translation
+
+impl From<schema::statistics::ChainStatistics> for ChainStatistics {
+    fn from(value: schema::statistics::ChainStatistics) -> Self {
+        Self {
+            from_height: value.from_height.into(),
+            to_height: value.to_height.into(),
+            tx_count: value.tx_count.into(),
+            gas_used: value.gas_used.into(),
+            new_addresses: value.new_addresses.into(),
+            total_addresses: value.total_addresses.into(),
+            fees_paid: value.fees_paid.into(),
+            fees_burned: value.fees_burned.into(),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use crate::client::{
+    schema,
+    types::primitives::{
+        Address,
+        AssetId,
+        TransactionId,
+    },
+    PaginatedResult,
+};
+use fuel_core_types::fuel_types::BlockHeight;
+
+pub use schema::transfer::{
+    TransferDirection,
+    TransferSource,
+};
+
+pub struct TransferEvent {
+    pub owner: Address,
+    pub asset_id: AssetId,
+    pub amount: u64,
+    pub direction: TransferDirection,
+    pub source: TransferSource,
+    pub block_height: BlockHeight,
+    /// The transaction that moved the funds.
+    /// It is `None` for the messages imported from the DA layer.
+    pub transaction_id: Option<TransactionId>,
+}
+
+// GraphQL Translation
+
+impl From<schema::transfer::TransferEvent> for TransferEvent {
+    fn from(value: schema::transfer::TransferEvent) -> Self {
+        TransferEvent {
+            owner: value.owner.into(),
+            asset_id: value.asset_id.into(),
+            amount: value.amount.into(),
+            direction: value.direction,
+            source: value.source,
+            block_height: value.block_height.into(),
+            transaction_id: value.transaction_id.map(Into::into),
+        }
+    }
+}
+
+impl From<schema::transfer::TransferEventConnection>
+    for PaginatedResult<TransferEvent, String>
+{
+    fn from(conn: schema::transfer::TransferEventConnection) -> Self {
+        PaginatedResult {
+            cursor: conn.page_info.end_cursor,
+            has_next_page: conn.page_info.has_next_page,
+            has_previous_page: conn.page_info.has_previous_page,
+            results: conn.edges.into_iter().map(|e| e.node.into()).collect(),
+        }
+    }
+}
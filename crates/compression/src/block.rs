@@ -0,0 +1,778 @@
+use crate::{
+    ports::TransactionLookup,
+    registry::{
+        Registry,
+        RegistryBuilder,
+        RegistryKey,
+    },
+};
+use anyhow::{
+    anyhow,
+    Context,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::Block,
+        header::BlockHeader,
+    },
+    fuel_tx::{
+        field::{
+            BytecodeWitnessIndex,
+            Inputs,
+            Outputs,
+            Witnesses,
+        },
+        input::coin::{
+            CoinPredicate,
+            CoinSigned,
+        },
+        Input,
+        Output,
+        Transaction,
+        TxPointer,
+        UtxoId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Word,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// The compressed block posted to the DA layer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VersionedCompressedBlock {
+    /// The first version of the format.
+    V0(CompressedBlockV0),
+    /// The format with the payloads of the transactions stored separately.
+    V1(CompressedBlockV1),
+}
+
+/// The first version of the compressed block format.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedBlockV0 {
+    /// The header of the block.
+    pub header: BlockHeader,
+    /// The values used by the transactions of the block.
+    pub registry: Registry,
+    /// The transactions of the block.
+    pub transactions: Vec<CompressedTransaction>,
+}
+
+/// The second version of the compressed block format.
+///
+/// The bytecode of the `Create` transactions is moved out of the transactions into
+/// the `blobs`. The same bytecode deployed by several transactions is stored once.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedBlockV1 {
+    /// The header of the block.
+    pub header: BlockHeader,
+    /// The values used by the transactions of the block.
+    pub registry: Registry,
+    /// The distinct payloads of the transactions of the block.
+    pub blobs: Vec<Vec<u8>>,
+    /// The transactions of the block.
+    pub transactions: Vec<CompressedTransactionV1>,
+}
+
+/// The compressed transaction with the reference to its payload.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedTransactionV1 {
+    /// The transaction with the empty payload.
+    pub transaction: CompressedTransaction,
+    /// The index of the payload in the `blobs` of the block,
+    /// set for the `Create` transactions.
+    pub blob: Option<u32>,
+}
+
+/// The transaction with the compressed inputs and outputs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressedTransaction {
+    /// The transaction without inputs and outputs.
+    pub body: Transaction,
+    /// The inputs of the transaction.
+    pub inputs: Vec<CompressedInput>,
+    /// The outputs of the transaction.
+    pub outputs: Vec<CompressedOutput>,
+}
+
+/// The reference to the UTXO spent by the input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UtxoRef {
+    /// The output with the index in the transaction at the `tx_pointer` of the input.
+    Pointer(u8),
+    /// The full id of the UTXO, used when the transaction that created the UTXO
+    /// can't be found by the `tx_pointer`, for example, for coins from the genesis.
+    Full(UtxoId),
+}
+
+/// The input of the transaction with the values replaced by the references.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompressedInput {
+    CoinSigned {
+        utxo: UtxoRef,
+        owner: RegistryKey,
+        amount: Word,
+        asset_id: RegistryKey,
+        tx_pointer: TxPointer,
+        witness_index: u8,
+    },
+    CoinPredicate {
+        utxo: UtxoRef,
+        owner: RegistryKey,
+        amount: Word,
+        asset_id: RegistryKey,
+        tx_pointer: TxPointer,
+        predicate_gas_used: Word,
+        predicate: Vec<u8>,
+        predicate_data: Vec<u8>,
+    },
+    /// The input that is stored as is.
+    Uncompressed(Input),
+}
+
+/// The output of the transaction with the values replaced by the references.
+#[allow(missing_docs)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompressedOutput {
+    Coin {
+        to: RegistryKey,
+        amount: Word,
+        asset_id: RegistryKey,
+    },
+    Change {
+        to: RegistryKey,
+        amount: Word,
+        asset_id: RegistryKey,
+    },
+    Variable {
+        to: RegistryKey,
+        amount: Word,
+        asset_id: RegistryKey,
+    },
+    /// The output that is stored as is.
+    Uncompressed(Output),
+}
+
+/// Compresses the `block` into the bytes posted to the DA layer.
+pub fn compress<D>(block: &Block, db: &D) -> anyhow::Result<Vec<u8>>
+where
+    D: TransactionLookup,
+{
+    VersionedCompressedBlock::compress(block, db)?.to_bytes()
+}
+
+/// Reconstructs the block from the bytes produced by the [`compress`].
+pub fn decompress<D>(bytes: &[u8], db: &D) -> anyhow::Result<Block>
+where
+    D: TransactionLookup,
+{
+    VersionedCompressedBlock::from_bytes(bytes)?.decompress(db)
+}
+
+impl VersionedCompressedBlock {
+    /// Compresses the `block` with the latest version of the format.
+    pub fn compress<D>(block: &Block, db: &D) -> anyhow::Result<Self>
+    where
+        D: TransactionLookup,
+    {
+        let mut compressor = Compressor {
+            db,
+            height: *block.header().height(),
+            registry: RegistryBuilder::default(),
+        };
+        let mut blobs = Vec::new();
+        let transactions = block
+            .transactions()
+            .iter()
+            .map(|tx| {
+                let mut tx = tx.clone();
+                let blob = take_blob(&mut tx, &mut blobs)?;
+                Ok(CompressedTransactionV1 {
+                    transaction: compressor.transaction(tx)?,
+                    blob,
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(Self::V1(CompressedBlockV1 {
+            header: block.header().clone(),
+            registry: compressor.registry.build(),
+            blobs,
+            transactions,
+        }))
+    }
+
+    /// Reconstructs the block and verifies that its transactions match the header.
+    pub fn decompress<D>(self, db: &D) -> anyhow::Result<Block>
+    where
+        D: TransactionLookup,
+    {
+        match self {
+            Self::V0(block) => {
+                let decompressor = Decompressor {
+                    db,
+                    registry: &block.registry,
+                };
+                let transactions = block
+                    .transactions
+                    .into_iter()
+                    .map(|tx| decompressor.transaction(tx))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let height = *block.header.height();
+
+                Block::try_from_executed(block.header, transactions).ok_or_else(|| {
+                    anyhow!("the decompressed transactions don't match the header of the block {height}")
+                })
+            }
+            Self::V1(block) => {
+                let decompressor = Decompressor {
+                    db,
+                    registry: &block.registry,
+                };
+                let transactions = block
+                    .transactions
+                    .into_iter()
+                    .map(|tx| {
+                        let mut transaction = decompressor.transaction(tx.transaction)?;
+                        if let Some(blob) = tx.blob {
+                            restore_blob(&mut transaction, blob, &block.blobs)?;
+                        }
+                        Ok(transaction)
+                    })
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let height = *block.header.height();
+
+                Block::try_from_executed(block.header, transactions).ok_or_else(|| {
+                    anyhow!("the decompressed transactions don't match the header of the block {height}")
+                })
+            }
+        }
+    }
+
+    /// Encodes the compressed block.
+    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        postcard::to_allocvec(self).context("failed to encode the compressed block")
+    }
+
+    /// Decodes the compressed block.
+    pub fn from_bytes(bytes: &[u8]) -> anyhow::Result<Self> {
+        postcard::from_bytes(bytes).context("failed to decode the compressed block")
+    }
+}
+
+/// Moves the bytecode of the `Create` transaction into the `blobs`
+/// and returns its index there.
+fn take_blob(
+    tx: &mut Transaction,
+    blobs: &mut Vec<Vec<u8>>,
+) -> anyhow::Result<Option<u32>> {
+    let Transaction::Create(create) = tx else {
+        return Ok(None)
+    };
+    let index = *create.bytecode_witness_index() as usize;
+    let Some(witness) = create.witnesses_mut().get_mut(index) else {
+        return Ok(None)
+    };
+    let bytecode = core::mem::take(witness.as_vec_mut());
+
+    let position = match blobs.iter().position(|blob| blob == &bytecode) {
+        Some(position) => position,
+        None => {
+            blobs.push(bytecode);
+            blobs.len().saturating_sub(1)
+        }
+    };
+    let position = u32::try_from(position).context("too many blobs in the block")?;
+    Ok(Some(position))
+}
+
+/// Puts the bytecode from the `blobs` back into the `Create` transaction.
+fn restore_blob(
+    tx: &mut Transaction,
+    blob: u32,
+    blobs: &[Vec<u8>],
+) -> anyhow::Result<()> {
+    let Transaction::Create(create) = tx else {
+        return Err(anyhow!("only the `Create` transactions refer to the blobs"))
+    };
+    let bytecode = blobs
+        .get(blob as usize)
+        .ok_or_else(|| anyhow!("the blob {blob} is unknown"))?;
+    let index = *create.bytecode_witness_index() as usize;
+    let witness = create
+        .witnesses_mut()
+        .get_mut(index)
+        .ok_or_else(|| anyhow!("the bytecode witness {index} is missing"))?;
+    *witness.as_vec_mut() = bytecode.clone();
+    Ok(())
+}
+
+struct Compressor<'a, D> {
+    db: &'a D,
+    height: BlockHeight,
+    registry: RegistryBuilder,
+}
+
+impl<'a, D> Compressor<'a, D>
+where
+    D: TransactionLookup,
+{
+    fn transaction(
+        &mut self,
+        mut tx: Transaction,
+    ) -> anyhow::Result<CompressedTransaction> {
+        let (inputs, outputs) = match &mut tx {
+            Transaction::Script(script) => (
+                core::mem::take(script.inputs_mut()),
+                core::mem::take(script.outputs_mut()),
+            ),
+            Transaction::Create(create) => (
+                core::mem::take(create.inputs_mut()),
+                core::mem::take(create.outputs_mut()),
+            ),
+            Transaction::Mint(_) => (vec![], vec![]),
+        };
+
+        let inputs = inputs
+            .into_iter()
+            .map(|input| self.input(input))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let outputs = outputs
+            .into_iter()
+            .map(|output| self.output(output))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        Ok(CompressedTransaction {
+            body: tx,
+            inputs,
+            outputs,
+        })
+    }
+
+    fn input(&mut self, input: Input) -> anyhow::Result<CompressedInput> {
+        let input = match input {
+            Input::CoinSigned(CoinSigned {
+                utxo_id,
+                owner,
+                amount,
+                asset_id,
+                tx_pointer,
+                witness_index,
+                ..
+            }) => CompressedInput::CoinSigned {
+                utxo: self.utxo(utxo_id, tx_pointer)?,
+                owner: self.registry.address(owner)?,
+                amount,
+                asset_id: self.registry.asset_id(asset_id)?,
+                tx_pointer,
+                witness_index,
+            },
+            Input::CoinPredicate(CoinPredicate {
+                utxo_id,
+                owner,
+                amount,
+                asset_id,
+                tx_pointer,
+                predicate_gas_used,
+                predicate,
+                predicate_data,
+                ..
+            }) => CompressedInput::CoinPredicate {
+                utxo: self.utxo(utxo_id, tx_pointer)?,
+                owner: self.registry.address(owner)?,
+                amount,
+                asset_id: self.registry.asset_id(asset_id)?,
+                tx_pointer,
+                predicate_gas_used,
+                predicate,
+                predicate_data,
+            },
+            input => CompressedInput::Uncompressed(input),
+        };
+        Ok(input)
+    }
+
+    fn output(&mut self, output: Output) -> anyhow::Result<CompressedOutput> {
+        let output = match output {
+            Output::Coin {
+                to,
+                amount,
+                asset_id,
+            } => CompressedOutput::Coin {
+                to: self.registry.address(to)?,
+                amount,
+                asset_id: self.registry.asset_id(asset_id)?,
+            },
+            Output::Change {
+                to,
+                amount,
+                asset_id,
+            } => CompressedOutput::Change {
+                to: self.registry.address(to)?,
+                amount,
+                asset_id: self.registry.asset_id(asset_id)?,
+            },
+            Output::Variable {
+                to,
+                amount,
+                asset_id,
+            } => CompressedOutput::Variable {
+                to: self.registry.address(to)?,
+                amount,
+                asset_id: self.registry.asset_id(asset_id)?,
+            },
+            output => CompressedOutput::Uncompressed(output),
+        };
+        Ok(output)
+    }
+
+    fn utxo(&self, utxo_id: UtxoId, tx_pointer: TxPointer) -> anyhow::Result<UtxoRef> {
+        // Only the previous blocks are available during the decompression.
+        if tx_pointer.block_height() < self.height {
+            let tx_id = self.db.transaction_id(&tx_pointer)?;
+            if tx_id.as_ref() == Some(utxo_id.tx_id()) {
+                return Ok(UtxoRef::Pointer(utxo_id.output_index()))
+            }
+        }
+        Ok(UtxoRef::Full(utxo_id))
+    }
+}
+
+struct Decompressor<'a, D> {
+    db: &'a D,
+    registry: &'a Registry,
+}
+
+impl<'a, D> Decompressor<'a, D>
+where
+    D: TransactionLookup,
+{
+    fn transaction(&self, tx: CompressedTransaction) -> anyhow::Result<Transaction> {
+        let CompressedTransaction {
+            body: mut tx,
+            inputs,
+            outputs,
+        } = tx;
+        let inputs = inputs
+            .into_iter()
+            .map(|input| self.input(input))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let outputs = outputs
+            .into_iter()
+            .map(|output| self.output(output))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        match &mut tx {
+            Transaction::Script(script) => {
+                *script.inputs_mut() = inputs;
+                *script.outputs_mut() = outputs;
+            }
+            Transaction::Create(create) => {
+                *create.inputs_mut() = inputs;
+                *create.outputs_mut() = outputs;
+            }
+            Transaction::Mint(_) => {}
+        }
+        Ok(tx)
+    }
+
+    fn input(&self, input: CompressedInput) -> anyhow::Result<Input> {
+        let input = match input {
+            CompressedInput::CoinSigned {
+                utxo,
+                owner,
+                amount,
+                asset_id,
+                tx_pointer,
+                witness_index,
+            } => Input::coin_signed(
+                self.utxo(utxo, tx_pointer)?,
+                self.registry.address(owner)?,
+                amount,
+                self.registry.asset_id(asset_id)?,
+                tx_pointer,
+                witness_index,
+            ),
+            CompressedInput::CoinPredicate {
+                utxo,
+                owner,
+                amount,
+                asset_id,
+                tx_pointer,
+                predicate_gas_used,
+                predicate,
+                predicate_data,
+            } => Input::coin_predicate(
+                self.utxo(utxo, tx_pointer)?,
+                self.registry.address(owner)?,
+                amount,
+                self.registry.asset_id(asset_id)?,
+                tx_pointer,
+                predicate_gas_used,
+                predicate,
+                predicate_data,
+            ),
+            CompressedInput::Uncompressed(input) => input,
+        };
+        Ok(input)
+    }
+
+    fn output(&self, output: CompressedOutput) -> anyhow::Result<Output> {
+        let output = match output {
+            CompressedOutput::Coin {
+                to,
+                amount,
+                asset_id,
+            } => Output::coin(
+                self.registry.address(to)?,
+                amount,
+                self.registry.asset_id(asset_id)?,
+            ),
+            CompressedOutput::Change {
+                to,
+                amount,
+                asset_id,
+            } => Output::change(
+                self.registry.address(to)?,
+                amount,
+                self.registry.asset_id(asset_id)?,
+            ),
+            CompressedOutput::Variable {
+                to,
+                amount,
+                asset_id,
+            } => Output::variable(
+                self.registry.address(to)?,
+                amount,
+                self.registry.asset_id(asset_id)?,
+            ),
+            CompressedOutput::Uncompressed(output) => output,
+        };
+        Ok(output)
+    }
+
+    fn utxo(&self, utxo: UtxoRef, tx_pointer: TxPointer) -> anyhow::Result<UtxoId> {
+        match utxo {
+            UtxoRef::Pointer(output_index) => {
+                let tx_id = self.db.transaction_id(&tx_pointer)?.ok_or_else(|| {
+                    anyhow!("the transaction at {tx_pointer} is unknown")
+                })?;
+                Ok(UtxoId::new(tx_id, output_index))
+            }
+            UtxoRef::Full(utxo_id) => Ok(utxo_id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::{
+        blockchain::header::PartialBlockHeader,
+        fuel_tx::{
+            AssetId,
+            TransactionBuilder,
+            TxId,
+        },
+        fuel_types::{
+            Address,
+            Salt,
+        },
+    };
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct MockLookup(HashMap<TxPointer, TxId>);
+
+    impl TransactionLookup for MockLookup {
+        fn transaction_id(&self, pointer: &TxPointer) -> anyhow::Result<Option<TxId>> {
+            Ok(self.0.get(pointer).copied())
+        }
+    }
+
+    const OWNER: Address = Address::new([1; 32]);
+    const ASSET: AssetId = AssetId::new([2; 32]);
+
+    fn transfer(utxo_id: UtxoId, tx_pointer: TxPointer) -> Transaction {
+        TransactionBuilder::script(vec![], vec![])
+            .add_input(Input::coin_signed(
+                utxo_id, OWNER, 100, ASSET, tx_pointer, 0,
+            ))
+            .add_output(Output::coin(OWNER, 50, ASSET))
+            .add_output(Output::change(OWNER, 0, ASSET))
+            .add_witness(vec![0; 64].into())
+            .finalize_as_transaction()
+    }
+
+    fn block(height: u32, transactions: Vec<Transaction>) -> Block {
+        let mut header = PartialBlockHeader::default();
+        header.consensus.height = height.into();
+        Block::new(header, transactions, &[])
+    }
+
+    fn deployment(bytecode: Vec<u8>, salt: Salt) -> Transaction {
+        TransactionBuilder::create(bytecode.into(), salt, vec![])
+            .add_witness(vec![0; 64].into())
+            .finalize_as_transaction()
+    }
+
+    fn compress_v1(block: &Block, db: &MockLookup) -> CompressedBlockV1 {
+        match VersionedCompressedBlock::compress(block, db).unwrap() {
+            VersionedCompressedBlock::V1(compressed) => compressed,
+            compressed => panic!("unexpected version: {compressed:?}"),
+        }
+    }
+
+    #[test]
+    fn decompress_restores_compressed_block() {
+        let pointer = TxPointer::new(5u32.into(), 1);
+        let known_tx = TxId::from([3; 32]);
+        let db = MockLookup([(pointer, known_tx)].into_iter().collect());
+        let block = block(
+            10,
+            vec![
+                transfer(UtxoId::new(known_tx, 2), pointer),
+                transfer(UtxoId::new([4; 32].into(), 0), Default::default()),
+                Transaction::default_test_tx(),
+            ],
+        );
+
+        let bytes = compress(&block, &db).unwrap();
+        let decompressed = decompress(&bytes, &db).unwrap();
+
+        assert_eq!(decompressed, block);
+    }
+
+    #[test]
+    fn compress_references_known_utxos_and_registers_values_once() {
+        let pointer = TxPointer::new(5u32.into(), 1);
+        let known_tx = TxId::from([3; 32]);
+        let unknown_utxo = UtxoId::new([4; 32].into(), 0);
+        let db = MockLookup([(pointer, known_tx)].into_iter().collect());
+        let block = block(
+            10,
+            vec![
+                transfer(UtxoId::new(known_tx, 2), pointer),
+                transfer(unknown_utxo, Default::default()),
+            ],
+        );
+
+        let compressed = compress_v1(&block, &db);
+
+        assert_eq!(compressed.registry.addresses, vec![OWNER]);
+        assert_eq!(compressed.registry.asset_ids, vec![ASSET]);
+        let utxos: Vec<_> = compressed
+            .transactions
+            .iter()
+            .flat_map(|tx| &tx.transaction.inputs)
+            .map(|input| match input {
+                CompressedInput::CoinSigned { utxo, .. } => *utxo,
+                _ => panic!("unexpected input"),
+            })
+            .collect();
+        assert_eq!(
+            utxos,
+            vec![UtxoRef::Pointer(2), UtxoRef::Full(unknown_utxo)]
+        );
+    }
+
+    #[test]
+    fn compress_doesnt_reference_utxos_from_the_same_block() {
+        let pointer = TxPointer::new(10u32.into(), 0);
+        let tx_id = TxId::from([3; 32]);
+        let utxo_id = UtxoId::new(tx_id, 0);
+        let db = MockLookup([(pointer, tx_id)].into_iter().collect());
+        let block = block(10, vec![transfer(utxo_id, pointer)]);
+
+        let compressed = compress_v1(&block, &db);
+
+        assert!(matches!(
+            compressed.transactions[0].transaction.inputs[0],
+            CompressedInput::CoinSigned {
+                utxo: UtxoRef::Full(utxo),
+                ..
+            } if utxo == utxo_id
+        ));
+    }
+
+    #[test]
+    fn compressed_block_is_smaller_than_uncompressed() {
+        let pointer = TxPointer::new(5u32.into(), 1);
+        let known_tx = TxId::from([3; 32]);
+        let db = MockLookup([(pointer, known_tx)].into_iter().collect());
+        let transactions = (0..10)
+            .map(|index| transfer(UtxoId::new(known_tx, index), pointer))
+            .collect();
+        let block = block(10, transactions);
+
+        let compressed = compress(&block, &db).unwrap();
+        let uncompressed = postcard::to_allocvec(&block).unwrap();
+
+        assert!(compressed.len() < uncompressed.len());
+    }
+
+    #[test]
+    fn decompress_fails_for_unknown_utxo_reference() {
+        let pointer = TxPointer::new(5u32.into(), 1);
+        let known_tx = TxId::from([3; 32]);
+        let db = MockLookup([(pointer, known_tx)].into_iter().collect());
+        let block = block(10, vec![transfer(UtxoId::new(known_tx, 2), pointer)]);
+        let bytes = compress(&block, &db).unwrap();
+
+        let result = decompress(&bytes, &MockLookup::default());
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn compress_stores_same_bytecode_of_deployments_once() {
+        let db = MockLookup::default();
+        let bytecode = vec![1; 1024];
+        let block = block(
+            10,
+            vec![
+                deployment(bytecode.clone(), Salt::new([1; 32])),
+                deployment(bytecode.clone(), Salt::new([2; 32])),
+                deployment(vec![2; 16], Salt::new([3; 32])),
+                Transaction::default_test_tx(),
+            ],
+        );
+
+        let compressed = compress_v1(&block, &db);
+        let bytes = compress(&block, &db).unwrap();
+        let decompressed = decompress(&bytes, &db).unwrap();
+
+        assert_eq!(compressed.blobs, vec![bytecode, vec![2; 16]]);
+        let blobs: Vec<_> = compressed.transactions.iter().map(|tx| tx.blob).collect();
+        assert_eq!(blobs, vec![Some(0), Some(0), Some(1), None]);
+        assert_eq!(decompressed, block);
+    }
+
+    #[test]
+    fn decompress_restores_block_in_previous_version() {
+        let db = MockLookup::default();
+        let block = block(
+            10,
+            vec![transfer(UtxoId::new([4; 32].into(), 0), Default::default())],
+        );
+        let compressed = compress_v1(&block, &db);
+        let previous = VersionedCompressedBlock::V0(CompressedBlockV0 {
+            header: compressed.header,
+            registry: compressed.registry,
+            transactions: compressed
+                .transactions
+                .into_iter()
+                .map(|tx| tx.transaction)
+                .collect(),
+        });
+
+        let decompressed = previous.decompress(&db).unwrap();
+
+        assert_eq!(decompressed, block);
+    }
+}
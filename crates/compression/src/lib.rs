@@ -0,0 +1,36 @@
+//! The compression of the blocks for posting them to the DA layer.
+//!
+//! The compressed block is self-contained except for the UTXO ids: the coins
+//! spent from the previous blocks are referenced by the [`TxPointer`] of the
+//! transaction that created them instead of the full transaction id. The addresses
+//! and asset ids are stored once per block in the [`Registry`] and referenced by
+//! the index everywhere else.
+//!
+//! [`TxPointer`]: fuel_core_types::fuel_tx::TxPointer
+
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::cast_possible_truncation)]
+#![deny(unused_crate_dependencies)]
+#![deny(missing_docs)]
+#![deny(warnings)]
+
+mod block;
+pub mod ports;
+mod registry;
+
+pub use block::{
+    compress,
+    decompress,
+    CompressedBlockV0,
+    CompressedBlockV1,
+    CompressedInput,
+    CompressedOutput,
+    CompressedTransaction,
+    CompressedTransactionV1,
+    UtxoRef,
+    VersionedCompressedBlock,
+};
+pub use registry::{
+    Registry,
+    RegistryKey,
+};
@@ -0,0 +1,13 @@
+//! The ports required by the compression and decompression of the blocks.
+
+use fuel_core_types::fuel_tx::{
+    TxId,
+    TxPointer,
+};
+
+/// Provides access to the transactions of the already imported blocks.
+pub trait TransactionLookup {
+    /// Returns the id of the transaction at the `pointer`,
+    /// or `None` if the transaction is unknown.
+    fn transaction_id(&self, pointer: &TxPointer) -> anyhow::Result<Option<TxId>>;
+}
@@ -0,0 +1,106 @@
+use anyhow::anyhow;
+use fuel_core_types::fuel_types::{
+    Address,
+    AssetId,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    collections::HashMap,
+    hash::Hash,
+};
+
+/// The index of the value in the [`Registry`].
+pub type RegistryKey = u32;
+
+/// The dictionary of the values used by the block. Each value is stored only
+/// once, and the compressed transactions refer to it by the [`RegistryKey`].
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Registry {
+    /// The addresses of the owners and recipients of the coins.
+    pub addresses: Vec<Address>,
+    /// The asset ids of the coins.
+    pub asset_ids: Vec<AssetId>,
+}
+
+impl Registry {
+    /// Returns the address by the `key`.
+    pub fn address(&self, key: RegistryKey) -> anyhow::Result<Address> {
+        lookup(&self.addresses, key)
+    }
+
+    /// Returns the asset id by the `key`.
+    pub fn asset_id(&self, key: RegistryKey) -> anyhow::Result<AssetId> {
+        lookup(&self.asset_ids, key)
+    }
+}
+
+fn lookup<T: Copy>(values: &[T], key: RegistryKey) -> anyhow::Result<T> {
+    values
+        .get(key as usize)
+        .copied()
+        .ok_or_else(|| anyhow!("the registry doesn't contain the key {key}"))
+}
+
+/// Builds the [`Registry`] during the compression of the block.
+#[derive(Default)]
+pub(crate) struct RegistryBuilder {
+    registry: Registry,
+    addresses: HashMap<Address, RegistryKey>,
+    asset_ids: HashMap<AssetId, RegistryKey>,
+}
+
+impl RegistryBuilder {
+    pub fn address(&mut self, address: Address) -> anyhow::Result<RegistryKey> {
+        insert(&mut self.registry.addresses, &mut self.addresses, address)
+    }
+
+    pub fn asset_id(&mut self, asset_id: AssetId) -> anyhow::Result<RegistryKey> {
+        insert(&mut self.registry.asset_ids, &mut self.asset_ids, asset_id)
+    }
+
+    pub fn build(self) -> Registry {
+        self.registry
+    }
+}
+
+fn insert<T>(
+    values: &mut Vec<T>,
+    keys: &mut HashMap<T, RegistryKey>,
+    value: T,
+) -> anyhow::Result<RegistryKey>
+where
+    T: Copy + Eq + Hash,
+{
+    if let Some(key) = keys.get(&value) {
+        return Ok(*key)
+    }
+
+    let key = RegistryKey::try_from(values.len())?;
+    values.push(value);
+    keys.insert(value, key);
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_stores_each_value_once() {
+        let mut builder = RegistryBuilder::default();
+        let first = Address::from([1; 32]);
+        let second = Address::from([2; 32]);
+
+        let keys =
+            [first, second, first].map(|address| builder.address(address).unwrap());
+        let registry = builder.build();
+
+        assert_eq!(keys, [0, 1, 0]);
+        assert_eq!(registry.addresses, vec![first, second]);
+        assert_eq!(registry.address(1).unwrap(), second);
+        assert!(registry.address(2).is_err());
+    }
+}
@@ -0,0 +1,62 @@
+use std::process::Command;
+
+fn main() {
+    #[cfg(feature = "grpc")]
+    compile_protos();
+    emit_fingerprint();
+}
+
+#[cfg(feature = "grpc")]
+fn compile_protos() {
+    println!("cargo:rerun-if-changed=./proto/fuel_core.proto");
+
+    let protoc = protoc_bin_vendored::protoc_bin_path()
+        .expect("Failed to find the vendored `protoc`");
+    std::env::set_var("PROTOC", protoc);
+
+    tonic_build::configure()
+        .compile(&["proto/fuel_core.proto"], &["proto"])
+        .expect("Failed to compile the protobuf definitions");
+}
+
+/// Exposes the git commit and the enabled features of the build to the fingerprint
+/// of the node. Nothing depending on the build machine or the build time is included,
+/// so the same sources and features always produce the same fingerprint.
+fn emit_fingerprint() {
+    // Allows to set the commit when building from the sources without the git history.
+    println!("cargo:rerun-if-env-changed=FUEL_CORE_GIT_COMMIT");
+    let commit = std::env::var("FUEL_CORE_GIT_COMMIT")
+        .ok()
+        .or_else(git_commit)
+        .unwrap_or_else(|| "unknown".to_string());
+    println!("cargo:rustc-env=FUEL_CORE_GIT_COMMIT={commit}");
+
+    let mut features: Vec<_> = std::env::vars()
+        .filter_map(|(name, _)| {
+            name.strip_prefix("CARGO_FEATURE_")
+                .map(|feature| feature.to_lowercase().replace('_', "-"))
+        })
+        .collect();
+    features.sort();
+    println!("cargo:rustc-env=FUEL_CORE_FEATURES={}", features.join(","));
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None
+    }
+    Some(String::from_utf8(output.stdout).ok()?.trim().to_string())
+}
+
+fn git_commit() -> Option<String> {
+    let commit = git(&["rev-parse", "HEAD"])?;
+
+    // Rebuild the fingerprint when the checked out commit changes.
+    let git_dir = git(&["rev-parse", "--absolute-git-dir"])?;
+    println!("cargo:rerun-if-changed={git_dir}/HEAD");
+    if let Some(reference) = git(&["symbolic-ref", "-q", "HEAD"]) {
+        println!("cargo:rerun-if-changed={git_dir}/{reference}");
+    }
+    Some(commit)
+}
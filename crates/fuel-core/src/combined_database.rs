@@ -1,11 +1,18 @@
-use crate::database::{
-    database_description::{
-        off_chain::OffChain,
-        on_chain::OnChain,
-        relayer::Relayer,
+use crate::{
+    database::{
+        database_description::{
+            off_chain::OffChain,
+            on_chain::OnChain,
+            relayer::Relayer,
+            DatabaseDescription,
+        },
+        Database,
+        Result as DatabaseResult,
+    },
+    state::{
+        ColumnStatistics,
+        MemoryUsage,
     },
-    Database,
-    Result as DatabaseResult,
 };
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
@@ -13,6 +20,13 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
 };
 
+/// The statistics of the columns of one of the combined databases.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
+pub struct DatabaseStatistics {
+    pub name: String,
+    pub columns: Vec<ColumnStatistics>,
+}
+
 /// A database that combines the on-chain, off-chain and relayer databases into one entity.
 #[derive(Default, Clone)]
 pub struct CombinedDatabase {
@@ -55,6 +69,24 @@ impl CombinedDatabase {
         )
     }
 
+    /// Caches the contracts bytecode and info of the on-chain database,
+    /// see [`Database::with_contract_cache`].
+    pub fn with_contract_cache(self, capacity: usize) -> Self {
+        Self {
+            on_chain: self.on_chain.with_contract_cache(capacity),
+            ..self
+        }
+    }
+
+    /// Caches the unspent coins of the on-chain database,
+    /// see [`Database::with_coin_cache`].
+    pub fn with_coin_cache(self, capacity: usize) -> Self {
+        Self {
+            on_chain: self.on_chain.with_coin_cache(capacity),
+            ..self
+        }
+    }
+
     pub fn init(
         &mut self,
         block_height: &BlockHeight,
@@ -88,6 +120,33 @@ impl CombinedDatabase {
         &self.relayer
     }
 
+    /// Returns the on-disk statistics of the columns of each database.
+    pub fn statistics(&self) -> DatabaseResult<Vec<DatabaseStatistics>> {
+        Ok(vec![
+            DatabaseStatistics {
+                name: OnChain::name().to_string(),
+                columns: self.on_chain.statistics()?,
+            },
+            DatabaseStatistics {
+                name: OffChain::name().to_string(),
+                columns: self.off_chain.statistics()?,
+            },
+            DatabaseStatistics {
+                name: Relayer::name().to_string(),
+                columns: self.relayer.statistics()?,
+            },
+        ])
+    }
+
+    /// Returns the memory used by the caches and the buffers of all databases.
+    pub fn memory_usage(&self) -> DatabaseResult<MemoryUsage> {
+        Ok(self
+            .on_chain
+            .memory_usage()?
+            .saturating_add(self.off_chain.memory_usage()?)
+            .saturating_add(self.relayer.memory_usage()?))
+    }
+
     pub fn flush(self) -> DatabaseResult<()> {
         self.on_chain.flush()?;
         self.off_chain.flush()?;
@@ -9,8 +9,13 @@ use crate::{
         transaction::DatabaseTransaction,
     },
     state::{
-        in_memory::memory_store::MemoryStore,
+        in_memory::{
+            memory_store::MemoryStore,
+            pending_commit::PendingCommitView,
+        },
+        ColumnStatistics,
         DataSource,
+        MemoryUsage,
     },
 };
 use fuel_core_chain_config::{
@@ -33,6 +38,7 @@ use fuel_core_storage::{
         Value,
         WriteOperation,
     },
+    not_found,
     structured_storage::{
         StructuredStorage,
         TableWithBlueprint,
@@ -48,7 +54,11 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    fuel_types::BlockHeight,
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+    tai64::Tai64,
 };
 use std::{
     fmt::{
@@ -81,6 +91,7 @@ pub mod contracts;
 pub mod database_description;
 pub mod message;
 pub mod metadata;
+pub mod relayer;
 pub mod sealed_block;
 pub mod state;
 pub mod statistic;
@@ -189,6 +200,32 @@ where
     pub fn flush(self) -> DatabaseResult<()> {
         self.data.as_ref().flush()
     }
+
+    /// Returns the on-disk statistics of each column of the database.
+    pub fn statistics(&self) -> DatabaseResult<Vec<ColumnStatistics>> {
+        self.data.as_ref().statistics()
+    }
+
+    /// Returns the memory used by the caches and the buffers of the database.
+    pub fn memory_usage(&self) -> DatabaseResult<MemoryUsage> {
+        self.data.as_ref().memory_usage()
+    }
+
+    /// Returns the view of the database with the uncommitted changes of the `transaction`
+    /// on top. The view stays consistent while the `transaction` is committed
+    /// into the database, see [`PendingCommitView`].
+    pub fn with_pending_changes(&self, transaction: &Self) -> StorageResult<Self> {
+        let changes = transaction
+            .data
+            .as_ref()
+            .uncommitted_changes()
+            .unwrap_or_default();
+        let view = PendingCommitView::new(self.data.as_ref().clone(), changes)?;
+        Ok(Self {
+            data: StructuredStorage::new(Arc::new(view).into()),
+            _drop: self._drop.clone(),
+        })
+    }
 }
 
 impl<Description> KeyValueStore for DataSource<Description>
@@ -411,9 +448,27 @@ impl ChainConfigDb for Database {
         Self::get_message_config(self).map_err(Into::into)
     }
 
+    fn get_spent_message_config(&self) -> StorageResult<Option<Vec<Nonce>>> {
+        Self::get_spent_message_config(self)
+    }
+
+    fn get_da_block_height(&self) -> StorageResult<DaBlockHeight> {
+        let block = self
+            .latest_compressed_block()?
+            .ok_or(not_found!("FuelBlocks"))?;
+        Ok(block.header().da_height)
+    }
+
     fn get_block_height(&self) -> StorageResult<BlockHeight> {
         self.latest_height()
     }
+
+    fn get_block_time(&self) -> StorageResult<Tai64> {
+        let block = self
+            .latest_compressed_block()?
+            .ok_or(not_found!("FuelBlocks"))?;
+        Ok(block.header().time())
+    }
 }
 
 impl AtomicView for Database<OnChain> {
@@ -7,11 +7,13 @@ use crate::{
         owner_coin_id_key,
         OwnedCoins,
     },
+    state::coin_cache::CoinCache,
 };
 use fuel_core_chain_config::CoinConfig;
 use fuel_core_storage::{
     iter::IterDirection,
     not_found,
+    structured_storage::StructuredStorage,
     tables::Coins,
     Result as StorageResult,
     StorageAsRef,
@@ -24,6 +26,10 @@ use fuel_core_types::{
         UtxoId,
     },
 };
+use std::{
+    num::NonZeroUsize,
+    sync::Arc,
+};
 
 impl Database<OffChain> {
     pub fn owned_coins_ids(
@@ -50,6 +56,19 @@ impl Database<OffChain> {
 }
 
 impl Database {
+    /// Caches up to `capacity` unspent coins in memory. The cache is shared by
+    /// all clones of the returned database and by the transactions created from them.
+    pub fn with_coin_cache(self, capacity: usize) -> Self {
+        let Some(capacity) = NonZeroUsize::new(capacity) else {
+            return self
+        };
+        let cache = CoinCache::new(self.data.as_ref().clone(), capacity);
+        Self {
+            data: StructuredStorage::new(Arc::new(cache).into()),
+            _drop: self._drop,
+        }
+    }
+
     pub fn coin(&self, utxo_id: &UtxoId) -> StorageResult<CompressedCoin> {
         let coin = self
             .storage_as_ref::<Coins>()
@@ -1,7 +1,15 @@
-use crate::database::Database;
-use fuel_core_chain_config::ContractConfig;
+use crate::{
+    database::Database,
+    state::contract_cache::ContractCache,
+};
+use fuel_core_chain_config::{
+    ContractBalanceRow,
+    ContractConfig,
+    ContractStateRow,
+};
 use fuel_core_storage::{
     iter::IterDirection,
+    structured_storage::StructuredStorage,
     tables::{
         ContractsAssets,
         ContractsInfo,
@@ -18,11 +26,57 @@ use fuel_core_types::fuel_types::{
     ContractId,
     Word,
 };
+use std::sync::Arc;
 
 impl Database {
+    /// Caches up to `capacity` bytes of the contracts bytecode and info in memory.
+    /// The cache is shared by all clones of the returned database and
+    /// by the transactions created from them.
+    pub fn with_contract_cache(self, capacity: usize) -> Self {
+        let cache = ContractCache::new(self.data.as_ref().clone(), capacity);
+        Self {
+            data: StructuredStorage::new(Arc::new(cache).into()),
+            _drop: self._drop,
+        }
+    }
+
     pub fn get_contract_config_by_id(
         &self,
         contract_id: ContractId,
+    ) -> StorageResult<ContractConfig> {
+        let state = Some(
+            self.iter_all_by_prefix::<ContractsState, _>(Some(contract_id.as_ref()))
+                .map(|res| {
+                    let (key, value) = res.map(|(key, value)| (key, value.0))?;
+
+                    Ok((*key.state_key(), value))
+                })
+                .filter(|val| val.is_ok())
+                .collect::<StorageResult<Vec<_>>>()?,
+        );
+
+        let balances = Some(
+            self.iter_all_by_prefix::<ContractsAssets, _>(Some(contract_id.as_ref()))
+                .map(|res| {
+                    let (key, value) = res?;
+
+                    Ok((*key.asset_id(), value))
+                })
+                .filter(|val| val.is_ok())
+                .collect::<StorageResult<Vec<_>>>()?,
+        );
+
+        Ok(ContractConfig {
+            state,
+            balances,
+            ..self.get_contract_config_without_slots(contract_id)?
+        })
+    }
+
+    /// Returns the config of the contract with the empty state and balances.
+    pub fn get_contract_config_without_slots(
+        &self,
+        contract_id: ContractId,
     ) -> StorageResult<ContractConfig> {
         let code: Vec<u8> = self
             .storage::<ContractsRawCode>()
@@ -47,34 +101,12 @@ impl Database {
         let utxo_id = latest_utxo.utxo_id();
         let tx_pointer = latest_utxo.tx_pointer();
 
-        let state = Some(
-            self.iter_all_by_prefix::<ContractsState, _>(Some(contract_id.as_ref()))
-                .map(|res| {
-                    let (key, value) = res.map(|(key, value)| (key, value.0))?;
-
-                    Ok((*key.state_key(), value))
-                })
-                .filter(|val| val.is_ok())
-                .collect::<StorageResult<Vec<_>>>()?,
-        );
-
-        let balances = Some(
-            self.iter_all_by_prefix::<ContractsAssets, _>(Some(contract_id.as_ref()))
-                .map(|res| {
-                    let (key, value) = res?;
-
-                    Ok((*key.asset_id(), value))
-                })
-                .filter(|val| val.is_ok())
-                .collect::<StorageResult<Vec<_>>>()?,
-        );
-
         Ok(ContractConfig {
             contract_id,
             code,
             salt,
-            state,
-            balances,
+            state: Some(vec![]),
+            balances: Some(vec![]),
             tx_id: Some(*utxo_id.tx_id()),
             output_index: Some(utxo_id.output_index()),
             tx_pointer_block_height: Some(tx_pointer.block_height()),
@@ -82,6 +114,46 @@ impl Database {
         })
     }
 
+    /// Returns the configs of *alive* contracts without their state and balances,
+    /// which are returned by [`Self::contract_state_rows`] and
+    /// [`Self::contract_balance_rows`].
+    pub fn contract_configs_without_slots(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<ContractConfig>> + '_ {
+        self.iter_all::<ContractsRawCode>(None).map(|res| {
+            let contract_id = res?.0;
+            self.get_contract_config_without_slots(contract_id)
+        })
+    }
+
+    /// Returns the state slots of all contracts. The slots of one contract follow each other.
+    pub fn contract_state_rows(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<ContractStateRow>> + '_ {
+        self.iter_all::<ContractsState>(None).map(|res| {
+            let (key, value) = res?;
+            Ok(ContractStateRow {
+                contract_id: *key.contract_id(),
+                key: *key.state_key(),
+                value: value.0,
+            })
+        })
+    }
+
+    /// Returns the balances of all contracts. The balances of one contract follow each other.
+    pub fn contract_balance_rows(
+        &self,
+    ) -> impl Iterator<Item = StorageResult<ContractBalanceRow>> + '_ {
+        self.iter_all::<ContractsAssets>(None).map(|res| {
+            let (key, amount) = res?;
+            Ok(ContractBalanceRow {
+                contract_id: *key.contract_id(),
+                asset_id: *key.asset_id(),
+                amount,
+            })
+        })
+    }
+
     pub fn contract_balances(
         &self,
         contract: ContractId,
@@ -27,7 +27,8 @@ impl DatabaseDescription for OffChain {
         match column {
             Self::Column::OwnedCoins
             | Self::Column::TransactionsByOwnerBlockIdx
-            | Self::Column::OwnedMessageIds => {
+            | Self::Column::OwnedMessageIds
+            | Self::Column::OwnedTransferEvents => {
                 // prefix is address length
                 Some(32)
             }
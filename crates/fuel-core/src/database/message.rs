@@ -86,6 +86,15 @@ impl Database {
         Ok(Some(configs))
     }
 
+    pub fn get_spent_message_config(&self) -> StorageResult<Option<Vec<Nonce>>> {
+        let nonces = self
+            .iter_all::<SpentMessages>(None)
+            .map(|res| res.map(|(nonce, _)| nonce))
+            .collect::<StorageResult<Vec<Nonce>>>()?;
+
+        Ok(Some(nonces))
+    }
+
     pub fn message_is_spent(&self, id: &Nonce) -> StorageResult<bool> {
         fuel_core_storage::StorageAsRef::storage::<SpentMessages>(&self).contains_key(id)
     }
@@ -0,0 +1,50 @@
+use crate::database::{
+    database_description::relayer::Relayer,
+    Database,
+};
+use fuel_core_chain_config::RelayerConfig;
+use fuel_core_storage::Result as StorageResult;
+
+impl Database<Relayer> {
+    /// Returns the events and the synced DA height of the relayer,
+    /// or `None` if the relayer is disabled.
+    pub fn get_relayer_config(&self) -> StorageResult<Option<RelayerConfig>> {
+        #[cfg(feature = "relayer")]
+        {
+            use fuel_core_chain_config::MessageOriginConfig;
+            use fuel_core_relayer::{
+                ports::RelayerDb,
+                storage::{
+                    EventsHistory,
+                    MessageOrigins,
+                },
+            };
+            use fuel_core_types::services::relayer::Event;
+
+            let messages = self
+                .iter_all::<EventsHistory>(None)
+                .map(|res| res.map(|(_, events)| events))
+                .collect::<StorageResult<Vec<_>>>()?
+                .into_iter()
+                .flatten()
+                .map(|event| match event {
+                    Event::Message(message) => message.into(),
+                })
+                .collect();
+            let message_origins = self
+                .iter_all::<MessageOrigins>(None)
+                .map(|res| res.map(MessageOriginConfig::from))
+                .collect::<StorageResult<Vec<_>>>()?;
+
+            Ok(Some(RelayerConfig {
+                da_block_height: self.get_finalized_da_height()?,
+                messages,
+                message_origins,
+            }))
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            Ok(None)
+        }
+    }
+}
@@ -18,6 +18,7 @@ use fuel_core_storage::{
     Result as StorageResult,
     StorageMutate,
 };
+use fuel_core_types::fuel_types::BlockHeight;
 
 /// The table that stores all statistic about blockchain. Each key is a string, while the value
 /// depends on the context.
@@ -75,3 +76,32 @@ impl Database<OffChain> {
         Ok(new_tx_count)
     }
 }
+
+/// The height of the last block header accepted by the shared sequencer.
+pub(crate) const SHARED_SEQUENCER_PUBLISHED_HEIGHT: &str =
+    "shared_sequencer_published_height";
+
+impl Database<OffChain> {
+    pub fn shared_sequencer_published_height(
+        &self,
+    ) -> StorageResult<Option<BlockHeight>> {
+        use fuel_core_storage::StorageAsRef;
+        let height = self
+            .storage::<StatisticTable<u32>>()
+            .get(SHARED_SEQUENCER_PUBLISHED_HEIGHT)?
+            .map(|height| height.into_owned().into());
+        Ok(height)
+    }
+
+    pub fn set_shared_sequencer_published_height(
+        &mut self,
+        height: &BlockHeight,
+    ) -> StorageResult<()> {
+        <_ as StorageMutate<StatisticTable<u32>>>::insert(
+            &mut self.data,
+            SHARED_SEQUENCER_PUBLISHED_HEIGHT,
+            &u32::from(*height),
+        )?;
+        Ok(())
+    }
+}
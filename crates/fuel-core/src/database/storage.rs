@@ -4,13 +4,41 @@ use crate::{
         Database,
     },
     fuel_core_graphql_api::storage::{
-        blocks::FuelBlockIdsToHeights,
+        assets::AssetsInfo,
+        blocks::{
+            BlockProducerFingerprints,
+            FuelBlockIdsToHeights,
+        },
         coins::OwnedCoins,
-        messages::OwnedMessageIds,
+        contracts::{
+            ContractsDeployments,
+            ContractsVerifications,
+        },
+        equivocations::EquivocationProofs,
+        fees::{
+            BlockFees,
+            TransactionFees,
+        },
+        messages::{
+            MessageOutboxLeaves,
+            MessageOutboxMerkleData,
+            MessageOutboxMerkleMetadata,
+            OwnedMessageIds,
+        },
+        statistics::{
+            AddressesFirstActivity,
+            BlockStatistics,
+            ChainStatistics,
+        },
         transactions::{
+            MessageSpendingTransactions,
             OwnedTransactions,
+            PrunableReceipts,
+            SpendingTransactions,
             TransactionStatuses,
+            TransactionVmMetrics,
         },
+        transfers::OwnedTransferEvents,
     },
     state::DataSource,
 };
@@ -94,16 +122,35 @@ use_structured_implementation!(
     OwnedCoins,
     Messages,
     OwnedMessageIds,
+    MessageOutboxLeaves,
+    MessageOutboxMerkleData,
+    MessageOutboxMerkleMetadata,
     OwnedTransactions,
+    PrunableReceipts,
     TransactionStatuses,
     FuelBlockIdsToHeights,
+    BlockProducerFingerprints,
+    BlockFees,
+    TransactionFees,
+    TransactionVmMetrics,
+    SpendingTransactions,
+    MessageSpendingTransactions,
+    BlockStatistics,
+    ChainStatistics,
+    AddressesFirstActivity,
+    OwnedTransferEvents,
+    AssetsInfo,
+    ContractsDeployments,
+    ContractsVerifications,
+    EquivocationProofs,
     FuelBlockMerkleData,
     FuelBlockMerkleMetadata
 );
 #[cfg(feature = "relayer")]
 use_structured_implementation!(
     fuel_core_relayer::storage::DaHeightTable,
-    fuel_core_relayer::storage::EventsHistory
+    fuel_core_relayer::storage::EventsHistory,
+    fuel_core_relayer::storage::MessageOrigins
 );
 
 impl<Description, M> StorageInspect<M> for Database<Description>
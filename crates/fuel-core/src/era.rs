@@ -0,0 +1,378 @@
+//! The era files with the fixed-size ranges of the sealed blocks, used to bootstrap
+//! the node from the disk or over HTTP before the sync with the network.
+//!
+//! The era file is the sequence of the consecutive sealed blocks with the index:
+//!
+//! ```text
+//! magic: b"FUELERA\0"
+//! version: u32
+//! start: u32                  the height of the first block
+//! count: u32                  the number of blocks
+//! records: count * {
+//!     length: u32
+//!     block: [u8; length]     the `SealedBlock` encoded with postcard
+//! }
+//! index: count * u64          the offset of each record from the beginning of the file
+//! index_offset: u64           the offset of the index from the beginning of the file
+//! ```
+//!
+//! All integers are little-endian. The era `k` of the archive contains the blocks
+//! at the heights `k * era_size..(k + 1) * era_size`. The archive directory contains
+//! the era files and the [`EraManifest`] in the `manifest.json` file.
+//!
+//! The files aren't trusted: the importer checks the seal of each block
+//! and executes it, like the block received from the network.
+
+use anyhow::Context;
+use fuel_core_types::{
+    blockchain::SealedBlock,
+    fuel_crypto::Hasher,
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    fmt,
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// The first bytes of the era file.
+pub const ERA_MAGIC: [u8; 8] = *b"FUELERA\0";
+/// The version of the era file layout.
+pub const ERA_VERSION: u32 = 1;
+/// The name of the manifest file in the archive.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+const HEADER_SIZE: usize = 20;
+const FOOTER_SIZE: usize = 8;
+
+/// Returns the name of the era file starting at the `start` height.
+pub fn era_file_name(start: BlockHeight) -> String {
+    format!("era-{:010}.era", *start)
+}
+
+/// Encodes the consecutive `blocks` into the era file.
+pub fn encode_era(blocks: &[SealedBlock]) -> anyhow::Result<Vec<u8>> {
+    let start = blocks
+        .first()
+        .map(|block| *block.entity.header().height())
+        .ok_or_else(|| anyhow::anyhow!("The era should contain at least one block"))?;
+    let count = u32::try_from(blocks.len())?;
+
+    let mut bytes = Vec::with_capacity(HEADER_SIZE);
+    bytes.extend_from_slice(&ERA_MAGIC);
+    bytes.extend_from_slice(&ERA_VERSION.to_le_bytes());
+    bytes.extend_from_slice(&start.to_le_bytes());
+    bytes.extend_from_slice(&count.to_le_bytes());
+
+    let mut offsets = Vec::with_capacity(blocks.len());
+    let mut expected_height = start;
+    for block in blocks {
+        let height = *block.entity.header().height();
+        anyhow::ensure!(
+            height == expected_height,
+            "The era contains the block at the height {height} instead of {expected_height}"
+        );
+        expected_height = expected_height
+            .succ()
+            .ok_or_else(|| anyhow::anyhow!("The block height overflows"))?;
+
+        let record = postcard::to_allocvec(block)?;
+        offsets.push(u64::try_from(bytes.len())?);
+        bytes.extend_from_slice(&u32::try_from(record.len())?.to_le_bytes());
+        bytes.extend_from_slice(&record);
+    }
+
+    let index_offset = u64::try_from(bytes.len())?;
+    for offset in offsets {
+        bytes.extend_from_slice(&offset.to_le_bytes());
+    }
+    bytes.extend_from_slice(&index_offset.to_le_bytes());
+    Ok(bytes)
+}
+
+/// The decoded era file with the random access to its blocks.
+#[derive(Debug, Clone)]
+pub struct Era {
+    bytes: Vec<u8>,
+    start: BlockHeight,
+    offsets: Vec<usize>,
+}
+
+impl Era {
+    /// Checks the layout of the era file and decodes its index.
+    pub fn decode(bytes: Vec<u8>) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            bytes.get(..ERA_MAGIC.len()) == Some(&ERA_MAGIC[..]),
+            "The file is not an era file"
+        );
+        let version = read_u32(&bytes, 8)?;
+        anyhow::ensure!(
+            version == ERA_VERSION,
+            "Unsupported version {version} of the era file"
+        );
+        let start = BlockHeight::new(read_u32(&bytes, 12)?);
+        let count = usize::try_from(read_u32(&bytes, 16)?)?;
+
+        let footer = bytes
+            .len()
+            .checked_sub(FOOTER_SIZE)
+            .context("The era file is truncated")?;
+        let index_offset = usize::try_from(read_u64(&bytes, footer)?)?;
+        let index_size = count.checked_mul(8).context("The era index is too big")?;
+        anyhow::ensure!(
+            index_offset >= HEADER_SIZE
+                && index_offset.checked_add(index_size) == Some(footer),
+            "The era index doesn't match the number of blocks"
+        );
+
+        let mut offsets = Vec::with_capacity(count);
+        for i in 0..count {
+            let position = index_offset
+                .checked_add(i.checked_mul(8).context("The era index is too big")?)
+                .context("The era index is too big")?;
+            let offset = usize::try_from(read_u64(&bytes, position)?)?;
+            anyhow::ensure!(
+                (HEADER_SIZE..index_offset).contains(&offset),
+                "The era index points outside of the records"
+            );
+            offsets.push(offset);
+        }
+
+        Ok(Self {
+            bytes,
+            start,
+            offsets,
+        })
+    }
+
+    /// The height of the first block of the era.
+    pub fn start(&self) -> BlockHeight {
+        self.start
+    }
+
+    /// The number of blocks in the era.
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Returns `true` if the era doesn't contain any blocks.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Decodes the block at the `height`, if the era contains it.
+    pub fn block(&self, height: BlockHeight) -> anyhow::Result<Option<SealedBlock>> {
+        let Some(index) = height.checked_sub(*self.start) else {
+            return Ok(None)
+        };
+        let Some(offset) = self.offsets.get(usize::try_from(index)?).copied() else {
+            return Ok(None)
+        };
+
+        let length = usize::try_from(read_u32(&self.bytes, offset)?)?;
+        let record_start = offset.checked_add(4).context("The era is corrupted")?;
+        let record_end = record_start
+            .checked_add(length)
+            .context("The era is corrupted")?;
+        let record = self
+            .bytes
+            .get(record_start..record_end)
+            .context("The era record is truncated")?;
+        let block: SealedBlock = postcard::from_bytes(record).with_context(|| {
+            format!("Failed to decode the block at the height {height}")
+        })?;
+        anyhow::ensure!(
+            *block.entity.header().height() == height,
+            "The era record of the height {height} contains another block"
+        );
+        Ok(Some(block))
+    }
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> anyhow::Result<u32> {
+    let end = offset.checked_add(4).context("The era is corrupted")?;
+    let slice = bytes
+        .get(offset..end)
+        .context("The era file is truncated")?;
+    Ok(u32::from_le_bytes(slice.try_into()?))
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> anyhow::Result<u64> {
+    let end = offset.checked_add(8).context("The era is corrupted")?;
+    let slice = bytes
+        .get(offset..end)
+        .context("The era file is truncated")?;
+    Ok(u64::from_le_bytes(slice.try_into()?))
+}
+
+/// The list of the era files of the archive.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EraManifest {
+    /// The number of blocks in each era.
+    pub era_size: u32,
+    /// The era files sorted by the height.
+    pub eras: Vec<EraEntry>,
+}
+
+/// The era file of the archive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EraEntry {
+    /// The height of the first block.
+    pub start: BlockHeight,
+    /// The number of blocks.
+    pub count: u32,
+    /// The name of the file in the archive.
+    pub file: String,
+    /// The SHA-256 hash of the file.
+    pub checksum: Bytes32,
+}
+
+impl EraManifest {
+    /// Returns the era containing the block at the `height`.
+    pub fn era_of(&self, height: BlockHeight) -> Option<&EraEntry> {
+        self.eras.iter().find(|era| {
+            let end = u64::from(*era.start).saturating_add(u64::from(era.count));
+            height >= era.start && u64::from(*height) < end
+        })
+    }
+
+    /// Adds the era, replacing the era with the same start.
+    pub fn insert(&mut self, entry: EraEntry) {
+        self.eras.retain(|era| era.start != entry.start);
+        self.eras.push(entry);
+        self.eras.sort_by_key(|era| era.start);
+    }
+}
+
+/// Returns the checksum of the era file stored in the [`EraManifest`].
+pub fn checksum(bytes: &[u8]) -> Bytes32 {
+    Hasher::hash(bytes)
+}
+
+/// The location of the archive with the era files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EraSource {
+    /// The local directory.
+    Directory(PathBuf),
+    /// The base URL of the HTTP server.
+    Url(String),
+}
+
+impl FromStr for EraSource {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("http://") || s.starts_with("https://") {
+            Ok(Self::Url(s.trim_end_matches('/').to_string()))
+        } else {
+            Ok(Self::Directory(PathBuf::from(s)))
+        }
+    }
+}
+
+impl fmt::Display for EraSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Directory(path) => write!(f, "{}", path.display()),
+            Self::Url(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+#[allow(clippy::arithmetic_side_effects)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::blockchain::block::Block;
+
+    fn block(height: u32) -> SealedBlock {
+        let mut block = Block::default();
+        block.header_mut().set_block_height(height.into());
+        block.header_mut().recalculate_metadata();
+        SealedBlock {
+            entity: block,
+            consensus: Default::default(),
+        }
+    }
+
+    #[test]
+    fn era_round_trip() {
+        // Given
+        let blocks: Vec<_> = (10..15).map(block).collect();
+
+        // When
+        let era = Era::decode(encode_era(&blocks).unwrap()).unwrap();
+
+        // Then
+        assert_eq!(era.start(), 10.into());
+        assert_eq!(era.len(), 5);
+        for expected in blocks {
+            let height = *expected.entity.header().height();
+            assert_eq!(era.block(height).unwrap(), Some(expected));
+        }
+        assert_eq!(era.block(9.into()).unwrap(), None);
+        assert_eq!(era.block(15.into()).unwrap(), None);
+    }
+
+    #[test]
+    fn encode_rejects_gaps_between_blocks() {
+        let result = encode_era(&[block(1), block(3)]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decode_rejects_corrupted_files() {
+        // Given
+        let bytes = encode_era(&[block(1), block(2)]).unwrap();
+        let mut wrong_magic = bytes.clone();
+        wrong_magic[0] = b'X';
+        let truncated = bytes[..bytes.len() - 1].to_vec();
+
+        // Then
+        assert!(Era::decode(wrong_magic).is_err());
+        assert!(Era::decode(truncated).is_err());
+    }
+
+    #[test]
+    fn manifest_finds_era_of_height() {
+        // Given
+        let mut manifest = EraManifest {
+            era_size: 10,
+            eras: vec![],
+        };
+        for start in [10u32, 0] {
+            manifest.insert(EraEntry {
+                start: start.into(),
+                count: 10,
+                file: era_file_name(start.into()),
+                checksum: Default::default(),
+            });
+        }
+
+        // Then
+        assert_eq!(manifest.eras[0].start, 0.into());
+        assert_eq!(manifest.era_of(9.into()).unwrap().start, 0.into());
+        assert_eq!(manifest.era_of(10.into()).unwrap().start, 10.into());
+        assert!(manifest.era_of(20.into()).is_none());
+    }
+
+    #[test]
+    fn source_is_parsed_from_path_or_url() {
+        assert_eq!(
+            "https://example.com/eras/".parse::<EraSource>().unwrap(),
+            EraSource::Url("https://example.com/eras".to_string())
+        );
+        assert_eq!(
+            "/data/eras".parse::<EraSource>().unwrap(),
+            EraSource::Directory("/data/eras".into())
+        );
+    }
+}
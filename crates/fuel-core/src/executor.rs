@@ -3,6 +3,10 @@
 #[cfg(test)]
 mod tests {
     use crate::database::Database;
+    use fuel_core_chain_config::{
+        BlobPricing,
+        FeeFreeAllowance,
+    };
     use fuel_core_executor::{
         executor::{
             block_component::PartialBlockComponent,
@@ -11,7 +15,11 @@ mod tests {
             Executor,
             OnceTransactionsSource,
         },
-        ports::RelayerPort,
+        ports::{
+            MaybeCheckedTransaction,
+            RelayerPort,
+            TransactionsSource,
+        },
         refs::ContractRef,
         Config,
     };
@@ -33,9 +41,11 @@ mod tests {
             },
             header::{
                 ConsensusHeader,
+                HeaderField,
                 PartialBlockHeader,
             },
             primitives::DaBlockHeight,
+            rules::LimitError,
         },
         entities::{
             coins::coin::CompressedCoin,
@@ -115,6 +125,7 @@ mod tests {
         services::{
             block_producer::Components,
             executor::{
+                ChangeRefund,
                 Error as ExecutorError,
                 Event as ExecutorEvent,
                 ExecutionBlock,
@@ -136,9 +147,17 @@ mod tests {
     };
     use std::{
         ops::DerefMut,
-        sync::Arc,
+        sync::{
+            atomic::{
+                AtomicUsize,
+                Ordering,
+            },
+            Arc,
+        },
     };
 
+    mod invariants;
+
     #[derive(Clone, Debug)]
     struct DisabledRelayer;
 
@@ -575,6 +594,62 @@ mod tests {
             assert_eq!(amount, expected_fee_amount_1 + expected_fee_amount_2);
         }
 
+        #[test]
+        fn executor_reports_fee_breakdown_of_transaction() {
+            let price = 1;
+            let max_fee = 10000;
+            let tip = 100;
+            let mut script = TxBuilder::new(1u64)
+                .script_gas_limit(0)
+                .max_fee_limit(max_fee)
+                .coin_input(AssetId::BASE, max_fee)
+                .change_output(AssetId::BASE)
+                .build()
+                .transaction()
+                .clone();
+            script.policies_mut().set(PolicyType::Tip, Some(tip));
+
+            let recipient = Contract::EMPTY_CONTRACT_ID;
+            let config = Config {
+                coinbase_recipient: recipient,
+                ..Default::default()
+            };
+            let database = &mut Database::default();
+            database
+                .storage::<ContractsRawCode>()
+                .insert(&recipient, &[])
+                .expect("Should insert coinbase contract");
+            let producer = create_executor(database.clone(), config);
+
+            let ExecutionResult {
+                block, tx_status, ..
+            } = producer
+                .execute_without_commit(ExecutionTypes::Production(Components {
+                    header_to_produce: PartialBlockHeader::default(),
+                    transactions_source: OnceTransactionsSource::new(vec![script.into()]),
+                    gas_price: price,
+                    gas_limit: u64::MAX,
+                }))
+                .unwrap()
+                .into_result();
+
+            let fee = tx_status[0].result.fee();
+            let mint = block.transactions()[1].as_mint().unwrap();
+            assert_eq!(fee.gas_price, price);
+            assert_eq!(fee.tip, tip);
+            assert_eq!(fee.total_fee, *mint.mint_amount());
+            assert_eq!(fee.base_fee.saturating_add(fee.tip), fee.total_fee);
+            assert!(fee.gas_used > 0);
+            assert_eq!(
+                fee.refunds,
+                vec![ChangeRefund {
+                    output_index: 0,
+                    asset_id: AssetId::BASE,
+                    amount: max_fee.saturating_sub(fee.total_fee),
+                }]
+            );
+        }
+
         #[test]
         fn skip_coinbase_during_dry_run() {
             let price = 1;
@@ -1230,6 +1305,169 @@ mod tests {
         assert!(matches!(verify_result, Err(ExecutorError::InvalidBlockId)))
     }
 
+    #[test]
+    fn executor_reports_diverging_header_field_in_strict_mode() {
+        let tx: Transaction = TxBuilder::new(2322u64)
+            .script_gas_limit(1)
+            .coin_input(Default::default(), 10)
+            .change_output(Default::default())
+            .build()
+            .transaction()
+            .clone()
+            .into();
+
+        let producer = create_executor(Default::default(), Default::default());
+
+        let verifier = create_executor(
+            Default::default(),
+            Config {
+                strict_header_verification: true,
+                ..Default::default()
+            },
+        );
+
+        let mut block = Block::default();
+        *block.transactions_mut() = vec![tx];
+
+        let ExecutionResult { mut block, .. } = producer
+            .execute_and_commit(
+                ExecutionBlock::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+
+        block.header_mut().set_message_receipt_root([1; 32].into());
+        block.header_mut().recalculate_metadata();
+
+        let verify_result = verifier
+            .execute_and_commit(ExecutionBlock::Validation(block), Default::default());
+
+        assert!(matches!(
+            verify_result,
+            Err(ExecutorError::BlockHeaderMismatch(
+                HeaderField::MessageReceiptRoot
+            ))
+        ))
+    }
+
+    #[test]
+    fn executor_passes_reexecution_audit_of_produced_block() {
+        let tx: Transaction = TxBuilder::new(2322u64)
+            .script_gas_limit(1)
+            .coin_input(Default::default(), 10)
+            .change_output(Default::default())
+            .build()
+            .transaction()
+            .clone()
+            .into();
+
+        let producer = create_executor(
+            Default::default(),
+            Config {
+                reexecution_audit: true,
+                ..Default::default()
+            },
+        );
+
+        let mut header = PartialBlockHeader::default();
+        header.consensus.height = 1.into();
+
+        let result = producer
+            .execute_without_commit(ExecutionTypes::Production(Components {
+                header_to_produce: header,
+                transactions_source: OnceTransactionsSource::new(vec![tx]),
+                gas_price: 0,
+                gas_limit: u64::MAX,
+            }))
+            .expect("The re-execution should match the produced block")
+            .into_result();
+
+        assert!(result.skipped_transactions.is_empty());
+        assert_eq!(result.block.transactions().len(), 2);
+    }
+
+    #[test]
+    fn executor_meters_vm_of_produced_transactions() {
+        // Given
+        let tx = TransactionBuilder::script(
+            vec![op::noop(), op::noop(), op::ret(RegId::ONE)]
+                .into_iter()
+                .collect(),
+            vec![],
+        )
+        .add_random_fee_input()
+        .script_gas_limit(1000)
+        .finalize_as_transaction();
+        let producer = create_executor(
+            Default::default(),
+            Config {
+                vm_metrics: true,
+                ..Default::default()
+            },
+        );
+
+        // When
+        let result = producer
+            .execute_without_commit(ExecutionTypes::Production(Components {
+                header_to_produce: Default::default(),
+                transactions_source: OnceTransactionsSource::new(vec![tx]),
+                gas_price: 0,
+                gas_limit: u64::MAX,
+            }))
+            .unwrap()
+            .into_result();
+
+        // Then
+        assert!(result.skipped_transactions.is_empty());
+        let vm_metrics = result.tx_status[0].vm_metrics.expect("The VM is metered");
+        assert_eq!(vm_metrics.instructions, 3);
+        assert!(vm_metrics.memory_watermark > 0);
+        // The `Mint` transaction is not executed by the VM.
+        assert_eq!(result.tx_status[1].vm_metrics, None);
+    }
+
+    #[test]
+    fn executor_invalidates_blocks_exceeding_block_gas_limit() {
+        let tx: Transaction = TxBuilder::new(2322u64)
+            .script_gas_limit(1)
+            .coin_input(Default::default(), 10)
+            .change_output(Default::default())
+            .build()
+            .transaction()
+            .clone()
+            .into();
+
+        let producer = create_executor(Default::default(), Default::default());
+
+        let verifier = create_executor(
+            Default::default(),
+            Config {
+                block_gas_limit: 1,
+                ..Default::default()
+            },
+        );
+
+        let mut block = Block::default();
+        *block.transactions_mut() = vec![tx];
+
+        let ExecutionResult { block, .. } = producer
+            .execute_and_commit(
+                ExecutionBlock::Production(block.into()),
+                Default::default(),
+            )
+            .unwrap();
+
+        let verify_result = verifier
+            .execute_and_commit(ExecutionBlock::Validation(block), Default::default());
+
+        assert!(matches!(
+            verify_result,
+            Err(ExecutorError::LimitExceeded(
+                LimitError::TransactionExceedsBlockGasLimit { block_limit: 1, .. }
+            ))
+        ))
+    }
+
     // invalidate a block if a tx is missing at least one coin input
     #[test]
     fn executor_invalidates_missing_coin_input() {
@@ -1531,6 +1769,69 @@ mod tests {
         assert_eq!(tx2_index_in_the_block, 0);
     }
 
+    #[test]
+    fn production_stops_including_transactions_when_source_is_exhausted() {
+        // Given
+        struct ExhaustibleSource {
+            transactions: OnceTransactionsSource,
+            remaining: AtomicUsize,
+        }
+
+        impl TransactionsSource for ExhaustibleSource {
+            fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction> {
+                self.transactions.next(gas_limit)
+            }
+
+            fn is_exhausted(&self) -> bool {
+                self.remaining
+                    .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |remaining| {
+                        remaining.checked_sub(1)
+                    })
+                    .is_err()
+            }
+        }
+
+        let (tx1, tx2) = setup_executable_script();
+        let executor = create_executor(Default::default(), Default::default());
+        let source = ExhaustibleSource {
+            transactions: OnceTransactionsSource::new(vec![
+                tx1.clone().into(),
+                tx2.into(),
+            ]),
+            remaining: AtomicUsize::new(1),
+        };
+
+        // When
+        let (
+            ExecutionResult {
+                block,
+                skipped_transactions,
+                ..
+            },
+            _,
+        ) = executor
+            .execute_without_commit(ExecutionTypes::Production(Components {
+                header_to_produce: Default::default(),
+                transactions_source: source,
+                gas_price: 0,
+                gas_limit: u64::MAX,
+            }))
+            .unwrap()
+            .into();
+
+        // Then
+        assert_eq!(
+            block.transactions().len(),
+            2 // `tx1` and coinbase
+        );
+        assert_eq!(
+            block.transactions()[0].id(&ChainId::default()),
+            tx1.id(&ChainId::default())
+        );
+        assert!(block.transactions()[1].is_mint());
+        assert!(skipped_transactions.is_empty());
+    }
+
     #[test]
     fn input_coins_are_marked_as_spent() {
         // ensure coins are marked as spent after tx is processed
@@ -2916,6 +3217,169 @@ mod tests {
         assert!(result.is_ok(), "{result:?}")
     }
 
+    #[test]
+    fn whitelisted_tx_with_zero_fee_included_by_block_producer_and_accepted_by_validator()
+    {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let predicate: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+        let owner = Input::predicate_owner(&predicate);
+        let amount = 1000;
+
+        let tx: Transaction = TransactionBuilder::script(
+            vec![op::ret(RegId::ONE)].into_iter().collect(),
+            vec![],
+        )
+        .max_fee_limit(0)
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            owner,
+            amount,
+            AssetId::BASE,
+            rng.gen(),
+            0,
+            predicate,
+            vec![],
+        ))
+        .add_output(Output::Change {
+            to: Default::default(),
+            amount: 0,
+            asset_id: Default::default(),
+        })
+        .finalize()
+        .into();
+        let produce = |config: Config| {
+            create_executor(Database::default(), config)
+                .execute_without_commit(ExecutionTypes::Production(Components {
+                    header_to_produce: PartialBlockHeader::default(),
+                    transactions_source: OnceTransactionsSource::new(vec![tx.clone()]),
+                    gas_price: 1,
+                    gas_limit: u64::MAX,
+                }))
+                .unwrap()
+                .into_result()
+        };
+
+        // Given
+        let config = Config {
+            fee_free_allowance: Some(FeeFreeAllowance {
+                predicate_roots: vec![owner],
+                gas_budget_per_block: u64::MAX,
+            }),
+            ..Default::default()
+        };
+
+        // When
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            ..
+        } = produce(config.clone());
+        let not_whitelisted = produce(Default::default());
+
+        // Then
+        assert!(skipped_transactions.is_empty());
+        assert_eq!(not_whitelisted.skipped_transactions.len(), 1);
+        let validator = create_executor(Database::default(), config);
+        let result = validator.execute_without_commit::<OnceTransactionsSource>(
+            ExecutionTypes::Validation(block),
+        );
+        assert!(result.is_ok(), "{result:?}")
+    }
+
+    #[test]
+    fn create_txs_over_blob_budget_are_skipped_by_producer_and_rejected_by_validator() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let (first, _) = create_contract(vec![0; 100], &mut rng);
+        let (second, _) = create_contract(vec![0; 100], &mut rng);
+        let second_id = second.id(&ChainId::default());
+        let produce = |config: Config| {
+            create_executor(Database::default(), config)
+                .execute_without_commit(ExecutionTypes::Production(Components {
+                    header_to_produce: PartialBlockHeader::default(),
+                    transactions_source: OnceTransactionsSource::new(vec![
+                        first.clone().into(),
+                        second.clone().into(),
+                    ]),
+                    gas_price: 0,
+                    gas_limit: u64::MAX,
+                }))
+                .unwrap()
+                .into_result()
+        };
+
+        // Given
+        let config = Config {
+            blob_pricing: Some(BlobPricing {
+                byte_price: 0,
+                max_bytes_per_block: 150,
+            }),
+            ..Default::default()
+        };
+
+        // When
+        let ExecutionResult {
+            skipped_transactions,
+            ..
+        } = produce(config.clone());
+        let without_pricing = produce(Default::default());
+
+        // Then
+        assert_eq!(skipped_transactions.len(), 1);
+        assert_eq!(skipped_transactions[0].0, second_id);
+        assert!(matches!(
+            skipped_transactions[0].1,
+            ExecutorError::LimitExceeded(LimitError::BlockBlobBytesLimitExceeded {
+                used_bytes: 200,
+                limit: 150
+            })
+        ));
+        let validator = create_executor(Database::default(), config);
+        let result = validator.execute_without_commit::<OnceTransactionsSource>(
+            ExecutionTypes::Validation(without_pricing.block),
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn create_tx_not_paying_for_payload_is_skipped_by_producer() {
+        let mut rng = StdRng::seed_from_u64(2322u64);
+        let (tx, _) = create_contract(vec![0; 100], &mut rng);
+        let producer = create_executor(
+            Database::default(),
+            Config {
+                blob_pricing: Some(BlobPricing {
+                    byte_price: 1,
+                    max_bytes_per_block: u64::MAX,
+                }),
+                ..Default::default()
+            },
+        );
+
+        // When
+        let ExecutionResult {
+            skipped_transactions,
+            ..
+        } = producer
+            .execute_without_commit(ExecutionTypes::Production(Components {
+                header_to_produce: PartialBlockHeader::default(),
+                transactions_source: OnceTransactionsSource::new(vec![tx.into()]),
+                gas_price: 0,
+                gas_limit: u64::MAX,
+            }))
+            .unwrap()
+            .into_result();
+
+        // Then
+        assert_eq!(skipped_transactions.len(), 1);
+        assert!(matches!(
+            skipped_transactions[0].1,
+            ExecutorError::LimitExceeded(LimitError::BlobFeeTooLow {
+                tip: 0,
+                required: 100
+            })
+        ));
+    }
+
     #[cfg(feature = "relayer")]
     mod relayer {
         use super::*;
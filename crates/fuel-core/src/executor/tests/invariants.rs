@@ -0,0 +1,318 @@
+//! The invariants of the coin accounting checked over the random blocks of transfers.
+//!
+//! After each block the sum of the coins of each asset stays the same, the spent coins
+//! are removed and never spent twice, and the owners index of the off-chain database
+//! matches the coins table. The dropped execution of the block leaves the state untouched.
+
+use super::create_executor;
+use crate::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    graphql_api::worker_service::process_executor_events,
+};
+use fuel_core_executor::{
+    executor::{
+        ExecutionOptions,
+        OnceTransactionsSource,
+    },
+    Config,
+};
+use fuel_core_storage::{
+    tables::Coins,
+    StorageAsMut,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::PartialFuelBlock,
+        header::PartialBlockHeader,
+    },
+    entities::coins::coin::{
+        Coin,
+        CompressedCoin,
+    },
+    fuel_crypto::SecretKey,
+    fuel_tx::{
+        field::Inputs,
+        Input,
+        Output,
+        Transaction,
+        TransactionBuilder,
+        TxId,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::{
+        Address,
+        AssetId,
+        ChainId,
+        Word,
+    },
+    services::{
+        block_producer::Components,
+        executor::{
+            Event as ExecutorEvent,
+            ExecutionBlock,
+            ExecutionResult,
+            ExecutionTypes,
+        },
+    },
+};
+use proptest::{
+    collection::vec,
+    prelude::*,
+};
+use rand::{
+    prelude::StdRng,
+    Rng,
+    SeedableRng,
+};
+use std::{
+    borrow::Cow,
+    collections::{
+        BTreeMap,
+        HashSet,
+    },
+};
+use test_strategy::proptest;
+
+const OWNERS: usize = 4;
+const ASSETS: [AssetId; 3] =
+    [AssetId::BASE, AssetId::new([1; 32]), AssetId::new([2; 32])];
+const INITIAL_AMOUNT: Word = 1_000_000;
+
+#[derive(Debug, Clone)]
+struct Transfer {
+    from: usize,
+    to: usize,
+    asset: usize,
+    percent: Word,
+    /// Adds the second transaction spending the same coin.
+    double_spend: bool,
+}
+
+fn transfer() -> impl Strategy<Value = Transfer> {
+    (
+        0..OWNERS,
+        0..OWNERS,
+        0..ASSETS.len(),
+        0..=100 as Word,
+        prop::bool::weighted(0.2),
+    )
+        .prop_map(|(from, to, asset, percent, double_spend)| Transfer {
+            from,
+            to,
+            asset,
+            percent,
+            double_spend,
+        })
+}
+
+fn blocks() -> impl Strategy<Value = Vec<Vec<Transfer>>> {
+    vec(vec(transfer(), 0..6), 1..6)
+}
+
+struct Ledger {
+    on_chain: Database,
+    off_chain: Database<OffChain>,
+    owners: Vec<(SecretKey, Address)>,
+    totals: BTreeMap<AssetId, Word>,
+    spent: HashSet<UtxoId>,
+}
+
+impl Ledger {
+    /// Creates the ledger with a coin of each asset for each owner.
+    fn new() -> Self {
+        let mut rng = StdRng::seed_from_u64(2322);
+        let mut ledger = Self {
+            on_chain: Database::default(),
+            off_chain: Database::default(),
+            owners: (0..OWNERS)
+                .map(|_| {
+                    let secret = SecretKey::random(&mut rng);
+                    (secret, Input::owner(&secret.public_key()))
+                })
+                .collect(),
+            totals: BTreeMap::new(),
+            spent: HashSet::new(),
+        };
+
+        let mut events = vec![];
+        for (_, owner) in ledger.owners.clone() {
+            for asset_id in ASSETS {
+                let mut coin = CompressedCoin::default();
+                coin.set_owner(owner);
+                coin.set_amount(INITIAL_AMOUNT);
+                coin.set_asset_id(asset_id);
+                let utxo_id = UtxoId::new(rng.gen(), 0);
+                ledger
+                    .on_chain
+                    .storage_as_mut::<Coins>()
+                    .insert(&utxo_id, &coin)
+                    .unwrap();
+                events.push(ExecutorEvent::CoinCreated(coin.uncompress(utxo_id)));
+                *ledger.totals.entry(asset_id).or_default() += INITIAL_AMOUNT;
+            }
+        }
+        process_executor_events(events.iter().map(Cow::Borrowed), &mut ledger.off_chain)
+            .unwrap();
+        ledger
+    }
+
+    fn coins(&self) -> Vec<Coin> {
+        self.on_chain
+            .iter_all::<Coins>(None)
+            .map(|result| {
+                let (utxo_id, coin) = result.unwrap();
+                coin.uncompress(utxo_id)
+            })
+            .collect()
+    }
+
+    /// Builds the transactions of the `transfers`. Returns them with the ids
+    /// of the transactions expected to be skipped as the double spends.
+    fn transactions(&self, transfers: &[Transfer]) -> (Vec<Transaction>, HashSet<TxId>) {
+        let coins = self.coins();
+        let mut used = HashSet::new();
+        let mut transactions = vec![];
+        let mut double_spends = HashSet::new();
+
+        for transfer in transfers {
+            let (secret, owner) = self.owners[transfer.from];
+            let asset_id = ASSETS[transfer.asset];
+            let Some(coin) = coins.iter().find(|coin| {
+                coin.owner == owner
+                    && coin.asset_id == asset_id
+                    && !used.contains(&coin.utxo_id)
+            }) else {
+                continue
+            };
+            used.insert(coin.utxo_id);
+
+            let to = self.owners[transfer.to].1;
+            let amount = coin.amount * transfer.percent / 100;
+            let tx = |amount| -> Transaction {
+                TransactionBuilder::script(vec![], vec![])
+                    .add_unsigned_coin_input(
+                        secret,
+                        coin.utxo_id,
+                        coin.amount,
+                        asset_id,
+                        coin.tx_pointer,
+                    )
+                    .add_output(Output::coin(to, amount, asset_id))
+                    .add_output(Output::change(owner, 0, asset_id))
+                    .finalize_as_transaction()
+            };
+            transactions.push(tx(amount));
+            if transfer.double_spend {
+                let double_spend = tx(coin.amount - amount);
+                double_spends.insert(double_spend.id(&ChainId::default()));
+                transactions.push(double_spend);
+            }
+        }
+        (transactions, double_spends)
+    }
+
+    fn check_invariants(&self) {
+        let coins = self.coins();
+
+        // The value of each asset is conserved.
+        let mut totals = BTreeMap::new();
+        for coin in &coins {
+            *totals.entry(coin.asset_id).or_default() += coin.amount;
+        }
+        assert_eq!(totals, self.totals);
+
+        // The spent coins are removed.
+        for coin in &coins {
+            assert!(!self.spent.contains(&coin.utxo_id));
+        }
+
+        // The owners index contains exactly the coins of the owner.
+        for (_, owner) in &self.owners {
+            let mut expected = coins
+                .iter()
+                .filter(|coin| coin.owner == *owner)
+                .map(|coin| coin.utxo_id)
+                .collect::<Vec<_>>();
+            let mut indexed = self
+                .off_chain
+                .owned_coins_ids(owner, None, None)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            expected.sort();
+            indexed.sort();
+            assert_eq!(indexed, expected);
+        }
+    }
+}
+
+#[proptest(cases = 16)]
+fn coin_accounting_invariants_hold_after_each_block(
+    #[strategy(blocks())] blocks: Vec<Vec<Transfer>>,
+) {
+    let mut ledger = Ledger::new();
+    let executor = create_executor(
+        ledger.on_chain.clone(),
+        Config {
+            utxo_validation_default: true,
+            ..Default::default()
+        },
+    );
+
+    for (height, transfers) in (1u32..).zip(blocks) {
+        let (transactions, double_spends) = ledger.transactions(&transfers);
+        let mut header = PartialBlockHeader::default();
+        header.consensus.height = height.into();
+
+        // The execution without the commit is rolled back.
+        let coins_before = ledger.coins();
+        let uncommitted = executor
+            .execute_without_commit(ExecutionTypes::Production(Components {
+                header_to_produce: header.clone(),
+                transactions_source: OnceTransactionsSource::new(transactions.clone()),
+                gas_price: 0,
+                gas_limit: u64::MAX,
+            }))
+            .unwrap();
+        drop(uncommitted);
+        assert_eq!(ledger.coins(), coins_before);
+
+        let ExecutionResult {
+            block,
+            skipped_transactions,
+            events,
+            ..
+        } = executor
+            .execute_and_commit(
+                ExecutionBlock::Production(PartialFuelBlock {
+                    header,
+                    transactions,
+                }),
+                ExecutionOptions {
+                    utxo_validation: true,
+                },
+            )
+            .unwrap();
+        process_executor_events(events.iter().map(Cow::Borrowed), &mut ledger.off_chain)
+            .unwrap();
+
+        let skipped = skipped_transactions
+            .iter()
+            .map(|(id, _)| *id)
+            .collect::<HashSet<_>>();
+        assert_eq!(skipped, double_spends);
+        for tx in block.transactions() {
+            if let Transaction::Script(script) = tx {
+                for input in script.inputs() {
+                    let utxo_id = *input.utxo_id().unwrap();
+                    assert!(ledger.spent.insert(utxo_id), "{utxo_id} is spent twice");
+                }
+            }
+        }
+
+        ledger.check_invariants();
+    }
+}
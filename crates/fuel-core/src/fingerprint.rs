@@ -0,0 +1,80 @@
+//! The fingerprint of the node binary.
+//!
+//! It identifies the exact version of the code and the enabled features, so the
+//! networks can audit which binary runs on the node and which binary produced the blocks.
+
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::Bytes32,
+};
+use std::fmt;
+
+/// The git commit of the sources the node is built from.
+pub const GIT_COMMIT: &str = env!("FUEL_CORE_GIT_COMMIT");
+
+/// The comma-separated list of the features enabled in the build.
+const FEATURES: &str = env!("FUEL_CORE_FEATURES");
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NodeFingerprint {
+    /// The version of the `fuel-core` crate.
+    pub version: String,
+    /// The git commit of the sources, or `unknown` if it isn't available during the build.
+    pub git_commit: String,
+    /// The sorted features enabled in the build.
+    pub features: Vec<String>,
+}
+
+impl NodeFingerprint {
+    /// Returns the fingerprint of the running binary.
+    pub fn current() -> Self {
+        Self {
+            version: crate::VERSION.to_string(),
+            git_commit: GIT_COMMIT.to_string(),
+            features: FEATURES
+                .split(',')
+                .filter(|feature| !feature.is_empty())
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+
+    /// The hash identifying the fingerprint.
+    pub fn id(&self) -> Bytes32 {
+        let bytes =
+            postcard::to_allocvec(self).expect("The fingerprint is always serializable");
+        Hasher::hash(bytes)
+    }
+}
+
+impl fmt::Display for NodeFingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "v{} ({}, features: [{}])",
+            self.version,
+            self.git_commit,
+            self.features.join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn id_depends_on_all_parts_of_the_fingerprint() {
+        // Given
+        let fingerprint = NodeFingerprint::current();
+        let mut other_commit = fingerprint.clone();
+        other_commit.git_commit = "other".to_string();
+        let mut other_features = fingerprint.clone();
+        other_features.features.push("other".to_string());
+
+        // Then
+        assert_eq!(fingerprint.id(), NodeFingerprint::current().id());
+        assert_ne!(fingerprint.id(), other_commit.id());
+        assert_ne!(fingerprint.id(), other_features.id());
+    }
+}
@@ -4,17 +4,36 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
+    fuel_crypto::Hasher,
     fuel_tx::ConsensusParameters,
+    fuel_types::Bytes32,
     secrecy::Secret,
 };
-use std::net::SocketAddr;
+use std::{
+    fmt::Debug,
+    net::SocketAddr,
+    path::PathBuf,
+    time::Duration,
+};
 
+pub mod address_activity;
+pub mod api_error;
 pub mod api_service;
+pub mod coin_leases;
 pub mod database;
+pub mod entity_cache;
+pub mod http;
+pub mod http_cache;
+pub mod message_outbox;
 pub(crate) mod metrics_extension;
+pub mod persisted_queries;
 pub mod ports;
+pub mod receipts_pruning;
+pub mod scheduler;
 pub mod storage;
+pub(crate) mod trace_extension;
 pub(crate) mod view_extension;
+pub mod websocket;
 pub mod worker_service;
 
 #[derive(Clone, Debug)]
@@ -29,6 +48,49 @@ pub struct Config {
     pub chain_name: String,
     pub consensus_parameters: ConsensusParameters,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// The token authorizing the admin endpoints. The endpoints are disabled if it is `None`.
+    pub admin_token: Option<AdminToken>,
+    /// Accepts only the queries registered with `registerPersistedQuery`,
+    /// except for the requests with the admin token.
+    pub persisted_queries_only: bool,
+    /// The file storing the persisted queries between the restarts.
+    /// The queries are kept only in memory if it is `None`.
+    pub persisted_queries_path: Option<PathBuf>,
+    /// The identity of the node in the p2p network. It is `None` if the p2p is disabled.
+    pub p2p_identity: Option<P2pIdentity>,
+    /// Whether the node follows the DA layer with the relayer.
+    pub relayer_enabled: bool,
+    /// The maximum size in bytes of the cache of the blocks and of the transactions
+    /// requested by id. The cache is disabled if it is zero.
+    pub entity_cache_size: usize,
+    /// The maximum time to live of the coin leases. The `lockCoins` mutation
+    /// is disabled if it is `None`.
+    pub coin_lease_max_ttl: Option<Duration>,
+}
+
+/// The identity of the node in the p2p network exposed to the clients.
+#[derive(Clone, Debug)]
+pub struct P2pIdentity {
+    pub peer_id: String,
+    pub addresses: Vec<String>,
+    pub protocol_versions: Vec<String>,
+}
+
+/// The token authorizing the admin endpoints.
+/// Only the hash of the token is kept in memory.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct AdminToken(Bytes32);
+
+impl AdminToken {
+    pub fn new(token: &str) -> Self {
+        Self(Hasher::hash(token.as_bytes()))
+    }
+}
+
+impl Debug for AdminToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AdminToken(..)")
+    }
 }
 
 pub trait IntoApiResult<T> {
@@ -0,0 +1,198 @@
+//! The activity of the addresses in the imported blocks, streamed to the subscribers
+//! of the `activityByAddress` subscription.
+
+use fuel_core_storage::Result as StorageResult;
+use fuel_core_types::{
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+        },
+        Address,
+        ContractId,
+        Input,
+        Output,
+        Receipt,
+        Transaction,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::BlockHeight,
+    services::block_importer::ImportResult,
+};
+use std::{
+    collections::{
+        BTreeSet,
+        HashMap,
+    },
+    sync::Arc,
+};
+
+/// The activity of the addresses in one block, sorted by the transaction.
+pub type BlockAddressActivity = Arc<Vec<AddressActivity>>;
+
+/// The way the transaction touched the address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ActivityKind {
+    /// The transaction created a coin owned by the address.
+    CoinCreated,
+    /// The transaction spent a coin owned by the address.
+    CoinSpent,
+    /// The transaction sent a message to the address with the `MessageOut` receipt.
+    MessageCreated,
+    /// The transaction spent a message received by the address.
+    MessageSpent,
+    /// The transaction called a contract deployed by the address.
+    ContractCalled,
+}
+
+/// The transaction of the imported block that touched the address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AddressActivity {
+    pub address: Address,
+    pub kind: ActivityKind,
+    pub tx_id: TxId,
+    pub block_height: BlockHeight,
+}
+
+/// The deployer of the contract is the owner of the first coin or message input
+/// of the `Create` transaction. The contracts have no owners in the protocol,
+/// so the deployer is treated as the owner of the contract.
+pub fn deployer(tx: &Transaction) -> Option<Address> {
+    let Transaction::Create(create) = tx else {
+        return None
+    };
+    create
+        .inputs()
+        .iter()
+        .find_map(|input| input.input_owner())
+        .copied()
+}
+
+/// Collects the activity of the addresses in the block. The `deployer_of` returns
+/// the deployer of the called contract, if it is known.
+pub fn block_address_activity<F>(
+    import_result: &ImportResult,
+    mut deployer_of: F,
+) -> StorageResult<Vec<AddressActivity>>
+where
+    F: FnMut(&ContractId) -> StorageResult<Option<Address>>,
+{
+    let block = &import_result.sealed_block.entity;
+    let block_height = *block.header().height();
+    let results = import_result
+        .tx_status
+        .iter()
+        .map(|status| (status.id, &status.result))
+        .collect::<HashMap<_, _>>();
+    let mut activity = vec![];
+    for tx in block.transactions() {
+        let (inputs, outputs) = match tx {
+            Transaction::Script(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+            Transaction::Create(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+            Transaction::Mint(_) => continue,
+        };
+        let tx_id = tx.cached_id().expect(
+            "The imported block should contains only transactions with cached id",
+        );
+
+        let mut touched = BTreeSet::new();
+        for input in inputs {
+            match input {
+                Input::CoinSigned(_) | Input::CoinPredicate(_) => {
+                    if let Some(owner) = input.input_owner() {
+                        touched.insert((*owner, ActivityKind::CoinSpent));
+                    }
+                }
+                Input::Contract(contract) => {
+                    if let Some(deployer) = deployer_of(&contract.contract_id)? {
+                        touched.insert((deployer, ActivityKind::ContractCalled));
+                    }
+                }
+                Input::MessageCoinSigned(_)
+                | Input::MessageCoinPredicate(_)
+                | Input::MessageDataSigned(_)
+                | Input::MessageDataPredicate(_) => {
+                    if let Some(recipient) = input.input_owner() {
+                        touched.insert((*recipient, ActivityKind::MessageSpent));
+                    }
+                }
+            }
+        }
+        for output in outputs {
+            match output {
+                Output::Coin { to, .. }
+                | Output::Change { to, .. }
+                | Output::Variable { to, .. } => {
+                    touched.insert((*to, ActivityKind::CoinCreated));
+                }
+                Output::Contract(_) | Output::ContractCreated { .. } => {}
+            }
+        }
+        let receipts = results
+            .get(&tx_id)
+            .map(|result| result.receipts())
+            .unwrap_or_default();
+        for receipt in receipts {
+            if let Receipt::MessageOut { recipient, .. } = receipt {
+                touched.insert((*recipient, ActivityKind::MessageCreated));
+            }
+        }
+
+        activity.extend(touched.into_iter().map(|(address, kind)| AddressActivity {
+            address,
+            kind,
+            tx_id,
+            block_height,
+        }));
+    }
+    Ok(activity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::TransactionBuilder;
+
+    #[test]
+    fn deployer_is_the_owner_of_the_first_coin_input() {
+        // Given
+        let deployer_address = Address::new([1; 32]);
+        let other = Address::new([2; 32]);
+        let create = TransactionBuilder::create(
+            vec![].into(),
+            Default::default(),
+            Default::default(),
+        )
+        .add_input(Input::coin_signed(
+            Default::default(),
+            deployer_address,
+            100,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        ))
+        .add_input(Input::coin_signed(
+            Default::default(),
+            other,
+            100,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+        ))
+        .add_output(Output::contract_created(
+            Default::default(),
+            Default::default(),
+        ))
+        .finalize_as_transaction();
+        let script = Transaction::default_test_tx();
+
+        // When
+        let deployer_of_create = deployer(&create);
+        let deployer_of_script = deployer(&script);
+
+        // Then
+        assert_eq!(deployer_of_create, Some(deployer_address));
+        assert_eq!(deployer_of_script, None);
+    }
+}
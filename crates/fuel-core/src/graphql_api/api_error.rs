@@ -0,0 +1,301 @@
+//! The machine-readable errors of the API. Each error returned to the client has the
+//! [`ErrorCode`], its category and whether the request is retryable in the extensions:
+//!
+//! ```json
+//! {
+//!   "message": "Transaction is not inserted. The gas price is too low.",
+//!   "extensions": {
+//!     "code": "GAS_PRICE_TOO_LOW",
+//!     "category": "TRANSACTION",
+//!     "retryable": false,
+//!     "details": { "transactionId": "0x..." }
+//!   }
+//! }
+//! ```
+//!
+//! The resolvers may return the [`ApiError`] with the explicit code. Other errors are
+//! classified by their source by the [`ErrorCodeExtension`].
+
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextRequest,
+        NextSubscribe,
+    },
+    ErrorExtensionValues,
+    Response,
+    ServerError,
+};
+use fuel_core_storage::Error as StorageError;
+use fuel_core_types::{
+    fuel_tx::ValidityError,
+    fuel_types::canonical,
+    fuel_vm::checked_transaction::CheckError,
+    services::{
+        graphql_api::ErrorCode,
+        txpool::Error as TxPoolError,
+    },
+};
+use futures::{
+    stream::BoxStream,
+    StreamExt,
+};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::Arc,
+};
+
+/// The key of the error extension with the [`ErrorCode`].
+pub const CODE_EXTENSION: &str = "code";
+/// The key of the error extension with the category of the [`ErrorCode`].
+pub const CATEGORY_EXTENSION: &str = "category";
+/// The key of the error extension that is `true` if the request may succeed later.
+pub const RETRYABLE_EXTENSION: &str = "retryable";
+/// The key of the error extension with the details of the error.
+pub const DETAILS_EXTENSION: &str = "details";
+
+/// The error of the API with the machine-readable code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiError {
+    pub code: ErrorCode,
+    pub message: String,
+    pub details: BTreeMap<String, String>,
+}
+
+impl ApiError {
+    pub fn new(code: ErrorCode, message: impl fmt::Display) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+            details: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the detail of the error, e.g. the id of the rejected transaction.
+    pub fn with_detail(mut self, key: &str, value: impl fmt::Display) -> Self {
+        self.details.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Classifies the error by its type, falling back to [`ErrorCode::Internal`].
+    pub fn from_anyhow(error: &anyhow::Error) -> Self {
+        let code = if let Some(error) = error.downcast_ref::<StorageError>() {
+            storage_error_code(error)
+        } else {
+            error
+                .chain()
+                .find_map(|error| error.downcast_ref::<TxPoolError>())
+                .map(ErrorCode::from)
+                .unwrap_or(ErrorCode::Internal)
+        };
+        Self::new(code, error)
+    }
+
+    /// Classifies the error returned by the GraphQL server by its source.
+    /// The errors without the source and the path are produced by `async-graphql`
+    /// itself while parsing and validating the request. `async-graphql` doesn't keep
+    /// the source of the errors converted by the `?` in the resolvers, so the errors
+    /// of the resolvers without the source are internal.
+    pub fn from_server_error(error: &ServerError) -> Self {
+        let message = &error.message;
+        let code = if let Some(error) = error.source::<ApiError>() {
+            return error.clone()
+        } else if let Some(error) = error.source::<anyhow::Error>() {
+            return Self {
+                message: message.clone(),
+                ..Self::from_anyhow(error)
+            }
+        } else if let Some(error) = error.source::<StorageError>() {
+            storage_error_code(error)
+        } else if let Some(error) = error.source::<TxPoolError>() {
+            ErrorCode::from(error)
+        } else if error.source::<CheckError>().is_some()
+            || error.source::<ValidityError>().is_some()
+        {
+            ErrorCode::InvalidTransaction
+        } else if error.source::<canonical::Error>().is_some()
+            || (error.source.is_none() && error.path.is_empty())
+        {
+            ErrorCode::InvalidRequest
+        } else {
+            ErrorCode::Internal
+        };
+        Self::new(code, message)
+    }
+
+    /// Writes the code, the category, the retryable flag and the details into the `extensions`.
+    pub fn extend(&self, extensions: &mut ErrorExtensionValues) {
+        let code = self.code;
+        extensions.set(CODE_EXTENSION, code.as_str());
+        extensions.set(CATEGORY_EXTENSION, code.category().as_str());
+        extensions.set(RETRYABLE_EXTENSION, code.is_retryable());
+        if !self.details.is_empty() {
+            if let Ok(details) = async_graphql::to_value(&self.details) {
+                extensions.set(DETAILS_EXTENSION, details);
+            }
+        }
+    }
+
+    pub fn into_server_error(self) -> ServerError {
+        let mut error = ServerError::new(self.message.clone(), None);
+        self.extend(error.extensions.get_or_insert_with(Default::default));
+        error
+    }
+}
+
+/// Keeps the code of the error returned by the resolvers. `ApiError` doesn't implement
+/// `Display`, so the conversion doesn't fall into the blanket implementation, which
+/// keeps only the message.
+impl From<ApiError> for async_graphql::Error {
+    fn from(error: ApiError) -> Self {
+        let mut result = async_graphql::Error::new(error.message.clone());
+        error.extend(result.extensions.get_or_insert_with(Default::default));
+        result.source = Some(Arc::new(error));
+        result
+    }
+}
+
+fn storage_error_code(error: &StorageError) -> ErrorCode {
+    match error {
+        StorageError::NotFound(_, _) => ErrorCode::NotFound,
+        _ => ErrorCode::Internal,
+    }
+}
+
+/// Adds the code to each error of the response that doesn't have it yet.
+fn with_error_codes(mut response: Response) -> Response {
+    for error in response.errors.iter_mut() {
+        let has_code = error
+            .extensions
+            .as_ref()
+            .map_or(false, |extensions| extensions.get(CODE_EXTENSION).is_some());
+        if !has_code {
+            let api_error = ApiError::from_server_error(error);
+            api_error.extend(error.extensions.get_or_insert_with(Default::default));
+        }
+    }
+    response
+}
+
+/// The extension that adds the [`ErrorCode`] to the errors of the responses.
+pub(crate) struct ErrorCodeExtension;
+
+impl ErrorCodeExtension {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ExtensionFactory for ErrorCodeExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(ErrorCodeExtension::new())
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for ErrorCodeExtension {
+    async fn request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextRequest<'_>,
+    ) -> Response {
+        with_error_codes(next.run(ctx).await)
+    }
+
+    fn subscribe<'s>(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        stream: BoxStream<'s, Response>,
+        next: NextSubscribe<'_>,
+    ) -> BoxStream<'s, Response> {
+        next.run(ctx, stream).map(with_error_codes).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_graphql::Value;
+    use fuel_core_types::fuel_tx::UtxoId;
+
+    fn code(error: &ServerError) -> Option<&Value> {
+        error.extensions.as_ref()?.get(CODE_EXTENSION)
+    }
+
+    #[test]
+    fn errors_are_classified_by_source() {
+        let txpool: anyhow::Error =
+            TxPoolError::NotInsertedInputUtxoIdSpent(UtxoId::default()).into();
+        assert_eq!(ApiError::from_anyhow(&txpool).code, ErrorCode::InputSpent);
+
+        let storage =
+            async_graphql::Error::new_with_source(StorageError::NotFound("Coins", ""));
+        let error = storage.into_server_error(Default::default());
+        assert_eq!(
+            ApiError::from_server_error(&error).code,
+            ErrorCode::NotFound
+        );
+
+        let validation = ServerError::new("Unknown field \"foo\"", None);
+        assert_eq!(
+            ApiError::from_server_error(&validation).code,
+            ErrorCode::InvalidRequest
+        );
+    }
+
+    #[test]
+    fn explicit_code_is_kept() {
+        // Given
+        let error = ApiError::new(ErrorCode::Forbidden, "The admin token is invalid")
+            .into_server_error();
+        let response = Response::from_errors(vec![error]);
+
+        // When
+        let response = with_error_codes(response);
+
+        // Then
+        let error = &response.errors[0];
+        assert_eq!(code(error), Some(&Value::from("FORBIDDEN")));
+        let extensions = error.extensions.as_ref().unwrap();
+        assert_eq!(
+            extensions.get(RETRYABLE_EXTENSION),
+            Some(&Value::from(false))
+        );
+    }
+
+    #[test]
+    fn missing_code_is_added() {
+        // Given
+        let error: async_graphql::Error =
+            ApiError::new(ErrorCode::PoolFull, "The pool is full")
+                .with_detail("transactionId", "0x01")
+                .into();
+        let response =
+            Response::from_errors(vec![error.into_server_error(Default::default())]);
+
+        // When
+        let response = with_error_codes(response);
+
+        // Then
+        let extensions = response.errors[0].extensions.as_ref().unwrap();
+        assert_eq!(
+            extensions.get(CODE_EXTENSION),
+            Some(&Value::from("POOL_FULL"))
+        );
+        assert_eq!(
+            extensions.get(CATEGORY_EXTENSION),
+            Some(&Value::from("NODE"))
+        );
+        assert_eq!(
+            extensions.get(RETRYABLE_EXTENSION),
+            Some(&Value::from(true))
+        );
+        let Some(Value::Object(details)) = extensions.get(DETAILS_EXTENSION) else {
+            panic!("Expected the details of the error")
+        };
+        assert_eq!(details.len(), 1);
+    }
+}
@@ -1,15 +1,48 @@
 use crate::{
     fuel_core_graphql_api::{
+        api_error::ErrorCodeExtension,
+        coin_leases::CoinLeases,
+        entity_cache::EntityCache,
+        http::{
+            cors_layer,
+            HttpConfig,
+            ReloadingTlsAcceptor,
+            TlsIncoming,
+        },
+        http_cache::{
+            self,
+            conditional_response,
+        },
         metrics_extension::MetricsExtension,
+        persisted_queries::{
+            PersistedQueries,
+            PersistedQueriesExtension,
+        },
         ports::{
             BlockProducerPort,
             ConsensusModulePort,
+            ContractVerificationPort,
+            DatabaseStatisticsPort,
             OffChainDatabase,
             OnChainDatabase,
             P2pPort,
+            RelayerPort,
             TxPoolPort,
         },
+        scheduler::{
+            Overloaded,
+            RequestScheduler,
+        },
+        trace_extension::{
+            request_span,
+            TraceExtension,
+        },
         view_extension::ViewExtension,
+        websocket::{
+            graphql_ws_handler,
+            WebSocketConfig,
+        },
+        AdminToken,
         Config,
     },
     schema::{
@@ -32,17 +65,12 @@ use axum::{
         Extension,
     },
     http::{
-        header::{
-            ACCESS_CONTROL_ALLOW_HEADERS,
-            ACCESS_CONTROL_ALLOW_METHODS,
-            ACCESS_CONTROL_ALLOW_ORIGIN,
-        },
-        HeaderValue,
+        header::AUTHORIZATION,
+        HeaderMap,
     },
     response::{
         sse::Event,
-        Html,
-        IntoResponse,
+        Response as HttpResponse,
         Sse,
     },
     routing::{
@@ -68,11 +96,11 @@ use std::{
         TcpListener,
     },
     pin::Pin,
+    sync::Arc,
     time::Duration,
 };
 use tokio_stream::StreamExt;
 use tower_http::{
-    set_header::SetResponseHeaderLayer,
     timeout::TimeoutLayer,
     trace::TraceLayer,
 };
@@ -85,21 +113,28 @@ pub type BlockProducer = Box<dyn BlockProducerPort>;
 // In the future GraphQL should not be aware of `TxPool`. It should
 //  use only `Database` to receive all information about transactions.
 pub type TxPool = Box<dyn TxPoolPort>;
-pub type ConsensusModule = Box<dyn ConsensusModulePort>;
+pub type ConsensusModule = Arc<dyn ConsensusModulePort>;
 pub type P2pService = Box<dyn P2pPort>;
+pub type RelayerService = Box<dyn RelayerPort>;
+pub type ContractVerification = Box<dyn ContractVerificationPort>;
+pub type DatabaseStatisticsProvider = Box<dyn DatabaseStatisticsPort>;
 
 #[derive(Clone)]
 pub struct SharedState {
     pub bound_address: SocketAddr,
+    /// The cache of the blocks and of the transactions shared by all requests.
+    pub entity_cache: Arc<EntityCache>,
 }
 
 pub struct GraphqlService {
     bound_address: SocketAddr,
+    entity_cache: Arc<EntityCache>,
 }
 
 pub struct ServerParams {
     router: Router,
     listener: TcpListener,
+    tls: Option<Arc<ReloadingTlsAcceptor>>,
 }
 
 pub struct Task {
@@ -118,6 +153,7 @@ impl RunnableService for GraphqlService {
     fn shared_data(&self) -> Self::SharedData {
         SharedState {
             bound_address: self.bound_address,
+            entity_cache: self.entity_cache.clone(),
         }
     }
 
@@ -127,21 +163,33 @@ impl RunnableService for GraphqlService {
         params: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         let mut state = state.clone();
-        let ServerParams { router, listener } = params;
+        let ServerParams {
+            router,
+            listener,
+            tls,
+        } = params;
 
-        let server = axum::Server::from_tcp(listener)
-            .unwrap()
-            .serve(router.into_make_service())
-            .with_graceful_shutdown(async move {
-                state
-                    .while_started()
-                    .await
-                    .expect("The service is destroyed");
-            });
+        let shutdown = async move {
+            state
+                .while_started()
+                .await
+                .expect("The service is destroyed");
+        };
 
-        Ok(Task {
-            server: Box::pin(server),
-        })
+        let server: Pin<Box<dyn Future<Output = hyper::Result<()>> + Send>> = match tls {
+            Some(acceptor) => Box::pin(
+                axum::Server::builder(TlsIncoming::new(listener, acceptor)?)
+                    .serve(router.into_make_service())
+                    .with_graceful_shutdown(shutdown),
+            ),
+            None => Box::pin(
+                axum::Server::from_tcp(listener)?
+                    .serve(router.into_make_service())
+                    .with_graceful_shutdown(shutdown),
+            ),
+        };
+
+        Ok(Task { server })
     }
 }
 
@@ -173,8 +221,14 @@ pub fn new_service<OnChain, OffChain>(
     producer: BlockProducer,
     consensus_module: ConsensusModule,
     p2p_service: P2pService,
+    relayer: RelayerService,
+    contract_verification: ContractVerification,
+    database_statistics: DatabaseStatisticsProvider,
     log_threshold_ms: Duration,
     request_timeout: Duration,
+    websocket_config: WebSocketConfig,
+    http_config: HttpConfig,
+    request_scheduler: RequestScheduler,
 ) -> anyhow::Result<Service>
 where
     OnChain: AtomicView<Height = BlockHeight> + 'static,
@@ -183,17 +237,34 @@ where
     OffChain::View: OffChainDatabase,
 {
     let network_addr = config.addr;
-    let combined_read_database = ReadDatabase::new(on_database, off_database);
+    let combined_read_database = ReadDatabase::new(on_database, off_database)
+        .with_entity_cache(config.entity_cache_size);
+    let entity_cache = combined_read_database.entity_cache().clone();
+    let persisted_queries =
+        PersistedQueries::load(config.persisted_queries_path.clone())?;
+    let persisted_queries_extension = PersistedQueriesExtension::new(
+        persisted_queries.clone(),
+        config.persisted_queries_only,
+        config.admin_token,
+    );
 
     let schema = schema
         .data(config)
         .data(combined_read_database)
         .data(txpool)
         .data(producer)
-        .data(consensus_module)
+        .data(consensus_module.clone())
         .data(p2p_service)
+        .data(relayer)
+        .data(contract_verification)
+        .data(database_statistics)
+        .data(persisted_queries)
+        .data(CoinLeases::default())
         .extension(async_graphql::extensions::Tracing)
+        .extension(TraceExtension::new())
+        .extension(ErrorCodeExtension::new())
         .extension(MetricsExtension::new(log_threshold_ms))
+        .extension(persisted_queries_extension)
         .extension(ViewExtension::new())
         .finish();
 
@@ -204,51 +275,103 @@ where
             "/v1/graphql-sub",
             post(graphql_subscription_handler).options(ok),
         )
+        .route("/v1/graphql-ws", get(graphql_ws_handler))
         .route("/v1/metrics", get(metrics))
         .route("/v1/health", get(health))
         .layer(Extension(schema))
-        .layer(TraceLayer::new_for_http())
+        .layer(Extension(websocket_config))
+        .layer(Extension(consensus_module))
+        .layer(Extension(request_scheduler))
+        .layer(TraceLayer::new_for_http().make_span_with(request_span))
         .layer(TimeoutLayer::new(request_timeout))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_ORIGIN,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_METHODS,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(SetResponseHeaderLayer::<_>::overriding(
-            ACCESS_CONTROL_ALLOW_HEADERS,
-            HeaderValue::from_static("*"),
-        ))
-        .layer(DefaultBodyLimit::disable());
+        .layer(cors_layer(&http_config.cors_origins)?)
+        .layer(match http_config.max_body_size {
+            Some(limit) => DefaultBodyLimit::max(limit),
+            None => DefaultBodyLimit::disable(),
+        });
+
+    // Load the certificate before binding to fail on the start if it is misconfigured.
+    let tls = http_config
+        .tls
+        .map(ReloadingTlsAcceptor::new)
+        .transpose()?
+        .map(Arc::new);
 
     let listener = TcpListener::bind(network_addr)?;
     let bound_address = listener.local_addr()?;
 
-    tracing::info!("Binding GraphQL provider to {}", bound_address);
+    tracing::info!(
+        "Binding GraphQL provider to {} (TLS: {})",
+        bound_address,
+        tls.is_some()
+    );
 
     Ok(Service::new_with_params(
-        GraphqlService { bound_address },
-        ServerParams { router, listener },
+        GraphqlService {
+            bound_address,
+            entity_cache,
+        },
+        ServerParams {
+            router,
+            listener,
+            tls,
+        },
     ))
 }
 
-async fn graphql_playground() -> impl IntoResponse {
-    Html(playground_source(GraphQLPlaygroundConfig::new(
-        "/v1/graphql",
-    )))
+async fn graphql_playground(headers: HeaderMap) -> HttpResponse {
+    let html = playground_source(GraphQLPlaygroundConfig::new("/v1/graphql"));
+    conditional_response(
+        &headers,
+        "text/html; charset=utf-8",
+        http_cache::STATIC,
+        html.into_bytes(),
+    )
 }
 
-async fn health() -> Json<serde_json::Value> {
-    Json(json!({ "up": true }))
+async fn health(
+    consensus_module: Extension<ConsensusModule>,
+    headers: HeaderMap,
+) -> HttpResponse {
+    let block_production = match consensus_module.block_production_paused() {
+        Some(paused) => json!({
+            "paused": true,
+            "reason": format!("{:?}", paused.reason),
+            "since": paused.since.to_unix(),
+        }),
+        None => json!({ "paused": false }),
+    };
+    let body = json!({ "up": true, "block_production": block_production });
+    conditional_response(
+        &headers,
+        "application/json",
+        http_cache::REVALIDATE,
+        body.to_string().into_bytes(),
+    )
 }
 
 async fn graphql_handler(
     schema: Extension<CoreSchema>,
+    scheduler: Extension<RequestScheduler>,
+    headers: HeaderMap,
     req: Json<Request>,
-) -> Json<Response> {
-    schema.execute(req.0).await.into()
+) -> Result<Json<Response>, Overloaded> {
+    let mut req = req.0;
+    if let Some(token) = bearer_token(&headers) {
+        req = req.data(AdminToken::new(token));
+    }
+    let _permit = scheduler.acquire().await?;
+    Ok(schema.execute(req).await.into())
+}
+
+/// Extracts the token from the `Authorization: Bearer <token>` header.
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
 }
 
 async fn graphql_subscription_handler(
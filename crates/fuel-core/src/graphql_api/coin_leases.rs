@@ -0,0 +1,334 @@
+//! The leases of the coins selected by the clients during the construction of the
+//! transactions. The coins locked by a lease are excluded from the `coinsToSpend`
+//! selections of the requests without the lease, so the concurrent requests don't
+//! select the same coins. The leases live only in the memory of the node.
+//!
+//! Each lease covers the coins of one owner, and the number of the coins locked
+//! per lease and per owner is limited, so one client can't lock all coins of the owner.
+
+use fuel_core_types::{
+    entities::coins::CoinId,
+    fuel_types::{
+        Address,
+        Bytes32,
+    },
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+/// The maximum number of the coins locked by one lease.
+pub const MAX_COINS_PER_LEASE: usize = 64;
+
+/// The maximum number of the coins of one owner locked by all leases at once.
+pub const MAX_LOCKED_COINS_PER_OWNER: usize = 256;
+
+/// The maximum number of the coins locked by all leases at once.
+pub const MAX_LOCKED_COINS: usize = 100_000;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum LeaseError {
+    #[error("The coin {0:?} is locked by another lease")]
+    AlreadyLocked(CoinId),
+    #[error("The lease can't lock more than {0} coins")]
+    TooManyCoinsInLease(usize),
+    #[error("The node can't lock more than {0} coins of one owner at once")]
+    TooManyLockedCoinsOfOwner(usize),
+    #[error("The node can't lock more than {0} coins at once")]
+    TooManyLockedCoins(usize),
+}
+
+/// The lease of the coins granted to the client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lease {
+    /// The secret identifier of the lease. The holder passes it
+    /// to `coinsToSpend` to select the locked coins and to `unlockCoins`.
+    pub id: Bytes32,
+    pub expires_at: Instant,
+}
+
+struct LockedCoins {
+    coins: Vec<CoinId>,
+    expires_at: Instant,
+}
+
+/// The leases of the coins of one owner.
+#[derive(Default)]
+struct OwnerLeases {
+    by_id: HashMap<Bytes32, LockedCoins>,
+    by_coin: HashMap<CoinId, Bytes32>,
+}
+
+impl OwnerLeases {
+    fn remove(&mut self, id: &Bytes32) -> usize {
+        let Some(lease) = self.by_id.remove(id) else {
+            return 0
+        };
+        for coin in lease.coins.iter() {
+            self.by_coin.remove(coin);
+        }
+        lease.coins.len()
+    }
+}
+
+#[derive(Default)]
+struct Leases {
+    by_owner: HashMap<Address, OwnerLeases>,
+    owners: HashMap<Bytes32, Address>,
+    locked_coins: usize,
+}
+
+impl Leases {
+    fn remove(&mut self, id: &Bytes32) -> bool {
+        let Some(owner) = self.owners.remove(id) else {
+            return false
+        };
+        if let Some(owner_leases) = self.by_owner.get_mut(&owner) {
+            let released = owner_leases.remove(id);
+            self.locked_coins = self.locked_coins.saturating_sub(released);
+            if owner_leases.by_id.is_empty() {
+                self.by_owner.remove(&owner);
+            }
+        }
+        true
+    }
+
+    fn prune_owner(&mut self, owner: &Address, now: Instant) {
+        let Some(owner_leases) = self.by_owner.get(owner) else {
+            return
+        };
+        let expired = owner_leases
+            .by_id
+            .iter()
+            .filter(|(_, lease)| lease.expires_at <= now)
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>();
+        for id in expired.iter() {
+            self.remove(id);
+        }
+    }
+
+    fn prune(&mut self, now: Instant) {
+        let owners = self.by_owner.keys().copied().collect::<Vec<_>>();
+        for owner in owners.iter() {
+            self.prune_owner(owner, now);
+        }
+    }
+}
+
+/// The registry of the active leases shared by the GraphQL requests.
+#[derive(Clone, Default)]
+pub struct CoinLeases(Arc<Mutex<Leases>>);
+
+impl CoinLeases {
+    /// Locks all `coins` of the `owner` until `now + ttl`, or none of them
+    /// if any coin is already locked by another lease or a limit is reached.
+    /// The caller checks that the `coins` belong to the `owner`.
+    pub fn lock(
+        &self,
+        owner: Address,
+        coins: Vec<CoinId>,
+        ttl: Duration,
+        now: Instant,
+    ) -> Result<Lease, LeaseError> {
+        if coins.len() > MAX_COINS_PER_LEASE {
+            return Err(LeaseError::TooManyCoinsInLease(MAX_COINS_PER_LEASE))
+        }
+        let mut leases = self.0.lock().expect("The lock is not poisoned; qed");
+        leases.prune(now);
+
+        let owner_locked = leases
+            .by_owner
+            .get(&owner)
+            .map_or(0, |owner_leases| owner_leases.by_coin.len());
+        if let Some(owner_leases) = leases.by_owner.get(&owner) {
+            if let Some(coin) = coins
+                .iter()
+                .find(|coin| owner_leases.by_coin.contains_key(coin))
+            {
+                return Err(LeaseError::AlreadyLocked(*coin))
+            }
+        }
+        if owner_locked.saturating_add(coins.len()) > MAX_LOCKED_COINS_PER_OWNER {
+            return Err(LeaseError::TooManyLockedCoinsOfOwner(
+                MAX_LOCKED_COINS_PER_OWNER,
+            ))
+        }
+        if leases.locked_coins.saturating_add(coins.len()) > MAX_LOCKED_COINS {
+            return Err(LeaseError::TooManyLockedCoins(MAX_LOCKED_COINS))
+        }
+
+        let id = Bytes32::new(rand::random());
+        let expires_at = now.checked_add(ttl).unwrap_or(now);
+        leases.locked_coins = leases.locked_coins.saturating_add(coins.len());
+        leases.owners.insert(id, owner);
+        let owner_leases = leases.by_owner.entry(owner).or_default();
+        for coin in coins.iter() {
+            owner_leases.by_coin.insert(*coin, id);
+        }
+        owner_leases
+            .by_id
+            .insert(id, LockedCoins { coins, expires_at });
+        Ok(Lease { id, expires_at })
+    }
+
+    /// Releases the coins of the lease. Returns `false` if the lease
+    /// doesn't exist or has already expired.
+    pub fn unlock(&self, id: &Bytes32, now: Instant) -> bool {
+        let mut leases = self.0.lock().expect("The lock is not poisoned; qed");
+        let Some(owner) = leases.owners.get(id).copied() else {
+            return false
+        };
+        leases.prune_owner(&owner, now);
+        leases.remove(id)
+    }
+
+    /// Returns the coins of the `owner` locked by the leases other than the `holder`.
+    pub fn locked_by_others(
+        &self,
+        owner: &Address,
+        holder: Option<&Bytes32>,
+        now: Instant,
+    ) -> Vec<CoinId> {
+        let mut leases = self.0.lock().expect("The lock is not poisoned; qed");
+        leases.prune_owner(owner, now);
+        let Some(owner_leases) = leases.by_owner.get(owner) else {
+            return vec![]
+        };
+        owner_leases
+            .by_coin
+            .iter()
+            .filter(|(_, id)| Some(*id) != holder)
+            .map(|(coin, _)| *coin)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_tx::UtxoId;
+
+    const OWNER: Address = Address::new([7; 32]);
+
+    fn coin(byte: u8) -> CoinId {
+        CoinId::Utxo(UtxoId::new([byte; 32].into(), 0))
+    }
+
+    #[test]
+    fn locked_coins_are_hidden_only_from_other_sessions() {
+        // Given
+        let leases = CoinLeases::default();
+        let now = Instant::now();
+        let lease = leases
+            .lock(OWNER, vec![coin(1), coin(2)], Duration::from_secs(10), now)
+            .unwrap();
+
+        // When
+        let for_holder = leases.locked_by_others(&OWNER, Some(&lease.id), now);
+        let mut for_others = leases.locked_by_others(&OWNER, None, now);
+        for_others.sort();
+        let for_other_owner = leases.locked_by_others(&Address::new([8; 32]), None, now);
+
+        // Then
+        assert!(for_holder.is_empty());
+        assert_eq!(for_others, vec![coin(1), coin(2)]);
+        assert!(for_other_owner.is_empty());
+    }
+
+    #[test]
+    fn lock_fails_without_locking_anything_if_any_coin_is_locked() {
+        // Given
+        let leases = CoinLeases::default();
+        let now = Instant::now();
+        leases
+            .lock(OWNER, vec![coin(1)], Duration::from_secs(10), now)
+            .unwrap();
+
+        // When
+        let result =
+            leases.lock(OWNER, vec![coin(2), coin(1)], Duration::from_secs(10), now);
+
+        // Then
+        assert_eq!(result, Err(LeaseError::AlreadyLocked(coin(1))));
+        assert_eq!(leases.locked_by_others(&OWNER, None, now), vec![coin(1)]);
+    }
+
+    #[test]
+    fn coins_are_released_on_expiration_and_unlock() {
+        // Given
+        let leases = CoinLeases::default();
+        let now = Instant::now();
+        let short = leases
+            .lock(OWNER, vec![coin(1)], Duration::from_secs(1), now)
+            .unwrap();
+        let long = leases
+            .lock(OWNER, vec![coin(2)], Duration::from_secs(10), now)
+            .unwrap();
+
+        // When
+        let later = now.checked_add(Duration::from_secs(2)).unwrap();
+        let unlocked = leases.unlock(&long.id, later);
+
+        // Then
+        assert!(unlocked);
+        assert!(!leases.unlock(&short.id, later));
+        assert!(leases.locked_by_others(&OWNER, None, later).is_empty());
+    }
+
+    #[test]
+    fn lock_fails_over_the_limits_of_the_lease_and_the_owner() {
+        // Given
+        let leases = CoinLeases::default();
+        let now = Instant::now();
+        let ttl = Duration::from_secs(10);
+        let coins = |from: usize, count: usize| {
+            (from..from.saturating_add(count))
+                .map(|index| {
+                    let mut tx_id = [0; 32];
+                    tx_id[..8]
+                        .copy_from_slice(&u64::try_from(index).unwrap().to_be_bytes());
+                    CoinId::Utxo(UtxoId::new(tx_id.into(), 0))
+                })
+                .collect::<Vec<_>>()
+        };
+        let leases_per_owner = MAX_LOCKED_COINS_PER_OWNER
+            .checked_div(MAX_COINS_PER_LEASE)
+            .unwrap();
+        for lease in 0..leases_per_owner {
+            let from = lease.saturating_mul(MAX_COINS_PER_LEASE);
+            leases
+                .lock(OWNER, coins(from, MAX_COINS_PER_LEASE), ttl, now)
+                .unwrap();
+        }
+
+        // When
+        let too_big_lease = leases.lock(
+            Address::new([8; 32]),
+            coins(MAX_LOCKED_COINS, MAX_COINS_PER_LEASE.saturating_add(1)),
+            ttl,
+            now,
+        );
+        let over_owner_limit = leases.lock(OWNER, coins(MAX_LOCKED_COINS, 1), ttl, now);
+
+        // Then
+        assert_eq!(
+            too_big_lease,
+            Err(LeaseError::TooManyCoinsInLease(MAX_COINS_PER_LEASE))
+        );
+        assert_eq!(
+            over_owner_limit,
+            Err(LeaseError::TooManyLockedCoinsOfOwner(
+                MAX_LOCKED_COINS_PER_OWNER
+            ))
+        );
+    }
+}
@@ -1,13 +1,35 @@
-use crate::fuel_core_graphql_api::{
-    database::arc_wrapper::ArcWrapper,
-    ports::{
-        DatabaseBlocks,
-        DatabaseChain,
-        DatabaseContracts,
-        DatabaseMessageProof,
-        DatabaseMessages,
-        OffChainDatabase,
-        OnChainDatabase,
+use crate::{
+    fingerprint::NodeFingerprint,
+    fuel_core_graphql_api::{
+        database::arc_wrapper::ArcWrapper,
+        entity_cache::EntityCache,
+        ports::{
+            DatabaseBlocks,
+            DatabaseChain,
+            DatabaseContracts,
+            DatabaseMessageProof,
+            DatabaseMessages,
+            OffChainDatabase,
+            OnChainDatabase,
+        },
+        storage::{
+            assets::AssetDetails,
+            contracts::ContractDeployment,
+            fees::BlockFeeReport,
+            statistics::{
+                BlockActivity,
+                ChainTotals,
+            },
+            transfers::{
+                OwnedTransferEventKey,
+                TransferEvent,
+                TransferEventIndex,
+            },
+        },
+    },
+    query::{
+        SimpleBlockData,
+        SimpleTransactionData,
     },
 };
 use fuel_core_storage::{
@@ -28,6 +50,7 @@ use fuel_core_txpool::types::{
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
+        consensus::poa::EquivocationProof,
         primitives::{
             BlockId,
             DaBlockHeight,
@@ -40,14 +63,20 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         AssetId,
+        Transaction,
         TxPointer,
         UtxoId,
     },
     fuel_types::{
         BlockHeight,
+        MessageId,
         Nonce,
     },
     services::{
+        executor::{
+            FeeBreakdown,
+            VmMetrics,
+        },
         graphql_api::ContractBalance,
         txpool::TransactionStatus,
     },
@@ -71,6 +100,8 @@ pub struct ReadDatabase {
     on_chain: Box<dyn AtomicView<View = OnChainView, Height = BlockHeight>>,
     /// The off-chain database view provider.
     off_chain: Box<dyn AtomicView<View = OffChainView, Height = BlockHeight>>,
+    /// The cache of the immutable entities shared by all views.
+    entity_cache: Arc<EntityCache>,
 }
 
 impl ReadDatabase {
@@ -85,9 +116,24 @@ impl ReadDatabase {
         Self {
             on_chain: Box::new(ArcWrapper::new(on_chain)),
             off_chain: Box::new(ArcWrapper::new(off_chain)),
+            entity_cache: Arc::new(EntityCache::default()),
+        }
+    }
+
+    /// Caches up to `capacity` bytes of the blocks and of the transactions
+    /// requested by id, see [`EntityCache`].
+    pub fn with_entity_cache(self, capacity: usize) -> Self {
+        Self {
+            entity_cache: Arc::new(EntityCache::new(capacity)),
+            ..self
         }
     }
 
+    /// The cache of the entities shared by all views.
+    pub fn entity_cache(&self) -> &Arc<EntityCache> {
+        &self.entity_cache
+    }
+
     /// Creates a consistent view of the database.
     pub fn view(&self) -> ReadView {
         // TODO: Use the same height for both views to guarantee consistency.
@@ -96,6 +142,7 @@ impl ReadDatabase {
         ReadView {
             on_chain: self.on_chain.latest_view(),
             off_chain: self.off_chain.latest_view(),
+            entity_cache: self.entity_cache.clone(),
         }
     }
 }
@@ -103,6 +150,20 @@ impl ReadDatabase {
 pub struct ReadView {
     on_chain: OnChainView,
     off_chain: OffChainView,
+    entity_cache: Arc<EntityCache>,
+}
+
+impl ReadView {
+    /// Returns the block by id from the cache or from the database.
+    pub fn cached_block(&self, id: &BlockId) -> StorageResult<CompressedBlock> {
+        self.entity_cache
+            .block(id, || self.block(&self.block_height(id)?))
+    }
+
+    /// Returns the transaction by id from the cache or from the database.
+    pub fn cached_transaction(&self, id: &TxId) -> StorageResult<Transaction> {
+        self.entity_cache.transaction(id, || self.transaction(id))
+    }
 }
 
 impl DatabaseBlocks for ReadView {
@@ -189,10 +250,76 @@ impl OffChainDatabase for ReadView {
         self.off_chain.block_height(block_id)
     }
 
+    fn block_fees(&self, height: &BlockHeight) -> StorageResult<BlockFeeReport> {
+        self.off_chain.block_fees(height)
+    }
+
+    fn block_producer_fingerprint(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<NodeFingerprint> {
+        self.off_chain.block_producer_fingerprint(height)
+    }
+
+    fn message_outbox_proof(
+        &self,
+        height: &BlockHeight,
+        message_id: &MessageId,
+    ) -> StorageResult<Option<MerkleProof>> {
+        self.off_chain.message_outbox_proof(height, message_id)
+    }
+
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus> {
         self.off_chain.tx_status(tx_id)
     }
 
+    fn tx_fee(&self, tx_id: &TxId) -> StorageResult<FeeBreakdown> {
+        self.off_chain.tx_fee(tx_id)
+    }
+
+    fn tx_vm_metrics(&self, tx_id: &TxId) -> StorageResult<VmMetrics> {
+        self.off_chain.tx_vm_metrics(tx_id)
+    }
+
+    fn spending_transaction(&self, utxo_id: &UtxoId) -> StorageResult<TxId> {
+        self.off_chain.spending_transaction(utxo_id)
+    }
+
+    fn message_spending_transaction(&self, nonce: &Nonce) -> StorageResult<TxId> {
+        self.off_chain.message_spending_transaction(nonce)
+    }
+
+    fn block_statistics(&self, height: &BlockHeight) -> StorageResult<BlockActivity> {
+        self.off_chain.block_statistics(height)
+    }
+
+    fn chain_totals(&self, height: &BlockHeight) -> StorageResult<ChainTotals> {
+        self.off_chain.chain_totals(height)
+    }
+
+    fn asset_info(&self, asset_id: &AssetId) -> StorageResult<AssetDetails> {
+        self.off_chain.asset_info(asset_id)
+    }
+
+    fn contract_deployment(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<ContractDeployment> {
+        self.off_chain.contract_deployment(contract_id)
+    }
+
+    fn contract_verification(&self, contract_id: &ContractId) -> StorageResult<Vec<u8>> {
+        self.off_chain.contract_verification(contract_id)
+    }
+
+    fn equivocations(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<EquivocationProof>> {
+        self.off_chain.equivocations(height, direction)
+    }
+
     fn owned_coins_ids(
         &self,
         owner: &Address,
@@ -221,4 +348,14 @@ impl OffChainDatabase for ReadView {
         self.off_chain
             .owned_transactions_ids(owner, start, direction)
     }
+
+    fn owned_transfer_events(
+        &self,
+        owner: Address,
+        start: Option<(BlockHeight, TransferEventIndex)>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(OwnedTransferEventKey, TransferEvent)>> {
+        self.off_chain
+            .owned_transfer_events(owner, start, direction)
+    }
 }
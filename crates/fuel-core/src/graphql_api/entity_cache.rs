@@ -0,0 +1,191 @@
+//! The in-memory cache of the immutable entities returned by the GraphQL API.
+//!
+//! Explorers request the same blocks and transactions many times, and each request
+//! deserializes them from the database. The blocks are final after the import,
+//! and both blocks and transactions are identified by their hash, so the cached
+//! entries never become stale and don't need invalidation.
+
+use crate::sized_lru::SizedLru;
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use fuel_core_txpool::types::TxId;
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        primitives::BlockId,
+    },
+    fuel_tx::Transaction,
+    fuel_types::canonical::Serialize,
+};
+use std::{
+    hash::Hash,
+    sync::Mutex,
+};
+
+/// The cache of the blocks by id and of the transactions by id
+/// shared by all GraphQL requests.
+#[derive(Debug)]
+pub struct EntityCache {
+    blocks: Mutex<SizedLru<BlockId, CompressedBlock>>,
+    transactions: Mutex<SizedLru<TxId, Transaction>>,
+}
+
+impl EntityCache {
+    /// Creates the cache that holds at most `capacity` bytes of the entities,
+    /// split equally between the blocks and the transactions.
+    /// The cache is disabled if the `capacity` is zero.
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.saturating_div(2);
+        Self {
+            blocks: Mutex::new(SizedLru::new(capacity)),
+            transactions: Mutex::new(SizedLru::new(capacity)),
+        }
+    }
+
+    /// Returns the cached block or fetches it with the `fetch` and caches it.
+    pub fn block<E>(
+        &self,
+        id: &BlockId,
+        fetch: impl FnOnce() -> Result<CompressedBlock, E>,
+    ) -> Result<CompressedBlock, E> {
+        get_or_fetch(&self.blocks, "block", id, fetch, |block| {
+            // The header has a fixed size, and the block refers to the transactions by ids.
+            core::mem::size_of::<CompressedBlock>().saturating_add(
+                block
+                    .transactions()
+                    .len()
+                    .saturating_mul(core::mem::size_of::<TxId>()),
+            )
+        })
+    }
+
+    /// Returns the cached transaction or fetches it with the `fetch` and caches it.
+    pub fn transaction<E>(
+        &self,
+        id: &TxId,
+        fetch: impl FnOnce() -> Result<Transaction, E>,
+    ) -> Result<Transaction, E> {
+        get_or_fetch(&self.transactions, "transaction", id, fetch, |tx| tx.size())
+    }
+
+    /// Returns the total size in bytes of the cached blocks and transactions.
+    pub fn size(&self) -> usize {
+        let blocks = self.blocks.lock().expect("poisoned").size();
+        let transactions = self.transactions.lock().expect("poisoned").size();
+        blocks.saturating_add(transactions)
+    }
+}
+
+impl Default for EntityCache {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+fn get_or_fetch<K, V, E>(
+    cache: &Mutex<SizedLru<K, V>>,
+    entity: &str,
+    key: &K,
+    fetch: impl FnOnce() -> Result<V, E>,
+    size_of: impl FnOnce(&V) -> usize,
+) -> Result<V, E>
+where
+    K: Hash + Eq + Clone,
+    V: Clone,
+{
+    if cache.lock().expect("poisoned").capacity() == 0 {
+        return fetch()
+    }
+
+    let cached = cache.lock().expect("poisoned").get(key).cloned();
+    graphql_metrics().observe_entity_cache(entity, cached.is_some());
+    if let Some(value) = cached {
+        return Ok(value)
+    }
+
+    let value = fetch()?;
+    let size = size_of(&value);
+    cache
+        .lock()
+        .expect("poisoned")
+        .insert(key.clone(), value.clone(), size);
+    Ok(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_storage::{
+        not_found,
+        tables::Transactions,
+        Error as StorageError,
+    };
+
+    fn tx() -> Transaction {
+        Transaction::script(
+            0,
+            vec![1],
+            vec![],
+            Default::default(),
+            vec![],
+            vec![],
+            vec![],
+        )
+        .into()
+    }
+
+    #[test]
+    fn transaction_is_fetched_once() {
+        // Given
+        let cache = EntityCache::new(1024);
+        let id = TxId::from([1; 32]);
+        let mut fetched = 0;
+
+        // When
+        for _ in 0..3 {
+            let result = cache.transaction(&id, || {
+                fetched += 1;
+                Ok::<_, StorageError>(tx())
+            });
+            assert_eq!(result.unwrap(), tx());
+        }
+
+        // Then
+        assert_eq!(fetched, 1);
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        // Given
+        let cache = EntityCache::new(1024);
+        let id = TxId::from([1; 32]);
+        let result = cache.transaction(&id, || Err(not_found!(Transactions)));
+        assert!(result.is_err());
+
+        // When
+        let result = cache.transaction(&id, || Ok::<_, StorageError>(tx()));
+
+        // Then
+        assert_eq!(result.unwrap(), tx());
+    }
+
+    #[test]
+    fn disabled_cache_always_fetches() {
+        // Given
+        let cache = EntityCache::default();
+        let id = TxId::from([1; 32]);
+        let mut fetched = 0;
+
+        // When
+        for _ in 0..2 {
+            cache
+                .transaction(&id, || {
+                    fetched += 1;
+                    Ok::<_, StorageError>(tx())
+                })
+                .unwrap();
+        }
+
+        // Then
+        assert_eq!(fetched, 2);
+    }
+}
@@ -0,0 +1,275 @@
+//! The hardening of the GraphQL HTTP server: CORS, request body limits and TLS termination.
+//!
+//! The TLS certificate and the key are reloaded from the disk when their modification time
+//! changes, so the rotated certificate is used for new connections without a restart.
+//! If the rotated files can't be loaded, the server keeps using the previous certificate.
+
+use axum::http::HeaderValue;
+use hyper::server::accept::Accept;
+use std::{
+    fs::File,
+    io::{
+        self,
+        BufReader,
+    },
+    path::PathBuf,
+    pin::Pin,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    task::{
+        Context,
+        Poll,
+    },
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+use tokio::{
+    net::TcpStream,
+    sync::mpsc,
+    task::JoinHandle,
+};
+use tokio_rustls::{
+    rustls::{
+        Certificate,
+        PrivateKey,
+        ServerConfig,
+    },
+    server::TlsStream,
+    TlsAcceptor,
+};
+use tower_http::cors::{
+    AllowOrigin,
+    Any,
+    CorsLayer,
+};
+
+/// The time given to the client to finish the TLS handshake.
+const TLS_HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The number of the established TLS connections waiting for the server.
+const TLS_ACCEPT_QUEUE: usize = 128;
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct HttpConfig {
+    /// The origins allowed to make cross-origin requests. Any origin is allowed if it is empty.
+    pub cors_origins: Vec<String>,
+    /// The maximum size of the request body in bytes. The size is unlimited if it is `None`.
+    pub max_body_size: Option<usize>,
+    /// Terminates TLS with the certificate if it is set. Otherwise, the server uses plain HTTP.
+    pub tls: Option<TlsConfig>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TlsConfig {
+    /// The path to the PEM encoded certificate chain.
+    pub cert_path: PathBuf,
+    /// The path to the PEM encoded private key.
+    pub key_path: PathBuf,
+}
+
+impl TlsConfig {
+    fn load(&self) -> anyhow::Result<Arc<ServerConfig>> {
+        let certs =
+            rustls_pemfile::certs(&mut BufReader::new(File::open(&self.cert_path)?))?
+                .into_iter()
+                .map(Certificate)
+                .collect::<Vec<_>>();
+        if certs.is_empty() {
+            return Err(anyhow::anyhow!(
+                "The `{}` doesn't contain certificates",
+                self.cert_path.display()
+            ))
+        }
+
+        let key =
+            rustls_pemfile::read_all(&mut BufReader::new(File::open(&self.key_path)?))?
+                .into_iter()
+                .find_map(|item| match item {
+                    rustls_pemfile::Item::RSAKey(key)
+                    | rustls_pemfile::Item::PKCS8Key(key)
+                    | rustls_pemfile::Item::ECKey(key) => Some(PrivateKey(key)),
+                    _ => None,
+                })
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The `{}` doesn't contain a private key",
+                        self.key_path.display()
+                    )
+                })?;
+
+        let mut config = ServerConfig::builder()
+            .with_safe_defaults()
+            .with_no_client_auth()
+            .with_single_cert(certs, key)?;
+        config.alpn_protocols = vec![b"http/1.1".to_vec()];
+        Ok(Arc::new(config))
+    }
+
+    fn modified(&self) -> (Option<SystemTime>, Option<SystemTime>) {
+        let modified = |path: &PathBuf| {
+            std::fs::metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok()
+        };
+        (modified(&self.cert_path), modified(&self.key_path))
+    }
+}
+
+/// Builds the CORS layer allowing requests from the `origins`, or from any origin
+/// if the `origins` are empty.
+pub fn cors_layer(origins: &[String]) -> anyhow::Result<CorsLayer> {
+    let allow_origin = if origins.is_empty() {
+        AllowOrigin::from(Any)
+    } else {
+        let origins = origins
+            .iter()
+            .map(|origin| HeaderValue::from_str(origin))
+            .collect::<Result<Vec<_>, _>>()?;
+        AllowOrigin::list(origins)
+    };
+
+    Ok(CorsLayer::new()
+        .allow_origin(allow_origin)
+        .allow_methods(Any)
+        .allow_headers(Any))
+}
+
+struct TlsState {
+    config: Arc<ServerConfig>,
+    modified: (Option<SystemTime>, Option<SystemTime>),
+}
+
+/// The TLS acceptor that reloads the certificate when it is rotated on the disk.
+pub struct ReloadingTlsAcceptor {
+    tls: TlsConfig,
+    state: Mutex<TlsState>,
+}
+
+impl ReloadingTlsAcceptor {
+    pub fn new(tls: TlsConfig) -> anyhow::Result<Self> {
+        let modified = tls.modified();
+        let config = tls.load()?;
+        Ok(Self {
+            tls,
+            state: Mutex::new(TlsState { config, modified }),
+        })
+    }
+
+    fn acceptor(&self) -> TlsAcceptor {
+        let modified = self.tls.modified();
+        let mut state = self.state.lock().expect("The lock is not poisoned; qed");
+        if state.modified != modified {
+            match self.tls.load() {
+                Ok(config) => {
+                    tracing::info!("Reloaded the TLS certificate of the GraphQL server");
+                    state.config = config;
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed to reload the TLS certificate of the GraphQL server: {err}"
+                    );
+                }
+            }
+            state.modified = modified;
+        }
+        TlsAcceptor::from(state.config.clone())
+    }
+}
+
+/// The connections accepted by the server after the TLS handshake. The handshakes
+/// are performed in separate tasks, so slow clients don't block other connections.
+pub struct TlsIncoming {
+    receiver: mpsc::Receiver<TlsStream<TcpStream>>,
+    accept_task: JoinHandle<()>,
+}
+
+impl TlsIncoming {
+    pub fn new(
+        listener: std::net::TcpListener,
+        acceptor: Arc<ReloadingTlsAcceptor>,
+    ) -> anyhow::Result<Self> {
+        listener.set_nonblocking(true)?;
+        let listener = tokio::net::TcpListener::from_std(listener)?;
+        let (sender, receiver) = mpsc::channel(TLS_ACCEPT_QUEUE);
+
+        let accept_task = tokio::spawn(async move {
+            loop {
+                let stream = match listener.accept().await {
+                    Ok((stream, _)) => stream,
+                    Err(err) => {
+                        tracing::warn!("Failed to accept the GraphQL connection: {err}");
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                        continue
+                    }
+                };
+                let acceptor = acceptor.acceptor();
+                let sender = sender.clone();
+                tokio::spawn(async move {
+                    match tokio::time::timeout(
+                        TLS_HANDSHAKE_TIMEOUT,
+                        acceptor.accept(stream),
+                    )
+                    .await
+                    {
+                        Ok(Ok(stream)) => {
+                            let _ = sender.send(stream).await;
+                        }
+                        Ok(Err(err)) => {
+                            tracing::debug!("The TLS handshake failed: {err}");
+                        }
+                        Err(_) => {
+                            tracing::debug!("The TLS handshake timed out");
+                        }
+                    }
+                });
+            }
+        });
+
+        Ok(Self {
+            receiver,
+            accept_task,
+        })
+    }
+}
+
+impl Accept for TlsIncoming {
+    type Conn = TlsStream<TcpStream>;
+    type Error = io::Error;
+
+    fn poll_accept(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Conn, Self::Error>>> {
+        self.receiver.poll_recv(cx).map(|conn| conn.map(Ok))
+    }
+}
+
+impl Drop for TlsIncoming {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cors_layer_rejects_invalid_origin() {
+        assert!(cors_layer(&["https://example.com".to_string()]).is_ok());
+        assert!(cors_layer(&["invalid\norigin".to_string()]).is_err());
+    }
+
+    #[test]
+    fn reloading_tls_acceptor_fails_on_missing_files() {
+        let tls = TlsConfig {
+            cert_path: "/non/existing/cert.pem".into(),
+            key_path: "/non/existing/key.pem".into(),
+        };
+        assert!(ReloadingTlsAcceptor::new(tls).is_err());
+    }
+}
@@ -0,0 +1,128 @@
+//! The conditional responses of the plain HTTP endpoints.
+//!
+//! The responses carry the strong `ETag` derived from the body and the `Cache-Control`
+//! of the endpoint. If the `If-None-Match` of the request matches the `ETag`, the body
+//! is not sent, and the client or the CDN reuses its copy.
+
+use axum::{
+    http::{
+        header::{
+            CACHE_CONTROL,
+            CONTENT_TYPE,
+            ETAG,
+            IF_NONE_MATCH,
+        },
+        HeaderMap,
+        HeaderValue,
+        StatusCode,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+};
+use fuel_core_types::fuel_crypto::Hasher;
+
+/// The `Cache-Control` of the responses that change at any moment,
+/// e.g. the health of the node. The client has to revalidate them on each use.
+pub const REVALIDATE: &str = "no-cache";
+
+/// The `Cache-Control` of the responses that change only with the version of the node.
+pub const STATIC: &str = "public, max-age=3600";
+
+/// Returns the `body` with the `ETag` and the `Cache-Control` headers,
+/// or `304 Not Modified` if the client already has it.
+pub fn conditional_response(
+    request_headers: &HeaderMap,
+    content_type: &'static str,
+    cache_control: &'static str,
+    body: Vec<u8>,
+) -> Response {
+    let etag = etag(&body);
+    let headers = [
+        (ETAG, etag.clone()),
+        (CACHE_CONTROL, HeaderValue::from_static(cache_control)),
+    ];
+
+    if is_not_modified(request_headers, &etag) {
+        (StatusCode::NOT_MODIFIED, headers).into_response()
+    } else {
+        let content_type = [(CONTENT_TYPE, HeaderValue::from_static(content_type))];
+        (headers, content_type, body).into_response()
+    }
+}
+
+fn etag(body: &[u8]) -> HeaderValue {
+    let hash = Hasher::hash(body);
+    HeaderValue::from_str(&format!("\"{}\"", hex::encode(hash)))
+        .expect("The hex string is a valid header value")
+}
+
+/// The `If-None-Match` uses the weak comparison, so the `W/` prefix is ignored.
+fn is_not_modified(request_headers: &HeaderMap, etag: &HeaderValue) -> bool {
+    let Ok(etag) = etag.to_str() else {
+        return false
+    };
+    request_headers
+        .get_all(IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn if_none_match(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(IF_NONE_MATCH, HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn response_has_etag_and_cache_control() {
+        // When
+        let response = conditional_response(
+            &HeaderMap::new(),
+            "text/plain",
+            STATIC,
+            b"body".to_vec(),
+        );
+
+        // Then
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[CACHE_CONTROL], STATIC);
+        assert_eq!(response.headers()[ETAG], etag(b"body"));
+    }
+
+    #[test]
+    fn matching_etag_is_not_modified() {
+        // Given
+        let etag = etag(b"body");
+        let headers = if_none_match(&format!("\"other\", W/{}", etag.to_str().unwrap()));
+
+        // When
+        let response =
+            conditional_response(&headers, "text/plain", REVALIDATE, b"body".to_vec());
+
+        // Then
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[ETAG], etag);
+    }
+
+    #[test]
+    fn changed_body_is_sent_again() {
+        // Given
+        let headers = if_none_match(etag(b"old body").to_str().unwrap());
+
+        // When
+        let response =
+            conditional_response(&headers, "text/plain", REVALIDATE, b"body".to_vec());
+
+        // Then
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
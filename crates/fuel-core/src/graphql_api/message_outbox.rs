@@ -0,0 +1,354 @@
+use crate::fuel_core_graphql_api::storage::messages::{
+    MessageOutboxLeaf,
+    MessageOutboxLeaves,
+    MessageOutboxMerkleData,
+    MessageOutboxMerkleMetadata,
+    MessageOutboxNodeKey,
+};
+use fuel_core_storage::{
+    not_found,
+    tables::merkle::DenseMerkleMetadata,
+    Error as StorageError,
+    Mappable,
+    Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
+    StorageInspect,
+    StorageMutate,
+};
+use fuel_core_types::{
+    entities::message::MerkleProof,
+    fuel_merkle::binary::{
+        self,
+        in_memory,
+    },
+    fuel_types::{
+        BlockHeight,
+        MessageId,
+    },
+};
+use lru::LruCache;
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    num::NonZeroUsize,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+/// The message outbox Merkle trees of the recent blocks.
+///
+/// The off-chain worker warms the cache with the tree of each imported block, so the
+/// proofs of the fresh messages don't touch the database. The proofs of the older
+/// messages are built from the persisted tree of the block by [`outbox_proof`].
+#[derive(Clone)]
+pub struct MessageOutboxCache {
+    trees: Arc<Mutex<LruCache<BlockHeight, OutboxTree>>>,
+}
+
+struct OutboxTree {
+    tree: in_memory::MerkleTree,
+    /// message id -> the index of the leaf.
+    leaves: HashMap<MessageId, u64>,
+}
+
+impl OutboxTree {
+    fn new(message_ids: &[MessageId]) -> Self {
+        let mut tree = in_memory::MerkleTree::new();
+        let mut leaves = HashMap::with_capacity(message_ids.len());
+        for (index, id) in (0u64..).zip(message_ids) {
+            tree.push(id.as_ref());
+            // The message ids are unique because they commit to the nonce.
+            leaves.entry(*id).or_insert(index);
+        }
+        Self { tree, leaves }
+    }
+
+    fn proof(&self, message_id: &MessageId) -> Option<MerkleProof> {
+        let proof_index = *self.leaves.get(message_id)?;
+        let (_, proof_set) = self.tree.prove(proof_index)?;
+        Some(MerkleProof {
+            proof_set,
+            proof_index,
+        })
+    }
+}
+
+impl MessageOutboxCache {
+    /// Creates the cache that holds the trees of at most `capacity` blocks.
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            trees: Arc::new(Mutex::new(LruCache::new(capacity))),
+        }
+    }
+
+    /// Caches the tree of the block sending the `message_ids`.
+    pub fn insert(&self, height: BlockHeight, message_ids: &[MessageId]) {
+        let tree = OutboxTree::new(message_ids);
+        self.trees.lock().expect("poisoned").put(height, tree);
+    }
+
+    /// Returns the proof of the inclusion of the message into the outbox of the block,
+    /// or `None` if the block didn't send the message. The proofs of the blocks
+    /// missing in the cache are returned by `load`.
+    pub fn proof<F>(
+        &self,
+        height: BlockHeight,
+        message_id: &MessageId,
+        load: F,
+    ) -> StorageResult<Option<MerkleProof>>
+    where
+        F: FnOnce() -> StorageResult<Option<MerkleProof>>,
+    {
+        if let Some(tree) = self.trees.lock().expect("poisoned").get(&height) {
+            return Ok(tree.proof(message_id))
+        }
+        load()
+    }
+}
+
+/// The nodes of the outbox tree of one block in the [`MessageOutboxMerkleData`] table.
+struct OutboxNodes;
+
+impl Mappable for OutboxNodes {
+    type Key = u64;
+    type OwnedKey = Self::Key;
+    type Value = binary::Primitive;
+    type OwnedValue = Self::Value;
+}
+
+/// The storage of the outbox tree of the block at `height`.
+struct OutboxTreeStorage<S> {
+    height: BlockHeight,
+    storage: S,
+}
+
+impl<S> OutboxTreeStorage<S> {
+    fn key(&self, node: u64) -> MessageOutboxNodeKey {
+        MessageOutboxNodeKey {
+            block_height: self.height,
+            node,
+        }
+    }
+}
+
+impl<S> StorageInspect<OutboxNodes> for OutboxTreeStorage<S>
+where
+    S: StorageInspect<MessageOutboxMerkleData, Error = StorageError>,
+{
+    type Error = StorageError;
+
+    fn get(&self, key: &u64) -> StorageResult<Option<Cow<binary::Primitive>>> {
+        StorageInspect::<MessageOutboxMerkleData>::get(&self.storage, &self.key(*key))
+    }
+
+    fn contains_key(&self, key: &u64) -> StorageResult<bool> {
+        StorageInspect::<MessageOutboxMerkleData>::contains_key(
+            &self.storage,
+            &self.key(*key),
+        )
+    }
+}
+
+impl<S> StorageMutate<OutboxNodes> for OutboxTreeStorage<S>
+where
+    S: StorageMutate<MessageOutboxMerkleData, Error = StorageError>,
+{
+    fn insert(
+        &mut self,
+        key: &u64,
+        value: &binary::Primitive,
+    ) -> StorageResult<Option<binary::Primitive>> {
+        let key = self.key(*key);
+        StorageMutate::<MessageOutboxMerkleData>::insert(&mut self.storage, &key, value)
+    }
+
+    fn remove(&mut self, key: &u64) -> StorageResult<Option<binary::Primitive>> {
+        let key = self.key(*key);
+        StorageMutate::<MessageOutboxMerkleData>::remove(&mut self.storage, &key)
+    }
+}
+
+/// Persists the outbox tree of the block sending the `message_ids`
+/// and the positions of the messages in it.
+pub fn persist_outbox<D>(
+    storage: &mut D,
+    height: BlockHeight,
+    message_ids: &[MessageId],
+) -> StorageResult<()>
+where
+    D: StorageMutate<MessageOutboxMerkleData, Error = StorageError>
+        + StorageMutate<MessageOutboxMerkleMetadata, Error = StorageError>
+        + StorageMutate<MessageOutboxLeaves, Error = StorageError>,
+{
+    let mut tree: binary::MerkleTree<OutboxNodes, _> =
+        binary::MerkleTree::new(OutboxTreeStorage {
+            height,
+            storage: &mut *storage,
+        });
+    for id in message_ids {
+        tree.push(id.as_ref())?;
+    }
+    let metadata = DenseMerkleMetadata::new(tree.root(), tree.leaves_count());
+    drop(tree);
+
+    for (index, id) in (0u64..).zip(message_ids) {
+        let leaf = MessageOutboxLeaf {
+            block_height: height,
+            index,
+        };
+        storage
+            .storage_as_mut::<MessageOutboxLeaves>()
+            .insert(id, &leaf)?;
+    }
+    storage
+        .storage_as_mut::<MessageOutboxMerkleMetadata>()
+        .insert(&height, &metadata)?;
+    Ok(())
+}
+
+/// Returns the proof of the inclusion of the message into the persisted outbox tree
+/// of the block, or `None` if the message was sent by another block.
+/// Returns the `NotFound` error if the message is not indexed.
+pub fn outbox_proof<D>(
+    storage: &D,
+    height: BlockHeight,
+    message_id: &MessageId,
+) -> StorageResult<Option<MerkleProof>>
+where
+    D: StorageInspect<MessageOutboxMerkleData, Error = StorageError>
+        + StorageInspect<MessageOutboxMerkleMetadata, Error = StorageError>
+        + StorageInspect<MessageOutboxLeaves, Error = StorageError>,
+{
+    let leaf = storage
+        .storage_as_ref::<MessageOutboxLeaves>()
+        .get(message_id)?
+        .ok_or(not_found!(MessageOutboxLeaves))?
+        .into_owned();
+    if leaf.block_height != height {
+        return Ok(None)
+    }
+    let metadata = storage
+        .storage_as_ref::<MessageOutboxMerkleMetadata>()
+        .get(&height)?
+        .ok_or(not_found!(MessageOutboxMerkleMetadata))?;
+
+    let tree: binary::MerkleTree<OutboxNodes, _> = binary::MerkleTree::load(
+        OutboxTreeStorage { height, storage },
+        metadata.version(),
+    )
+    .map_err(|err| StorageError::Other(anyhow::anyhow!(err)))?;
+    let (_, proof_set) = tree
+        .prove(leaf.index)
+        .map_err(|err| StorageError::Other(anyhow::anyhow!(err)))?;
+    Ok(Some(MerkleProof {
+        proof_set,
+        proof_index: leaf.index,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{
+        database_description::off_chain::OffChain,
+        Database,
+    };
+
+    fn message_ids() -> Vec<MessageId> {
+        (0u8..5).map(|i| [i; 32].into()).collect()
+    }
+
+    fn capacity(capacity: usize) -> NonZeroUsize {
+        NonZeroUsize::new(capacity).unwrap()
+    }
+
+    fn expected_proof(ids: &[MessageId], index: u64) -> MerkleProof {
+        let mut tree = in_memory::MerkleTree::new();
+        ids.iter().for_each(|id| tree.push(id.as_ref()));
+        let (_, proof_set) = tree.prove(index).unwrap();
+        MerkleProof {
+            proof_set,
+            proof_index: index,
+        }
+    }
+
+    #[test]
+    fn proof_matches_the_tree_of_the_block() {
+        // Given
+        let ids = message_ids();
+        let cache = MessageOutboxCache::new(capacity(1));
+        cache.insert(1u32.into(), &ids);
+
+        // When
+        let proof = cache
+            .proof(1u32.into(), &ids[3], || panic!("The tree is cached"))
+            .unwrap();
+
+        // Then
+        assert_eq!(proof, Some(expected_proof(&ids, 3)));
+    }
+
+    #[test]
+    fn unknown_message_has_no_proof() {
+        // Given
+        let cache = MessageOutboxCache::new(capacity(1));
+        cache.insert(1u32.into(), &message_ids());
+
+        // When
+        let proof = cache
+            .proof(1u32.into(), &[9; 32].into(), || {
+                panic!("The tree is cached")
+            })
+            .unwrap();
+
+        // Then
+        assert!(proof.is_none());
+    }
+
+    #[test]
+    fn evicted_tree_is_loaded() {
+        // Given
+        let ids = message_ids();
+        let cache = MessageOutboxCache::new(capacity(1));
+        cache.insert(1u32.into(), &ids);
+        cache.insert(2u32.into(), &[]);
+
+        // When
+        let mut loads = 0;
+        let proof = cache
+            .proof(1u32.into(), &ids[0], || {
+                loads += 1;
+                Ok(Some(expected_proof(&ids, 0)))
+            })
+            .unwrap();
+
+        // Then
+        assert_eq!(loads, 1);
+        assert_eq!(proof, Some(expected_proof(&ids, 0)));
+    }
+
+    #[test]
+    fn persisted_tree_proves_messages_of_historical_blocks() {
+        // Given
+        let mut db = Database::<OffChain>::default();
+        let ids = message_ids();
+        let (first, second) = ids.split_at(2);
+        persist_outbox(&mut db, 1u32.into(), first).unwrap();
+        persist_outbox(&mut db, 2u32.into(), second).unwrap();
+
+        // When
+        let first_proof = outbox_proof(&db, 1u32.into(), &first[1]).unwrap();
+        let second_proof = outbox_proof(&db, 2u32.into(), &second[2]).unwrap();
+        let wrong_block = outbox_proof(&db, 2u32.into(), &first[0]).unwrap();
+        let unknown = outbox_proof(&db, 1u32.into(), &[9; 32].into());
+
+        // Then
+        assert_eq!(first_proof, Some(expected_proof(first, 1)));
+        assert_eq!(second_proof, Some(expected_proof(second, 2)));
+        assert_eq!(wrong_block, None);
+        assert!(matches!(unknown, Err(StorageError::NotFound(_, _))));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::state::slow_reads;
 use async_graphql::{
     extensions::{
         Extension,
@@ -57,7 +58,7 @@ impl Extension for MetricsExtInner {
         next: NextRequest<'_>,
     ) -> Response {
         let start_time = Instant::now();
-        let result = next.run(ctx).await;
+        let result = slow_reads::track_tables(next.run(ctx)).await;
         let seconds = start_time.elapsed().as_secs_f64();
         graphql_metrics().graphql_observe("request", seconds);
 
@@ -90,6 +91,7 @@ impl Extension for MetricsExtInner {
             _ => None,
         };
 
+        let (parent_type, name) = (info.parent_type, info.name);
         let start_time = Instant::now();
         let res = next.run(ctx, info).await;
         let elapsed = start_time.elapsed();
@@ -99,19 +101,74 @@ impl Extension for MetricsExtInner {
         }
 
         if elapsed > self.log_threshold_ms {
+            let path = format!("{parent_type}.{name}");
+            graphql_metrics().observe_slow_query(&path);
             let query = self
                 .current_query
                 .get()
                 .map(String::as_str)
-                .unwrap_or("UNKNOWN");
+                .map(query_shape)
+                .unwrap_or_else(|| "UNKNOWN".to_string());
             tracing::info!(
-                "Query {:?} exceeded threshold of {:?} seconds at {:?} seconds",
+                "Query {:?} exceeded threshold of {:?} seconds at {:?} seconds in {} touching the tables {:?}",
                 query,
                 self.log_threshold_ms.as_secs_f64(),
-                elapsed.as_secs_f64()
+                elapsed.as_secs_f64(),
+                path,
+                slow_reads::touched_tables()
             );
         }
 
         res
     }
 }
+
+/// Returns the query without the string literals and the redundant whitespaces,
+/// so the log doesn't contain the values passed by the user.
+fn query_shape(query: &str) -> String {
+    let mut shape = String::with_capacity(query.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for char in query.chars() {
+        if in_string {
+            match char {
+                '\\' if !escaped => escaped = true,
+                '"' if !escaped => {
+                    in_string = false;
+                    shape.push_str("\"?\"");
+                }
+                _ => escaped = false,
+            }
+        } else if char == '"' {
+            in_string = true;
+        } else if char.is_whitespace() {
+            if !shape.ends_with(' ') && !shape.is_empty() {
+                shape.push(' ');
+            }
+        } else {
+            shape.push(char);
+        }
+    }
+    shape.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_shape_hides_string_literals() {
+        let query = r#"
+            query {
+                coins(owner: "0x01\"02", first: 10) {
+                    nodes { amount }
+                }
+            }
+        "#;
+
+        assert_eq!(
+            query_shape(query),
+            r#"query { coins(owner: "?", first: 10) { nodes { amount } } }"#
+        );
+    }
+}
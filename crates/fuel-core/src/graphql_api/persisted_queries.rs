@@ -0,0 +1,233 @@
+use crate::fuel_core_graphql_api::{
+    api_error::ApiError,
+    AdminToken,
+};
+use anyhow::Context;
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextPrepareRequest,
+    },
+    Request,
+    ServerResult,
+    Value,
+};
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::Bytes32,
+    services::graphql_api::ErrorCode,
+};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    str::FromStr,
+    sync::{
+        Arc,
+        RwLock,
+    },
+};
+
+/// The queries registered by the admin, identified by the SHA-256 hash of their text.
+#[derive(Clone, Default)]
+pub struct PersistedQueries {
+    queries: Arc<RwLock<HashMap<Bytes32, String>>>,
+    /// The file storing the registered queries between the restarts, if any.
+    path: Option<PathBuf>,
+}
+
+impl PersistedQueries {
+    /// Restores the queries registered before the restart from the file at the `path`.
+    /// If the `path` is set, the file is rewritten on each registration and removal.
+    pub fn load(path: Option<PathBuf>) -> anyhow::Result<Self> {
+        let mut queries = HashMap::new();
+        if let Some(path) = path.as_ref().filter(|path| path.exists()) {
+            let file = std::fs::read(path).with_context(|| {
+                format!(
+                    "Failed to read the persisted queries from {}",
+                    path.display()
+                )
+            })?;
+            let stored: Vec<String> =
+                serde_json::from_slice(&file).with_context(|| {
+                    format!(
+                        "Failed to parse the persisted queries from {}",
+                        path.display()
+                    )
+                })?;
+            for query in stored {
+                queries.insert(Self::hash(&query), query);
+            }
+        }
+        Ok(Self {
+            queries: Arc::new(RwLock::new(queries)),
+            path,
+        })
+    }
+
+    pub fn hash(query: &str) -> Bytes32 {
+        Hasher::hash(query.as_bytes())
+    }
+
+    /// Registers the query and returns its hash.
+    pub fn register(&self, query: String) -> anyhow::Result<Bytes32> {
+        let hash = Self::hash(&query);
+        let mut queries = self.queries.write().expect("The lock is not poisoned; qed");
+        if queries.insert(hash, query).is_none() {
+            if let Err(err) = self.store(&queries) {
+                queries.remove(&hash);
+                return Err(err)
+            }
+        }
+        Ok(hash)
+    }
+
+    /// Removes the query. Returns `false` if it was not registered.
+    pub fn remove(&self, hash: &Bytes32) -> anyhow::Result<bool> {
+        let mut queries = self.queries.write().expect("The lock is not poisoned; qed");
+        let Some(query) = queries.remove(hash) else {
+            return Ok(false)
+        };
+        if let Err(err) = self.store(&queries) {
+            queries.insert(*hash, query);
+            return Err(err)
+        }
+        Ok(true)
+    }
+
+    pub fn get(&self, hash: &Bytes32) -> Option<String> {
+        self.queries
+            .read()
+            .expect("The lock is not poisoned; qed")
+            .get(hash)
+            .cloned()
+    }
+
+    pub fn contains(&self, hash: &Bytes32) -> bool {
+        self.queries
+            .read()
+            .expect("The lock is not poisoned; qed")
+            .contains_key(hash)
+    }
+
+    /// Replaces the file with the `queries`. The new file is renamed over
+    /// the old one, so the crash doesn't leave the file partially written.
+    fn store(&self, queries: &HashMap<Bytes32, String>) -> anyhow::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(())
+        };
+        let mut stored = queries.values().collect::<Vec<_>>();
+        stored.sort();
+        let tmp_path = path.with_extension("tmp");
+        std::fs::write(&tmp_path, serde_json::to_vec_pretty(&stored)?)
+            .and_then(|_| std::fs::rename(&tmp_path, path))
+            .with_context(|| {
+                format!(
+                    "Failed to store the persisted queries to {}",
+                    path.display()
+                )
+            })
+    }
+}
+
+/// The extension that resolves the persisted queries sent as
+/// `extensions: { persistedQuery: { sha256Hash: "<hash>" } }` without the query text.
+///
+/// In the allow-list mode, it also rejects the queries that are not registered,
+/// unless the request carries the valid admin token.
+pub(crate) struct PersistedQueriesExtension {
+    queries: PersistedQueries,
+    allow_list_only: bool,
+    admin_token: Option<AdminToken>,
+}
+
+impl PersistedQueriesExtension {
+    pub fn new(
+        queries: PersistedQueries,
+        allow_list_only: bool,
+        admin_token: Option<AdminToken>,
+    ) -> Self {
+        Self {
+            queries,
+            allow_list_only,
+            admin_token,
+        }
+    }
+
+    /// The token of the request is moved from the request into
+    /// the data of the query before the extensions are called.
+    fn is_admin(&self, ctx: &ExtensionContext<'_>) -> bool {
+        match (self.admin_token, ctx.data_opt::<AdminToken>()) {
+            (Some(expected), Some(token)) => expected == *token,
+            _ => false,
+        }
+    }
+}
+
+impl ExtensionFactory for PersistedQueriesExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(PersistedQueriesExtension::new(
+            self.queries.clone(),
+            self.allow_list_only,
+            self.admin_token,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for PersistedQueriesExtension {
+    async fn prepare_request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        mut request: Request,
+        next: NextPrepareRequest<'_>,
+    ) -> ServerResult<Request> {
+        if let Some(hash) = persisted_query_hash(&request) {
+            let hash = Bytes32::from_str(hash).map_err(|_| {
+                ApiError::new(
+                    ErrorCode::InvalidRequest,
+                    "The persisted query hash is invalid",
+                )
+                .into_server_error()
+            })?;
+            let query = self.queries.get(&hash).ok_or_else(|| {
+                ApiError::new(ErrorCode::NotFound, "PersistedQueryNotFound")
+                    .into_server_error()
+            })?;
+            if request.query.is_empty() {
+                request.query = query;
+            } else if request.query != query {
+                return Err(ApiError::new(
+                    ErrorCode::InvalidRequest,
+                    "The query doesn't match the persisted query hash",
+                )
+                .into_server_error())
+            }
+        } else if self.allow_list_only
+            && !self.is_admin(ctx)
+            && !self
+                .queries
+                .contains(&PersistedQueries::hash(&request.query))
+        {
+            return Err(ApiError::new(
+                ErrorCode::Forbidden,
+                "Only the persisted queries are allowed by the node",
+            )
+            .into_server_error())
+        }
+
+        next.run(ctx, request).await
+    }
+}
+
+fn persisted_query_hash(request: &Request) -> Option<&str> {
+    let Some(Value::Object(persisted_query)) = request.extensions.get("persistedQuery")
+    else {
+        return None
+    };
+    match persisted_query.get("sha256Hash") {
+        Some(Value::String(hash)) => Some(hash.as_str()),
+        _ => None,
+    }
+}
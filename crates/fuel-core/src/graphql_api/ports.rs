@@ -1,4 +1,26 @@
+use crate::{
+    combined_database::DatabaseStatistics,
+    fingerprint::NodeFingerprint,
+    fuel_core_graphql_api::storage::{
+        assets::AssetDetails,
+        contracts::ContractDeployment,
+        fees::BlockFeeReport,
+        statistics::{
+            BlockActivity,
+            ChainTotals,
+        },
+        transfers::{
+            OwnedTransferEventKey,
+            TransferEvent,
+            TransferEventIndex,
+        },
+    },
+};
 use async_trait::async_trait;
+use fuel_core_poa::service::{
+    PauseReason,
+    Paused,
+};
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::{
     iter::{
@@ -19,10 +41,14 @@ use fuel_core_storage::{
     Result as StorageResult,
     StorageInspect,
 };
-use fuel_core_txpool::service::TxStatusMessage;
+use fuel_core_txpool::{
+    service::TxStatusMessage,
+    TxInfo,
+};
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
+        consensus::poa::EquivocationProof,
         primitives::{
             BlockId,
             DaBlockHeight,
@@ -43,26 +69,86 @@ use fuel_core_types::{
         AssetId,
         BlockHeight,
         ContractId,
+        MessageId,
         Nonce,
     },
     services::{
-        executor::TransactionExecutionStatus,
+        executor::{
+            DryRunResult,
+            FeeBreakdown,
+            StateOverrides,
+            VmMetrics,
+        },
         graphql_api::ContractBalance,
         p2p::PeerInfo,
+        relayer::MessageOrigin,
         txpool::{
+            EvictedTransaction,
             InsertionResult,
             TransactionStatus,
         },
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Duration,
+};
+use tokio::sync::broadcast;
 
 pub trait OffChainDatabase: Send + Sync {
     fn block_height(&self, block_id: &BlockId) -> StorageResult<BlockHeight>;
 
+    fn block_fees(&self, height: &BlockHeight) -> StorageResult<BlockFeeReport>;
+
+    /// Returns the fingerprint of the binary that produced the block.
+    /// Only the blocks produced by this node have the fingerprint.
+    fn block_producer_fingerprint(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<NodeFingerprint>;
+
+    /// Returns the proof of the inclusion of the message into the message outbox
+    /// Merkle tree of the block, or `None` if the message was sent by another block.
+    fn message_outbox_proof(
+        &self,
+        height: &BlockHeight,
+        message_id: &MessageId,
+    ) -> StorageResult<Option<MerkleProof>>;
+
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus>;
 
+    fn tx_fee(&self, tx_id: &TxId) -> StorageResult<FeeBreakdown>;
+
+    fn tx_vm_metrics(&self, tx_id: &TxId) -> StorageResult<VmMetrics>;
+
+    /// Returns the id of the transaction that spent the coin with the `utxo_id`.
+    fn spending_transaction(&self, utxo_id: &UtxoId) -> StorageResult<TxId>;
+
+    /// Returns the id of the transaction that consumed the message with the `nonce`.
+    fn message_spending_transaction(&self, nonce: &Nonce) -> StorageResult<TxId>;
+
+    /// Returns the activity of the transactions of the block at the `height`.
+    fn block_statistics(&self, height: &BlockHeight) -> StorageResult<BlockActivity>;
+
+    /// Returns the totals of the chain up to and including the block at the `height`.
+    fn chain_totals(&self, height: &BlockHeight) -> StorageResult<ChainTotals>;
+
+    fn asset_info(&self, asset_id: &AssetId) -> StorageResult<AssetDetails>;
+
+    fn contract_deployment(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<ContractDeployment>;
+
+    fn contract_verification(&self, contract_id: &ContractId) -> StorageResult<Vec<u8>>;
+
+    fn equivocations(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<EquivocationProof>>;
+
     fn owned_coins_ids(
         &self,
         owner: &Address,
@@ -83,6 +169,13 @@ pub trait OffChainDatabase: Send + Sync {
         start: Option<TxPointer>,
         direction: IterDirection,
     ) -> BoxedIter<StorageResult<(TxPointer, TxId)>>;
+
+    fn owned_transfer_events(
+        &self,
+        owner: Address,
+        start: Option<(BlockHeight, TransferEventIndex)>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(OwnedTransferEventKey, TransferEvent)>>;
 }
 
 /// The on chain database port expected by GraphQL API service.
@@ -151,15 +244,31 @@ pub trait TxPoolPort: Send + Sync {
 
     fn submission_time(&self, id: TxId) -> Option<Tai64>;
 
+    /// Returns all transactions from the pool sorted by their ids.
+    fn pending_transactions(&self) -> Vec<TxInfo>;
+
+    /// Returns the pending transactions of the owner with the ordering hint,
+    /// in the order of their inclusion into the blocks.
+    fn pending_chain(&self, owner: Address) -> Vec<TxInfo>;
+
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
     ) -> Vec<anyhow::Result<InsertionResult>>;
 
+    /// Inserts the transactions with the optional hints ordering the transactions of one owner.
+    async fn insert_with_ordering_hints(
+        &self,
+        txs: Vec<(Arc<Transaction>, Option<u64>)>,
+    ) -> Vec<anyhow::Result<InsertionResult>>;
+
     fn tx_update_subscribe(
         &self,
         tx_id: TxId,
     ) -> anyhow::Result<BoxStream<TxStatusMessage>>;
+
+    /// Subscribes to the transactions evicted from the pool after the call.
+    fn evicted_tx_subscribe(&self) -> broadcast::Receiver<EvictedTransaction>;
 }
 
 #[async_trait]
@@ -169,7 +278,9 @@ pub trait BlockProducerPort: Send + Sync {
         transactions: Vec<Transaction>,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
-    ) -> anyhow::Result<Vec<TransactionExecutionStatus>>;
+        state_overrides: StateOverrides,
+        profile_gas: bool,
+    ) -> anyhow::Result<Vec<DryRunResult>>;
 }
 
 #[async_trait::async_trait]
@@ -178,7 +289,23 @@ pub trait ConsensusModulePort: Send + Sync {
         &self,
         start_time: Option<Tai64>,
         number_of_blocks: u32,
+        interval: Option<Duration>,
     ) -> anyhow::Result<()>;
+
+    /// Overrides the timestamp of the next produced block.
+    async fn set_next_block_time(&self, time: Tai64) -> anyhow::Result<()>;
+
+    /// Pauses the block production with the `reason`.
+    async fn pause_block_production(&self, reason: PauseReason) -> anyhow::Result<()>;
+
+    /// Resumes the paused block production.
+    async fn resume_block_production(&self) -> anyhow::Result<()>;
+
+    /// Produces one block without transactions, even if the block production is paused.
+    async fn produce_heartbeat_block(&self) -> anyhow::Result<()>;
+
+    /// Returns the state of the pause if the block production is paused.
+    fn block_production_paused(&self) -> Option<Paused>;
 }
 
 /// Trait that specifies queries supported by the database.
@@ -197,16 +324,65 @@ pub trait P2pPort: Send + Sync {
     async fn all_peer_info(&self) -> anyhow::Result<Vec<PeerInfo>>;
 }
 
+/// Trait that specifies how the verified-source metadata of the contracts is stored.
+pub trait ContractVerificationPort: Send + Sync {
+    /// Stores the `metadata` of the contract, replacing the previous one.
+    fn set_contract_verification(
+        &self,
+        contract_id: &ContractId,
+        metadata: &[u8],
+    ) -> anyhow::Result<()>;
+}
+
+/// Trait that specifies how the on-disk statistics of the databases are read.
+pub trait DatabaseStatisticsPort: Send + Sync {
+    /// Returns the statistics of the columns of each database.
+    fn database_statistics(&self) -> anyhow::Result<Vec<DatabaseStatistics>>;
+}
+
+/// Trait that specifies the data from the relayer about the DA layer.
+pub trait RelayerPort: Send + Sync {
+    /// Returns the origin of the message on the DA layer,
+    /// if the relayer downloaded the message.
+    fn message_origin(&self, nonce: &Nonce) -> StorageResult<Option<MessageOrigin>>;
+}
+
 pub mod worker {
-    use super::super::storage::blocks::FuelBlockIdsToHeights;
+    use super::super::storage::blocks::{
+        BlockProducerFingerprints,
+        FuelBlockIdsToHeights,
+    };
     use crate::{
         database::{
             database_description::off_chain::OffChain,
             metadata::MetadataTable,
         },
         fuel_core_graphql_api::storage::{
+            assets::AssetsInfo,
             coins::OwnedCoins,
-            messages::OwnedMessageIds,
+            contracts::ContractsDeployments,
+            fees::{
+                BlockFees,
+                TransactionFees,
+            },
+            messages::{
+                MessageOutboxLeaves,
+                MessageOutboxMerkleData,
+                MessageOutboxMerkleMetadata,
+                OwnedMessageIds,
+            },
+            statistics::{
+                AddressesFirstActivity,
+                BlockStatistics,
+                ChainStatistics,
+            },
+            transactions::{
+                MessageSpendingTransactions,
+                PrunableReceipts,
+                SpendingTransactions,
+                TransactionVmMetrics,
+            },
+            transfers::OwnedTransferEvents,
         },
     };
     use fuel_core_services::stream::BoxStream;
@@ -220,6 +396,7 @@ pub mod worker {
         fuel_tx::{
             Address,
             Bytes32,
+            Receipt,
         },
         fuel_types::BlockHeight,
         services::{
@@ -232,9 +409,25 @@ pub mod worker {
         Send
         + Sync
         + StorageMutate<OwnedMessageIds, Error = StorageError>
+        + StorageMutate<MessageOutboxLeaves, Error = StorageError>
+        + StorageMutate<MessageOutboxMerkleData, Error = StorageError>
+        + StorageMutate<MessageOutboxMerkleMetadata, Error = StorageError>
+        + StorageMutate<BlockFees, Error = StorageError>
         + StorageMutate<OwnedCoins, Error = StorageError>
         + StorageMutate<MetadataTable<OffChain>, Error = StorageError>
         + StorageMutate<FuelBlockIdsToHeights, Error = StorageError>
+        + StorageMutate<BlockProducerFingerprints, Error = StorageError>
+        + StorageMutate<OwnedTransferEvents, Error = StorageError>
+        + StorageMutate<TransactionFees, Error = StorageError>
+        + StorageMutate<TransactionVmMetrics, Error = StorageError>
+        + StorageMutate<SpendingTransactions, Error = StorageError>
+        + StorageMutate<MessageSpendingTransactions, Error = StorageError>
+        + StorageMutate<BlockStatistics, Error = StorageError>
+        + StorageMutate<ChainStatistics, Error = StorageError>
+        + StorageMutate<AddressesFirstActivity, Error = StorageError>
+        + StorageMutate<AssetsInfo, Error = StorageError>
+        + StorageMutate<ContractsDeployments, Error = StorageError>
+        + StorageMutate<PrunableReceipts, Error = StorageError>
         + Transactional<Storage = Self>
     {
         fn record_tx_id_owner(
@@ -251,6 +444,16 @@ pub mod worker {
             status: TransactionStatus,
         ) -> StorageResult<Option<TransactionStatus>>;
 
+        fn get_tx_status(&self, id: &Bytes32)
+            -> StorageResult<Option<TransactionStatus>>;
+
+        /// Returns the transactions with the unpruned receipts of the blocks
+        /// up to the `height`, inclusive, sorted by the height.
+        fn prunable_receipts(
+            &self,
+            height: BlockHeight,
+        ) -> StorageResult<Vec<(BlockHeight, Vec<Bytes32>)>>;
+
         /// Update metadata about the total number of transactions on the chain.
         /// Returns the total count after the update.
         fn increase_tx_count(&mut self, new_txs_count: u64) -> StorageResult<u64>;
@@ -261,6 +464,16 @@ pub mod worker {
         fn block_events(&self) -> BoxStream<SharedImportResult>;
     }
 
+    #[async_trait::async_trait]
+    pub trait ReceiptsArchive: Send + Sync {
+        /// Stores the receipts of the transactions of the block before they are pruned.
+        async fn archive(
+            &self,
+            height: BlockHeight,
+            receipts: &[(Bytes32, Vec<Receipt>)],
+        ) -> anyhow::Result<()>;
+    }
+
     pub trait TxPool: Send + Sync {
         /// Sends the complete status of the transaction.
         fn send_complete(
@@ -0,0 +1,54 @@
+//! The pruning of the receipts of the transactions from the old blocks.
+//!
+//! The receipts are the biggest part of the off-chain database on the busy networks.
+//! When the pruning is enabled, the off-chain worker removes the receipts from the
+//! statuses of the transactions once the block is older than the retention window.
+//! The `MessageOut` receipts are kept, because the proofs of the messages are built from them.
+//! The pruned receipts can be streamed to the external archive before the removal.
+//! If the archive fails, the receipts are kept and the pruning is retried with the next block.
+
+use std::{
+    fmt,
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// The configuration of the pruning of the receipts.
+#[derive(Clone, Debug)]
+pub struct ReceiptsPruningConfig {
+    /// The number of the latest blocks keeping the receipts of their transactions.
+    pub retention: u32,
+    /// Receives the receipts before they are pruned if set.
+    pub archive: Option<ReceiptsArchiveSink>,
+}
+
+/// The external store of the pruned receipts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ReceiptsArchiveSink {
+    /// The local directory with one JSON file per block.
+    Directory(PathBuf),
+    /// The HTTP endpoint receiving one JSON document per block, e.g.
+    /// the gateway of the object storage.
+    Url(String),
+}
+
+impl FromStr for ReceiptsArchiveSink {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("http://") || s.starts_with("https://") {
+            Ok(Self::Url(s.trim_end_matches('/').to_string()))
+        } else {
+            Ok(Self::Directory(PathBuf::from(s)))
+        }
+    }
+}
+
+impl fmt::Display for ReceiptsArchiveSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Directory(path) => write!(f, "{}", path.display()),
+            Self::Url(url) => write!(f, "{url}"),
+        }
+    }
+}
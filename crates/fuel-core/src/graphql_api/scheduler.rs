@@ -0,0 +1,292 @@
+//! The admission control of the GraphQL requests.
+//!
+//! The GraphQL requests and the execution of the blocks contend for the database and CPU.
+//! The scheduler limits the number of the requests executed at the same time and queues
+//! the rest. While a block is executed, the limit is lowered to keep the capacity
+//! for the import and the production of the blocks. When the queue is full, or the request
+//! waits in the queue for too long, it is rejected with `503 Service Unavailable`.
+//!
+//! Subscriptions are long-lived and are not limited by the scheduler.
+
+use axum::{
+    http::{
+        header::RETRY_AFTER,
+        HeaderValue,
+        StatusCode,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+};
+use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use std::{
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::Notify;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestSchedulerConfig {
+    /// The maximum number of the requests executed at the same time.
+    pub max_concurrent_requests: usize,
+    /// The maximum number of the requests executed at the same time while a block
+    /// is executed. The new requests wait in the queue until the execution ends.
+    pub max_concurrent_requests_while_executing: usize,
+    /// The maximum number of the requests waiting in the queue.
+    /// The requests above the limit are rejected.
+    pub max_queued_requests: usize,
+    /// The maximum time the request waits in the queue before it is rejected.
+    pub queue_timeout: Duration,
+}
+
+impl Default for RequestSchedulerConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_requests: 64,
+            max_concurrent_requests_while_executing: 16,
+            max_queued_requests: 1024,
+            queue_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Default, Debug)]
+struct State {
+    /// The number of the requests being executed.
+    in_flight: usize,
+    /// The number of the requests waiting in the queue.
+    queued: usize,
+    /// The number of the blocks being executed.
+    executing_blocks: usize,
+}
+
+#[derive(Debug)]
+struct Inner {
+    config: RequestSchedulerConfig,
+    state: Mutex<State>,
+    released: Notify,
+}
+
+/// Shared by the GraphQL service, which admits the requests, and the executor,
+/// which reports the execution of the blocks.
+#[derive(Clone, Debug)]
+pub struct RequestScheduler {
+    inner: Arc<Inner>,
+}
+
+/// The request was rejected because the node is overloaded.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Overloaded;
+
+impl IntoResponse for Overloaded {
+    fn into_response(self) -> Response {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(RETRY_AFTER, HeaderValue::from_static("1"))],
+            "The node is overloaded, try again later",
+        )
+            .into_response()
+    }
+}
+
+/// Allows the request to be executed. The next request is admitted when it is dropped.
+#[derive(Debug)]
+pub struct RequestPermit {
+    inner: Arc<Inner>,
+}
+
+/// Lowers the limit of the concurrent requests while the block is executed.
+#[derive(Debug)]
+pub struct ExecutionGuard {
+    inner: Arc<Inner>,
+}
+
+impl RequestScheduler {
+    pub fn new(config: RequestSchedulerConfig) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                config,
+                state: Mutex::new(State::default()),
+                released: Notify::new(),
+            }),
+        }
+    }
+
+    /// Waits until the request can be executed.
+    pub async fn acquire(&self) -> Result<RequestPermit, Overloaded> {
+        {
+            let mut state = self.inner.state.lock().expect("poisoned");
+            if state.queued == 0 && self.inner.has_capacity(&state) {
+                state.in_flight = state.in_flight.saturating_add(1);
+                return Ok(self.permit())
+            }
+            if state.queued >= self.inner.config.max_queued_requests {
+                graphql_metrics().observe_shed_request();
+                return Err(Overloaded)
+            }
+            state.queued = state.queued.saturating_add(1);
+            graphql_metrics().observe_queued_requests(state.queued);
+        }
+
+        let admitted = tokio::time::timeout(self.inner.config.queue_timeout, async {
+            loop {
+                let released = self.inner.released.notified();
+                {
+                    let mut state = self.inner.state.lock().expect("poisoned");
+                    if self.inner.has_capacity(&state) {
+                        state.in_flight = state.in_flight.saturating_add(1);
+                        break
+                    }
+                }
+                released.await;
+            }
+        })
+        .await;
+
+        let mut state = self.inner.state.lock().expect("poisoned");
+        state.queued = state.queued.saturating_sub(1);
+        graphql_metrics().observe_queued_requests(state.queued);
+        match admitted {
+            Ok(()) => Ok(self.permit()),
+            Err(_) => {
+                graphql_metrics().observe_shed_request();
+                Err(Overloaded)
+            }
+        }
+    }
+
+    /// Marks the execution of the block until the returned guard is dropped.
+    pub fn execution(&self) -> ExecutionGuard {
+        let mut state = self.inner.state.lock().expect("poisoned");
+        state.executing_blocks = state.executing_blocks.saturating_add(1);
+        ExecutionGuard {
+            inner: self.inner.clone(),
+        }
+    }
+
+    fn permit(&self) -> RequestPermit {
+        RequestPermit {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl Default for RequestScheduler {
+    fn default() -> Self {
+        Self::new(Default::default())
+    }
+}
+
+impl Inner {
+    fn has_capacity(&self, state: &State) -> bool {
+        let limit = if state.executing_blocks > 0 {
+            self.config.max_concurrent_requests_while_executing
+        } else {
+            self.config.max_concurrent_requests
+        };
+        state.in_flight < limit
+    }
+}
+
+impl Drop for RequestPermit {
+    fn drop(&mut self) {
+        let mut state = self.inner.state.lock().expect("poisoned");
+        state.in_flight = state.in_flight.saturating_sub(1);
+        self.inner.released.notify_waiters();
+    }
+}
+
+impl Drop for ExecutionGuard {
+    fn drop(&mut self) {
+        let mut state = self.inner.state.lock().expect("poisoned");
+        state.executing_blocks = state.executing_blocks.saturating_sub(1);
+        self.inner.released.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scheduler(
+        max_concurrent_requests: usize,
+        max_queued_requests: usize,
+    ) -> RequestScheduler {
+        RequestScheduler::new(RequestSchedulerConfig {
+            max_concurrent_requests,
+            max_concurrent_requests_while_executing: 0,
+            max_queued_requests,
+            queue_timeout: Duration::from_secs(10),
+        })
+    }
+
+    #[tokio::test]
+    async fn queued_request_is_admitted_after_the_permit_is_released() {
+        // Given
+        let scheduler = scheduler(1, 1);
+        let permit = scheduler.acquire().await.unwrap();
+        let queued = tokio::spawn({
+            let scheduler = scheduler.clone();
+            async move { scheduler.acquire().await.map(|_| ()) }
+        });
+        tokio::task::yield_now().await;
+
+        // When
+        drop(permit);
+
+        // Then
+        assert_eq!(queued.await.unwrap(), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn request_is_rejected_when_the_queue_is_full() {
+        // Given
+        let scheduler = scheduler(0, 0);
+
+        // When
+        let result = scheduler.acquire().await;
+
+        // Then
+        assert_eq!(result.unwrap_err(), Overloaded);
+    }
+
+    #[tokio::test]
+    async fn request_is_rejected_after_the_queue_timeout() {
+        // Given
+        let scheduler = RequestScheduler::new(RequestSchedulerConfig {
+            max_concurrent_requests: 0,
+            max_concurrent_requests_while_executing: 0,
+            max_queued_requests: 1,
+            queue_timeout: Duration::from_millis(10),
+        });
+
+        // When
+        let result = scheduler.acquire().await;
+
+        // Then
+        assert_eq!(result.unwrap_err(), Overloaded);
+    }
+
+    #[tokio::test]
+    async fn requests_wait_for_the_end_of_the_block_execution() {
+        // Given
+        let scheduler = scheduler(1, 1);
+        let execution = scheduler.execution();
+        let queued = tokio::spawn({
+            let scheduler = scheduler.clone();
+            async move { scheduler.acquire().await.map(|_| ()) }
+        });
+        tokio::task::yield_now().await;
+        assert!(!queued.is_finished());
+
+        // When
+        drop(execution);
+
+        // Then
+        assert_eq!(queued.await.unwrap(), Ok(()));
+    }
+}
@@ -1,9 +1,15 @@
 use fuel_core_storage::kv_store::StorageColumn;
 
+pub mod assets;
 pub mod blocks;
 pub mod coins;
+pub mod contracts;
+pub mod equivocations;
+pub mod fees;
 pub mod messages;
+pub mod statistics;
 pub mod transactions;
+pub mod transfers;
 
 /// GraphQL database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
 #[repr(u32)]
@@ -33,6 +39,42 @@ pub enum Column {
     Statistic = 5,
     /// See [`blocks::FuelBlockIdsToHeights`]
     FuelBlockIdsToHeights = 6,
+    /// See [`fees::BlockFees`]
+    BlockFees = 7,
+    /// See [`transfers::OwnedTransferEvents`]
+    OwnedTransferEvents = 8,
+    /// See [`fees::TransactionFees`]
+    TransactionFees = 9,
+    /// See [`assets::AssetsInfo`]
+    AssetsInfo = 10,
+    /// See [`contracts::ContractsDeployments`]
+    ContractsDeployments = 11,
+    /// See [`contracts::ContractsVerifications`]
+    ContractsVerifications = 12,
+    /// See [`equivocations::EquivocationProofs`]
+    EquivocationProofs = 13,
+    /// See [`messages::MessageOutboxLeaves`]
+    MessageOutboxLeaves = 14,
+    /// See [`messages::MessageOutboxMerkleData`]
+    MessageOutboxMerkleData = 15,
+    /// See [`messages::MessageOutboxMerkleMetadata`]
+    MessageOutboxMerkleMetadata = 16,
+    /// See [`transactions::PrunableReceipts`]
+    PrunableReceipts = 17,
+    /// See [`blocks::BlockProducerFingerprints`]
+    BlockProducerFingerprints = 18,
+    /// See [`transactions::TransactionVmMetrics`]
+    TransactionVmMetrics = 19,
+    /// See [`transactions::SpendingTransactions`]
+    SpendingTransactions = 20,
+    /// See [`transactions::MessageSpendingTransactions`]
+    MessageSpendingTransactions = 21,
+    /// See [`statistics::BlockStatistics`]
+    BlockStatistics = 22,
+    /// See [`statistics::ChainStatistics`]
+    ChainStatistics = 23,
+    /// See [`statistics::AddressesFirstActivity`]
+    AddressesFirstActivity = 24,
 }
 
 impl Column {
@@ -0,0 +1,85 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        AssetId,
+        Bytes32,
+        ContractId,
+    },
+    fuel_types::Word,
+};
+
+/// The table of the assets minted by the contracts, with the minted and burned totals.
+pub struct AssetsInfo;
+
+impl Mappable for AssetsInfo {
+    type Key = AssetId;
+    type OwnedKey = Self::Key;
+    type Value = AssetDetails;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for AssetsInfo {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::AssetsInfo
+    }
+}
+
+/// The information about the asset minted by the contract.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct AssetDetails {
+    /// The contract that minted the asset.
+    pub contract_id: ContractId,
+    /// The sub identifier of the asset within the contract.
+    pub sub_id: Bytes32,
+    /// The total amount minted by the contract.
+    pub minted: u128,
+    /// The total amount burned by the contract.
+    pub burned: u128,
+}
+
+impl AssetDetails {
+    /// Creates the details of the asset without any minted or burned amount.
+    pub fn new(contract_id: ContractId, sub_id: Bytes32) -> Self {
+        Self {
+            contract_id,
+            sub_id,
+            minted: 0,
+            burned: 0,
+        }
+    }
+
+    /// Records the `amount` minted by the contract.
+    pub fn mint(&mut self, amount: Word) {
+        self.minted = self.minted.saturating_add(amount as u128);
+    }
+
+    /// Records the `amount` burned by the contract.
+    pub fn burn(&mut self, amount: Word) {
+        self.burned = self.burned.saturating_add(amount as u128);
+    }
+
+    /// The amount of the asset in circulation.
+    pub fn total_supply(&self) -> u128 {
+        self.minted.saturating_sub(self.burned)
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    AssetsInfo,
+    <AssetsInfo as Mappable>::Key::default(),
+    <AssetsInfo as Mappable>::Value::default()
+);
@@ -1,6 +1,8 @@
+use crate::fingerprint::NodeFingerprint;
 use fuel_core_storage::{
     blueprint::plain::Plain,
     codec::{
+        postcard::Postcard,
         primitive::Primitive,
         raw::Raw,
     },
@@ -34,9 +36,36 @@ impl TableWithBlueprint for FuelBlockIdsToHeights {
     }
 }
 
+/// The table of the fingerprints of the binaries that produced the blocks.
+/// Only the blocks produced by this node are present.
+pub struct BlockProducerFingerprints;
+
+impl Mappable for BlockProducerFingerprints {
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    type Value = NodeFingerprint;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for BlockProducerFingerprints {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::BlockProducerFingerprints
+    }
+}
+
 #[cfg(test)]
 fuel_core_storage::basic_storage_tests!(
     FuelBlockIdsToHeights,
     <FuelBlockIdsToHeights as Mappable>::Key::default(),
     <FuelBlockIdsToHeights as Mappable>::Value::default()
 );
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    BlockProducerFingerprints,
+    <BlockProducerFingerprints as Mappable>::Key::default(),
+    NodeFingerprint::current()
+);
@@ -0,0 +1,91 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Address,
+        Bytes32,
+        ContractId,
+        Salt,
+        TxId,
+    },
+    fuel_types::BlockHeight,
+};
+
+/// The table of the `Create` transactions that deployed the contracts.
+pub struct ContractsDeployments;
+
+impl Mappable for ContractsDeployments {
+    type Key = ContractId;
+    type OwnedKey = Self::Key;
+    type Value = ContractDeployment;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ContractsDeployments {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractsDeployments
+    }
+}
+
+/// The deployment of the contract.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ContractDeployment {
+    /// The `Create` transaction that deployed the contract.
+    pub tx_id: TxId,
+    /// The height of the block with the deployment transaction.
+    pub block_height: BlockHeight,
+    /// The SHA-256 hash of the contract bytecode.
+    pub bytecode_hash: Bytes32,
+    /// The salt used to derive the contract id.
+    pub salt: Salt,
+    /// The owner of the first coin or message input of the `Create` transaction,
+    /// if the transaction has one.
+    pub deployer: Option<Address>,
+}
+
+/// The table of the verified-source metadata of the contracts,
+/// uploaded by the node operator. The node doesn't interpret the metadata.
+pub struct ContractsVerifications;
+
+impl Mappable for ContractsVerifications {
+    type Key = ContractId;
+    type OwnedKey = Self::Key;
+    type Value = [u8];
+    type OwnedValue = Vec<u8>;
+}
+
+impl TableWithBlueprint for ContractsVerifications {
+    type Blueprint = Plain<Raw, Raw>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ContractsVerifications
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    ContractsDeployments,
+    <ContractsDeployments as Mappable>::Key::default(),
+    <ContractsDeployments as Mappable>::Value::default()
+);
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    ContractsVerifications,
+    <ContractsVerifications as Mappable>::Key::default(),
+    vec![32u8],
+    <ContractsVerifications as Mappable>::OwnedValue::from(vec![32u8])
+);
@@ -0,0 +1,40 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    blockchain::consensus::poa::EquivocationProof,
+    fuel_types::BlockHeight,
+};
+
+/// The table of the proofs that the authority sealed two different blocks at the height.
+/// Only the first proof found at the height is stored.
+pub struct EquivocationProofs;
+
+impl Mappable for EquivocationProofs {
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    type Value = EquivocationProof;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for EquivocationProofs {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::EquivocationProofs
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    EquivocationProofs,
+    <EquivocationProofs as Mappable>::Key::default(),
+    <EquivocationProofs as Mappable>::Value::default()
+);
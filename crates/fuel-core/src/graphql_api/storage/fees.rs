@@ -0,0 +1,90 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    fuel_tx::TxId,
+    fuel_types::{
+        BlockHeight,
+        Word,
+    },
+    services::executor::FeeBreakdown,
+};
+
+/// The table of the fees collected in each block.
+pub struct BlockFees;
+
+impl Mappable for BlockFees {
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    type Value = BlockFeeReport;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for BlockFees {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::BlockFees
+    }
+}
+
+/// The fees paid by the transactions of the block.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct BlockFeeReport {
+    /// The sum of the tips paid to the block producer.
+    pub tips: Word,
+    /// The sum of the fees charged for the used gas, without tips.
+    pub base_fees: Word,
+    /// The amount minted to the coinbase recipient by the `Mint` transaction.
+    pub coinbase: Word,
+    /// The part of the fees that is not minted to anyone,
+    /// because the block producer has no coinbase recipient.
+    pub burned: Word,
+    /// The sum of the unused fees returned to the change outputs.
+    pub refunds: Word,
+}
+
+/// The table of the fee breakdown of each executed transaction.
+/// It is separate from the `TransactionStatuses` to keep
+/// the encoding of the already stored statuses unchanged.
+pub struct TransactionFees;
+
+impl Mappable for TransactionFees {
+    type Key = TxId;
+    type OwnedKey = Self::Key;
+    type Value = FeeBreakdown;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for TransactionFees {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TransactionFees
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    BlockFees,
+    <BlockFees as Mappable>::Key::default(),
+    <BlockFees as Mappable>::Value::default()
+);
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    TransactionFees,
+    <TransactionFees as Mappable>::Key::default(),
+    <TransactionFees as Mappable>::Value::default()
+);
@@ -3,15 +3,23 @@ use fuel_core_storage::{
     codec::{
         manual::Manual,
         postcard::Postcard,
+        primitive::Primitive,
+        raw::Raw,
         Decode,
         Encode,
     },
     structured_storage::TableWithBlueprint,
+    tables::merkle::DenseMerkleMetadata,
     Mappable,
 };
-use fuel_core_types::fuel_types::{
-    Address,
-    Nonce,
+use fuel_core_types::{
+    fuel_merkle::binary,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        MessageId,
+        Nonce,
+    },
 };
 use rand::{
     distributions::{
@@ -20,7 +28,10 @@ use rand::{
     },
     Rng,
 };
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    mem::size_of,
+};
 
 fuel_core_types::fuel_vm::double_key!(OwnedMessageKey, Address, address, Nonce, nonce);
 
@@ -68,6 +79,145 @@ impl TableWithBlueprint for OwnedMessageIds {
     }
 }
 
+/// The table of the positions of the sent messages in the message outbox Merkle trees
+/// of the blocks.
+pub struct MessageOutboxLeaves;
+
+impl Mappable for MessageOutboxLeaves {
+    type Key = MessageId;
+    type OwnedKey = Self::Key;
+    type Value = MessageOutboxLeaf;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for MessageOutboxLeaves {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::MessageOutboxLeaves
+    }
+}
+
+/// The position of the message in the message outbox Merkle tree of the block.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct MessageOutboxLeaf {
+    /// The height of the block that sent the message.
+    pub block_height: BlockHeight,
+    /// The index of the leaf.
+    pub index: u64,
+}
+
+/// The table of the nodes of the message outbox Merkle trees of the blocks.
+/// Each block without messages doesn't have the tree.
+pub struct MessageOutboxMerkleData;
+
+impl Mappable for MessageOutboxMerkleData {
+    type Key = MessageOutboxNodeKey;
+    type OwnedKey = Self::Key;
+    type Value = binary::Primitive;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for MessageOutboxMerkleData {
+    type Blueprint = Plain<Manual<MessageOutboxNodeKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::MessageOutboxMerkleData
+    }
+}
+
+/// The metadata table for [`MessageOutboxMerkleData`] table.
+pub struct MessageOutboxMerkleMetadata;
+
+impl Mappable for MessageOutboxMerkleMetadata {
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    type Value = DenseMerkleMetadata;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for MessageOutboxMerkleMetadata {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::MessageOutboxMerkleMetadata
+    }
+}
+
+const NODE_KEY_SIZE: usize = size_of::<BlockHeight>() + size_of::<u64>();
+
+/// The key of the node of the message outbox Merkle tree of the block.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MessageOutboxNodeKey {
+    pub block_height: BlockHeight,
+    /// The key of the node within the tree of the block.
+    pub node: u64,
+}
+
+impl Distribution<MessageOutboxNodeKey> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> MessageOutboxNodeKey {
+        MessageOutboxNodeKey {
+            block_height: rng.gen::<u32>().into(),
+            node: rng.gen(),
+        }
+    }
+}
+
+impl Encode<MessageOutboxNodeKey> for Manual<MessageOutboxNodeKey> {
+    type Encoder<'a> = [u8; NODE_KEY_SIZE];
+
+    fn encode(t: &MessageOutboxNodeKey) -> Self::Encoder<'_> {
+        // block_height + node to keep the nodes of one tree together
+        let mut bytes = [0u8; NODE_KEY_SIZE];
+        let (height, node) = bytes.split_at_mut(size_of::<BlockHeight>());
+        height.copy_from_slice(t.block_height.to_bytes().as_ref());
+        node.copy_from_slice(t.node.to_be_bytes().as_ref());
+        bytes
+    }
+}
+
+impl Decode<MessageOutboxNodeKey> for Manual<MessageOutboxNodeKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<MessageOutboxNodeKey> {
+        let bytes: [u8; NODE_KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let (height, node) = bytes.split_at(size_of::<BlockHeight>());
+        let height: [u8; 4] = height.try_into().expect("It's an array of 4 bytes");
+        let node: [u8; 8] = node.try_into().expect("It's an array of 8 bytes");
+
+        Ok(MessageOutboxNodeKey {
+            block_height: u32::from_be_bytes(height).into(),
+            node: u64::from_be_bytes(node),
+        })
+    }
+}
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    MessageOutboxLeaves,
+    <MessageOutboxLeaves as Mappable>::Key::default(),
+    <MessageOutboxLeaves as Mappable>::Value::default()
+);
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    MessageOutboxMerkleData,
+    <MessageOutboxMerkleData as Mappable>::Key::default(),
+    <MessageOutboxMerkleData as Mappable>::Value::default()
+);
+
+#[cfg(test)]
+fuel_core_storage::basic_storage_tests!(
+    MessageOutboxMerkleMetadata,
+    <MessageOutboxMerkleMetadata as Mappable>::Key::default(),
+    <MessageOutboxMerkleMetadata as Mappable>::Value::default()
+);
+
 #[cfg(test)]
 fuel_core_storage::basic_storage_tests!(
     OwnedMessageIds,
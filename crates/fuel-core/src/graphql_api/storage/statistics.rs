@@ -0,0 +1,188 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        postcard::Postcard,
+        primitive::Primitive,
+        raw::Raw,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    fuel_tx::Address,
+    fuel_types::{
+        BlockHeight,
+        Word,
+    },
+};
+
+/// The table of the activity in each block.
+pub struct BlockStatistics;
+
+impl Mappable for BlockStatistics {
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    type Value = BlockActivity;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for BlockStatistics {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::BlockStatistics
+    }
+}
+
+/// The activity of the transactions of the block.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct BlockActivity {
+    /// The number of transactions, including the `Mint` transaction.
+    pub tx_count: u64,
+    /// The gas used by the transactions.
+    pub gas_used: Word,
+    /// The number of distinct owners of the coins spent or created by the block.
+    pub active_addresses: u64,
+    /// The fees paid by the transactions, including the tips.
+    pub fees_paid: Word,
+    /// The part of the fees that is not minted to anyone.
+    pub fees_burned: Word,
+}
+
+/// The table of the totals of the chain up to and including each block.
+/// The totals of the range of blocks are the difference between the totals
+/// at its ends, so they don't require the iteration over the blocks.
+pub struct ChainStatistics;
+
+impl Mappable for ChainStatistics {
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    type Value = ChainTotals;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for ChainStatistics {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::ChainStatistics
+    }
+}
+
+/// The totals of the chain since the first indexed block.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct ChainTotals {
+    pub tx_count: u64,
+    pub gas_used: Word,
+    /// The number of distinct addresses that have ever been active.
+    pub addresses: u64,
+    pub fees_paid: Word,
+    pub fees_burned: Word,
+}
+
+impl ChainTotals {
+    /// Adds the activity of the next block to the totals.
+    pub fn add(&self, block: &BlockActivity, new_addresses: u64) -> Self {
+        Self {
+            tx_count: self.tx_count.saturating_add(block.tx_count),
+            gas_used: self.gas_used.saturating_add(block.gas_used),
+            addresses: self.addresses.saturating_add(new_addresses),
+            fees_paid: self.fees_paid.saturating_add(block.fees_paid),
+            fees_burned: self.fees_burned.saturating_add(block.fees_burned),
+        }
+    }
+
+    /// Returns the totals of the blocks after the `earlier` totals.
+    pub fn since(&self, earlier: &Self) -> Self {
+        Self {
+            tx_count: self.tx_count.saturating_sub(earlier.tx_count),
+            gas_used: self.gas_used.saturating_sub(earlier.gas_used),
+            addresses: self.addresses.saturating_sub(earlier.addresses),
+            fees_paid: self.fees_paid.saturating_sub(earlier.fees_paid),
+            fees_burned: self.fees_burned.saturating_sub(earlier.fees_burned),
+        }
+    }
+}
+
+/// The table of the height of the block where the address was active for the first time.
+pub struct AddressesFirstActivity;
+
+impl Mappable for AddressesFirstActivity {
+    type Key = Address;
+    type OwnedKey = Self::Key;
+    type Value = BlockHeight;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for AddressesFirstActivity {
+    type Blueprint = Plain<Raw, Primitive<4>>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::AddressesFirstActivity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fuel_core_storage::basic_storage_tests!(
+        BlockStatistics,
+        <BlockStatistics as Mappable>::Key::default(),
+        <BlockStatistics as Mappable>::Value::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        ChainStatistics,
+        <ChainStatistics as Mappable>::Key::default(),
+        <ChainStatistics as Mappable>::Value::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        AddressesFirstActivity,
+        <AddressesFirstActivity as Mappable>::Key::default(),
+        <AddressesFirstActivity as Mappable>::Value::default()
+    );
+
+    #[test]
+    fn totals_of_the_range_are_the_difference_of_the_totals() {
+        // Given
+        let first = BlockActivity {
+            tx_count: 2,
+            gas_used: 100,
+            active_addresses: 2,
+            fees_paid: 10,
+            fees_burned: 10,
+        };
+        let second = BlockActivity {
+            tx_count: 3,
+            gas_used: 50,
+            active_addresses: 3,
+            fees_paid: 7,
+            fees_burned: 0,
+        };
+        let before = ChainTotals::default().add(&first, 2);
+
+        // When
+        let after = before.add(&second, 1);
+
+        // Then
+        assert_eq!(
+            after.since(&before),
+            ChainTotals {
+                tx_count: 3,
+                gas_used: 50,
+                addresses: 1,
+                fees_paid: 7,
+                fees_burned: 0,
+            }
+        );
+    }
+}
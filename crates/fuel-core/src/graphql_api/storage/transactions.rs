@@ -3,6 +3,7 @@ use fuel_core_storage::{
     codec::{
         manual::Manual,
         postcard::Postcard,
+        primitive::Primitive,
         raw::Raw,
         Decode,
         Encode,
@@ -14,9 +15,17 @@ use fuel_core_types::{
     fuel_tx::{
         Address,
         Bytes32,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Nonce,
+    },
+    services::{
+        executor::VmMetrics,
+        txpool::TransactionStatus,
     },
-    fuel_types::BlockHeight,
-    services::txpool::TransactionStatus,
 };
 use std::{
     array::TryFromSliceError,
@@ -61,6 +70,84 @@ impl TableWithBlueprint for TransactionStatuses {
     }
 }
 
+/// The table of the transactions with the receipts that are not pruned yet,
+/// grouped by the block. It is filled only when the pruning of the receipts is enabled.
+pub struct PrunableReceipts;
+
+impl Mappable for PrunableReceipts {
+    type Key = BlockHeight;
+    type OwnedKey = Self::Key;
+    type Value = Vec<Bytes32>;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for PrunableReceipts {
+    type Blueprint = Plain<Primitive<4>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::PrunableReceipts
+    }
+}
+
+/// The table of the resources used by the VM to execute each transaction.
+/// It is filled only when the metering of the VM is enabled.
+pub struct TransactionVmMetrics;
+
+impl Mappable for TransactionVmMetrics {
+    type Key = Bytes32;
+    type OwnedKey = Self::Key;
+    type Value = VmMetrics;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for TransactionVmMetrics {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::TransactionVmMetrics
+    }
+}
+
+/// The table of the transactions that spent the coins, by the id of the spent coin.
+pub struct SpendingTransactions;
+
+impl Mappable for SpendingTransactions {
+    type Key = UtxoId;
+    type OwnedKey = Self::Key;
+    type Value = TxId;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for SpendingTransactions {
+    type Blueprint = Plain<Primitive<{ TxId::LEN + 1 }>, Raw>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::SpendingTransactions
+    }
+}
+
+/// The table of the transactions that consumed the messages, by the nonce of the message.
+pub struct MessageSpendingTransactions;
+
+impl Mappable for MessageSpendingTransactions {
+    type Key = Nonce;
+    type OwnedKey = Self::Key;
+    type Value = TxId;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for MessageSpendingTransactions {
+    type Blueprint = Plain<Raw, Raw>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::MessageSpendingTransactions
+    }
+}
+
 const TX_INDEX_SIZE: usize = size_of::<TransactionIndex>();
 const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
 const INDEX_SIZE: usize = Address::LEN + BLOCK_HEIGHT + TX_INDEX_SIZE;
@@ -209,4 +296,28 @@ mod test {
             time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
         }
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        PrunableReceipts,
+        <PrunableReceipts as Mappable>::Key::default(),
+        vec![<Bytes32>::default()]
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        TransactionVmMetrics,
+        <TransactionVmMetrics as Mappable>::Key::default(),
+        <TransactionVmMetrics as Mappable>::Value::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        SpendingTransactions,
+        <SpendingTransactions as Mappable>::Key::default(),
+        <SpendingTransactions as Mappable>::Value::default()
+    );
+
+    fuel_core_storage::basic_storage_tests!(
+        MessageSpendingTransactions,
+        <MessageSpendingTransactions as Mappable>::Key::default(),
+        <MessageSpendingTransactions as Mappable>::Value::default()
+    );
 }
@@ -0,0 +1,170 @@
+use fuel_core_storage::{
+    blueprint::plain::Plain,
+    codec::{
+        manual::Manual,
+        postcard::Postcard,
+        Decode,
+        Encode,
+    },
+    structured_storage::TableWithBlueprint,
+    Mappable,
+};
+use fuel_core_types::{
+    fuel_tx::TxId,
+    fuel_types::{
+        Address,
+        AssetId,
+        BlockHeight,
+        Word,
+    },
+};
+use std::mem::size_of;
+
+/// The table of the normalized transfer events per owner.
+/// It allows iteration over all transfers of the owner sorted by the block height.
+pub struct OwnedTransferEvents;
+
+impl Mappable for OwnedTransferEvents {
+    type Key = OwnedTransferEventKey;
+    type OwnedKey = Self::Key;
+    type Value = TransferEvent;
+    type OwnedValue = Self::Value;
+}
+
+impl TableWithBlueprint for OwnedTransferEvents {
+    type Blueprint = Plain<Manual<OwnedTransferEventKey>, Postcard>;
+    type Column = super::Column;
+
+    fn column() -> Self::Column {
+        Self::Column::OwnedTransferEvents
+    }
+}
+
+/// The index of the transfer event among all transfer events of the block.
+pub type TransferEventIndex = u32;
+
+const BLOCK_HEIGHT: usize = size_of::<BlockHeight>();
+const EVENT_INDEX_SIZE: usize = size_of::<TransferEventIndex>();
+const KEY_SIZE: usize = Address::LEN + BLOCK_HEIGHT + EVENT_INDEX_SIZE;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct OwnedTransferEventKey {
+    pub owner: Address,
+    pub block_height: BlockHeight,
+    pub event_idx: TransferEventIndex,
+}
+
+impl OwnedTransferEventKey {
+    pub fn new(
+        owner: Address,
+        block_height: BlockHeight,
+        event_idx: TransferEventIndex,
+    ) -> Self {
+        Self {
+            owner,
+            block_height,
+            event_idx,
+        }
+    }
+}
+
+impl Encode<OwnedTransferEventKey> for Manual<OwnedTransferEventKey> {
+    type Encoder<'a> = [u8; KEY_SIZE];
+
+    fn encode(t: &OwnedTransferEventKey) -> Self::Encoder<'_> {
+        // owner + block_height + event_idx to sort the events of the owner by the block height
+        let mut bytes = [0u8; KEY_SIZE];
+        bytes[..Address::LEN].copy_from_slice(t.owner.as_ref());
+        bytes[Address::LEN..Address::LEN + BLOCK_HEIGHT]
+            .copy_from_slice(t.block_height.to_bytes().as_ref());
+        bytes[Address::LEN + BLOCK_HEIGHT..]
+            .copy_from_slice(t.event_idx.to_be_bytes().as_ref());
+        bytes
+    }
+}
+
+impl Decode<OwnedTransferEventKey> for Manual<OwnedTransferEventKey> {
+    fn decode(bytes: &[u8]) -> anyhow::Result<OwnedTransferEventKey> {
+        let bytes: [u8; KEY_SIZE] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("Unable to decode bytes"))?;
+        let owner: [u8; Address::LEN] = bytes[..Address::LEN]
+            .try_into()
+            .expect("It's an array of 32 bytes");
+        let block_height: [u8; BLOCK_HEIGHT] = bytes
+            [Address::LEN..Address::LEN + BLOCK_HEIGHT]
+            .try_into()
+            .expect("It's an array of 4 bytes");
+        let event_idx: [u8; EVENT_INDEX_SIZE] = bytes[Address::LEN + BLOCK_HEIGHT..]
+            .try_into()
+            .expect("It's an array of 4 bytes");
+
+        Ok(OwnedTransferEventKey {
+            owner: owner.into(),
+            block_height: u32::from_be_bytes(block_height).into(),
+            event_idx: u32::from_be_bytes(event_idx),
+        })
+    }
+}
+
+/// The direction of the funds for the owner.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub enum TransferDirection {
+    /// The owner received the funds.
+    #[default]
+    Incoming,
+    /// The owner spent the funds.
+    Outgoing,
+}
+
+/// The origin of the transfer event.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub enum TransferSource {
+    /// The coin was created or spent.
+    #[default]
+    Coin,
+    /// The message from the DA layer was imported or spent.
+    Message,
+    /// The `MessageOut` receipt withdrew the funds to the DA layer.
+    /// The withdrawn funds are already reflected by the `Coin` events of the transaction.
+    MessageOut,
+}
+
+/// The transfer of the funds derived from the coins, messages and receipts.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+pub struct TransferEvent {
+    pub asset_id: AssetId,
+    pub amount: Word,
+    pub direction: TransferDirection,
+    pub source: TransferSource,
+    /// The transaction that moved the funds.
+    /// It is `None` for the messages imported from the DA layer.
+    pub tx_id: Option<TxId>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn generate_key(rng: &mut impl rand::Rng) -> <OwnedTransferEvents as Mappable>::Key {
+        OwnedTransferEventKey::new(
+            Address::new(rng.gen()),
+            rng.gen::<u32>().into(),
+            rng.gen(),
+        )
+    }
+
+    fuel_core_storage::basic_storage_tests!(
+        OwnedTransferEvents,
+        OwnedTransferEventKey::new(Address::new([1; 32]), 1u32.into(), 1),
+        <OwnedTransferEvents as Mappable>::Value::default(),
+        <OwnedTransferEvents as Mappable>::Value::default(),
+        generate_key
+    );
+}
@@ -0,0 +1,150 @@
+use async_graphql::{
+    extensions::{
+        Extension,
+        ExtensionContext,
+        ExtensionFactory,
+        NextRequest,
+    },
+    Response,
+    Value,
+};
+use axum::{
+    body::Body,
+    http::{
+        HeaderMap,
+        Request,
+    },
+};
+use opentelemetry::{
+    global,
+    propagation::Extractor,
+    trace::TraceContextExt,
+};
+use std::sync::Arc;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// The key of the response extension that contains the trace id of the request.
+pub(crate) const TRACE_ID_EXTENSION: &str = "traceId";
+
+/// Creates the span of the HTTP request. If the request carries the trace context
+/// of the caller (for example, the `traceparent` header), the span continues that trace.
+pub(crate) fn request_span(request: &Request<Body>) -> Span {
+    let span = tracing::info_span!(
+        "request",
+        method = %request.method(),
+        uri = %request.uri(),
+        version = ?request.version(),
+    );
+    let parent = global::get_text_map_propagator(|propagator| {
+        propagator.extract(&HeaderExtractor(request.headers()))
+    });
+    span.set_parent(parent);
+    span
+}
+
+/// Returns the trace id of the span, if the span is exported to OpenTelemetry.
+pub(crate) fn trace_id(span: &Span) -> Option<String> {
+    let context = span.context();
+    let span_context = context.span().span_context().clone();
+    span_context
+        .is_valid()
+        .then(|| span_context.trace_id().to_string())
+}
+
+/// The extension that adds the trace id of the request to the response extensions,
+/// so the caller can find the trace of the request in the tracing backend.
+pub(crate) struct TraceExtension;
+
+impl TraceExtension {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl ExtensionFactory for TraceExtension {
+    fn create(&self) -> Arc<dyn Extension> {
+        Arc::new(TraceExtension::new())
+    }
+}
+
+#[async_trait::async_trait]
+impl Extension for TraceExtension {
+    async fn request(
+        &self,
+        ctx: &ExtensionContext<'_>,
+        next: NextRequest<'_>,
+    ) -> Response {
+        let mut response = next.run(ctx).await;
+        if let Some(trace_id) = trace_id(&Span::current()) {
+            response
+                .extensions
+                .insert(TRACE_ID_EXTENSION.to_string(), Value::String(trace_id));
+        }
+        response
+    }
+}
+
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl<'a> Extractor for HeaderExtractor<'a> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use opentelemetry::{
+        sdk::{
+            propagation::TraceContextPropagator,
+            trace::TracerProvider,
+        },
+        trace::TracerProvider as _,
+    };
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn request_span_continues_trace_of_the_caller() {
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        let provider = TracerProvider::builder().build();
+        let tracer = provider.tracer("test");
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(tracer));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let request = Request::builder()
+                .uri("/v1/graphql")
+                .header(
+                    "traceparent",
+                    "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01",
+                )
+                .body(Body::empty())
+                .unwrap();
+
+            let span = request_span(&request);
+
+            assert_eq!(
+                trace_id(&span).as_deref(),
+                Some("4bf92f3577b34da6a3ce929d0e0e4736")
+            );
+        });
+    }
+
+    #[test]
+    fn trace_id_is_none_without_opentelemetry() {
+        let request = Request::builder()
+            .uri("/v1/graphql")
+            .body(Body::empty())
+            .unwrap();
+
+        let span = request_span(&request);
+
+        assert_eq!(trace_id(&span), None);
+    }
+}
@@ -0,0 +1,343 @@
+//! The WebSocket transport for GraphQL subscriptions.
+//!
+//! The transport supports the `graphql-transport-ws` and the legacy `graphql-ws` protocols.
+//! Messages for the client are buffered in a bounded queue per connection. If the client
+//! doesn't drain the queue in time, it is considered a slow consumer and is disconnected
+//! instead of making the node buffer an unbounded amount of data.
+//! The connection is kept alive with ping frames, and clients that stop answering are
+//! disconnected.
+
+use crate::schema::CoreSchema;
+use async_graphql::http::{
+    WebSocket as GraphQLWebSocket,
+    WebSocketProtocols,
+    WsMessage,
+    ALL_WEBSOCKET_PROTOCOLS,
+};
+use axum::{
+    extract::{
+        ws::{
+            CloseFrame,
+            Message,
+            WebSocket,
+            WebSocketUpgrade,
+        },
+        Extension,
+    },
+    http::{
+        header::SEC_WEBSOCKET_PROTOCOL,
+        HeaderMap,
+        StatusCode,
+    },
+    response::{
+        IntoResponse,
+        Response,
+    },
+};
+use futures::{
+    Sink,
+    SinkExt,
+    Stream,
+    StreamExt,
+};
+use std::{
+    num::NonZeroUsize,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::sync::mpsc;
+
+/// The close code sent to the client that doesn't consume messages fast enough.
+const SLOW_CONSUMER_CLOSE_CODE: u16 = 1008;
+
+/// The close code sent to the client that stopped answering pings.
+const KEEPALIVE_TIMEOUT_CLOSE_CODE: u16 = 1001;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebSocketConfig {
+    /// The maximum number of messages buffered for one connection.
+    pub max_queued_messages: NonZeroUsize,
+    /// The interval between ping frames. The client that doesn't answer
+    /// until the next ping is disconnected.
+    pub ping_interval: Duration,
+    /// The time to wait for free space in the queue before the connection
+    /// is closed as a slow consumer.
+    pub slow_consumer_timeout: Duration,
+}
+
+impl Default for WebSocketConfig {
+    fn default() -> Self {
+        Self {
+            max_queued_messages: NonZeroUsize::new(128).expect("Is not zero; qed"),
+            ping_interval: Duration::from_secs(30),
+            slow_consumer_timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// The reason why the server closes the connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Disconnect {
+    /// The GraphQL session is finished, or the client closed the connection.
+    Finished,
+    /// The client doesn't consume messages fast enough.
+    SlowConsumer,
+    /// The client doesn't answer pings.
+    KeepaliveTimeout,
+}
+
+pub async fn graphql_ws_handler(
+    schema: Extension<CoreSchema>,
+    config: Extension<WebSocketConfig>,
+    headers: HeaderMap,
+    upgrade: WebSocketUpgrade,
+) -> Response {
+    let Some(protocol) = negotiate_protocol(&headers) else {
+        return (
+            StatusCode::BAD_REQUEST,
+            "Unsupported Sec-WebSocket-Protocol, expected `graphql-transport-ws` or `graphql-ws`",
+        )
+            .into_response()
+    };
+    let Extension(schema) = schema;
+    let Extension(config) = config;
+
+    upgrade
+        .protocols(ALL_WEBSOCKET_PROTOCOLS)
+        .on_upgrade(move |socket| serve(socket, schema, protocol, config))
+}
+
+/// Selects the first protocol requested by the client that is supported by the node.
+fn negotiate_protocol(headers: &HeaderMap) -> Option<WebSocketProtocols> {
+    headers
+        .get_all(SEC_WEBSOCKET_PROTOCOL)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .find_map(|protocol| protocol.trim().parse().ok())
+}
+
+async fn serve(
+    socket: WebSocket,
+    schema: CoreSchema,
+    protocol: WebSocketProtocols,
+    config: WebSocketConfig,
+) {
+    let (mut sink, stream) = socket.split();
+
+    // Any frame from the client proves that the connection is alive.
+    let alive = Arc::new(AtomicBool::new(true));
+    let incoming = {
+        let alive = alive.clone();
+        stream
+            .take_while(|message| futures::future::ready(message.is_ok()))
+            .filter_map(move |message| {
+                alive.store(true, Ordering::Relaxed);
+                let payload = match message {
+                    Ok(Message::Text(text)) => Some(text.into_bytes()),
+                    Ok(Message::Binary(bytes)) => Some(bytes),
+                    _ => None,
+                };
+                futures::future::ready(payload)
+            })
+    };
+    let outgoing = GraphQLWebSocket::new(schema, incoming, protocol);
+
+    let disconnect = forward(outgoing, &mut sink, &alive, &config).await;
+    close(&mut sink, disconnect, config.slow_consumer_timeout).await;
+}
+
+/// Forwards the `outgoing` messages to the `sink` through the bounded queue
+/// and pings the client until one of the sides finishes the session.
+async fn forward<Outgoing, S>(
+    outgoing: Outgoing,
+    sink: &mut S,
+    alive: &AtomicBool,
+    config: &WebSocketConfig,
+) -> Disconnect
+where
+    Outgoing: Stream<Item = WsMessage>,
+    S: Sink<Message> + Unpin,
+{
+    let (queue_sender, mut queue_receiver) =
+        mpsc::channel(config.max_queued_messages.get());
+
+    let produce = async {
+        futures::pin_mut!(outgoing);
+        while let Some(message) = outgoing.next().await {
+            if queue_sender
+                .send_timeout(message, config.slow_consumer_timeout)
+                .await
+                .is_err()
+            {
+                return Disconnect::SlowConsumer
+            }
+        }
+        Disconnect::Finished
+    };
+
+    let consume = async {
+        let mut ping = tokio::time::interval(config.ping_interval);
+        // The first tick completes immediately.
+        ping.tick().await;
+        loop {
+            tokio::select! {
+                message = queue_receiver.recv() => {
+                    let Some(message) = message else {
+                        return Disconnect::Finished
+                    };
+                    let message = match message {
+                        WsMessage::Text(text) => Message::Text(text),
+                        WsMessage::Close(code, reason) => Message::Close(Some(CloseFrame {
+                            code,
+                            reason: reason.into(),
+                        })),
+                    };
+                    if sink.send(message).await.is_err() {
+                        return Disconnect::Finished
+                    }
+                }
+                _ = ping.tick() => {
+                    if !alive.swap(false, Ordering::Relaxed) {
+                        return Disconnect::KeepaliveTimeout
+                    }
+                    if sink.send(Message::Ping(vec![])).await.is_err() {
+                        return Disconnect::Finished
+                    }
+                }
+            }
+        }
+    };
+
+    tokio::select! {
+        disconnect = produce => disconnect,
+        disconnect = consume => disconnect,
+    }
+}
+
+async fn close<S>(sink: &mut S, disconnect: Disconnect, timeout: Duration)
+where
+    S: Sink<Message> + Unpin,
+{
+    let frame = match disconnect {
+        Disconnect::Finished => None,
+        Disconnect::SlowConsumer => {
+            tracing::debug!("Closing the GraphQL WebSocket of the slow consumer");
+            Some(CloseFrame {
+                code: SLOW_CONSUMER_CLOSE_CODE,
+                reason: "Slow consumer".into(),
+            })
+        }
+        Disconnect::KeepaliveTimeout => {
+            tracing::debug!("Closing the GraphQL WebSocket after the keepalive timeout");
+            Some(CloseFrame {
+                code: KEEPALIVE_TIMEOUT_CLOSE_CODE,
+                reason: "Keepalive timeout".into(),
+            })
+        }
+    };
+
+    if let Some(frame) = frame {
+        // The client may not read anything anymore, so don't wait forever.
+        let _ =
+            tokio::time::timeout(timeout, sink.send(Message::Close(Some(frame)))).await;
+    }
+    let _ = tokio::time::timeout(timeout, sink.close()).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+    use futures::{
+        channel::mpsc as futures_mpsc,
+        stream,
+    };
+
+    fn config() -> WebSocketConfig {
+        WebSocketConfig {
+            max_queued_messages: NonZeroUsize::new(1).unwrap(),
+            ping_interval: Duration::from_millis(50),
+            slow_consumer_timeout: Duration::from_millis(50),
+        }
+    }
+
+    fn headers(protocols: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            SEC_WEBSOCKET_PROTOCOL,
+            HeaderValue::from_str(protocols).unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn negotiate_protocol_selects_graphql_transport_ws() {
+        let protocol = negotiate_protocol(&headers("graphql-transport-ws"));
+        assert_eq!(protocol, Some(WebSocketProtocols::GraphQLWS));
+    }
+
+    #[test]
+    fn negotiate_protocol_selects_first_supported_protocol() {
+        let protocol = negotiate_protocol(&headers("unknown, graphql-ws"));
+        assert_eq!(protocol, Some(WebSocketProtocols::SubscriptionsTransportWS));
+    }
+
+    #[test]
+    fn negotiate_protocol_rejects_unknown_protocols() {
+        assert_eq!(negotiate_protocol(&headers("unknown")), None);
+        assert_eq!(negotiate_protocol(&HeaderMap::new()), None);
+    }
+
+    #[tokio::test]
+    async fn forward_disconnects_slow_consumer() {
+        // The client doesn't read anything, so the sink accepts only one message.
+        let (mut sink, _receiver) = futures_mpsc::channel(0);
+        let outgoing = stream::repeat(WsMessage::Text("data".to_string()));
+        let alive = AtomicBool::new(true);
+
+        let disconnect = forward(outgoing, &mut sink, &alive, &config()).await;
+
+        assert_eq!(disconnect, Disconnect::SlowConsumer);
+    }
+
+    #[tokio::test]
+    async fn forward_disconnects_client_that_does_not_answer_pings() {
+        let (mut sink, mut receiver) = futures_mpsc::channel(16);
+        let alive = AtomicBool::new(true);
+
+        let disconnect = forward(stream::pending(), &mut sink, &alive, &config()).await;
+
+        assert_eq!(disconnect, Disconnect::KeepaliveTimeout);
+        assert_eq!(receiver.next().await, Some(Message::Ping(vec![])));
+    }
+
+    #[tokio::test]
+    async fn forward_keeps_alive_client_that_answers_pings() {
+        let (mut sink, _receiver) = futures_mpsc::channel(16);
+        let alive = AtomicBool::new(true);
+        let config = config();
+        // The session finishes after several ping intervals.
+        let outgoing = stream::once(tokio::time::sleep(config.ping_interval * 5))
+            .filter_map(|_| futures::future::ready(None));
+
+        let answer_pings = async {
+            loop {
+                alive.store(true, Ordering::Relaxed);
+                tokio::time::sleep(config.ping_interval / 5).await;
+            }
+        };
+        let disconnect = tokio::select! {
+            disconnect = forward(outgoing, &mut sink, &alive, &config) => disconnect,
+            _ = answer_pings => unreachable!(),
+        };
+
+        assert_eq!(disconnect, Disconnect::Finished);
+    }
+}
@@ -7,25 +7,72 @@ use crate::{
         },
         metadata::MetadataTable,
     },
+    fingerprint::NodeFingerprint,
     fuel_core_graphql_api::{
+        address_activity::{
+            self,
+            BlockAddressActivity,
+        },
+        message_outbox::{
+            persist_outbox,
+            MessageOutboxCache,
+        },
         ports,
         storage::{
-            blocks::FuelBlockIdsToHeights,
+            assets::{
+                AssetDetails,
+                AssetsInfo,
+            },
+            blocks::{
+                BlockProducerFingerprints,
+                FuelBlockIdsToHeights,
+            },
             coins::{
                 owner_coin_id_key,
                 OwnedCoins,
             },
+            contracts::{
+                ContractDeployment,
+                ContractsDeployments,
+            },
+            fees::{
+                BlockFeeReport,
+                BlockFees,
+                TransactionFees,
+            },
             messages::{
                 OwnedMessageIds,
                 OwnedMessageKey,
             },
+            statistics::{
+                AddressesFirstActivity,
+                BlockActivity,
+                BlockStatistics,
+                ChainStatistics,
+            },
+            transactions::{
+                MessageSpendingTransactions,
+                PrunableReceipts,
+                SpendingTransactions,
+                TransactionVmMetrics,
+            },
+            transfers::{
+                OwnedTransferEventKey,
+                OwnedTransferEvents,
+                TransferDirection,
+                TransferEvent,
+                TransferEventIndex,
+                TransferSource,
+            },
         },
     },
 };
-use fuel_core_metrics::graphql_metrics::graphql_metrics;
+use fuel_core_metrics::{
+    fees_metrics::fees_metrics,
+    graphql_metrics::graphql_metrics,
+};
 use fuel_core_services::{
     stream::BoxStream,
-    EmptyShared,
     RunnableService,
     RunnableTask,
     ServiceRunner,
@@ -36,45 +83,97 @@ use fuel_core_storage::{
     StorageAsMut,
 };
 use fuel_core_types::{
-    blockchain::block::Block,
+    blockchain::{
+        block::Block,
+        consensus::Consensus,
+    },
+    fuel_crypto::Hasher,
     fuel_tx::{
         field::{
+            BytecodeWitnessIndex,
             Inputs,
+            MintAmount,
             Outputs,
+            Salt,
+            Witnesses,
         },
         input::coin::{
             CoinPredicate,
             CoinSigned,
         },
+        Address,
+        ContractIdExt,
         Input,
         Output,
+        Receipt,
         Transaction,
+        TxId,
         UniqueIdentifier,
+        UtxoId,
     },
     fuel_types::{
+        AssetId,
         BlockHeight,
         Bytes32,
+        MessageId,
+        Nonce,
     },
     services::{
         block_importer::{
             ImportResult,
             SharedImportResult,
+            Source,
         },
         executor::{
             Event,
+            TransactionExecutionResult,
             TransactionExecutionStatus,
         },
-        txpool::from_executor_to_status,
+        txpool::{
+            from_executor_to_status,
+            TransactionStatus,
+        },
     },
 };
 use futures::{
+    future::BoxFuture,
     FutureExt,
     StreamExt,
 };
 use std::{
     borrow::Cow,
+    collections::{
+        BTreeSet,
+        HashMap,
+        HashSet,
+    },
+    num::NonZeroUsize,
     ops::Deref,
+    sync::Arc,
+    time::Duration,
 };
+use tokio::sync::{
+    broadcast,
+    mpsc,
+};
+
+/// The transfer events of one block, sorted by the index within the block.
+pub type BlockTransferEvents = Arc<Vec<(OwnedTransferEventKey, TransferEvent)>>;
+
+/// The capacity of the transfer events channel, in blocks.
+const TRANSFER_EVENTS_CAPACITY: usize = 1024;
+
+/// The capacity of the address activity channel, in blocks.
+const ADDRESS_ACTIVITY_CAPACITY: usize = 1024;
+
+/// The number of the recent blocks with the cached message outbox trees.
+const MESSAGE_OUTBOX_CACHE_CAPACITY: usize = 256;
+
+/// The number of the blocks waiting for the archive of their receipts.
+const RECEIPTS_ARCHIVE_QUEUE_CAPACITY: usize = 16;
+
+/// The time limit of archiving the receipts of one block.
+const RECEIPTS_ARCHIVE_TIMEOUT: Duration = Duration::from_secs(60);
 
 /// The off-chain GraphQL API worker task processes the imported blocks
 /// and actualize the information used by the GraphQL service.
@@ -82,6 +181,123 @@ pub struct Task<TxPool, D> {
     tx_pool: TxPool,
     block_importer: BoxStream<SharedImportResult>,
     database: D,
+    base_asset_id: AssetId,
+    transfer_events: broadcast::Sender<BlockTransferEvents>,
+    address_activity: broadcast::Sender<BlockAddressActivity>,
+    message_outbox: MessageOutboxCache,
+    receipts_pruning: Option<ReceiptsPruner>,
+    /// The fingerprint of this binary recorded for the produced blocks.
+    fingerprint: NodeFingerprint,
+}
+
+/// The pruning of the receipts of the transactions from the old blocks.
+pub struct ReceiptsPruning {
+    /// The number of the latest blocks keeping the receipts of their transactions.
+    pub retention: u32,
+    /// Receives the receipts before they are pruned if set.
+    pub archive: Option<Arc<dyn ports::worker::ReceiptsArchive>>,
+}
+
+/// The receipts of the transactions of one block sent to the archive.
+type ArchiveRequest = (BlockHeight, Vec<(Bytes32, Vec<Receipt>)>);
+
+struct ReceiptsPruner {
+    retention: u32,
+    archive: Option<ArchiveQueue>,
+}
+
+/// The queue of the blocks archived by a separate task, so the worker
+/// never waits for the external archive.
+struct ArchiveQueue {
+    requests: mpsc::Sender<ArchiveRequest>,
+    /// The results of the archiving of the blocks.
+    archived: mpsc::Receiver<(BlockHeight, bool)>,
+    in_flight: HashSet<BlockHeight>,
+    /// The task archiving the receipts, spawned when the worker starts.
+    archiver: Option<BoxFuture<'static, ()>>,
+}
+
+impl ReceiptsPruner {
+    fn new(pruning: ReceiptsPruning) -> Self {
+        let archive = pruning.archive.map(|archive| {
+            let (requests, requests_receiver) =
+                mpsc::channel(RECEIPTS_ARCHIVE_QUEUE_CAPACITY);
+            let (archived_sender, archived) =
+                mpsc::channel(RECEIPTS_ARCHIVE_QUEUE_CAPACITY);
+            ArchiveQueue {
+                requests,
+                archived,
+                in_flight: HashSet::new(),
+                archiver: Some(
+                    archive_receipts(archive, requests_receiver, archived_sender).boxed(),
+                ),
+            }
+        });
+        Self {
+            retention: pruning.retention,
+            archive,
+        }
+    }
+
+    async fn next_archived(pruner: &mut Option<Self>) -> Option<(BlockHeight, bool)> {
+        match pruner.as_mut().and_then(|pruner| pruner.archive.as_mut()) {
+            Some(queue) => queue.archived.recv().await,
+            None => futures::future::pending().await,
+        }
+    }
+}
+
+/// Archives the receipts of the requested blocks one by one and reports the results.
+async fn archive_receipts(
+    archive: Arc<dyn ports::worker::ReceiptsArchive>,
+    mut requests: mpsc::Receiver<ArchiveRequest>,
+    archived: mpsc::Sender<(BlockHeight, bool)>,
+) {
+    while let Some((height, receipts)) = requests.recv().await {
+        let result = tokio::time::timeout(
+            RECEIPTS_ARCHIVE_TIMEOUT,
+            archive.archive(height, &receipts),
+        )
+        .await
+        .unwrap_or_else(|_| Err(anyhow::anyhow!("The archive timed out")));
+        if let Err(err) = &result {
+            tracing::warn!(
+                "Failed to archive the receipts of the block {}, \
+                they are kept until the next attempt: {:?}",
+                height,
+                err
+            );
+        }
+        if archived.send((height, result.is_ok())).await.is_err() {
+            break
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct SharedState {
+    transfer_events: broadcast::Sender<BlockTransferEvents>,
+    address_activity: broadcast::Sender<BlockAddressActivity>,
+    message_outbox: MessageOutboxCache,
+}
+
+impl SharedState {
+    /// Subscribes to the transfer events of the blocks processed after the call.
+    pub fn subscribe_transfer_events(&self) -> broadcast::Receiver<BlockTransferEvents> {
+        self.transfer_events.subscribe()
+    }
+
+    /// Subscribes to the activity of the addresses in the blocks processed after the call.
+    pub fn subscribe_address_activity(
+        &self,
+    ) -> broadcast::Receiver<BlockAddressActivity> {
+        self.address_activity.subscribe()
+    }
+
+    /// The message outbox trees of the recent blocks.
+    pub fn message_outbox(&self) -> &MessageOutboxCache {
+        &self.message_outbox
+    }
 }
 
 impl<TxPool, D> Task<TxPool, D>
@@ -105,6 +321,34 @@ where
             .storage::<FuelBlockIdsToHeights>()
             .insert(&block_id, height)?;
 
+        // The genesis block is created from the chain config, not produced.
+        let is_genesis = matches!(result.sealed_block.consensus, Consensus::Genesis(_));
+        if result.source == Source::Local && !is_genesis {
+            transaction
+                .as_mut()
+                .storage::<BlockProducerFingerprints>()
+                .insert(height, &self.fingerprint)?;
+        }
+
+        if self.receipts_pruning.is_some() {
+            let tx_ids: Vec<_> =
+                result.tx_status.iter().map(|status| status.id).collect();
+            if !tx_ids.is_empty() {
+                transaction
+                    .as_mut()
+                    .storage::<PrunableReceipts>()
+                    .insert(height, &tx_ids)?;
+            }
+        }
+
+        let fees = block_fee_report(&result);
+        transaction
+            .as_mut()
+            .storage::<BlockFees>()
+            .insert(height, &fees)?;
+
+        persist_statistics(&result, &fees, transaction.as_mut())?;
+
         let total_tx_count = transaction
             .as_mut()
             .increase_tx_count(block.transactions().len() as u64)
@@ -115,6 +359,36 @@ where
             transaction.as_mut(),
         )?;
 
+        let transfer_events = transfer_events(&result, self.base_asset_id)?;
+        for (key, event) in transfer_events.iter() {
+            transaction
+                .as_mut()
+                .storage::<OwnedTransferEvents>()
+                .insert(key, event)?;
+        }
+
+        persist_assets_info(&result, transaction.as_mut())?;
+
+        persist_contracts_deployments(block, transaction.as_mut())?;
+
+        persist_spending_transactions(block, transaction.as_mut())?;
+
+        // Collected after the deployments of the block are persisted,
+        // so the calls of the contracts deployed in the same block are included.
+        let address_activity =
+            address_activity::block_address_activity(&result, |contract_id| {
+                Ok(transaction
+                    .as_mut()
+                    .storage::<ContractsDeployments>()
+                    .get(contract_id)?
+                    .and_then(|deployment| deployment.deployer))
+            })?;
+
+        let message_ids = message_outbox(&result);
+        if !message_ids.is_empty() {
+            persist_outbox(transaction.as_mut(), *height, &message_ids)?;
+        }
+
         // TODO: Temporary solution to store the block height in the database manually here.
         //  Later it will be controlled by the `commit_changes` function on the `Database` side.
         //  https://github.com/FuelLabs/fuel-core/issues/1589
@@ -139,11 +413,450 @@ where
 
         // update the importer metrics after the block is successfully committed
         graphql_metrics().total_txs_count.set(total_tx_count as i64);
+        // the fee metrics track the revenue of this node as the block producer
+        if result.source == Source::Local {
+            let metrics = fees_metrics();
+            metrics.tips.inc_by(fees.tips);
+            metrics.base_fees.inc_by(fees.base_fees);
+            metrics.coinbase.inc_by(fees.coinbase);
+            metrics.burned.inc_by(fees.burned);
+            metrics.refunds.inc_by(fees.refunds);
+        }
+
+        self.message_outbox.insert(*height, &message_ids);
+
+        // It is okay if nobody is subscribed
+        let _ = self.transfer_events.send(Arc::new(transfer_events));
+        let _ = self.address_activity.send(Arc::new(address_activity));
+
+        Ok(())
+    }
+
+    /// Prunes the receipts of the blocks that left the retention window. If the archive
+    /// is set, the receipts are queued for it and are pruned once they are archived.
+    /// The receipts are kept if the archive fails or the queue is full, and the pruning
+    /// is retried with the next block.
+    fn prune_receipts(&mut self, height: BlockHeight) -> anyhow::Result<()> {
+        let Some(pruner) = &mut self.receipts_pruning else {
+            return Ok(())
+        };
+        let Some(last_pruned) = u32::from(height).checked_sub(pruner.retention) else {
+            return Ok(())
+        };
+
+        for (block_height, tx_ids) in
+            self.database.prunable_receipts(last_pruned.into())?
+        {
+            let Some(queue) = &mut pruner.archive else {
+                strip_receipts(&mut self.database, block_height, tx_ids)?;
+                continue
+            };
+            if queue.in_flight.contains(&block_height) {
+                continue
+            }
 
+            let mut receipts = Vec::with_capacity(tx_ids.len());
+            for tx_id in tx_ids {
+                let Some(mut status) = self.database.get_tx_status(&tx_id)? else {
+                    continue
+                };
+                receipts.push((tx_id, take_receipts(&mut status)));
+            }
+            if queue.requests.try_send((block_height, receipts)).is_err() {
+                break
+            }
+            queue.in_flight.insert(block_height);
+        }
+        Ok(())
+    }
+
+    /// Prunes the receipts of the block after the archive has stored them.
+    fn on_archived(&mut self, height: BlockHeight, archived: bool) -> anyhow::Result<()> {
+        if let Some(queue) = self
+            .receipts_pruning
+            .as_mut()
+            .and_then(|pruner| pruner.archive.as_mut())
+        {
+            queue.in_flight.remove(&height);
+        }
+        if !archived {
+            return Ok(())
+        }
+        let tx_ids = self
+            .database
+            .storage::<PrunableReceipts>()
+            .get(&height)?
+            .map(Cow::into_owned);
+        if let Some(tx_ids) = tx_ids {
+            strip_receipts(&mut self.database, height, tx_ids)?;
+        }
         Ok(())
     }
 }
 
+/// Removes the receipts from the statuses of the transactions of the block.
+fn strip_receipts<D>(
+    database: &mut D,
+    height: BlockHeight,
+    tx_ids: Vec<Bytes32>,
+) -> anyhow::Result<()>
+where
+    D: ports::worker::OffChainDatabase,
+{
+    let mut transaction = database.transaction();
+    for tx_id in tx_ids {
+        let Some(mut status) = transaction.as_mut().get_tx_status(&tx_id)? else {
+            continue
+        };
+        take_receipts(&mut status);
+        transaction.as_mut().update_tx_status(&tx_id, status)?;
+    }
+    transaction
+        .as_mut()
+        .storage::<PrunableReceipts>()
+        .remove(&height)?;
+    transaction.commit()?;
+    Ok(())
+}
+
+/// Removes the receipts from the status of the executed transaction, except
+/// the `MessageOut` receipts required to build the proofs of the messages.
+/// Returns all receipts of the transaction.
+fn take_receipts(status: &mut TransactionStatus) -> Vec<Receipt> {
+    match status {
+        TransactionStatus::Success { receipts, .. }
+        | TransactionStatus::Failed { receipts, .. } => {
+            let taken = core::mem::take(receipts);
+            *receipts = taken
+                .iter()
+                .filter(|receipt| matches!(receipt, Receipt::MessageOut { .. }))
+                .cloned()
+                .collect();
+            taken
+        }
+        _ => vec![],
+    }
+}
+
+/// Derives the transfer events of the owners from the coins and messages
+/// created or spent in the block, and from the `MessageOut` receipts.
+fn transfer_events(
+    result: &ImportResult,
+    base_asset_id: AssetId,
+) -> anyhow::Result<Vec<(OwnedTransferEventKey, TransferEvent)>> {
+    let block = &result.sealed_block.entity;
+    let height = *block.header().height();
+
+    // The executor events don't contain the spending transaction, so find it by inputs
+    let mut spent_coins = HashMap::<UtxoId, TxId>::new();
+    let mut spent_messages = HashMap::<Nonce, TxId>::new();
+    for tx in block.transactions() {
+        let inputs = match tx {
+            Transaction::Script(tx) => tx.inputs().as_slice(),
+            Transaction::Create(tx) => tx.inputs().as_slice(),
+            Transaction::Mint(_) => continue,
+        };
+        let tx_id = tx.cached_id().expect(
+            "The imported block should contains only transactions with cached id",
+        );
+        for input in inputs {
+            if let Some(utxo_id) = input.utxo_id() {
+                spent_coins.insert(*utxo_id, tx_id);
+            }
+            if let Some(nonce) = input.nonce() {
+                spent_messages.insert(*nonce, tx_id);
+            }
+        }
+    }
+
+    let mut events = vec![];
+    for event in result.events.iter() {
+        let (owner, event) = match event {
+            Event::CoinCreated(coin) => (
+                coin.owner,
+                TransferEvent {
+                    asset_id: coin.asset_id,
+                    amount: coin.amount,
+                    direction: TransferDirection::Incoming,
+                    source: TransferSource::Coin,
+                    tx_id: Some(*coin.utxo_id.tx_id()),
+                },
+            ),
+            Event::CoinConsumed(coin) => (
+                coin.owner,
+                TransferEvent {
+                    asset_id: coin.asset_id,
+                    amount: coin.amount,
+                    direction: TransferDirection::Outgoing,
+                    source: TransferSource::Coin,
+                    tx_id: spent_coins.get(&coin.utxo_id).copied(),
+                },
+            ),
+            Event::MessageImported(message) => (
+                *message.recipient(),
+                TransferEvent {
+                    asset_id: base_asset_id,
+                    amount: message.amount(),
+                    direction: TransferDirection::Incoming,
+                    source: TransferSource::Message,
+                    tx_id: None,
+                },
+            ),
+            Event::MessageConsumed(message) => (
+                *message.recipient(),
+                TransferEvent {
+                    asset_id: base_asset_id,
+                    amount: message.amount(),
+                    direction: TransferDirection::Outgoing,
+                    source: TransferSource::Message,
+                    tx_id: spent_messages.get(message.nonce()).copied(),
+                },
+            ),
+        };
+        events.push((owner, event));
+    }
+
+    for status in result.tx_status.iter() {
+        // The receipts of the failed transactions are reverted
+        let TransactionExecutionResult::Success { receipts, .. } = &status.result else {
+            continue
+        };
+        for receipt in receipts {
+            if let Receipt::MessageOut { sender, amount, .. } = receipt {
+                events.push((
+                    *sender,
+                    TransferEvent {
+                        asset_id: base_asset_id,
+                        amount: *amount,
+                        direction: TransferDirection::Outgoing,
+                        source: TransferSource::MessageOut,
+                        tx_id: Some(status.id),
+                    },
+                ));
+            }
+        }
+    }
+
+    events
+        .into_iter()
+        .enumerate()
+        .map(|(idx, (owner, event))| {
+            let idx = TransferEventIndex::try_from(idx).map_err(|e| {
+                anyhow::anyhow!("The block has more than `u32::MAX` transfers, {}", e)
+            })?;
+            Ok((OwnedTransferEventKey::new(owner, height, idx), event))
+        })
+        .collect()
+}
+
+/// Updates the minted and burned totals of the assets
+/// from the `Mint` and `Burn` receipts of the successful transactions.
+fn persist_assets_info<D>(import_result: &ImportResult, db: &mut D) -> StorageResult<()>
+where
+    D: ports::worker::OffChainDatabase,
+{
+    for status in import_result.tx_status.iter() {
+        // The receipts of the failed transactions are reverted
+        let TransactionExecutionResult::Success { receipts, .. } = &status.result else {
+            continue
+        };
+        for receipt in receipts {
+            let (contract_id, sub_id, val, is_mint) = match receipt {
+                Receipt::Mint {
+                    contract_id,
+                    sub_id,
+                    val,
+                    ..
+                } => (contract_id, sub_id, *val, true),
+                Receipt::Burn {
+                    contract_id,
+                    sub_id,
+                    val,
+                    ..
+                } => (contract_id, sub_id, *val, false),
+                _ => continue,
+            };
+            let asset_id = contract_id.asset_id(sub_id);
+            let mut details = db
+                .storage::<AssetsInfo>()
+                .get(&asset_id)?
+                .map(Cow::into_owned)
+                .unwrap_or_else(|| AssetDetails::new(*contract_id, *sub_id));
+            if is_mint {
+                details.mint(val);
+            } else {
+                details.burn(val);
+            }
+            db.storage::<AssetsInfo>().insert(&asset_id, &details)?;
+        }
+    }
+    Ok(())
+}
+
+/// Records the `Create` transactions of the block as the deployments of the contracts.
+fn persist_contracts_deployments<D>(block: &Block, db: &mut D) -> StorageResult<()>
+where
+    D: ports::worker::OffChainDatabase,
+{
+    let block_height = *block.header().height();
+    for tx in block.transactions() {
+        let Transaction::Create(create) = tx else {
+            continue
+        };
+        let tx_id = tx.cached_id().expect(
+            "The imported block should contains only transactions with cached id",
+        );
+        let deployer = address_activity::deployer(tx);
+        let bytecode_hash = create
+            .witnesses()
+            .get(*create.bytecode_witness_index() as usize)
+            .map(|witness| Hasher::hash(witness.as_ref()))
+            .unwrap_or_default();
+        for output in create.outputs() {
+            if let Output::ContractCreated { contract_id, .. } = output {
+                let deployment = ContractDeployment {
+                    tx_id,
+                    block_height,
+                    bytecode_hash,
+                    salt: *create.salt(),
+                    deployer,
+                };
+                db.storage::<ContractsDeployments>()
+                    .insert(contract_id, &deployment)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Records the transaction that spent each coin and consumed each message of the block.
+fn persist_spending_transactions<D>(block: &Block, db: &mut D) -> StorageResult<()>
+where
+    D: ports::worker::OffChainDatabase,
+{
+    for tx in block.transactions() {
+        let inputs = match tx {
+            Transaction::Script(tx) => tx.inputs().as_slice(),
+            Transaction::Create(tx) => tx.inputs().as_slice(),
+            Transaction::Mint(_) => continue,
+        };
+        let tx_id = tx.cached_id().expect(
+            "The imported block should contains only transactions with cached id",
+        );
+        for input in inputs {
+            match input {
+                Input::Contract(_) => {}
+                _ => {
+                    if let Some(utxo_id) = input.utxo_id() {
+                        db.storage::<SpendingTransactions>()
+                            .insert(utxo_id, &tx_id)?;
+                    }
+                    if let Some(nonce) = input.nonce() {
+                        db.storage::<MessageSpendingTransactions>()
+                            .insert(nonce, &tx_id)?;
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Records the activity of the block and adds it to the totals of the chain.
+fn persist_statistics<D>(
+    import_result: &ImportResult,
+    fees: &BlockFeeReport,
+    db: &mut D,
+) -> StorageResult<()>
+where
+    D: ports::worker::OffChainDatabase,
+{
+    let block = &import_result.sealed_block.entity;
+    let height = *block.header().height();
+
+    let mut addresses = BTreeSet::new();
+    for tx in block.transactions() {
+        let (inputs, outputs) = match tx {
+            Transaction::Script(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+            Transaction::Create(tx) => (tx.inputs().as_slice(), tx.outputs().as_slice()),
+            Transaction::Mint(_) => continue,
+        };
+        addresses.extend(tx_owners(inputs, outputs));
+    }
+
+    let gas_used = import_result.tx_status.iter().fold(0u64, |sum, status| {
+        sum.saturating_add(status.result.fee().gas_used)
+    });
+    let activity = BlockActivity {
+        tx_count: block.transactions().len() as u64,
+        gas_used,
+        active_addresses: addresses.len() as u64,
+        fees_paid: fees.tips.saturating_add(fees.base_fees),
+        fees_burned: fees.burned,
+    };
+    db.storage::<BlockStatistics>().insert(&height, &activity)?;
+
+    let mut new_addresses = 0u64;
+    for address in addresses {
+        if !db
+            .storage::<AddressesFirstActivity>()
+            .contains_key(address)?
+        {
+            db.storage::<AddressesFirstActivity>()
+                .insert(address, &height)?;
+            new_addresses = new_addresses.saturating_add(1);
+        }
+    }
+
+    // The totals start from zero at the first indexed block.
+    let previous = match height.pred() {
+        Some(previous) => db
+            .storage::<ChainStatistics>()
+            .get(&previous)?
+            .map(Cow::into_owned)
+            .unwrap_or_default(),
+        None => Default::default(),
+    };
+    let totals = previous.add(&activity, new_addresses);
+    db.storage::<ChainStatistics>().insert(&height, &totals)?;
+    Ok(())
+}
+
+/// Returns the ids of the messages sent by the block in the order of the leaves
+/// of the message outbox Merkle tree. Like the executor, it includes the `MessageOut`
+/// receipts of the failed transactions.
+fn message_outbox(result: &ImportResult) -> Vec<MessageId> {
+    result
+        .tx_status
+        .iter()
+        .flat_map(|status| status.result.receipts())
+        .filter_map(|receipt| receipt.message_id())
+        .collect()
+}
+
+/// Sums the fees of all transactions of the block.
+fn block_fee_report(result: &ImportResult) -> BlockFeeReport {
+    let mut report = BlockFeeReport::default();
+    for status in result.tx_status.iter() {
+        let fee = status.result.fee();
+        report.tips = report.tips.saturating_add(fee.tip);
+        report.base_fees = report.base_fees.saturating_add(fee.base_fee);
+        for refund in fee.refunds.iter() {
+            report.refunds = report.refunds.saturating_add(refund.amount);
+        }
+    }
+
+    report.coinbase = match result.sealed_block.entity.transactions().last() {
+        Some(Transaction::Mint(mint)) => *mint.mint_amount(),
+        _ => 0,
+    };
+    report.burned = report
+        .tips
+        .saturating_add(report.base_fees)
+        .saturating_sub(report.coinbase);
+    report
+}
+
 /// Process the executor events and update the indexes for the messages and coins.
 pub fn process_executor_events<'a, D, Iter>(
     events: Iter,
@@ -241,6 +954,15 @@ fn persist_owners_index<D>(
 where
     D: ports::worker::OffChainDatabase,
 {
+    for owner in tx_owners(inputs, outputs) {
+        db.record_tx_id_owner(owner, block_height, tx_idx, tx_id)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the distinct owners of the coins spent and created by the transaction.
+fn tx_owners<'a>(inputs: &'a [Input], outputs: &'a [Output]) -> Vec<&'a Address> {
     let mut owners = vec![];
     for input in inputs {
         if let Input::CoinSigned(CoinSigned { owner, .. })
@@ -264,12 +986,7 @@ where
     // dedupe owners from inputs and outputs prior to indexing
     owners.sort();
     owners.dedup();
-
-    for owner in owners {
-        db.record_tx_id_owner(owner, block_height, tx_idx, tx_id)?;
-    }
-
-    Ok(())
+    owners
 }
 
 fn persist_transaction_status<D>(
@@ -279,7 +996,12 @@ fn persist_transaction_status<D>(
 where
     D: ports::worker::OffChainDatabase,
 {
-    for TransactionExecutionStatus { id, result } in import_result.tx_status.iter() {
+    for TransactionExecutionStatus {
+        id,
+        result,
+        vm_metrics,
+    } in import_result.tx_status.iter()
+    {
         let status =
             from_executor_to_status(&import_result.sealed_block.entity, result.clone());
 
@@ -290,6 +1012,11 @@ where
             )
             .into());
         }
+        db.storage::<TransactionFees>().insert(id, result.fee())?;
+        if let Some(vm_metrics) = vm_metrics {
+            db.storage::<TransactionVmMetrics>()
+                .insert(id, vm_metrics)?;
+        }
     }
     Ok(())
 }
@@ -301,12 +1028,16 @@ where
     D: ports::worker::OffChainDatabase,
 {
     const NAME: &'static str = "GraphQL_Off_Chain_Worker";
-    type SharedData = EmptyShared;
+    type SharedData = SharedState;
     type Task = Self;
     type TaskParams = ();
 
     fn shared_data(&self) -> Self::SharedData {
-        EmptyShared
+        SharedState {
+            transfer_events: self.transfer_events.clone(),
+            address_activity: self.address_activity.clone(),
+            message_outbox: self.message_outbox.clone(),
+        }
     }
 
     async fn into_task(
@@ -317,6 +1048,16 @@ where
         let total_tx_count = self.database.increase_tx_count(0).unwrap_or_default();
         graphql_metrics().total_txs_count.set(total_tx_count as i64);
 
+        let archiver = self
+            .receipts_pruning
+            .as_mut()
+            .and_then(|pruner| pruner.archive.as_mut())
+            .and_then(|queue| queue.archiver.take());
+        if let Some(archiver) = archiver {
+            // The archiver stops when the worker drops the queue.
+            tokio::spawn(archiver);
+        }
+
         // TODO: It is possible that the node was shut down before we processed all imported blocks.
         //  It could lead to some missed blocks and the database's inconsistent state.
         //  Because the result of block execution is not stored on the chain, it is impossible
@@ -345,13 +1086,20 @@ where
 
             result = self.block_importer.next() => {
                 if let Some(block) = result {
+                    let height = *block.sealed_block.entity.header().height();
                     self.process_block(block)?;
+                    self.prune_receipts(height)?;
 
                     should_continue = true
                 } else {
                     should_continue = false
                 }
             }
+
+            Some((height, archived)) = ReceiptsPruner::next_archived(&mut self.receipts_pruning) => {
+                self.on_archived(height, archived)?;
+                should_continue = true
+            }
         }
         Ok(should_continue)
     }
@@ -362,7 +1110,9 @@ where
             let result = self.block_importer.next().now_or_never();
 
             if let Some(Some(block)) = result {
+                let height = *block.sealed_block.entity.header().height();
                 self.process_block(block)?;
+                self.prune_receipts(height)?;
             } else {
                 break;
             }
@@ -375,6 +1125,8 @@ pub fn new_service<TxPool, I, D>(
     tx_pool: TxPool,
     block_importer: I,
     database: D,
+    base_asset_id: AssetId,
+    receipts_pruning: Option<ReceiptsPruning>,
 ) -> ServiceRunner<Task<TxPool, D>>
 where
     TxPool: ports::worker::TxPool,
@@ -382,9 +1134,21 @@ where
     D: ports::worker::OffChainDatabase,
 {
     let block_importer = block_importer.block_events();
+    let (transfer_events, _) = broadcast::channel(TRANSFER_EVENTS_CAPACITY);
+    let (address_activity, _) = broadcast::channel(ADDRESS_ACTIVITY_CAPACITY);
+    let message_outbox = MessageOutboxCache::new(
+        NonZeroUsize::new(MESSAGE_OUTBOX_CACHE_CAPACITY)
+            .expect("The capacity is not zero"),
+    );
     ServiceRunner::new(Task {
         tx_pool,
         block_importer,
         database,
+        base_asset_id,
+        transfer_events,
+        address_activity,
+        message_outbox,
+        receipts_pruning: receipts_pruning.map(ReceiptsPruner::new),
+        fingerprint: NodeFingerprint::current(),
     })
 }
@@ -0,0 +1,22 @@
+//! The optional gRPC API of the node. It mirrors the core GraphQL queries and
+//! the transaction submission for clients that prefer strongly-typed RPC.
+//! The protobuf definitions are located in the `proto` folder of the crate.
+
+use fuel_core_types::fuel_tx::ConsensusParameters;
+use std::net::SocketAddr;
+
+pub mod api;
+pub mod service;
+
+#[allow(missing_docs)]
+#[allow(clippy::arithmetic_side_effects)]
+#[allow(clippy::cast_possible_truncation)]
+pub mod proto {
+    tonic::include_proto!("fuel_core.v1");
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub addr: SocketAddr,
+    pub consensus_parameters: ConsensusParameters,
+}
@@ -0,0 +1,322 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_error::ApiError,
+        api_service::TxPool,
+        database::ReadDatabase,
+        ports::{
+            worker::BlockImporter,
+            OffChainDatabase,
+        },
+    },
+    grpc_api::proto::{
+        self,
+        fuel_core_server::FuelCore,
+        get_block_request,
+        transaction_status::Kind,
+    },
+    query::{
+        BlockQueryData,
+        SimpleBlockData,
+        SimpleTransactionData,
+        TransactionQueryData,
+    },
+};
+use fuel_core_services::stream::BoxStream;
+use fuel_core_storage::{
+    Error as StorageError,
+    IsNotFound,
+};
+use fuel_core_types::{
+    blockchain::{
+        block::CompressedBlock,
+        header::BlockHeader,
+        primitives::BlockId,
+    },
+    fuel_tx::{
+        Bytes32,
+        Receipt,
+        Transaction as FuelTx,
+        TxId,
+        UniqueIdentifier,
+    },
+    fuel_types::{
+        canonical::{
+            Deserialize,
+            Serialize,
+        },
+        ChainId,
+    },
+    fuel_vm::ProgramState,
+    services::{
+        executor::TransactionExecutionResult,
+        graphql_api::ErrorCode,
+        txpool::TransactionStatus,
+    },
+};
+use futures::StreamExt;
+use prost::Message as _;
+use std::sync::Arc;
+use tonic::{
+    Code,
+    Request,
+    Response,
+    Status,
+};
+
+/// The implementation of the `FuelCore` gRPC service.
+pub struct FuelCoreApi {
+    chain_id: ChainId,
+    database: ReadDatabase,
+    txpool: TxPool,
+    block_importer: Box<dyn BlockImporter + Send + Sync>,
+}
+
+impl FuelCoreApi {
+    pub fn new(
+        chain_id: ChainId,
+        database: ReadDatabase,
+        txpool: TxPool,
+        block_importer: Box<dyn BlockImporter + Send + Sync>,
+    ) -> Self {
+        Self {
+            chain_id,
+            database,
+            txpool,
+            block_importer,
+        }
+    }
+
+    fn block(&self, request: proto::GetBlockRequest) -> Result<CompressedBlock, Status> {
+        let query = self.database.view();
+        let block = match request.id {
+            None => query.latest_block(),
+            Some(get_block_request::Id::Height(height)) => query.block(&height.into()),
+            Some(get_block_request::Id::BlockId(id)) => {
+                let id: BlockId = bytes32(&id)?.into();
+                query
+                    .block_height(&id)
+                    .and_then(|height| query.block(&height))
+            }
+        };
+        block.map_err(storage_status)
+    }
+
+    fn status(&self, id: TxId) -> Result<Option<TransactionStatus>, Status> {
+        match self.database.view().status(&id) {
+            Ok(status) => Ok(Some(status)),
+            Err(err) if err.is_not_found() => Ok(self
+                .txpool
+                .submission_time(id)
+                .map(|time| TransactionStatus::Submitted { time })),
+            Err(err) => Err(storage_status(err)),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl FuelCore for FuelCoreApi {
+    async fn get_block(
+        &self,
+        request: Request<proto::GetBlockRequest>,
+    ) -> Result<Response<proto::Block>, Status> {
+        let block = self.block(request.into_inner())?;
+        Ok(Response::new(block.into()))
+    }
+
+    async fn get_header(
+        &self,
+        request: Request<proto::GetBlockRequest>,
+    ) -> Result<Response<proto::Header>, Status> {
+        let block = self.block(request.into_inner())?;
+        Ok(Response::new(block.header().into()))
+    }
+
+    async fn get_transaction(
+        &self,
+        request: Request<proto::GetTransactionRequest>,
+    ) -> Result<Response<proto::Transaction>, Status> {
+        let id = bytes32(&request.into_inner().id)?;
+
+        let transaction = match self.txpool.transaction(id) {
+            Some(transaction) => transaction,
+            None => self
+                .database
+                .view()
+                .transaction(&id)
+                .map_err(storage_status)?,
+        };
+        let status = self.status(id)?;
+
+        Ok(Response::new(proto::Transaction {
+            id: id.to_vec(),
+            raw_payload: transaction.to_bytes(),
+            status: status.map(Into::into),
+        }))
+    }
+
+    async fn submit_transaction(
+        &self,
+        request: Request<proto::SubmitTransactionRequest>,
+    ) -> Result<Response<proto::SubmitTransactionResponse>, Status> {
+        let transaction =
+            FuelTx::from_bytes(&request.into_inner().transaction).map_err(|err| {
+                ApiError::new(ErrorCode::InvalidRequest, format!("{err:?}"))
+            })?;
+        let id = transaction.id(&self.chain_id);
+
+        for result in self.txpool.insert(vec![Arc::new(transaction)]).await {
+            result.map_err(|err| {
+                ApiError::from_anyhow(&err)
+                    .with_detail("transactionId", format!("{id:#x}"))
+            })?;
+        }
+
+        Ok(Response::new(proto::SubmitTransactionResponse {
+            id: id.to_vec(),
+        }))
+    }
+
+    type SubscribeBlocksStream = BoxStream<Result<proto::Block, Status>>;
+
+    async fn subscribe_blocks(
+        &self,
+        _: Request<proto::SubscribeBlocksRequest>,
+    ) -> Result<Response<Self::SubscribeBlocksStream>, Status> {
+        let chain_id = self.chain_id;
+        let stream = self.block_importer.block_events().map(move |result| {
+            let block = result.sealed_block.entity.compress(&chain_id);
+            Ok(block.into())
+        });
+        Ok(Response::new(Box::pin(stream)))
+    }
+}
+
+fn bytes32(bytes: &[u8]) -> Result<Bytes32, Status> {
+    Bytes32::try_from(bytes).map_err(|_| {
+        ApiError::new(ErrorCode::InvalidRequest, "Expected an id of 32 bytes").into()
+    })
+}
+
+fn storage_status(err: StorageError) -> Status {
+    let code = if err.is_not_found() {
+        ErrorCode::NotFound
+    } else {
+        ErrorCode::Internal
+    };
+    ApiError::new(code, err).into()
+}
+
+/// Converts the error into the status with the [`proto::ErrorDetails`] in its details.
+impl From<ApiError> for Status {
+    fn from(error: ApiError) -> Self {
+        let code = match error.code {
+            ErrorCode::InvalidRequest | ErrorCode::InvalidTransaction => {
+                Code::InvalidArgument
+            }
+            ErrorCode::NotFound => Code::NotFound,
+            ErrorCode::Forbidden => Code::PermissionDenied,
+            ErrorCode::TransactionKnown => Code::AlreadyExists,
+            ErrorCode::LimitExceeded | ErrorCode::PoolFull => Code::ResourceExhausted,
+            ErrorCode::Unavailable => Code::Unavailable,
+            ErrorCode::Timeout => Code::DeadlineExceeded,
+            ErrorCode::Internal => Code::Internal,
+            _ => Code::FailedPrecondition,
+        };
+        let details = proto::ErrorDetails {
+            code: error.code.as_str().to_string(),
+            category: error.code.category().as_str().to_string(),
+            retryable: error.code.is_retryable(),
+            details: error.details.into_iter().collect(),
+        };
+        Status::with_details(code, error.message, details.encode_to_vec().into())
+    }
+}
+
+impl From<&BlockHeader> for proto::Header {
+    fn from(header: &BlockHeader) -> Self {
+        proto::Header {
+            id: Bytes32::from(header.id()).to_vec(),
+            height: (*header.height()).into(),
+            da_height: header.da_height.0,
+            time: header.time().0,
+            prev_root: header.prev_root().to_vec(),
+            application_hash: header.application_hash().to_vec(),
+            transactions_count: header.transactions_count,
+            message_receipt_count: header.message_receipt_count,
+            transactions_root: header.transactions_root.to_vec(),
+            message_receipt_root: header.message_receipt_root.to_vec(),
+        }
+    }
+}
+
+impl From<CompressedBlock> for proto::Block {
+    fn from(block: CompressedBlock) -> Self {
+        proto::Block {
+            header: Some(block.header().into()),
+            transaction_ids: block.transactions().iter().map(|id| id.to_vec()).collect(),
+        }
+    }
+}
+
+impl From<TransactionStatus> for proto::TransactionStatus {
+    fn from(status: TransactionStatus) -> Self {
+        fn executed(
+            kind: Kind,
+            block_height: u32,
+            time: u64,
+            result: &Option<ProgramState>,
+            receipts: &[Receipt],
+        ) -> proto::TransactionStatus {
+            let reason = match kind {
+                Kind::Failure => TransactionExecutionResult::reason(receipts, result),
+                _ => String::new(),
+            };
+            proto::TransactionStatus {
+                kind: kind.into(),
+                time,
+                block_height: Some(block_height),
+                reason,
+                receipts: receipts.iter().map(|receipt| receipt.to_bytes()).collect(),
+            }
+        }
+
+        match status {
+            TransactionStatus::Submitted { time } => proto::TransactionStatus {
+                kind: Kind::Submitted.into(),
+                time: time.0,
+                ..Default::default()
+            },
+            TransactionStatus::Success {
+                block_height,
+                time,
+                result,
+                receipts,
+                ..
+            } => executed(
+                Kind::Success,
+                block_height.into(),
+                time.0,
+                &result,
+                &receipts,
+            ),
+            TransactionStatus::SqueezedOut { reason } => proto::TransactionStatus {
+                kind: Kind::SqueezedOut.into(),
+                reason,
+                ..Default::default()
+            },
+            TransactionStatus::Failed {
+                block_height,
+                time,
+                result,
+                receipts,
+                ..
+            } => executed(
+                Kind::Failure,
+                block_height.into(),
+                time.0,
+                &result,
+                &receipts,
+            ),
+        }
+    }
+}
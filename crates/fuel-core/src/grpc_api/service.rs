@@ -0,0 +1,235 @@
+use crate::{
+    fuel_core_graphql_api::{
+        api_service::TxPool,
+        database::ReadDatabase,
+        ports::{
+            worker::BlockImporter,
+            OffChainDatabase,
+            OnChainDatabase,
+        },
+    },
+    grpc_api::{
+        api::FuelCoreApi,
+        proto::fuel_core_server::FuelCoreServer,
+        Config,
+    },
+};
+use fuel_core_services::{
+    RunnableService,
+    RunnableTask,
+    StateWatcher,
+};
+use fuel_core_storage::transactional::AtomicView;
+use fuel_core_types::fuel_types::BlockHeight;
+use std::{
+    future::Future,
+    net::{
+        SocketAddr,
+        TcpListener,
+    },
+    pin::Pin,
+};
+use tokio_stream::wrappers::TcpListenerStream;
+
+pub type Service = fuel_core_services::ServiceRunner<GrpcService>;
+
+#[derive(Clone)]
+pub struct SharedState {
+    pub bound_address: SocketAddr,
+}
+
+pub struct GrpcService {
+    bound_address: SocketAddr,
+}
+
+pub struct ServerParams {
+    api: FuelCoreApi,
+    listener: TcpListener,
+}
+
+pub struct Task {
+    server: Pin<Box<dyn Future<Output = Result<(), tonic::transport::Error>> + Send>>,
+}
+
+#[async_trait::async_trait]
+impl RunnableService for GrpcService {
+    const NAME: &'static str = "gRPC";
+
+    type SharedData = SharedState;
+    type Task = Task;
+    type TaskParams = ServerParams;
+
+    fn shared_data(&self) -> Self::SharedData {
+        SharedState {
+            bound_address: self.bound_address,
+        }
+    }
+
+    async fn into_task(
+        self,
+        state: &StateWatcher,
+        params: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let mut state = state.clone();
+        let ServerParams { api, listener } = params;
+
+        listener.set_nonblocking(true)?;
+        let incoming =
+            TcpListenerStream::new(tokio::net::TcpListener::from_std(listener)?);
+
+        let server = tonic::transport::Server::builder()
+            .add_service(FuelCoreServer::new(api))
+            .serve_with_incoming_shutdown(incoming, async move {
+                state
+                    .while_started()
+                    .await
+                    .expect("The service is destroyed");
+            });
+
+        Ok(Task {
+            server: Box::pin(server),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, _: &mut StateWatcher) -> anyhow::Result<bool> {
+        self.server.as_mut().await?;
+        // The server has its internal loop. If `await` is finished, we get an internal
+        // error or stop signal.
+        Ok(false /* should_continue */)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        // The server was already gracefully shutdown at this point.
+        Ok(())
+    }
+}
+
+pub fn new_service<OnChain, OffChain, Importer>(
+    config: Config,
+    on_database: OnChain,
+    off_database: OffChain,
+    txpool: TxPool,
+    block_importer: Importer,
+) -> anyhow::Result<Service>
+where
+    OnChain: AtomicView<Height = BlockHeight> + 'static,
+    OffChain: AtomicView<Height = BlockHeight> + 'static,
+    OnChain::View: OnChainDatabase,
+    OffChain::View: OffChainDatabase,
+    Importer: BlockImporter + Send + Sync + 'static,
+{
+    let database = ReadDatabase::new(on_database, off_database);
+    let api = FuelCoreApi::new(
+        config.consensus_parameters.chain_id,
+        database,
+        txpool,
+        Box::new(block_importer),
+    );
+
+    let listener = TcpListener::bind(config.addr)?;
+    let bound_address = listener.local_addr()?;
+
+    tracing::info!("Binding gRPC provider to {}", bound_address);
+
+    Ok(Service::new_with_params(
+        GrpcService { bound_address },
+        ServerParams { api, listener },
+    ))
+}
+
+// The test produces the blocks.
+#[cfg(all(test, feature = "producer"))]
+mod tests {
+    use crate::{
+        grpc_api::proto::{
+            fuel_core_client::FuelCoreClient,
+            transaction_status::Kind,
+            GetBlockRequest,
+            GetTransactionRequest,
+            SubmitTransactionRequest,
+            SubscribeBlocksRequest,
+        },
+        service::{
+            Config,
+            FuelService,
+        },
+    };
+    use fuel_core_types::{
+        fuel_asm::{
+            op,
+            RegId,
+        },
+        fuel_tx::{
+            Transaction,
+            TransactionBuilder,
+            UniqueIdentifier,
+        },
+        fuel_types::{
+            canonical::{
+                Deserialize,
+                Serialize,
+            },
+            ChainId,
+        },
+    };
+    use std::net::{
+        Ipv4Addr,
+        SocketAddr,
+    };
+
+    #[tokio::test]
+    async fn grpc_api_serves_blocks_and_transactions() {
+        let mut config = Config::local_node();
+        config.grpc_addr = Some(SocketAddr::new(Ipv4Addr::LOCALHOST.into(), 0));
+        let node = FuelService::new_node(config).await.unwrap();
+        let address = node.shared.grpc.as_ref().unwrap().bound_address;
+        let mut client = FuelCoreClient::connect(format!("http://{address}"))
+            .await
+            .unwrap();
+
+        let genesis = client
+            .get_header(GetBlockRequest { id: None })
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(genesis.height, 0);
+
+        let mut blocks = client
+            .subscribe_blocks(SubscribeBlocksRequest {})
+            .await
+            .unwrap()
+            .into_inner();
+
+        let script = op::ret(RegId::ONE).to_bytes().to_vec();
+        let tx = TransactionBuilder::script(script, vec![])
+            .script_gas_limit(10_000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+        let id = client
+            .submit_transaction(SubmitTransactionRequest {
+                transaction: tx.to_bytes(),
+            })
+            .await
+            .unwrap()
+            .into_inner()
+            .id;
+
+        let block = blocks.message().await.unwrap().unwrap();
+        assert_eq!(block.header.unwrap().height, 1);
+        assert_eq!(block.transaction_ids[0], id);
+
+        let transaction = client
+            .get_transaction(GetTransactionRequest { id: id.clone() })
+            .await
+            .unwrap()
+            .into_inner();
+        let executed_tx = Transaction::from_bytes(&transaction.raw_payload).unwrap();
+        assert_eq!(executed_tx.id(&ChainId::default()).to_vec(), id);
+        let status = transaction.status.unwrap();
+        assert_eq!(status.kind(), Kind::Success);
+        assert_eq!(status.block_height, Some(1));
+    }
+}
@@ -10,11 +10,15 @@ pub use fuel_core_chain_config as chain_config;
 #[cfg(feature = "p2p")]
 #[doc(no_inline)]
 pub use fuel_core_p2p as p2p;
+#[cfg(feature = "producer")]
 #[doc(no_inline)]
 pub use fuel_core_producer as producer;
 #[cfg(feature = "relayer")]
 #[doc(no_inline)]
 pub use fuel_core_relayer as relayer;
+#[cfg(feature = "shared-sequencer")]
+#[doc(no_inline)]
+pub use fuel_core_shared_sequencer as shared_sequencer;
 #[cfg(feature = "p2p")]
 #[doc(no_inline)]
 pub use fuel_core_sync as sync;
@@ -26,13 +30,19 @@ pub use fuel_core_types as types;
 pub mod coins_query;
 pub mod combined_database;
 pub mod database;
+pub mod era;
 pub mod executor;
+pub mod fingerprint;
+#[cfg(feature = "grpc")]
+pub mod grpc_api;
 pub mod model;
 #[cfg(all(feature = "p2p", feature = "test-helpers"))]
 pub mod p2p_test_helpers;
 pub mod query;
 pub mod schema;
 pub mod service;
+mod sized_lru;
+pub mod snapshot_store;
 pub mod state;
 
 // In the future this module will be a separate crate for `fuel-core-graphql-api`.
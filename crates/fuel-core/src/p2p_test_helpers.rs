@@ -64,6 +64,8 @@ use std::{
 };
 use tokio::sync::broadcast;
 
+pub mod chaos;
+
 #[derive(Copy, Clone)]
 pub enum BootstrapType {
     BootstrapNodes,
@@ -369,7 +371,15 @@ pub async fn make_nodes(
 }
 
 pub fn make_config(name: String, mut node_config: Config) -> Config {
+    // Each node gets the new key pair, but stays in the same in-memory network.
+    let memory_network = node_config
+        .p2p
+        .as_ref()
+        .and_then(|p2p| p2p.memory_network.clone());
     node_config.p2p = Config::local_node().p2p;
+    if let Some(p2p) = node_config.p2p.as_mut() {
+        p2p.memory_network = memory_network;
+    }
     node_config.utxo_validation = true;
     node_config.name = name;
     node_config
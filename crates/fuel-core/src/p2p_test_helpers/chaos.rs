@@ -0,0 +1,185 @@
+//! # Helpers for testing the nodes against the network failures
+//!
+//! The nodes of the [`Cluster`] are connected through the in-memory network
+//! with the scripted partitions, latency and message loss:
+//!
+//! ```ignore
+//! let cluster = Cluster::new(seed, 3).await;
+//! cluster.partition(&[&[PRODUCER, "validator:0"], &["validator:1", "validator:2"]]);
+//! cluster.produce_blocks(10).await;
+//! cluster.heal();
+//! cluster.eventually_consistent(Duration::from_secs(20)).await;
+//! ```
+
+use super::{
+    make_nodes,
+    BootstrapSetup,
+    Node,
+    Nodes,
+    ProducerSetup,
+    ValidatorSetup,
+};
+use crate::service::Config;
+use fuel_core_p2p::{
+    memory_network::MemoryNetwork,
+    PeerId,
+    TryPeerId,
+};
+use fuel_core_poa::service::Mode;
+use fuel_core_types::{
+    blockchain::primitives::BlockId,
+    fuel_crypto::SecretKey,
+    fuel_tx::Input,
+    fuel_types::BlockHeight,
+};
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
+use std::{
+    collections::BTreeMap,
+    time::Duration,
+};
+
+/// The name of the block producer of the [`Cluster`].
+pub const PRODUCER: &str = "producer";
+
+/// How often the tips of the nodes are compared.
+const CONSISTENCY_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The bootstrap node, the producer and the validators connected through the in-memory network.
+pub struct Cluster {
+    pub network: MemoryNetwork,
+    pub nodes: Nodes,
+}
+
+impl Cluster {
+    /// Spawns the bootstrap node, the [`PRODUCER`] and the validators named `validator:{i}`.
+    pub async fn new(seed: u64, validators: usize) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let secret = SecretKey::random(&mut rng);
+        let pub_key = Input::owner(&secret.public_key());
+
+        let network = MemoryNetwork::default();
+        let mut config = Config::local_node();
+        config
+            .p2p
+            .as_mut()
+            .expect("The p2p is enabled for the local node")
+            .memory_network = Some(network.clone());
+
+        let nodes = make_nodes(
+            [Some(BootstrapSetup::new(pub_key))],
+            [Some(ProducerSetup::new(secret).with_name(PRODUCER))],
+            (0..validators).map(|i| {
+                Some(ValidatorSetup::new(pub_key).with_name(format!("validator:{i}")))
+            }),
+            Some(config),
+        )
+        .await;
+
+        Self { network, nodes }
+    }
+
+    /// Returns the producer or the validator by its name.
+    pub fn node(&self, name: &str) -> &Node {
+        self.nodes
+            .producers
+            .iter()
+            .chain(self.nodes.validators.iter())
+            .find(|node| node.config.name == name)
+            .unwrap_or_else(|| panic!("The node {name} doesn't exist"))
+    }
+
+    /// Splits the nodes by their names. The bootstrap node joins the first partition,
+    /// and the nodes missing from all partitions can reach only each other.
+    pub fn partition(&self, partitions: &[&[&str]]) {
+        let bootstrap: Vec<_> = self
+            .nodes
+            .bootstrap_nodes
+            .iter()
+            .flat_map(|bootstrap| bootstrap.listeners())
+            .filter_map(|address| address.try_to_peer_id())
+            .collect();
+
+        let partitions = partitions.iter().enumerate().map(|(i, names)| {
+            let mut peers: Vec<_> =
+                names.iter().map(|name| peer_id(self.node(name))).collect();
+            if i == 0 {
+                peers.extend(bootstrap.iter().copied());
+            }
+            peers
+        });
+        self.network.partition(partitions);
+    }
+
+    /// Reconnects all nodes.
+    pub fn heal(&self) {
+        self.network.heal();
+    }
+
+    /// Produces the blocks on the [`PRODUCER`].
+    pub async fn produce_blocks(&self, number_of_blocks: u32) {
+        self.node(PRODUCER)
+            .node
+            .shared
+            .poa_adapter
+            .manually_produce_blocks(
+                None,
+                Mode::Blocks {
+                    number_of_blocks,
+                    interval: None,
+                },
+            )
+            .await
+            .expect("The producer should produce the blocks");
+    }
+
+    /// The height and the id of the latest block of each node by its name.
+    pub fn tips(&self) -> BTreeMap<String, (BlockHeight, BlockId)> {
+        self.nodes
+            .producers
+            .iter()
+            .chain(self.nodes.validators.iter())
+            .map(|node| {
+                let height = node.db.latest_height().expect("The node has the genesis");
+                let block = node
+                    .db
+                    .get_sealed_block_by_height(&height)
+                    .expect("The database is available")
+                    .expect("The latest block exists");
+                (node.config.name.clone(), (height, block.entity.id()))
+            })
+            .collect()
+    }
+
+    /// Waits until all nodes have the same latest block.
+    /// Panics with the tips of the nodes after the `timeout`.
+    pub async fn eventually_consistent(&self, timeout: Duration) {
+        let consistent = async {
+            loop {
+                let tips = self.tips();
+                let mut blocks = tips.values();
+                let first = blocks.next();
+                if blocks.all(|block| Some(block) == first) {
+                    return
+                }
+                tokio::time::sleep(CONSISTENCY_CHECK_INTERVAL).await;
+            }
+        };
+
+        tokio::time::timeout(timeout, consistent)
+            .await
+            .unwrap_or_else(|_| {
+                panic!("The nodes didn't reach the same tip: {:?}", self.tips())
+            });
+    }
+}
+
+fn peer_id(node: &Node) -> PeerId {
+    node.config
+        .p2p
+        .as_ref()
+        .expect("The p2p is enabled for the node")
+        .peer_id()
+}
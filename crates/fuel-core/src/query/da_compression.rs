@@ -0,0 +1,66 @@
+use crate::{
+    fuel_core_graphql_api::ports::{
+        OffChainDatabase,
+        OnChainDatabase,
+    },
+    query::{
+        SimpleBlockData,
+        SimpleTransactionData,
+    },
+};
+use fuel_core_compression::ports::TransactionLookup;
+use fuel_core_storage::{
+    Error as StorageError,
+    IsNotFound,
+    Result as StorageResult,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        TxId,
+        TxPointer,
+    },
+    fuel_types::BlockHeight,
+};
+
+pub trait DaCompressedBlockData: Send + Sync {
+    /// Returns the block at the `height` compressed for posting to the DA layer.
+    fn da_compressed_block(&self, height: &BlockHeight) -> StorageResult<Vec<u8>>;
+}
+
+impl<D> DaCompressedBlockData for D
+where
+    D: OnChainDatabase + OffChainDatabase + ?Sized,
+{
+    fn da_compressed_block(&self, height: &BlockHeight) -> StorageResult<Vec<u8>> {
+        let block = self.block(height)?;
+        let transactions = block
+            .transactions()
+            .iter()
+            .map(|id| self.transaction(id))
+            .collect::<StorageResult<Vec<_>>>()?;
+        let block = block.uncompress(transactions);
+
+        fuel_core_compression::compress(&block, &BlockTransactions(self))
+            .map_err(StorageError::Other)
+    }
+}
+
+/// Finds the ids of the transactions in the imported blocks.
+struct BlockTransactions<'a, D: ?Sized>(&'a D);
+
+impl<'a, D> TransactionLookup for BlockTransactions<'a, D>
+where
+    D: OnChainDatabase + ?Sized,
+{
+    fn transaction_id(&self, pointer: &TxPointer) -> anyhow::Result<Option<TxId>> {
+        let block = self.0.block(&pointer.block_height());
+        if block.is_not_found() {
+            return Ok(None)
+        }
+
+        Ok(block?
+            .transactions()
+            .get(pointer.tx_index() as usize)
+            .copied())
+    }
+}
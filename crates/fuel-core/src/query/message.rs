@@ -1,5 +1,6 @@
 use crate::{
     fuel_core_graphql_api::{
+        message_outbox::MessageOutboxCache,
         ports::{
             DatabaseMessageProof,
             DatabaseMessages,
@@ -125,6 +126,13 @@ pub trait MessageProofData:
         &self,
         transaction_id: &TxId,
     ) -> StorageResult<TransactionStatus>;
+
+    /// Get the proof of the message from the message outbox Merkle tree of the block.
+    fn message_outbox_proof(
+        &self,
+        height: &BlockHeight,
+        message_id: &MessageId,
+    ) -> StorageResult<Option<MerkleProof>>;
 }
 
 impl<D> MessageProofData for D
@@ -137,12 +145,21 @@ where
     ) -> StorageResult<TransactionStatus> {
         self.status(transaction_id)
     }
+
+    fn message_outbox_proof(
+        &self,
+        height: &BlockHeight,
+        message_id: &MessageId,
+    ) -> StorageResult<Option<MerkleProof>> {
+        OffChainDatabase::message_outbox_proof(self, height, message_id)
+    }
 }
 
 /// Generate an output proof.
 // TODO: Do we want to return `Option` here?
 pub fn message_proof<T: MessageProofData + ?Sized>(
     database: &T,
+    outbox: &MessageOutboxCache,
     transaction_id: Bytes32,
     desired_nonce: Nonce,
     commit_block_height: BlockHeight,
@@ -192,11 +209,20 @@ pub fn message_proof<T: MessageProofData + ?Sized>(
 
     let message_id = compute_message_id(&sender, &recipient, &nonce, amount, &data);
 
-    let message_proof =
-        match message_receipts_proof(database, message_id, &message_block_txs)? {
-            Some(proof) => proof,
-            None => return Ok(None),
-        };
+    let message_proof = match outbox
+        .proof(message_block_height, &message_id, || {
+            database.message_outbox_proof(&message_block_height, &message_id)
+        })
+        .into_api_result::<_, StorageError>()?
+    {
+        Some(proof) => proof,
+        // The outbox of the block isn't indexed, so build the tree from the receipts.
+        None => message_receipts_proof(database, message_id, &message_block_txs)?,
+    };
+    let message_proof = match message_proof {
+        Some(proof) => proof,
+        None => return Ok(None),
+    };
 
     // Get the commit fuel block header.
     let commit_block_header = match database
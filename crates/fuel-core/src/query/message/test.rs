@@ -1,4 +1,7 @@
-use std::ops::Deref;
+use std::{
+    num::NonZeroUsize,
+    ops::Deref,
+};
 
 use fuel_core_types::{
     blockchain::header::{
@@ -77,6 +80,11 @@ mockall::mock! {
 
     impl MessageProofData for ProofDataStorage {
         fn transaction_status(&self, transaction_id: &TxId) -> StorageResult<TransactionStatus>;
+        fn message_outbox_proof(
+            &self,
+            height: &BlockHeight,
+            message_id: &MessageId,
+        ) -> StorageResult<Option<MerkleProof>>;
     }
 }
 
@@ -107,6 +115,11 @@ async fn can_build_message_proof() {
     let mut data = MockProofDataStorage::new();
     let mut count = 0;
 
+    // The outbox of the block isn't indexed, so the proof is built from the receipts.
+    data.expect_message_outbox_proof()
+        .once()
+        .returning(|_, _| Err(not_found!("MessageOutboxLeaves")));
+
     data.expect_receipts().returning(move |txn_id| {
         if *txn_id == transaction_id {
             Ok(receipts.to_vec())
@@ -200,8 +213,10 @@ async fn can_build_message_proof() {
 
     let data: Box<dyn MessageProofData> = Box::new(data);
 
+    let outbox = MessageOutboxCache::new(NonZeroUsize::new(1).unwrap());
     let proof = message_proof(
         data.deref(),
+        &outbox,
         transaction_id,
         nonce.to_owned(),
         *commit_block.header().height(),
@@ -222,3 +237,78 @@ async fn can_build_message_proof() {
     );
     assert_eq!(proof.block_proof, block_proof);
 }
+
+#[tokio::test]
+async fn message_proof_uses_cached_outbox() {
+    use mockall::predicate::*;
+
+    // Given
+    let message_block_height = BlockHeight::from(1u32);
+    let commit_block_height = BlockHeight::from(2u32);
+    let expected_receipt = receipt(Some(11));
+    let nonce = *expected_receipt.nonce().unwrap();
+    let transaction_id = txn_id(33);
+    let message_ids: Vec<MessageId> = [receipt(Some(4)), expected_receipt.clone()]
+        .iter()
+        .filter_map(|r| r.message_id())
+        .collect();
+
+    let block = |height: BlockHeight, message_ids: &[MessageId]| {
+        let header = PartialBlockHeader {
+            application: ApplicationHeader {
+                da_height: 0u64.into(),
+                generated: Default::default(),
+            },
+            consensus: ConsensusHeader {
+                prev_root: Bytes32::zeroed(),
+                height,
+                time: Tai64::UNIX_EPOCH,
+                generated: Default::default(),
+            },
+        }
+        .generate(&[], message_ids);
+        CompressedBlock::test(header, vec![txn_id(20), transaction_id])
+    };
+    let message_block = block(message_block_height, &message_ids);
+    let commit_block = block(commit_block_height, &[]);
+
+    let outbox = MessageOutboxCache::new(NonZeroUsize::new(1).unwrap());
+    outbox.insert(message_block_height, &message_ids);
+
+    let mut data = MockProofDataStorage::new();
+    // Only the receipts of the proven transaction are read.
+    data.expect_receipts()
+        .with(eq(transaction_id))
+        .returning(move |_| Ok(vec![expected_receipt.clone()]));
+    data.expect_message_outbox_proof().never();
+    data.expect_transaction_status().returning(move |_| {
+        Ok(TransactionStatus::Success {
+            block_height: message_block_height,
+            time: Tai64::UNIX_EPOCH,
+            result: None,
+            receipts: vec![],
+        })
+    });
+    data.expect_block().returning(move |height| {
+        if *height == message_block_height {
+            Ok(message_block.clone())
+        } else {
+            Ok(commit_block.clone())
+        }
+    });
+    data.expect_block_history_proof()
+        .returning(|_, _| Ok(MerkleProof::default()));
+
+    // When
+    let proof = message_proof(&data, &outbox, transaction_id, nonce, commit_block_height)
+        .unwrap()
+        .unwrap();
+
+    // Then
+    let mut tree = MerkleTree::new();
+    message_ids.iter().for_each(|id| tree.push(id.as_ref()));
+    let (root, proof_set) = tree.prove(1).unwrap();
+    assert_eq!(proof.message_proof.proof_index, 1);
+    assert_eq!(proof.message_proof.proof_set, proof_set);
+    assert_eq!(proof.message_block_header.message_receipt_root, root.into());
+}
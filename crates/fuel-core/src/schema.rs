@@ -19,18 +19,25 @@ use fuel_core_storage::{
 };
 use itertools::Itertools;
 
+pub mod activity;
+pub mod admin;
+pub mod assets;
 pub mod balance;
 pub mod block;
 pub mod chain;
 pub mod coins;
 pub mod contract;
+pub mod da_compression;
 pub mod dap;
 pub mod health;
+pub mod mempool;
 pub mod message;
 pub mod node_info;
 
 pub mod gas_price;
 pub mod scalars;
+pub mod statistics;
+pub mod transfer;
 pub mod tx;
 
 #[derive(MergedObject, Default)]
@@ -38,23 +45,41 @@ pub struct Query(
     dap::DapQuery,
     balance::BalanceQuery,
     block::BlockQuery,
+    block::BlockCommitmentQuery,
+    block::EquivocationQuery,
     chain::ChainQuery,
     tx::TxQuery,
+    mempool::MempoolQuery,
     health::HealthQuery,
     coins::CoinQuery,
     contract::ContractQuery,
     contract::ContractBalanceQuery,
+    da_compression::DaCompressedBlockQuery,
     node_info::NodeQuery,
     gas_price::LatestGasPriceQuery,
     gas_price::EstimateGasPriceQuery,
     message::MessageQuery,
+    transfer::TransferQuery,
+    assets::AssetInfoQuery,
+    statistics::StatisticsQuery,
+    admin::AdminQuery,
 );
 
 #[derive(MergedObject, Default)]
-pub struct Mutation(dap::DapMutation, tx::TxMutation, block::BlockMutation);
+pub struct Mutation(
+    dap::DapMutation,
+    tx::TxMutation,
+    block::BlockMutation,
+    admin::AdminMutation,
+    coins::CoinLeaseMutation,
+);
 
 #[derive(MergedSubscription, Default)]
-pub struct Subscription(tx::TxStatusSubscription);
+pub struct Subscription(
+    tx::TxStatusSubscription,
+    transfer::TransferSubscription,
+    activity::ActivitySubscription,
+);
 
 pub type CoreSchema = Schema<Query, Mutation, Subscription>;
 pub type CoreSchemaBuilder = SchemaBuilder<Query, Mutation, Subscription>;
@@ -0,0 +1,125 @@
+use crate::{
+    fuel_core_graphql_api::{
+        address_activity,
+        worker_service,
+    },
+    schema::scalars::{
+        Address,
+        TransactionId,
+        U32,
+    },
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+    Subscription,
+};
+use futures::{
+    stream,
+    Stream,
+    StreamExt,
+};
+use tokio_stream::wrappers::{
+    errors::BroadcastStreamRecvError,
+    BroadcastStream,
+};
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum ActivityKind {
+    /// The transaction created a coin owned by the address.
+    CoinCreated,
+    /// The transaction spent a coin owned by the address.
+    CoinSpent,
+    /// The transaction sent a message to the address with the `MessageOut` receipt.
+    MessageCreated,
+    /// The transaction spent a message received by the address.
+    MessageSpent,
+    /// The transaction called a contract deployed by the address. The deployer is
+    /// the owner of the first coin or message input of the `Create` transaction.
+    ContractCalled,
+}
+
+impl From<address_activity::ActivityKind> for ActivityKind {
+    fn from(kind: address_activity::ActivityKind) -> Self {
+        match kind {
+            address_activity::ActivityKind::CoinCreated => ActivityKind::CoinCreated,
+            address_activity::ActivityKind::CoinSpent => ActivityKind::CoinSpent,
+            address_activity::ActivityKind::MessageCreated => {
+                ActivityKind::MessageCreated
+            }
+            address_activity::ActivityKind::MessageSpent => ActivityKind::MessageSpent,
+            address_activity::ActivityKind::ContractCalled => {
+                ActivityKind::ContractCalled
+            }
+        }
+    }
+}
+
+pub struct AddressActivity(address_activity::AddressActivity);
+
+#[Object]
+impl AddressActivity {
+    async fn address(&self) -> Address {
+        self.0.address.into()
+    }
+
+    async fn kind(&self) -> ActivityKind {
+        self.0.kind.into()
+    }
+
+    async fn transaction_id(&self) -> TransactionId {
+        self.0.tx_id.into()
+    }
+
+    async fn block_height(&self) -> U32 {
+        self.0.block_height.into()
+    }
+}
+
+#[derive(Default)]
+pub struct ActivitySubscription;
+
+#[Subscription]
+impl ActivitySubscription {
+    /// Returns a stream of the transactions touching the address from the blocks
+    /// imported after the subscription. The transaction is emitted once per each
+    /// way it touched the address.
+    ///
+    /// The stream ends with an error if it is polled slower than the blocks arrive.
+    async fn activity_by_address<'a>(
+        &self,
+        ctx: &Context<'a>,
+        address: Address,
+    ) -> impl Stream<Item = async_graphql::Result<AddressActivity>> + 'a {
+        let worker = ctx.data_unchecked::<worker_service::SharedState>();
+        let receiver = worker.subscribe_address_activity();
+        let address = address.0;
+        let mut lagged = false;
+
+        BroadcastStream::new(receiver)
+            .map(move |result| match result {
+                Ok(activity) => {
+                    let activity = activity
+                        .iter()
+                        .filter(|activity| activity.address == address)
+                        .map(|activity| Ok(AddressActivity(*activity)))
+                        .collect::<Vec<_>>();
+                    stream::iter(activity).left_stream()
+                }
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                    let error = anyhow::anyhow!(
+                        "The subscription skipped the activity of {skipped} blocks"
+                    );
+                    stream::iter([Err(error.into())]).right_stream()
+                }
+            })
+            .flatten()
+            // Ends the stream after the error
+            .take_while(move |result| {
+                let should_continue = !lagged;
+                lagged = result.is_err();
+                futures::future::ready(should_continue)
+            })
+    }
+}
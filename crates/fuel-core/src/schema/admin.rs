@@ -0,0 +1,291 @@
+use crate::{
+    combined_database,
+    fuel_core_graphql_api::{
+        api_error::ApiError,
+        api_service::{
+            ConsensusModule,
+            ContractVerification,
+            DatabaseStatisticsProvider,
+        },
+        database::ReadView,
+        persisted_queries::PersistedQueries,
+        AdminToken,
+        Config as GraphQLConfig,
+    },
+    query::{
+        BlockQueryData,
+        ContractQueryData,
+    },
+    schema::scalars::{
+        Bytes32,
+        ContractId,
+        HexString,
+        Tai64Timestamp,
+        U32,
+        U64,
+    },
+    state,
+};
+use async_graphql::{
+    Context,
+    Enum,
+    Object,
+};
+use fuel_core_poa::service;
+use fuel_core_types::services::graphql_api::ErrorCode;
+
+/// The maximum size of the verified-source metadata of one contract.
+pub const MAX_CONTRACT_VERIFICATION_SIZE: usize = 1024 * 1024;
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum PauseReason {
+    Incident,
+    Maintenance,
+    Upgrade,
+    Other,
+}
+
+impl From<PauseReason> for service::PauseReason {
+    fn from(reason: PauseReason) -> Self {
+        match reason {
+            PauseReason::Incident => service::PauseReason::Incident,
+            PauseReason::Maintenance => service::PauseReason::Maintenance,
+            PauseReason::Upgrade => service::PauseReason::Upgrade,
+            PauseReason::Other => service::PauseReason::Other,
+        }
+    }
+}
+
+impl From<service::PauseReason> for PauseReason {
+    fn from(reason: service::PauseReason) -> Self {
+        match reason {
+            service::PauseReason::Incident => PauseReason::Incident,
+            service::PauseReason::Maintenance => PauseReason::Maintenance,
+            service::PauseReason::Upgrade => PauseReason::Upgrade,
+            service::PauseReason::Other => PauseReason::Other,
+        }
+    }
+}
+
+pub struct BlockProductionStatus(pub Option<service::Paused>);
+
+#[Object]
+impl BlockProductionStatus {
+    /// Returns true when the block production is paused by the admin.
+    async fn paused(&self) -> bool {
+        self.0.is_some()
+    }
+
+    /// The reason of the pause.
+    async fn reason(&self) -> Option<PauseReason> {
+        self.0.map(|paused| paused.reason.into())
+    }
+
+    /// The time when the block production was paused.
+    async fn since(&self) -> Option<Tai64Timestamp> {
+        self.0.map(|paused| Tai64Timestamp(paused.since))
+    }
+}
+
+pub struct DatabaseStatistics(pub combined_database::DatabaseStatistics);
+
+#[Object]
+impl DatabaseStatistics {
+    /// The name of the database.
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    /// The statistics of each column of the database.
+    async fn columns(&self) -> Vec<ColumnStatistics> {
+        self.0
+            .columns
+            .iter()
+            .cloned()
+            .map(ColumnStatistics)
+            .collect()
+    }
+}
+
+pub struct ColumnStatistics(pub state::ColumnStatistics);
+
+#[Object]
+impl ColumnStatistics {
+    /// The name of the column.
+    async fn name(&self) -> &str {
+        &self.0.name
+    }
+
+    /// The total size of the SST files of the column in bytes.
+    async fn size(&self) -> U64 {
+        self.0.size.into()
+    }
+
+    /// The estimated number of keys in the column.
+    async fn estimated_keys(&self) -> U64 {
+        self.0.estimated_keys.into()
+    }
+
+    /// The estimated number of bytes the compaction should rewrite.
+    async fn pending_compaction_bytes(&self) -> U64 {
+        self.0.pending_compaction_bytes.into()
+    }
+
+    /// The number of SST files on each level, starting from the level 0.
+    async fn files_per_level(&self) -> Vec<U64> {
+        self.0
+            .files_per_level
+            .iter()
+            .copied()
+            .map(Into::into)
+            .collect()
+    }
+}
+
+#[derive(Default)]
+pub struct AdminQuery;
+
+#[Object]
+impl AdminQuery {
+    /// Returns the on-disk size, the estimated key count, the layout of the levels, and
+    /// the pending compaction bytes of each column of the databases. The in-memory
+    /// databases don't report any columns.
+    /// Requires the admin token in the `Authorization: Bearer <token>` header.
+    async fn database_statistics(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Vec<DatabaseStatistics>> {
+        authorize(ctx)?;
+        let provider = ctx.data_unchecked::<DatabaseStatisticsProvider>();
+        let statistics = provider.database_statistics()?;
+        Ok(statistics.into_iter().map(DatabaseStatistics).collect())
+    }
+}
+
+#[derive(Default)]
+pub struct AdminMutation;
+
+#[Object]
+impl AdminMutation {
+    /// Pauses the block production until `resumeBlockProduction` is called.
+    /// Requires the admin token in the `Authorization: Bearer <token>` header.
+    async fn pause_block_production(
+        &self,
+        ctx: &Context<'_>,
+        reason: PauseReason,
+    ) -> async_graphql::Result<bool> {
+        authorize(ctx)?;
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        consensus_module
+            .pause_block_production(reason.into())
+            .await?;
+        Ok(true)
+    }
+
+    /// Resumes the paused block production.
+    /// Requires the admin token in the `Authorization: Bearer <token>` header.
+    async fn resume_block_production(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<bool> {
+        authorize(ctx)?;
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        consensus_module.resume_block_production().await?;
+        Ok(true)
+    }
+
+    /// Produces one block without transactions, even if the block production is paused.
+    /// Returns the height of the produced block.
+    /// Requires the admin token in the `Authorization: Bearer <token>` header.
+    async fn produce_heartbeat_block(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<U32> {
+        authorize(ctx)?;
+        let query: &ReadView = ctx.data_unchecked();
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        consensus_module.produce_heartbeat_block().await?;
+
+        query
+            .latest_block_height()
+            .map(Into::into)
+            .map_err(Into::into)
+    }
+
+    /// Attaches the verified-source metadata to the deployed contract,
+    /// replacing the previous one. The node stores the metadata as is.
+    /// Requires the admin token in the `Authorization: Bearer <token>` header.
+    async fn set_contract_verification(
+        &self,
+        ctx: &Context<'_>,
+        contract_id: ContractId,
+        metadata: HexString,
+    ) -> async_graphql::Result<bool> {
+        authorize(ctx)?;
+        if metadata.0.len() > MAX_CONTRACT_VERIFICATION_SIZE {
+            return Err(ApiError::new(
+                ErrorCode::LimitExceeded,
+                format!(
+                    "The metadata is bigger than {} bytes",
+                    MAX_CONTRACT_VERIFICATION_SIZE
+                ),
+            )
+            .into())
+        }
+        let query: &ReadView = ctx.data_unchecked();
+        query.contract_id(contract_id.0)?;
+
+        let contract_verification = ctx.data_unchecked::<ContractVerification>();
+        contract_verification.set_contract_verification(&contract_id.0, &metadata.0)?;
+        Ok(true)
+    }
+
+    /// Registers the query as persisted and returns its SHA-256 hash. The clients can send
+    /// the hash in `extensions.persistedQuery.sha256Hash` instead of the query text.
+    /// If the node accepts only the persisted queries, other queries are rejected.
+    /// The registered queries are restored after a restart if the node stores them
+    /// in a file, otherwise they must be registered again.
+    /// Requires the admin token in the `Authorization: Bearer <token>` header.
+    async fn register_persisted_query(
+        &self,
+        ctx: &Context<'_>,
+        query: String,
+    ) -> async_graphql::Result<Bytes32> {
+        authorize(ctx)?;
+        async_graphql::parser::parse_query(&query)?;
+        let persisted_queries = ctx.data_unchecked::<PersistedQueries>();
+        Ok(persisted_queries.register(query)?.into())
+    }
+
+    /// Removes the persisted query. Returns `false` if the query was not registered.
+    /// Requires the admin token in the `Authorization: Bearer <token>` header.
+    async fn remove_persisted_query(
+        &self,
+        ctx: &Context<'_>,
+        hash: Bytes32,
+    ) -> async_graphql::Result<bool> {
+        authorize(ctx)?;
+        let persisted_queries = ctx.data_unchecked::<PersistedQueries>();
+        Ok(persisted_queries.remove(&hash.0)?)
+    }
+}
+
+pub(crate) fn authorize(ctx: &Context<'_>) -> async_graphql::Result<()> {
+    let config = ctx.data_unchecked::<GraphQLConfig>();
+    let Some(expected) = config.admin_token else {
+        return Err(ApiError::new(
+            ErrorCode::Forbidden,
+            "The admin endpoints are disabled",
+        )
+        .into())
+    };
+
+    match ctx.data_opt::<AdminToken>() {
+        Some(token) if *token == expected => Ok(()),
+        _ => Err(ApiError::new(
+            ErrorCode::Forbidden,
+            "The admin token is missing or invalid",
+        )
+        .into()),
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::OffChainDatabase,
+        storage::assets::AssetDetails,
+        IntoApiResult,
+    },
+    schema::scalars::{
+        AssetId,
+        Bytes32,
+        ContractId,
+        U128,
+    },
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+
+pub struct AssetInfo {
+    asset_id: AssetId,
+    details: AssetDetails,
+}
+
+#[Object]
+impl AssetInfo {
+    async fn asset_id(&self) -> AssetId {
+        self.asset_id
+    }
+
+    /// The contract that minted the asset.
+    async fn contract_id(&self) -> ContractId {
+        self.details.contract_id.into()
+    }
+
+    /// The sub identifier of the asset within the contract.
+    async fn sub_id(&self) -> Bytes32 {
+        self.details.sub_id.into()
+    }
+
+    /// The total amount minted by the contract.
+    async fn minted(&self) -> U128 {
+        self.details.minted.into()
+    }
+
+    /// The total amount burned by the contract.
+    async fn burned(&self) -> U128 {
+        self.details.burned.into()
+    }
+
+    /// The amount of the asset in circulation, the minted minus the burned amount.
+    async fn total_supply(&self) -> U128 {
+        self.details.total_supply().into()
+    }
+}
+
+#[derive(Default)]
+pub struct AssetInfoQuery;
+
+#[Object]
+impl AssetInfoQuery {
+    /// Returns the information about the asset minted by a contract.
+    async fn asset_info(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "ID of the Asset")] id: AssetId,
+    ) -> async_graphql::Result<Option<AssetInfo>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .asset_info(&id.0)
+            .map(|details| AssetInfo {
+                asset_id: id,
+                details,
+            })
+            .into_api_result()
+    }
+}
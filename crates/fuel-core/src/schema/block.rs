@@ -12,16 +12,18 @@ use crate::{
     },
     query::{
         BlockQueryData,
+        DaCompressedBlockData,
         SimpleBlockData,
-        SimpleTransactionData,
     },
     schema::{
+        node_info::NodeFingerprint,
         scalars::{
             BlockId,
             Signature,
             U32,
             U64,
         },
+        statistics::BlockStatistics,
         tx::types::Transaction,
     },
 };
@@ -47,16 +49,29 @@ use fuel_core_storage::{
 use fuel_core_types::{
     blockchain::{
         block::CompressedBlock,
+        consensus::poa::EquivocationProof,
         header::BlockHeader,
+        SealedBlockHeader,
     },
+    fuel_crypto::Hasher,
     fuel_types,
     fuel_types::BlockHeight,
 };
+use std::time::Duration;
 
 pub struct Block(pub(crate) CompressedBlock);
 
 pub struct Header(pub(crate) BlockHeader);
 
+/// Everything required to commit the block to the L1.
+pub struct BlockCommitment(pub(crate) CompressedBlock);
+
+/// The block header with the signature of the producer.
+pub struct SealedHeader(pub(crate) SealedBlockHeader);
+
+/// The proof that the authority sealed two different blocks at the same height.
+pub struct Equivocation(pub(crate) EquivocationProof);
+
 #[derive(Union)]
 #[non_exhaustive]
 pub enum Consensus {
@@ -84,6 +99,22 @@ pub struct PoAConsensus {
     signature: Signature,
 }
 
+/// The fees paid by the transactions of the block.
+#[derive(SimpleObject)]
+pub struct BlockFees {
+    /// The sum of the tips paid to the block producer.
+    pub tips: U64,
+    /// The sum of the fees charged for the used gas, without tips.
+    pub base_fees: U64,
+    /// The amount minted to the coinbase recipient by the `Mint` transaction.
+    pub coinbase: U64,
+    /// The part of the fees that is not minted to anyone,
+    /// because the block producer has no coinbase recipient.
+    pub burned: U64,
+    /// The sum of the unused fees returned to the change outputs.
+    pub refunds: U64,
+}
+
 #[Object]
 impl Block {
     async fn id(&self) -> BlockId {
@@ -109,6 +140,46 @@ impl Block {
         Ok(my_consensus)
     }
 
+    /// The fees paid by the transactions of the block.
+    async fn fees(&self, ctx: &Context<'_>) -> async_graphql::Result<Option<BlockFees>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .block_fees(self.0.header().height())
+            .map(|fees| BlockFees {
+                tips: fees.tips.into(),
+                base_fees: fees.base_fees.into(),
+                coinbase: fees.coinbase.into(),
+                burned: fees.burned.into(),
+                refunds: fees.refunds.into(),
+            })
+            .into_api_result()
+    }
+
+    /// The activity of the transactions of the block.
+    async fn statistics(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<BlockStatistics>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .block_statistics(self.0.header().height())
+            .map(BlockStatistics::from)
+            .into_api_result()
+    }
+
+    /// The fingerprint of the binary that produced the block.
+    /// It is `null` if the block was not produced by this node.
+    async fn producer_fingerprint(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<NodeFingerprint>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .block_producer_fingerprint(self.0.header().height())
+            .map(NodeFingerprint)
+            .into_api_result()
+    }
+
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -118,7 +189,7 @@ impl Block {
             .transactions()
             .iter()
             .map(|tx_id| {
-                let tx = query.transaction(tx_id)?;
+                let tx = query.cached_transaction(tx_id)?;
                 Ok(Transaction::from_tx(*tx_id, tx))
             })
             .collect()
@@ -187,6 +258,74 @@ impl PoAConsensus {
     }
 }
 
+#[Object]
+impl BlockCommitment {
+    /// Fuel block height.
+    async fn height(&self) -> U32 {
+        (*self.0.header().height()).into()
+    }
+
+    /// Hash of the header.
+    async fn block_id(&self) -> BlockId {
+        let bytes: fuel_types::Bytes32 = self.0.header().id().into();
+        bytes.into()
+    }
+
+    /// The consensus of the block with the signature of the producer.
+    async fn consensus(&self, ctx: &Context<'_>) -> async_graphql::Result<Consensus> {
+        let query: &ReadView = ctx.data_unchecked();
+        let core_consensus = query.consensus(self.0.header().height())?;
+
+        let my_consensus = core_consensus.try_into()?;
+        Ok(my_consensus)
+    }
+
+    /// Merkle root of message receipts in this block, the outbox of the block.
+    async fn message_receipt_root(&self) -> Bytes32 {
+        self.0.header().message_receipt_root.into()
+    }
+
+    /// The SHA-256 hash of the block compressed for posting to the DA layer.
+    async fn da_compressed_block_digest(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Bytes32> {
+        let query: &ReadView = ctx.data_unchecked();
+        let bytes = query.da_compressed_block(self.0.header().height())?;
+        Ok(Hasher::hash(bytes).into())
+    }
+}
+
+#[Object]
+impl SealedHeader {
+    async fn header(&self) -> Header {
+        self.0.entity.clone().into()
+    }
+
+    async fn consensus(&self) -> async_graphql::Result<Consensus> {
+        let consensus = self.0.consensus.clone().try_into()?;
+        Ok(consensus)
+    }
+}
+
+#[Object]
+impl Equivocation {
+    /// The height of the conflicting blocks.
+    async fn height(&self) -> U32 {
+        self.0.height().into()
+    }
+
+    /// The header known to the node first, usually the one from the local chain.
+    async fn first(&self) -> SealedHeader {
+        SealedHeader(self.0.first.clone())
+    }
+
+    /// The conflicting header received later.
+    async fn second(&self) -> SealedHeader {
+        SealedHeader(self.0.second.clone())
+    }
+}
+
 #[derive(Default)]
 pub struct BlockQuery;
 
@@ -199,25 +338,23 @@ impl BlockQuery {
         #[graphql(desc = "Height of the block")] height: Option<U32>,
     ) -> async_graphql::Result<Option<Block>> {
         let query: &ReadView = ctx.data_unchecked();
-        let height = match (id, height) {
+        let block = match (id, height) {
             (Some(_), Some(_)) => {
                 return Err(async_graphql::Error::new(
                     "Can't provide both an id and a height",
                 ))
             }
-            (Some(id), None) => query.block_height(&id.0.into()),
+            (Some(id), None) => query.cached_block(&id.0.into()),
             (None, Some(height)) => {
                 let height: u32 = height.into();
-                Ok(height.into())
+                query.block(&height.into())
             }
             (None, None) => {
                 return Err(async_graphql::Error::new("Missing either id or height"))
             }
         };
 
-        height
-            .and_then(|height| query.block(&height))
-            .into_api_result()
+        block.into_api_result()
     }
 
     async fn blocks(
@@ -236,6 +373,65 @@ impl BlockQuery {
     }
 }
 
+#[derive(Default)]
+pub struct BlockCommitmentQuery;
+
+#[Object]
+impl BlockCommitmentQuery {
+    /// Returns the commitments of the blocks. The cursor is the height of the block,
+    /// so the range of heights is requested with `after` and `first`.
+    ///
+    /// The commitment of the committed block never changes, so the committer
+    /// can cache it by the height.
+    async fn block_commitments(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<U32, BlockCommitment, EmptyFields, EmptyFields>>
+    {
+        let query: &ReadView = ctx.data_unchecked();
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            Ok(blocks_query(query, start.map(Into::into), direction))
+        })
+        .await
+    }
+}
+
+#[derive(Default)]
+pub struct EquivocationQuery;
+
+#[Object]
+impl EquivocationQuery {
+    /// Returns the proofs that the authority sealed two different blocks at the same height,
+    /// found during the synchronization. The cursor is the height of the blocks.
+    ///
+    /// Each proof carries both signed headers, so it can be verified without trusting the node.
+    async fn equivocations(
+        &self,
+        ctx: &Context<'_>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<Connection<U32, Equivocation, EmptyFields, EmptyFields>>
+    {
+        let query: &ReadView = ctx.data_unchecked();
+        crate::schema::query_pagination(after, before, first, last, |start, direction| {
+            let proofs =
+                query
+                    .equivocations(start.map(Into::into), direction)
+                    .map(|result| {
+                        result.map(|proof| (proof.height().into(), Equivocation(proof)))
+                    });
+            Ok(proofs.into_boxed())
+        })
+        .await
+    }
+}
+
 #[derive(Default)]
 pub struct HeaderQuery;
 
@@ -291,14 +487,16 @@ pub struct BlockMutation;
 #[Object]
 impl BlockMutation {
     /// Sequentially produces `blocks_to_produce` blocks. The first block starts with
-    /// `start_timestamp`. If the block production in the [`crate::service::Config`] is
-    /// `Trigger::Interval { block_time }`, produces blocks with `block_time ` intervals between
-    /// them. The `start_timestamp` is the timestamp in seconds.
+    /// `start_timestamp`. If the `interval` is set, the timestamps of the next blocks increase
+    /// by the `interval` seconds. Otherwise, if the block production in the
+    /// [`crate::service::Config`] is `Trigger::Interval { block_time }`, produces blocks with
+    /// `block_time ` intervals between them. The `start_timestamp` is the timestamp in seconds.
     async fn produce_blocks(
         &self,
         ctx: &Context<'_>,
         start_timestamp: Option<Tai64Timestamp>,
         blocks_to_produce: U32,
+        interval: Option<U64>,
     ) -> async_graphql::Result<U32> {
         let query: &ReadView = ctx.data_unchecked();
         let consensus_module = ctx.data_unchecked::<ConsensusModule>();
@@ -310,8 +508,9 @@ impl BlockMutation {
 
         let start_time = start_timestamp.map(|timestamp| timestamp.0);
         let blocks_to_produce: u32 = blocks_to_produce.into();
+        let interval = interval.map(|interval| Duration::from_secs(interval.into()));
         consensus_module
-            .manually_produce_blocks(start_time, blocks_to_produce)
+            .manually_produce_blocks(start_time, blocks_to_produce, interval)
             .await?;
 
         query
@@ -319,6 +518,24 @@ impl BlockMutation {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// Overrides the timestamp of the next produced block. The `timestamp` is in seconds
+    /// and can't be lower than the timestamp of the latest block.
+    async fn set_next_block_timestamp(
+        &self,
+        ctx: &Context<'_>,
+        timestamp: Tai64Timestamp,
+    ) -> async_graphql::Result<bool> {
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+
+        if !config.debug {
+            return Err(anyhow!("`debug` must be enabled to use this endpoint").into())
+        }
+
+        consensus_module.set_next_block_time(timestamp.0).await?;
+        Ok(true)
+    }
 }
 
 impl From<CompressedBlock> for Block {
@@ -327,6 +544,12 @@ impl From<CompressedBlock> for Block {
     }
 }
 
+impl From<CompressedBlock> for BlockCommitment {
+    fn from(block: CompressedBlock) -> Self {
+        BlockCommitment(block)
+    }
+}
+
 impl From<BlockHeader> for Header {
     fn from(header: BlockHeader) -> Self {
         Header(header)
@@ -4,6 +4,11 @@ use crate::{
         SpendQuery,
     },
     fuel_core_graphql_api::{
+        coin_leases::{
+            CoinLeases,
+            LeaseError,
+            MAX_COINS_PER_LEASE,
+        },
         database::ReadView,
         Config as GraphQLConfig,
         IntoApiResult,
@@ -11,16 +16,22 @@ use crate::{
     query::{
         asset_query::AssetSpendTarget,
         CoinQueryData,
+        MessageQueryData,
     },
-    schema::scalars::{
-        Address,
-        AssetId,
-        Nonce,
-        UtxoId,
-        U32,
-        U64,
+    schema::{
+        admin::authorize,
+        scalars::{
+            Address,
+            AssetId,
+            Bytes32,
+            Nonce,
+            UtxoId,
+            U32,
+            U64,
+        },
     },
 };
+use anyhow::anyhow;
 use async_graphql::{
     connection::{
         Connection,
@@ -39,6 +50,10 @@ use fuel_core_types::{
     fuel_tx,
 };
 use itertools::Itertools;
+use std::time::{
+    Duration,
+    Instant,
+};
 
 pub struct Coin(pub(crate) CoinModel);
 
@@ -137,6 +152,36 @@ pub struct ExcludeInput {
     messages: Vec<Nonce>,
 }
 
+impl ExcludeInput {
+    fn into_coin_ids(self) -> Vec<coins::CoinId> {
+        coin_ids(self.utxos, self.messages)
+    }
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct CoinIdsInput {
+    /// The ids of the coins.
+    utxos: Vec<UtxoId>,
+    /// The nonces of the message coins.
+    messages: Vec<Nonce>,
+}
+
+impl CoinIdsInput {
+    fn into_coin_ids(self) -> Vec<coins::CoinId> {
+        coin_ids(self.utxos, self.messages)
+    }
+}
+
+fn coin_ids(utxos: Vec<UtxoId>, messages: Vec<Nonce>) -> Vec<coins::CoinId> {
+    let utxos = utxos
+        .into_iter()
+        .map(|utxo| coins::CoinId::Utxo(utxo.into()));
+    let messages = messages
+        .into_iter()
+        .map(|message| coins::CoinId::Message(message.into()));
+    utxos.chain(messages).collect()
+}
+
 #[derive(Default)]
 pub struct CoinQuery;
 
@@ -206,6 +251,10 @@ impl CoinQuery {
         #[graphql(desc = "The excluded coins from the selection.")] excluded_ids: Option<
             ExcludeInput,
         >,
+        #[graphql(desc = "\
+            The lease returned by `lockCoins`. The coins locked by other leases are \
+            excluded from the selection, while the coins of this lease can be selected.")]
+        lease_id: Option<Bytes32>,
     ) -> async_graphql::Result<Vec<Vec<CoinType>>> {
         let config = ctx.data_unchecked::<GraphQLConfig>();
 
@@ -220,17 +269,17 @@ impl CoinQuery {
                 )
             })
             .collect_vec();
-        let excluded_ids: Option<Vec<_>> = excluded_ids.map(|exclude| {
-            let utxos = exclude
-                .utxos
-                .into_iter()
-                .map(|utxo| coins::CoinId::Utxo(utxo.into()));
-            let messages = exclude
-                .messages
-                .into_iter()
-                .map(|message| coins::CoinId::Message(message.into()));
-            utxos.chain(messages).collect()
-        });
+        let mut excluded_ids: Option<Vec<_>> =
+            excluded_ids.map(ExcludeInput::into_coin_ids);
+        let leases = ctx.data_unchecked::<CoinLeases>();
+        let locked = leases.locked_by_others(
+            &owner,
+            lease_id.map(|id| id.0).as_ref(),
+            Instant::now(),
+        );
+        if !locked.is_empty() {
+            excluded_ids.get_or_insert_with(Vec::new).extend(locked);
+        }
 
         let base_asset_id = config.consensus_parameters.base_asset_id();
         let spend_query =
@@ -277,3 +326,74 @@ impl From<coins::CoinType> for CoinType {
         }
     }
 }
+
+#[derive(Default)]
+pub struct CoinLeaseMutation;
+
+#[async_graphql::Object]
+impl CoinLeaseMutation {
+    /// Locks the coins for `ttl` seconds and returns the id of the lease. The locked coins
+    /// are excluded from the `coinsToSpend` selections of the requests without the lease,
+    /// so the concurrent constructions of the transactions don't select the same coins.
+    /// The lock doesn't prevent spending the coins.
+    ///
+    /// The lease covers only the coins of the `owner`, and the number of the coins
+    /// locked per lease and per owner is limited.
+    ///
+    /// Fails if any of the coins doesn't exist, isn't owned by the `owner` or is locked
+    /// by another lease. Requires the node to be started with the maximum time to live
+    /// of the leases, and the admin token in the `Authorization: Bearer <token>` header.
+    async fn lock_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The `Address` of the coins owner.")] owner: Address,
+        #[graphql(desc = "The coins to lock.")] ids: CoinIdsInput,
+        #[graphql(desc = "The time to live of the lease in seconds.")] ttl: U32,
+    ) -> async_graphql::Result<Bytes32> {
+        authorize(ctx)?;
+        let config = ctx.data_unchecked::<GraphQLConfig>();
+        let Some(max_ttl) = config.coin_lease_max_ttl else {
+            return Err(anyhow!("The coin leases are disabled on this node").into())
+        };
+        let ttl = Duration::from_secs(u64::from(ttl.0));
+        if ttl > max_ttl {
+            return Err(anyhow!(
+                "The time to live of the lease is above the maximum of {} seconds",
+                max_ttl.as_secs()
+            )
+            .into())
+        }
+
+        let owner: fuel_tx::Address = owner.0;
+        let ids = ids.into_coin_ids();
+        if ids.len() > MAX_COINS_PER_LEASE {
+            return Err(LeaseError::TooManyCoinsInLease(MAX_COINS_PER_LEASE).into())
+        }
+        let query: &ReadView = ctx.data_unchecked();
+        for id in ids.iter() {
+            let coin_owner = match id {
+                coins::CoinId::Utxo(utxo_id) => query.coin(*utxo_id)?.owner,
+                coins::CoinId::Message(nonce) => *query.message(nonce)?.recipient(),
+            };
+            if coin_owner != owner {
+                return Err(anyhow!("The coin {id:?} is not owned by {owner}").into())
+            }
+        }
+
+        let leases = ctx.data_unchecked::<CoinLeases>();
+        let lease = leases.lock(owner, ids, ttl, Instant::now())?;
+        Ok(lease.id.into())
+    }
+
+    /// Releases the coins of the lease before it expires.
+    /// Returns `false` if the lease doesn't exist or has already expired.
+    async fn unlock_coins(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The id of the lease returned by `lockCoins`.")]
+        lease_id: Bytes32,
+    ) -> bool {
+        let leases = ctx.data_unchecked::<CoinLeases>();
+        leases.unlock(&lease_id.0, Instant::now())
+    }
+}
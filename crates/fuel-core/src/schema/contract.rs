@@ -1,14 +1,19 @@
 use crate::{
     fuel_core_graphql_api::{
         database::ReadView,
+        ports::OffChainDatabase,
+        storage::contracts,
         IntoApiResult,
     },
     query::ContractQueryData,
     schema::scalars::{
         AssetId,
+        Bytes32,
         ContractId,
         HexString,
         Salt,
+        TransactionId,
+        U32,
         U64,
     },
 };
@@ -55,6 +60,55 @@ impl Contract {
             .map(Into::into)
             .map_err(Into::into)
     }
+
+    /// The transaction that deployed the contract.
+    /// It is `null` for the contracts from the genesis state.
+    async fn deployment(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<ContractDeployment>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .contract_deployment(&self.0)
+            .map(ContractDeployment)
+            .into_api_result()
+    }
+
+    /// The verified-source metadata of the contract uploaded by the node operator.
+    async fn verification(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<HexString>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .contract_verification(&self.0)
+            .map(HexString)
+            .into_api_result()
+    }
+}
+
+pub struct ContractDeployment(contracts::ContractDeployment);
+
+#[Object]
+impl ContractDeployment {
+    /// The `Create` transaction that deployed the contract.
+    async fn transaction_id(&self) -> TransactionId {
+        self.0.tx_id.into()
+    }
+
+    /// The height of the block with the deployment transaction.
+    async fn block_height(&self) -> U32 {
+        self.0.block_height.into()
+    }
+
+    /// The SHA-256 hash of the contract bytecode.
+    async fn bytecode_hash(&self) -> Bytes32 {
+        self.0.bytecode_hash.into()
+    }
+
+    async fn salt(&self) -> Salt {
+        self.0.salt.into()
+    }
 }
 
 #[derive(Default)]
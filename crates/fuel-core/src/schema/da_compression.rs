@@ -0,0 +1,47 @@
+use super::scalars::{
+    HexString,
+    U32,
+};
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        IntoApiResult,
+    },
+    query::DaCompressedBlockData,
+};
+use async_graphql::{
+    Context,
+    Object,
+};
+
+pub struct DaCompressedBlock {
+    bytes: Vec<u8>,
+}
+
+#[Object]
+impl DaCompressedBlock {
+    /// The compressed block in the format posted to the DA layer.
+    async fn bytes(&self) -> HexString {
+        HexString(self.bytes.clone())
+    }
+}
+
+#[derive(Default)]
+pub struct DaCompressedBlockQuery;
+
+#[Object]
+impl DaCompressedBlockQuery {
+    /// Returns the block compressed for posting to the DA layer.
+    async fn da_compressed_block(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "Height of the block")] height: U32,
+    ) -> async_graphql::Result<Option<DaCompressedBlock>> {
+        let query: &ReadView = ctx.data_unchecked();
+        let height: u32 = height.into();
+        query
+            .da_compressed_block(&height.into())
+            .map(|bytes| DaCompressedBlock { bytes })
+            .into_api_result()
+    }
+}
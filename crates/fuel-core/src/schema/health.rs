@@ -1,4 +1,11 @@
-use async_graphql::Object;
+use crate::{
+    fuel_core_graphql_api::api_service::ConsensusModule,
+    schema::admin::BlockProductionStatus,
+};
+use async_graphql::{
+    Context,
+    Object,
+};
 
 #[derive(Default)]
 pub struct HealthQuery;
@@ -9,4 +16,10 @@ impl HealthQuery {
     async fn health(&self) -> bool {
         true
     }
+
+    /// Returns the state of the block production.
+    async fn block_production(&self, ctx: &Context<'_>) -> BlockProductionStatus {
+        let consensus_module = ctx.data_unchecked::<ConsensusModule>();
+        BlockProductionStatus(consensus_module.block_production_paused())
+    }
 }
@@ -0,0 +1,191 @@
+use crate::{
+    fuel_core_graphql_api::api_service::TxPool,
+    schema::{
+        scalars::{
+            Address,
+            ContractId,
+            Tai64Timestamp,
+            TransactionId,
+            U64,
+        },
+        tx::types::Transaction,
+    },
+};
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    InputObject,
+    Object,
+};
+use fuel_core_storage::iter::IterDirection;
+use fuel_core_txpool::TxInfo;
+use fuel_core_types::{
+    fuel_tx::{
+        input::coin::{
+            CoinPredicate,
+            CoinSigned,
+        },
+        Input,
+        Output,
+    },
+    fuel_types,
+    tai64::Tai64,
+};
+use std::ops::Deref;
+
+pub struct MempoolTransaction(TxInfo);
+
+#[Object]
+impl MempoolTransaction {
+    async fn transaction(&self) -> Transaction {
+        Transaction::from_tx(self.0.id(), self.0.tx().deref().into())
+    }
+
+    async fn tip(&self) -> U64 {
+        self.0.tip().into()
+    }
+
+    async fn max_gas(&self) -> U64 {
+        self.0.max_gas().into()
+    }
+
+    /// The metered size of the transaction in bytes.
+    async fn size(&self) -> U64 {
+        (self.0.metered_bytes_size() as u64).into()
+    }
+
+    async fn submitted_at(&self) -> Tai64Timestamp {
+        Tai64Timestamp(Tai64::from_unix(self.0.submitted_time().as_secs() as i64))
+    }
+
+    /// The time in ms that the transaction spent in the pool.
+    async fn time_in_pool_ms(&self) -> U64 {
+        let time = self.0.created().elapsed().as_millis();
+        U64(time.try_into().unwrap_or(u64::MAX))
+    }
+
+    /// The hint ordering the transaction among the pending transactions
+    /// of the same owner, if it was supplied with the submission.
+    async fn ordering_hint(&self) -> Option<U64> {
+        self.0.ordering_hint().map(Into::into)
+    }
+}
+
+#[derive(InputObject)]
+struct MempoolFilterInput {
+    /// Returns only the transactions that spend or create the coins of the owner, if set.
+    owner: Option<Address>,
+    /// Returns only the transactions that call or create the contract, if set.
+    contract: Option<ContractId>,
+}
+
+impl MempoolFilterInput {
+    fn matches(&self, info: &TxInfo) -> bool {
+        let owner_matches = self
+            .owner
+            .map_or(true, |owner| touches_owner(info, &owner.0));
+        let contract_matches = self
+            .contract
+            .map_or(true, |contract| touches_contract(info, &contract.0));
+        owner_matches && contract_matches
+    }
+}
+
+fn touches_owner(info: &TxInfo, owner: &fuel_types::Address) -> bool {
+    let in_inputs = info.inputs().iter().any(|input| match input {
+        Input::CoinSigned(CoinSigned {
+            owner: coin_owner, ..
+        })
+        | Input::CoinPredicate(CoinPredicate {
+            owner: coin_owner, ..
+        }) => coin_owner == owner,
+        _ => false,
+    });
+    let in_outputs = info.outputs().iter().any(|output| match output {
+        Output::Coin { to, .. }
+        | Output::Change { to, .. }
+        | Output::Variable { to, .. } => to == owner,
+        Output::Contract(_) | Output::ContractCreated { .. } => false,
+    });
+    in_inputs || in_outputs
+}
+
+fn touches_contract(info: &TxInfo, contract: &fuel_types::ContractId) -> bool {
+    let in_inputs = info.inputs().iter().any(|input| match input {
+        Input::Contract(input) => &input.contract_id == contract,
+        _ => false,
+    });
+    let in_outputs = info.outputs().iter().any(|output| match output {
+        Output::ContractCreated { contract_id, .. } => contract_id == contract,
+        _ => false,
+    });
+    in_inputs || in_outputs
+}
+
+#[derive(Default)]
+pub struct MempoolQuery;
+
+#[Object]
+impl MempoolQuery {
+    /// Returns the transactions that are waiting in the pool for the inclusion
+    /// into the block, sorted by their ids.
+    async fn mempool(
+        &self,
+        ctx: &Context<'_>,
+        filter: Option<MempoolFilterInput>,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransactionId, MempoolTransaction, EmptyFields, EmptyFields>,
+    > {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let mut txs = txpool.pending_transactions();
+        if let Some(filter) = filter {
+            txs.retain(|info| filter.matches(info));
+        }
+
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TransactionId>, direction| {
+                let start = start.map(|id| id.0);
+                let txs: Box<dyn Iterator<Item = TxInfo> + Send> = match direction {
+                    IterDirection::Forward => Box::new(
+                        txs.into_iter()
+                            .filter(move |info| start.map_or(true, |id| info.id() >= id)),
+                    ),
+                    IterDirection::Reverse => Box::new(
+                        txs.into_iter()
+                            .rev()
+                            .filter(move |info| start.map_or(true, |id| info.id() <= id)),
+                    ),
+                };
+                Ok(txs.map(|info| Ok((info.id().into(), MempoolTransaction(info)))))
+            },
+        )
+        .await
+    }
+
+    /// Returns the pending transactions of the owner submitted with the ordering hint,
+    /// in the order of their inclusion into the blocks. The owner of the transaction
+    /// is the owner of its first coin or message input.
+    async fn pending_chain(
+        &self,
+        ctx: &Context<'_>,
+        owner: Address,
+    ) -> Vec<MempoolTransaction> {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        txpool
+            .pending_chain(owner.0)
+            .into_iter()
+            .map(MempoolTransaction)
+            .collect()
+    }
+}
@@ -11,8 +11,10 @@ use super::{
 };
 use crate::{
     fuel_core_graphql_api::{
+        api_service::RelayerService,
         database::ReadView,
         ports::OffChainDatabase,
+        worker_service,
     },
     graphql_api::IntoApiResult,
     query::MessageQueryData,
@@ -31,7 +33,11 @@ use async_graphql::{
     Enum,
     Object,
 };
-use fuel_core_types::entities;
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    entities,
+    fuel_types,
+};
 
 pub struct Message(pub(crate) entities::message::Message);
 
@@ -139,8 +145,10 @@ impl MessageQuery {
             ))?,
         };
 
+        let worker: &worker_service::SharedState = ctx.data_unchecked();
         Ok(crate::query::message_proof(
             query,
+            worker.message_outbox(),
             transaction_id.into(),
             nonce.into(),
             height,
@@ -154,8 +162,27 @@ impl MessageQuery {
         nonce: Nonce,
     ) -> async_graphql::Result<MessageStatus> {
         let query: &ReadView = ctx.data_unchecked();
-        let status = crate::query::message_status(query, nonce.into())?;
-        Ok(status.into())
+        let relayer: &RelayerService = ctx.data_unchecked();
+        let nonce = nonce.into();
+        let status = crate::query::message_status(query, nonce)?;
+        let origin = relayer.message_origin(&nonce)?;
+
+        let (da_height, da_transaction_hash) = match origin {
+            Some(origin) => (Some(origin.da_height), Some(origin.transaction_hash)),
+            // The nodes without the relayer know only the DA height of unspent messages.
+            None => match status.state {
+                entities::message::MessageState::Unspent => {
+                    (Some(query.message(&nonce)?.da_height()), None)
+                }
+                _ => (None, None),
+            },
+        };
+
+        Ok(MessageStatus {
+            status,
+            da_height,
+            da_transaction_hash,
+        })
     }
 }
 pub struct MerkleProof(pub(crate) entities::message::MerkleProof);
@@ -229,7 +256,11 @@ impl From<entities::message::MerkleProof> for MerkleProof {
     }
 }
 
-pub struct MessageStatus(pub(crate) entities::message::MessageStatus);
+pub struct MessageStatus {
+    status: entities::message::MessageStatus,
+    da_height: Option<DaBlockHeight>,
+    da_transaction_hash: Option<fuel_types::Bytes32>,
+}
 
 #[derive(Enum, Copy, Clone, Eq, PartialEq)]
 enum MessageState {
@@ -241,16 +272,22 @@ enum MessageState {
 #[Object]
 impl MessageStatus {
     async fn state(&self) -> MessageState {
-        match self.0.state {
+        match self.status.state {
             entities::message::MessageState::Unspent => MessageState::Unspent,
             entities::message::MessageState::Spent => MessageState::Spent,
             entities::message::MessageState::NotFound => MessageState::NotFound,
         }
     }
-}
 
-impl From<entities::message::MessageStatus> for MessageStatus {
-    fn from(status: entities::message::MessageStatus) -> Self {
-        MessageStatus(status)
+    /// The height of the DA block with the transaction that sent the message.
+    /// It is known for unspent messages, and for all messages downloaded by the relayer.
+    async fn da_height(&self) -> Option<U64> {
+        self.da_height.map(|height| height.0.into())
+    }
+
+    /// The hash of the DA transaction that sent the message.
+    /// It is known only for the messages downloaded by the relayer.
+    async fn da_transaction_hash(&self) -> Option<Bytes32> {
+        self.da_transaction_hash.map(Into::into)
     }
 }
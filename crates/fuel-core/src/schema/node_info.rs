@@ -1,12 +1,20 @@
 use super::scalars::{
+    Bytes32,
     U32,
     U64,
 };
-use crate::fuel_core_graphql_api::Config as GraphQLConfig;
+use crate::{
+    fingerprint,
+    fuel_core_graphql_api::{
+        Config as GraphQLConfig,
+        P2pIdentity,
+    },
+};
 use async_graphql::{
     Context,
     Object,
 };
+use fuel_core_chain_config::GenesisCommitment;
 use std::time::UNIX_EPOCH;
 
 pub struct NodeInfo {
@@ -16,6 +24,36 @@ pub struct NodeInfo {
     max_tx: U64,
     max_depth: U64,
     node_version: String,
+    p2p_identity: Option<P2pIdentity>,
+    relayer_enabled: bool,
+    consensus_parameters_root: Bytes32,
+}
+
+/// The fingerprint of the node binary.
+pub struct NodeFingerprint(pub fingerprint::NodeFingerprint);
+
+#[Object]
+impl NodeFingerprint {
+    /// The hash of the fingerprint. The binaries built from the same commit
+    /// with the same features have the same id.
+    async fn id(&self) -> Bytes32 {
+        self.0.id().into()
+    }
+
+    /// The version of the node.
+    async fn version(&self) -> String {
+        self.0.version.clone()
+    }
+
+    /// The git commit of the sources, or `unknown` if it wasn't available during the build.
+    async fn git_commit(&self) -> String {
+        self.0.git_commit.clone()
+    }
+
+    /// The features enabled in the build.
+    async fn features(&self) -> Vec<String> {
+        self.0.features.clone()
+    }
 }
 
 #[Object]
@@ -44,6 +82,45 @@ impl NodeInfo {
         self.node_version.to_owned()
     }
 
+    /// The id of the node in the p2p network. It is `null` if the p2p is disabled.
+    async fn peer_id(&self) -> Option<String> {
+        self.p2p_identity
+            .as_ref()
+            .map(|identity| identity.peer_id.clone())
+    }
+
+    /// The multi-addrs that the node advertises to other peers.
+    async fn advertised_addresses(&self) -> Vec<String> {
+        self.p2p_identity
+            .as_ref()
+            .map(|identity| identity.addresses.clone())
+            .unwrap_or_default()
+    }
+
+    /// The versions of the p2p protocols that the node speaks.
+    async fn protocol_versions(&self) -> Vec<String> {
+        self.p2p_identity
+            .as_ref()
+            .map(|identity| identity.protocol_versions.clone())
+            .unwrap_or_default()
+    }
+
+    /// Whether the node follows the DA layer with the relayer.
+    async fn relayer_enabled(&self) -> bool {
+        self.relayer_enabled
+    }
+
+    /// The commitment to the consensus parameters used by the node.
+    /// The nodes with the same root use the same version of the parameters.
+    async fn consensus_parameters_root(&self) -> Bytes32 {
+        self.consensus_parameters_root
+    }
+
+    /// The fingerprint of the node binary.
+    async fn fingerprint(&self) -> NodeFingerprint {
+        NodeFingerprint(fingerprint::NodeFingerprint::current())
+    }
+
     async fn peers(&self, _ctx: &Context<'_>) -> async_graphql::Result<Vec<PeerInfo>> {
         #[cfg(feature = "p2p")]
         {
@@ -71,6 +148,9 @@ impl NodeQuery {
         let config = ctx.data_unchecked::<GraphQLConfig>();
 
         const VERSION: &str = env!("CARGO_PKG_VERSION");
+        let consensus_parameters_root = fuel_core_types::fuel_types::Bytes32::from(
+            config.consensus_parameters.root()?,
+        );
 
         Ok(NodeInfo {
             utxo_validation: config.utxo_validation,
@@ -79,6 +159,9 @@ impl NodeQuery {
             max_tx: (config.max_tx as u64).into(),
             max_depth: (config.max_depth as u64).into(),
             node_version: VERSION.to_owned(),
+            p2p_identity: config.p2p_identity.clone(),
+            relayer_enabled: config.relayer_enabled,
+            consensus_parameters_root: consensus_parameters_root.into(),
         })
     }
 }
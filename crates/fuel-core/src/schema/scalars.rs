@@ -79,6 +79,7 @@ macro_rules! number_scalar {
     };
 }
 
+number_scalar!(U128, u128, "U128");
 number_scalar!(U64, u64, "U64");
 number_scalar!(U32, u32, "U32");
 number_scalar!(U8, u8, "U8");
@@ -156,6 +157,41 @@ impl CursorType for SortedTxCursor {
     }
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TransferEventCursor {
+    pub block_height: BlockHeight,
+    pub event_idx: u32,
+}
+
+impl TransferEventCursor {
+    pub fn new(block_height: BlockHeight, event_idx: u32) -> Self {
+        Self {
+            block_height,
+            event_idx,
+        }
+    }
+}
+
+impl CursorType for TransferEventCursor {
+    type Error = String;
+
+    fn decode_cursor(s: &str) -> Result<Self, Self::Error> {
+        let (block_height, event_idx) =
+            s.split_once('#').ok_or("Incorrect format provided")?;
+
+        Ok(Self::new(
+            u32::from_str(block_height)
+                .map_err(|_| "Failed to decode block_height")?
+                .into(),
+            u32::from_str(event_idx).map_err(|_| "Failed to decode event_idx")?,
+        ))
+    }
+
+    fn encode_cursor(&self) -> String {
+        format!("{}#{}", self.block_height, self.event_idx)
+    }
+}
+
 #[derive(Clone, Debug, derive_more::Into, derive_more::From, PartialEq, Eq)]
 pub struct HexString(pub(crate) Vec<u8>);
 
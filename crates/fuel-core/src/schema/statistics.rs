@@ -0,0 +1,132 @@
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::OffChainDatabase,
+        storage::statistics::{
+            BlockActivity,
+            ChainTotals,
+        },
+        IntoApiResult,
+    },
+    schema::scalars::{
+        U32,
+        U64,
+    },
+};
+use anyhow::anyhow;
+use async_graphql::{
+    Context,
+    Object,
+    SimpleObject,
+};
+use fuel_core_storage::Error as StorageError;
+use fuel_core_types::fuel_types::BlockHeight;
+
+/// The activity of the transactions of the block.
+#[derive(SimpleObject)]
+pub struct BlockStatistics {
+    /// The number of transactions, including the `Mint` transaction.
+    pub tx_count: U64,
+    /// The gas used by the transactions.
+    pub gas_used: U64,
+    /// The number of distinct owners of the coins spent or created by the block.
+    pub active_addresses: U64,
+    /// The fees paid by the transactions, including the tips.
+    pub fees_paid: U64,
+    /// The part of the fees that is not minted to anyone.
+    pub fees_burned: U64,
+}
+
+impl From<BlockActivity> for BlockStatistics {
+    fn from(activity: BlockActivity) -> Self {
+        Self {
+            tx_count: activity.tx_count.into(),
+            gas_used: activity.gas_used.into(),
+            active_addresses: activity.active_addresses.into(),
+            fees_paid: activity.fees_paid.into(),
+            fees_burned: activity.fees_burned.into(),
+        }
+    }
+}
+
+/// The aggregated activity of the range of blocks.
+#[derive(SimpleObject)]
+pub struct ChainStatistics {
+    pub from_height: U32,
+    pub to_height: U32,
+    /// The number of transactions, including the `Mint` transactions.
+    pub tx_count: U64,
+    /// The gas used by the transactions.
+    pub gas_used: U64,
+    /// The number of addresses that were active for the first time within the range.
+    pub new_addresses: U64,
+    /// The number of distinct addresses that have ever been active up to the end of the range.
+    pub total_addresses: U64,
+    /// The fees paid by the transactions, including the tips.
+    pub fees_paid: U64,
+    /// The part of the fees that is not minted to anyone.
+    pub fees_burned: U64,
+}
+
+impl ChainStatistics {
+    fn new(
+        from: BlockHeight,
+        to: BlockHeight,
+        range: ChainTotals,
+        end: ChainTotals,
+    ) -> Self {
+        Self {
+            from_height: from.into(),
+            to_height: to.into(),
+            tx_count: range.tx_count.into(),
+            gas_used: range.gas_used.into(),
+            new_addresses: range.addresses.into(),
+            total_addresses: end.addresses.into(),
+            fees_paid: range.fees_paid.into(),
+            fees_burned: range.fees_burned.into(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct StatisticsQuery;
+
+#[Object]
+impl StatisticsQuery {
+    /// Returns the activity of the blocks from the `from` height up to and including
+    /// the `to` height. The aggregates are maintained by the node for every block,
+    /// so the cost of the query doesn't depend on the length of the range.
+    ///
+    /// Returns `null` if any of the ends of the range is not indexed yet.
+    async fn chain_statistics(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The height of the first block of the range")] from: U32,
+        #[graphql(desc = "The height of the last block of the range")] to: U32,
+    ) -> async_graphql::Result<Option<ChainStatistics>> {
+        let from: BlockHeight = from.into();
+        let to: BlockHeight = to.into();
+        if from > to {
+            return Err(
+                anyhow!("The start of the range {from} is above its end {to}").into(),
+            )
+        }
+
+        let query: &ReadView = ctx.data_unchecked();
+        let totals = query.chain_totals(&from).and_then(|_| {
+            let end = query.chain_totals(&to)?;
+            let before = match from.pred() {
+                Some(previous) => match query.chain_totals(&previous) {
+                    Ok(totals) => totals,
+                    Err(StorageError::NotFound(_, _)) => ChainTotals::default(),
+                    Err(err) => return Err(err),
+                },
+                None => ChainTotals::default(),
+            };
+            Ok((end.since(&before), end))
+        });
+        totals
+            .map(|(range, end)| ChainStatistics::new(from, to, range, end))
+            .into_api_result()
+    }
+}
@@ -0,0 +1,225 @@
+use crate::{
+    fuel_core_graphql_api::{
+        database::ReadView,
+        ports::OffChainDatabase,
+        storage::transfers::{
+            self,
+            OwnedTransferEventKey,
+        },
+        worker_service,
+    },
+    schema::scalars::{
+        Address,
+        AssetId,
+        TransactionId,
+        TransferEventCursor,
+        U32,
+        U64,
+    },
+};
+use async_graphql::{
+    connection::{
+        Connection,
+        EmptyFields,
+    },
+    Context,
+    Enum,
+    InputObject,
+    Object,
+    Subscription,
+};
+use fuel_core_types::fuel_types;
+use futures::{
+    stream,
+    Stream,
+    StreamExt,
+};
+use tokio_stream::wrappers::{
+    errors::BroadcastStreamRecvError,
+    BroadcastStream,
+};
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum TransferDirection {
+    Incoming,
+    Outgoing,
+}
+
+impl From<transfers::TransferDirection> for TransferDirection {
+    fn from(direction: transfers::TransferDirection) -> Self {
+        match direction {
+            transfers::TransferDirection::Incoming => TransferDirection::Incoming,
+            transfers::TransferDirection::Outgoing => TransferDirection::Outgoing,
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum TransferSource {
+    Coin,
+    Message,
+    MessageOut,
+}
+
+impl From<transfers::TransferSource> for TransferSource {
+    fn from(source: transfers::TransferSource) -> Self {
+        match source {
+            transfers::TransferSource::Coin => TransferSource::Coin,
+            transfers::TransferSource::Message => TransferSource::Message,
+            transfers::TransferSource::MessageOut => TransferSource::MessageOut,
+        }
+    }
+}
+
+pub struct TransferEvent(OwnedTransferEventKey, transfers::TransferEvent);
+
+#[Object]
+impl TransferEvent {
+    async fn owner(&self) -> Address {
+        self.0.owner.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.1.asset_id.into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.1.amount.into()
+    }
+
+    async fn direction(&self) -> TransferDirection {
+        self.1.direction.into()
+    }
+
+    /// The origin of the event. The `MESSAGE_OUT` events describe the withdrawals
+    /// to the DA layer. The withdrawn funds are already reflected by the `COIN` events
+    /// of the transaction, so they shouldn't be counted twice in the balance.
+    async fn source(&self) -> TransferSource {
+        self.1.source.into()
+    }
+
+    async fn block_height(&self) -> U32 {
+        self.0.block_height.into()
+    }
+
+    /// The transaction that moved the funds.
+    /// It is `null` for the messages imported from the DA layer.
+    async fn transaction_id(&self) -> Option<TransactionId> {
+        self.1.tx_id.map(Into::into)
+    }
+}
+
+#[derive(InputObject)]
+struct TransferEventFilterInput {
+    /// Returns the transfers of the owner.
+    owner: Address,
+    /// Returns only the transfers of the asset, if set.
+    asset_id: Option<AssetId>,
+}
+
+impl TransferEventFilterInput {
+    fn matches(
+        &self,
+        key: &OwnedTransferEventKey,
+        event: &transfers::TransferEvent,
+    ) -> bool {
+        key.owner == self.owner.0
+            && self
+                .asset_id
+                .map_or(true, |asset_id| event.asset_id == asset_id.0)
+    }
+}
+
+#[derive(Default)]
+pub struct TransferQuery;
+
+#[Object]
+impl TransferQuery {
+    /// Returns the transfers of the owner derived from the coins and messages created
+    /// or spent in the blocks, and from the `MessageOut` receipts, sorted by the block height.
+    async fn transfer_events(
+        &self,
+        ctx: &Context<'_>,
+        filter: TransferEventFilterInput,
+        first: Option<i32>,
+        after: Option<String>,
+        last: Option<i32>,
+        before: Option<String>,
+    ) -> async_graphql::Result<
+        Connection<TransferEventCursor, TransferEvent, EmptyFields, EmptyFields>,
+    > {
+        let query: &ReadView = ctx.data_unchecked();
+        let owner: fuel_types::Address = filter.owner.into();
+        crate::schema::query_pagination(
+            after,
+            before,
+            first,
+            last,
+            |start: &Option<TransferEventCursor>, direction| {
+                let start = start.map(|cursor| (cursor.block_height, cursor.event_idx));
+                let events = query
+                    .owned_transfer_events(owner, start, direction)
+                    .filter(move |result| match result {
+                        Ok((key, event)) => filter.matches(key, event),
+                        Err(_) => true,
+                    })
+                    .map(|result| {
+                        result.map(|(key, event)| {
+                            (
+                                TransferEventCursor::new(key.block_height, key.event_idx),
+                                TransferEvent(key, event),
+                            )
+                        })
+                    });
+                Ok(events)
+            },
+        )
+        .await
+    }
+}
+
+#[derive(Default)]
+pub struct TransferSubscription;
+
+#[Subscription]
+impl TransferSubscription {
+    /// Returns a stream of the transfers of the owner from the blocks imported
+    /// after the subscription.
+    ///
+    /// The stream ends with an error if it is polled slower than the blocks arrive.
+    /// In such a case, the missed transfers can be fetched with the `transferEvents` query.
+    async fn transfer_events<'a>(
+        &self,
+        ctx: &Context<'a>,
+        filter: TransferEventFilterInput,
+    ) -> impl Stream<Item = async_graphql::Result<TransferEvent>> + 'a {
+        let worker = ctx.data_unchecked::<worker_service::SharedState>();
+        let receiver = worker.subscribe_transfer_events();
+        let mut lagged = false;
+
+        BroadcastStream::new(receiver)
+            .map(move |result| match result {
+                Ok(events) => {
+                    let events = events
+                        .iter()
+                        .filter(|(key, event)| filter.matches(key, event))
+                        .map(|(key, event)| Ok(TransferEvent(*key, *event)))
+                        .collect::<Vec<_>>();
+                    stream::iter(events).left_stream()
+                }
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                    let error = anyhow::anyhow!(
+                        "The subscription skipped the transfers of {skipped} blocks"
+                    );
+                    stream::iter([Err(error.into())]).right_stream()
+                }
+            })
+            .flatten()
+            // Ends the stream after the error
+            .take_while(move |result| {
+                let should_continue = !lagged;
+                lagged = result.is_err();
+                futures::future::ready(should_continue)
+            })
+    }
+}
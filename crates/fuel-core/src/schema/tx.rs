@@ -1,5 +1,6 @@
 use crate::{
     fuel_core_graphql_api::{
+        api_error::ApiError,
         api_service::{
             BlockProducer,
             TxPool,
@@ -12,18 +13,26 @@ use crate::{
     query::{
         transaction_status_change,
         BlockQueryData,
-        SimpleTransactionData,
         TransactionQueryData,
     },
     schema::{
         scalars::{
             Address,
+            AssetId,
+            Bytes32,
+            ContractId,
             HexString,
+            Nonce,
             SortedTxCursor,
             TransactionId,
             TxPointer,
+            UtxoId,
+            U64,
+        },
+        tx::{
+            receipt::ReceiptsFilter,
+            types::TransactionStatus,
         },
-        tx::types::TransactionStatus,
     },
 };
 use async_graphql::{
@@ -45,9 +54,22 @@ use fuel_core_txpool::{
     txpool::TokioWithRayon,
 };
 use fuel_core_types::{
+    entities::coins::coin::Coin,
     fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+            Policies,
+            ScriptGasLimit,
+        },
+        policies::PolicyType,
         Cacheable,
+        Chargeable,
+        ConsensusParameters,
+        Output,
+        Receipt,
         Transaction as FuelTx,
+        TransactionFee,
         UniqueIdentifier,
     },
     fuel_types,
@@ -56,7 +78,17 @@ use fuel_core_types::{
         CheckPredicateParams,
         EstimatePredicates,
     },
-    services::txpool,
+    services::{
+        executor::{
+            ContractBalanceOverride,
+            ContractCodeOverride,
+            ContractSlotOverride,
+            StateOverrides,
+            TransactionExecutionResult,
+        },
+        graphql_api::ErrorCode,
+        txpool,
+    },
 };
 use futures::{
     Stream,
@@ -67,9 +99,17 @@ use std::{
     iter,
     sync::Arc,
 };
-use tokio_stream::StreamExt;
+use tokio_stream::{
+    wrappers::{
+        errors::BroadcastStreamRecvError,
+        BroadcastStream,
+    },
+    StreamExt,
+};
 use types::{
     DryRunTransactionExecutionStatus,
+    EvictedTransaction,
+    GasProfile,
     Transaction,
 };
 
@@ -96,12 +136,46 @@ impl TxQuery {
             Ok(Some(Transaction(transaction, id)))
         } else {
             query
-                .transaction(&id)
+                .cached_transaction(&id)
                 .map(|tx| Transaction::from_tx(id, tx))
                 .into_api_result()
         }
     }
 
+    /// Returns the transaction that spent the coin with the `utxo_id`.
+    async fn spending_transaction(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The UTXO ID of the spent coin")] utxo_id: UtxoId,
+    ) -> async_graphql::Result<Option<Transaction>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .spending_transaction(&utxo_id.0)
+            .and_then(|id| {
+                query
+                    .cached_transaction(&id)
+                    .map(|tx| Transaction::from_tx(id, tx))
+            })
+            .into_api_result()
+    }
+
+    /// Returns the transaction that consumed the message with the `nonce`.
+    async fn message_spending_transaction(
+        &self,
+        ctx: &Context<'_>,
+        #[graphql(desc = "The Nonce of the consumed message")] nonce: Nonce,
+    ) -> async_graphql::Result<Option<Transaction>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query
+            .message_spending_transaction(&nonce.0)
+            .and_then(|id| {
+                query
+                    .cached_transaction(&id)
+                    .map(|tx| Transaction::from_tx(id, tx))
+            })
+            .into_api_result()
+    }
+
     async fn transactions(
         &self,
         ctx: &Context<'_>,
@@ -151,7 +225,7 @@ impl TxQuery {
                     });
                 let all_txs = all_txs.map(|result: StorageResult<SortedTxCursor>| {
                     result.and_then(|sorted| {
-                        let tx = query.transaction(&sorted.tx_id.0)?;
+                        let tx = query.cached_transaction(&sorted.tx_id.0)?;
 
                         Ok((sorted, Transaction::from_tx(sorted.tx_id.0, tx)))
                     })
@@ -213,7 +287,9 @@ impl TxQuery {
             &config.consensus_parameters,
         ))
         .await
-        .map_err(|err| anyhow::anyhow!("{:?}", err))?;
+        .map_err(|err| {
+            ApiError::new(ErrorCode::InvalidTransaction, format!("{err:?}"))
+        })?;
 
         Ok(Transaction::from_tx(
             tx.id(&config.consensus_parameters.chain_id),
@@ -221,6 +297,153 @@ impl TxQuery {
         ))
     }
 
+    /// Estimates the predicate gas, the script gas limit and the max fee of the
+    /// provided transaction, and returns the transaction with these values set.
+    ///
+    /// The script gas limit is the gas used by the script during a dry run, and
+    /// the max fee is calculated at the current gas price. The returned transaction
+    /// is ready to be signed. Witnesses added after the estimation increase the size
+    /// of the transaction, so placeholders for signatures should be included beforehand.
+    async fn estimate_and_provision(
+        &self,
+        ctx: &Context<'_>,
+        tx: HexString,
+    ) -> async_graphql::Result<Transaction> {
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+        let config = ctx.data_unchecked::<Config>();
+        let params = &config.consensus_parameters;
+        let predicate_params = CheckPredicateParams::from(params);
+
+        let mut tx = FuelTx::from_bytes(&tx.0)?;
+        if tx.is_mint() {
+            return Err(ApiError::new(
+                ErrorCode::InvalidRequest,
+                "Mint transactions can't be provisioned",
+            )
+            .into())
+        }
+
+        tx.estimate_predicates_async::<TokioWithRayon>(&predicate_params)
+            .await
+            .map_err(|err| {
+                ApiError::new(ErrorCode::InvalidTransaction, format!("{err:?}"))
+            })?;
+
+        if let FuelTx::Script(script) = &mut tx {
+            // Allow the script to use all gas available to the transaction during the dry run,
+            // and use the whole spendable balance to cover the fee.
+            let min_gas = script.min_gas(params.gas_costs(), params.fee_params());
+            *script.script_gas_limit_mut() =
+                params.tx_params().max_gas_per_tx.saturating_sub(min_gas);
+            let spendable = spendable_base_asset(script, params.base_asset_id());
+            script
+                .policies_mut()
+                .set(PolicyType::MaxFee, Some(spendable));
+            script.precompute(&params.chain_id)?;
+
+            let mut results = block_producer
+                .dry_run_txs(
+                    vec![script.clone().into()],
+                    None,
+                    Some(false),
+                    Default::default(),
+                    false,
+                )
+                .await?;
+            let result = results
+                .pop()
+                .ok_or_else(|| anyhow::anyhow!("The dry run didn't return a status"))?;
+            let gas_used = match result.status.result {
+                TransactionExecutionResult::Success { receipts, .. } => receipts
+                    .iter()
+                    .find_map(|receipt| match receipt {
+                        Receipt::ScriptResult { gas_used, .. } => Some(*gas_used),
+                        _ => None,
+                    })
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("The dry run didn't produce a script result")
+                    })?,
+                TransactionExecutionResult::Failed {
+                    result, receipts, ..
+                } => {
+                    return Err(ApiError::new(
+                        ErrorCode::InvalidTransaction,
+                        format!(
+                            "The transaction failed during the estimation: {}",
+                            TransactionExecutionResult::reason(&receipts, &result)
+                        ),
+                    )
+                    .into())
+                }
+            };
+            *script.script_gas_limit_mut() = gas_used;
+
+            // The predicates may depend on the script gas limit.
+            script
+                .estimate_predicates_async::<TokioWithRayon>(&predicate_params)
+                .await
+                .map_err(|err| {
+                    ApiError::new(ErrorCode::InvalidTransaction, format!("{err:?}"))
+                })?;
+        }
+
+        match &mut tx {
+            FuelTx::Script(script) => provision_max_fee(script, params, config)?,
+            FuelTx::Create(create) => provision_max_fee(create, params, config)?,
+            FuelTx::Mint(_) => unreachable!("Mint transactions are rejected above"),
+        }
+
+        Ok(Transaction::from_tx(tx.id(&params.chain_id), tx))
+    }
+
+    /// Executes a dry-run of the transactions like `dryRun`, and returns the gas spent
+    /// by them, aggregated per contract and opcode. The instructions are executed one by
+    /// one, so it is slower than `dryRun`. Requires the node to be started with `--debug`.
+    async fn profile_gas(
+        &self,
+        ctx: &Context<'_>,
+        txs: Vec<HexString>,
+        utxo_validation: Option<bool>,
+        state_overrides: Option<StateOverridesInput>,
+    ) -> async_graphql::Result<GasProfile> {
+        let config = ctx.data_unchecked::<Config>();
+        if !config.debug {
+            return Err(ApiError::new(
+                ErrorCode::Forbidden,
+                "The gas profiling requires the `debug` mode",
+            )
+            .into())
+        }
+        let block_producer = ctx.data_unchecked::<BlockProducer>();
+
+        let mut transactions = txs
+            .iter()
+            .map(|tx| FuelTx::from_bytes(&tx.0))
+            .collect::<Result<Vec<FuelTx>, _>>()?;
+        for transaction in &mut transactions {
+            transaction.precompute(&config.consensus_parameters.chain_id)?;
+        }
+
+        let results = block_producer
+            .dry_run_txs(
+                transactions,
+                None,
+                utxo_validation,
+                state_overrides.map(Into::into).unwrap_or_default(),
+                true,
+            )
+            .await?;
+
+        let mut gas_profile = GasProfile::default();
+        for profile in results
+            .iter()
+            .filter_map(|result| result.gas_profile.as_ref())
+        {
+            gas_profile.add(profile);
+        }
+        Ok(gas_profile)
+    }
+
     #[cfg(feature = "test-helpers")]
     /// Returns all possible receipts for test purposes.
     async fn all_receipts(&self) -> Vec<receipt::Receipt> {
@@ -231,12 +454,102 @@ impl TxQuery {
     }
 }
 
+/// The changes of the state visible only to the transactions of the dry run.
+#[derive(async_graphql::InputObject)]
+pub struct StateOverridesInput {
+    /// The coins added to the state. They replace the coins with the same `utxo_id`.
+    #[graphql(default)]
+    coins: Vec<CoinOverrideInput>,
+    /// The balances of the contracts.
+    #[graphql(default)]
+    contract_balances: Vec<ContractBalanceOverrideInput>,
+    /// The storage slots of the contracts.
+    #[graphql(default)]
+    contract_slots: Vec<ContractSlotOverrideInput>,
+    /// The bytecode of the contracts. The contract is created if it doesn't exist.
+    #[graphql(default)]
+    contract_code: Vec<ContractCodeOverrideInput>,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct CoinOverrideInput {
+    utxo_id: UtxoId,
+    owner: Address,
+    amount: U64,
+    asset_id: AssetId,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct ContractBalanceOverrideInput {
+    contract_id: ContractId,
+    asset_id: AssetId,
+    amount: U64,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct ContractSlotOverrideInput {
+    contract_id: ContractId,
+    key: Bytes32,
+    value: Bytes32,
+}
+
+#[derive(async_graphql::InputObject)]
+pub struct ContractCodeOverrideInput {
+    contract_id: ContractId,
+    bytecode: HexString,
+}
+
+impl From<StateOverridesInput> for StateOverrides {
+    fn from(input: StateOverridesInput) -> Self {
+        Self {
+            coins: input
+                .coins
+                .into_iter()
+                .map(|coin| Coin {
+                    utxo_id: coin.utxo_id.into(),
+                    owner: coin.owner.into(),
+                    amount: coin.amount.into(),
+                    asset_id: coin.asset_id.into(),
+                    tx_pointer: Default::default(),
+                })
+                .collect(),
+            contract_balances: input
+                .contract_balances
+                .into_iter()
+                .map(|balance| ContractBalanceOverride {
+                    contract_id: balance.contract_id.into(),
+                    asset_id: balance.asset_id.into(),
+                    amount: balance.amount.into(),
+                })
+                .collect(),
+            contract_slots: input
+                .contract_slots
+                .into_iter()
+                .map(|slot| ContractSlotOverride {
+                    contract_id: slot.contract_id.into(),
+                    key: slot.key.into(),
+                    value: slot.value.into(),
+                })
+                .collect(),
+            contract_code: input
+                .contract_code
+                .into_iter()
+                .map(|code| ContractCodeOverride {
+                    contract_id: code.contract_id.into(),
+                    bytecode: code.bytecode.0,
+                })
+                .collect(),
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct TxMutation;
 
 #[Object]
 impl TxMutation {
     /// Execute a dry-run of multiple transactions using a fork of current state, no changes are committed.
+    /// The transactions are executed in order, and each of them sees the changes of the previous ones.
     async fn dry_run(
         &self,
         ctx: &Context<'_>,
@@ -245,6 +558,9 @@ impl TxMutation {
         // This allows for non-existent inputs to be used without signature validation
         // for read-only calls.
         utxo_validation: Option<bool>,
+        // The changes of the state applied only for the dry run, e.g. to simulate the
+        // transactions of the account without funding it.
+        state_overrides: Option<StateOverridesInput>,
     ) -> async_graphql::Result<Vec<DryRunTransactionExecutionStatus>> {
         let block_producer = ctx.data_unchecked::<BlockProducer>();
         let config = ctx.data_unchecked::<Config>();
@@ -257,10 +573,16 @@ impl TxMutation {
             transaction.precompute(&config.consensus_parameters.chain_id)?;
         }
 
-        let tx_statuses = block_producer
-            .dry_run_txs(transactions, None, utxo_validation)
+        let results = block_producer
+            .dry_run_txs(
+                transactions,
+                None,
+                utxo_validation,
+                state_overrides.map(Into::into).unwrap_or_default(),
+                false,
+            )
             .await?;
-        let tx_statuses = tx_statuses
+        let tx_statuses = results
             .into_iter()
             .map(DryRunTransactionExecutionStatus)
             .collect();
@@ -275,17 +597,29 @@ impl TxMutation {
         &self,
         ctx: &Context<'_>,
         tx: HexString,
+        #[graphql(
+            desc = "The hint ordering the pending transactions of the owner of the first coin \
+            or message input, like the nonce of the account. The transaction is included after \
+            the pending transactions of the owner with the lower hints. The hint is local to \
+            the node and isn't gossiped to the network."
+        )]
+        ordering_hint: Option<U64>,
     ) -> async_graphql::Result<Transaction> {
         let txpool = ctx.data_unchecked::<TxPool>();
         let config = ctx.data_unchecked::<Config>();
         let tx = FuelTx::from_bytes(&tx.0)?;
+        let id = tx.id(&config.consensus_parameters.chain_id);
+        let ordering_hint = ordering_hint.map(|hint| hint.0);
 
         let _: Vec<_> = txpool
-            .insert(vec![Arc::new(tx.clone())])
+            .insert_with_ordering_hints(vec![(Arc::new(tx.clone()), ordering_hint)])
             .await
             .into_iter()
-            .try_collect()?;
-        let id = tx.id(&config.consensus_parameters.chain_id);
+            .try_collect()
+            .map_err(|err| {
+                ApiError::from_anyhow(&err)
+                    .with_detail("transactionId", format!("{id:#x}"))
+            })?;
 
         let tx = Transaction(tx, id);
         Ok(tx)
@@ -313,6 +647,8 @@ impl TxStatusSubscription {
         &self,
         ctx: &Context<'a>,
         #[graphql(desc = "The ID of the transaction")] id: TransactionId,
+        #[graphql(desc = "Returns only the matching receipts with the final status")]
+        receipts_filter: Option<ReceiptsFilter>,
     ) -> anyhow::Result<impl Stream<Item = async_graphql::Result<TransactionStatus>> + 'a>
     {
         let txpool = ctx.data_unchecked::<TxPool>();
@@ -330,14 +666,45 @@ impl TxStatusSubscription {
             rx,
             id.into(),
         )
+        .map_ok(move |status| status.with_receipts_filter(receipts_filter.clone()))
         .map_err(async_graphql::Error::from))
     }
 
+    /// Returns a stream of the transactions evicted from the `TxPool` without
+    /// the inclusion into the block after the subscription.
+    ///
+    /// The stream ends with an error if it is polled slower than the evictions arrive.
+    async fn evicted_transactions<'a>(
+        &self,
+        ctx: &Context<'a>,
+    ) -> impl Stream<Item = async_graphql::Result<EvictedTransaction>> + 'a {
+        let txpool = ctx.data_unchecked::<TxPool>();
+        let receiver = txpool.evicted_tx_subscribe();
+        let mut lagged = false;
+
+        BroadcastStream::new(receiver)
+            .map(|result| match result {
+                Ok(evicted) => Ok(EvictedTransaction(evicted)),
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => Err(anyhow::anyhow!(
+                    "The subscription skipped {skipped} evicted transactions"
+                )
+                .into()),
+            })
+            // Ends the stream after the error
+            .take_while(move |result| {
+                let should_continue = !lagged;
+                lagged = result.is_err();
+                should_continue
+            })
+    }
+
     /// Submits transaction to the `TxPool` and await either confirmation or failure.
     async fn submit_and_await<'a>(
         &self,
         ctx: &Context<'a>,
         tx: HexString,
+        #[graphql(desc = "Returns only the matching receipts with the final status")]
+        receipts_filter: Option<ReceiptsFilter>,
     ) -> async_graphql::Result<
         impl Stream<Item = async_graphql::Result<TransactionStatus>> + 'a,
     > {
@@ -362,7 +729,8 @@ impl TxStatusSubscription {
             })
             .map(move |event| match event {
                 TxStatusMessage::Status(status) => {
-                    let status = TransactionStatus::new(tx_id, status);
+                    let status = TransactionStatus::new(tx_id, status)
+                        .with_receipts_filter(receipts_filter.clone());
                     Ok(status)
                 }
                 TxStatusMessage::FailedStatus => {
@@ -372,3 +740,48 @@ impl TxStatusSubscription {
             .take(1))
     }
 }
+
+/// Returns the amount of the base asset that the transaction can spend on the fee.
+fn spendable_base_asset<Tx>(tx: &Tx, base_asset_id: &fuel_types::AssetId) -> u64
+where
+    Tx: Inputs + Outputs,
+{
+    let inputs = tx
+        .inputs()
+        .iter()
+        .filter(|input| input.asset_id(base_asset_id) == Some(base_asset_id))
+        .filter_map(|input| input.amount())
+        .fold(0u64, |total, amount| total.saturating_add(amount));
+    let outputs = tx
+        .outputs()
+        .iter()
+        .filter_map(|output| match output {
+            Output::Coin {
+                amount, asset_id, ..
+            } if asset_id == base_asset_id => Some(*amount),
+            _ => None,
+        })
+        .fold(0u64, |total, amount| total.saturating_add(amount));
+    inputs.saturating_sub(outputs)
+}
+
+/// Sets the max fee of the transaction to the fee required at the current gas price.
+fn provision_max_fee<Tx>(
+    tx: &mut Tx,
+    params: &ConsensusParameters,
+    config: &Config,
+) -> anyhow::Result<()>
+where
+    Tx: Chargeable,
+{
+    let max_fee = TransactionFee::checked_from_tx(
+        params.gas_costs(),
+        params.fee_params(),
+        tx,
+        config.min_gas_price,
+    )
+    .ok_or_else(|| anyhow::anyhow!("The max fee of the transaction overflows"))?
+    .max_fee();
+    tx.policies_mut().set(PolicyType::MaxFee, Some(max_fee));
+    Ok(())
+}
@@ -9,6 +9,7 @@ use crate::schema::scalars::{
 };
 use async_graphql::{
     Enum,
+    InputObject,
     Object,
 };
 use fuel_core_types::{
@@ -159,6 +160,28 @@ impl From<fuel_tx::Receipt> for Receipt {
     }
 }
 
+/// Limits the receipts returned with the transaction status.
+#[derive(Clone, Debug, InputObject)]
+pub struct ReceiptsFilter {
+    /// Returns only the receipts emitted in the context of the contract, if set.
+    contract_id: Option<ContractId>,
+    /// Returns only the receipts of the types, if set.
+    receipt_types: Option<Vec<ReceiptType>>,
+}
+
+impl ReceiptsFilter {
+    pub fn matches(&self, receipt: &fuel_tx::Receipt) -> bool {
+        let contract_matches = self
+            .contract_id
+            .map_or(true, |contract_id| receipt.id() == Some(&contract_id.0));
+        let type_matches = self
+            .receipt_types
+            .as_ref()
+            .map_or(true, |types| types.contains(&receipt.into()));
+        contract_matches && type_matches
+    }
+}
+
 #[cfg(feature = "test-helpers")]
 pub fn all_receipts() -> Vec<fuel_tx::Receipt> {
     use strum::IntoEnumIterator;
@@ -1,12 +1,16 @@
 use super::{
     input::Input,
     output::Output,
-    receipt::Receipt,
+    receipt::{
+        Receipt,
+        ReceiptsFilter,
+    },
 };
 use crate::{
     fuel_core_graphql_api::{
         api_service::TxPool,
         database::ReadView,
+        ports::OffChainDatabase,
         Config,
         IntoApiResult,
     },
@@ -16,15 +20,19 @@ use crate::{
     },
     schema::{
         block::Block,
+        coins::Coin,
+        message::Message,
         scalars::{
             AssetId,
             Bytes32,
             ContractId,
             HexString,
+            Nonce,
             Salt,
             Tai64Timestamp,
             TransactionId,
             TxPointer,
+            UtxoId,
             U32,
             U64,
         },
@@ -69,18 +77,21 @@ use fuel_core_types::{
         Executable,
         TxId,
     },
+    fuel_types,
     fuel_types::canonical::Serialize,
     fuel_vm::ProgramState as VmProgramState,
     services::{
         executor::{
+            self,
+            DryRunResult,
             TransactionExecutionResult,
-            TransactionExecutionStatus,
         },
         txpool,
         txpool::TransactionStatus as TxStatus,
     },
     tai64::Tai64,
 };
+use std::collections::BTreeMap;
 
 pub struct ProgramState {
     return_type: ReturnType,
@@ -152,6 +163,7 @@ pub struct SuccessStatus {
     time: Tai64,
     result: Option<VmProgramState>,
     receipts: Vec<fuel_tx::Receipt>,
+    receipts_filter: Option<ReceiptsFilter>,
 }
 
 #[Object]
@@ -175,7 +187,30 @@ impl SuccessStatus {
     }
 
     async fn receipts(&self) -> async_graphql::Result<Vec<Receipt>> {
-        Ok(self.receipts.iter().map(Into::into).collect())
+        Ok(filtered_receipts(
+            &self.receipts,
+            self.receipts_filter.as_ref(),
+        ))
+    }
+
+    /// The fee charged for the transaction.
+    /// It is `null` for transactions executed before the node started indexing fees.
+    async fn fee(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<FeeBreakdown>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query.tx_fee(&self.tx_id).into_api_result()
+    }
+
+    /// The resources used by the VM to execute the transaction.
+    /// It is `null` if the node doesn't meter the VM.
+    async fn vm_metrics(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<VmMetrics>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query.tx_vm_metrics(&self.tx_id).into_api_result()
     }
 }
 
@@ -186,6 +221,7 @@ pub struct FailureStatus {
     time: Tai64,
     state: Option<VmProgramState>,
     receipts: Vec<fuel_tx::Receipt>,
+    receipts_filter: Option<ReceiptsFilter>,
 }
 
 #[Object]
@@ -213,7 +249,109 @@ impl FailureStatus {
     }
 
     async fn receipts(&self) -> async_graphql::Result<Vec<Receipt>> {
-        Ok(self.receipts.iter().map(Into::into).collect())
+        Ok(filtered_receipts(
+            &self.receipts,
+            self.receipts_filter.as_ref(),
+        ))
+    }
+
+    /// The fee charged for the transaction.
+    /// It is `null` for transactions executed before the node started indexing fees.
+    async fn fee(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<FeeBreakdown>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query.tx_fee(&self.tx_id).into_api_result()
+    }
+
+    /// The resources used by the VM to execute the transaction.
+    /// It is `null` if the node doesn't meter the VM.
+    async fn vm_metrics(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<Option<VmMetrics>> {
+        let query: &ReadView = ctx.data_unchecked();
+        query.tx_vm_metrics(&self.tx_id).into_api_result()
+    }
+}
+
+/// The fee charged for the transaction, split into its components.
+pub struct FeeBreakdown(executor::FeeBreakdown);
+
+#[Object]
+impl FeeBreakdown {
+    /// The total gas used by the transaction, including the intrinsic gas.
+    async fn gas_used(&self) -> U64 {
+        self.0.gas_used.into()
+    }
+
+    async fn gas_price(&self) -> U64 {
+        self.0.gas_price.into()
+    }
+
+    async fn tip(&self) -> U64 {
+        self.0.tip.into()
+    }
+
+    /// The fee charged for the used gas, without the tip.
+    async fn base_fee(&self) -> U64 {
+        self.0.base_fee.into()
+    }
+
+    /// The total fee charged for the transaction, `base_fee + tip`.
+    async fn total_fee(&self) -> U64 {
+        self.0.total_fee.into()
+    }
+
+    /// The unused part of the max fee returned to the change outputs.
+    async fn refunds(&self) -> Vec<ChangeRefund> {
+        self.0.refunds.iter().cloned().map(ChangeRefund).collect()
+    }
+}
+
+impl From<executor::FeeBreakdown> for FeeBreakdown {
+    fn from(fee: executor::FeeBreakdown) -> Self {
+        FeeBreakdown(fee)
+    }
+}
+
+/// The resources used by the VM to execute the transaction.
+pub struct VmMetrics(executor::VmMetrics);
+
+#[Object]
+impl VmMetrics {
+    /// The number of the instructions executed by the VM.
+    async fn instructions(&self) -> U64 {
+        self.0.instructions.into()
+    }
+
+    /// The highest number of bytes of the VM memory used by the stack and the heap.
+    async fn memory_watermark(&self) -> U64 {
+        self.0.memory_watermark.into()
+    }
+}
+
+impl From<executor::VmMetrics> for VmMetrics {
+    fn from(metrics: executor::VmMetrics) -> Self {
+        VmMetrics(metrics)
+    }
+}
+
+pub struct ChangeRefund(executor::ChangeRefund);
+
+#[Object]
+impl ChangeRefund {
+    async fn output_index(&self) -> u16 {
+        self.0.output_index
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+
+    async fn amount(&self) -> U64 {
+        self.0.amount.into()
     }
 }
 
@@ -229,6 +367,47 @@ impl SqueezedOutStatus {
     }
 }
 
+#[derive(Enum, Copy, Clone, Eq, PartialEq)]
+pub enum EvictionReason {
+    /// Replaced by the conflicting transaction with a higher tip.
+    Conflict,
+    /// Exceeded the time to live in the pool.
+    Expired,
+    /// Pushed out by the transaction with a higher tip because the pool is full.
+    LowFee,
+    /// Became invalid against the latest state of the chain.
+    Invalid,
+}
+
+impl From<txpool::EvictionReason> for EvictionReason {
+    fn from(reason: txpool::EvictionReason) -> Self {
+        match reason {
+            txpool::EvictionReason::Conflict => EvictionReason::Conflict,
+            txpool::EvictionReason::Expired => EvictionReason::Expired,
+            txpool::EvictionReason::LowFee => EvictionReason::LowFee,
+            txpool::EvictionReason::Invalid => EvictionReason::Invalid,
+        }
+    }
+}
+
+pub struct EvictedTransaction(pub(crate) txpool::EvictedTransaction);
+
+#[Object]
+impl EvictedTransaction {
+    async fn transaction_id(&self) -> TransactionId {
+        self.0.tx_id.into()
+    }
+
+    async fn reason(&self) -> EvictionReason {
+        self.0.reason.into()
+    }
+
+    /// The same description as in the `SqueezedOutStatus` of the transaction.
+    async fn message(&self) -> String {
+        self.0.message.clone()
+    }
+}
+
 impl TransactionStatus {
     pub fn new(tx_id: TxId, tx_status: TxStatus) -> Self {
         match tx_status {
@@ -246,6 +425,7 @@ impl TransactionStatus {
                 result,
                 time,
                 receipts,
+                receipts_filter: None,
             }),
             TxStatus::SqueezedOut { reason } => {
                 TransactionStatus::SqueezedOut(SqueezedOutStatus { reason })
@@ -261,9 +441,31 @@ impl TransactionStatus {
                 time,
                 state: result,
                 receipts,
+                receipts_filter: None,
             }),
         }
     }
+
+    /// Applies the filter to the receipts returned by the final status.
+    pub fn with_receipts_filter(mut self, filter: Option<ReceiptsFilter>) -> Self {
+        match &mut self {
+            TransactionStatus::Success(status) => status.receipts_filter = filter,
+            TransactionStatus::Failed(status) => status.receipts_filter = filter,
+            TransactionStatus::Submitted(_) | TransactionStatus::SqueezedOut(_) => {}
+        }
+        self
+    }
+}
+
+fn filtered_receipts(
+    receipts: &[fuel_tx::Receipt],
+    filter: Option<&ReceiptsFilter>,
+) -> Vec<Receipt> {
+    receipts
+        .iter()
+        .filter(|receipt| filter.map_or(true, |filter| filter.matches(receipt)))
+        .map(Into::into)
+        .collect()
 }
 
 impl From<TransactionStatus> for TxStatus {
@@ -607,12 +809,24 @@ pub enum DryRunTransactionStatus {
 impl DryRunTransactionStatus {
     pub fn new(tx_status: TransactionExecutionResult) -> Self {
         match tx_status {
-            TransactionExecutionResult::Success { result, receipts } => {
-                DryRunTransactionStatus::Success(DryRunSuccessStatus { result, receipts })
-            }
-            TransactionExecutionResult::Failed { result, receipts } => {
-                DryRunTransactionStatus::Failed(DryRunFailureStatus { result, receipts })
-            }
+            TransactionExecutionResult::Success {
+                result,
+                receipts,
+                fee,
+            } => DryRunTransactionStatus::Success(DryRunSuccessStatus {
+                result,
+                receipts,
+                fee,
+            }),
+            TransactionExecutionResult::Failed {
+                result,
+                receipts,
+                fee,
+            } => DryRunTransactionStatus::Failed(DryRunFailureStatus {
+                result,
+                receipts,
+                fee,
+            }),
         }
     }
 }
@@ -621,6 +835,7 @@ impl DryRunTransactionStatus {
 pub struct DryRunSuccessStatus {
     result: Option<VmProgramState>,
     receipts: Vec<fuel_tx::Receipt>,
+    fee: executor::FeeBreakdown,
 }
 
 #[Object]
@@ -632,12 +847,17 @@ impl DryRunSuccessStatus {
     async fn receipts(&self) -> Vec<Receipt> {
         self.receipts.iter().map(Into::into).collect()
     }
+
+    async fn fee(&self) -> FeeBreakdown {
+        self.fee.clone().into()
+    }
 }
 
 #[derive(Debug)]
 pub struct DryRunFailureStatus {
     result: Option<VmProgramState>,
     receipts: Vec<fuel_tx::Receipt>,
+    fee: executor::FeeBreakdown,
 }
 
 #[Object]
@@ -653,22 +873,312 @@ impl DryRunFailureStatus {
     async fn receipts(&self) -> Vec<Receipt> {
         self.receipts.iter().map(Into::into).collect()
     }
+
+    async fn fee(&self) -> FeeBreakdown {
+        self.fee.clone().into()
+    }
 }
 
-pub struct DryRunTransactionExecutionStatus(pub TransactionExecutionStatus);
+pub struct DryRunTransactionExecutionStatus(pub DryRunResult);
 
 #[Object]
 impl DryRunTransactionExecutionStatus {
     async fn id(&self) -> TransactionId {
-        TransactionId(self.0.id)
+        TransactionId(self.0.status.id)
     }
 
     async fn status(&self) -> DryRunTransactionStatus {
-        DryRunTransactionStatus::new(self.0.result.clone())
+        DryRunTransactionStatus::new(self.0.status.result.clone())
     }
 
     async fn receipts(&self) -> Vec<Receipt> {
-        self.0.result.receipts().iter().map(Into::into).collect()
+        self.0
+            .status
+            .result
+            .receipts()
+            .iter()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// The changes of the state made by the transaction.
+    async fn state_diff(&self) -> StateDiff {
+        StateDiff(self.0.state_diff.clone())
+    }
+
+    /// The state read or written by the transaction.
+    async fn access_list(&self) -> AccessList {
+        AccessList(self.0.access_list.clone())
+    }
+}
+
+pub struct StateDiff(executor::StateDiff);
+
+#[Object]
+impl StateDiff {
+    /// The coins spent by the transaction.
+    async fn consumed_coins(&self) -> Vec<Coin> {
+        self.0
+            .consumed_coins
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// The coins created by the transaction.
+    async fn created_coins(&self) -> Vec<Coin> {
+        self.0
+            .created_coins
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// The messages spent by the transaction.
+    async fn consumed_messages(&self) -> Vec<Message> {
+        self.0
+            .consumed_messages
+            .iter()
+            .cloned()
+            .map(Into::into)
+            .collect()
+    }
+
+    /// The contracts used by the transaction with their roots after the execution.
+    async fn contracts(&self) -> Vec<ContractStateDiff> {
+        self.0
+            .contracts
+            .iter()
+            .cloned()
+            .map(ContractStateDiff)
+            .collect()
+    }
+}
+
+pub struct ContractStateDiff(executor::ContractStateDiff);
+
+#[Object]
+impl ContractStateDiff {
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn balance_root(&self) -> Bytes32 {
+        self.0.balance_root.into()
+    }
+
+    async fn state_root(&self) -> Bytes32 {
+        self.0.state_root.into()
+    }
+}
+
+pub struct AccessList(executor::AccessList);
+
+#[Object]
+impl AccessList {
+    /// The contracts used by the transaction.
+    async fn contracts(&self) -> Vec<ContractId> {
+        self.0.contracts.iter().copied().map(Into::into).collect()
+    }
+
+    /// The storage slots read by the transaction.
+    async fn read_slots(&self) -> Vec<ContractSlot> {
+        self.0
+            .read_slots
+            .iter()
+            .copied()
+            .map(ContractSlot)
+            .collect()
+    }
+
+    /// The storage slots written by the transaction.
+    async fn written_slots(&self) -> Vec<ContractSlot> {
+        self.0
+            .written_slots
+            .iter()
+            .copied()
+            .map(ContractSlot)
+            .collect()
+    }
+
+    /// The balances of the contracts read or written by the transaction.
+    async fn contract_balances(&self) -> Vec<ContractAsset> {
+        self.0
+            .contract_balances
+            .iter()
+            .copied()
+            .map(ContractAsset)
+            .collect()
+    }
+
+    /// The coins spent or created by the transaction.
+    async fn coins(&self) -> Vec<UtxoId> {
+        self.0.coins.iter().copied().map(Into::into).collect()
+    }
+
+    /// The messages spent by the transaction.
+    async fn messages(&self) -> Vec<Nonce> {
+        self.0.messages.iter().copied().map(Into::into).collect()
+    }
+}
+
+pub struct ContractSlot(executor::ContractSlot);
+
+#[Object]
+impl ContractSlot {
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn key(&self) -> Bytes32 {
+        self.0.key.into()
+    }
+}
+
+pub struct ContractAsset(executor::ContractAsset);
+
+#[Object]
+impl ContractAsset {
+    async fn contract_id(&self) -> ContractId {
+        self.0.contract_id.into()
+    }
+
+    async fn asset_id(&self) -> AssetId {
+        self.0.asset_id.into()
+    }
+}
+
+/// The gas spent by the VM, aggregated per contract and opcode
+/// across all profiled transactions.
+#[derive(Default)]
+pub struct GasProfile(
+    BTreeMap<Option<fuel_types::ContractId>, BTreeMap<String, (u64, u64)>>,
+);
+
+impl GasProfile {
+    pub fn add(&mut self, profile: &executor::GasProfile) {
+        for entry in &profile.entries {
+            let (count, gas) = self
+                .0
+                .entry(entry.contract_id)
+                .or_default()
+                .entry(entry.opcode.clone())
+                .or_default();
+            *count = count.saturating_add(entry.count);
+            *gas = gas.saturating_add(entry.gas);
+        }
+    }
+}
+
+#[Object]
+impl GasProfile {
+    /// The total gas spent by the executed instructions.
+    async fn total_gas(&self) -> U64 {
+        self.0
+            .values()
+            .flat_map(BTreeMap::values)
+            .fold(0u64, |total, (_, gas)| total.saturating_add(*gas))
+            .into()
+    }
+
+    /// The gas spent per contract, sorted by the gas in descending order.
+    async fn contracts(&self) -> Vec<ContractGasProfile> {
+        let mut contracts: Vec<_> = self
+            .0
+            .iter()
+            .map(|(contract_id, opcodes)| ContractGasProfile {
+                contract_id: *contract_id,
+                opcodes: sorted_opcodes(opcodes.clone()),
+            })
+            .collect();
+        contracts.sort_by_key(|contract| std::cmp::Reverse(contract.total_gas()));
+        contracts
+    }
+
+    /// The gas spent per opcode by all contracts and scripts,
+    /// sorted by the gas in descending order.
+    async fn opcodes(&self) -> Vec<OpcodeGasProfile> {
+        let mut opcodes = BTreeMap::<String, (u64, u64)>::new();
+        for (opcode, (count, gas)) in self.0.values().flatten() {
+            let (total_count, total_gas) = opcodes.entry(opcode.clone()).or_default();
+            *total_count = total_count.saturating_add(*count);
+            *total_gas = total_gas.saturating_add(*gas);
+        }
+        sorted_opcodes(opcodes)
+    }
+}
+
+fn sorted_opcodes(opcodes: BTreeMap<String, (u64, u64)>) -> Vec<OpcodeGasProfile> {
+    let mut opcodes: Vec<_> = opcodes
+        .into_iter()
+        .map(|(opcode, (count, gas))| OpcodeGasProfile { opcode, count, gas })
+        .collect();
+    opcodes.sort_by_key(|opcode| std::cmp::Reverse(opcode.gas));
+    opcodes
+}
+
+pub struct ContractGasProfile {
+    contract_id: Option<fuel_types::ContractId>,
+    opcodes: Vec<OpcodeGasProfile>,
+}
+
+impl ContractGasProfile {
+    fn total_gas(&self) -> u64 {
+        self.opcodes
+            .iter()
+            .fold(0u64, |total, opcode| total.saturating_add(opcode.gas))
+    }
+}
+
+#[Object]
+impl ContractGasProfile {
+    /// The contract, or `null` for the instructions of the scripts.
+    async fn contract_id(&self) -> Option<ContractId> {
+        self.contract_id.map(Into::into)
+    }
+
+    /// The gas spent by the instructions of the contract.
+    async fn gas(&self) -> U64 {
+        self.total_gas().into()
+    }
+
+    /// The number of the executed instructions of the contract.
+    async fn instructions(&self) -> U64 {
+        self.opcodes
+            .iter()
+            .fold(0u64, |total, opcode| total.saturating_add(opcode.count))
+            .into()
+    }
+
+    /// The gas spent per opcode, sorted by the gas in descending order.
+    async fn opcodes(&self) -> &Vec<OpcodeGasProfile> {
+        &self.opcodes
+    }
+}
+
+pub struct OpcodeGasProfile {
+    opcode: String,
+    count: u64,
+    gas: u64,
+}
+
+#[Object]
+impl OpcodeGasProfile {
+    /// The name of the opcode, e.g. `CALL`.
+    async fn opcode(&self) -> &str {
+        &self.opcode
+    }
+
+    /// The number of the executed instructions.
+    async fn count(&self) -> U64 {
+        self.count.into()
+    }
+
+    /// The gas spent by the instructions.
+    async fn gas(&self) -> U64 {
+        self.gas.into()
     }
 }
 
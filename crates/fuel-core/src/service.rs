@@ -34,10 +34,15 @@ pub use config::{
 pub use fuel_core_services::Service as ServiceTrait;
 
 pub mod adapters;
+pub mod alerts;
 pub mod config;
 pub mod genesis;
+#[cfg(feature = "p2p")]
+pub mod localnet;
+pub mod memory;
 pub mod metrics;
 mod query;
+pub mod recovery;
 pub mod sub_services;
 
 #[derive(Clone)]
@@ -58,6 +63,9 @@ pub struct SharedState {
     >,
     /// The GraphQL shared state.
     pub graph_ql: crate::fuel_core_graphql_api::api_service::SharedState,
+    /// The gRPC shared state.
+    #[cfg(feature = "grpc")]
+    pub grpc: Option<crate::grpc_api::service::SharedState>,
     /// The underlying database.
     pub database: CombinedDatabase,
     /// Subscribe to new block production.
@@ -123,6 +131,9 @@ impl FuelService {
             #[cfg(not(feature = "rocksdb"))]
             _ => CombinedDatabase::in_memory(),
         };
+        let combined_database = combined_database
+            .with_contract_cache(config.contract_cache_size)
+            .with_coin_cache(config.coin_cache_size);
 
         Self::from_combined_database(combined_database, config).await
     }
@@ -209,6 +220,8 @@ pub struct Task {
     services: SubServices,
     /// The address bound by the system for serving the API
     pub shared: SharedState,
+    /// The marker of the database used by the node, removed on the clean shutdown.
+    running_marker: Option<recovery::RunningMarker>,
 }
 
 impl Task {
@@ -224,11 +237,16 @@ impl Task {
             .unwrap_or_default();
         let da_block_height = 0u64.into();
         database.init(&block_height, &da_block_height)?;
+        let running_marker = recovery::audit_on_startup(&config, &database)?;
 
         // initialize sub services
         tracing::info!("Initializing sub services");
         let (services, shared) = sub_services::init_sub_services(&config, database)?;
-        Ok(Task { services, shared })
+        Ok(Task {
+            services,
+            shared,
+            running_marker,
+        })
     }
 
     #[cfg(test)]
@@ -255,6 +273,7 @@ impl RunnableService for Task {
     ) -> anyhow::Result<Self::Task> {
         let on_view = self.shared.database.on_chain().latest_view();
         let off_view = self.shared.database.off_chain().latest_view();
+        let relayer_view = self.shared.database.relayer().latest_view();
         // check if chain is initialized
         if let Err(err) = on_view.get_genesis() {
             if err.is_not_found() {
@@ -267,6 +286,12 @@ impl RunnableService for Task {
                     &off_view,
                 )?;
                 off_chain_db_transaction.commit()?;
+
+                let relayer_db_transaction = genesis::relayer::execute_genesis_block(
+                    &self.shared.config,
+                    &relayer_view,
+                )?;
+                relayer_db_transaction.commit()?;
             }
         }
 
@@ -311,6 +336,9 @@ impl RunnableTask for Task {
             }
         }
         self.shared.database.flush()?;
+        if let Some(running_marker) = self.running_marker {
+            running_marker.remove()?;
+        }
         Ok(())
     }
 }
@@ -354,9 +382,15 @@ mod tests {
             i += 1;
         }
 
-        // current services: graphql, graphql worker, txpool, PoA
+        // current services: graphql, graphql worker, txpool
         #[allow(unused_mut)]
-        let mut expected_services = 4;
+        let mut expected_services = 3;
+
+        #[cfg(feature = "producer")]
+        {
+            // PoA
+            expected_services += 1;
+        }
 
         // Relayer service is disabled with `Config::local_node`.
         // #[cfg(feature = "relayer")]
@@ -1,15 +1,29 @@
 use crate::{
+    combined_database::CombinedDatabase,
     database::{
-        database_description::relayer::Relayer,
+        database_description::{
+            off_chain::OffChain,
+            relayer::Relayer,
+        },
         Database,
     },
-    service::sub_services::BlockProducerService,
+    fuel_core_graphql_api::{
+        entity_cache::EntityCache,
+        scheduler::RequestScheduler,
+    },
+    service::alerts::AlertReporter,
 };
+use executor::ProductionDeadline;
 use fuel_core_consensus_module::{
     block_verifier::Verifier,
     RelayerConsensusConfig,
 };
 use fuel_core_executor::executor::Executor;
+use fuel_core_importer::event_bus::{
+    BlockCommitted,
+    ImporterEvent,
+    StateDiffAvailable,
+};
 use fuel_core_services::stream::BoxStream;
 use fuel_core_txpool::service::SharedState as TxPoolSharedState;
 #[cfg(feature = "p2p")]
@@ -18,15 +32,24 @@ use fuel_core_types::{
     fuel_types::BlockHeight,
     services::block_importer::SharedImportResult,
 };
-use std::sync::Arc;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::Instant,
+};
 
+pub mod alerts;
 pub mod block_importer;
 pub mod consensus_module;
 pub mod executor;
 pub mod graphql_api;
+pub mod memory;
 #[cfg(feature = "p2p")]
 pub mod p2p;
+#[cfg(feature = "producer")]
 pub mod producer;
+#[cfg(feature = "shared-sequencer")]
+pub mod shared_sequencer;
 #[cfg(feature = "p2p")]
 pub mod sync;
 pub mod txpool;
@@ -34,6 +57,8 @@ pub mod txpool;
 #[derive(Clone)]
 pub struct PoAAdapter {
     shared_state: Option<fuel_core_poa::service::SharedState>,
+    /// The file persisting the pause of the block production, if any.
+    pause_path: Option<PathBuf>,
 }
 
 #[derive(Clone)]
@@ -51,16 +76,20 @@ impl TxPoolAdapter {
 pub struct TransactionsSource {
     txpool: TxPoolSharedState<P2PAdapter, Database>,
     _block_height: BlockHeight,
+    deadline: Option<Arc<ProductionDeadline>>,
 }
 
 impl TransactionsSource {
     pub fn new(
         txpool: TxPoolSharedState<P2PAdapter, Database>,
         block_height: BlockHeight,
+        deadline: Option<Instant>,
     ) -> Self {
         Self {
             txpool,
             _block_height: block_height,
+            deadline: deadline
+                .map(|deadline| Arc::new(ProductionDeadline::new(deadline))),
         }
     }
 }
@@ -68,6 +97,8 @@ impl TransactionsSource {
 #[derive(Clone)]
 pub struct ExecutorAdapter {
     pub executor: Arc<Executor<Database, Database<Relayer>>>,
+    /// Throttles the GraphQL requests while the block is executed.
+    pub request_scheduler: RequestScheduler,
 }
 
 impl ExecutorAdapter {
@@ -75,6 +106,7 @@ impl ExecutorAdapter {
         database: Database,
         relayer_database: Database<Relayer>,
         config: fuel_core_executor::Config,
+        request_scheduler: RequestScheduler,
     ) -> Self {
         let executor = Executor {
             database_view_provider: database,
@@ -83,6 +115,7 @@ impl ExecutorAdapter {
         };
         Self {
             executor: Arc::new(executor),
+            request_scheduler,
         }
     }
 }
@@ -97,6 +130,10 @@ pub struct ConsensusAdapter {
     pub block_verifier: Arc<Verifier<Database>>,
     pub config: RelayerConsensusConfig,
     pub maybe_relayer: MaybeRelayerAdapter,
+    /// Stores the equivocation proofs of the authority.
+    pub database: Database<OffChain>,
+    /// Alerts about the conflicting blocks.
+    pub alerts: AlertReporter,
 }
 
 impl ConsensusAdapter {
@@ -104,11 +141,15 @@ impl ConsensusAdapter {
         block_verifier: VerifierAdapter,
         config: RelayerConsensusConfig,
         maybe_relayer: MaybeRelayerAdapter,
+        database: Database<OffChain>,
+        alerts: AlertReporter,
     ) -> Self {
         Self {
             block_verifier: block_verifier.block_verifier,
             config,
             maybe_relayer,
+            database,
+            alerts,
         }
     }
 }
@@ -121,9 +162,37 @@ pub struct MaybeRelayerAdapter {
     pub da_deploy_height: fuel_core_types::blockchain::primitives::DaBlockHeight,
 }
 
+/// The state of the node watched by the alerts.
+#[derive(Clone)]
+pub struct AlertsAdapter {
+    pub poa: PoAAdapter,
+    pub relayer: MaybeRelayerAdapter,
+}
+
+/// The subsystems of the node with the memory accounted by the memory accountant.
+#[derive(Clone)]
+pub struct MemoryAdapter {
+    pub database: CombinedDatabase,
+    pub txpool: TxPoolAdapter,
+    pub entity_cache: Arc<EntityCache>,
+}
+
+#[cfg(feature = "producer")]
 #[derive(Clone)]
 pub struct BlockProducerAdapter {
-    pub block_producer: Arc<BlockProducerService>,
+    pub block_producer: Arc<crate::service::sub_services::BlockProducerService>,
+}
+
+/// The block producer is compiled out, so the node only follows the chain.
+#[cfg(not(feature = "producer"))]
+#[derive(Default, Clone)]
+pub struct BlockProducerAdapter;
+
+#[cfg(not(feature = "producer"))]
+impl BlockProducerAdapter {
+    pub fn new() -> Self {
+        Default::default()
+    }
 }
 
 #[derive(Clone)]
@@ -133,12 +202,26 @@ pub struct BlockImporterAdapter {
 }
 
 impl BlockImporterAdapter {
+    /// Returns the stream of the committed blocks. The importer doesn't commit
+    /// the next block until the received block is dropped.
     pub fn events(&self) -> BoxStream<SharedImportResult> {
-        use futures::StreamExt;
-        fuel_core_services::stream::IntoBoxStream::into_boxed(
-            tokio_stream::wrappers::BroadcastStream::new(self.block_importer.subscribe())
-                .filter_map(|r| futures::future::ready(r.ok())),
-        )
+        self.subscribe::<BlockCommitted>()
+    }
+
+    /// Returns the stream of the changes of the UTXO state made by the committed blocks.
+    pub fn state_diffs(&self) -> BoxStream<StateDiffAvailable> {
+        self.subscribe::<StateDiffAvailable>()
+    }
+
+    fn subscribe<E: ImporterEvent>(&self) -> BoxStream<E> {
+        let subscription = self.block_importer.subscribe::<E>();
+        fuel_core_services::stream::IntoBoxStream::into_boxed(futures::stream::unfold(
+            subscription,
+            |mut subscription| async move {
+                let event = subscription.recv().await?;
+                Some((event, subscription))
+            },
+        ))
     }
 }
 
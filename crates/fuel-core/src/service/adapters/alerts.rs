@@ -0,0 +1,27 @@
+use super::AlertsAdapter;
+use crate::service::alerts::AlertsPort;
+use fuel_core_types::blockchain::primitives::DaBlockHeight;
+
+impl AlertsPort for AlertsAdapter {
+    fn is_producing_blocks(&self) -> bool {
+        self.poa
+            .shared_state
+            .as_ref()
+            .map(|poa| poa.paused().is_none())
+            .unwrap_or(false)
+    }
+
+    fn da_height(&self) -> Option<DaBlockHeight> {
+        #[cfg(feature = "relayer")]
+        {
+            self.relayer
+                .relayer_synced
+                .as_ref()
+                .and_then(|relayer| relayer.get_finalized_da_height().ok())
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            None
+        }
+    }
+}
@@ -68,6 +68,21 @@ impl BlockImporterAdapter {
         self.block_importer.execute_and_commit(sealed_block).await?;
         Ok(())
     }
+
+    pub async fn execute_and_commit_pipelined(
+        &self,
+        sealed_block: SealedBlock,
+    ) -> anyhow::Result<()> {
+        self.block_importer
+            .execute_and_commit_pipelined(sealed_block)
+            .await?;
+        Ok(())
+    }
+
+    pub async fn await_pending_commit(&self) -> anyhow::Result<()> {
+        self.block_importer.await_pending_commit().await?;
+        Ok(())
+    }
 }
 
 impl BlockVerifier for VerifierAdapter {
@@ -129,4 +144,24 @@ impl Executor for ExecutorAdapter {
             block,
         ))
     }
+
+    fn pending_view(&self, uncommitted: &Database) -> StorageResult<Database> {
+        self.executor
+            .database_view_provider
+            .with_pending_changes(uncommitted)
+    }
+
+    fn execute_without_commit_on(
+        &self,
+        view: Database,
+        block: Block,
+    ) -> ExecutorResult<UncommittedExecutionResult<StorageTransaction<Self::Database>>>
+    {
+        let _execution = self.request_scheduler.execution();
+        self.executor
+            .execute_without_commit_on::<TransactionsSource>(
+                view,
+                ExecutionTypes::Validation(block),
+            )
+    }
 }
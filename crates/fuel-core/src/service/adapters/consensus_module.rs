@@ -13,8 +13,8 @@ use fuel_core_consensus_module::block_verifier::{
     Verifier,
 };
 use fuel_core_poa::ports::RelayerPort;
-use fuel_core_producer::ports::BlockProducerDatabase;
 use fuel_core_storage::{
+    not_found,
     tables::FuelBlocks,
     Result as StorageResult,
     StorageAsRef,
@@ -23,6 +23,7 @@ use fuel_core_types::{
     blockchain::{
         header::BlockHeader,
         primitives::DaBlockHeight,
+        SealedBlockHeader,
     },
     fuel_tx::Bytes32,
     fuel_types::BlockHeight,
@@ -33,21 +34,33 @@ pub mod poa;
 
 impl VerifierAdapter {
     pub fn new(config: &Config, database: Database) -> Self {
-        let config = VerifierConfig::new(config.chain_conf.clone());
+        let mut verifier_config = VerifierConfig::new(config.chain_conf.clone());
+        verifier_config.block_time_schedule = config.block_time_schedule;
         Self {
-            block_verifier: Arc::new(Verifier::new(config, database)),
+            block_verifier: Arc::new(Verifier::new(verifier_config, database)),
         }
     }
 }
 
 impl fuel_core_poa::ports::Database for Database {
     fn block_header(&self, height: &BlockHeight) -> StorageResult<BlockHeader> {
-        Ok(self.get_block(height)?.header().clone())
+        let block = self
+            .storage::<FuelBlocks>()
+            .get(height)?
+            .ok_or(not_found!(FuelBlocks))?;
+        Ok(block.header().clone())
     }
 
     fn block_header_merkle_root(&self, height: &BlockHeight) -> StorageResult<Bytes32> {
         self.storage::<FuelBlocks>().root(height).map(Into::into)
     }
+
+    fn sealed_block_header(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<SealedBlockHeader>> {
+        self.get_sealed_block_header(height)
+    }
 }
 
 #[async_trait::async_trait]
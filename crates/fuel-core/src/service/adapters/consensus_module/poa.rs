@@ -3,52 +3,60 @@ use crate::{
     fuel_core_graphql_api::ports::ConsensusModulePort,
     service::adapters::{
         BlockImporterAdapter,
-        BlockProducerAdapter,
         P2PAdapter,
         PoAAdapter,
         TxPoolAdapter,
     },
 };
-use anyhow::anyhow;
+use anyhow::{
+    anyhow,
+    Context,
+};
 use fuel_core_poa::{
     ports::{
         BlockImporter,
         P2pPort,
         TransactionPool,
-        TransactionsSource,
     },
     service::{
         Mode,
+        PauseReason,
+        Paused,
         SharedState,
     },
 };
 use fuel_core_services::stream::BoxStream;
 use fuel_core_storage::transactional::StorageTransaction;
 use fuel_core_types::{
-    fuel_asm::Word,
     fuel_tx::TxId,
-    fuel_types::BlockHeight,
     services::{
         block_importer::{
             BlockImportInfo,
             UncommittedResult as UncommittedImporterResult,
         },
-        executor::{
-            Error as ExecutorError,
-            UncommittedResult,
-        },
+        executor::Error as ExecutorError,
         txpool::ArcPoolTx,
     },
     tai64::Tai64,
 };
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
 use tokio_stream::{
     wrappers::BroadcastStream,
     StreamExt,
 };
 
 impl PoAAdapter {
-    pub fn new(shared_state: Option<SharedState>) -> Self {
-        Self { shared_state }
+    pub fn new(shared_state: Option<SharedState>, pause_path: Option<PathBuf>) -> Self {
+        Self {
+            shared_state,
+            pause_path,
+        }
     }
 
     pub async fn manually_produce_blocks(
@@ -56,12 +64,68 @@ impl PoAAdapter {
         start_time: Option<Tai64>,
         mode: Mode,
     ) -> anyhow::Result<()> {
-        self.shared_state
-            .as_ref()
-            .ok_or(anyhow!("The block production is disabled"))?
+        self.shared_state()?
             .manually_produce_block(start_time, mode)
             .await
     }
+
+    fn shared_state(&self) -> anyhow::Result<&SharedState> {
+        self.shared_state
+            .as_ref()
+            .ok_or(anyhow!("The block production is disabled"))
+    }
+}
+
+/// Restores the pause of the block production from the file at the `path`.
+/// The block production is not paused if the file doesn't exist.
+pub fn load_block_production_pause(
+    path: Option<&Path>,
+) -> anyhow::Result<Option<Paused>> {
+    let Some(path) = path.filter(|path| path.exists()) else {
+        return Ok(None)
+    };
+    let file = std::fs::read(path).with_context(|| {
+        format!(
+            "Failed to read the block production pause from {}",
+            path.display()
+        )
+    })?;
+    let paused = serde_json::from_slice(&file).with_context(|| {
+        format!(
+            "Failed to parse the block production pause from {}",
+            path.display()
+        )
+    })?;
+    Ok(Some(paused))
+}
+
+/// Stores the pause of the block production to the file at the `path`, or removes
+/// the file if the block production is resumed. The new file is renamed over
+/// the old one, so the crash doesn't leave the file partially written.
+fn store_block_production_pause(
+    path: Option<&Path>,
+    paused: Option<&Paused>,
+) -> anyhow::Result<()> {
+    let Some(path) = path else { return Ok(()) };
+    let result = match paused {
+        Some(paused) => {
+            let tmp_path = path.with_extension("tmp");
+            serde_json::to_vec_pretty(paused)
+                .map_err(std::io::Error::from)
+                .and_then(|content| std::fs::write(&tmp_path, content))
+                .and_then(|_| std::fs::rename(&tmp_path, path))
+        }
+        None => match std::fs::remove_file(path) {
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        },
+    };
+    result.with_context(|| {
+        format!(
+            "Failed to store the block production pause to {}",
+            path.display()
+        )
+    })
 }
 
 #[async_trait::async_trait]
@@ -70,10 +134,44 @@ impl ConsensusModulePort for PoAAdapter {
         &self,
         start_time: Option<Tai64>,
         number_of_blocks: u32,
+        interval: Option<Duration>,
     ) -> anyhow::Result<()> {
-        self.manually_produce_blocks(start_time, Mode::Blocks { number_of_blocks })
+        self.manually_produce_blocks(
+            start_time,
+            Mode::Blocks {
+                number_of_blocks,
+                interval,
+            },
+        )
+        .await
+    }
+
+    async fn set_next_block_time(&self, time: Tai64) -> anyhow::Result<()> {
+        self.shared_state()?.set_next_block_time(time).await
+    }
+
+    async fn pause_block_production(&self, reason: PauseReason) -> anyhow::Result<()> {
+        let shared_state = self.shared_state()?;
+        shared_state.pause(reason).await?;
+        store_block_production_pause(
+            self.pause_path.as_deref(),
+            shared_state.paused().as_ref(),
+        )
+    }
+
+    async fn resume_block_production(&self) -> anyhow::Result<()> {
+        self.shared_state()?.resume().await?;
+        store_block_production_pause(self.pause_path.as_deref(), None)
+    }
+
+    async fn produce_heartbeat_block(&self) -> anyhow::Result<()> {
+        self.manually_produce_blocks(None, Mode::HeartbeatBlock)
             .await
     }
+
+    fn block_production_paused(&self) -> Option<Paused> {
+        self.shared_state.as_ref().and_then(SharedState::paused)
+    }
 }
 
 impl TransactionPool for TxPoolAdapter {
@@ -101,34 +199,6 @@ impl TransactionPool for TxPoolAdapter {
     }
 }
 
-#[async_trait::async_trait]
-impl fuel_core_poa::ports::BlockProducer for BlockProducerAdapter {
-    type Database = Database;
-
-    async fn produce_and_execute_block(
-        &self,
-        height: BlockHeight,
-        block_time: Tai64,
-        source: TransactionsSource,
-        max_gas: Word,
-    ) -> anyhow::Result<UncommittedResult<StorageTransaction<Database>>> {
-        match source {
-            TransactionsSource::TxPool => {
-                self.block_producer
-                    .produce_and_execute_block_txpool(height, block_time, max_gas)
-                    .await
-            }
-            TransactionsSource::SpecificTransactions(txs) => {
-                self.block_producer
-                    .produce_and_execute_block_transactions(
-                        height, block_time, txs, max_gas,
-                    )
-                    .await
-            }
-        }
-    }
-}
-
 #[async_trait::async_trait]
 impl BlockImporter for BlockImporterAdapter {
     type Database = Database;
@@ -144,11 +214,7 @@ impl BlockImporter for BlockImporterAdapter {
     }
 
     fn block_stream(&self) -> BoxStream<BlockImportInfo> {
-        Box::pin(
-            BroadcastStream::new(self.block_importer.subscribe())
-                .filter_map(|result| result.ok())
-                .map(BlockImportInfo::from),
-        )
+        Box::pin(self.events().map(BlockImportInfo::from))
     }
 }
 
@@ -18,17 +18,32 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    fuel_tx,
     services::{
-        block_producer::Components,
         executor::{
             Result as ExecutorResult,
-            TransactionExecutionStatus,
             UncommittedResult,
         },
         relayer::Event,
     },
 };
+#[cfg(feature = "producer")]
+use fuel_core_types::{
+    fuel_tx,
+    services::{
+        block_producer::Components,
+        executor::{
+            DryRunResult,
+            StateOverrides,
+        },
+    },
+};
+use std::{
+    sync::Mutex,
+    time::{
+        Duration,
+        Instant,
+    },
+};
 
 impl fuel_core_executor::ports::TransactionsSource for TransactionsSource {
     fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction> {
@@ -38,6 +53,66 @@ impl fuel_core_executor::ports::TransactionsSource for TransactionsSource {
             .map(|tx| MaybeCheckedTransaction::CheckedTransaction(tx.as_ref().into()))
             .collect()
     }
+
+    fn is_exhausted(&self) -> bool {
+        match &self.deadline {
+            Some(deadline) => deadline.is_exhausted_at(Instant::now()),
+            None => false,
+        }
+    }
+}
+
+/// The wall-clock budget of the block production.
+///
+/// The executor checks the budget before each transaction, so the time between
+/// the checks is the execution time of the previous transaction. The budget is
+/// nearly exhausted when the slowest transaction of the block so far doesn't fit
+/// into the time left before the deadline.
+pub struct ProductionDeadline {
+    deadline: Instant,
+    timings: Mutex<Timings>,
+}
+
+#[derive(Default)]
+struct Timings {
+    last_check: Option<Instant>,
+    slowest_transaction: Duration,
+    exhausted: bool,
+}
+
+impl ProductionDeadline {
+    pub fn new(deadline: Instant) -> Self {
+        Self {
+            deadline,
+            timings: Mutex::new(Timings::default()),
+        }
+    }
+
+    fn is_exhausted_at(&self, now: Instant) -> bool {
+        let mut timings = self.timings.lock().expect("poisoned");
+        if timings.exhausted {
+            return true
+        }
+        if let Some(last_check) = timings.last_check {
+            timings.slowest_transaction = timings
+                .slowest_transaction
+                .max(now.saturating_duration_since(last_check));
+        }
+        timings.last_check = Some(now);
+
+        let exhausted = match now.checked_add(timings.slowest_transaction) {
+            Some(finish) => finish >= self.deadline,
+            None => true,
+        };
+        if exhausted {
+            tracing::warn!(
+                "The block production budget is exhausted, the remaining \
+                transactions are left for the next block"
+            );
+            timings.exhausted = true;
+        }
+        exhausted
+    }
 }
 
 impl ExecutorAdapter {
@@ -48,15 +123,20 @@ impl ExecutorAdapter {
     where
         TxSource: fuel_core_executor::ports::TransactionsSource,
     {
+        let _execution = self.request_scheduler.execution();
         self.executor.execute_without_commit(block)
     }
 
+    #[cfg(feature = "producer")]
     pub(crate) fn _dry_run(
         &self,
         block: Components<Vec<fuel_tx::Transaction>>,
         utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
-        self.executor.dry_run(block, utxo_validation)
+        state_overrides: StateOverrides,
+        profile_gas: bool,
+    ) -> ExecutorResult<Vec<DryRunResult>> {
+        self.executor
+            .dry_run(block, utxo_validation, state_overrides, profile_gas)
     }
 }
 
@@ -96,3 +176,39 @@ impl fuel_core_executor::ports::RelayerPort for Database<Relayer> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deadline_is_exhausted_when_slowest_transaction_does_not_fit() {
+        // Given
+        let start = Instant::now();
+        let deadline = ProductionDeadline::new(start + Duration::from_millis(100));
+        assert!(!deadline.is_exhausted_at(start));
+        // The first transaction takes 30 milliseconds.
+        assert!(!deadline.is_exhausted_at(start + Duration::from_millis(30)));
+
+        // When
+        // The second transaction takes 40 milliseconds, and only 30 milliseconds are left.
+        let exhausted = deadline.is_exhausted_at(start + Duration::from_millis(70));
+
+        // Then
+        assert!(exhausted);
+    }
+
+    #[test]
+    fn deadline_stays_exhausted() {
+        // Given
+        let start = Instant::now();
+        let deadline = ProductionDeadline::new(start + Duration::from_millis(10));
+        assert!(deadline.is_exhausted_at(start + Duration::from_millis(10)));
+
+        // When
+        let exhausted = deadline.is_exhausted_at(start);
+
+        // Then
+        assert!(exhausted);
+    }
+}
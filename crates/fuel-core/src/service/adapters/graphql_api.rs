@@ -3,12 +3,21 @@ use super::{
     BlockProducerAdapter,
 };
 use crate::{
-    database::Database,
+    combined_database::{
+        CombinedDatabase,
+        DatabaseStatistics,
+    },
+    database::{
+        database_description::relayer::Relayer,
+        Database,
+    },
     fuel_core_graphql_api::ports::{
         worker,
         BlockProducerPort,
         DatabaseMessageProof,
+        DatabaseStatisticsPort,
         P2pPort,
+        RelayerPort,
         TxPoolPort,
     },
     service::adapters::{
@@ -22,6 +31,7 @@ use fuel_core_storage::Result as StorageResult;
 use fuel_core_txpool::{
     service::TxStatusMessage,
     types::TxId,
+    TxInfo,
 };
 use fuel_core_types::{
     entities::message::MerkleProof,
@@ -29,12 +39,21 @@ use fuel_core_types::{
         Bytes32,
         Transaction,
     },
-    fuel_types::BlockHeight,
+    fuel_types::{
+        Address,
+        BlockHeight,
+        Nonce,
+    },
     services::{
         block_importer::SharedImportResult,
-        executor::TransactionExecutionStatus,
+        executor::{
+            DryRunResult,
+            StateOverrides,
+        },
         p2p::PeerInfo,
+        relayer::MessageOrigin,
         txpool::{
+            EvictedTransaction,
             InsertionResult,
             TransactionStatus,
         },
@@ -45,9 +64,13 @@ use std::{
     ops::Deref,
     sync::Arc,
 };
+use tokio::sync::broadcast;
 
 mod off_chain;
 mod on_chain;
+mod receipts_archive;
+
+pub use receipts_archive::ReceiptsArchiveAdapter;
 
 #[async_trait]
 impl TxPoolPort for TxPoolAdapter {
@@ -63,6 +86,15 @@ impl TxPoolPort for TxPoolAdapter {
             .map(|info| Tai64::from_unix(info.submitted_time().as_secs() as i64))
     }
 
+    fn pending_transactions(&self) -> Vec<TxInfo> {
+        self.service.pending_transactions()
+    }
+
+    fn pending_chain(&self, owner: Address) -> Vec<TxInfo> {
+        self.service.pending_chain(&owner)
+    }
+
+    #[tracing::instrument(skip_all, fields(tx_count = txs.len()))]
     async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
@@ -75,12 +107,29 @@ impl TxPoolPort for TxPoolAdapter {
             .collect()
     }
 
+    #[tracing::instrument(skip_all, fields(tx_count = txs.len()))]
+    async fn insert_with_ordering_hints(
+        &self,
+        txs: Vec<(Arc<Transaction>, Option<u64>)>,
+    ) -> Vec<anyhow::Result<InsertionResult>> {
+        self.service
+            .insert_with_ordering_hints(txs)
+            .await
+            .into_iter()
+            .map(|res| res.map_err(anyhow::Error::from))
+            .collect()
+    }
+
     fn tx_update_subscribe(
         &self,
         id: TxId,
     ) -> anyhow::Result<BoxStream<TxStatusMessage>> {
         self.service.tx_update_subscribe(id)
     }
+
+    fn evicted_tx_subscribe(&self) -> broadcast::Receiver<EvictedTransaction> {
+        self.service.evicted_tx_subscribe()
+    }
 }
 
 impl DatabaseMessageProof for Database {
@@ -100,10 +149,34 @@ impl BlockProducerPort for BlockProducerAdapter {
         transactions: Vec<Transaction>,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
-    ) -> anyhow::Result<Vec<TransactionExecutionStatus>> {
-        self.block_producer
-            .dry_run(transactions, height, utxo_validation)
-            .await
+        state_overrides: StateOverrides,
+        profile_gas: bool,
+    ) -> anyhow::Result<Vec<DryRunResult>> {
+        #[cfg(feature = "producer")]
+        {
+            self.block_producer
+                .dry_run(
+                    transactions,
+                    height,
+                    utxo_validation,
+                    state_overrides,
+                    profile_gas,
+                )
+                .await
+        }
+        #[cfg(not(feature = "producer"))]
+        {
+            let _ = (
+                transactions,
+                height,
+                utxo_validation,
+                state_overrides,
+                profile_gas,
+            );
+            Err(anyhow::anyhow!(
+                "The dry run requires the block producer, which isn't included in the node"
+            ))
+        }
     }
 }
 
@@ -145,6 +218,31 @@ impl P2pPort for P2PAdapter {
     }
 }
 
+impl DatabaseStatisticsPort for CombinedDatabase {
+    fn database_statistics(&self) -> anyhow::Result<Vec<DatabaseStatistics>> {
+        Ok(self.statistics()?)
+    }
+}
+
+impl RelayerPort for Database<Relayer> {
+    fn message_origin(&self, nonce: &Nonce) -> StorageResult<Option<MessageOrigin>> {
+        #[cfg(feature = "relayer")]
+        {
+            use fuel_core_storage::StorageAsRef;
+            let origin = self
+                .storage::<fuel_core_relayer::storage::MessageOrigins>()
+                .get(nonce)?
+                .map(|origin| origin.into_owned());
+            Ok(origin)
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            let _ = nonce;
+            Ok(None)
+        }
+    }
+}
+
 impl worker::BlockImporter for BlockImporterAdapter {
     fn block_events(&self) -> BoxStream<SharedImportResult> {
         self.events()
@@ -3,12 +3,51 @@ use crate::{
         database_description::off_chain::OffChain,
         Database,
     },
+    fingerprint::NodeFingerprint,
     fuel_core_graphql_api::{
+        message_outbox,
         ports::{
             worker,
+            ContractVerificationPort,
             OffChainDatabase,
         },
-        storage::transactions::OwnedTransactionIndexCursor,
+        storage::{
+            assets::{
+                AssetDetails,
+                AssetsInfo,
+            },
+            blocks::BlockProducerFingerprints,
+            contracts::{
+                ContractDeployment,
+                ContractsDeployments,
+                ContractsVerifications,
+            },
+            equivocations::EquivocationProofs,
+            fees::{
+                BlockFeeReport,
+                BlockFees,
+                TransactionFees,
+            },
+            statistics::{
+                BlockActivity,
+                BlockStatistics,
+                ChainStatistics,
+                ChainTotals,
+            },
+            transactions::{
+                MessageSpendingTransactions,
+                OwnedTransactionIndexCursor,
+                PrunableReceipts,
+                SpendingTransactions,
+                TransactionVmMetrics,
+            },
+            transfers::{
+                OwnedTransferEventKey,
+                OwnedTransferEvents,
+                TransferEvent,
+                TransferEventIndex,
+            },
+        },
     },
 };
 use fuel_core_storage::{
@@ -20,10 +59,16 @@ use fuel_core_storage::{
     not_found,
     Error as StorageError,
     Result as StorageResult,
+    StorageAsMut,
+    StorageAsRef,
 };
 use fuel_core_txpool::types::TxId;
 use fuel_core_types::{
-    blockchain::primitives::BlockId,
+    blockchain::{
+        consensus::poa::EquivocationProof,
+        primitives::BlockId,
+    },
+    entities::message::MerkleProof,
     fuel_tx::{
         Address,
         Bytes32,
@@ -31,11 +76,21 @@ use fuel_core_types::{
         UtxoId,
     },
     fuel_types::{
+        AssetId,
         BlockHeight,
+        ContractId,
+        MessageId,
         Nonce,
     },
-    services::txpool::TransactionStatus,
+    services::{
+        executor::{
+            FeeBreakdown,
+            VmMetrics,
+        },
+        txpool::TransactionStatus,
+    },
 };
+use std::borrow::Cow;
 
 impl OffChainDatabase for Database<OffChain> {
     fn block_height(&self, id: &BlockId) -> StorageResult<BlockHeight> {
@@ -43,12 +98,113 @@ impl OffChainDatabase for Database<OffChain> {
             .and_then(|height| height.ok_or(not_found!("BlockHeight")))
     }
 
+    fn block_fees(&self, height: &BlockHeight) -> StorageResult<BlockFeeReport> {
+        self.storage::<BlockFees>()
+            .get(height)?
+            .map(Cow::into_owned)
+            .ok_or(not_found!(BlockFees))
+    }
+
+    fn block_producer_fingerprint(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<NodeFingerprint> {
+        self.storage::<BlockProducerFingerprints>()
+            .get(height)?
+            .map(Cow::into_owned)
+            .ok_or(not_found!(BlockProducerFingerprints))
+    }
+
+    fn message_outbox_proof(
+        &self,
+        height: &BlockHeight,
+        message_id: &MessageId,
+    ) -> StorageResult<Option<MerkleProof>> {
+        message_outbox::outbox_proof(self, *height, message_id)
+    }
+
     fn tx_status(&self, tx_id: &TxId) -> StorageResult<TransactionStatus> {
         self.get_tx_status(tx_id)
             .transpose()
             .ok_or(not_found!("TransactionId"))?
     }
 
+    fn tx_fee(&self, tx_id: &TxId) -> StorageResult<FeeBreakdown> {
+        self.storage::<TransactionFees>()
+            .get(tx_id)?
+            .map(Cow::into_owned)
+            .ok_or(not_found!(TransactionFees))
+    }
+
+    fn tx_vm_metrics(&self, tx_id: &TxId) -> StorageResult<VmMetrics> {
+        self.storage::<TransactionVmMetrics>()
+            .get(tx_id)?
+            .map(Cow::into_owned)
+            .ok_or(not_found!(TransactionVmMetrics))
+    }
+
+    fn spending_transaction(&self, utxo_id: &UtxoId) -> StorageResult<TxId> {
+        self.storage::<SpendingTransactions>()
+            .get(utxo_id)?
+            .map(Cow::into_owned)
+            .ok_or(not_found!(SpendingTransactions))
+    }
+
+    fn message_spending_transaction(&self, nonce: &Nonce) -> StorageResult<TxId> {
+        self.storage::<MessageSpendingTransactions>()
+            .get(nonce)?
+            .map(Cow::into_owned)
+            .ok_or(not_found!(MessageSpendingTransactions))
+    }
+
+    fn block_statistics(&self, height: &BlockHeight) -> StorageResult<BlockActivity> {
+        self.storage::<BlockStatistics>()
+            .get(height)?
+            .map(Cow::into_owned)
+            .ok_or(not_found!(BlockStatistics))
+    }
+
+    fn chain_totals(&self, height: &BlockHeight) -> StorageResult<ChainTotals> {
+        self.storage::<ChainStatistics>()
+            .get(height)?
+            .map(Cow::into_owned)
+            .ok_or(not_found!(ChainStatistics))
+    }
+
+    fn asset_info(&self, asset_id: &AssetId) -> StorageResult<AssetDetails> {
+        self.storage::<AssetsInfo>()
+            .get(asset_id)?
+            .map(Cow::into_owned)
+            .ok_or(not_found!(AssetsInfo))
+    }
+
+    fn contract_deployment(
+        &self,
+        contract_id: &ContractId,
+    ) -> StorageResult<ContractDeployment> {
+        self.storage::<ContractsDeployments>()
+            .get(contract_id)?
+            .map(Cow::into_owned)
+            .ok_or(not_found!(ContractsDeployments))
+    }
+
+    fn contract_verification(&self, contract_id: &ContractId) -> StorageResult<Vec<u8>> {
+        self.storage::<ContractsVerifications>()
+            .get(contract_id)?
+            .map(Cow::into_owned)
+            .ok_or(not_found!(ContractsVerifications))
+    }
+
+    fn equivocations(
+        &self,
+        height: Option<BlockHeight>,
+        direction: IterDirection,
+    ) -> BoxedIter<'_, StorageResult<EquivocationProof>> {
+        self.iter_all_by_start::<EquivocationProofs>(height.as_ref(), Some(direction))
+            .map(|result| result.map(|(_, proof)| proof))
+            .into_boxed()
+    }
+
     fn owned_coins_ids(
         &self,
         owner: &Address,
@@ -85,6 +241,36 @@ impl OffChainDatabase for Database<OffChain> {
             .map(|result| result.map_err(StorageError::from))
             .into_boxed()
     }
+
+    fn owned_transfer_events(
+        &self,
+        owner: Address,
+        start: Option<(BlockHeight, TransferEventIndex)>,
+        direction: IterDirection,
+    ) -> BoxedIter<StorageResult<(OwnedTransferEventKey, TransferEvent)>> {
+        let start = start.map(|(block_height, event_idx)| {
+            OwnedTransferEventKey::new(owner, block_height, event_idx)
+        });
+        self.iter_all_filtered::<OwnedTransferEvents, _>(
+            Some(owner),
+            start.as_ref(),
+            Some(direction),
+        )
+        .into_boxed()
+    }
+}
+
+impl ContractVerificationPort for Database<OffChain> {
+    fn set_contract_verification(
+        &self,
+        contract_id: &ContractId,
+        metadata: &[u8],
+    ) -> anyhow::Result<()> {
+        self.clone()
+            .storage_as_mut::<ContractsVerifications>()
+            .insert(contract_id, metadata)?;
+        Ok(())
+    }
 }
 
 impl worker::OffChainDatabase for Database<OffChain> {
@@ -106,6 +292,23 @@ impl worker::OffChainDatabase for Database<OffChain> {
         Database::update_tx_status(self, id, status)
     }
 
+    fn get_tx_status(&self, id: &Bytes32) -> StorageResult<Option<TransactionStatus>> {
+        Database::get_tx_status(self, id)
+    }
+
+    fn prunable_receipts(
+        &self,
+        height: BlockHeight,
+    ) -> StorageResult<Vec<(BlockHeight, Vec<Bytes32>)>> {
+        self.iter_all_by_start::<PrunableReceipts>(None, Some(IterDirection::Forward))
+            .take_while(|result| {
+                result
+                    .as_ref()
+                    .map_or(true, |(block_height, _)| *block_height <= height)
+            })
+            .collect()
+    }
+
     fn increase_tx_count(&mut self, new_txs_count: u64) -> StorageResult<u64> {
         Database::increase_tx_count(self, new_txs_count)
     }
@@ -0,0 +1,121 @@
+use crate::fuel_core_graphql_api::{
+    ports::worker::ReceiptsArchive,
+    receipts_pruning::ReceiptsArchiveSink,
+};
+use anyhow::Context;
+use fuel_core_types::{
+    fuel_tx::{
+        Bytes32,
+        Receipt,
+    },
+    fuel_types::BlockHeight,
+};
+use std::time::Duration;
+
+/// The receipts of the transactions of one block in the archive.
+#[derive(serde::Serialize)]
+struct ArchivedReceipts<'a> {
+    height: BlockHeight,
+    transactions: Vec<ArchivedTransaction<'a>>,
+}
+
+#[derive(serde::Serialize)]
+struct ArchivedTransaction<'a> {
+    id: Bytes32,
+    receipts: &'a [Receipt],
+}
+
+/// The time limit of one upload to the HTTP endpoint.
+const UPLOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Writes the pruned receipts into the local directory or sends them to the HTTP endpoint.
+pub struct ReceiptsArchiveAdapter {
+    sink: ReceiptsArchiveSink,
+    client: reqwest::Client,
+}
+
+impl ReceiptsArchiveAdapter {
+    pub fn new(sink: ReceiptsArchiveSink) -> Self {
+        Self {
+            sink,
+            client: reqwest::Client::builder()
+                .timeout(UPLOAD_TIMEOUT)
+                .build()
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReceiptsArchive for ReceiptsArchiveAdapter {
+    async fn archive(
+        &self,
+        height: BlockHeight,
+        receipts: &[(Bytes32, Vec<Receipt>)],
+    ) -> anyhow::Result<()> {
+        let document = ArchivedReceipts {
+            height,
+            transactions: receipts
+                .iter()
+                .map(|(id, receipts)| ArchivedTransaction { id: *id, receipts })
+                .collect(),
+        };
+        let bytes = serde_json::to_vec(&document)?;
+        let name = format!("{:010}", *height);
+
+        match &self.sink {
+            ReceiptsArchiveSink::Directory(directory) => {
+                let path = directory.join(format!("{name}.json"));
+                let tmp = directory.join(format!("{name}.json.tmp"));
+                tokio::task::spawn_blocking(move || {
+                    // Write into the temporary file first to not leave the partial
+                    // document if the node is stopped in the middle.
+                    std::fs::write(&tmp, bytes)
+                        .and_then(|_| std::fs::rename(&tmp, &path))
+                        .with_context(|| format!("Failed to write {}", path.display()))
+                })
+                .await?
+            }
+            ReceiptsArchiveSink::Url(url) => {
+                let url = format!("{url}/{name}");
+                self.client
+                    .put(&url)
+                    .header(reqwest::header::CONTENT_TYPE, "application/json")
+                    .body(bytes)
+                    .send()
+                    .await?
+                    .error_for_status()
+                    .with_context(|| format!("Failed to upload {url}"))?;
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn archive_writes_one_document_per_block() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let archive = ReceiptsArchiveAdapter::new(ReceiptsArchiveSink::Directory(
+            dir.path().into(),
+        ));
+        let receipts = vec![(
+            Bytes32::from([1; 32]),
+            vec![Receipt::ret(Default::default(), 1, 2, 3)],
+        )];
+
+        // When
+        archive.archive(7u32.into(), &receipts).await.unwrap();
+
+        // Then
+        let bytes = std::fs::read(dir.path().join("0000000007.json")).unwrap();
+        let document: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(document["height"], 7);
+        assert_eq!(document["transactions"].as_array().unwrap().len(), 1);
+        assert!(!dir.path().join("0000000007.json.tmp").exists());
+    }
+}
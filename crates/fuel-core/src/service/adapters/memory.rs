@@ -0,0 +1,18 @@
+use super::MemoryAdapter;
+use crate::service::memory::{
+    MemoryPort,
+    Subsystem,
+};
+
+impl MemoryPort for MemoryAdapter {
+    fn usage(&self) -> anyhow::Result<Vec<(Subsystem, usize)>> {
+        let database = self.database.memory_usage()?;
+        Ok(vec![
+            (Subsystem::StorageEngine, database.storage_engine),
+            (Subsystem::ContractCache, database.contract_cache),
+            (Subsystem::CoinCache, database.coin_cache),
+            (Subsystem::EntityCache, self.entity_cache.size()),
+            (Subsystem::TxPool, self.txpool.service.pending_bytes()),
+        ])
+    }
+}
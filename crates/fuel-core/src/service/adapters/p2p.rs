@@ -38,13 +38,9 @@ impl P2pDb for Database {
 
 impl BlockHeightImporter for BlockImporterAdapter {
     fn next_block_height(&self) -> BoxStream<BlockHeight> {
-        use tokio_stream::{
-            wrappers::BroadcastStream,
-            StreamExt,
-        };
+        use tokio_stream::StreamExt;
         Box::pin(
-            BroadcastStream::new(self.block_importer.subscribe())
-                .filter_map(|result| result.ok())
+            self.events()
                 .map(|result| *result.sealed_block.entity.header().height()),
         )
     }
@@ -25,6 +25,7 @@ use fuel_core_types::{
         block::CompressedBlock,
         primitives,
     },
+    fuel_asm::Word,
     fuel_tx,
     fuel_tx::Transaction,
     fuel_types::{
@@ -34,16 +35,19 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            DryRunResult,
             ExecutionTypes,
             Result as ExecutorResult,
-            TransactionExecutionStatus,
+            StateOverrides,
             UncommittedResult,
         },
     },
+    tai64::Tai64,
 };
 use std::{
     borrow::Cow,
     sync::Arc,
+    time::Instant,
 };
 
 impl BlockProducerAdapter {
@@ -54,12 +58,44 @@ impl BlockProducerAdapter {
     }
 }
 
+#[async_trait::async_trait]
+impl fuel_core_poa::ports::BlockProducer for BlockProducerAdapter {
+    type Database = Database;
+
+    async fn produce_and_execute_block(
+        &self,
+        height: BlockHeight,
+        block_time: Tai64,
+        source: fuel_core_poa::ports::TransactionsSource,
+        max_gas: Word,
+    ) -> anyhow::Result<UncommittedResult<StorageTransaction<Database>>> {
+        match source {
+            fuel_core_poa::ports::TransactionsSource::TxPool => {
+                self.block_producer
+                    .produce_and_execute_block_txpool(height, block_time, max_gas)
+                    .await
+            }
+            fuel_core_poa::ports::TransactionsSource::SpecificTransactions(txs) => {
+                self.block_producer
+                    .produce_and_execute_block_transactions(
+                        height, block_time, txs, max_gas,
+                    )
+                    .await
+            }
+        }
+    }
+}
+
 #[async_trait::async_trait]
 impl TxPool for TxPoolAdapter {
     type TxSource = TransactionsSource;
 
-    fn get_source(&self, block_height: BlockHeight) -> Self::TxSource {
-        TransactionsSource::new(self.service.clone(), block_height)
+    fn get_source(
+        &self,
+        block_height: BlockHeight,
+        deadline: Option<Instant>,
+    ) -> Self::TxSource {
+        TransactionsSource::new(self.service.clone(), block_height, deadline)
     }
 }
 
@@ -101,8 +137,10 @@ impl fuel_core_producer::ports::DryRunner for ExecutorAdapter {
         &self,
         block: Components<Vec<fuel_tx::Transaction>>,
         utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
-        self._dry_run(block, utxo_validation)
+        state_overrides: StateOverrides,
+        profile_gas: bool,
+    ) -> ExecutorResult<Vec<DryRunResult>> {
+        self._dry_run(block, utxo_validation, state_overrides, profile_gas)
     }
 }
 
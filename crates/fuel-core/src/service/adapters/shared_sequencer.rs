@@ -0,0 +1,62 @@
+use crate::{
+    database::{
+        database_description::off_chain::OffChain,
+        Database,
+    },
+    service::adapters::BlockImporterAdapter,
+};
+use fuel_core_services::stream::BoxStream;
+use fuel_core_shared_sequencer::ports::{
+    BlockImporter,
+    Database as PublisherDatabase,
+};
+use fuel_core_types::{
+    blockchain::SealedBlockHeader,
+    fuel_types::BlockHeight,
+    services::block_importer::SharedImportResult,
+};
+
+impl BlockImporter for BlockImporterAdapter {
+    fn block_events(&self) -> BoxStream<SharedImportResult> {
+        self.events()
+    }
+}
+
+/// Reads the blocks from the on-chain database and stores
+/// the progress of the publisher in the off-chain database.
+pub struct SharedSequencerDatabase {
+    on_chain: Database,
+    off_chain: Database<OffChain>,
+}
+
+impl SharedSequencerDatabase {
+    pub fn new(on_chain: Database, off_chain: Database<OffChain>) -> Self {
+        Self {
+            on_chain,
+            off_chain,
+        }
+    }
+}
+
+impl PublisherDatabase for SharedSequencerDatabase {
+    fn latest_height(&self) -> anyhow::Result<BlockHeight> {
+        Ok(self.on_chain.latest_height()?)
+    }
+
+    fn sealed_block_header(
+        &self,
+        height: &BlockHeight,
+    ) -> anyhow::Result<Option<SealedBlockHeader>> {
+        Ok(self.on_chain.get_sealed_block_header(height)?)
+    }
+
+    fn published_height(&self) -> anyhow::Result<Option<BlockHeight>> {
+        Ok(self.off_chain.shared_sequencer_published_height()?)
+    }
+
+    fn set_published_height(&mut self, height: &BlockHeight) -> anyhow::Result<()> {
+        Ok(self
+            .off_chain
+            .set_shared_sequencer_published_height(height)?)
+    }
+}
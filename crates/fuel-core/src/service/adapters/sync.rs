@@ -3,9 +3,24 @@ use super::{
     ConsensusAdapter,
     P2PAdapter,
 };
+use crate::{
+    era::{
+        self,
+        Era,
+        EraManifest,
+        EraSource,
+    },
+    fuel_core_graphql_api::storage::equivocations::EquivocationProofs,
+};
+use anyhow::Context;
 use fuel_core_poa::ports::RelayerPort;
 use fuel_core_services::stream::BoxStream;
+use fuel_core_storage::{
+    StorageAsMut,
+    StorageAsRef,
+};
 use fuel_core_sync::ports::{
+    BlockArchivePort,
     BlockImporterPort,
     ConsensusPort,
     PeerReportReason,
@@ -13,6 +28,7 @@ use fuel_core_sync::ports::{
 };
 use fuel_core_types::{
     blockchain::{
+        consensus::poa::EquivocationProof,
         primitives::DaBlockHeight,
         SealedBlock,
         SealedBlockHeader,
@@ -29,6 +45,7 @@ use fuel_core_types::{
     },
 };
 use std::ops::Range;
+use tokio::sync::OnceCell;
 
 #[async_trait::async_trait]
 impl PeerToPeerPort for P2PAdapter {
@@ -130,16 +147,16 @@ impl BlockImporterPort for BlockImporterAdapter {
     fn committed_height_stream(&self) -> BoxStream<BlockHeight> {
         use futures::StreamExt;
         fuel_core_services::stream::IntoBoxStream::into_boxed(
-            tokio_stream::wrappers::BroadcastStream::new(self.block_importer.subscribe())
-                .filter_map(|r| {
-                    futures::future::ready(
-                        r.ok().map(|r| *r.sealed_block.entity.header().height()),
-                    )
-                }),
+            self.events()
+                .map(|result| *result.sealed_block.entity.header().height()),
         )
     }
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()> {
-        self.execute_and_commit(block).await
+        self.execute_and_commit_pipelined(block).await
+    }
+
+    async fn await_pending_commit(&self) -> anyhow::Result<()> {
+        self.await_pending_commit().await
     }
 }
 
@@ -148,6 +165,25 @@ impl ConsensusPort for ConsensusAdapter {
     fn check_sealed_header(&self, header: &SealedBlockHeader) -> anyhow::Result<bool> {
         Ok(self.block_verifier.verify_consensus(header))
     }
+    fn check_equivocation(
+        &self,
+        header: &SealedBlockHeader,
+    ) -> anyhow::Result<Option<EquivocationProof>> {
+        let proof = self.block_verifier.detect_equivocation(header)?;
+        if let Some(proof) = &proof {
+            self.alerts.conflicting_block(proof.height());
+            let mut database = self.database.clone();
+            if !database
+                .storage::<EquivocationProofs>()
+                .contains_key(&proof.height())?
+            {
+                database
+                    .storage_as_mut::<EquivocationProofs>()
+                    .insert(&proof.height(), proof)?;
+            }
+        }
+        Ok(proof)
+    }
     async fn await_da_height(&self, da_height: &DaBlockHeight) -> anyhow::Result<()> {
         tokio::time::timeout(
             self.config.max_wait_time,
@@ -157,3 +193,89 @@ impl ConsensusPort for ConsensusAdapter {
         .await?
     }
 }
+
+/// Reads the sealed blocks from the era files in the local directory or on the HTTP server.
+pub struct EraArchive {
+    source: EraSource,
+    client: reqwest::Client,
+    manifest: OnceCell<EraManifest>,
+}
+
+impl EraArchive {
+    pub fn new(source: EraSource) -> Self {
+        Self {
+            source,
+            client: reqwest::Client::new(),
+            manifest: OnceCell::new(),
+        }
+    }
+
+    async fn read(&self, file: &str) -> anyhow::Result<Vec<u8>> {
+        match &self.source {
+            EraSource::Directory(directory) => {
+                let path = directory.join(file);
+                tokio::task::spawn_blocking(move || {
+                    std::fs::read(&path)
+                        .with_context(|| format!("Failed to read {}", path.display()))
+                })
+                .await?
+            }
+            EraSource::Url(url) => {
+                let url = format!("{url}/{file}");
+                let response = self
+                    .client
+                    .get(&url)
+                    .send()
+                    .await?
+                    .error_for_status()
+                    .with_context(|| format!("Failed to download {url}"))?;
+                Ok(response.bytes().await?.to_vec())
+            }
+        }
+    }
+
+    async fn manifest(&self) -> anyhow::Result<&EraManifest> {
+        self.manifest
+            .get_or_try_init(|| async {
+                let bytes = self.read(era::MANIFEST_FILE).await?;
+                let manifest: EraManifest = serde_json::from_slice(&bytes)
+                    .context("Failed to decode the manifest of the era archive")?;
+                Ok(manifest)
+            })
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockArchivePort for EraArchive {
+    async fn get_blocks(&self, height: BlockHeight) -> anyhow::Result<Vec<SealedBlock>> {
+        let Some(entry) = self.manifest().await?.era_of(height).cloned() else {
+            return Ok(vec![])
+        };
+        tracing::info!("Importing the era {} from {}", entry.file, self.source);
+
+        let bytes = self.read(&entry.file).await?;
+        anyhow::ensure!(
+            era::checksum(&bytes) == entry.checksum,
+            "The checksum of the era {} doesn't match the manifest",
+            entry.file
+        );
+        let era = Era::decode(bytes)?;
+        anyhow::ensure!(
+            era.start() == entry.start,
+            "The era {} starts at the height {} instead of {}",
+            entry.file,
+            era.start(),
+            entry.start
+        );
+
+        let mut blocks = vec![];
+        let mut next = height;
+        while let Some(block) = era.block(next)? {
+            blocks.push(block);
+            let Some(succ) = next.succ() else { break };
+            next = succ;
+        }
+        Ok(blocks)
+    }
+}
@@ -0,0 +1,602 @@
+//! The alerts of the node operator about the critical events.
+//!
+//! The alerts are posted as JSON to the configured webhooks. The payload is rendered from
+//! the template, so the same alert can be delivered to PagerDuty, Slack, or any service
+//! accepting JSON. Each incident is alerted once: the missed block production again only
+//! after the next block, and the stalled DA sync again only after the DA height advances.
+
+use crate::state::corruption;
+use fuel_core_services::{
+    stream::BoxStream,
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    blockchain::primitives::DaBlockHeight,
+    fuel_types::BlockHeight,
+};
+use futures::StreamExt;
+use std::time::{
+    Duration,
+    Instant,
+    SystemTime,
+};
+use tokio::{
+    sync::{
+        mpsc,
+        watch,
+    },
+    time::{
+        Interval,
+        MissedTickBehavior,
+    },
+};
+
+/// The payload with all fields of the alert.
+pub const GENERIC_TEMPLATE: &str = r#"{"kind":"{{kind}}","node":"{{node}}","summary":"{{summary}}","timestamp":{{timestamp}},"details":{{details}}}"#;
+
+/// The payload of the Slack incoming webhook.
+pub const SLACK_TEMPLATE: &str = r#"{"text":"[{{node}}] {{summary}}"}"#;
+
+/// The payload of the PagerDuty Events API v2, posted to
+/// `https://events.pagerduty.com/v2/enqueue`.
+const PAGERDUTY_TEMPLATE: &str = r#"{"routing_key":"{{routing_key}}","event_action":"trigger","dedup_key":"{{node}}/{{kind}}","payload":{"summary":"{{summary}}","source":"{{node}}","severity":"critical","custom_details":{{details}}}}"#;
+
+/// How often the block production and the DA sync are checked.
+const CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The maximum time of the delivery of the alert to one webhook.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Returns the template of the PagerDuty Events API v2 for the integration
+/// with the `routing_key`.
+pub fn pagerduty_template(routing_key: &str) -> String {
+    render(PAGERDUTY_TEMPLATE, &[("routing_key", escape(routing_key))])
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlertsConfig {
+    /// The webhooks receiving each alert.
+    pub webhooks: Vec<Webhook>,
+    /// Alerts if the producing node doesn't import a block for this time.
+    /// The block production isn't watched if it is not set.
+    pub block_production_timeout: Option<Duration>,
+    /// Alerts if the finalized DA height doesn't advance for this time.
+    pub da_sync_stall_timeout: Duration,
+    /// Alerts about the conflicting block at least this number of blocks deep,
+    /// where the conflicting block at the tip of the chain is one block deep.
+    pub min_reorg_depth: u32,
+}
+
+/// The endpoint receiving the alerts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Webhook {
+    pub url: String,
+    /// The JSON payload with the `{{kind}}`, `{{node}}`, `{{summary}}`, `{{timestamp}}`,
+    /// and `{{details}}` placeholders. The `{{details}}` is replaced with the JSON object,
+    /// the `{{timestamp}}` with the UNIX time in seconds, and the other placeholders
+    /// with the escaped contents of the JSON string.
+    pub template: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Alert {
+    /// The producing node didn't import a block since the `last_height`.
+    BlockProductionMissed {
+        last_height: BlockHeight,
+        since: Duration,
+    },
+    /// The finalized DA height didn't advance.
+    DaSyncStalled {
+        da_height: DaBlockHeight,
+        since: Duration,
+    },
+    /// The node received the block conflicting with the imported block at the `height`.
+    ChainReorg { height: BlockHeight, depth: u32 },
+    /// The database reported the corrupted data.
+    DatabaseCorruption { error: String },
+}
+
+impl Alert {
+    /// The stable identifier of the kind of the alert.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Alert::BlockProductionMissed { .. } => "block_production_missed",
+            Alert::DaSyncStalled { .. } => "da_sync_stalled",
+            Alert::ChainReorg { .. } => "chain_reorg",
+            Alert::DatabaseCorruption { .. } => "database_corruption",
+        }
+    }
+
+    /// The human-readable description of the alert.
+    pub fn summary(&self) -> String {
+        match self {
+            Alert::BlockProductionMissed { last_height, since } => format!(
+                "No block was produced for {} seconds after the block {last_height}",
+                since.as_secs()
+            ),
+            Alert::DaSyncStalled { da_height, since } => format!(
+                "The DA sync is stalled at the height {} for {} seconds",
+                **da_height,
+                since.as_secs()
+            ),
+            Alert::ChainReorg { height, depth } => format!(
+                "Received the block conflicting with the block {height}, {depth} blocks deep"
+            ),
+            Alert::DatabaseCorruption { error } => {
+                format!("The database is corrupted: {error}")
+            }
+        }
+    }
+
+    fn details(&self) -> serde_json::Value {
+        match self {
+            Alert::BlockProductionMissed { last_height, since } => serde_json::json!({
+                "last_height": **last_height,
+                "seconds": since.as_secs(),
+            }),
+            Alert::DaSyncStalled { da_height, since } => serde_json::json!({
+                "da_height": **da_height,
+                "seconds": since.as_secs(),
+            }),
+            Alert::ChainReorg { height, depth } => serde_json::json!({
+                "height": **height,
+                "depth": depth,
+            }),
+            Alert::DatabaseCorruption { error } => serde_json::json!({
+                "error": error,
+            }),
+        }
+    }
+
+    /// Renders the payload of the alert from the `template`.
+    pub fn render(&self, template: &str, node: &str, timestamp: u64) -> String {
+        render(
+            template,
+            &[
+                ("kind", escape(self.kind())),
+                ("node", escape(node)),
+                ("summary", escape(&self.summary())),
+                ("timestamp", timestamp.to_string()),
+                ("details", self.details().to_string()),
+            ],
+        )
+    }
+}
+
+/// Replaces the known `{{placeholders}}` of the `template` with the `values`
+/// in one pass, so the values are never rendered again.
+fn render(template: &str, values: &[(&str, String)]) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some((before, after)) = rest.split_once("{{") {
+        rendered.push_str(before);
+        let placeholder = after.split_once("}}").and_then(|(name, tail)| {
+            values
+                .iter()
+                .find(|(key, _)| *key == name.trim())
+                .map(|(_, value)| (value, tail))
+        });
+        match placeholder {
+            Some((value, tail)) => {
+                rendered.push_str(value);
+                rest = tail;
+            }
+            None => {
+                rendered.push_str("{{");
+                rest = after;
+            }
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
+
+/// Escapes the `value` to be placed inside the JSON string.
+fn escape(value: &str) -> String {
+    let quoted = serde_json::Value::from(value).to_string();
+    quoted
+        .strip_prefix('"')
+        .and_then(|quoted| quoted.strip_suffix('"'))
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// The state of the node watched by the alerts.
+pub trait AlertsPort: Send + Sync {
+    /// Returns `true` if the node produces the blocks, and the production isn't paused.
+    fn is_producing_blocks(&self) -> bool;
+
+    /// Returns the finalized height of the DA layer, or `None` if the node doesn't follow it.
+    fn da_height(&self) -> Option<DaBlockHeight>;
+}
+
+/// Reports the conflicting blocks found by the synchronization to the alerts.
+#[derive(Clone, Debug, Default)]
+pub struct AlertReporter {
+    sender: Option<mpsc::UnboundedSender<BlockHeight>>,
+}
+
+impl AlertReporter {
+    /// Reports the received block conflicting with the imported block at the `height`.
+    pub fn conflicting_block(&self, height: BlockHeight) {
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(height);
+        }
+    }
+}
+
+/// Detects the incidents from the observed events.
+struct Monitor {
+    block_production_timeout: Option<Duration>,
+    da_sync_stall_timeout: Duration,
+    min_reorg_depth: u32,
+    last_height: BlockHeight,
+    last_block_at: Instant,
+    production_alerted: bool,
+    /// The last finalized DA height and the time when it was observed first.
+    da_height: Option<(DaBlockHeight, Instant)>,
+    da_alerted: bool,
+}
+
+impl Monitor {
+    fn new(config: &AlertsConfig, last_height: BlockHeight, now: Instant) -> Self {
+        Self {
+            block_production_timeout: config.block_production_timeout,
+            da_sync_stall_timeout: config.da_sync_stall_timeout,
+            min_reorg_depth: config.min_reorg_depth,
+            last_height,
+            last_block_at: now,
+            production_alerted: false,
+            da_height: None,
+            da_alerted: false,
+        }
+    }
+
+    fn on_block(&mut self, height: BlockHeight, now: Instant) {
+        self.last_height = height;
+        self.last_block_at = now;
+        self.production_alerted = false;
+    }
+
+    fn on_conflicting_block(&self, height: BlockHeight) -> Option<Alert> {
+        let depth = (*self.last_height)
+            .saturating_sub(*height)
+            .saturating_add(1);
+        (depth >= self.min_reorg_depth).then_some(Alert::ChainReorg { height, depth })
+    }
+
+    fn check(
+        &mut self,
+        now: Instant,
+        is_producing_blocks: bool,
+        da_height: Option<DaBlockHeight>,
+    ) -> Vec<Alert> {
+        let mut alerts = vec![];
+
+        if let Some(timeout) = self.block_production_timeout {
+            // The time of the pause doesn't count toward the timeout.
+            if !is_producing_blocks {
+                self.last_block_at = now;
+            }
+            let since = now.saturating_duration_since(self.last_block_at);
+            if since >= timeout && !self.production_alerted {
+                self.production_alerted = true;
+                alerts.push(Alert::BlockProductionMissed {
+                    last_height: self.last_height,
+                    since,
+                });
+            }
+        }
+
+        if let Some(da_height) = da_height {
+            match self.da_height {
+                Some((last, observed_at)) if last == da_height => {
+                    let since = now.saturating_duration_since(observed_at);
+                    if since >= self.da_sync_stall_timeout && !self.da_alerted {
+                        self.da_alerted = true;
+                        alerts.push(Alert::DaSyncStalled { da_height, since });
+                    }
+                }
+                _ => {
+                    self.da_height = Some((da_height, now));
+                    self.da_alerted = false;
+                }
+            }
+        }
+
+        alerts
+    }
+}
+
+pub struct Task {
+    node: String,
+    webhooks: Vec<Webhook>,
+    client: reqwest::Client,
+    port: Box<dyn AlertsPort>,
+    blocks: BoxStream<BlockHeight>,
+    conflicting_blocks: mpsc::UnboundedReceiver<BlockHeight>,
+    corruption: watch::Receiver<Option<String>>,
+    monitor: Monitor,
+    /// The timer of the checks, started with the task.
+    check_interval: Option<Interval>,
+}
+
+impl Task {
+    /// Delivers the alert in the background, so the slow webhooks don't delay
+    /// the processing of the imported blocks.
+    fn send(&self, alert: &Alert) {
+        tracing::error!("Alert {}: {}", alert.kind(), alert.summary());
+        let timestamp = SystemTime::now()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|time| time.as_secs())
+            .unwrap_or_default();
+
+        for webhook in &self.webhooks {
+            let request = self
+                .client
+                .post(&webhook.url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(alert.render(&webhook.template, &self.node, timestamp))
+                .timeout(DELIVERY_TIMEOUT);
+            let kind = alert.kind();
+            let url = webhook.url.clone();
+            tokio::spawn(async move {
+                let result = request
+                    .send()
+                    .await
+                    .and_then(|response| response.error_for_status());
+                if let Err(err) = result {
+                    tracing::warn!("Failed to deliver the alert {kind} to {url}: {err}");
+                }
+            });
+        }
+    }
+
+    fn corruption_alert(&mut self) -> Option<Alert> {
+        self.corruption
+            .borrow_and_update()
+            .clone()
+            .map(|error| Alert::DatabaseCorruption { error })
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "Alerts";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        mut self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        // The corruption could be found before the start, e.g. during the genesis.
+        if let Some(alert) = self.corruption_alert() {
+            self.send(&alert);
+        }
+
+        let mut check_interval = tokio::time::interval(CHECK_INTERVAL);
+        check_interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        self.check_interval = Some(check_interval);
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let alerts = tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                return Ok(false)
+            }
+            height = self.blocks.next() => {
+                let Some(height) = height else {
+                    return Ok(false)
+                };
+                self.monitor.on_block(height, Instant::now());
+                vec![]
+            }
+            Some(height) = self.conflicting_blocks.recv() => {
+                self.monitor.on_conflicting_block(height).into_iter().collect()
+            }
+            Ok(()) = self.corruption.changed() => {
+                self.corruption_alert().into_iter().collect()
+            }
+            _ = tick(&mut self.check_interval) => {
+                let is_producing_blocks = self.port.is_producing_blocks();
+                let da_height = self.port.da_height();
+                self.monitor.check(Instant::now(), is_producing_blocks, da_height)
+            }
+        };
+
+        for alert in &alerts {
+            self.send(alert);
+        }
+        Ok(true)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => futures::future::pending().await,
+    }
+}
+
+pub type Service = ServiceRunner<Task>;
+
+/// Creates the alerts service and the reporter of the conflicting blocks for it.
+pub fn new_service(
+    config: AlertsConfig,
+    node: String,
+    port: Box<dyn AlertsPort>,
+    blocks: BoxStream<BlockHeight>,
+    last_height: BlockHeight,
+) -> (Service, AlertReporter) {
+    let (sender, conflicting_blocks) = mpsc::unbounded_channel();
+
+    let task = Task {
+        node,
+        monitor: Monitor::new(&config, last_height, Instant::now()),
+        webhooks: config.webhooks,
+        client: reqwest::Client::new(),
+        port,
+        blocks,
+        conflicting_blocks,
+        corruption: corruption::subscribe(),
+        check_interval: None,
+    };
+    let reporter = AlertReporter {
+        sender: Some(sender),
+    };
+    (ServiceRunner::new(task), reporter)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn after(start: Instant, seconds: u64) -> Instant {
+        start.checked_add(Duration::from_secs(seconds)).unwrap()
+    }
+
+    fn config() -> AlertsConfig {
+        AlertsConfig {
+            webhooks: vec![],
+            block_production_timeout: Some(Duration::from_secs(10)),
+            da_sync_stall_timeout: Duration::from_secs(60),
+            min_reorg_depth: 2,
+        }
+    }
+
+    #[test]
+    fn missed_block_production_is_alerted_once() {
+        // Given
+        let start = Instant::now();
+        let mut monitor = Monitor::new(&config(), 5u32.into(), start);
+
+        // When
+        let first = monitor.check(after(start, 10), true, None);
+        let second = monitor.check(after(start, 20), true, None);
+
+        // Then
+        assert_eq!(
+            first,
+            vec![Alert::BlockProductionMissed {
+                last_height: 5u32.into(),
+                since: Duration::from_secs(10),
+            }]
+        );
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    fn paused_block_production_is_not_alerted() {
+        // Given
+        let start = Instant::now();
+        let mut monitor = Monitor::new(&config(), 5u32.into(), start);
+        monitor.check(after(start, 30), false, None);
+
+        // When
+        let alerts = monitor.check(after(start, 35), true, None);
+
+        // Then
+        assert!(alerts.is_empty());
+    }
+
+    #[test]
+    fn stalled_da_sync_is_alerted_until_the_height_advances() {
+        // Given
+        let start = Instant::now();
+        let mut monitor = Monitor::new(&config(), 5u32.into(), start);
+        monitor.on_block(6u32.into(), after(start, 60));
+        monitor.check(start, true, Some(10u64.into()));
+
+        // When
+        let stalled = monitor.check(after(start, 60), true, Some(10u64.into()));
+        let advanced = monitor.check(after(start, 61), true, Some(11u64.into()));
+
+        // Then
+        assert_eq!(
+            stalled,
+            vec![Alert::DaSyncStalled {
+                da_height: 10u64.into(),
+                since: Duration::from_secs(60),
+            }]
+        );
+        assert!(advanced.is_empty());
+    }
+
+    #[test]
+    fn shallow_conflicting_blocks_are_not_alerted() {
+        let monitor = Monitor::new(&config(), 10u32.into(), Instant::now());
+
+        assert_eq!(monitor.on_conflicting_block(10u32.into()), None);
+        assert_eq!(
+            monitor.on_conflicting_block(9u32.into()),
+            Some(Alert::ChainReorg {
+                height: 9u32.into(),
+                depth: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn payloads_are_valid_json() {
+        // Given
+        let alert = Alert::DatabaseCorruption {
+            error: "Corruption: \"block checksum mismatch\" {{kind}}".to_string(),
+        };
+
+        for template in [
+            GENERIC_TEMPLATE.to_string(),
+            SLACK_TEMPLATE.to_string(),
+            pagerduty_template("key\""),
+        ] {
+            // When
+            let payload = alert.render(&template, "node-1", 100);
+
+            // Then
+            let payload: serde_json::Value = serde_json::from_str(&payload)
+                .unwrap_or_else(|e| panic!("{payload} is invalid: {e}"));
+            assert!(payload.to_string().contains("block checksum mismatch"));
+        }
+    }
+
+    #[test]
+    fn generic_payload_has_all_fields() {
+        // Given
+        let alert = Alert::ChainReorg {
+            height: 9u32.into(),
+            depth: 2,
+        };
+
+        // When
+        let payload = alert.render(GENERIC_TEMPLATE, "node-1", 100);
+
+        // Then
+        let payload: serde_json::Value = serde_json::from_str(&payload).unwrap();
+        assert_eq!(payload["kind"], "chain_reorg");
+        assert_eq!(payload["node"], "node-1");
+        assert_eq!(payload["timestamp"], 100);
+        assert_eq!(payload["details"]["depth"], 2);
+    }
+}
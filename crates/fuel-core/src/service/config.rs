@@ -1,11 +1,14 @@
+use crate::fuel_core_graphql_api::AdminToken;
 use clap::ValueEnum;
 use fuel_core_chain_config::{
     default_consensus_dev_key,
     ChainConfig,
+    PartitionedSnapshot,
 };
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     secrecy::Secret,
+    tai64::Tai64,
 };
 use std::{
     net::{
@@ -30,18 +33,52 @@ use fuel_core_p2p::config::{
 #[cfg(feature = "relayer")]
 use fuel_core_relayer::Config as RelayerConfig;
 
+pub use crate::fuel_core_graphql_api::{
+    http::{
+        HttpConfig,
+        TlsConfig,
+    },
+    receipts_pruning::{
+        ReceiptsArchiveSink,
+        ReceiptsPruningConfig,
+    },
+    scheduler::RequestSchedulerConfig,
+    websocket::WebSocketConfig,
+};
+
+pub use super::alerts::{
+    AlertsConfig,
+    Webhook,
+};
 pub use fuel_core_consensus_module::RelayerConsensusConfig;
 pub use fuel_core_importer;
-pub use fuel_core_poa::Trigger;
+pub use fuel_core_poa::{
+    verifier::BlockTimeSchedule,
+    LeaderLeaseConfig,
+    Trigger,
+};
 
 #[derive(Clone, Debug)]
 pub struct Config {
     pub addr: SocketAddr,
     pub api_request_timeout: Duration,
     pub max_database_cache_size: usize,
+    /// The maximum size in bytes of the in-memory cache of the contracts bytecode
+    /// and info shared by the transaction pool, dry runs, and the block execution.
+    pub contract_cache_size: usize,
+    /// The maximum number of the unspent coins in the in-memory cache shared by
+    /// the transaction pool and the block production. The cache is disabled if it is zero.
+    pub coin_cache_size: usize,
+    /// The maximum size in bytes of the GraphQL cache of the blocks and of the transactions
+    /// requested by id. The cache is disabled if it is zero.
+    pub api_entity_cache_size: usize,
     pub database_path: PathBuf,
     pub database_type: DbType,
     pub chain_conf: ChainConfig,
+    /// The partitioned snapshot of the initial state. The genesis streams the state
+    /// slots and the balances of the contracts from it, while the rest of the state
+    /// is taken from the `initial_state` of the `chain_conf`.
+    pub state_snapshot: Option<PartitionedSnapshot>,
     /// When `true`:
     /// - Enables manual block production.
     /// - Enables debugger endpoint.
@@ -52,23 +89,62 @@ pub struct Config {
     pub block_production: Trigger,
     pub vm: VMConfig,
     pub txpool: fuel_core_txpool::Config,
+    #[cfg(feature = "producer")]
     pub block_producer: fuel_core_producer::Config,
     pub block_importer: fuel_core_importer::Config,
     #[cfg(feature = "relayer")]
     pub relayer: Option<RelayerConfig>,
+    /// Publishes the sealed block headers to the shared sequencer if set.
+    #[cfg(feature = "shared-sequencer")]
+    pub shared_sequencer: Option<fuel_core_shared_sequencer::Config>,
     #[cfg(feature = "p2p")]
     pub p2p: Option<P2PConfig<NotInitialized>>,
     #[cfg(feature = "p2p")]
     pub sync: fuel_core_sync::Config,
+    /// Imports the blocks from the era files before the sync with the network if set.
+    #[cfg(feature = "p2p")]
+    pub era_source: Option<crate::era::EraSource>,
     pub consensus_key: Option<Secret<SecretKeyWrapper>>,
+    /// Enables the admin endpoints authorized by the token.
+    pub admin_token: Option<AdminToken>,
+    /// Accepts only the persisted GraphQL queries, except for the requests with the admin token.
+    pub api_persisted_queries_only: bool,
+    /// The file storing the persisted GraphQL queries between the restarts.
+    pub api_persisted_queries_path: Option<PathBuf>,
+    /// The maximum time to live of the coin leases. The coin leases are disabled if it is `None`.
+    pub api_coin_lease_max_ttl: Option<Duration>,
     pub name: String,
     pub relayer_consensus_config: fuel_core_consensus_module::RelayerConsensusConfig,
+    /// Rejects the imported PoA blocks with the timestamps outside of the schedule if set.
+    pub block_time_schedule: Option<BlockTimeSchedule>,
+    /// Produces the blocks only while the node holds the leader lease if set.
+    pub leader_lease: Option<LeaderLeaseConfig>,
+    /// The file storing the pause of the block production between the restarts.
+    pub poa_pause_path: Option<PathBuf>,
     /// The number of reserved peers to connect to before starting to sync.
     pub min_connected_reserved_peers: usize,
     /// Time to wait after receiving the latest block before considered to be Synced.
     pub time_until_synced: Duration,
     /// Time to wait after submitting a query before debug info will be logged about query.
     pub query_log_threshold_time: Duration,
+    /// Logs the storage reads exceeding the threshold if it is set.
+    pub storage_log_threshold_time: Option<Duration>,
+    /// The configuration of the WebSocket transport for GraphQL subscriptions.
+    pub graphql_websocket: WebSocketConfig,
+    /// The configuration of CORS, the request body limit and TLS of the GraphQL server.
+    pub graphql_http: HttpConfig,
+    /// The limits of the concurrent and queued GraphQL requests.
+    pub graphql_scheduler: RequestSchedulerConfig,
+    /// Prunes the receipts of the transactions from the old blocks if set.
+    pub receipts_pruning: Option<ReceiptsPruningConfig>,
+    /// Sends the alerts about the critical events to the webhooks if set.
+    pub alerts: Option<AlertsConfig>,
+    /// Logs the memory used by the caches, the storage engine, and the `TxPool`
+    /// with this interval if set.
+    pub memory_report_interval: Option<Duration>,
+    /// The address of the gRPC server. The server is disabled if it is not set.
+    #[cfg(feature = "grpc")]
+    pub grpc_addr: Option<SocketAddr>,
 }
 
 impl Config {
@@ -83,6 +159,9 @@ impl Config {
             api_request_timeout: Duration::from_secs(60),
             // Set the cache for tests = 10MB
             max_database_cache_size: 10 * 1024 * 1024,
+            contract_cache_size: 10 * 1024 * 1024,
+            coin_cache_size: 10_000,
+            api_entity_cache_size: 10 * 1024 * 1024,
             database_path: Default::default(),
             #[cfg(feature = "rocksdb")]
             database_type: DbType::RocksDb,
@@ -90,6 +169,7 @@ impl Config {
             database_type: DbType::InMemory,
             debug: true,
             chain_conf: chain_conf.clone(),
+            state_snapshot: None,
             block_production: Trigger::Instant,
             vm: Default::default(),
             utxo_validation,
@@ -100,6 +180,7 @@ impl Config {
                 transaction_ttl: Duration::from_secs(60 * 100000000),
                 ..fuel_core_txpool::Config::default()
             },
+            #[cfg(feature = "producer")]
             block_producer: fuel_core_producer::Config {
                 gas_price: min_gas_price,
                 ..Default::default()
@@ -107,16 +188,36 @@ impl Config {
             block_importer,
             #[cfg(feature = "relayer")]
             relayer: None,
+            #[cfg(feature = "shared-sequencer")]
+            shared_sequencer: None,
             #[cfg(feature = "p2p")]
             p2p: Some(P2PConfig::<NotInitialized>::default("test_network")),
             #[cfg(feature = "p2p")]
             sync: fuel_core_sync::Config::default(),
+            #[cfg(feature = "p2p")]
+            era_source: None,
             consensus_key: Some(Secret::new(default_consensus_dev_key().into())),
+            admin_token: None,
+            api_persisted_queries_only: false,
+            api_persisted_queries_path: None,
+            api_coin_lease_max_ttl: None,
             name: String::default(),
             relayer_consensus_config: Default::default(),
+            block_time_schedule: None,
+            leader_lease: None,
+            poa_pause_path: None,
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
             query_log_threshold_time: Duration::from_secs(2),
+            storage_log_threshold_time: None,
+            graphql_websocket: Default::default(),
+            graphql_http: Default::default(),
+            graphql_scheduler: Default::default(),
+            receipts_pruning: None,
+            alerts: None,
+            memory_report_interval: None,
+            #[cfg(feature = "grpc")]
+            grpc_addr: None,
         }
     }
 
@@ -134,6 +235,7 @@ impl Config {
             self.txpool.chain_config = self.chain_conf.clone();
         }
 
+        #[cfg(feature = "producer")]
         if self.txpool.min_gas_price != self.block_producer.gas_price {
             tracing::warn!(
                 "The `min_gas_price` of `TxPool` was inconsistent with `BlockProducer`"
@@ -147,6 +249,7 @@ impl Config {
             tracing::warn!("The `utxo_validation` of `TxPool` was inconsistent");
             self.txpool.utxo_validation = self.utxo_validation;
         }
+        #[cfg(feature = "producer")]
         if self.block_producer.utxo_validation != self.utxo_validation {
             tracing::warn!("The `utxo_validation` of `BlockProducer` was inconsistent");
             self.block_producer.utxo_validation = self.utxo_validation;
@@ -166,6 +269,14 @@ impl From<&Config> for fuel_core_poa::Config {
             consensus_params: config.chain_conf.consensus_parameters.clone(),
             min_connected_reserved_peers: config.min_connected_reserved_peers,
             time_until_synced: config.time_until_synced,
+            paused: None,
+            leader_lease: config.leader_lease.clone(),
+            genesis_time: config
+                .chain_conf
+                .initial_state
+                .as_ref()
+                .and_then(|state| state.timestamp)
+                .unwrap_or(Tai64::UNIX_EPOCH),
         }
     }
 }
@@ -173,6 +284,11 @@ impl From<&Config> for fuel_core_poa::Config {
 #[derive(Clone, Debug, Default)]
 pub struct VMConfig {
     pub backtrace: bool,
+    /// Executes each block twice with the sequential executor and halts
+    /// on the divergence of the results.
+    pub reexecution_audit: bool,
+    /// Meters the instructions and the memory used by the VM for each transaction.
+    pub metrics: bool,
 }
 
 #[derive(
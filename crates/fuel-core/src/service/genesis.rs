@@ -7,6 +7,7 @@ use fuel_core_chain_config::{
     CoinConfig,
     ContractConfig,
     GenesisCommitment,
+    PartitionedSnapshot,
     StateConfig,
 };
 use fuel_core_executor::refs::ContractRef;
@@ -17,6 +18,7 @@ use fuel_core_storage::{
         ContractsLatestUtxo,
         ContractsRawCode,
         Messages,
+        SpentMessages,
     },
     transactional::{
         StorageTransaction,
@@ -41,13 +43,17 @@ use fuel_core_types::{
         SealedBlock,
     },
     entities::{
-        coins::coin::Coin,
+        coins::coin::{
+            Coin,
+            CompressedCoin,
+        },
         contract::{
             ContractUtxoInfo,
             ContractsInfoType,
         },
         message::Message,
     },
+    fuel_crypto::Hasher,
     fuel_merkle::binary,
     fuel_tx::{
         Contract,
@@ -58,15 +64,20 @@ use fuel_core_types::{
         bytes::WORD_SIZE,
         Bytes32,
         ContractId,
+        Nonce,
     },
     services::block_importer::{
         ImportResult,
         UncommittedResult as UncommittedImportResult,
     },
+    tai64::Tai64,
 };
 use itertools::Itertools;
+use rayon::prelude::*;
+use std::collections::HashSet;
 
 pub mod off_chain;
+pub mod relayer;
 
 /// Performs the importing of the genesis block from the snapshot.
 pub fn execute_genesis_block(
@@ -80,17 +91,27 @@ pub fn execute_genesis_block(
     // Initialize the chain id and height.
 
     let chain_config_hash = config.chain_conf.root()?.into();
-    let coins_root = init_coin_state(database, &config.chain_conf.initial_state)?.into();
-    let contracts_root =
-        init_contracts(database, &config.chain_conf.initial_state)?.into();
-    let messages_root = init_da_messages(database, &config.chain_conf.initial_state)?;
-    let messages_root = messages_root.into();
+    let coins = init_coin_state(database, &config.chain_conf.initial_state)?;
+    let contracts = init_contracts(database, &config.chain_conf.initial_state)?;
+    if let Some(snapshot) = &config.state_snapshot {
+        init_contract_slots(database, snapshot, &contracts)?;
+    }
+    let messages = init_da_messages(database, &config.chain_conf.initial_state)?;
+    let (coins_root, contracts_root, messages_root) =
+        state_roots(database, &coins, &contracts, &messages)?;
+    let spent_messages = config
+        .chain_conf
+        .initial_state
+        .as_ref()
+        .and_then(|state| state.spent_messages.as_deref())
+        .unwrap_or_default();
+    let messages_root = fold_spent_messages_root(messages_root, spent_messages);
 
     let genesis = Genesis {
         chain_config_hash,
-        coins_root,
-        contracts_root,
-        messages_root,
+        coins_root: coins_root.into(),
+        contracts_root: contracts_root.into(),
+        messages_root: messages_root.into(),
     };
 
     let block = create_genesis_block(config);
@@ -111,8 +132,14 @@ pub fn create_genesis_block(config: &Config) -> Block {
     let block = Block::new(
         PartialBlockHeader {
             application: ApplicationHeader::<Empty> {
-                // TODO: Set `da_height` based on the chain config.
-                da_height: Default::default(),
+                // The initial DA height is defined by the `ChainConfig`.
+                // If it is `None` then it will be zero.
+                da_height: config
+                    .chain_conf
+                    .initial_state
+                    .as_ref()
+                    .and_then(|config| config.da_block_height)
+                    .unwrap_or_default(),
                 generated: Empty,
             },
             consensus: ConsensusHeader::<Empty> {
@@ -126,7 +153,14 @@ pub fn create_genesis_block(config: &Config) -> Block {
                     .as_ref()
                     .map(|config| config.height.unwrap_or_else(|| 0u32.into()))
                     .unwrap_or_else(|| 0u32.into()),
-                time: fuel_core_types::tai64::Tai64::UNIX_EPOCH,
+                // The initial time is defined by the `ChainConfig`.
+                // If it is `None` then it will be the unix epoch.
+                time: config
+                    .chain_conf
+                    .initial_state
+                    .as_ref()
+                    .and_then(|config| config.timestamp)
+                    .unwrap_or(Tai64::UNIX_EPOCH),
                 generated: Empty,
             },
         },
@@ -153,16 +187,80 @@ pub async fn execute_and_commit_genesis_block(
     Ok(())
 }
 
+/// Calculates the roots of the coins, contracts and messages from the genesis.
+///
+/// The subtrees don't depend on each other, so they are hashed in parallel.
+/// The leaves of each subtree are also hashed in parallel, but are pushed
+/// into the tree in the order of the snapshot, so the roots are the same as
+/// if they were calculated sequentially.
+fn state_roots(
+    db: &Database,
+    coins: &[CompressedCoin],
+    contracts: &[ContractId],
+    messages: &[Message],
+) -> anyhow::Result<(MerkleRoot, MerkleRoot, MerkleRoot)> {
+    let (coins_root, (contracts_root, messages_root)) = rayon::join(
+        || subtree_root(coins, |coin| coin.root()),
+        || {
+            rayon::join(
+                || {
+                    subtree_root(contracts, |contract_id| {
+                        ContractRef::new(db, *contract_id).root()
+                    })
+                },
+                || subtree_root(messages, |message| message.root()),
+            )
+        },
+    );
+    Ok((coins_root?, contracts_root?, messages_root?))
+}
+
+/// Folds the root of the spent messages carried over the regenesis into the root
+/// of the genesis messages, so the genesis block commits to the spent set too.
+/// The root is unchanged if there are no spent messages.
+fn fold_spent_messages_root(
+    messages_root: MerkleRoot,
+    spent_messages: &[Nonce],
+) -> MerkleRoot {
+    if spent_messages.is_empty() {
+        return messages_root
+    }
+    let mut spent_tree = binary::in_memory::MerkleTree::new();
+    for nonce in spent_messages {
+        spent_tree.push(nonce.as_slice());
+    }
+    let mut hasher = Hasher::default();
+    hasher.input(messages_root);
+    hasher.input(spent_tree.root());
+    hasher.digest().into()
+}
+
+fn subtree_root<T, F>(entries: &[T], leaf: F) -> anyhow::Result<MerkleRoot>
+where
+    T: Sync,
+    F: Fn(&T) -> anyhow::Result<MerkleRoot> + Send + Sync,
+{
+    let leaves = entries
+        .par_iter()
+        .map(leaf)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let mut tree = binary::in_memory::MerkleTree::new();
+    for leaf in leaves {
+        tree.push(leaf.as_slice());
+    }
+    Ok(tree.root())
+}
+
 fn init_coin_state(
     db: &mut Database,
     state: &Option<StateConfig>,
-) -> anyhow::Result<MerkleRoot> {
-    let mut coins_tree = binary::in_memory::MerkleTree::new();
+) -> anyhow::Result<Vec<CompressedCoin>> {
+    let mut coins = vec![];
     // TODO: Store merkle sum tree root over coins with unspecified utxo ids.
     let mut generated_output_index: u64 = 0;
     if let Some(state) = &state {
-        if let Some(coins) = &state.coins {
-            for coin in coins {
+        if let Some(configs) = &state.coins {
+            for coin in configs {
                 let coin = create_coin_from_config(coin, &mut generated_output_index);
                 let utxo_id = coin.utxo_id;
                 let compressed_coin = coin.compress();
@@ -182,18 +280,18 @@ fn init_coin_state(
                 {
                     return Err(anyhow!("Coin should not exist"))
                 }
-                coins_tree.push(compressed_coin.root()?.as_slice())
+                coins.push(compressed_coin);
             }
         }
     }
-    Ok(coins_tree.root())
+    Ok(coins)
 }
 
 fn init_contracts(
     db: &mut Database,
     state: &Option<StateConfig>,
-) -> anyhow::Result<MerkleRoot> {
-    let mut contracts_tree = binary::in_memory::MerkleTree::new();
+) -> anyhow::Result<Vec<ContractId>> {
+    let mut contract_ids = vec![];
     // initialize contract state
     if let Some(state) = &state {
         if let Some(contracts) = &state.contracts {
@@ -269,12 +367,11 @@ fn init_contracts(
                 }
                 init_contract_state(db, &contract_id, contract_config)?;
                 init_contract_balance(db, &contract_id, contract_config)?;
-                contracts_tree
-                    .push(ContractRef::new(&mut *db, contract_id).root()?.as_slice());
+                contract_ids.push(contract_id);
             }
         }
     }
-    Ok(contracts_tree.root())
+    Ok(contract_ids)
 }
 
 fn init_contract_state(
@@ -289,16 +386,106 @@ fn init_contract_state(
     Ok(())
 }
 
+/// Initializes the state slots and the balances of the contracts streamed from the
+/// `snapshot`. Only the slots of one contract are held in memory at once.
+fn init_contract_slots(
+    db: &mut Database,
+    snapshot: &PartitionedSnapshot,
+    contracts: &[ContractId],
+) -> anyhow::Result<()> {
+    let contracts = contracts.iter().collect::<HashSet<_>>();
+    for_each_contract(
+        snapshot.contract_state(),
+        &contracts,
+        |row| row.contract_id,
+        |contract_id, rows| {
+            let slots = rows.into_iter().map(|row| (row.key, row.value));
+            db.init_contract_state(&contract_id, slots)?;
+            Ok(())
+        },
+    )?;
+    for_each_contract(
+        snapshot.contract_balances(),
+        &contracts,
+        |row| row.contract_id,
+        |contract_id, rows| {
+            let balances = rows.into_iter().map(|row| (row.asset_id, row.amount));
+            db.init_contract_balances(&contract_id, balances)?;
+            Ok(())
+        },
+    )
+}
+
+/// Groups the consecutive `rows` of the same contract and passes each group to `init`.
+/// The rows of one contract should follow each other.
+fn for_each_contract<T>(
+    rows: impl Iterator<Item = anyhow::Result<T>>,
+    contracts: &HashSet<&ContractId>,
+    contract_id: impl Fn(&T) -> ContractId,
+    mut init: impl FnMut(ContractId, Vec<T>) -> anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    let mut initialized = HashSet::new();
+    let mut current: Option<(ContractId, Vec<T>)> = None;
+    for row in rows {
+        let row = row?;
+        let id = contract_id(&row);
+        match &mut current {
+            Some((current_id, group)) if *current_id == id => group.push(row),
+            _ => {
+                if let Some((current_id, group)) = current.take() {
+                    init(current_id, group)?;
+                }
+                if !contracts.contains(&id) {
+                    return Err(anyhow!(
+                        "The snapshot has the slots of the unknown contract {id}"
+                    ))
+                }
+                if !initialized.insert(id) {
+                    return Err(anyhow!(
+                        "The slots of the contract {id} don't follow each other in the snapshot"
+                    ))
+                }
+                current = Some((id, vec![row]));
+            }
+        }
+    }
+    if let Some((current_id, group)) = current {
+        init(current_id, group)?;
+    }
+    Ok(())
+}
+
 fn init_da_messages(
     db: &mut Database,
     state: &Option<StateConfig>,
-) -> anyhow::Result<MerkleRoot> {
-    let mut message_tree = binary::in_memory::MerkleTree::new();
+) -> anyhow::Result<Vec<Message>> {
+    let mut messages = vec![];
     if let Some(state) = &state {
+        if let Some(spent_messages) = &state.spent_messages {
+            for nonce in spent_messages {
+                if db.storage::<SpentMessages>().insert(nonce, &())?.is_some() {
+                    return Err(anyhow!("Spent message should not exist"))
+                }
+            }
+        }
+
         if let Some(message_state) = &state.messages {
             for msg in message_state {
                 let message: Message = msg.clone().into();
 
+                // ensure message can't come from the DA blocks in the future
+                if let Some(da_block_height) = state.da_block_height {
+                    if message.da_height() > da_block_height {
+                        return Err(anyhow!(
+                            "message da_height cannot be greater than genesis da_height"
+                        ))
+                    }
+                }
+
+                if db.storage::<SpentMessages>().contains_key(message.id())? {
+                    return Err(anyhow!("Message {} is already spent", message.id()))
+                }
+
                 if db
                     .storage::<Messages>()
                     .insert(message.id(), &message)?
@@ -306,12 +493,12 @@ fn init_da_messages(
                 {
                     return Err(anyhow!("Message should not exist"))
                 }
-                message_tree.push(message.root()?.as_slice());
+                messages.push(message);
             }
         }
     }
 
-    Ok(message_tree.root())
+    Ok(messages)
 }
 
 fn init_contract_balance(
@@ -379,6 +566,7 @@ mod tests {
         ChainConfig,
         CoinConfig,
         MessageConfig,
+        PartitionLimits,
     };
     use fuel_core_services::RunnableService;
     use fuel_core_storage::{
@@ -433,6 +621,35 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn config_initializes_genesis_time_and_da_height() {
+        let test_time = Tai64(1_700_000_000);
+        let test_da_height = DaBlockHeight(42);
+        let service_config = Config {
+            chain_conf: ChainConfig {
+                initial_state: Some(StateConfig {
+                    timestamp: Some(test_time),
+                    da_block_height: Some(test_da_height),
+                    ..Default::default()
+                }),
+                ..ChainConfig::local_testnet()
+            },
+            ..Config::local_node()
+        };
+
+        let db = Database::default();
+        FuelService::from_database(db.clone(), service_config)
+            .await
+            .unwrap();
+
+        let genesis = db
+            .get_current_block()
+            .unwrap()
+            .expect("Expected the genesis block to be set");
+        assert_eq!(genesis.header().time(), test_time);
+        assert_eq!(genesis.header().da_height, test_da_height);
+    }
+
     #[tokio::test]
     async fn config_state_initializes_multiple_coins_with_different_owners_and_asset_ids()
     {
@@ -611,6 +828,126 @@ mod tests {
         assert_eq!(expected_msg, ret_msg);
     }
 
+    #[tokio::test]
+    async fn tests_init_spent_da_msgs_and_da_height() {
+        let mut rng = StdRng::seed_from_u64(32492);
+        let mut config = Config::local_node();
+        let spent_nonce = rng.gen();
+        let da_block_height = DaBlockHeight(rng.gen());
+
+        config.chain_conf.initial_state = Some(StateConfig {
+            spent_messages: Some(vec![spent_nonce]),
+            da_block_height: Some(da_block_height),
+            ..Default::default()
+        });
+
+        let db = &Database::default();
+
+        let result = execute_genesis_block(&config, db).unwrap();
+        assert_eq!(
+            result.result().sealed_block.entity.header().da_height,
+            da_block_height
+        );
+
+        let db_transaction = result.into_transaction();
+        assert!(db_transaction
+            .as_ref()
+            .storage::<SpentMessages>()
+            .contains_key(&spent_nonce)
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn spent_da_msgs_are_committed_to_messages_root() {
+        let mut rng = StdRng::seed_from_u64(32492);
+        let msg = MessageConfig {
+            sender: rng.gen(),
+            recipient: rng.gen(),
+            nonce: rng.gen(),
+            amount: rng.gen(),
+            data: vec![rng.gen()],
+            da_height: DaBlockHeight(0),
+        };
+        let messages_root = |spent_messages| {
+            let mut config = Config::local_node();
+            config.chain_conf.initial_state = Some(StateConfig {
+                messages: Some(vec![msg.clone()]),
+                spent_messages,
+                ..Default::default()
+            });
+            let result = execute_genesis_block(&config, &Database::default()).unwrap();
+            let Consensus::Genesis(genesis) = &result.result().sealed_block.consensus
+            else {
+                panic!("The genesis block should have the genesis consensus")
+            };
+            genesis.messages_root
+        };
+        let spent: Nonce = rng.gen();
+        let other_spent: Nonce = rng.gen();
+
+        // Given
+        let without_spent = messages_root(None);
+
+        // When
+        let with_spent = messages_root(Some(vec![spent]));
+        let with_other_spent = messages_root(Some(vec![other_spent]));
+
+        // Then
+        assert_eq!(without_spent, messages_root(Some(vec![])));
+        assert_ne!(without_spent, with_spent);
+        assert_ne!(with_spent, with_other_spent);
+    }
+
+    #[tokio::test]
+    async fn spent_da_msg_cant_be_imported_as_unspent() {
+        let mut rng = StdRng::seed_from_u64(32492);
+        let mut config = Config::local_node();
+
+        let msg = MessageConfig {
+            sender: rng.gen(),
+            recipient: rng.gen(),
+            nonce: rng.gen(),
+            amount: rng.gen(),
+            data: vec![rng.gen()],
+            da_height: DaBlockHeight(0),
+        };
+
+        config.chain_conf.initial_state = Some(StateConfig {
+            messages: Some(vec![msg.clone()]),
+            spent_messages: Some(vec![msg.nonce]),
+            ..Default::default()
+        });
+
+        let db = &Database::default();
+
+        assert!(execute_genesis_block(&config, db).is_err());
+    }
+
+    #[tokio::test]
+    async fn da_msg_cant_exceed_genesis_da_height() {
+        let mut rng = StdRng::seed_from_u64(32492);
+        let mut config = Config::local_node();
+
+        let msg = MessageConfig {
+            sender: rng.gen(),
+            recipient: rng.gen(),
+            nonce: rng.gen(),
+            amount: rng.gen(),
+            data: vec![rng.gen()],
+            da_height: DaBlockHeight(11),
+        };
+
+        config.chain_conf.initial_state = Some(StateConfig {
+            messages: Some(vec![msg]),
+            da_block_height: Some(DaBlockHeight(10)),
+            ..Default::default()
+        });
+
+        let db = &Database::default();
+
+        assert!(execute_genesis_block(&config, db).is_err());
+    }
+
     #[tokio::test]
     async fn config_state_initializes_contract_balance() {
         let mut rng = StdRng::seed_from_u64(10);
@@ -729,6 +1066,161 @@ mod tests {
         assert!(init_result.is_err())
     }
 
+    #[tokio::test]
+    async fn genesis_roots_match_sequentially_calculated_roots() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2322);
+        let coins = (0..100)
+            .map(|_| CoinConfig {
+                tx_id: Some(rng.gen()),
+                output_index: Some(rng.gen()),
+                tx_pointer_block_height: Some(0u32.into()),
+                tx_pointer_tx_idx: Some(rng.gen()),
+                owner: rng.gen(),
+                amount: rng.gen(),
+                asset_id: rng.gen(),
+            })
+            .collect_vec();
+        let contracts = (0..20u8)
+            .map(|i| {
+                let salt: Salt = rng.gen();
+                let contract = Contract::from(vec![i; 64]);
+                let root = contract.root();
+                let contract_id =
+                    contract.id(&salt, &root, &Contract::default_state_root());
+                ContractConfig {
+                    contract_id,
+                    code: contract.into(),
+                    salt,
+                    state: Some(
+                        (0..10)
+                            .map(|_| (rng.gen(), rng.gen::<Bytes32>().to_vec()))
+                            .collect(),
+                    ),
+                    balances: Some((0..10).map(|_| (rng.gen(), rng.gen())).collect()),
+                    tx_id: Some(rng.gen()),
+                    output_index: Some(rng.gen()),
+                    tx_pointer_block_height: Some(0u32.into()),
+                    tx_pointer_tx_idx: Some(rng.gen()),
+                }
+            })
+            .collect_vec();
+        let messages = (0..100)
+            .map(|_| MessageConfig {
+                sender: rng.gen(),
+                recipient: rng.gen(),
+                nonce: rng.gen(),
+                amount: rng.gen(),
+                data: vec![rng.gen()],
+                da_height: DaBlockHeight(0),
+            })
+            .collect_vec();
+        let mut config = Config::local_node();
+        config.chain_conf.initial_state = Some(StateConfig {
+            coins: Some(coins.clone()),
+            contracts: Some(contracts.clone()),
+            messages: Some(messages.clone()),
+            ..Default::default()
+        });
+
+        // When
+        let result = execute_genesis_block(&config, &Database::default()).unwrap();
+
+        // Then
+        let Consensus::Genesis(genesis) = result.result().sealed_block.consensus.clone()
+        else {
+            panic!("The genesis block should have the genesis consensus")
+        };
+        let mut db_transaction = result.into_transaction();
+        let db = db_transaction.as_mut();
+
+        let mut coins_tree = binary::in_memory::MerkleTree::new();
+        for coin in &coins {
+            let utxo_id = UtxoId::new(coin.tx_id.unwrap(), coin.output_index.unwrap());
+            let coin = db
+                .storage::<Coins>()
+                .get(&utxo_id)
+                .unwrap()
+                .unwrap()
+                .into_owned();
+            coins_tree.push(coin.root().unwrap().as_slice());
+        }
+        let mut contracts_tree = binary::in_memory::MerkleTree::new();
+        for contract in &contracts {
+            let root = ContractRef::new(&mut *db, contract.contract_id)
+                .root()
+                .unwrap();
+            contracts_tree.push(root.as_slice());
+        }
+        let mut messages_tree = binary::in_memory::MerkleTree::new();
+        for message in messages {
+            let message: Message = message.into();
+            messages_tree.push(message.root().unwrap().as_slice());
+        }
+
+        assert_eq!(genesis.coins_root, coins_tree.root().into());
+        assert_eq!(genesis.contracts_root, contracts_tree.root().into());
+        assert_eq!(genesis.messages_root, messages_tree.root().into());
+    }
+
+    #[tokio::test]
+    async fn snapshot_slots_give_the_same_genesis_as_inline_slots() {
+        // Given
+        let mut rng = StdRng::seed_from_u64(2323);
+        let contracts = (0..3u8)
+            .map(|i| {
+                let salt: Salt = rng.gen();
+                let contract = Contract::from(vec![i; 64]);
+                let root = contract.root();
+                let contract_id =
+                    contract.id(&salt, &root, &Contract::default_state_root());
+                ContractConfig {
+                    contract_id,
+                    code: contract.into(),
+                    salt,
+                    state: Some(
+                        (0..5)
+                            .map(|_| (rng.gen(), rng.gen::<Bytes32>().to_vec()))
+                            .collect(),
+                    ),
+                    balances: Some((0..5).map(|_| (rng.gen(), rng.gen())).collect()),
+                    tx_id: Some(rng.gen()),
+                    output_index: Some(rng.gen()),
+                    tx_pointer_block_height: Some(0u32.into()),
+                    tx_pointer_tx_idx: Some(rng.gen()),
+                }
+            })
+            .collect_vec();
+        let state = StateConfig {
+            contracts: Some(contracts),
+            ..Default::default()
+        };
+        let directory = tempfile::tempdir().unwrap();
+        let limits = PartitionLimits {
+            max_rows: 4,
+            ..Default::default()
+        };
+        state.write_partitioned(directory.path(), limits).unwrap();
+        let snapshot = PartitionedSnapshot::open(directory.path()).unwrap();
+
+        let mut inline_config = Config::local_node();
+        inline_config.chain_conf.initial_state = Some(state);
+        let mut snapshot_config = Config::local_node();
+        snapshot_config.chain_conf.initial_state = Some(snapshot.state_config().unwrap());
+        snapshot_config.state_snapshot = Some(snapshot);
+
+        // When
+        let inline = execute_genesis_block(&inline_config, &Database::default()).unwrap();
+        let streamed =
+            execute_genesis_block(&snapshot_config, &Database::default()).unwrap();
+
+        // Then
+        assert_eq!(
+            inline.result().sealed_block.consensus,
+            streamed.result().sealed_block.consensus
+        );
+    }
+
     fn get_coins(db: &CombinedDatabase, owner: &Address) -> Vec<Coin> {
         db.off_chain()
             .owned_coins_ids(owner, None, None)
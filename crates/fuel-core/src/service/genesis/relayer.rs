@@ -0,0 +1,191 @@
+use crate::{
+    database::{
+        database_description::relayer::Relayer,
+        Database,
+    },
+    service::Config,
+};
+use fuel_core_storage::transactional::{
+    StorageTransaction,
+    Transactional,
+};
+
+/// Imports the events and the synced DA height of the relayer from the snapshot.
+pub fn execute_genesis_block(
+    config: &Config,
+    original_database: &Database<Relayer>,
+) -> anyhow::Result<StorageTransaction<Database<Relayer>>> {
+    // start a db transaction for bulk-writing
+    #[allow(unused_mut)]
+    let mut database_transaction = Transactional::transaction(original_database);
+
+    let relayer_config = config
+        .chain_conf
+        .initial_state
+        .as_ref()
+        .and_then(|state| state.relayer.as_ref());
+
+    if let Some(relayer_config) = relayer_config {
+        #[cfg(feature = "relayer")]
+        {
+            use fuel_core_relayer::storage::{
+                DaHeightTable,
+                EventsHistory,
+                MessageOrigins,
+            };
+            use fuel_core_storage::StorageAsMut;
+            use fuel_core_types::{
+                entities::message::Message,
+                services::relayer::{
+                    Event,
+                    MessageOrigin,
+                },
+            };
+            use std::collections::BTreeMap;
+
+            let database = database_transaction.as_mut();
+
+            let mut events = BTreeMap::<_, Vec<Event>>::new();
+            for config in &relayer_config.messages {
+                let message: Message = config.clone().into();
+                events
+                    .entry(message.da_height())
+                    .or_default()
+                    .push(message.into());
+            }
+            for (da_height, events) in events {
+                if da_height > relayer_config.da_block_height {
+                    anyhow::bail!(
+                        "The relayer event at the DA height {da_height} is above \
+                        the synced DA height {}",
+                        relayer_config.da_block_height
+                    )
+                }
+                database
+                    .storage::<EventsHistory>()
+                    .insert(&da_height, &events)?;
+            }
+
+            for config in &relayer_config.message_origins {
+                let (nonce, origin): (_, MessageOrigin) = config.clone().into();
+                database
+                    .storage::<MessageOrigins>()
+                    .insert(&nonce, &origin)?;
+            }
+
+            database
+                .storage::<DaHeightTable>()
+                .insert(&(), &relayer_config.da_block_height)?;
+        }
+        #[cfg(not(feature = "relayer"))]
+        {
+            let _ = relayer_config;
+            tracing::warn!(
+                "The relayer is disabled, so its state from the snapshot is ignored"
+            );
+        }
+    }
+
+    Ok(database_transaction)
+}
+
+#[cfg(all(test, feature = "relayer"))]
+mod tests {
+    use super::*;
+    use fuel_core_chain_config::{
+        ChainConfig,
+        MessageConfig,
+        MessageOriginConfig,
+        RelayerConfig,
+        StateConfig,
+    };
+    use fuel_core_relayer::{
+        ports::RelayerDb,
+        storage::{
+            EventsHistory,
+            MessageOrigins,
+        },
+    };
+    use fuel_core_storage::StorageAsRef;
+    use fuel_core_types::{
+        entities::message::Message,
+        services::relayer::Event,
+    };
+
+    fn relayer_config() -> RelayerConfig {
+        let message = |nonce: u8, da_height: u64| MessageConfig {
+            nonce: [nonce; 32].into(),
+            da_height: da_height.into(),
+            ..Default::default()
+        };
+        RelayerConfig {
+            da_block_height: 10u64.into(),
+            messages: vec![message(1, 3), message(2, 3), message(3, 7)],
+            message_origins: vec![MessageOriginConfig {
+                nonce: [1; 32].into(),
+                da_height: 3u64.into(),
+                transaction_hash: [4; 32].into(),
+            }],
+        }
+    }
+
+    fn config(relayer: RelayerConfig) -> Config {
+        Config {
+            chain_conf: ChainConfig {
+                initial_state: Some(StateConfig {
+                    relayer: Some(relayer),
+                    ..Default::default()
+                }),
+                ..ChainConfig::local_testnet()
+            },
+            ..Config::local_node()
+        }
+    }
+
+    #[test]
+    fn relayer_state_is_imported_and_exported() {
+        // Given
+        let relayer = relayer_config();
+        let database = Database::<Relayer>::default();
+
+        // When
+        execute_genesis_block(&config(relayer.clone()), &database)
+            .unwrap()
+            .commit()
+            .unwrap();
+
+        // Then
+        assert_eq!(database.get_finalized_da_height().unwrap(), 10u64.into());
+        let events = database
+            .storage::<EventsHistory>()
+            .get(&3u64.into())
+            .unwrap()
+            .unwrap()
+            .into_owned();
+        let expected: Vec<Event> = relayer.messages[..2]
+            .iter()
+            .map(|config| Message::from(config.clone()).into())
+            .collect();
+        assert_eq!(events, expected);
+        let origin = database
+            .storage::<MessageOrigins>()
+            .get(&[1; 32].into())
+            .unwrap()
+            .unwrap();
+        assert_eq!(origin.transaction_hash, [4; 32].into());
+        assert_eq!(database.get_relayer_config().unwrap(), Some(relayer));
+    }
+
+    #[test]
+    fn relayer_events_above_synced_height_are_rejected() {
+        // Given
+        let mut relayer = relayer_config();
+        relayer.da_block_height = 5u64.into();
+
+        // When
+        let result = execute_genesis_block(&config(relayer), &Database::default());
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,272 @@
+//! The network of the block producer and the full nodes running in the same process
+//! and connected over the local p2p. It is used to test the multi-node behavior
+//! on one machine:
+//!
+//! ```ignore
+//! let localnet = Localnet::start(LocalnetConfig {
+//!     full_nodes: 3,
+//!     ..Default::default()
+//! })
+//! .await?;
+//! for node in localnet.status().await? {
+//!     println!("{node}");
+//! }
+//! localnet.stop().await?;
+//! ```
+//!
+//! The logs of all nodes are written by the same subscriber, and each line has the
+//! name of its node in the span.
+
+use crate::{
+    chain_config::ConsensusConfig,
+    p2p::Multiaddr,
+    service::{
+        config::{
+            DbType,
+            Trigger,
+        },
+        Config,
+        FuelService,
+        ServiceTrait,
+    },
+};
+use fuel_core_types::{
+    fuel_crypto::SecretKey,
+    fuel_tx::Input,
+    fuel_types::BlockHeight,
+    secrecy::Secret,
+};
+use std::{
+    fmt,
+    net::{
+        Ipv4Addr,
+        SocketAddr,
+    },
+    time::Duration,
+};
+
+/// The name of the block producer of the [`Localnet`].
+pub const PRODUCER: &str = "producer";
+
+/// The configuration of the [`Localnet`].
+#[derive(Clone, Debug)]
+pub struct LocalnetConfig {
+    /// The number of the block producers. The PoA consensus supports only one producer.
+    pub validators: usize,
+    /// The number of the full nodes syncing the blocks from the producer.
+    pub full_nodes: usize,
+    /// The GraphQL port of the producer. The full nodes use the next ports.
+    /// The nodes use random ports if it is zero.
+    pub graphql_port: u16,
+    /// The p2p port of the producer. The full nodes use it to bootstrap
+    /// and listen on random ports.
+    pub p2p_port: u16,
+    /// The block production of the producer.
+    pub block_production: Trigger,
+    /// The base configuration of the nodes. The addresses, the keys, the database
+    /// and the block production are overridden for each node.
+    pub node: Config,
+}
+
+impl Default for LocalnetConfig {
+    fn default() -> Self {
+        Self {
+            validators: 1,
+            full_nodes: 3,
+            graphql_port: 4000,
+            p2p_port: 30333,
+            block_production: Trigger::Interval {
+                block_time: Duration::from_secs(1),
+            },
+            node: Config::local_node(),
+        }
+    }
+}
+
+/// The role of the node in the [`Localnet`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Role {
+    Producer,
+    FullNode,
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Role::Producer => f.write_str("producer"),
+            Role::FullNode => f.write_str("full node"),
+        }
+    }
+}
+
+/// The node of the [`Localnet`].
+pub struct LocalNode {
+    pub role: Role,
+    pub service: FuelService,
+}
+
+impl LocalNode {
+    pub fn name(&self) -> &str {
+        &self.service.shared.config.name
+    }
+}
+
+/// The snapshot of the state of the [`LocalNode`].
+#[derive(Clone, Debug)]
+pub struct NodeStatus {
+    pub name: String,
+    pub role: Role,
+    /// The address of the GraphQL API.
+    pub graphql: SocketAddr,
+    pub height: BlockHeight,
+    /// The number of the connected peers.
+    pub peers: usize,
+}
+
+impl fmt::Display for NodeStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}): height {}, {} peers, GraphQL http://{}/v1/graphql",
+            self.name, self.role, self.height, self.peers, self.graphql
+        )
+    }
+}
+
+/// The block producer and the full nodes connected over the local p2p.
+pub struct Localnet {
+    pub nodes: Vec<LocalNode>,
+}
+
+impl Localnet {
+    /// Generates the configs of the nodes and starts them, the producer first.
+    /// All nodes use the in-memory databases and share the chain config
+    /// with the generated PoA key.
+    pub async fn start(config: LocalnetConfig) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            config.validators == 1,
+            "The PoA consensus supports only one block producer, got {}",
+            config.validators
+        );
+        anyhow::ensure!(
+            config.p2p_port != 0,
+            "The full nodes need the known p2p port of the producer"
+        );
+
+        let secret = SecretKey::random(&mut rand::thread_rng());
+        let mut base = config.node;
+        match &mut base.chain_conf.consensus {
+            ConsensusConfig::PoA { signing_key } => {
+                *signing_key = Input::owner(&secret.public_key());
+            }
+        }
+        base.txpool.chain_config = base.chain_conf.clone();
+        base.database_type = DbType::InMemory;
+        base.utxo_validation = true;
+        base.consensus_key = None;
+        let graphql_port = |i: usize| -> anyhow::Result<u16> {
+            if config.graphql_port == 0 {
+                return Ok(0)
+            }
+            let offset = u16::try_from(i)?;
+            config
+                .graphql_port
+                .checked_add(offset)
+                .ok_or_else(|| anyhow::anyhow!("The GraphQL port is out of range"))
+        };
+
+        let mut producer = node_config(&base, PRODUCER.to_string(), graphql_port(0)?)?;
+        producer.block_production = config.block_production;
+        producer.consensus_key = Some(Secret::new(secret.into()));
+        let p2p = producer
+            .p2p
+            .as_mut()
+            .ok_or_else(|| anyhow::anyhow!("The p2p is disabled in the node config"))?;
+        p2p.tcp_port = config.p2p_port;
+        let bootstrap: Multiaddr = format!(
+            "/ip4/{}/tcp/{}/p2p/{}",
+            Ipv4Addr::LOCALHOST,
+            config.p2p_port,
+            p2p.peer_id()
+        )
+        .parse()?;
+
+        let mut nodes = Vec::with_capacity(config.full_nodes.saturating_add(1));
+        nodes.push(LocalNode {
+            role: Role::Producer,
+            service: FuelService::new_node(producer).await?,
+        });
+
+        for i in 0..config.full_nodes {
+            let name = format!("full-node:{i}");
+            let mut full_node =
+                node_config(&base, name, graphql_port(i.saturating_add(1))?)?;
+            full_node.block_production = Trigger::Never;
+            if let Some(p2p) = full_node.p2p.as_mut() {
+                p2p.bootstrap_nodes = vec![bootstrap.clone()];
+            }
+            let service = match FuelService::new_node(full_node).await {
+                Ok(service) => service,
+                Err(err) => {
+                    Self { nodes }.stop().await?;
+                    return Err(err)
+                }
+            };
+            nodes.push(LocalNode {
+                role: Role::FullNode,
+                service,
+            });
+        }
+
+        Ok(Self { nodes })
+    }
+
+    /// Returns the node by its name.
+    pub fn node(&self, name: &str) -> Option<&LocalNode> {
+        self.nodes.iter().find(|node| node.name() == name)
+    }
+
+    /// Returns the state of each node.
+    pub async fn status(&self) -> anyhow::Result<Vec<NodeStatus>> {
+        let mut statuses = Vec::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let shared = &node.service.shared;
+            let peers = match &shared.network {
+                Some(network) => network.get_peer_ids().await?.len(),
+                None => 0,
+            };
+            statuses.push(NodeStatus {
+                name: node.name().to_string(),
+                role: node.role,
+                graphql: node.service.bound_address,
+                height: shared.database.on_chain().latest_height()?,
+                peers,
+            });
+        }
+        Ok(statuses)
+    }
+
+    /// Stops the full nodes and then the producer.
+    pub async fn stop(self) -> anyhow::Result<()> {
+        for node in self.nodes.iter().rev() {
+            node.service.stop_and_await().await?;
+        }
+        Ok(())
+    }
+}
+
+fn node_config(base: &Config, name: String, graphql_port: u16) -> anyhow::Result<Config> {
+    let mut config = base.clone();
+    config.addr = SocketAddr::new(Ipv4Addr::LOCALHOST.into(), graphql_port);
+    config.name = name;
+    // Each node gets the new p2p key pair.
+    let mut p2p = Config::local_node()
+        .p2p
+        .ok_or_else(|| anyhow::anyhow!("The p2p is disabled in the local node config"))?;
+    if let Some(base) = &base.p2p {
+        p2p.network_name = base.network_name.clone();
+    }
+    p2p.address = Ipv4Addr::LOCALHOST.into();
+    config.p2p = Some(p2p);
+    Ok(config)
+}
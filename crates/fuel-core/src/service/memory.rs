@@ -0,0 +1,282 @@
+//! The accounting of the memory used by the subsystems of the node.
+//!
+//! The accountant periodically logs the memory taken by the caches, the buffers of
+//! the storage engine, and the `TxPool` against their budgets, along with the resident
+//! memory of the process. It helps to size the node running on a host with little memory:
+//! the difference between the resident memory and the accounted subsystems is taken by
+//! the VM instances, the network, and the allocator.
+
+use fuel_core_services::{
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use std::{
+    fmt,
+    time::Duration,
+};
+use tokio::time::{
+    Interval,
+    MissedTickBehavior,
+};
+
+/// The subsystem of the node with the accounted memory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Subsystem {
+    /// The block cache, the row cache, and the memtables of the databases.
+    StorageEngine,
+    ContractCache,
+    CoinCache,
+    /// The GraphQL cache of the blocks and of the transactions.
+    EntityCache,
+    TxPool,
+}
+
+impl Subsystem {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Subsystem::StorageEngine => "storage engine",
+            Subsystem::ContractCache => "contract cache",
+            Subsystem::CoinCache => "coin cache",
+            Subsystem::EntityCache => "GraphQL entity cache",
+            Subsystem::TxPool => "TxPool",
+        }
+    }
+}
+
+/// The memory in bytes used by the subsystem and its budget, if it has one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SubsystemUsage {
+    pub subsystem: Subsystem,
+    pub used: usize,
+    pub budget: Option<usize>,
+}
+
+impl SubsystemUsage {
+    pub fn is_over_budget(&self) -> bool {
+        self.budget
+            .map(|budget| self.used > budget)
+            .unwrap_or(false)
+    }
+}
+
+/// The memory used by the subsystems of the node at one moment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MemoryReport {
+    pub subsystems: Vec<SubsystemUsage>,
+    /// The resident memory of the process in bytes, if it is known.
+    pub resident: Option<usize>,
+}
+
+impl MemoryReport {
+    /// Pairs the memory used by each subsystem with its budget.
+    pub fn new(
+        usage: Vec<(Subsystem, usize)>,
+        budgets: &[(Subsystem, usize)],
+        resident: Option<usize>,
+    ) -> Self {
+        let subsystems = usage
+            .into_iter()
+            .map(|(subsystem, used)| SubsystemUsage {
+                subsystem,
+                used,
+                budget: budgets
+                    .iter()
+                    .find(|(budgeted, _)| *budgeted == subsystem)
+                    .map(|(_, budget)| *budget),
+            })
+            .collect();
+        Self {
+            subsystems,
+            resident,
+        }
+    }
+
+    /// The total memory used by the accounted subsystems.
+    pub fn accounted(&self) -> usize {
+        self.subsystems
+            .iter()
+            .fold(0usize, |sum, usage| sum.saturating_add(usage.used))
+    }
+}
+
+impl fmt::Display for MemoryReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for usage in &self.subsystems {
+            write!(f, "{}: {}", usage.subsystem.name(), mib(usage.used))?;
+            if let Some(budget) = usage.budget {
+                write!(f, " of {}", mib(budget))?;
+            }
+            write!(f, ", ")?;
+        }
+        write!(f, "accounted: {}", mib(self.accounted()))?;
+        if let Some(resident) = self.resident {
+            write!(f, ", resident: {}", mib(resident))?;
+        }
+        Ok(())
+    }
+}
+
+const MIB: usize = 1024 * 1024;
+
+fn mib(bytes: usize) -> String {
+    let tenths = bytes.saturating_mul(10) / MIB;
+    format!("{}.{} MiB", tenths / 10, tenths % 10)
+}
+
+/// Reports the memory used by the subsystems of the node.
+pub trait MemoryPort: Send + Sync {
+    /// Returns the memory in bytes used by each subsystem.
+    fn usage(&self) -> anyhow::Result<Vec<(Subsystem, usize)>>;
+}
+
+/// Returns the resident memory of the process in bytes, if it is known.
+fn resident_memory() -> Option<usize> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse::<usize>()
+        .ok()?;
+    kib.checked_mul(1024)
+}
+
+pub struct Task {
+    port: Box<dyn MemoryPort>,
+    budgets: Vec<(Subsystem, usize)>,
+    report_interval: Duration,
+    /// The timer of the reports, started with the task.
+    interval: Option<Interval>,
+}
+
+impl Task {
+    fn report(&self) {
+        let usage = match self.port.usage() {
+            Ok(usage) => usage,
+            Err(err) => {
+                tracing::warn!("Failed to account the memory usage: {err}");
+                return
+            }
+        };
+        let report = MemoryReport::new(usage, &self.budgets, resident_memory());
+        tracing::info!("Memory usage: {report}");
+        for usage in report
+            .subsystems
+            .iter()
+            .filter(|usage| usage.is_over_budget())
+        {
+            tracing::warn!(
+                "The {} uses {} bytes and exceeds its budget of {} bytes",
+                usage.subsystem.name(),
+                usage.used,
+                usage.budget.unwrap_or_default()
+            );
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableService for Task {
+    const NAME: &'static str = "MemoryAccountant";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        mut self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        let mut interval = tokio::time::interval(self.report_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        self.interval = Some(interval);
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl RunnableTask for Task {
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        let Some(interval) = self.interval.as_mut() else {
+            return Ok(false)
+        };
+        tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                return Ok(false)
+            }
+            _ = interval.tick() => {
+                self.report();
+            }
+        }
+        Ok(true)
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+pub type Service = ServiceRunner<Task>;
+
+/// Creates the service logging the memory used by the subsystems against
+/// their `budgets` every `report_interval`.
+pub fn new_service(
+    report_interval: Duration,
+    budgets: Vec<(Subsystem, usize)>,
+    port: Box<dyn MemoryPort>,
+) -> Service {
+    ServiceRunner::new(Task {
+        port,
+        budgets,
+        report_interval,
+        interval: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn report_pairs_usage_with_budgets() {
+        // Given
+        let usage = vec![
+            (Subsystem::StorageEngine, 48 * MIB),
+            (Subsystem::CoinCache, MIB / 2),
+            (Subsystem::TxPool, 20 * MIB),
+        ];
+        let budgets = [
+            (Subsystem::StorageEngine, 64 * MIB),
+            (Subsystem::TxPool, 16 * MIB),
+        ];
+
+        // When
+        let report = MemoryReport::new(usage, &budgets, Some(300 * MIB));
+
+        // Then
+        let over_budget = report
+            .subsystems
+            .iter()
+            .filter(|usage| usage.is_over_budget())
+            .map(|usage| usage.subsystem)
+            .collect::<Vec<_>>();
+        assert_eq!(over_budget, vec![Subsystem::TxPool]);
+        assert_eq!(report.subsystems[1].budget, None);
+        assert_eq!(
+            report.to_string(),
+            "storage engine: 48.0 MiB of 64.0 MiB, coin cache: 0.5 MiB, \
+             TxPool: 20.0 MiB of 16.0 MiB, accounted: 68.5 MiB, resident: 300.0 MiB"
+        );
+    }
+}
@@ -0,0 +1,549 @@
+//! The audit of the database after an unclean shutdown of the node.
+//!
+//! The node creates the [`RunningMarker`] in the directory of the database on start and
+//! removes it on the clean shutdown. If the marker of the previous run is left, the node
+//! crashed or was killed, and the latest committed block is audited before the node
+//! serves its state: the block is complete, its header matches its transactions and
+//! the merkle root of the previous blocks, and the roots of the contracts touched by
+//! the block are stored.
+//!
+//! The database doesn't keep the state of the previous blocks, so the latest block can't
+//! be replayed from its parent. If the on-chain state is inconsistent, the node refuses
+//! to start with the recovery report instead of serving the corrupted state, and the
+//! database has to be restored from a snapshot or synced from the network.
+
+use crate::{
+    combined_database::CombinedDatabase,
+    service::{
+        Config,
+        DbType,
+    },
+};
+use fuel_core_storage::{
+    tables::{
+        merkle::{
+            ContractsAssetsMerkleData,
+            ContractsStateMerkleData,
+        },
+        ContractsAssets,
+        ContractsState,
+        FuelBlocks,
+        SealedBlockConsensus,
+        Transactions,
+    },
+    IsNotFound,
+    MerkleRoot,
+    Result as StorageResult,
+    StorageAsRef,
+};
+use fuel_core_types::{
+    blockchain::{
+        consensus::Consensus,
+        header::HeaderField,
+    },
+    fuel_merkle::sparse,
+    fuel_tx::{
+        field::{
+            InputContract,
+            Outputs,
+        },
+        ContractId,
+        Executable,
+        Output,
+        Transaction,
+        TxId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Bytes32,
+    },
+};
+use std::{
+    collections::BTreeSet,
+    fmt,
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+use thiserror::Error;
+
+/// The name of the marker file in the directory of the database.
+pub const RUNNING_MARKER: &str = "RUNNING";
+
+/// The marker of the node using the database. It is removed on the clean shutdown.
+#[derive(Debug)]
+pub struct RunningMarker {
+    path: PathBuf,
+}
+
+impl RunningMarker {
+    fn path(database_path: &Path) -> PathBuf {
+        database_path.join(RUNNING_MARKER)
+    }
+
+    /// Returns `true` if the marker of the previous run is left in the `database_path`.
+    pub fn is_left(database_path: &Path) -> bool {
+        Self::path(database_path).exists()
+    }
+
+    /// Creates the marker with the id of the current process.
+    pub fn create(database_path: &Path) -> io::Result<Self> {
+        let path = Self::path(database_path);
+        fs::write(&path, std::process::id().to_string())?;
+        Ok(Self { path })
+    }
+
+    /// Removes the marker after the database is flushed.
+    pub fn remove(self) -> io::Result<()> {
+        fs::remove_file(self.path)
+    }
+}
+
+/// The inconsistency of the database found by the audit.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum Inconsistency {
+    #[error("the metadata is at the height {metadata}, but the latest block is {block}")]
+    HeightMismatch {
+        metadata: BlockHeight,
+        block: BlockHeight,
+    },
+    #[error("the consensus of the block is missing")]
+    MissingConsensus,
+    #[error("the transaction {0} of the block is missing")]
+    MissingTransaction(TxId),
+    #[error("the {0} of the header doesn't match the transactions")]
+    TransactionsMismatch(HeaderField),
+    #[error("the application hash of the header doesn't match the header")]
+    ApplicationHashMismatch,
+    #[error("the merkle root of the blocks up to {0} is missing")]
+    MissingBlocksRoot(BlockHeight),
+    #[error(
+        "the previous root of the header doesn't match the merkle root of the blocks"
+    )]
+    PrevRootMismatch,
+    #[error("the root node of the {tree} of the contract {contract_id} is missing")]
+    MissingContractRoot {
+        contract_id: ContractId,
+        tree: &'static str,
+    },
+    #[error("the off-chain database is behind at the height {0}")]
+    OffChainBehind(BlockHeight),
+}
+
+impl Inconsistency {
+    /// Returns `true` if the on-chain state can't be served. The off-chain database only
+    /// misses the indexes of the latest blocks used by the GraphQL API.
+    pub fn is_fatal(&self) -> bool {
+        !matches!(self, Self::OffChainBehind(_))
+    }
+}
+
+/// The result of the audit of the latest block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecoveryReport {
+    /// The height of the latest block, if the database has any.
+    pub height: Option<BlockHeight>,
+    pub inconsistencies: Vec<Inconsistency>,
+}
+
+impl RecoveryReport {
+    /// Returns `true` if the on-chain state can be served.
+    pub fn is_consistent(&self) -> bool {
+        !self.inconsistencies.iter().any(Inconsistency::is_fatal)
+    }
+}
+
+impl fmt::Display for RecoveryReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.height {
+            Some(height) => write!(f, "the audit of the block {height}")?,
+            None => write!(f, "the audit of the empty database")?,
+        }
+        if self.inconsistencies.is_empty() {
+            return write!(f, " found no inconsistencies")
+        }
+        write!(f, " found:")?;
+        for inconsistency in &self.inconsistencies {
+            write!(f, "\n - {inconsistency}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Audits the latest block if the previous run of the node didn't shut down cleanly,
+/// and marks the database as used by the node. Returns the marker to remove on
+/// the clean shutdown, or `None` if the database isn't stored on disk.
+pub fn audit_on_startup(
+    config: &Config,
+    database: &CombinedDatabase,
+) -> anyhow::Result<Option<RunningMarker>> {
+    let path = &config.database_path;
+    if config.database_type != DbType::RocksDb || !path.is_dir() {
+        return Ok(None)
+    }
+
+    if RunningMarker::is_left(path) {
+        tracing::warn!(
+            "The node didn't shut down cleanly, auditing the latest block of {path:?}"
+        );
+        let report = audit(database)?;
+        if !report.is_consistent() {
+            tracing::error!("Recovery report: {report}");
+            anyhow::bail!(
+                "The database {path:?} is inconsistent after the unclean shutdown, \
+                 restore it from a snapshot or sync it from the network: {report}"
+            )
+        }
+        if report.inconsistencies.is_empty() {
+            tracing::info!("Recovery report: {report}");
+        } else {
+            tracing::warn!("Recovery report: {report}");
+        }
+    }
+
+    Ok(Some(RunningMarker::create(path)?))
+}
+
+/// Audits the latest block of the `database`.
+pub fn audit(database: &CombinedDatabase) -> StorageResult<RecoveryReport> {
+    let on_chain = database.on_chain();
+    let Some(block) = on_chain.latest_compressed_block()? else {
+        return Ok(RecoveryReport {
+            height: None,
+            inconsistencies: vec![],
+        })
+    };
+    let header = block.header();
+    let height = *header.height();
+    let mut inconsistencies = vec![];
+
+    let metadata_height = on_chain.latest_height()?;
+    if metadata_height != height {
+        inconsistencies.push(Inconsistency::HeightMismatch {
+            metadata: metadata_height,
+            block: height,
+        });
+    }
+
+    let consensus = on_chain.storage::<SealedBlockConsensus>().get(&height)?;
+    if consensus.is_none() {
+        inconsistencies.push(Inconsistency::MissingConsensus);
+    }
+
+    let mut transactions = Vec::with_capacity(block.transactions().len());
+    for tx_id in block.transactions() {
+        match on_chain.storage::<Transactions>().get(tx_id)? {
+            Some(tx) => transactions.push(tx.into_owned()),
+            None => inconsistencies.push(Inconsistency::MissingTransaction(*tx_id)),
+        }
+    }
+    if transactions.len() == block.transactions().len() {
+        if let Some(field) = header.mismatched_transactions_field(&transactions) {
+            inconsistencies.push(Inconsistency::TransactionsMismatch(field));
+        }
+    }
+
+    if header.application().hash() != *header.application_hash() {
+        inconsistencies.push(Inconsistency::ApplicationHashMismatch);
+    }
+
+    let is_genesis = matches!(consensus.as_deref(), Some(Consensus::Genesis(_)));
+    if let Some(prev_height) = height.pred().filter(|_| !is_genesis) {
+        if let Some(prev_root) =
+            blocks_root(database, &prev_height, &mut inconsistencies)?
+        {
+            if header.prev_root() != &Bytes32::from(prev_root) {
+                inconsistencies.push(Inconsistency::PrevRootMismatch);
+            }
+        }
+    }
+    blocks_root(database, &height, &mut inconsistencies)?;
+
+    let empty_root = sparse::in_memory::MerkleTree::new().root();
+    for contract_id in touched_contracts(&transactions) {
+        let state_root = on_chain.storage::<ContractsState>().root(&contract_id)?;
+        if state_root != empty_root
+            && !on_chain
+                .storage::<ContractsStateMerkleData>()
+                .contains_key(&state_root)?
+        {
+            inconsistencies.push(Inconsistency::MissingContractRoot {
+                contract_id,
+                tree: "state",
+            });
+        }
+
+        let assets_root = on_chain.storage::<ContractsAssets>().root(&contract_id)?;
+        if assets_root != empty_root
+            && !on_chain
+                .storage::<ContractsAssetsMerkleData>()
+                .contains_key(&assets_root)?
+        {
+            inconsistencies.push(Inconsistency::MissingContractRoot {
+                contract_id,
+                tree: "assets",
+            });
+        }
+    }
+
+    let off_chain_height = database.off_chain().latest_height()?;
+    if off_chain_height < height {
+        inconsistencies.push(Inconsistency::OffChainBehind(off_chain_height));
+    }
+
+    Ok(RecoveryReport {
+        height: Some(height),
+        inconsistencies,
+    })
+}
+
+fn blocks_root(
+    database: &CombinedDatabase,
+    height: &BlockHeight,
+    inconsistencies: &mut Vec<Inconsistency>,
+) -> StorageResult<Option<MerkleRoot>> {
+    match database.on_chain().storage::<FuelBlocks>().root(height) {
+        Ok(root) => Ok(Some(root)),
+        Err(err) if err.is_not_found() => {
+            inconsistencies.push(Inconsistency::MissingBlocksRoot(*height));
+            Ok(None)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Returns the contracts used or created by the `transactions`.
+fn touched_contracts(transactions: &[Transaction]) -> BTreeSet<ContractId> {
+    let mut contracts = BTreeSet::new();
+    for tx in transactions {
+        match tx {
+            Transaction::Script(script) => {
+                contracts.extend(script.input_contracts().copied());
+            }
+            Transaction::Create(create) => {
+                contracts.extend(create.input_contracts().copied());
+                contracts.extend(create.outputs().iter().filter_map(
+                    |output| match output {
+                        Output::ContractCreated { contract_id, .. } => Some(*contract_id),
+                        _ => None,
+                    },
+                ));
+            }
+            Transaction::Mint(mint) => {
+                contracts.insert(mint.input_contract().contract_id);
+            }
+        }
+    }
+    contracts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{
+        database_description::{
+            off_chain::OffChain,
+            DatabaseDescription,
+            DatabaseMetadata,
+        },
+        metadata::MetadataTable,
+    };
+    use fuel_core_storage::StorageAsMut;
+    use fuel_core_types::{
+        blockchain::{
+            block::PartialFuelBlock,
+            header::{
+                ConsensusHeader,
+                PartialBlockHeader,
+            },
+            primitives::Empty,
+        },
+        fuel_tx::UniqueIdentifier,
+        fuel_types::ChainId,
+    };
+
+    fn commit_block(
+        database: &mut CombinedDatabase,
+        transactions: Vec<Transaction>,
+        store_transactions: bool,
+    ) -> BlockHeight {
+        let on_chain = database.on_chain_mut();
+        let (height, prev_root, consensus) = match on_chain.latest_height() {
+            Ok(prev_height) => {
+                let prev_root = on_chain.storage::<FuelBlocks>().root(&prev_height);
+                (
+                    prev_height.succ().unwrap(),
+                    prev_root.unwrap().into(),
+                    Consensus::default(),
+                )
+            }
+            Err(_) => (
+                BlockHeight::from(0),
+                Bytes32::zeroed(),
+                Consensus::Genesis(Default::default()),
+            ),
+        };
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader::<Empty> {
+                prev_root,
+                height,
+                ..Default::default()
+            },
+        };
+        let block = PartialFuelBlock::new(header, transactions).generate(&[]);
+
+        if store_transactions {
+            for tx in block.transactions() {
+                on_chain
+                    .storage_as_mut::<Transactions>()
+                    .insert(&tx.id(&ChainId::default()), tx)
+                    .unwrap();
+            }
+        }
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height, &block.compress(&ChainId::default()))
+            .unwrap();
+        on_chain
+            .storage_as_mut::<SealedBlockConsensus>()
+            .insert(&height, &consensus)
+            .unwrap();
+        set_off_chain_height(database, height);
+        height
+    }
+
+    fn set_off_chain_height(database: &mut CombinedDatabase, height: BlockHeight) {
+        database
+            .off_chain_mut()
+            .storage_as_mut::<MetadataTable<OffChain>>()
+            .insert(
+                &(),
+                &DatabaseMetadata::V1 {
+                    version: OffChain::version(),
+                    height,
+                },
+            )
+            .unwrap();
+    }
+
+    fn database_with_blocks(count: usize) -> CombinedDatabase {
+        let mut database = CombinedDatabase::in_memory();
+        for _ in 0..count {
+            commit_block(&mut database, vec![], true);
+        }
+        database
+    }
+
+    #[test]
+    fn empty_database_is_consistent() {
+        // When
+        let report = audit(&CombinedDatabase::in_memory()).unwrap();
+
+        // Then
+        assert_eq!(report.height, None);
+        assert!(report.is_consistent());
+    }
+
+    #[test]
+    fn committed_blocks_are_consistent() {
+        // Given
+        let mut database = database_with_blocks(3);
+        let height =
+            commit_block(&mut database, vec![Transaction::default_test_tx()], true);
+
+        // When
+        let report = audit(&database).unwrap();
+
+        // Then
+        assert_eq!(report.height, Some(height));
+        assert_eq!(report.inconsistencies, vec![]);
+    }
+
+    #[test]
+    fn missing_transaction_is_fatal() {
+        // Given
+        let mut database = database_with_blocks(1);
+        let tx = Transaction::default_test_tx();
+        let tx_id = tx.id(&ChainId::default());
+        commit_block(&mut database, vec![tx], false);
+
+        // When
+        let report = audit(&database).unwrap();
+
+        // Then
+        assert_eq!(
+            report.inconsistencies,
+            vec![Inconsistency::MissingTransaction(tx_id)]
+        );
+        assert!(!report.is_consistent());
+    }
+
+    #[test]
+    fn wrong_prev_root_is_fatal() {
+        // Given
+        let mut database = database_with_blocks(2);
+        let height = BlockHeight::from(2);
+        let header = PartialBlockHeader {
+            application: Default::default(),
+            consensus: ConsensusHeader::<Empty> {
+                height,
+                ..Default::default()
+            },
+        };
+        let block = PartialFuelBlock::new(header, vec![]).generate(&[]);
+        let on_chain = database.on_chain_mut();
+        on_chain
+            .storage_as_mut::<FuelBlocks>()
+            .insert(&height, &block.compress(&ChainId::default()))
+            .unwrap();
+        on_chain
+            .storage_as_mut::<SealedBlockConsensus>()
+            .insert(&height, &Consensus::default())
+            .unwrap();
+
+        // When
+        let report = audit(&database).unwrap();
+
+        // Then
+        assert!(report
+            .inconsistencies
+            .contains(&Inconsistency::PrevRootMismatch));
+        assert!(!report.is_consistent());
+    }
+
+    #[test]
+    fn lagging_off_chain_database_is_not_fatal() {
+        // Given
+        let mut database = database_with_blocks(2);
+        set_off_chain_height(&mut database, BlockHeight::from(0));
+
+        // When
+        let report = audit(&database).unwrap();
+
+        // Then
+        assert_eq!(
+            report.inconsistencies,
+            vec![Inconsistency::OffChainBehind(BlockHeight::from(0))]
+        );
+        assert!(report.is_consistent());
+    }
+
+    #[test]
+    fn marker_is_left_until_removed() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!RunningMarker::is_left(dir.path()));
+
+        // When
+        let marker = RunningMarker::create(dir.path()).unwrap();
+
+        // Then
+        assert!(RunningMarker::is_left(dir.path()));
+        marker.remove().unwrap();
+        assert!(!RunningMarker::is_left(dir.path()));
+    }
+}
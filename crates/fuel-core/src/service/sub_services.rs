@@ -4,26 +4,37 @@ use crate::{
     combined_database::CombinedDatabase,
     database::Database,
     fuel_core_graphql_api,
-    fuel_core_graphql_api::Config as GraphQLConfig,
+    fuel_core_graphql_api::{
+        scheduler::RequestScheduler,
+        Config as GraphQLConfig,
+    },
     schema::build_schema,
     service::{
         adapters::{
+            consensus_module::poa::load_block_production_pause,
+            AlertsAdapter,
             BlockImporterAdapter,
             BlockProducerAdapter,
             ExecutorAdapter,
             MaybeRelayerAdapter,
+            MemoryAdapter,
             PoAAdapter,
             TxPoolAdapter,
             VerifierAdapter,
         },
         genesis::create_genesis_block,
+        memory::Subsystem,
         Config,
         SharedState,
         SubServices,
     },
+    state::slow_reads,
 };
 use fuel_core_poa::Trigger;
+use fuel_core_services::stream::IntoBoxStream;
+use futures::StreamExt;
 use std::sync::Arc;
+#[cfg(feature = "producer")]
 use tokio::sync::Mutex;
 
 #[cfg(feature = "relayer")]
@@ -31,11 +42,13 @@ use crate::relayer::Config as RelayerConfig;
 #[cfg(feature = "relayer")]
 use fuel_core_types::blockchain::primitives::DaBlockHeight;
 
+#[cfg(feature = "producer")]
 pub type PoAService =
     fuel_core_poa::Service<TxPoolAdapter, BlockProducerAdapter, BlockImporterAdapter>;
 #[cfg(feature = "p2p")]
 pub type P2PService = fuel_core_p2p::service::Service<Database>;
 pub type TxPoolService = fuel_core_txpool::Service<P2PAdapter, Database>;
+#[cfg(feature = "producer")]
 pub type BlockProducerService = fuel_core_producer::block_producer::Producer<
     Database,
     TxPoolAdapter,
@@ -47,6 +60,10 @@ pub fn init_sub_services(
     config: &Config,
     database: CombinedDatabase,
 ) -> anyhow::Result<(SubServices, SharedState)> {
+    if let Some(threshold) = config.storage_log_threshold_time {
+        slow_reads::set_threshold(threshold);
+    }
+
     let last_block_header = database
         .on_chain()
         .get_current_block()?
@@ -57,18 +74,31 @@ pub fn init_sub_services(
         });
     let last_height = *last_block_header.height();
 
+    let request_scheduler = RequestScheduler::new(config.graphql_scheduler.clone());
+
     let executor = ExecutorAdapter::new(
         database.on_chain().clone(),
         database.relayer().clone(),
         fuel_core_executor::Config {
             consensus_parameters: config.chain_conf.consensus_parameters.clone(),
+            #[cfg(feature = "producer")]
             coinbase_recipient: config
                 .block_producer
                 .coinbase_recipient
                 .unwrap_or_default(),
+            // Without the producer, the node only validates the blocks of the others.
+            #[cfg(not(feature = "producer"))]
+            coinbase_recipient: Default::default(),
             backtrace: config.vm.backtrace,
             utxo_validation_default: config.utxo_validation,
+            strict_header_verification: config.block_importer.strict_header_verification,
+            block_gas_limit: config.chain_conf.block_gas_limit,
+            fee_free_allowance: config.chain_conf.fee_free_allowance.clone(),
+            blob_pricing: config.chain_conf.blob_pricing.clone(),
+            reexecution_audit: config.vm.reexecution_audit,
+            vm_metrics: config.vm.metrics,
         },
+        request_scheduler.clone(),
     );
 
     let verifier = VerifierAdapter::new(config, database.on_chain().clone());
@@ -141,17 +171,20 @@ pub fn init_sub_services(
     );
     let tx_pool_adapter = TxPoolAdapter::new(txpool.shared.clone());
 
-    let block_producer = fuel_core_producer::Producer {
+    #[cfg(feature = "producer")]
+    let producer_adapter = BlockProducerAdapter::new(fuel_core_producer::Producer {
         config: config.block_producer.clone(),
         view_provider: database.on_chain().clone(),
         txpool: tx_pool_adapter.clone(),
         executor: Arc::new(executor),
         relayer: Box::new(relayer_adapter.clone()),
         lock: Mutex::new(()),
-    };
-    let producer_adapter = BlockProducerAdapter::new(block_producer);
+    });
+    #[cfg(not(feature = "producer"))]
+    let producer_adapter = BlockProducerAdapter::new();
 
-    let poa_config: fuel_core_poa::Config = config.into();
+    let mut poa_config: fuel_core_poa::Config = config.into();
+    poa_config.paused = load_block_production_pause(config.poa_pause_path.as_deref())?;
     let mut production_enabled = !matches!(poa_config.trigger, Trigger::Never);
 
     if !production_enabled && config.debug {
@@ -159,6 +192,7 @@ pub fn init_sub_services(
         tracing::info!("Enabled manual block production because of `debug` flag");
     }
 
+    #[cfg(feature = "producer")]
     let poa = (production_enabled).then(|| {
         fuel_core_poa::new_service(
             &last_block_header,
@@ -169,7 +203,44 @@ pub fn init_sub_services(
             p2p_adapter.clone(),
         )
     });
-    let poa_adapter = PoAAdapter::new(poa.as_ref().map(|service| service.shared.clone()));
+    #[cfg(feature = "producer")]
+    let poa_shared = poa.as_ref().map(|service| service.shared.clone());
+    #[cfg(not(feature = "producer"))]
+    let poa_shared = {
+        if production_enabled {
+            tracing::warn!(
+                "The block production is disabled because the block producer \
+                 isn't included in the node"
+            );
+        }
+        None
+    };
+    let poa_adapter = PoAAdapter::new(poa_shared, config.poa_pause_path.clone());
+
+    let (alerts, alert_reporter) = config
+        .alerts
+        .clone()
+        .map(|alerts_config| {
+            let (service, reporter) = super::alerts::new_service(
+                alerts_config,
+                config.name.clone(),
+                Box::new(AlertsAdapter {
+                    poa: poa_adapter.clone(),
+                    relayer: relayer_adapter.clone(),
+                }),
+                importer_adapter
+                    .events()
+                    .map(|result| *result.sealed_block.entity.header().height())
+                    .into_boxed(),
+                last_height,
+            );
+            (Some(service), reporter)
+        })
+        .unwrap_or_default();
+
+    // Only the synchronization with the network finds the conflicting blocks.
+    #[cfg(not(feature = "p2p"))]
+    drop(alert_reporter);
 
     #[cfg(feature = "p2p")]
     let sync = fuel_core_sync::service::new_service(
@@ -180,7 +251,13 @@ pub fn init_sub_services(
             verifier.clone(),
             config.relayer_consensus_config.clone(),
             relayer_adapter,
+            database.off_chain().clone(),
+            alert_reporter,
         ),
+        config.era_source.clone().map(|source| {
+            Arc::new(super::adapters::sync::EraArchive::new(source))
+                as fuel_core_sync::import::SharedArchive
+        }),
         config.sync,
     )?;
 
@@ -192,11 +269,47 @@ pub fn init_sub_services(
     )
     .data(database.on_chain().clone());
 
+    let receipts_pruning = config.receipts_pruning.clone().map(|pruning| {
+        fuel_core_graphql_api::worker_service::ReceiptsPruning {
+            retention: pruning.retention,
+            archive: pruning.archive.map(|sink| {
+                Arc::new(super::adapters::graphql_api::ReceiptsArchiveAdapter::new(
+                    sink,
+                ))
+                    as Arc<dyn fuel_core_graphql_api::ports::worker::ReceiptsArchive>
+            }),
+        }
+    });
     let graphql_worker = fuel_core_graphql_api::worker_service::new_service(
         tx_pool_adapter.clone(),
         importer_adapter.clone(),
         database.off_chain().clone(),
+        *config.chain_conf.consensus_parameters.base_asset_id(),
+        receipts_pruning,
     );
+    let schema = schema.data(graphql_worker.shared.clone());
+
+    #[cfg(feature = "p2p")]
+    let p2p_identity =
+        config
+            .p2p
+            .as_ref()
+            .map(|p2p| fuel_core_graphql_api::P2pIdentity {
+                peer_id: p2p.peer_id().to_string(),
+                addresses: p2p
+                    .advertised_addresses()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+                protocol_versions: p2p.protocol_versions(),
+            });
+    #[cfg(not(feature = "p2p"))]
+    let p2p_identity = None;
+
+    #[cfg(feature = "relayer")]
+    let relayer_enabled = config.relayer.is_some();
+    #[cfg(not(feature = "relayer"))]
+    let relayer_enabled = false;
 
     let graphql_config = GraphQLConfig {
         addr: config.addr,
@@ -209,8 +322,32 @@ pub fn init_sub_services(
         chain_name: config.chain_conf.chain_name.clone(),
         consensus_parameters: config.chain_conf.consensus_parameters.clone(),
         consensus_key: config.consensus_key.clone(),
+        admin_token: config.admin_token,
+        persisted_queries_only: config.api_persisted_queries_only,
+        persisted_queries_path: config.api_persisted_queries_path.clone(),
+        p2p_identity,
+        relayer_enabled,
+        entity_cache_size: config.api_entity_cache_size,
+        coin_lease_max_ttl: config.api_coin_lease_max_ttl,
     };
 
+    #[cfg(feature = "grpc")]
+    let grpc = config
+        .grpc_addr
+        .map(|addr| {
+            crate::grpc_api::service::new_service(
+                crate::grpc_api::Config {
+                    addr,
+                    consensus_parameters: config.chain_conf.consensus_parameters.clone(),
+                },
+                database.on_chain().clone(),
+                database.off_chain().clone(),
+                Box::new(tx_pool_adapter.clone()),
+                importer_adapter.clone(),
+            )
+        })
+        .transpose()?;
+
     let graph_ql = fuel_core_graphql_api::api_service::new_service(
         graphql_config,
         schema,
@@ -218,12 +355,60 @@ pub fn init_sub_services(
         database.off_chain().clone(),
         Box::new(tx_pool_adapter),
         Box::new(producer_adapter),
-        Box::new(poa_adapter.clone()),
+        Arc::new(poa_adapter.clone()),
         Box::new(p2p_adapter),
+        Box::new(database.relayer().clone()),
+        Box::new(database.off_chain().clone()),
+        Box::new(database.clone()),
         config.query_log_threshold_time,
         config.api_request_timeout,
+        config.graphql_websocket.clone(),
+        config.graphql_http.clone(),
+        request_scheduler,
     )?;
 
+    let memory_accountant = config.memory_report_interval.map(|interval| {
+        let mut budgets = vec![
+            (Subsystem::StorageEngine, config.max_database_cache_size),
+            (Subsystem::ContractCache, config.contract_cache_size),
+            (Subsystem::EntityCache, config.api_entity_cache_size),
+        ];
+        // The `TxPool` has the budget only if both of its size limits are set.
+        let txpool_config = &config.txpool;
+        if txpool_config.max_tx_bytes != usize::MAX
+            && txpool_config.max_blob_tx_bytes != usize::MAX
+        {
+            budgets.push((
+                Subsystem::TxPool,
+                txpool_config
+                    .max_tx_bytes
+                    .saturating_add(txpool_config.max_blob_tx_bytes),
+            ));
+        }
+        super::memory::new_service(
+            interval,
+            budgets,
+            Box::new(MemoryAdapter {
+                database: database.clone(),
+                txpool: TxPoolAdapter::new(txpool.shared.clone()),
+                entity_cache: graph_ql.shared.entity_cache.clone(),
+            }),
+        )
+    });
+
+    #[cfg(feature = "shared-sequencer")]
+    let shared_sequencer = config.shared_sequencer.as_ref().map(|config| {
+        fuel_core_shared_sequencer::new_service(
+            config.clone(),
+            importer_adapter.clone(),
+            fuel_core_shared_sequencer::http::HttpSink::new(config.endpoint.clone()),
+            super::adapters::shared_sequencer::SharedSequencerDatabase::new(
+                database.on_chain().clone(),
+                database.off_chain().clone(),
+            ),
+        )
+    });
+
     let shared = SharedState {
         poa_adapter,
         txpool: txpool.shared.clone(),
@@ -232,6 +417,8 @@ pub fn init_sub_services(
         #[cfg(feature = "relayer")]
         relayer: relayer_service.as_ref().map(|r| r.shared.clone()),
         graph_ql: graph_ql.shared.clone(),
+        #[cfg(feature = "grpc")]
+        grpc: grpc.as_ref().map(|grpc| grpc.shared.clone()),
         database,
         block_importer: importer_adapter,
         config: config.clone(),
@@ -242,9 +429,16 @@ pub fn init_sub_services(
     let mut services: SubServices = vec![
         // GraphQL should be shutdown first, so let's start it first.
         Box::new(graph_ql),
-        Box::new(txpool),
     ];
 
+    #[cfg(feature = "grpc")]
+    if let Some(grpc) = grpc {
+        services.push(Box::new(grpc));
+    }
+
+    services.push(Box::new(txpool));
+
+    #[cfg(feature = "producer")]
     if let Some(poa) = poa {
         services.push(Box::new(poa));
     }
@@ -262,7 +456,20 @@ pub fn init_sub_services(
         }
     }
 
+    #[cfg(feature = "shared-sequencer")]
+    if let Some(shared_sequencer) = shared_sequencer {
+        services.push(Box::new(shared_sequencer));
+    }
+
     services.push(Box::new(graphql_worker));
 
+    if let Some(alerts) = alerts {
+        services.push(Box::new(alerts));
+    }
+
+    if let Some(memory_accountant) = memory_accountant {
+        services.push(Box::new(memory_accountant));
+    }
+
     Ok((services, shared))
 }
@@ -0,0 +1,171 @@
+//! The LRU cache bounded by the total size of the values, shared by the caches
+//! of the database and of the GraphQL API.
+
+use lru::LruCache;
+use std::{
+    borrow::Borrow,
+    hash::Hash,
+};
+
+/// The LRU cache bounded by the total size of the values.
+/// The size of each value is given on the insertion, so the caches bounded
+/// by the number of values use the size `1`.
+///
+/// The caches in front of the mutable storage fetch the missing values without
+/// holding the lock of the cache, so the write could change the value in the meantime.
+/// Each removal increments the generation, and [`SizedLru::insert_fetched`] discards
+/// the values fetched before the removal.
+#[derive(Debug)]
+pub(crate) struct SizedLru<K: Hash + Eq, V> {
+    entries: LruCache<K, (V, usize)>,
+    capacity: usize,
+    size: usize,
+    generation: u64,
+}
+
+impl<K, V> SizedLru<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Creates the cache holding the values with the total size of at most `capacity`.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: LruCache::unbounded(),
+            capacity,
+            size: 0,
+            generation: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// The total size of the cached values.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The generation to pass to [`SizedLru::insert_fetched`] with the value
+    /// fetched from the storage after the miss.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the cached value and marks it as the most recently used.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.get(key).map(|(value, _)| value)
+    }
+
+    /// Returns the cached value without changing the order of the values.
+    #[cfg(test)]
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.entries.peek(key).map(|(value, _)| value)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.iter().map(|(key, (value, _))| (key, value))
+    }
+
+    /// Inserts the value and evicts the least recently used values above the capacity.
+    /// The value larger than the capacity is not cached.
+    pub fn insert(&mut self, key: K, value: V, size: usize) {
+        if size > self.capacity {
+            return
+        }
+        if let Some((_, old_size)) = self.entries.put(key, (value, size)) {
+            self.size = self.size.saturating_sub(old_size);
+        }
+        self.size = self.size.saturating_add(size);
+        while self.size > self.capacity {
+            let Some((_, (_, evicted_size))) = self.entries.pop_lru() else {
+                break
+            };
+            self.size = self.size.saturating_sub(evicted_size);
+        }
+    }
+
+    /// Inserts the value fetched from the storage if nothing was removed
+    /// since the `generation` was taken.
+    pub fn insert_fetched(&mut self, key: K, value: V, size: usize, generation: u64) {
+        if self.generation == generation {
+            self.insert(key, value, size);
+        }
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.generation = self.generation.wrapping_add(1);
+        if let Some((_, old_size)) = self.entries.pop(key) {
+            self.size = self.size.saturating_sub(old_size);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.generation = self.generation.wrapping_add(1);
+        self.entries.clear();
+        self.size = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_is_bounded_by_size_of_values() {
+        // Given
+        let mut cache = SizedLru::new(10);
+
+        // When
+        for key in [1, 2, 3] {
+            cache.insert(key, (), 4);
+        }
+
+        // Then
+        assert_eq!(cache.get(&1), None);
+        assert!(cache.get(&2).is_some());
+        assert!(cache.get(&3).is_some());
+        assert_eq!(cache.size(), 8);
+    }
+
+    #[test]
+    fn values_larger_than_capacity_are_not_cached() {
+        // Given
+        let mut cache = SizedLru::new(10);
+
+        // When
+        cache.insert(1, (), 11);
+
+        // Then
+        assert_eq!(cache.get(&1), None);
+        assert_eq!(cache.size(), 0);
+    }
+
+    #[test]
+    fn values_fetched_before_removal_are_discarded() {
+        // Given
+        let mut cache = SizedLru::new(10);
+        let generation = cache.generation();
+
+        // When
+        cache.remove(&1);
+        cache.insert_fetched(1, (), 1, generation);
+
+        // Then
+        assert_eq!(cache.get(&1), None);
+        cache.insert_fetched(1, (), 1, cache.generation());
+        assert!(cache.get(&1).is_some());
+    }
+}
@@ -0,0 +1,228 @@
+//! The locations of the partitioned snapshots of the state.
+//!
+//! The snapshot lives in the local directory or, with the `object-store` feature,
+//! in the object store reachable over HTTP. The files of the snapshot are downloaded
+//! with `GET` and uploaded with `PUT` one by one, as the reader and the writer of the
+//! partitioned snapshot need them, so the snapshot is never copied to the local disk.
+//!
+//! The `s3://bucket/prefix` and `gs://bucket/prefix` URLs are mapped to the HTTPS
+//! endpoints of the buckets. The requests are not signed, so the private buckets
+//! need the gateway signing the requests, given by its `http(s)://` URL.
+
+use fuel_core_chain_config::{
+    SnapshotDirectory,
+    SnapshotStorage,
+};
+use std::{
+    fmt,
+    path::{
+        Path,
+        PathBuf,
+    },
+    str::FromStr,
+    sync::Arc,
+};
+
+/// The location of the partitioned snapshot.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnapshotLocation {
+    /// The local directory.
+    Directory(PathBuf),
+    /// The base URL of the files of the snapshot in the object store.
+    #[cfg(feature = "object-store")]
+    Url(String),
+}
+
+impl SnapshotLocation {
+    /// Returns the local directory of the snapshot, or `None` for the object store.
+    pub fn local_directory(&self) -> Option<&Path> {
+        match self {
+            Self::Directory(directory) => Some(directory),
+            #[cfg(feature = "object-store")]
+            Self::Url(_) => None,
+        }
+    }
+
+    /// Returns the storage reading the files of the snapshot.
+    pub fn reader(&self) -> Arc<dyn SnapshotStorage> {
+        match self {
+            Self::Directory(directory) => Arc::new(SnapshotDirectory::new(directory)),
+            #[cfg(feature = "object-store")]
+            Self::Url(url) => Arc::new(object_store::HttpStorage::new(url.clone())),
+        }
+    }
+
+    /// Returns the storage writing the files of the snapshot.
+    /// The local directory is created if it is missing.
+    pub fn writer(&self) -> anyhow::Result<Arc<dyn SnapshotStorage>> {
+        match self {
+            Self::Directory(directory) => {
+                Ok(Arc::new(SnapshotDirectory::create(directory)?))
+            }
+            #[cfg(feature = "object-store")]
+            Self::Url(url) => Ok(Arc::new(object_store::HttpStorage::new(url.clone()))),
+        }
+    }
+}
+
+impl FromStr for SnapshotLocation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let url = if let Some(path) = s.strip_prefix("s3://") {
+            let (bucket, prefix) = path.split_once('/').unwrap_or((path, ""));
+            Some(format!("https://{bucket}.s3.amazonaws.com/{prefix}"))
+        } else if let Some(path) = s.strip_prefix("gs://") {
+            Some(format!("https://storage.googleapis.com/{path}"))
+        } else if s.starts_with("http://") || s.starts_with("https://") {
+            Some(s.to_string())
+        } else {
+            None
+        };
+
+        match url {
+            None => Ok(Self::Directory(PathBuf::from(s))),
+            #[cfg(feature = "object-store")]
+            Some(url) => Ok(Self::Url(url.trim_end_matches('/').to_string())),
+            #[cfg(not(feature = "object-store"))]
+            Some(_) => Err(anyhow::anyhow!(
+                "The snapshot at {s} needs the `object-store` feature"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for SnapshotLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Directory(path) => write!(f, "{}", path.display()),
+            #[cfg(feature = "object-store")]
+            Self::Url(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+#[cfg(feature = "object-store")]
+mod object_store {
+    use anyhow::Context;
+    use fuel_core_chain_config::SnapshotStorage;
+    use std::future::Future;
+
+    /// The files of the snapshot under the base URL of the object store.
+    #[derive(Debug)]
+    pub struct HttpStorage {
+        url: String,
+    }
+
+    impl HttpStorage {
+        pub fn new(url: String) -> Self {
+            Self { url }
+        }
+
+        /// Runs the request on its own thread and runtime, because the snapshot
+        /// is read by the synchronous genesis inside of the node's runtime.
+        fn request<T, F>(
+            &self,
+            request: impl FnOnce(reqwest::Client) -> F + Send,
+        ) -> anyhow::Result<T>
+        where
+            F: Future<Output = anyhow::Result<T>>,
+            T: Send,
+        {
+            std::thread::scope(|scope| {
+                scope
+                    .spawn(move || {
+                        let runtime = tokio::runtime::Builder::new_current_thread()
+                            .enable_all()
+                            .build()?;
+                        runtime.block_on(request(reqwest::Client::new()))
+                    })
+                    .join()
+                    .map_err(|_| {
+                        anyhow::anyhow!("The request to {} panicked", self.url)
+                    })?
+            })
+        }
+    }
+
+    impl SnapshotStorage for HttpStorage {
+        fn read(&self, name: &str) -> anyhow::Result<Vec<u8>> {
+            let url = format!("{}/{name}", self.url);
+            self.request(|client| async move {
+                let response = client
+                    .get(&url)
+                    .send()
+                    .await?
+                    .error_for_status()
+                    .with_context(|| format!("Failed to download {url}"))?;
+                Ok(response.bytes().await?.to_vec())
+            })
+        }
+
+        fn write(&self, name: &str, content: &[u8]) -> anyhow::Result<()> {
+            let url = format!("{}/{name}", self.url);
+            let body = content.to_vec();
+            self.request(|client| async move {
+                client
+                    .put(&url)
+                    .header(reqwest::header::CONTENT_TYPE, "application/octet-stream")
+                    .body(body)
+                    .send()
+                    .await?
+                    .error_for_status()
+                    .with_context(|| format!("Failed to upload {url}"))?;
+                Ok(())
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_are_local_directories() {
+        // When
+        let location: SnapshotLocation = "./snapshot".parse().unwrap();
+
+        // Then
+        assert_eq!(location.local_directory(), Some(Path::new("./snapshot")));
+    }
+
+    #[cfg(feature = "object-store")]
+    #[test]
+    fn bucket_urls_are_mapped_to_https_endpoints() {
+        // When
+        let s3: SnapshotLocation = "s3://bucket/regenesis/".parse().unwrap();
+        let gs: SnapshotLocation = "gs://bucket/regenesis".parse().unwrap();
+        let http: SnapshotLocation = "http://gateway:8080/snapshot".parse().unwrap();
+
+        // Then
+        assert_eq!(
+            s3,
+            SnapshotLocation::Url("https://bucket.s3.amazonaws.com/regenesis".into())
+        );
+        assert_eq!(
+            gs,
+            SnapshotLocation::Url(
+                "https://storage.googleapis.com/bucket/regenesis".into()
+            )
+        );
+        assert_eq!(
+            http,
+            SnapshotLocation::Url("http://gateway:8080/snapshot".into())
+        );
+        assert_eq!(s3.local_directory(), None);
+    }
+
+    #[cfg(not(feature = "object-store"))]
+    #[test]
+    fn bucket_urls_need_the_object_store_feature() {
+        // When
+        let result = "s3://bucket/regenesis".parse::<SnapshotLocation>();
+
+        // Then
+        assert!(result.is_err());
+    }
+}
@@ -6,9 +6,14 @@ use crate::{
         },
         Result as DatabaseResult,
     },
-    state::in_memory::{
-        memory_store::MemoryStore,
-        transaction::MemoryTransactionView,
+    state::{
+        coin_cache::CoinCache,
+        contract_cache::ContractCache,
+        in_memory::{
+            memory_store::MemoryStore,
+            pending_commit::PendingCommitView,
+            transaction::MemoryTransactionView,
+        },
     },
 };
 use fuel_core_storage::{
@@ -16,16 +21,23 @@ use fuel_core_storage::{
         IterDirection,
         IteratorableStore,
     },
-    kv_store::BatchOperations,
+    kv_store::{
+        BatchOperations,
+        WriteOperation,
+    },
 };
 use std::{
     fmt::Debug,
     sync::Arc,
 };
 
+pub mod coin_cache;
+pub mod contract_cache;
+pub mod corruption;
 pub mod in_memory;
 #[cfg(feature = "rocksdb")]
 pub mod rocks_db;
+pub mod slow_reads;
 
 type DataSourceInner<Column> = Arc<dyn TransactableStorage<Column = Column>>;
 
@@ -54,6 +66,27 @@ where
     }
 }
 
+impl<Description> From<Arc<PendingCommitView<Description>>> for DataSource<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn from(inner: Arc<PendingCommitView<Description>>) -> Self {
+        Self(inner)
+    }
+}
+
+impl From<Arc<CoinCache>> for DataSource<OnChain> {
+    fn from(inner: Arc<CoinCache>) -> Self {
+        Self(inner)
+    }
+}
+
+impl From<Arc<ContractCache>> for DataSource<OnChain> {
+    fn from(inner: Arc<ContractCache>) -> Self {
+        Self(inner)
+    }
+}
+
 impl<Description> From<Arc<MemoryStore<Description>>> for DataSource<Description>
 where
     Description: DatabaseDescription,
@@ -87,4 +120,61 @@ pub trait TransactableStorage:
     IteratorableStore + BatchOperations + Debug + Send + Sync
 {
     fn flush(&self) -> DatabaseResult<()>;
+
+    /// Returns the statistics of each column. The data sources without
+    /// the on-disk layout don't have statistics.
+    fn statistics(&self) -> DatabaseResult<Vec<ColumnStatistics>> {
+        Ok(vec![])
+    }
+
+    /// Returns the memory used by the caches and the buffers of the data source.
+    /// The data sources without the caches don't report the usage.
+    fn memory_usage(&self) -> DatabaseResult<MemoryUsage> {
+        Ok(MemoryUsage::default())
+    }
+
+    /// Returns the copy of the changes that are not committed into the underlying
+    /// data source yet. Only the transactions have such changes.
+    fn uncommitted_changes(&self) -> Option<Vec<Change<Self::Column>>> {
+        None
+    }
+}
+
+/// The write operation of the `key` in the column.
+pub type Change<Column> = (Vec<u8>, Column, WriteOperation);
+
+/// The on-disk statistics of the column reported by the storage engine.
+/// The key count and the pending compaction bytes are estimations.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Serialize)]
+pub struct ColumnStatistics {
+    pub name: String,
+    /// The total size of the SST files of the column in bytes.
+    pub size: u64,
+    pub estimated_keys: u64,
+    /// The number of bytes that should be rewritten by the compaction to bring
+    /// every level of the column under its target size.
+    pub pending_compaction_bytes: u64,
+    /// The number of SST files on each level, starting from the level 0.
+    pub files_per_level: Vec<u64>,
+}
+
+/// The memory in bytes used by the caches and the buffers of the data source.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// The block cache, the row cache, and the memtables of the storage engine.
+    pub storage_engine: usize,
+    /// The values of the contract cache.
+    pub contract_cache: usize,
+    /// The values of the coin cache.
+    pub coin_cache: usize,
+}
+
+impl MemoryUsage {
+    pub fn saturating_add(self, other: Self) -> Self {
+        Self {
+            storage_engine: self.storage_engine.saturating_add(other.storage_engine),
+            contract_cache: self.contract_cache.saturating_add(other.contract_cache),
+            coin_cache: self.coin_cache.saturating_add(other.coin_cache),
+        }
+    }
 }
@@ -0,0 +1,308 @@
+use crate::{
+    database::{
+        database_description::on_chain::OnChain,
+        Result as DatabaseResult,
+    },
+    sized_lru::SizedLru,
+    state::{
+        ColumnStatistics,
+        DataSource,
+        IterDirection,
+        MemoryUsage,
+        TransactableStorage,
+    },
+};
+use fuel_core_metrics::core_metrics::database_metrics;
+use fuel_core_storage::{
+    column::Column,
+    iter::{
+        BoxedIter,
+        IteratorableStore,
+    },
+    kv_store::{
+        BatchOperations,
+        KVItem,
+        KeyValueStore,
+        Value,
+        WriteOperation,
+    },
+    Result as StorageResult,
+};
+use std::{
+    num::NonZeroUsize,
+    sync::Mutex,
+};
+
+/// The cache of the unspent coins, keyed by the `UtxoId`, on top of the on-chain data source.
+///
+/// The same parent coins are fetched by the transaction pool during the insertion
+/// and by the block producer and the executor for each block. The cache keeps
+/// the most recently used coins in memory and counts the hits and the misses.
+///
+/// Like [`ContractCache`](super::contract_cache::ContractCache), only the data source
+/// is wrapped, so the cache sees only committed coins. Each write into the coins column,
+/// including the removal of the spent coin by the commit of the block, evicts the coin
+/// before and after the write. The first eviction stops serving the old coin while
+/// the write is applied, the second one discards the coins read in the meantime.
+#[derive(Debug)]
+pub struct CoinCache {
+    inner: DataSource<OnChain>,
+    cache: Mutex<SizedLru<Vec<u8>, Value>>,
+}
+
+impl CoinCache {
+    /// Wraps the `inner` data source with the cache that holds at most `capacity` coins.
+    pub fn new(inner: DataSource<OnChain>, capacity: NonZeroUsize) -> Self {
+        Self {
+            inner,
+            cache: Mutex::new(SizedLru::new(capacity.get())),
+        }
+    }
+
+    fn invalidate(&self, key: &[u8], column: Column) {
+        if column == Column::Coins {
+            self.cache.lock().expect("poisoned").remove(key);
+        }
+    }
+
+    fn evict(&self, keys: &[Vec<u8>]) {
+        let mut cache = self.cache.lock().expect("poisoned");
+        for key in keys {
+            cache.remove(key);
+        }
+    }
+
+    #[cfg(test)]
+    fn cached(&self, key: &[u8]) -> Option<Value> {
+        self.cache.lock().expect("poisoned").peek(key).cloned()
+    }
+}
+
+impl KeyValueStore for CoinCache {
+    type Column = Column;
+
+    fn put(&self, key: &[u8], column: Self::Column, value: Value) -> StorageResult<()> {
+        self.invalidate(key, column);
+        let result = self.inner.put(key, column, value);
+        self.invalidate(key, column);
+        result
+    }
+
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        value: Value,
+    ) -> StorageResult<Option<Value>> {
+        self.invalidate(key, column);
+        let result = self.inner.replace(key, column, value);
+        self.invalidate(key, column);
+        result
+    }
+
+    fn write(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &[u8],
+    ) -> StorageResult<usize> {
+        self.invalidate(key, column);
+        let result = self.inner.write(key, column, buf);
+        self.invalidate(key, column);
+        result
+    }
+
+    fn take(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.invalidate(key, column);
+        let result = self.inner.take(key, column);
+        self.invalidate(key, column);
+        result
+    }
+
+    fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
+        self.invalidate(key, column);
+        let result = self.inner.delete(key, column);
+        self.invalidate(key, column);
+        result
+    }
+
+    fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
+        if column == Column::Coins {
+            Ok(self.get(key, column)?.is_some())
+        } else {
+            self.inner.exists(key, column)
+        }
+    }
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        if column == Column::Coins {
+            Ok(self.get(key, column)?.map(|value| value.len()))
+        } else {
+            self.inner.size_of_value(key, column)
+        }
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        if column != Column::Coins {
+            return self.inner.get(key, column)
+        }
+
+        let generation = {
+            let mut cache = self.cache.lock().expect("poisoned");
+            if let Some(value) = cache.get(key) {
+                database_metrics().coin_cache_hits.inc();
+                return Ok(Some(value.clone()))
+            }
+            cache.generation()
+        };
+        database_metrics().coin_cache_misses.inc();
+
+        let value = self.inner.get(key, column)?;
+        if let Some(value) = &value {
+            // The coin could be spent while we were reading it.
+            // Each coin has the size `1`, so the cache is bounded by the number of coins.
+            self.cache.lock().expect("poisoned").insert_fetched(
+                key.to_vec(),
+                value.clone(),
+                1,
+                generation,
+            );
+        }
+        Ok(value)
+    }
+}
+
+impl IteratorableStore for CoinCache {
+    fn iter_all(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.inner.iter_all(column, prefix, start, direction)
+    }
+}
+
+impl BatchOperations for CoinCache {
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Self::Column, WriteOperation)>,
+    ) -> StorageResult<()> {
+        let entries = entries.collect::<Vec<_>>();
+        let written = entries
+            .iter()
+            .filter(|(_, column, _)| *column == Column::Coins)
+            .map(|(key, _, _)| key.clone())
+            .collect::<Vec<_>>();
+
+        self.evict(&written);
+        let result = self.inner.batch_write(&mut entries.into_iter());
+        self.evict(&written);
+        result
+    }
+}
+
+impl TransactableStorage for CoinCache {
+    fn flush(&self) -> DatabaseResult<()> {
+        self.cache.lock().expect("poisoned").clear();
+        self.inner.flush()
+    }
+
+    fn statistics(&self) -> DatabaseResult<Vec<ColumnStatistics>> {
+        self.inner.statistics()
+    }
+
+    fn memory_usage(&self) -> DatabaseResult<MemoryUsage> {
+        Ok(MemoryUsage {
+            coin_cache: self.cache.lock().expect("poisoned").iter().fold(
+                0usize,
+                |size, (key, value)| {
+                    size.saturating_add(key.len()).saturating_add(value.len())
+                },
+            ),
+            ..self.inner.memory_usage()?
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::in_memory::{
+        memory_store::MemoryStore,
+        transaction::MemoryTransactionView,
+    };
+    use std::sync::Arc;
+
+    fn cache(capacity: usize) -> Arc<CoinCache> {
+        let store = Arc::new(MemoryStore::<OnChain>::default());
+        Arc::new(CoinCache::new(
+            store.into(),
+            NonZeroUsize::new(capacity).unwrap(),
+        ))
+    }
+
+    #[test]
+    fn get_caches_coins() {
+        let cache = cache(10);
+        let value = Arc::new(vec![1, 2, 3]);
+        cache.put(b"coin", Column::Coins, value.clone()).unwrap();
+        assert_eq!(cache.cached(b"coin"), None);
+
+        assert_eq!(
+            cache.get(b"coin", Column::Coins).unwrap(),
+            Some(value.clone())
+        );
+
+        assert_eq!(cache.cached(b"coin"), Some(value));
+    }
+
+    #[test]
+    fn get_doesnt_cache_other_columns() {
+        let cache = cache(10);
+        cache
+            .put(b"message", Column::Messages, Arc::new(vec![1]))
+            .unwrap();
+
+        cache.get(b"message", Column::Messages).unwrap();
+
+        assert_eq!(cache.cached(b"message"), None);
+    }
+
+    #[test]
+    fn commit_of_spent_coin_evicts_cached_coin() {
+        let cache = cache(10);
+        cache
+            .put(b"coin", Column::Coins, Arc::new(vec![1]))
+            .unwrap();
+        cache.get(b"coin", Column::Coins).unwrap();
+
+        let transaction = MemoryTransactionView::<OnChain>::new(cache.clone());
+        transaction.delete(b"coin", Column::Coins).unwrap();
+        assert_eq!(transaction.get(b"coin", Column::Coins).unwrap(), None);
+        assert_eq!(cache.cached(b"coin"), Some(Arc::new(vec![1])));
+
+        transaction.commit().unwrap();
+
+        assert_eq!(cache.cached(b"coin"), None);
+        assert_eq!(cache.get(b"coin", Column::Coins).unwrap(), None);
+    }
+
+    #[test]
+    fn cache_is_bounded_by_number_of_coins() {
+        let cache = cache(2);
+        for key in [b"a", b"b", b"c"] {
+            cache.put(key, Column::Coins, Arc::new(vec![0])).unwrap();
+            cache.get(key, Column::Coins).unwrap();
+        }
+
+        assert_eq!(cache.cached(b"a"), None);
+        assert!(cache.cached(b"b").is_some());
+        assert!(cache.cached(b"c").is_some());
+    }
+}
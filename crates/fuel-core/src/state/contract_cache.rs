@@ -0,0 +1,361 @@
+use crate::{
+    database::{
+        database_description::on_chain::OnChain,
+        Result as DatabaseResult,
+    },
+    sized_lru::SizedLru,
+    state::{
+        ColumnStatistics,
+        DataSource,
+        IterDirection,
+        MemoryUsage,
+        TransactableStorage,
+    },
+};
+use fuel_core_storage::{
+    column::Column,
+    iter::{
+        BoxedIter,
+        IteratorableStore,
+    },
+    kv_store::{
+        BatchOperations,
+        KVItem,
+        KeyValueStore,
+        Value,
+        WriteOperation,
+    },
+    Result as StorageResult,
+};
+use std::sync::Mutex;
+
+/// The cache of the contracts bytecode and info on top of the on-chain data source.
+///
+/// The bytecode of popular contracts is loaded by the transaction pool during
+/// the verification, by dry runs, and by the executor for each block. The cache
+/// keeps the most recently used values in memory, so they are not fetched from the
+/// underlying storage every time.
+///
+/// Only the data source of the database is wrapped, so the cache sees only
+/// committed values: the uncommitted changes of the storage transactions live
+/// in the transaction views above it. Each write into the cached columns,
+/// including the commit of a transaction, evicts the affected keys before and after
+/// the write, so neither the old values nor the values read while the write
+/// is applied stay in the cache.
+#[derive(Debug)]
+pub struct ContractCache {
+    inner: DataSource<OnChain>,
+    cache: Mutex<SizedLru<(Column, Vec<u8>), Value>>,
+}
+
+impl ContractCache {
+    /// Wraps the `inner` data source with the cache that holds
+    /// at most `capacity` bytes of values.
+    pub fn new(inner: DataSource<OnChain>, capacity: usize) -> Self {
+        Self {
+            inner,
+            cache: Mutex::new(SizedLru::new(capacity)),
+        }
+    }
+
+    fn is_cached(column: Column) -> bool {
+        matches!(column, Column::ContractsRawCode | Column::ContractsInfo)
+    }
+
+    fn invalidate(&self, key: &[u8], column: Column) {
+        if Self::is_cached(column) {
+            self.cache
+                .lock()
+                .expect("poisoned")
+                .remove(&(column, key.to_vec()));
+        }
+    }
+
+    fn evict(&self, keys: &[(Column, Vec<u8>)]) {
+        let mut cache = self.cache.lock().expect("poisoned");
+        for key in keys {
+            cache.remove(key);
+        }
+    }
+
+    #[cfg(test)]
+    fn cached(&self, key: &[u8], column: Column) -> Option<Value> {
+        self.cache
+            .lock()
+            .expect("poisoned")
+            .peek(&(column, key.to_vec()))
+            .cloned()
+    }
+}
+
+impl KeyValueStore for ContractCache {
+    type Column = Column;
+
+    fn put(&self, key: &[u8], column: Self::Column, value: Value) -> StorageResult<()> {
+        self.invalidate(key, column);
+        let result = self.inner.put(key, column, value);
+        self.invalidate(key, column);
+        result
+    }
+
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        value: Value,
+    ) -> StorageResult<Option<Value>> {
+        self.invalidate(key, column);
+        let result = self.inner.replace(key, column, value);
+        self.invalidate(key, column);
+        result
+    }
+
+    fn write(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &[u8],
+    ) -> StorageResult<usize> {
+        self.invalidate(key, column);
+        let result = self.inner.write(key, column, buf);
+        self.invalidate(key, column);
+        result
+    }
+
+    fn take(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.invalidate(key, column);
+        let result = self.inner.take(key, column);
+        self.invalidate(key, column);
+        result
+    }
+
+    fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
+        self.invalidate(key, column);
+        let result = self.inner.delete(key, column);
+        self.invalidate(key, column);
+        result
+    }
+
+    fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
+        if Self::is_cached(column) {
+            Ok(self.get(key, column)?.is_some())
+        } else {
+            self.inner.exists(key, column)
+        }
+    }
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        if Self::is_cached(column) {
+            Ok(self.get(key, column)?.map(|value| value.len()))
+        } else {
+            self.inner.size_of_value(key, column)
+        }
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        if !Self::is_cached(column) {
+            return self.inner.get(key, column)
+        }
+
+        let cache_key = (column, key.to_vec());
+        let generation = {
+            let mut cache = self.cache.lock().expect("poisoned");
+            if let Some(value) = cache.get(&cache_key) {
+                return Ok(Some(value.clone()))
+            }
+            cache.generation()
+        };
+
+        let value = self.inner.get(key, column)?;
+        if let Some(value) = &value {
+            // The value could be overridden while we were reading it.
+            self.cache.lock().expect("poisoned").insert_fetched(
+                cache_key,
+                value.clone(),
+                value.len(),
+                generation,
+            );
+        }
+        Ok(value)
+    }
+}
+
+impl IteratorableStore for ContractCache {
+    fn iter_all(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.inner.iter_all(column, prefix, start, direction)
+    }
+}
+
+impl BatchOperations for ContractCache {
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Self::Column, WriteOperation)>,
+    ) -> StorageResult<()> {
+        let entries = entries.collect::<Vec<_>>();
+        let written = entries
+            .iter()
+            .filter(|(_, column, _)| Self::is_cached(*column))
+            .map(|(key, column, _)| (*column, key.clone()))
+            .collect::<Vec<_>>();
+
+        self.evict(&written);
+        let result = self.inner.batch_write(&mut entries.into_iter());
+        self.evict(&written);
+        result
+    }
+}
+
+impl TransactableStorage for ContractCache {
+    fn flush(&self) -> DatabaseResult<()> {
+        self.cache.lock().expect("poisoned").clear();
+        self.inner.flush()
+    }
+
+    fn statistics(&self) -> DatabaseResult<Vec<ColumnStatistics>> {
+        self.inner.statistics()
+    }
+
+    fn memory_usage(&self) -> DatabaseResult<MemoryUsage> {
+        Ok(MemoryUsage {
+            contract_cache: self.cache.lock().expect("poisoned").size(),
+            ..self.inner.memory_usage()?
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::in_memory::{
+        memory_store::MemoryStore,
+        transaction::MemoryTransactionView,
+    };
+    use std::sync::Arc;
+
+    fn cache(capacity: usize) -> Arc<ContractCache> {
+        let store = Arc::new(MemoryStore::<OnChain>::default());
+        Arc::new(ContractCache::new(store.into(), capacity))
+    }
+
+    #[test]
+    fn get_caches_contract_values() {
+        let cache = cache(1024);
+        let value = Arc::new(vec![1, 2, 3]);
+        cache
+            .put(b"contract", Column::ContractsRawCode, value.clone())
+            .unwrap();
+        assert_eq!(cache.cached(b"contract", Column::ContractsRawCode), None);
+
+        assert_eq!(
+            cache.get(b"contract", Column::ContractsRawCode).unwrap(),
+            Some(value.clone())
+        );
+
+        assert_eq!(
+            cache.cached(b"contract", Column::ContractsRawCode),
+            Some(value)
+        );
+    }
+
+    #[test]
+    fn memory_usage_reports_cached_values() {
+        let cache = cache(1024);
+        cache
+            .put(
+                b"contract",
+                Column::ContractsRawCode,
+                Arc::new(vec![1, 2, 3]),
+            )
+            .unwrap();
+        assert_eq!(cache.memory_usage().unwrap().contract_cache, 0);
+
+        cache.get(b"contract", Column::ContractsRawCode).unwrap();
+
+        assert_eq!(cache.memory_usage().unwrap().contract_cache, 3);
+    }
+
+    #[test]
+    fn get_doesnt_cache_other_columns() {
+        let cache = cache(1024);
+        cache
+            .put(b"key", Column::ContractsState, Arc::new(vec![1]))
+            .unwrap();
+
+        cache.get(b"key", Column::ContractsState).unwrap();
+
+        assert_eq!(cache.cached(b"key", Column::ContractsState), None);
+    }
+
+    #[test]
+    fn write_evicts_cached_value() {
+        let cache = cache(1024);
+        cache
+            .put(b"contract", Column::ContractsInfo, Arc::new(vec![1]))
+            .unwrap();
+        cache.get(b"contract", Column::ContractsInfo).unwrap();
+
+        cache
+            .put(b"contract", Column::ContractsInfo, Arc::new(vec![2]))
+            .unwrap();
+
+        assert_eq!(cache.cached(b"contract", Column::ContractsInfo), None);
+        assert_eq!(
+            cache.get(b"contract", Column::ContractsInfo).unwrap(),
+            Some(Arc::new(vec![2]))
+        );
+    }
+
+    #[test]
+    fn uncommitted_changes_are_not_cached_and_commit_evicts_cached_value() {
+        let cache = cache(1024);
+        cache
+            .put(b"contract", Column::ContractsRawCode, Arc::new(vec![1]))
+            .unwrap();
+        cache.get(b"contract", Column::ContractsRawCode).unwrap();
+
+        let transaction = MemoryTransactionView::<OnChain>::new(cache.clone());
+        transaction
+            .put(b"contract", Column::ContractsRawCode, Arc::new(vec![2]))
+            .unwrap();
+        transaction
+            .get(b"contract", Column::ContractsRawCode)
+            .unwrap();
+        assert_eq!(
+            cache.cached(b"contract", Column::ContractsRawCode),
+            Some(Arc::new(vec![1]))
+        );
+
+        transaction.commit().unwrap();
+
+        assert_eq!(cache.cached(b"contract", Column::ContractsRawCode), None);
+        assert_eq!(
+            cache.get(b"contract", Column::ContractsRawCode).unwrap(),
+            Some(Arc::new(vec![2]))
+        );
+    }
+
+    #[test]
+    fn cache_is_bounded_by_size_of_values() {
+        let cache = cache(10);
+        for key in [b"a", b"b", b"c"] {
+            cache
+                .put(key, Column::ContractsRawCode, Arc::new(vec![0; 4]))
+                .unwrap();
+            cache.get(key, Column::ContractsRawCode).unwrap();
+        }
+
+        assert_eq!(cache.cached(b"a", Column::ContractsRawCode), None);
+        assert!(cache.cached(b"b", Column::ContractsRawCode).is_some());
+        assert!(cache.cached(b"c", Column::ContractsRawCode).is_some());
+    }
+}
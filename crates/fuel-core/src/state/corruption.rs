@@ -0,0 +1,24 @@
+//! The report of the corrupted data found by the database.
+//!
+//! The report is process-wide, like the metrics, so the storage doesn't need to know
+//! who watches it. The alerts subscribe to the report and notify the node operator.
+
+use std::sync::OnceLock;
+use tokio::sync::watch;
+
+fn report_sender() -> &'static watch::Sender<Option<String>> {
+    static REPORT: OnceLock<watch::Sender<Option<String>>> = OnceLock::new();
+    REPORT.get_or_init(|| watch::channel(None).0)
+}
+
+/// Reports the corruption of the database with the `error`.
+pub fn report(error: String) {
+    tracing::error!("The database is corrupted: {error}");
+    report_sender().send_replace(Some(error));
+}
+
+/// Subscribes to the next reports of the corruption.
+/// The last report, if any, is available with `borrow`.
+pub fn subscribe() -> watch::Receiver<Option<String>> {
+    report_sender().subscribe()
+}
@@ -1,2 +1,3 @@
 pub mod memory_store;
+pub mod pending_commit;
 pub mod transaction;
@@ -0,0 +1,249 @@
+use crate::{
+    database::{
+        database_description::DatabaseDescription,
+        Result as DatabaseResult,
+    },
+    state::{
+        in_memory::transaction::MemoryTransactionView,
+        BatchOperations,
+        Change,
+        ColumnStatistics,
+        DataSource,
+        IterDirection,
+        MemoryUsage,
+        TransactableStorage,
+    },
+};
+use fuel_core_storage::{
+    iter::{
+        BoxedIter,
+        IteratorableStore,
+    },
+    kv_store::{
+        KVItem,
+        KeyValueStore,
+        Value,
+        WriteOperation,
+    },
+    Result as StorageResult,
+};
+
+/// The view of the data source with the changes of the block that is being committed
+/// into it, so the next block can be executed without waiting for the commit.
+///
+/// The reads see the pending changes on top of the data source. The pending changes
+/// are a copy, so the view stays consistent before, during, and after the commit
+/// of the original changes.
+///
+/// The writes go directly to the data source. The block importer commits the next block
+/// only after the pending changes are committed, and at that moment the data source
+/// already contains everything this view shows.
+#[derive(Debug)]
+pub struct PendingCommitView<Description>
+where
+    Description: DatabaseDescription,
+{
+    pending: MemoryTransactionView<Description>,
+    data_source: DataSource<Description>,
+}
+
+impl<Description> PendingCommitView<Description>
+where
+    Description: DatabaseDescription,
+{
+    /// Creates the view of the `changes` on top of the `data_source`.
+    pub fn new(
+        data_source: DataSource<Description>,
+        changes: Vec<Change<Description::Column>>,
+    ) -> StorageResult<Self> {
+        let pending = MemoryTransactionView::new(data_source.clone());
+        pending.batch_write(&mut changes.into_iter())?;
+        Ok(Self {
+            pending,
+            data_source,
+        })
+    }
+}
+
+impl<Description> KeyValueStore for PendingCommitView<Description>
+where
+    Description: DatabaseDescription,
+{
+    type Column = Description::Column;
+
+    fn put(&self, key: &[u8], column: Self::Column, value: Value) -> StorageResult<()> {
+        self.data_source.put(key, column, value)
+    }
+
+    fn replace(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        value: Value,
+    ) -> StorageResult<Option<Value>> {
+        self.data_source.replace(key, column, value)
+    }
+
+    fn write(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &[u8],
+    ) -> StorageResult<usize> {
+        self.data_source.write(key, column, buf)
+    }
+
+    fn take(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.data_source.take(key, column)
+    }
+
+    fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
+        self.data_source.delete(key, column)
+    }
+
+    fn exists(&self, key: &[u8], column: Self::Column) -> StorageResult<bool> {
+        self.pending.exists(key, column)
+    }
+
+    fn size_of_value(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+    ) -> StorageResult<Option<usize>> {
+        self.pending.size_of_value(key, column)
+    }
+
+    fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
+        self.pending.get(key, column)
+    }
+
+    fn read(
+        &self,
+        key: &[u8],
+        column: Self::Column,
+        buf: &mut [u8],
+    ) -> StorageResult<Option<usize>> {
+        self.pending.read(key, column, buf)
+    }
+}
+
+impl<Description> IteratorableStore for PendingCommitView<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn iter_all(
+        &self,
+        column: Self::Column,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> BoxedIter<KVItem> {
+        self.pending.iter_all(column, prefix, start, direction)
+    }
+}
+
+impl<Description> BatchOperations for PendingCommitView<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Self::Column, WriteOperation)>,
+    ) -> StorageResult<()> {
+        self.data_source.batch_write(entries)
+    }
+}
+
+impl<Description> TransactableStorage for PendingCommitView<Description>
+where
+    Description: DatabaseDescription,
+{
+    fn flush(&self) -> DatabaseResult<()> {
+        self.data_source.flush()
+    }
+
+    fn statistics(&self) -> DatabaseResult<Vec<ColumnStatistics>> {
+        self.data_source.statistics()
+    }
+
+    fn memory_usage(&self) -> DatabaseResult<MemoryUsage> {
+        self.data_source.memory_usage()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::database_description::on_chain::OnChain,
+        state::in_memory::memory_store::MemoryStore,
+    };
+    use fuel_core_storage::column::Column;
+    use std::sync::Arc;
+
+    fn keys(view: &PendingCommitView<OnChain>) -> Vec<Vec<u8>> {
+        view.iter_all(Column::Metadata, None, None, IterDirection::Forward)
+            .map(|item| item.unwrap().0)
+            .collect()
+    }
+
+    #[test]
+    fn view_sees_pending_changes_during_and_after_commit() {
+        // Given
+        let store = Arc::new(MemoryStore::<OnChain>::default());
+        store
+            .put(b"removed", Column::Metadata, Arc::new(vec![1]))
+            .unwrap();
+        store
+            .put(b"untouched", Column::Metadata, Arc::new(vec![2]))
+            .unwrap();
+        let transaction = MemoryTransactionView::<OnChain>::new(store.clone());
+        transaction.delete(b"removed", Column::Metadata).unwrap();
+        transaction
+            .put(b"inserted", Column::Metadata, Arc::new(vec![3]))
+            .unwrap();
+        let changes = transaction.uncommitted_changes().unwrap();
+
+        // When
+        let view = PendingCommitView::new(store.into(), changes).unwrap();
+        let before_commit = keys(&view);
+        transaction.commit().unwrap();
+        let after_commit = keys(&view);
+
+        // Then
+        let expected = vec![b"inserted".to_vec(), b"untouched".to_vec()];
+        assert_eq!(before_commit, expected);
+        assert_eq!(after_commit, expected);
+        assert_eq!(view.get(b"removed", Column::Metadata).unwrap(), None);
+        assert_eq!(
+            view.get(b"inserted", Column::Metadata).unwrap(),
+            Some(Arc::new(vec![3]))
+        );
+    }
+
+    #[test]
+    fn commit_of_next_transaction_goes_to_data_source() {
+        // Given
+        let store = Arc::new(MemoryStore::<OnChain>::default());
+        let pending = vec![(
+            b"pending".to_vec(),
+            Column::Metadata,
+            WriteOperation::Insert(Arc::new(vec![1])),
+        )];
+        let view =
+            Arc::new(PendingCommitView::new(store.clone().into(), pending).unwrap());
+        let next = MemoryTransactionView::<OnChain>::new(view.clone());
+        next.put(b"next", Column::Metadata, Arc::new(vec![2]))
+            .unwrap();
+
+        // When
+        next.commit().unwrap();
+
+        // Then
+        assert_eq!(
+            store.get(b"next", Column::Metadata).unwrap(),
+            Some(Arc::new(vec![2]))
+        );
+        assert_eq!(store.get(b"pending", Column::Metadata).unwrap(), None);
+    }
+}
@@ -9,6 +9,7 @@ use crate::{
     state::{
         in_memory::memory_store::MemoryStore,
         BatchOperations,
+        Change,
         DataSource,
         IterDirection,
         TransactableStorage,
@@ -270,9 +271,32 @@ where
     }
 }
 
-impl<Description> BatchOperations for MemoryTransactionView<Description> where
-    Description: DatabaseDescription
+impl<Description> BatchOperations for MemoryTransactionView<Description>
+where
+    Description: DatabaseDescription,
 {
+    fn batch_write(
+        &self,
+        entries: &mut dyn Iterator<Item = (Vec<u8>, Self::Column, WriteOperation)>,
+    ) -> StorageResult<()> {
+        // Unlike `put` and `take`, the batch doesn't need the previous values,
+        // so it doesn't read them from the data source.
+        for (key, column, operation) in entries {
+            match &operation {
+                WriteOperation::Insert(value) => {
+                    self.view_layer.put(&key, column, value.clone())?;
+                }
+                WriteOperation::Remove => {
+                    self.view_layer.delete(&key, column)?;
+                }
+            }
+            self.changes[column.as_usize()]
+                .lock()
+                .expect("poisoned lock")
+                .insert(key, operation);
+        }
+        Ok(())
+    }
 }
 
 impl<Description> TransactableStorage for MemoryTransactionView<Description>
@@ -286,6 +310,21 @@ where
         self.view_layer.flush()?;
         self.data_source.flush()
     }
+
+    fn uncommitted_changes(&self) -> Option<Vec<Change<Self::Column>>> {
+        let changes = self
+            .changes
+            .iter()
+            .zip(enum_iterator::all::<Description::Column>())
+            .flat_map(|(column_map, column)| {
+                let map = column_map.lock().expect("poisoned lock");
+                map.iter()
+                    .map(|(key, operation)| (key.clone(), column, operation.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        Some(changes)
+    }
 }
 
 #[cfg(test)]
@@ -6,8 +6,12 @@ use crate::{
         Result as DatabaseResult,
     },
     state::{
+        corruption,
+        slow_reads,
         BatchOperations,
+        ColumnStatistics,
         IterDirection,
+        MemoryUsage,
         TransactableStorage,
     },
 };
@@ -29,6 +33,7 @@ use fuel_core_storage::{
 };
 use rand::RngCore;
 use rocksdb::{
+    properties,
     BlockBasedOptions,
     BoundColumnFamily,
     Cache,
@@ -52,10 +57,14 @@ use std::{
         PathBuf,
     },
     sync::Arc,
+    time::Instant,
 };
 
 type DB = DBWithThreadMode<MultiThreaded>;
 
+/// The default number of the LSM levels of the column.
+const NUM_LEVELS: usize = 7;
+
 /// Reimplementation of `tempdir::TempDir` that allows creating a new
 /// instance without actually creating a new directory on the filesystem.
 /// This is needed since rocksdb requires empty directory for checkpoints.
@@ -94,6 +103,7 @@ impl Drop for ShallowTempDir {
 #[derive(Debug)]
 pub struct RocksDb<Description> {
     db: DB,
+    caches: Caches,
     _marker: core::marker::PhantomData<Description>,
 }
 
@@ -118,28 +128,13 @@ where
         capacity: Option<usize>,
     ) -> DatabaseResult<Self> {
         let path = path.as_ref().join(Description::name());
-        let mut block_opts = BlockBasedOptions::default();
-        // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
-        block_opts.set_format_version(5);
-
-        if let Some(capacity) = capacity {
-            // Set cache size 1/3 of the capacity as recommended by
-            // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
-            let block_cache_size = capacity / 3;
-            let cache = Cache::new_lru_cache(block_cache_size);
-            block_opts.set_block_cache(&cache);
-            // "index and filter blocks will be stored in block cache, together with all other data blocks."
-            // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
-            block_opts.set_cache_index_and_filter_blocks(true);
-            // Don't evict L0 filter/index blocks from the cache
-            block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
-        } else {
-            block_opts.disable_cache();
-        }
-        block_opts.set_bloom_filter(10.0, true);
+        // Set cache size 1/3 of the capacity as recommended by
+        // https://github.com/facebook/rocksdb/wiki/Setup-Options-and-Basic-Tuning#block-cache-size
+        let block_cache = capacity.map(|capacity| Cache::new_lru_cache(capacity / 3));
+        let block_cache = block_cache.as_ref();
 
         let cf_descriptors = columns.clone().into_iter().map(|i| {
-            ColumnFamilyDescriptor::new(Self::col_name(i), Self::cf_opts(i, &block_opts))
+            ColumnFamilyDescriptor::new(Self::col_name(i), Self::cf_opts(i, block_cache))
         });
 
         let mut opts = Options::default();
@@ -150,6 +145,7 @@ where
         let cpu_number =
             i32::try_from(num_cpus::get()).expect("The number of CPU can't exceed `i32`");
         opts.increase_parallelism(cmp::max(1, cpu_number / 2));
+        let mut row_cache = None;
         if let Some(capacity) = capacity {
             // Set cache size 1/3 of the capacity. Another 1/3 is
             // used by block cache and the last 1 / 3 remains for other purposes:
@@ -158,6 +154,9 @@ where
             let row_cache_size = capacity / 3;
             let cache = Cache::new_lru_cache(row_cache_size);
             opts.set_row_cache(&cache);
+            row_cache = Some(cache);
+            // The memtables of all columns share the remaining 1/3.
+            opts.set_db_write_buffer_size(capacity / 3);
         }
 
         let db = match DB::open_cf_descriptors(&opts, &path, cf_descriptors) {
@@ -166,21 +165,21 @@ where
                 match DB::open_cf(&opts, &path, &[] as &[&str]) {
                     Ok(db) => {
                         for i in columns {
-                            let opts = Self::cf_opts(i, &block_opts);
+                            let opts = Self::cf_opts(i, block_cache);
                             db.create_cf(Self::col_name(i), &opts)
-                                .map_err(|e| DatabaseError::Other(e.into()))?;
+                                .map_err(into_database_error)?;
                         }
                         Ok(db)
                     }
                     Err(err) => {
                         tracing::error!("Couldn't open the database with an error: {}. \nTrying to repair the database", err);
                         DB::repair(&opts, &path)
-                            .map_err(|e| DatabaseError::Other(e.into()))?;
+                            .map_err(into_database_error)?;
 
                         let cf_descriptors = columns.clone().into_iter().map(|i| {
                             ColumnFamilyDescriptor::new(
                                 Self::col_name(i),
-                                Self::cf_opts(i, &block_opts),
+                                Self::cf_opts(i, block_cache),
                             )
                         });
                         DB::open_cf_descriptors(&opts, &path, cf_descriptors)
@@ -189,9 +188,13 @@ where
             }
             ok => ok,
         }
-        .map_err(|e| DatabaseError::Other(e.into()))?;
+        .map_err(into_database_error)?;
         let rocks_db = RocksDb {
             db,
+            caches: Caches {
+                block: block_cache.cloned(),
+                row: row_cache,
+            },
             _marker: Default::default(),
         };
         Ok(rocks_db)
@@ -203,24 +206,113 @@ where
             .expect("invalid column state")
     }
 
+    fn column_statistics(
+        &self,
+        column: Description::Column,
+    ) -> DatabaseResult<ColumnStatistics> {
+        let cf = self.cf(column);
+        let property = |name: &properties::PropName| -> DatabaseResult<u64> {
+            let value = self
+                .db
+                .property_int_value_cf(&cf, name)
+                .map_err(into_database_error)?;
+            Ok(value.unwrap_or_default())
+        };
+        let files_per_level = (0..NUM_LEVELS)
+            .map(|level| property(&properties::num_files_at_level(level)))
+            .collect::<DatabaseResult<Vec<_>>>()?;
+
+        Ok(ColumnStatistics {
+            name: column.name().to_string(),
+            size: property(properties::TOTAL_SST_FILES_SIZE)?,
+            estimated_keys: property(properties::ESTIMATE_NUM_KEYS)?,
+            pending_compaction_bytes: property(
+                properties::ESTIMATE_PENDING_COMPACTION_BYTES,
+            )?,
+            files_per_level,
+        })
+    }
+
+    fn memtables_size(&self) -> DatabaseResult<usize> {
+        enum_iterator::all::<Description::Column>().try_fold(0usize, |size, column| {
+            let memtables = self
+                .db
+                .property_int_value_cf(
+                    &self.cf(column),
+                    properties::CUR_SIZE_ALL_MEM_TABLES,
+                )
+                .map_err(into_database_error)?
+                .unwrap_or_default();
+            Ok(size.saturating_add(usize::try_from(memtables).unwrap_or(usize::MAX)))
+        })
+    }
+
     fn col_name(column: Description::Column) -> String {
         format!("col-{}", column.as_usize())
     }
 
-    fn cf_opts(column: Description::Column, block_opts: &BlockBasedOptions) -> Options {
+    fn cf_opts(column: Description::Column, block_cache: Option<&Cache>) -> Options {
+        let prefix = Description::prefix(&column);
+        let mut block_opts = BlockBasedOptions::default();
+        // See https://github.com/facebook/rocksdb/blob/a1523efcdf2f0e8133b9a9f6e170a0dad49f928f/include/rocksdb/table.h#L246-L271 for details on what the format versions are/do.
+        block_opts.set_format_version(5);
+
+        if let Some(cache) = block_cache {
+            block_opts.set_block_cache(cache);
+            // "index and filter blocks will be stored in block cache, together with all other data blocks."
+            // See: https://github.com/facebook/rocksdb/wiki/Memory-usage-in-RocksDB#indexes-and-filter-blocks
+            block_opts.set_cache_index_and_filter_blocks(true);
+            // Don't evict L0 filter/index blocks from the cache
+            block_opts.set_pin_l0_filter_and_index_blocks_in_cache(true);
+        } else {
+            block_opts.disable_cache();
+        }
+        // The full filter of the SST file contains the prefixes of the keys along with
+        // the keys, so the prefix scans skip the files without the prefix.
+        let block_based_filter = prefix.is_none();
+        block_opts.set_bloom_filter(10.0, block_based_filter);
+
         let mut opts = Options::default();
         opts.create_if_missing(true);
         opts.set_compression_type(DBCompressionType::Lz4);
-        opts.set_block_based_table_factory(block_opts);
+        opts.set_block_based_table_factory(&block_opts);
 
         // All double-keys should be configured here
-        if let Some(size) = Description::prefix(&column) {
-            opts.set_prefix_extractor(SliceTransform::create_fixed_prefix(size))
+        if let Some(size) = prefix {
+            opts.set_prefix_extractor(SliceTransform::create_fixed_prefix(size));
+            // The prefix bloom filter of the memtable, so the scans of the prefixes
+            // without the recent writes don't search the memtable.
+            opts.set_memtable_prefix_bloom_ratio(0.1);
         }
 
         opts
     }
 
+    /// The options of the iteration that may cross the prefixes of the column,
+    /// so the prefix bloom filters can't be used.
+    fn total_order_read_options() -> ReadOptions {
+        let mut opts = ReadOptions::default();
+        opts.set_total_order_seek(true);
+        opts
+    }
+
+    /// The options of the iteration within the `prefix`. The iteration is bounded
+    /// by the `prefix`, so it doesn't read the keys after it. If the `prefix` covers
+    /// the prefix extractor of the column, the prefix bloom filters skip the SST files
+    /// and the memtable without the keys of the `prefix`.
+    fn prefix_read_options(column: Description::Column, prefix: &[u8]) -> ReadOptions {
+        let mut opts = ReadOptions::default();
+        match Description::prefix(&column) {
+            Some(size) if prefix.len() >= size => opts.set_prefix_same_as_start(true),
+            _ => opts.set_total_order_seek(true),
+        }
+        opts.set_iterate_lower_bound(prefix.to_vec());
+        if let Some(upper_bound) = next_prefix(prefix.to_vec()) {
+            opts.set_iterate_upper_bound(upper_bound);
+        }
+        opts
+    }
+
     /// RocksDB prefix iteration doesn't support reverse order,
     /// but seeking the start key and iterating in reverse order works.
     /// So we can create a workaround. We need to find the next available
@@ -235,11 +327,13 @@ where
     ) -> impl Iterator<Item = KVItem> + '_ {
         let maybe_next_item = next_prefix(prefix.to_vec())
             .and_then(|next_prefix| {
-                self.iter_all(
+                self._iter_all(
                     column,
-                    Some(next_prefix.as_slice()),
-                    None,
-                    IterDirection::Forward,
+                    Self::total_order_read_options(),
+                    IteratorMode::From(
+                        next_prefix.as_slice(),
+                        rocksdb::Direction::Forward,
+                    ),
                 )
                 .next()
             })
@@ -252,7 +346,7 @@ where
             );
             let prefix = prefix.to_vec();
             self
-                ._iter_all(column, ReadOptions::default(), iter_mode)
+                ._iter_all(column, Self::total_order_read_options(), iter_mode)
                 // Skip the element under the `next_start_key` key.
                 .skip(1)
                 .take_while(move |item| {
@@ -266,7 +360,7 @@ where
         } else {
             // No next item, so we can start backward iteration from the end.
             let prefix = prefix.to_vec();
-            self._iter_all(column, ReadOptions::default(), IteratorMode::End)
+            self._iter_all(column, Self::total_order_read_options(), IteratorMode::End)
                 .take_while(move |item| {
                     if let Ok((key, _)) = item {
                         key.starts_with(prefix.as_slice())
@@ -298,7 +392,7 @@ where
 
                     (key_as_vec, Arc::new(value_as_vec))
                 })
-                .map_err(|e| DatabaseError::Other(e.into()).into())
+                .map_err(|e| into_database_error(e).into())
             })
     }
 }
@@ -318,7 +412,7 @@ where
         let r = buf.len();
         self.db
             .put_cf(&self.cf(column), key, buf)
-            .map_err(|e| DatabaseError::Other(e.into()))?;
+            .map_err(into_database_error)?;
 
         database_metrics().write_meter.inc();
         database_metrics().bytes_written.observe(r as f64);
@@ -329,7 +423,7 @@ where
     fn delete(&self, key: &[u8], column: Self::Column) -> StorageResult<()> {
         self.db
             .delete_cf(&self.cf(column), key)
-            .map_err(|e| DatabaseError::Other(e.into()).into())
+            .map_err(|e| into_database_error(e).into())
     }
 
     fn size_of_value(
@@ -338,21 +432,27 @@ where
         column: Self::Column,
     ) -> StorageResult<Option<usize>> {
         database_metrics().read_meter.inc();
+        let start = Instant::now();
 
-        Ok(self
+        let size = self
             .db
             .get_pinned_cf(&self.cf(column), key)
-            .map_err(|e| DatabaseError::Other(e.into()))?
-            .map(|value| value.len()))
+            .map_err(into_database_error)?
+            .map(|value| value.len());
+        slow_reads::observe_read(column.name(), start);
+
+        Ok(size)
     }
 
     fn get(&self, key: &[u8], column: Self::Column) -> StorageResult<Option<Value>> {
         database_metrics().read_meter.inc();
+        let start = Instant::now();
 
         let value = self
             .db
             .get_cf(&self.cf(column), key)
-            .map_err(|e| DatabaseError::Other(e.into()))?;
+            .map_err(into_database_error)?;
+        slow_reads::observe_read(column.name(), start);
 
         if let Some(value) = &value {
             database_metrics().bytes_read.observe(value.len() as f64);
@@ -368,11 +468,12 @@ where
         mut buf: &mut [u8],
     ) -> StorageResult<Option<usize>> {
         database_metrics().read_meter.inc();
+        let start = Instant::now();
 
         let r = self
             .db
             .get_pinned_cf(&self.cf(column), key)
-            .map_err(|e| DatabaseError::Other(e.into()))?
+            .map_err(into_database_error)?
             .map(|value| {
                 let read = value.len();
                 std::io::Write::write_all(&mut buf, value.as_ref())
@@ -380,6 +481,7 @@ where
                 StorageResult::Ok(read)
             })
             .transpose()?;
+        slow_reads::observe_read(column.name(), start);
 
         if let Some(r) = &r {
             database_metrics().bytes_read.observe(*r as f64);
@@ -400,6 +502,7 @@ where
         start: Option<&[u8]>,
         direction: IterDirection,
     ) -> BoxedIter<KVItem> {
+        slow_reads::touch(column.name());
         match (prefix, start) {
             (None, None) => {
                 let iter_mode =
@@ -409,7 +512,7 @@ where
                         // end always iterates in reverse
                         IterDirection::Reverse => IteratorMode::End,
                     };
-                self._iter_all(column, ReadOptions::default(), iter_mode)
+                self._iter_all(column, Self::total_order_read_options(), iter_mode)
                     .into_boxed()
             }
             (Some(prefix), None) => {
@@ -421,8 +524,7 @@ where
                         prefix,
                         convert_to_rocksdb_direction(direction),
                     );
-                    let mut opts = ReadOptions::default();
-                    opts.set_prefix_same_as_start(true);
+                    let opts = Self::prefix_read_options(column, prefix);
 
                     self._iter_all(column, opts, iter_mode).into_boxed()
                 }
@@ -431,7 +533,7 @@ where
                 // start iterating in a certain direction from the start key
                 let iter_mode =
                     IteratorMode::From(start, convert_to_rocksdb_direction(direction));
-                self._iter_all(column, ReadOptions::default(), iter_mode)
+                self._iter_all(column, Self::total_order_read_options(), iter_mode)
                     .into_boxed()
             }
             (Some(prefix), Some(start)) => {
@@ -443,10 +545,11 @@ where
 
                 // start iterating in a certain direction from the start key
                 // and end iterating when we've gone outside the prefix
+                let opts = Self::prefix_read_options(column, prefix);
                 let prefix = prefix.to_vec();
                 let iter_mode =
                     IteratorMode::From(start, convert_to_rocksdb_direction(direction));
-                self._iter_all(column, ReadOptions::default(), iter_mode)
+                self._iter_all(column, opts, iter_mode)
                     .take_while(move |item| {
                         if let Ok((key, _)) = item {
                             key.starts_with(prefix.as_slice())
@@ -488,7 +591,7 @@ where
 
         self.db
             .write(batch)
-            .map_err(|e| DatabaseError::Other(e.into()).into())
+            .map_err(|e| into_database_error(e).into())
     }
 }
 
@@ -505,13 +608,55 @@ where
             .map_err(|e| anyhow::anyhow!("Unable to flush SST files: {}", e))?;
         Ok(())
     }
+
+    fn statistics(&self) -> DatabaseResult<Vec<ColumnStatistics>> {
+        enum_iterator::all::<Description::Column>()
+            .map(|column| self.column_statistics(column))
+            .collect()
+    }
+
+    fn memory_usage(&self) -> DatabaseResult<MemoryUsage> {
+        let caches = [&self.caches.block, &self.caches.row]
+            .into_iter()
+            .flatten()
+            .fold(0usize, |size, cache| size.saturating_add(cache.get_usage()));
+        Ok(MemoryUsage {
+            storage_engine: caches.saturating_add(self.memtables_size()?),
+            ..Default::default()
+        })
+    }
+}
+
+/// The caches shared by the columns, kept to report their usage.
+struct Caches {
+    block: Option<Cache>,
+    row: Option<Cache>,
+}
+
+impl Debug for Caches {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Caches")
+            .field("block", &self.block.as_ref().map(Cache::get_usage))
+            .field("row", &self.row.as_ref().map(Cache::get_usage))
+            .finish()
+    }
 }
 
 /// The `None` means overflow, so there is not following prefix.
+/// Converts the error of the RocksDB, reporting the corrupted data.
+fn into_database_error(error: rocksdb::Error) -> DatabaseError {
+    if error.kind() == rocksdb::ErrorKind::Corruption {
+        corruption::report(error.to_string());
+    }
+    DatabaseError::Other(error.into())
+}
+
 fn next_prefix(mut prefix: Vec<u8>) -> Option<Vec<u8>> {
-    for byte in prefix.iter_mut().rev() {
+    // The trailing `0xFF` bytes can't be incremented, so they are dropped:
+    // the next prefix of `[0x01, 0xFF]` is `[0x02]`.
+    while let Some(byte) = prefix.pop() {
         if let Some(new_byte) = byte.checked_add(1) {
-            *byte = new_byte;
+            prefix.push(new_byte);
             return Some(prefix)
         }
     }
@@ -682,4 +827,114 @@ mod tests {
 
         assert!(!db.exists(&key, Column::Metadata).unwrap());
     }
+
+    fn keys(
+        db: &RocksDb<OnChain>,
+        prefix: Option<&[u8]>,
+        start: Option<&[u8]>,
+        direction: IterDirection,
+    ) -> Vec<Vec<u8>> {
+        db.iter_all(Column::ContractsState, prefix, start, direction)
+            .map(|item| item.unwrap().0)
+            .collect()
+    }
+
+    fn key(prefix: u8, suffix: u8) -> Vec<u8> {
+        let mut key = vec![0; 64];
+        key[31] = prefix;
+        key[63] = suffix;
+        key
+    }
+
+    #[test]
+    fn prefix_iteration_stays_within_the_prefix() {
+        let (db, _tmp) = create_db();
+        for key in [key(0xFE, 1), key(0xFF, 1), key(0xFF, 2), key(0xFF, 0xFF)] {
+            db.put(&key, Column::ContractsState, Arc::new(vec![]))
+                .unwrap();
+        }
+        let mut prefix = vec![0; 32];
+        prefix[31] = 0xFF;
+
+        let forward = keys(&db, Some(&prefix), None, IterDirection::Forward);
+        let reverse = keys(&db, Some(&prefix), None, IterDirection::Reverse);
+        let from_start = keys(
+            &db,
+            Some(&prefix),
+            Some(&key(0xFF, 2)),
+            IterDirection::Forward,
+        );
+
+        assert_eq!(forward, vec![key(0xFF, 1), key(0xFF, 2), key(0xFF, 0xFF)]);
+        assert_eq!(reverse, vec![key(0xFF, 0xFF), key(0xFF, 2), key(0xFF, 1)]);
+        assert_eq!(from_start, vec![key(0xFF, 2), key(0xFF, 0xFF)]);
+    }
+
+    #[test]
+    fn reverse_prefix_iteration_skips_empty_next_prefix() {
+        let (db, _tmp) = create_db();
+        for key in [key(1, 1), key(1, 2), key(3, 1)] {
+            db.put(&key, Column::ContractsState, Arc::new(vec![]))
+                .unwrap();
+        }
+        let mut prefix = vec![0; 32];
+        prefix[31] = 1;
+
+        let reverse = keys(&db, Some(&prefix), None, IterDirection::Reverse);
+
+        assert_eq!(reverse, vec![key(1, 2), key(1, 1)]);
+    }
+
+    #[test]
+    fn prefix_shorter_than_the_extractor_spans_multiple_prefixes() {
+        let (db, _tmp) = create_db();
+        for key in [key(1, 1), key(2, 1), key(3, 1)] {
+            db.put(&key, Column::ContractsState, Arc::new(vec![]))
+                .unwrap();
+        }
+
+        let forward = keys(&db, Some(&[0; 31]), None, IterDirection::Forward);
+
+        assert_eq!(forward, vec![key(1, 1), key(2, 1), key(3, 1)]);
+    }
+
+    #[test]
+    fn next_prefix_drops_trailing_max_bytes() {
+        assert_eq!(next_prefix(vec![1, 2]), Some(vec![1, 3]));
+        assert_eq!(next_prefix(vec![1, 0xFF]), Some(vec![2]));
+        assert_eq!(next_prefix(vec![0xFF, 0xFF]), None);
+    }
+
+    #[test]
+    fn statistics_reports_flushed_columns() {
+        let (db, _tmp) = create_db();
+        db.put(&[1; 32], Column::Coins, Arc::new(vec![2; 32]))
+            .unwrap();
+        db.flush().unwrap();
+
+        let statistics = db.statistics().unwrap();
+
+        assert_eq!(statistics.len(), enum_iterator::all::<Column>().count());
+        let coins = statistics
+            .iter()
+            .find(|column| column.name == Column::Coins.name())
+            .unwrap();
+        assert_eq!(coins.estimated_keys, 1);
+        assert!(coins.size > 0);
+        assert_eq!(coins.files_per_level.len(), NUM_LEVELS);
+        assert_eq!(coins.files_per_level.iter().sum::<u64>(), 1);
+    }
+
+    #[test]
+    fn memory_usage_includes_the_memtables() {
+        let (db, _tmp) = create_db();
+        let before = db.memory_usage().unwrap();
+
+        db.put(&[1; 32], Column::Coins, Arc::new(vec![2; 1024 * 1024]))
+            .unwrap();
+
+        let after = db.memory_usage().unwrap();
+        assert!(after.storage_engine > before.storage_engine);
+        assert_eq!(after.contract_cache, 0);
+    }
 }
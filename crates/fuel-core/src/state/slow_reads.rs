@@ -0,0 +1,102 @@
+//! The log of the slow storage reads and the tables touched by the GraphQL requests.
+//!
+//! The threshold is process-wide, like the metrics, and the logging is disabled until it is set.
+//! The tables are collected only for the futures wrapped by [`track_tables`],
+//! and only for the reads performed on the task polling that future.
+
+use fuel_core_metrics::core_metrics::database_metrics;
+use std::{
+    collections::BTreeSet,
+    future::Future,
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+type TouchedTables = Arc<Mutex<BTreeSet<&'static str>>>;
+
+static THRESHOLD_MICROS: AtomicU64 = AtomicU64::new(u64::MAX);
+
+tokio::task_local! {
+    static TOUCHED_TABLES: TouchedTables;
+}
+
+/// Logs the storage reads exceeding the `threshold`.
+pub fn set_threshold(threshold: Duration) {
+    let micros = u64::try_from(threshold.as_micros()).unwrap_or(u64::MAX);
+    THRESHOLD_MICROS.store(micros, Ordering::Relaxed);
+}
+
+/// Records that the `table` is touched by the current request.
+pub fn touch(table: &'static str) {
+    let _ = TOUCHED_TABLES.try_with(|tables| {
+        tables
+            .lock()
+            .expect("The lock is not poisoned; qed")
+            .insert(table);
+    });
+}
+
+/// Records the read of the `table` started at `start`. The read is logged
+/// and counted by the metrics if it exceeds the threshold.
+pub fn observe_read(table: &'static str, start: Instant) {
+    touch(table);
+
+    let threshold = Duration::from_micros(THRESHOLD_MICROS.load(Ordering::Relaxed));
+    let elapsed = start.elapsed();
+    if elapsed > threshold {
+        database_metrics().observe_slow_read(table);
+        tracing::info!(
+            "The read from the table {table:?} exceeded threshold of {:?} seconds at {:?} seconds",
+            threshold.as_secs_f64(),
+            elapsed.as_secs_f64()
+        );
+    }
+}
+
+/// Runs the `future` collecting the tables it reads from.
+pub async fn track_tables<F: Future>(future: F) -> F::Output {
+    TOUCHED_TABLES.scope(TouchedTables::default(), future).await
+}
+
+/// Returns the tables read so far by the future wrapped with [`track_tables`].
+pub fn touched_tables() -> Vec<&'static str> {
+    TOUCHED_TABLES
+        .try_with(|tables| {
+            tables
+                .lock()
+                .expect("The lock is not poisoned; qed")
+                .iter()
+                .copied()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn track_tables_collects_the_read_tables() {
+        let tables = track_tables(async {
+            observe_read("Coins", Instant::now());
+            observe_read("Messages", Instant::now());
+            observe_read("Coins", Instant::now());
+            touched_tables()
+        })
+        .await;
+
+        assert_eq!(tables, vec!["Coins", "Messages"]);
+        assert!(touched_tables().is_empty());
+    }
+}
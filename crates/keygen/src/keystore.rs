@@ -0,0 +1,357 @@
+//! The keystore file with the secret key encrypted by the passphrase.
+//!
+//! The keystore is a JSON object:
+//!
+//! ```json
+//! {
+//!   "version": 1,
+//!   "address": "<the address of the key in hex>",
+//!   "kdf": { "log_n": 15, "r": 8, "p": 1, "salt": "<32 bytes in hex>" },
+//!   "cipher": { "nonce": "<12 bytes in hex>" },
+//!   "ciphertext": "<48 bytes in hex>"
+//! }
+//! ```
+//!
+//! The 32-byte encryption key is derived from the passphrase with scrypt using the `kdf`
+//! parameters. The secret key is encrypted with AES-256-GCM under the `nonce`, and the
+//! `ciphertext` contains the encrypted secret key followed by the 16-byte tag.
+//! The `address` isn't encrypted, so it identifies the key without the passphrase.
+
+use aes_gcm::{
+    aead::{
+        Aead,
+        KeyInit,
+    },
+    Aes256Gcm,
+    Nonce,
+};
+use fuel_core_types::{
+    blockchain::primitives::SecretKeyWrapper,
+    fuel_crypto::{
+        rand::{
+            prelude::StdRng,
+            RngCore,
+            SeedableRng,
+        },
+        SecretKey,
+    },
+    fuel_tx::Input,
+    fuel_types::Address,
+    secrecy::{
+        zeroize::Zeroizing,
+        ExposeSecret,
+        Secret,
+        SecretString,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    ops::Deref,
+    path::Path,
+};
+
+/// The version of the keystore layout.
+pub const KEYSTORE_VERSION: u32 = 1;
+
+/// The maximal memory of the scrypt key derivation accepted from the keystore file,
+/// so a malformed file can't make the node allocate an unbounded amount of memory.
+/// It fits the cost `log_n = 20` with `r = 8`.
+const MAX_SCRYPT_MEMORY: u64 = 1 << 30;
+/// The maximal scrypt parallelism accepted from the keystore file. The key derivation
+/// runs sequentially, so the parallelism multiplies its time.
+const MAX_SCRYPT_PARALLELISM: u32 = 16;
+
+const SALT_SIZE: usize = 32;
+const NONCE_SIZE: usize = 12;
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Keystore {
+    pub version: u32,
+    pub address: Address,
+    pub kdf: KdfParams,
+    pub cipher: CipherParams,
+    #[serde(with = "hex::serde")]
+    pub ciphertext: Vec<u8>,
+}
+
+/// The parameters of the scrypt key derivation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub log_n: u8,
+    pub r: u32,
+    pub p: u32,
+    #[serde(with = "hex::serde")]
+    pub salt: Vec<u8>,
+}
+
+/// The parameters of the AES-256-GCM encryption.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CipherParams {
+    #[serde(with = "hex::serde")]
+    pub nonce: Vec<u8>,
+}
+
+impl Keystore {
+    /// Encrypts the `secret` with the default scrypt cost.
+    pub fn encrypt(
+        secret: &SecretKey,
+        passphrase: &SecretString,
+    ) -> anyhow::Result<Self> {
+        Self::encrypt_with_cost(secret, passphrase, 15, 8, 1)
+    }
+
+    /// Encrypts the `secret` with the scrypt cost parameters.
+    pub fn encrypt_with_cost(
+        secret: &SecretKey,
+        passphrase: &SecretString,
+        log_n: u8,
+        r: u32,
+        p: u32,
+    ) -> anyhow::Result<Self> {
+        let mut rng = StdRng::from_entropy();
+        let mut salt = vec![0u8; SALT_SIZE];
+        rng.fill_bytes(&mut salt);
+        let mut nonce = vec![0u8; NONCE_SIZE];
+        rng.fill_bytes(&mut nonce);
+
+        let kdf = KdfParams { log_n, r, p, salt };
+        let cipher = cipher(&kdf, passphrase)?;
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), secret.deref().as_slice())
+            .map_err(|_| anyhow::anyhow!("failed to encrypt the secret key"))?;
+
+        Ok(Self {
+            version: KEYSTORE_VERSION,
+            address: Input::owner(&secret.public_key()),
+            kdf,
+            cipher: CipherParams { nonce },
+            ciphertext,
+        })
+    }
+
+    /// Decrypts the secret key. The intermediate buffers are zeroized,
+    /// and the returned secret is zeroized on drop.
+    pub fn decrypt(
+        &self,
+        passphrase: &SecretString,
+    ) -> anyhow::Result<Secret<SecretKeyWrapper>> {
+        anyhow::ensure!(
+            self.version == KEYSTORE_VERSION,
+            "unsupported keystore version {}",
+            self.version
+        );
+        anyhow::ensure!(
+            self.cipher.nonce.len() == NONCE_SIZE,
+            "the keystore nonce should have {NONCE_SIZE} bytes"
+        );
+
+        let cipher = cipher(&self.kdf, passphrase)?;
+        let plaintext = Zeroizing::new(
+            cipher
+                .decrypt(
+                    Nonce::from_slice(&self.cipher.nonce),
+                    self.ciphertext.as_slice(),
+                )
+                .map_err(|_| {
+                    anyhow::anyhow!("invalid passphrase or corrupted keystore")
+                })?,
+        );
+        let secret = SecretKey::try_from(plaintext.as_slice())
+            .map_err(|_| anyhow::anyhow!("the keystore doesn't contain a secret key"))?;
+        anyhow::ensure!(
+            Input::owner(&secret.public_key()) == self.address,
+            "the decrypted key doesn't match the keystore address"
+        );
+
+        Ok(Secret::new(secret.into()))
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content)
+            .map_err(|err| anyhow::anyhow!("invalid keystore {}: {err}", path.display()))
+    }
+
+    /// Creates the keystore file readable only by the owner.
+    pub fn save(&self, path: &Path) -> anyhow::Result<()> {
+        use std::io::Write;
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(path)?;
+        file.write_all(serde_json::to_string_pretty(self)?.as_bytes())?;
+        Ok(())
+    }
+}
+
+/// Derives the encryption key from the `passphrase`.
+fn cipher(kdf: &KdfParams, passphrase: &SecretString) -> anyhow::Result<Aes256Gcm> {
+    // scrypt allocates `128 * r * 2^log_n` bytes for the mixing of each lane.
+    let memory = 1u64
+        .checked_shl(u32::from(kdf.log_n))
+        .and_then(|n| n.checked_mul(128))
+        .and_then(|memory| memory.checked_mul(u64::from(kdf.r)));
+    anyhow::ensure!(
+        memory.is_some_and(|memory| memory <= MAX_SCRYPT_MEMORY),
+        "the keystore scrypt parameters `log_n` and `r` need more than {MAX_SCRYPT_MEMORY} bytes"
+    );
+    anyhow::ensure!(
+        kdf.p <= MAX_SCRYPT_PARALLELISM,
+        "the keystore scrypt parallelism `p` can't exceed {MAX_SCRYPT_PARALLELISM}"
+    );
+    let params = scrypt::Params::new(kdf.log_n, kdf.r, kdf.p)
+        .map_err(|_| anyhow::anyhow!("invalid scrypt parameters of the keystore"))?;
+    let mut key = Zeroizing::new([0u8; 32]);
+    scrypt::scrypt(
+        passphrase.expose_secret().as_bytes(),
+        &kdf.salt,
+        &params,
+        key.as_mut_slice(),
+    )
+    .map_err(|_| anyhow::anyhow!("failed to derive the keystore key"))?;
+
+    Aes256Gcm::new_from_slice(key.as_slice())
+        .map_err(|_| anyhow::anyhow!("invalid keystore key length"))
+}
+
+/// Reads the passphrase from the file, or prompts it from the terminal without echo
+/// if the `path` is not set.
+pub fn read_passphrase(path: Option<&Path>) -> anyhow::Result<SecretString> {
+    match path {
+        Some(path) => read_passphrase_file(path),
+        None => prompt_passphrase("Keystore passphrase: "),
+    }
+}
+
+/// Reads the passphrase for a new keystore. The prompted passphrase is asked twice
+/// to make sure it doesn't contain a typo.
+pub fn read_new_passphrase(path: Option<&Path>) -> anyhow::Result<SecretString> {
+    match path {
+        Some(path) => read_passphrase_file(path),
+        None => {
+            let passphrase = prompt_passphrase("New keystore passphrase: ")?;
+            let confirmation = prompt_passphrase("Repeat the passphrase: ")?;
+            anyhow::ensure!(
+                passphrase.expose_secret() == confirmation.expose_secret(),
+                "the passphrases don't match"
+            );
+            Ok(passphrase)
+        }
+    }
+}
+
+fn read_passphrase_file(path: &Path) -> anyhow::Result<SecretString> {
+    let content = Zeroizing::new(std::fs::read_to_string(path)?);
+    Ok(SecretString::new(
+        content.trim_end_matches(['\r', '\n']).to_string(),
+    ))
+}
+
+fn prompt_passphrase(prompt: &str) -> anyhow::Result<SecretString> {
+    use std::io::Write;
+    use termion::input::TermRead;
+
+    let mut stdout = std::io::stdout();
+    write!(stdout, "{prompt}")?;
+    stdout.flush()?;
+    let passphrase = std::io::stdin()
+        .read_passwd(&mut stdout)?
+        .ok_or_else(|| anyhow::anyhow!("the passphrase is not provided"))?;
+    writeln!(stdout)?;
+
+    Ok(SecretString::new(passphrase))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn passphrase(passphrase: &str) -> SecretString {
+        SecretString::new(passphrase.to_string())
+    }
+
+    #[test]
+    fn decrypt_returns_the_encrypted_secret() {
+        // Given
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(1234));
+        let keystore =
+            Keystore::encrypt_with_cost(&secret, &passphrase("password"), 4, 8, 1)
+                .unwrap();
+
+        // When
+        let decrypted = keystore.decrypt(&passphrase("password")).unwrap();
+
+        // Then
+        assert_eq!(**decrypted.expose_secret(), secret);
+        assert_eq!(keystore.address, Input::owner(&secret.public_key()));
+    }
+
+    #[test]
+    fn decrypt_fails_with_wrong_passphrase() {
+        // Given
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(1234));
+        let keystore =
+            Keystore::encrypt_with_cost(&secret, &passphrase("password"), 4, 8, 1)
+                .unwrap();
+
+        // When
+        let result = keystore.decrypt(&passphrase("wrong"));
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn keystore_survives_json_round_trip() {
+        // Given
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(1234));
+        let keystore =
+            Keystore::encrypt_with_cost(&secret, &passphrase("password"), 4, 8, 1)
+                .unwrap();
+
+        // When
+        let json = serde_json::to_string(&keystore).unwrap();
+        let decoded: Keystore = serde_json::from_str(&json).unwrap();
+
+        // Then
+        assert_eq!(decoded, keystore);
+        assert!(decoded.decrypt(&passphrase("password")).is_ok());
+    }
+
+    #[test]
+    fn decrypt_rejects_excessive_scrypt_memory() {
+        // Given
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(1234));
+        let mut keystore =
+            Keystore::encrypt_with_cost(&secret, &passphrase("password"), 4, 8, 1)
+                .unwrap();
+        keystore.kdf.r = u32::MAX;
+
+        // When
+        let result = keystore.decrypt(&passphrase("password"));
+
+        // Then
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn decrypt_rejects_excessive_scrypt_parallelism() {
+        // Given
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(1234));
+        let mut keystore =
+            Keystore::encrypt_with_cost(&secret, &passphrase("password"), 4, 8, 1)
+                .unwrap();
+        keystore.kdf.p = u32::MAX;
+
+        // When
+        let result = keystore.decrypt(&passphrase("password"));
+
+        // Then
+        assert!(result.is_err());
+    }
+}
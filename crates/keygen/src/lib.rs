@@ -1,6 +1,13 @@
 #![deny(clippy::arithmetic_side_effects)]
 #![deny(clippy::cast_possible_truncation)]
 
+pub mod keystore;
+
+use bech32::{
+    FromBase32,
+    ToBase32,
+    Variant,
+};
 use clap::ValueEnum;
 use fuel_core_types::{
     fuel_crypto::{
@@ -8,22 +15,42 @@ use fuel_core_types::{
             prelude::StdRng,
             SeedableRng,
         },
+        Message,
         SecretKey,
+        Signature,
     },
     fuel_tx::Input,
-    fuel_types::Address,
+    fuel_types::{
+        Address,
+        Bytes32,
+    },
+    secrecy::{
+        zeroize::Zeroizing,
+        ExposeSecret,
+    },
+};
+use keystore::{
+    read_passphrase,
+    Keystore,
 };
 use libp2p_identity::{
     secp256k1,
     Keypair,
     PeerId,
 };
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 use std::{
     ops::Deref,
+    path::Path,
     str::FromStr,
 };
 
+/// The human-readable part of the bech32 encoded Fuel addresses.
+pub const FUEL_BECH32_HRP: &str = "fuel";
+
 #[derive(Clone, Copy, Debug, Default, Serialize, ValueEnum)]
 #[serde(rename_all = "kebab-case")]
 pub enum KeyType {
@@ -145,3 +172,124 @@ pub fn parse_secret(
         }
     })
 }
+
+#[derive(Clone, Debug, Serialize)]
+pub struct AddressResponse {
+    address: Address,
+    bech32: String,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct SignResponse {
+    digest: Bytes32,
+    signature: Signature,
+    address: Address,
+}
+
+/// The keystore file created from the output of the `new` command.
+#[derive(Deserialize)]
+struct PlainKeystore {
+    secret: SecretKey,
+}
+
+/// Parses the address in the hex or the bech32 encoding and returns it in both encodings.
+pub fn convert_address(address: &str) -> anyhow::Result<AddressResponse> {
+    let address = match Address::from_str(address) {
+        Ok(address) => address,
+        Err(_) => {
+            let (hrp, data, variant) = bech32::decode(address)
+                .map_err(|_| anyhow::anyhow!("invalid hex or bech32 address"))?;
+            anyhow::ensure!(
+                hrp == FUEL_BECH32_HRP && variant == Variant::Bech32m,
+                "the bech32 address should have the `{FUEL_BECH32_HRP}` prefix and use bech32m"
+            );
+            let bytes = Vec::<u8>::from_base32(&data)?;
+            Address::try_from(bytes.as_slice())
+                .map_err(|_| anyhow::anyhow!("the bech32 address should have 32 bytes"))?
+        }
+    };
+    let bech32 = bech32::encode(FUEL_BECH32_HRP, address.to_base32(), Variant::Bech32m)?;
+
+    Ok(AddressResponse { address, bech32 })
+}
+
+/// Reads the secret key from the keystore file. The file contains either the JSON
+/// output of the `new` command, the secret key in hex, or the [`Keystore`] encrypted
+/// with the passphrase from the `passphrase_file` or prompted from the terminal.
+pub fn read_keystore(
+    path: &Path,
+    passphrase_file: Option<&Path>,
+) -> anyhow::Result<SecretKey> {
+    let content = Zeroizing::new(std::fs::read_to_string(path)?);
+    let content = content.trim();
+    if let Ok(secret) = SecretKey::from_str(content) {
+        return Ok(secret)
+    }
+    if let Ok(keystore) = serde_json::from_str::<Keystore>(content) {
+        let passphrase = read_passphrase(passphrase_file)?;
+        let secret = keystore.decrypt(&passphrase)?;
+        return Ok(**secret.expose_secret())
+    }
+    let keystore: PlainKeystore = serde_json::from_str(content)
+        .map_err(|_| anyhow::anyhow!("invalid keystore file {}", path.display()))?;
+    Ok(keystore.secret)
+}
+
+/// Signs the 32-byte `digest` in hex with the `secret` key.
+pub fn sign_digest(secret: &SecretKey, digest: &str) -> anyhow::Result<SignResponse> {
+    let digest = Bytes32::from_str(digest)
+        .map_err(|_| anyhow::anyhow!("invalid digest, expected 32 bytes in hex"))?;
+    let signature = Signature::sign(secret, &Message::from_bytes(*digest));
+
+    Ok(SignResponse {
+        digest,
+        signature,
+        address: Input::owner(&secret.public_key()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_address_returns_the_same_address_for_both_encodings() {
+        // Given
+        let address = Address::from([7; 32]);
+        let from_hex = convert_address(&address.to_string()).unwrap();
+
+        // When
+        let from_bech32 = convert_address(&from_hex.bech32).unwrap();
+
+        // Then
+        assert_eq!(from_hex.address, address);
+        assert_eq!(from_bech32.address, address);
+        assert_eq!(from_bech32.bech32, from_hex.bech32);
+    }
+
+    #[test]
+    fn convert_address_rejects_other_prefixes() {
+        let bech32 =
+            bech32::encode("eth", [7u8; 32].to_base32(), Variant::Bech32m).unwrap();
+
+        assert!(convert_address(&bech32).is_err());
+    }
+
+    #[test]
+    fn sign_digest_signature_recovers_the_signer() {
+        // Given
+        let secret = SecretKey::random(&mut StdRng::seed_from_u64(1234));
+        let digest = Bytes32::from([1; 32]);
+
+        // When
+        let response = sign_digest(&secret, &digest.to_string()).unwrap();
+
+        // Then
+        let public_key = response
+            .signature
+            .recover(&Message::from_bytes(*digest))
+            .unwrap();
+        assert_eq!(public_key, secret.public_key());
+        assert_eq!(response.address, Input::owner(&public_key));
+    }
+}
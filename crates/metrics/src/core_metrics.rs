@@ -1,12 +1,19 @@
 use prometheus_client::{
+    encoding::EncodeLabelSet,
     metrics::{
         counter::Counter,
+        family::Family,
         histogram::Histogram,
     },
     registry::Registry,
 };
 use std::sync::OnceLock;
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct TableLabel {
+    table: String,
+}
+
 pub struct DatabaseMetrics {
     pub registry: Registry,
     // For descriptions of each Counter, see the `new` function where each Counter/Histogram is initialized
@@ -14,6 +21,9 @@ pub struct DatabaseMetrics {
     pub read_meter: Counter,
     pub bytes_written: Histogram,
     pub bytes_read: Histogram,
+    pub slow_reads: Family<TableLabel, Counter>,
+    pub coin_cache_hits: Counter,
+    pub coin_cache_misses: Counter,
 }
 
 impl DatabaseMetrics {
@@ -35,8 +45,19 @@ impl DatabaseMetrics {
             read_meter,
             bytes_read: bytes_read_histogram,
             bytes_written: bytes_written_histogram,
+            slow_reads: Family::default(),
+            coin_cache_hits: Counter::default(),
+            coin_cache_misses: Counter::default(),
         }
     }
+
+    pub fn observe_slow_read(&self, table: &str) {
+        self.slow_reads
+            .get_or_create(&TableLabel {
+                table: table.to_string(),
+            })
+            .inc();
+    }
 }
 
 pub fn init(mut metrics: DatabaseMetrics) -> DatabaseMetrics {
@@ -60,6 +81,21 @@ pub fn init(mut metrics: DatabaseMetrics) -> DatabaseMetrics {
         "Histogram containing values of amount of bytes written per operation",
         metrics.bytes_written.clone(),
     );
+    metrics.registry.register(
+        "database_slow_reads",
+        "Number of the reads exceeding the log threshold per table",
+        metrics.slow_reads.clone(),
+    );
+    metrics.registry.register(
+        "coin_cache_hits",
+        "Number of the coins read from the coin cache",
+        metrics.coin_cache_hits.clone(),
+    );
+    metrics.registry.register(
+        "coin_cache_misses",
+        "Number of the coins read from the storage because they weren't in the coin cache",
+        metrics.coin_cache_misses.clone(),
+    );
 
     metrics
 }
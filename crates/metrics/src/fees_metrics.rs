@@ -0,0 +1,74 @@
+use prometheus_client::{
+    metrics::counter::Counter,
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+/// The fees of the blocks produced by this node, so operators can reconcile
+/// the revenue of the block producer. The blocks received from the network
+/// are not counted.
+pub struct FeesMetrics {
+    pub registry: Registry,
+    pub tips: Counter,
+    pub base_fees: Counter,
+    pub coinbase: Counter,
+    pub burned: Counter,
+    pub refunds: Counter,
+}
+
+impl Default for FeesMetrics {
+    fn default() -> Self {
+        let mut registry = Registry::default();
+
+        let tips = Counter::default();
+        let base_fees = Counter::default();
+        let coinbase = Counter::default();
+        let burned = Counter::default();
+        let refunds = Counter::default();
+
+        registry.register(
+            "fees_tips",
+            "The sum of the tips paid to the block producer",
+            tips.clone(),
+        );
+
+        registry.register(
+            "fees_base_fees",
+            "The sum of the fees charged for the used gas, without tips",
+            base_fees.clone(),
+        );
+
+        registry.register(
+            "fees_coinbase",
+            "The amount minted to the coinbase recipient",
+            coinbase.clone(),
+        );
+
+        registry.register(
+            "fees_burned",
+            "The fees not minted to anyone because of the missing coinbase recipient",
+            burned.clone(),
+        );
+
+        registry.register(
+            "fees_refunds",
+            "The sum of the unused fees returned to the change outputs",
+            refunds.clone(),
+        );
+
+        Self {
+            registry,
+            tips,
+            base_fees,
+            coinbase,
+            burned,
+            refunds,
+        }
+    }
+}
+
+static FEES_METRICS: OnceLock<FeesMetrics> = OnceLock::new();
+
+pub fn fees_metrics() -> &'static FeesMetrics {
+    FEES_METRICS.get_or_init(FeesMetrics::default)
+}
@@ -2,6 +2,7 @@ use crate::timing_buckets;
 use prometheus_client::{
     encoding::EncodeLabelSet,
     metrics::{
+        counter::Counter,
         family::Family,
         gauge::Gauge,
         histogram::Histogram,
@@ -16,11 +17,22 @@ pub struct Label {
     path: String,
 }
 
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+pub struct EntityLabel {
+    // the kind of the cached entity
+    entity: String,
+}
+
 pub struct GraphqlMetrics {
     pub registry: Registry,
     // using gauges in case blocks are rolled back for any reason
     pub total_txs_count: Gauge,
     requests: Family<Label, Histogram>,
+    slow_queries: Family<Label, Counter>,
+    entity_cache_hits: Family<EntityLabel, Counter>,
+    entity_cache_misses: Family<EntityLabel, Counter>,
+    queued_requests: Gauge,
+    shed_requests: Counter,
 }
 
 impl GraphqlMetrics {
@@ -31,6 +43,36 @@ impl GraphqlMetrics {
             Histogram::new(timing_buckets().iter().cloned())
         });
         registry.register("graphql_request_duration_seconds", "", requests.clone());
+        let slow_queries = Family::<Label, Counter>::default();
+        registry.register(
+            "graphql_slow_queries",
+            "Number of the resolvers exceeding the log threshold per path",
+            slow_queries.clone(),
+        );
+        let entity_cache_hits = Family::<EntityLabel, Counter>::default();
+        registry.register(
+            "graphql_entity_cache_hits",
+            "Number of the entities served from the cache of the immutable entities",
+            entity_cache_hits.clone(),
+        );
+        let entity_cache_misses = Family::<EntityLabel, Counter>::default();
+        registry.register(
+            "graphql_entity_cache_misses",
+            "Number of the entities fetched from the database on the cache miss",
+            entity_cache_misses.clone(),
+        );
+        let queued_requests = Gauge::default();
+        registry.register(
+            "graphql_queued_requests",
+            "Number of the requests waiting for the execution in the queue",
+            queued_requests.clone(),
+        );
+        let shed_requests = Counter::default();
+        registry.register(
+            "graphql_shed_requests",
+            "Number of the requests rejected because the node is overloaded",
+            shed_requests.clone(),
+        );
 
         registry.register(
             "importer_tx_count",
@@ -42,6 +84,11 @@ impl GraphqlMetrics {
             registry,
             total_txs_count: tx_count_gauge,
             requests,
+            slow_queries,
+            entity_cache_hits,
+            entity_cache_misses,
+            queued_requests,
+            shed_requests,
         }
     }
 
@@ -51,6 +98,36 @@ impl GraphqlMetrics {
         });
         histogram.observe(time);
     }
+
+    pub fn observe_slow_query(&self, path: &str) {
+        self.slow_queries
+            .get_or_create(&Label {
+                path: path.to_string(),
+            })
+            .inc();
+    }
+
+    pub fn observe_entity_cache(&self, entity: &str, hit: bool) {
+        let counters = if hit {
+            &self.entity_cache_hits
+        } else {
+            &self.entity_cache_misses
+        };
+        counters
+            .get_or_create(&EntityLabel {
+                entity: entity.to_string(),
+            })
+            .inc();
+    }
+
+    pub fn observe_queued_requests(&self, queued: usize) {
+        self.queued_requests
+            .set(i64::try_from(queued).unwrap_or(i64::MAX));
+    }
+
+    pub fn observe_shed_request(&self) {
+        self.shed_requests.inc();
+    }
 }
 
 static GRAPHQL_METRICS: OnceLock<GraphqlMetrics> = OnceLock::new();
@@ -1,8 +1,12 @@
 use crate::timing_buckets;
 use prometheus_client::{
     metrics::{
+        counter::Counter,
         gauge::Gauge,
-        histogram::Histogram,
+        histogram::{
+            exponential_buckets,
+            Histogram,
+        },
     },
     registry::Registry,
 };
@@ -14,8 +18,16 @@ use std::sync::{
 pub struct ImporterMetrics {
     pub registry: Registry,
     pub block_height: Gauge,
+    pub da_height: Gauge,
     pub latest_block_import_timestamp: Gauge<f64, AtomicU64>,
+    pub block_time_drift: Gauge<f64, AtomicU64>,
     pub execute_and_commit_duration: Histogram,
+    pub block_execution_duration: Histogram,
+    pub block_execution_duration_per_transaction: Histogram,
+    pub transaction_instructions: Histogram,
+    pub transaction_memory_watermark: Histogram,
+    pub block_committed_lagged_events: Counter,
+    pub state_diff_lagged_events: Counter,
 }
 
 impl Default for ImporterMetrics {
@@ -23,9 +35,22 @@ impl Default for ImporterMetrics {
         let mut registry = Registry::default();
 
         let block_height_gauge = Gauge::default();
+        let da_height_gauge = Gauge::default();
         let latest_block_import_ms = Gauge::default();
+        let block_time_drift = Gauge::default();
         let execute_and_commit_duration =
             Histogram::new(timing_buckets().iter().cloned());
+        let block_execution_duration = Histogram::new(timing_buckets().iter().cloned());
+        // From 10 microseconds to 2.6 seconds.
+        let block_execution_duration_per_transaction =
+            Histogram::new(exponential_buckets(0.00001, 4.0, 10));
+        // From 16 to 4 billions of instructions.
+        let transaction_instructions = Histogram::new(exponential_buckets(16.0, 4.0, 15));
+        // From 1 KiB to 64 MiB.
+        let transaction_memory_watermark =
+            Histogram::new(exponential_buckets(1024.0, 4.0, 9));
+        let block_committed_lagged_events = Counter::default();
+        let state_diff_lagged_events = Counter::default();
 
         registry.register(
             "importer_block_height",
@@ -33,23 +58,79 @@ impl Default for ImporterMetrics {
             block_height_gauge.clone(),
         );
 
+        registry.register(
+            "importer_da_height",
+            "the DA height processed by the current block of the chain",
+            da_height_gauge.clone(),
+        );
+
         registry.register(
             "importer_latest_block_commit_timestamp_s",
             "A timestamp of when the current block was imported",
             latest_block_import_ms.clone(),
         );
 
+        registry.register(
+            "importer_block_time_drift_s",
+            "The difference between the timestamp of the current block and the local clock at the import",
+            block_time_drift.clone(),
+        );
+
         registry.register(
             "importer_execute_and_commit_duration_s",
             "Records the duration time of executing and committing a block",
             execute_and_commit_duration.clone(),
         );
 
+        registry.register(
+            "importer_block_execution_duration_s",
+            "The time of the execution of the imported block during its validation",
+            block_execution_duration.clone(),
+        );
+
+        registry.register(
+            "importer_block_execution_duration_per_transaction_s",
+            "The average execution time of one transaction of the imported block",
+            block_execution_duration_per_transaction.clone(),
+        );
+
+        registry.register(
+            "importer_transaction_instructions",
+            "The number of the instructions executed by the VM for the transaction of the imported block",
+            transaction_instructions.clone(),
+        );
+
+        registry.register(
+            "importer_transaction_memory_watermark_bytes",
+            "The highest memory usage of the VM for the transaction of the imported block",
+            transaction_memory_watermark.clone(),
+        );
+
+        registry.register(
+            "importer_block_committed_lagged_events",
+            "The number of the committed block events skipped by the slow subscribers",
+            block_committed_lagged_events.clone(),
+        );
+
+        registry.register(
+            "importer_state_diff_lagged_events",
+            "The number of the state diff events skipped by the slow subscribers",
+            state_diff_lagged_events.clone(),
+        );
+
         Self {
             registry,
             block_height: block_height_gauge,
+            da_height: da_height_gauge,
             latest_block_import_timestamp: latest_block_import_ms,
+            block_time_drift,
             execute_and_commit_duration,
+            block_execution_duration,
+            block_execution_duration_per_transaction,
+            transaction_instructions,
+            transaction_memory_watermark,
+            block_committed_lagged_events,
+            state_diff_lagged_events,
         }
     }
 }
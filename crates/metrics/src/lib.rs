@@ -6,10 +6,12 @@
 use std::sync::OnceLock;
 
 pub mod core_metrics;
+pub mod fees_metrics;
 pub mod future_tracker;
 pub mod graphql_metrics;
 pub mod importer;
 pub mod p2p_metrics;
+pub mod relayer_metrics;
 pub mod response;
 pub mod services;
 pub mod txpool_metrics;
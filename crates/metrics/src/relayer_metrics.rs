@@ -0,0 +1,106 @@
+use prometheus_client::{
+    metrics::{
+        counter::Counter,
+        gauge::Gauge,
+        histogram::{
+            exponential_buckets,
+            Histogram,
+        },
+    },
+    registry::Registry,
+};
+use std::sync::OnceLock;
+
+/// The progress of the relayer, so operators can detect the outages of the bridge.
+pub struct RelayerMetrics {
+    pub registry: Registry,
+    pub da_finalized_height: Gauge,
+    pub downloaded_da_height: Gauge,
+    pub synced_height: Gauge,
+    pub da_lag: Gauge,
+    pub sync_cycles: Counter,
+    pub failed_sync_cycles: Counter,
+    pub events: Counter,
+    pub events_per_sync_cycle: Histogram,
+}
+
+impl Default for RelayerMetrics {
+    fn default() -> Self {
+        let mut registry = Registry::default();
+
+        let da_finalized_height = Gauge::default();
+        let downloaded_da_height = Gauge::default();
+        let synced_height = Gauge::default();
+        let da_lag = Gauge::default();
+        let sync_cycles = Counter::default();
+        let failed_sync_cycles = Counter::default();
+        let events = Counter::default();
+        let events_per_sync_cycle = Histogram::new(exponential_buckets(1.0, 4.0, 8));
+
+        registry.register(
+            "relayer_da_finalized_height",
+            "The last finalized height reported by the DA node",
+            da_finalized_height.clone(),
+        );
+
+        registry.register(
+            "relayer_downloaded_da_height",
+            "The last DA height with the events downloaded and stored by the relayer",
+            downloaded_da_height.clone(),
+        );
+
+        registry.register(
+            "relayer_synced_height",
+            "The last DA height the relayer reported as synced, available to the block production and the verification",
+            synced_height.clone(),
+        );
+
+        registry.register(
+            "relayer_da_lag",
+            "The number of finalized DA blocks the relayer was behind at the start of the last sync cycle",
+            da_lag.clone(),
+        );
+
+        registry.register(
+            "relayer_sync_cycles",
+            "The number of the sync cycles with the DA node",
+            sync_cycles.clone(),
+        );
+
+        registry.register(
+            "relayer_failed_sync_cycles",
+            "The number of the sync cycles failed because of the DA node RPC or the database errors",
+            failed_sync_cycles.clone(),
+        );
+
+        registry.register(
+            "relayer_events",
+            "The number of the events downloaded from the DA node",
+            events.clone(),
+        );
+
+        registry.register(
+            "relayer_events_per_sync_cycle",
+            "The number of the events downloaded during one sync cycle",
+            events_per_sync_cycle.clone(),
+        );
+
+        Self {
+            registry,
+            da_finalized_height,
+            downloaded_da_height,
+            synced_height,
+            da_lag,
+            sync_cycles,
+            failed_sync_cycles,
+            events,
+            events_per_sync_cycle,
+        }
+    }
+}
+
+static RELAYER_METRICS: OnceLock<RelayerMetrics> = OnceLock::new();
+
+pub fn relayer_metrics() -> &'static RelayerMetrics {
+    RELAYER_METRICS.get_or_init(RelayerMetrics::default)
+}
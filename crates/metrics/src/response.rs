@@ -1,7 +1,9 @@
 use crate::{
+    fees_metrics::fees_metrics,
     graphql_metrics::graphql_metrics,
     importer::importer_metrics,
     p2p_metrics::p2p_metrics,
+    relayer_metrics::relayer_metrics,
     services::services_metrics,
     txpool_metrics::txpool_metrics,
 };
@@ -50,6 +52,14 @@ pub fn encode_metrics_response() -> impl IntoResponse {
         return error_body()
     }
 
+    if encode(&mut encoded, &fees_metrics().registry).is_err() {
+        return error_body()
+    }
+
+    if encode(&mut encoded, &relayer_metrics().registry).is_err() {
+        return error_body()
+    }
+
     Response::builder()
         .status(200)
         .body(Body::from(encoded))
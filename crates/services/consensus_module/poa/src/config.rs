@@ -1,8 +1,13 @@
+use crate::{
+    service::Paused,
+    LeaderLeaseConfig,
+};
 use fuel_core_types::{
     blockchain::primitives::SecretKeyWrapper,
     fuel_asm::Word,
     fuel_tx::ConsensusParameters,
     secrecy::Secret,
+    tai64::Tai64,
 };
 use tokio::time::Duration;
 
@@ -15,6 +20,12 @@ pub struct Config {
     pub consensus_params: ConsensusParameters,
     pub min_connected_reserved_peers: usize,
     pub time_until_synced: Duration,
+    /// The pause of the block production restored after the restart of the node.
+    pub paused: Option<Paused>,
+    /// Produces the blocks only while the node holds the leader lease if set.
+    pub leader_lease: Option<LeaderLeaseConfig>,
+    /// The timestamp of the genesis block. The produced blocks can't have a lower timestamp.
+    pub genesis_time: Tai64,
 }
 
 impl Default for Config {
@@ -27,6 +38,9 @@ impl Default for Config {
             consensus_params: ConsensusParameters::default(),
             min_connected_reserved_peers: 0,
             time_until_synced: Duration::ZERO,
+            paused: None,
+            leader_lease: None,
+            genesis_time: Tai64::UNIX_EPOCH,
         }
     }
 }
@@ -0,0 +1,414 @@
+//! The leader lease allows running the standby block producer next to the primary one.
+//!
+//! Both nodes share the consensus key, but only the holder of the lease produces blocks.
+//! The holder renews the lease while it is alive. If the holder stops renewing it,
+//! the lease expires and the standby node acquires it and continues the block production.
+
+use crate::ports::LeaderLease;
+use anyhow::Context;
+use std::{
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::Mutex,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+/// The configuration of the lease stored in the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeaderLeaseConfig {
+    /// The path on the storage shared by the nodes. The generations of the lease
+    /// are stored next to it in the `<path>.<generation>` files.
+    pub path: PathBuf,
+    /// The unique identifier of the node.
+    pub holder: String,
+    /// The time after which the lease expires if it is not renewed.
+    /// It should be longer than the block time.
+    pub ttl: Duration,
+}
+
+impl LeaderLeaseConfig {
+    /// The interval between the attempts to acquire or renew the lease.
+    pub fn renewal_interval(&self) -> Duration {
+        self.ttl
+            .checked_div(3)
+            .unwrap_or_default()
+            .max(Duration::from_millis(1))
+    }
+}
+
+/// The lease stored in the files on the storage shared by the nodes.
+///
+/// Each acquisition or renewal of the lease creates the next generation of the lease
+/// in the `<path>.<generation>` file. The file is created atomically only if it doesn't
+/// exist, so when several nodes compete for the expired lease, only one of them creates
+/// the next generation and holds the lease. The node signs the blocks only while its
+/// generation is the latest one and is not expired.
+///
+/// The clocks of the nodes are expected to be synchronized
+/// with the precision much better than the ttl.
+pub struct FileLease {
+    config: LeaderLeaseConfig,
+    held: Mutex<Option<HeldLease>>,
+}
+
+impl FileLease {
+    pub fn new(config: LeaderLeaseConfig) -> Self {
+        Self {
+            config,
+            held: Mutex::new(None),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct HeldLease {
+    generation: u64,
+    /// Milliseconds since the Unix epoch.
+    expires_at: u64,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct LeaseRecord {
+    holder: String,
+    /// Milliseconds since the Unix epoch.
+    expires_at: u64,
+}
+
+impl LeaseRecord {
+    fn read(path: &Path) -> anyhow::Result<Option<Self>> {
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("Failed to read {}", path.display()))
+            }
+        };
+        let mut lines = content.lines();
+        let (Some(holder), Some(expires_at)) = (lines.next(), lines.next()) else {
+            return Ok(None)
+        };
+        Ok(Some(Self {
+            holder: holder.to_string(),
+            expires_at: expires_at.parse().unwrap_or_default(),
+        }))
+    }
+
+    /// Creates the file of the record only if it doesn't exist yet.
+    /// Returns `false` if another node has created it first.
+    fn create(&self, path: &Path) -> anyhow::Result<bool> {
+        let tmp = path.with_extension(format!("{}.tmp", self.holder));
+        std::fs::write(&tmp, format!("{}\n{}\n", self.holder, self.expires_at))
+            .with_context(|| format!("Failed to write {}", tmp.display()))?;
+        // The hard link is created atomically with the full content of the record
+        // and fails if the file already exists.
+        let created = match std::fs::hard_link(&tmp, path) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+            Err(err) => {
+                Err(err).with_context(|| format!("Failed to create {}", path.display()))
+            }
+        };
+        let _ = std::fs::remove_file(&tmp);
+        created
+    }
+}
+
+fn now_millis() -> u64 {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX)
+}
+
+fn generation_path(config: &LeaderLeaseConfig, generation: u64) -> PathBuf {
+    let mut name = config.path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{generation}"));
+    config.path.with_file_name(name)
+}
+
+/// Returns the generations of the lease stored on the disk.
+fn generations(config: &LeaderLeaseConfig) -> anyhow::Result<Vec<u64>> {
+    let dir = match config.path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let prefix = format!(
+        "{}.",
+        config
+            .path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+    );
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(err) => {
+            return Err(err).with_context(|| format!("Failed to read {}", dir.display()))
+        }
+    };
+    let mut generations = vec![];
+    for entry in entries {
+        let name = entry?.file_name();
+        let generation = name
+            .to_str()
+            .and_then(|name| name.strip_prefix(&prefix))
+            .and_then(|generation| generation.parse::<u64>().ok());
+        generations.extend(generation);
+    }
+    Ok(generations)
+}
+
+/// Returns the latest generation of the lease with its record.
+fn latest(config: &LeaderLeaseConfig) -> anyhow::Result<Option<(u64, LeaseRecord)>> {
+    let mut generations = generations(config)?;
+    generations.sort_unstable();
+    for generation in generations.into_iter().rev() {
+        // The older generations may be removed concurrently.
+        if let Some(record) = LeaseRecord::read(&generation_path(config, generation))? {
+            return Ok(Some((generation, record)))
+        }
+    }
+    Ok(None)
+}
+
+/// Creates the next generation of the lease after the `latest`.
+/// Returns `false` if another node has created it first.
+fn create_next(
+    config: &LeaderLeaseConfig,
+    latest: Option<u64>,
+    expires_at: u64,
+) -> anyhow::Result<Option<HeldLease>> {
+    let generation = match latest {
+        Some(latest) => latest
+            .checked_add(1)
+            .ok_or_else(|| anyhow::anyhow!("The lease generation overflowed"))?,
+        None => 0,
+    };
+    let record = LeaseRecord {
+        holder: config.holder.clone(),
+        expires_at,
+    };
+    if !record.create(&generation_path(config, generation))? {
+        return Ok(None)
+    }
+
+    // Only the two latest generations are kept.
+    for old in generations(config)? {
+        if old.saturating_add(1) < generation {
+            let _ = std::fs::remove_file(generation_path(config, old));
+        }
+    }
+    Ok(Some(HeldLease {
+        generation,
+        expires_at,
+    }))
+}
+
+fn try_acquire(config: &LeaderLeaseConfig) -> anyhow::Result<Option<HeldLease>> {
+    let now = now_millis();
+    let latest = latest(config)?;
+    if let Some((_, current)) = &latest {
+        if current.holder != config.holder && current.expires_at > now {
+            return Ok(None)
+        }
+    }
+
+    let ttl = u64::try_from(config.ttl.as_millis()).unwrap_or(u64::MAX);
+    create_next(
+        config,
+        latest.map(|(generation, _)| generation),
+        now.saturating_add(ttl),
+    )
+}
+
+/// Returns `true` if the `held` generation is still the latest one and is not expired.
+fn is_held(config: &LeaderLeaseConfig, held: &HeldLease) -> anyhow::Result<bool> {
+    if held.expires_at <= now_millis() {
+        return Ok(false)
+    }
+    let latest = generations(config)?.into_iter().max();
+    Ok(latest == Some(held.generation))
+}
+
+fn release(config: &LeaderLeaseConfig, held: &HeldLease) -> anyhow::Result<()> {
+    // The expired generation lets other nodes acquire the lease immediately.
+    // If the lease has already been taken over, there is nothing to release.
+    create_next(config, Some(held.generation), 0)?;
+    Ok(())
+}
+
+impl FileLease {
+    fn held(&self) -> Option<HeldLease> {
+        *self.held.lock().expect("The lock is not poisoned; qed")
+    }
+
+    fn set_held(&self, held: Option<HeldLease>) {
+        *self.held.lock().expect("The lock is not poisoned; qed") = held;
+    }
+}
+
+#[async_trait::async_trait]
+impl LeaderLease for FileLease {
+    async fn acquire(&self) -> anyhow::Result<bool> {
+        let config = self.config.clone();
+        // The held lease is forgotten on failure, so the blocks are not signed
+        // until the lease is acquired again.
+        self.set_held(None);
+        let held = tokio::task::spawn_blocking(move || try_acquire(&config)).await??;
+        self.set_held(held);
+        Ok(held.is_some())
+    }
+
+    async fn is_held(&self) -> anyhow::Result<bool> {
+        let Some(held) = self.held() else {
+            return Ok(false)
+        };
+        let config = self.config.clone();
+        tokio::task::spawn_blocking(move || is_held(&config, &held)).await?
+    }
+
+    async fn release(&self) -> anyhow::Result<()> {
+        let Some(held) = self.held() else {
+            return Ok(())
+        };
+        self.set_held(None);
+        let config = self.config.clone();
+        tokio::task::spawn_blocking(move || release(&config, &held)).await?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lease(dir: &Path, holder: &str, ttl: Duration) -> FileLease {
+        FileLease::new(LeaderLeaseConfig {
+            path: dir.join("lease"),
+            holder: holder.to_string(),
+            ttl,
+        })
+    }
+
+    #[tokio::test]
+    async fn only_one_node_holds_the_lease() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let primary = lease(dir.path(), "primary", Duration::from_secs(60));
+        let standby = lease(dir.path(), "standby", Duration::from_secs(60));
+
+        // When
+        let primary_acquired = primary.acquire().await.unwrap();
+        let standby_acquired = standby.acquire().await.unwrap();
+        let primary_renewed = primary.acquire().await.unwrap();
+
+        // Then
+        assert!(primary_acquired);
+        assert!(!standby_acquired);
+        assert!(primary_renewed);
+    }
+
+    #[tokio::test]
+    async fn standby_acquires_the_expired_lease() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let primary = lease(dir.path(), "primary", Duration::ZERO);
+        let standby = lease(dir.path(), "standby", Duration::from_secs(60));
+        assert!(primary.acquire().await.unwrap());
+
+        // When
+        let standby_acquired = standby.acquire().await.unwrap();
+
+        // Then
+        assert!(standby_acquired);
+        assert!(!primary.acquire().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn standby_acquires_the_released_lease() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let primary = lease(dir.path(), "primary", Duration::from_secs(60));
+        let standby = lease(dir.path(), "standby", Duration::from_secs(60));
+        assert!(primary.acquire().await.unwrap());
+
+        // When
+        standby.release().await.unwrap();
+        let before_release = standby.acquire().await.unwrap();
+        primary.release().await.unwrap();
+        let after_release = standby.acquire().await.unwrap();
+
+        // Then
+        assert!(!before_release);
+        assert!(after_release);
+    }
+
+    fn compete(dir: &Path, nodes: usize) -> usize {
+        let barrier = std::sync::Arc::new(std::sync::Barrier::new(nodes));
+        let handles = (0..nodes)
+            .map(|node| {
+                let barrier = barrier.clone();
+                let config = LeaderLeaseConfig {
+                    path: dir.join("lease"),
+                    holder: format!("node-{node}"),
+                    ttl: Duration::from_secs(60),
+                };
+                std::thread::spawn(move || {
+                    barrier.wait();
+                    try_acquire(&config).unwrap().is_some()
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .filter(|acquired| *acquired)
+            .count()
+    }
+
+    #[test]
+    fn only_one_of_the_concurrent_nodes_acquires_the_lease() {
+        for _ in 0..20 {
+            // Given
+            let dir = tempfile::tempdir().unwrap();
+            let expired = LeaderLeaseConfig {
+                path: dir.path().join("lease"),
+                holder: "crashed".to_string(),
+                ttl: Duration::ZERO,
+            };
+            try_acquire(&expired).unwrap().unwrap();
+
+            // When
+            let acquired = compete(dir.path(), 8);
+
+            // Then
+            assert_eq!(acquired, 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn lease_is_not_held_after_the_take_over() {
+        // Given
+        let dir = tempfile::tempdir().unwrap();
+        let primary = lease(dir.path(), "primary", Duration::from_millis(50));
+        let standby = lease(dir.path(), "standby", Duration::from_secs(60));
+        assert!(primary.acquire().await.unwrap());
+        assert!(primary.is_held().await.unwrap());
+
+        // When
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let standby_acquired = standby.acquire().await.unwrap();
+
+        // Then
+        assert!(standby_acquired);
+        assert!(!primary.is_held().await.unwrap());
+        assert!(standby.is_held().await.unwrap());
+    }
+}
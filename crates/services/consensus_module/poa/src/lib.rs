@@ -11,6 +11,7 @@ mod sync;
 mod service_test;
 
 pub mod config;
+pub mod leader_lease;
 pub mod ports;
 pub mod service;
 pub mod verifier;
@@ -19,6 +20,7 @@ pub use config::{
     Config,
     Trigger,
 };
+pub use leader_lease::LeaderLeaseConfig;
 pub use service::{
     new_service,
     Service,
@@ -7,6 +7,7 @@ use fuel_core_types::{
     blockchain::{
         header::BlockHeader,
         primitives::DaBlockHeight,
+        SealedBlockHeader,
     },
     fuel_asm::Word,
     fuel_tx::{
@@ -89,6 +90,12 @@ pub trait Database {
 
     /// Gets the block header BMT MMR root at `height`.
     fn block_header_merkle_root(&self, height: &BlockHeight) -> StorageResult<Bytes32>;
+
+    /// Gets the block header at `height` with its consensus data, if the block exists.
+    fn sealed_block_header(
+        &self,
+        height: &BlockHeight,
+    ) -> StorageResult<Option<SealedBlockHeader>>;
 }
 
 #[cfg_attr(test, mockall::automock)]
@@ -111,6 +118,25 @@ pub trait P2pPort: Send + Sync + 'static {
     fn reserved_peers_count(&self) -> BoxStream<usize>;
 }
 
+#[cfg_attr(test, mockall::automock)]
+#[async_trait::async_trait]
+/// The lease granting the right to produce the blocks to one of the nodes
+/// sharing the consensus key.
+pub trait LeaderLease: Send + Sync {
+    /// Acquires the lease or renews the held lease.
+    /// Returns `false` if another node holds the lease.
+    async fn acquire(&self) -> anyhow::Result<bool>;
+
+    /// Returns `true` if the node still holds the lease acquired by the last
+    /// [`Self::acquire`]. It is checked before signing each block, so the node
+    /// never signs a block after another node has taken over the lease.
+    async fn is_held(&self) -> anyhow::Result<bool>;
+
+    /// Releases the held lease, so another node can acquire it without waiting
+    /// for the expiration.
+    async fn release(&self) -> anyhow::Result<()>;
+}
+
 #[async_trait::async_trait]
 #[cfg_attr(test, mockall::automock)]
 pub trait SyncPort: Send + Sync {
@@ -3,9 +3,14 @@ use crate::{
         DeadlineClock,
         OnConflict,
     },
+    leader_lease::{
+        FileLease,
+        LeaderLeaseConfig,
+    },
     ports::{
         BlockImporter,
         BlockProducer,
+        LeaderLease,
         P2pPort,
         TransactionPool,
         TransactionsSource,
@@ -53,7 +58,10 @@ use fuel_core_types::{
         Secret,
     },
     services::{
-        block_importer::ImportResult,
+        block_importer::{
+            BlockImportInfo,
+            ImportResult,
+        },
         executor::{
             ExecutionResult,
             UncommittedResult as UncommittedExecutionResult,
@@ -62,6 +70,10 @@ use fuel_core_types::{
     },
     tai64::Tai64,
 };
+use serde::{
+    Deserialize,
+    Serialize,
+};
 use std::{
     ops::Deref,
     time::Duration,
@@ -70,8 +82,13 @@ use tokio::{
     sync::{
         mpsc,
         oneshot,
+        watch,
+    },
+    time::{
+        Instant,
+        Interval,
+        MissedTickBehavior,
     },
-    time::Instant,
 };
 use tokio_stream::StreamExt;
 
@@ -79,6 +96,7 @@ pub type Service<T, B, I> = ServiceRunner<MainTask<T, B, I>>;
 #[derive(Clone)]
 pub struct SharedState {
     request_sender: mpsc::Sender<Request>,
+    paused: watch::Receiver<Option<Paused>>,
 }
 
 impl SharedState {
@@ -97,13 +115,77 @@ impl SharedState {
             .await?;
         receiver.await?
     }
+
+    /// Pauses the block production until [`SharedState::resume`] is called.
+    /// Only the manually requested heartbeat blocks are produced while paused.
+    pub async fn pause(&self, reason: PauseReason) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(Request::Pause((reason, sender)))
+            .await?;
+        receiver.await?
+    }
+
+    /// Resumes the paused block production.
+    pub async fn resume(&self) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender.send(Request::Resume(sender)).await?;
+        receiver.await?
+    }
+
+    /// Overrides the timestamp of the next produced block.
+    /// The timestamp can't be lower than the timestamp of the latest block.
+    pub async fn set_next_block_time(&self, time: Tai64) -> anyhow::Result<()> {
+        let (sender, receiver) = oneshot::channel();
+
+        self.request_sender
+            .send(Request::SetNextBlockTime((time, sender)))
+            .await?;
+        receiver.await?
+    }
+
+    /// Returns the reason and the time of the pause if the block production is paused.
+    pub fn paused(&self) -> Option<Paused> {
+        *self.paused.borrow()
+    }
 }
 
 pub enum Mode {
     /// Produces `number_of_blocks` blocks using `TxPool` as a source of transactions.
-    Blocks { number_of_blocks: u32 },
+    /// The timestamps of the blocks increase by the `interval` if it is set.
+    /// Otherwise, the increase is defined by the trigger.
+    Blocks {
+        number_of_blocks: u32,
+        interval: Option<Duration>,
+    },
     /// Produces one block with the given transactions.
     BlockWithTransactions(Vec<Transaction>),
+    /// Produces one block without transactions.
+    /// It is allowed while the block production is paused.
+    HeartbeatBlock,
+}
+
+/// The reason code of the block production pause.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PauseReason {
+    /// The incident response is in progress.
+    Incident,
+    /// The planned maintenance of the node.
+    Maintenance,
+    /// The network upgrade is in progress.
+    Upgrade,
+    /// Any other reason.
+    Other,
+}
+
+/// The state of the paused block production.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Paused {
+    pub reason: PauseReason,
+    /// The time when the block production was paused.
+    pub since: Tai64,
 }
 
 struct ManualProduction {
@@ -116,6 +198,12 @@ enum Request {
     /// Manually produces the next blocks with `Tai64` block timestamp.
     /// The block timestamp should be higher than previous one.
     ManualBlocks((ManualProduction, oneshot::Sender<anyhow::Result<()>>)),
+    /// Pauses the block production with the reason.
+    Pause((PauseReason, oneshot::Sender<anyhow::Result<()>>)),
+    /// Resumes the paused block production.
+    Resume(oneshot::Sender<anyhow::Result<()>>),
+    /// Overrides the timestamp of the next block.
+    SetNextBlockTime((Tai64, oneshot::Sender<anyhow::Result<()>>)),
 }
 
 impl core::fmt::Debug for Request {
@@ -141,10 +229,24 @@ pub struct MainTask<T, B, I> {
     last_height: BlockHeight,
     last_timestamp: Tai64,
     last_block_created: Instant,
+    /// The timestamp of the genesis block, the lower bound of the block timestamps.
+    genesis_time: Tai64,
     trigger: Trigger,
     /// Deadline clock, used by the triggers
     timer: DeadlineClock,
     sync_task_handle: ServiceRunner<SyncTask>,
+    paused: watch::Sender<Option<Paused>>,
+    /// The timestamp of the next block, if it was overridden.
+    next_block_time: Option<Tai64>,
+    /// The lease of the block production shared with the standby producers.
+    leader_lease: Option<Box<dyn LeaderLease>>,
+    lease_renewal_interval: Duration,
+    /// The timer of the lease renewal, started with the task.
+    lease_renewal: Option<Interval>,
+    /// Whether the node holds the leader lease. Always `true` without the lease.
+    is_leader: bool,
+    /// The blocks imported from the leader while the node is the standby producer.
+    imported_blocks: BoxStream<BlockImportInfo>,
 }
 
 impl<T, B, I> MainTask<T, B, I>
@@ -174,9 +276,20 @@ where
             min_connected_reserved_peers,
             time_until_synced,
             trigger,
+            paused,
+            leader_lease,
+            genesis_time,
             ..
         } = config;
 
+        if let Some(paused) = paused {
+            tracing::warn!(
+                "The block production starts paused with the reason {:?}",
+                paused.reason
+            );
+        }
+        let (paused, paused_receiver) = watch::channel(paused);
+
         let sync_task = SyncTask::new(
             peer_connections_stream,
             min_connected_reserved_peers,
@@ -187,6 +300,19 @@ where
 
         let sync_task_handle = ServiceRunner::new(sync_task);
 
+        let lease_renewal_interval = leader_lease
+            .as_ref()
+            .map(LeaderLeaseConfig::renewal_interval)
+            .unwrap_or_default();
+        let imported_blocks = if leader_lease.is_some() {
+            block_importer.block_stream()
+        } else {
+            Box::pin(tokio_stream::pending())
+        };
+        let is_leader = leader_lease.is_none();
+        let leader_lease = leader_lease
+            .map(|config| Box::new(FileLease::new(config)) as Box<dyn LeaderLease>);
+
         Self {
             block_gas_limit,
             signing_key,
@@ -195,13 +321,24 @@ where
             block_importer,
             tx_status_update_stream,
             request_receiver,
-            shared_state: SharedState { request_sender },
+            shared_state: SharedState {
+                request_sender,
+                paused: paused_receiver,
+            },
             last_height,
             last_timestamp,
             last_block_created,
+            genesis_time,
             trigger,
             timer: DeadlineClock::new(),
             sync_task_handle,
+            paused,
+            next_block_time: None,
+            leader_lease,
+            lease_renewal_interval,
+            lease_renewal: None,
+            is_leader,
+            imported_blocks,
         }
     }
 
@@ -223,6 +360,9 @@ where
     }
 
     fn next_time(&self, request_type: RequestType) -> anyhow::Result<Tai64> {
+        if let Some(time) = self.next_block_time {
+            return Ok(time)
+        }
         match request_type {
             RequestType::Manual => match self.trigger {
                 Trigger::Never | Trigger::Instant => {
@@ -277,13 +417,30 @@ where
         &mut self,
         block_production: ManualProduction,
     ) -> anyhow::Result<()> {
+        if !self.is_leader {
+            return Err(anyhow!(
+                "The node is the standby producer without the leader lease"
+            ))
+        }
+        if let Some(paused) = *self.paused.borrow() {
+            if !matches!(block_production.mode, Mode::HeartbeatBlock) {
+                return Err(anyhow!(
+                    "The block production is paused with the reason {:?}",
+                    paused.reason
+                ))
+            }
+        }
+
         let mut block_time = if let Some(time) = block_production.start_time {
             time
         } else {
             self.next_time(RequestType::Manual)?
         };
         match block_production.mode {
-            Mode::Blocks { number_of_blocks } => {
+            Mode::Blocks {
+                number_of_blocks,
+                interval,
+            } => {
                 for _ in 0..number_of_blocks {
                     self.produce_block(
                         self.next_height(),
@@ -292,7 +449,10 @@ where
                         RequestType::Manual,
                     )
                     .await?;
-                    block_time = self.next_time(RequestType::Manual)?;
+                    block_time = match interval {
+                        Some(interval) => increase_time(self.last_timestamp, interval)?,
+                        None => self.next_time(RequestType::Manual)?,
+                    };
                 }
             }
             Mode::BlockWithTransactions(txs) => {
@@ -304,10 +464,123 @@ where
                 )
                 .await?;
             }
+            Mode::HeartbeatBlock => {
+                self.produce_block(
+                    self.next_height(),
+                    block_time,
+                    TransactionsSource::SpecificTransactions(vec![]),
+                    RequestType::Manual,
+                )
+                .await?;
+            }
         }
         Ok(())
     }
 
+    fn pause(&mut self, reason: PauseReason) -> anyhow::Result<()> {
+        if let Some(paused) = *self.paused.borrow() {
+            return Err(anyhow!(
+                "The block production is already paused with the reason {:?}",
+                paused.reason
+            ))
+        }
+        tracing::warn!(
+            "The block production is paused with the reason {:?}",
+            reason
+        );
+        self.paused.send_replace(Some(Paused {
+            reason,
+            since: Tai64::now(),
+        }));
+        Ok(())
+    }
+
+    async fn resume(&mut self) -> anyhow::Result<()> {
+        if self.paused.borrow().is_none() {
+            return Err(anyhow!("The block production is not paused"))
+        }
+        tracing::warn!("The block production is resumed");
+        self.paused.send_replace(None);
+
+        // Catch up with the blocks missed during the pause.
+        self.schedule_production().await
+    }
+
+    /// Schedules the production of the next block according to the trigger.
+    async fn schedule_production(&mut self) -> anyhow::Result<()> {
+        match self.trigger {
+            Trigger::Never => {}
+            Trigger::Instant => self.on_txpool_event().await?,
+            Trigger::Interval { block_time } => {
+                let deadline = self.last_block_created.checked_add(block_time).expect("It is impossible to overflow except in the case where we don't want to produce a block.");
+                self.timer.set_deadline(deadline, OnConflict::Min).await;
+            }
+        }
+        Ok(())
+    }
+
+    fn set_next_block_time(&mut self, time: Tai64) -> anyhow::Result<()> {
+        if self.last_timestamp > time {
+            return Err(anyhow!(
+                "The next block timestamp can't be lower than the latest block timestamp"
+            ))
+        }
+        if self.genesis_time > time {
+            return Err(anyhow!(
+                "The next block timestamp can't be lower than the genesis timestamp"
+            ))
+        }
+        self.next_block_time = Some(time);
+        Ok(())
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused.borrow().is_some()
+    }
+
+    /// Acquires or renews the leader lease. The node stops the block production
+    /// if the lease is lost or can't be renewed, and starts it once the lease is acquired.
+    pub(crate) async fn renew_lease(&mut self) -> anyhow::Result<()> {
+        let Some(lease) = &self.leader_lease else {
+            return Ok(())
+        };
+        let is_leader = match lease.acquire().await {
+            Ok(is_leader) => is_leader,
+            Err(err) => {
+                tracing::error!("Failed to renew the leader lease: {:?}", err);
+                false
+            }
+        };
+        if is_leader == self.is_leader {
+            return Ok(())
+        }
+
+        self.is_leader = is_leader;
+        if is_leader {
+            tracing::warn!(
+                "The leader lease is acquired, the block production starts at {}",
+                self.next_height()
+            );
+            self.schedule_production().await
+        } else {
+            tracing::warn!("The leader lease is lost, the block production stops");
+            Ok(())
+        }
+    }
+
+    /// Follows the blocks of the leader, so the production continues
+    /// from the latest block after the lease is acquired.
+    fn on_imported_block(&mut self, block_info: BlockImportInfo) {
+        if block_info.block_header.height() > &self.last_height {
+            let (last_height, last_timestamp, last_block_created) =
+                Self::extract_block_info(&block_info.block_header);
+            self.last_height = last_height;
+            self.last_timestamp = last_timestamp;
+            self.last_block_created = last_block_created;
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(height = %height), err)]
     async fn produce_block(
         &mut self,
         height: BlockHeight,
@@ -325,6 +598,12 @@ where
             return Err(anyhow!("The block timestamp should monotonically increase"))
         }
 
+        if self.genesis_time > block_time {
+            return Err(anyhow!(
+                "The block timestamp can't be lower than the genesis timestamp"
+            ))
+        }
+
         // Ask the block producer to create the block
         let (
             ExecutionResult {
@@ -350,6 +629,15 @@ where
         }
         self.txpool.remove_txs(tx_ids_to_remove);
 
+        if let Some(lease) = &self.leader_lease {
+            if !lease.is_held().await? {
+                self.is_leader = false;
+                return Err(anyhow!(
+                    "The leader lease was lost before signing the block {height}"
+                ))
+            }
+        }
+
         // Sign the block and seal it
         let seal = seal_block(&self.signing_key, &block)?;
         let block = SealedBlock {
@@ -368,6 +656,7 @@ where
         self.last_height = height;
         self.last_timestamp = block_time;
         self.last_block_created = last_block_created;
+        self.next_block_time = None;
 
         // Set timer for the next block
         match (self.trigger, request_type) {
@@ -392,6 +681,9 @@ where
     }
 
     pub(crate) async fn on_txpool_event(&mut self) -> anyhow::Result<()> {
+        if self.is_paused() || !self.is_leader {
+            return Ok(())
+        }
         match self.trigger {
             Trigger::Instant => {
                 let pending_number = self.txpool.pending_number();
@@ -406,6 +698,10 @@ where
     }
 
     async fn on_timer(&mut self, _at: Instant) -> anyhow::Result<()> {
+        // The timer is set again when the block production is resumed.
+        if self.is_paused() || !self.is_leader {
+            return Ok(())
+        }
         match self.trigger {
             Trigger::Instant | Trigger::Never => {
                 unreachable!("Timer is never set in this mode");
@@ -435,12 +731,19 @@ where
     }
 
     async fn into_task(
-        self,
+        mut self,
         _: &StateWatcher,
         _: Self::TaskParams,
     ) -> anyhow::Result<Self::Task> {
         self.sync_task_handle.start_and_await().await?;
 
+        if self.leader_lease.is_some() {
+            // The first tick completes immediately to acquire the lease on start.
+            let mut renewal = tokio::time::interval(self.lease_renewal_interval);
+            renewal.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            self.lease_renewal = Some(renewal);
+        }
+
         match self.trigger {
             Trigger::Never | Trigger::Instant => {}
             Trigger::Interval { block_time } => {
@@ -495,6 +798,18 @@ where
                             let result = self.produce_manual_blocks(block).await;
                             let _ = response.send(result);
                         }
+                        Request::Pause((reason, response)) => {
+                            let result = self.pause(reason);
+                            let _ = response.send(result);
+                        }
+                        Request::Resume(response) => {
+                            let result = self.resume().await;
+                            let _ = response.send(result);
+                        }
+                        Request::SetNextBlockTime((time, response)) => {
+                            let result = self.set_next_block_time(time);
+                            let _ = response.send(result);
+                        }
                     }
                     should_continue = true;
                 } else {
@@ -519,12 +834,30 @@ where
                 self.on_timer(at).await.context("While processing timer event")?;
                 should_continue = true;
             }
+            _ = tick(&mut self.lease_renewal) => {
+                self.renew_lease().await.context("While renewing the leader lease")?;
+                should_continue = true;
+            }
+            block_info = self.imported_blocks.next() => {
+                if let Some(block_info) = block_info {
+                    self.on_imported_block(block_info);
+                    should_continue = true;
+                } else {
+                    should_continue = false;
+                }
+            }
         }
         Ok(should_continue)
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
         tracing::info!("PoA MainTask shutting down");
+        if let (Some(lease), true) = (&self.leader_lease, self.is_leader) {
+            // Let the standby producer take over without waiting for the expiration.
+            if let Err(err) = lease.release().await {
+                tracing::error!("Failed to release the leader lease: {:?}", err);
+            }
+        }
         self.sync_task_handle.stop_and_await().await?;
         Ok(())
     }
@@ -554,6 +887,15 @@ where
     ))
 }
 
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => core::future::pending().await,
+    }
+}
+
 fn seal_block(
     signing_key: &Option<Secret<SecretKeyWrapper>>,
     block: &Block,
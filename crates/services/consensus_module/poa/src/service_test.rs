@@ -67,7 +67,9 @@ use tokio::{
     time,
 };
 
+mod leader_lease_tests;
 mod manually_produce_tests;
+mod pause_tests;
 mod trigger_tests;
 
 struct TestContextBuilder {
@@ -0,0 +1,102 @@
+use crate::{
+    leader_lease::FileLease,
+    ports::LeaderLease,
+    LeaderLeaseConfig,
+};
+
+use super::*;
+
+fn lease_config(dir: &std::path::Path, holder: &str) -> LeaderLeaseConfig {
+    LeaderLeaseConfig {
+        path: dir.join("lease"),
+        holder: holder.to_string(),
+        ttl: Duration::from_secs(60),
+    }
+}
+
+fn standby_task(
+    lease: LeaderLeaseConfig,
+    produced_blocks: Arc<StdMutex<u32>>,
+) -> MainTask<MockTransactionPool, MockBlockProducer, MockBlockImporter> {
+    let mut producer = MockBlockProducer::default();
+    producer
+        .expect_produce_and_execute_block()
+        .returning(move |_, _, _, _| {
+            *produced_blocks.lock().unwrap() += 1;
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block: Default::default(),
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                    events: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+
+    let mut importer = MockBlockImporter::default();
+    importer.expect_commit_result().returning(|_| Ok(()));
+    importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    let mut txpool = MockTransactionPool::no_tx_updates();
+    txpool.expect_pending_number().returning(|| 1);
+    txpool.expect_total_consumable_gas().returning(|| 0);
+    txpool.expect_remove_txs().returning(|_| vec![]);
+
+    let config = Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 1_000_000,
+        signing_key: Some(test_signing_key()),
+        leader_lease: Some(lease),
+        ..Default::default()
+    };
+
+    MainTask::new(
+        &BlockHeader::new_block(BlockHeight::from(1u32), Tai64::now()),
+        config,
+        txpool,
+        producer,
+        importer,
+        generate_p2p_port(),
+    )
+}
+
+#[tokio::test]
+async fn standby_producer_does_not_produce_blocks_while_lease_is_held() {
+    // Given
+    let dir = tempfile::tempdir().unwrap();
+    let primary = FileLease::new(lease_config(dir.path(), "primary"));
+    assert!(primary.acquire().await.unwrap());
+    let produced_blocks = Arc::new(StdMutex::new(0));
+    let mut task =
+        standby_task(lease_config(dir.path(), "standby"), produced_blocks.clone());
+
+    // When
+    task.renew_lease().await.unwrap();
+    task.on_txpool_event().await.unwrap();
+
+    // Then
+    assert_eq!(*produced_blocks.lock().unwrap(), 0);
+}
+
+#[tokio::test]
+async fn standby_producer_takes_over_after_lease_is_released() {
+    // Given
+    let dir = tempfile::tempdir().unwrap();
+    let primary = FileLease::new(lease_config(dir.path(), "primary"));
+    assert!(primary.acquire().await.unwrap());
+    let produced_blocks = Arc::new(StdMutex::new(0));
+    let mut task =
+        standby_task(lease_config(dir.path(), "standby"), produced_blocks.clone());
+    task.renew_lease().await.unwrap();
+
+    // When
+    primary.release().await.unwrap();
+    task.renew_lease().await.unwrap();
+
+    // Then
+    assert_eq!(*produced_blocks.lock().unwrap(), 1);
+    assert!(!primary.acquire().await.unwrap());
+}
@@ -7,39 +7,47 @@ use test_case::test_case;
 
 use super::*;
 
-#[test_case(Tai64::now(), 10, vec![Tai64::now(); 10], Trigger::Never, 0;
+#[test_case(Tai64::now(), 10, vec![Tai64::now(); 10], Trigger::Never, 0, None;
 "can manually produce blocks even when trigger is Never")]
-#[test_case(Tai64::now(), 10, vec![Tai64::now(); 10], Trigger::Instant, 0;
+#[test_case(Tai64::now(), 10, vec![Tai64::now(); 10], Trigger::Instant, 0, None;
 "can manually produce blocks when trigger is Instant")]
 #[test_case(
     Tai64::now(), 3, vec![Tai64::now(), Tai64::now() + 10, Tai64::now() + 20],
-    Trigger::Interval { block_time: Duration::from_secs(10) }, 0
+    Trigger::Interval { block_time: Duration::from_secs(10) }, 0, None
 ; "can manually produce blocks with different times")]
-#[test_case(Tai64::now() + 100, 10, vec![Tai64::now() + 100; 10], Trigger::Never, 0;
+#[test_case(Tai64::now() + 100, 10, vec![Tai64::now() + 100; 10], Trigger::Never, 0, None;
 "can manually produce blocks starting in the future even when trigger is Never")]
-#[test_case(Tai64::now() + 100, 10, vec![Tai64::now() + 100; 10], Trigger::Instant, 0;
+#[test_case(Tai64::now() + 100, 10, vec![Tai64::now() + 100; 10], Trigger::Instant, 0, None;
 "can manually produce blocks starting in the future when trigger is Instant")]
 #[test_case(
     Tai64::now() + 100, 3, vec![Tai64::now() + 100, Tai64::now() + 110, Tai64::now() + 120],
-    Trigger::Interval { block_time: Duration::from_secs(10) }, 0;
+    Trigger::Interval { block_time: Duration::from_secs(10) }, 0, None;
 "can manually produce blocks starting in the future with different times")]
-#[test_case(Tai64::now(), 10, vec![Tai64::now(); 10], Trigger::Never, 10;
+#[test_case(Tai64::now(), 10, vec![Tai64::now(); 10], Trigger::Never, 10, None;
 "can manually produce blocks with txs even when trigger is Never")]
-#[test_case(Tai64::now(), 10, vec![Tai64::now(); 10], Trigger::Instant, 10;
+#[test_case(Tai64::now(), 10, vec![Tai64::now(); 10], Trigger::Instant, 10, None;
 "can manually produce blocks with txs when trigger is Instant")]
 #[test_case(
     Tai64::now(), 3, vec![Tai64::now(), Tai64::now() + 10, Tai64::now() + 20],
-    Trigger::Interval { block_time: Duration::from_secs(10) }, 10
+    Trigger::Interval { block_time: Duration::from_secs(10) }, 10, None
 ;
 "can manually produce blocks with different times with txs")]
-#[test_case(Tai64::now() + 100, 10, vec![Tai64::now() + 100; 10], Trigger::Never, 10;
+#[test_case(Tai64::now() + 100, 10, vec![Tai64::now() + 100; 10], Trigger::Never, 10, None;
 "can manually produce blocks with txs starting in the future even when trigger is Never")]
-#[test_case(Tai64::now() + 100, 10, vec![Tai64::now() + 100; 10], Trigger::Instant, 10;
+#[test_case(Tai64::now() + 100, 10, vec![Tai64::now() + 100; 10], Trigger::Instant, 10, None;
 "can manually produce blocks with txs starting in the future when trigger is Instant")]
 #[test_case(
     Tai64::now() + 100, 3, vec![Tai64::now() + 100, Tai64::now() + 110, Tai64::now() + 120],
-    Trigger::Interval { block_time: Duration::from_secs(10) }, 10;
+    Trigger::Interval { block_time: Duration::from_secs(10) }, 10, None;
 "can manually produce blocks with txs starting in the future with different times")]
+#[test_case(
+    Tai64::now(), 3, vec![Tai64::now(), Tai64::now() + 5, Tai64::now() + 10],
+    Trigger::Never, 0, Some(Duration::from_secs(5));
+"can manually produce blocks with the interval even when trigger is Never")]
+#[test_case(
+    Tai64::now() + 100, 3, vec![Tai64::now() + 100, Tai64::now() + 105, Tai64::now() + 110],
+    Trigger::Interval { block_time: Duration::from_secs(10) }, 10, Some(Duration::from_secs(5));
+"can manually produce blocks with the interval overriding the block time")]
 #[tokio::test]
 async fn can_manually_produce_block(
     start_time: Tai64,
@@ -47,6 +55,7 @@ async fn can_manually_produce_block(
     times: Vec<Tai64>,
     trigger: Trigger,
     num_txns: usize,
+    interval: Option<Duration>,
 ) {
     let mut rng = StdRng::seed_from_u64(1234u64);
     let mut ctx_builder = TestContextBuilder::new();
@@ -103,7 +112,13 @@ async fn can_manually_produce_block(
 
     ctx.service
         .shared
-        .manually_produce_block(Some(start_time), Mode::Blocks { number_of_blocks })
+        .manually_produce_block(
+            Some(start_time),
+            Mode::Blocks {
+                number_of_blocks,
+                interval,
+            },
+        )
         .await
         .unwrap();
     for tx in txs {
@@ -118,3 +133,166 @@ async fn can_manually_produce_block(
     // Stop
     assert_eq!(ctx.stop().await, State::Stopped);
 }
+
+fn never_trigger_context() -> (TestContext, tokio::sync::mpsc::Receiver<Tai64>) {
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(Config {
+        trigger: Trigger::Never,
+        block_gas_limit: 100_000,
+        signing_key: Some(test_signing_key()),
+        metrics: false,
+        ..Default::default()
+    });
+    let TxPoolContext { txpool, .. } = MockTransactionPool::new_with_txs(vec![]);
+    ctx_builder.with_txpool(txpool);
+
+    let mut importer = MockBlockImporter::default();
+    let (tx, rx) = tokio::sync::mpsc::channel(10);
+    importer.expect_commit_result().returning(move |r| {
+        tx.try_send(r.into_result().sealed_block.entity.header().time())
+            .unwrap();
+        Ok(())
+    });
+    importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+
+    let mut producer = MockBlockProducer::default();
+    producer
+        .expect_produce_and_execute_block()
+        .returning(|_, time, _, _| {
+            let mut block = Block::default();
+            block.header_mut().set_time(time);
+            block.header_mut().recalculate_metadata();
+            Ok(UncommittedResult::new(
+                ExecutionResult {
+                    block,
+                    skipped_transactions: Default::default(),
+                    tx_status: Default::default(),
+                    events: Default::default(),
+                },
+                StorageTransaction::new(EmptyStorage),
+            ))
+        });
+    ctx_builder.with_importer(importer);
+    ctx_builder.with_producer(producer);
+
+    (ctx_builder.build(), rx)
+}
+
+#[tokio::test]
+async fn next_block_time_overrides_the_timestamp_of_only_next_block() {
+    let (ctx, mut rx) = never_trigger_context();
+    let next_block_time = Tai64::now() + 1000;
+
+    // Given
+    ctx.service
+        .shared
+        .set_next_block_time(next_block_time)
+        .await
+        .unwrap();
+
+    // When
+    ctx.service
+        .shared
+        .manually_produce_block(
+            None,
+            Mode::Blocks {
+                number_of_blocks: 2,
+                interval: Some(Duration::from_secs(10)),
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(rx.recv().await.unwrap(), next_block_time);
+    assert_eq!(rx.recv().await.unwrap(), next_block_time + 10);
+
+    // Stop
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test]
+async fn next_block_time_cant_be_lower_than_latest_block_time() {
+    let (ctx, mut rx) = never_trigger_context();
+    let start_time = Tai64::now() + 1000;
+    ctx.service
+        .shared
+        .manually_produce_block(
+            Some(start_time),
+            Mode::Blocks {
+                number_of_blocks: 1,
+                interval: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(rx.recv().await.unwrap(), start_time);
+
+    // When
+    let result = ctx.service.shared.set_next_block_time(start_time - 1).await;
+
+    // Then
+    assert!(result.is_err());
+    ctx.service
+        .shared
+        .set_next_block_time(start_time)
+        .await
+        .unwrap();
+
+    // Stop
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test]
+async fn blocks_cant_be_produced_before_the_genesis_time() {
+    // Given
+    let genesis_time = Tai64::now() + 1000;
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(Config {
+        trigger: Trigger::Never,
+        signing_key: Some(test_signing_key()),
+        genesis_time,
+        ..Default::default()
+    });
+    let TxPoolContext { txpool, .. } = MockTransactionPool::new_with_txs(vec![]);
+    ctx_builder.with_txpool(txpool);
+    let ctx = ctx_builder.build();
+
+    // When
+    let before_genesis = ctx
+        .service
+        .shared
+        .manually_produce_block(
+            Some(genesis_time - 1),
+            Mode::Blocks {
+                number_of_blocks: 1,
+                interval: None,
+            },
+        )
+        .await;
+    let next_block_time = ctx
+        .service
+        .shared
+        .set_next_block_time(genesis_time - 1)
+        .await;
+
+    // Then
+    assert!(before_genesis.is_err());
+    assert!(next_block_time.is_err());
+    ctx.service
+        .shared
+        .manually_produce_block(
+            Some(genesis_time),
+            Mode::Blocks {
+                number_of_blocks: 1,
+                interval: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Stop
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
@@ -0,0 +1,169 @@
+use crate::service::{
+    Mode,
+    PauseReason,
+    Paused,
+};
+
+use super::*;
+
+fn instant_context() -> (
+    TestContext,
+    Arc<watch::Sender<Option<TxId>>>,
+    broadcast::Receiver<()>,
+) {
+    instant_context_with_pause(None)
+}
+
+fn instant_context_with_pause(
+    paused: Option<Paused>,
+) -> (
+    TestContext,
+    Arc<watch::Sender<Option<TxId>>>,
+    broadcast::Receiver<()>,
+) {
+    let mut rng = StdRng::seed_from_u64(1234u64);
+    let mut ctx_builder = TestContextBuilder::new();
+    ctx_builder.with_config(Config {
+        trigger: Trigger::Instant,
+        block_gas_limit: 100_000,
+        signing_key: Some(test_signing_key()),
+        metrics: false,
+        paused,
+        ..Default::default()
+    });
+
+    let TxPoolContext {
+        txpool,
+        status_sender,
+        ..
+    } = MockTransactionPool::new_with_txs(vec![make_tx(&mut rng)]);
+    ctx_builder.with_txpool(txpool);
+
+    let (block_import_sender, block_import_receiver) = broadcast::channel(100);
+    let mut importer = MockBlockImporter::default();
+    importer.expect_commit_result().returning(move |_| {
+        block_import_sender.send(())?;
+        Ok(())
+    });
+    importer
+        .expect_block_stream()
+        .returning(|| Box::pin(tokio_stream::pending()));
+    ctx_builder.with_importer(importer);
+
+    (ctx_builder.build(), status_sender, block_import_receiver)
+}
+
+#[tokio::test(start_paused = true)]
+async fn paused_production_doesnt_produce_blocks_until_resumed() {
+    let (ctx, status_sender, mut block_import) = instant_context();
+
+    // Given
+    ctx.service
+        .shared
+        .pause(PauseReason::Incident)
+        .await
+        .unwrap();
+    let paused = ctx.service.shared.paused().expect("Should be paused");
+    assert_eq!(paused.reason, PauseReason::Incident);
+
+    // When
+    status_sender.send_replace(Some(TxId::zeroed()));
+    time::sleep(Duration::new(1, 0)).await;
+
+    // Then
+    assert!(matches!(
+        block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    let result = ctx
+        .service
+        .shared
+        .manually_produce_block(
+            None,
+            Mode::Blocks {
+                number_of_blocks: 1,
+                interval: None,
+            },
+        )
+        .await;
+    assert!(result.is_err());
+
+    // When
+    ctx.service.shared.resume().await.unwrap();
+
+    // Then
+    assert!(ctx.service.shared.paused().is_none());
+    assert!(block_import.recv().await.is_ok());
+
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test(start_paused = true)]
+async fn heartbeat_block_is_produced_while_paused() {
+    let (ctx, _status_sender, mut block_import) = instant_context();
+
+    // Given
+    ctx.service.shared.pause(PauseReason::Other).await.unwrap();
+
+    // When
+    ctx.service
+        .shared
+        .manually_produce_block(None, Mode::HeartbeatBlock)
+        .await
+        .unwrap();
+
+    // Then
+    assert!(block_import.try_recv().is_ok());
+    assert!(ctx.service.shared.paused().is_some());
+
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test(start_paused = true)]
+async fn pause_and_resume_fail_in_the_wrong_state() {
+    let (ctx, _status_sender, _block_import) = instant_context();
+
+    assert!(ctx.service.shared.resume().await.is_err());
+    ctx.service
+        .shared
+        .pause(PauseReason::Maintenance)
+        .await
+        .unwrap();
+    assert!(ctx
+        .service
+        .shared
+        .pause(PauseReason::Upgrade)
+        .await
+        .is_err());
+    assert_eq!(
+        ctx.service.shared.paused().map(|paused| paused.reason),
+        Some(PauseReason::Maintenance)
+    );
+
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
+
+#[tokio::test(start_paused = true)]
+async fn production_starts_paused_with_restored_pause() {
+    // Given
+    let paused = Paused {
+        reason: PauseReason::Upgrade,
+        since: Tai64(1),
+    };
+
+    // When
+    let (ctx, status_sender, mut block_import) = instant_context_with_pause(Some(paused));
+    status_sender.send_replace(Some(TxId::zeroed()));
+    time::sleep(Duration::new(1, 0)).await;
+
+    // Then
+    assert_eq!(ctx.service.shared.paused(), Some(paused));
+    assert!(matches!(
+        block_import.try_recv(),
+        Err(broadcast::error::TryRecvError::Empty)
+    ));
+    ctx.service.shared.resume().await.unwrap();
+    assert!(block_import.recv().await.is_ok());
+
+    assert_eq!(ctx.stop().await, State::Stopped);
+}
@@ -4,11 +4,20 @@ use fuel_core_chain_config::ConsensusConfig;
 use fuel_core_types::{
     blockchain::{
         block::Block,
-        consensus::poa::PoAConsensus,
+        consensus::{
+            poa::{
+                EquivocationProof,
+                PoAConsensus,
+            },
+            Consensus,
+        },
         header::BlockHeader,
+        SealedBlockHeader,
     },
     fuel_tx::Input,
+    tai64::Tai64,
 };
+use std::time::Duration;
 
 #[cfg(test)]
 mod tests;
@@ -31,6 +40,73 @@ pub fn verify_consensus(
     }
 }
 
+/// Returns the equivocation proof if the `known` and `received` headers are
+/// different blocks at the same height, both sealed by the authority.
+pub fn detect_equivocation(
+    consensus_config: &ConsensusConfig,
+    known: &SealedBlockHeader,
+    received: &SealedBlockHeader,
+) -> Option<EquivocationProof> {
+    if known.entity.height() != received.entity.height()
+        || known.entity.id() == received.entity.id()
+    {
+        return None
+    }
+
+    let is_sealed_by_authority = |header: &SealedBlockHeader| match &header.consensus {
+        Consensus::PoA(consensus) => {
+            verify_consensus(consensus_config, &header.entity, consensus)
+        }
+        _ => false,
+    };
+
+    if is_sealed_by_authority(known) && is_sealed_by_authority(received) {
+        Some(EquivocationProof {
+            first: known.clone(),
+            second: received.clone(),
+        })
+    } else {
+        None
+    }
+}
+
+/// The schedule of the PoA block timestamps enforced during the import.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlockTimeSchedule {
+    /// The expected interval between the blocks. Only the minimal interval is enforced,
+    /// because the producer skips the slots while it is offline.
+    pub block_time: Option<Duration>,
+    /// The allowed deviation of the block time from the schedule and from the local clock.
+    pub tolerance: Duration,
+}
+
+/// Verifies that the block `time` isn't ahead of the local clock and isn't earlier than
+/// the next slot after the previous block, both within the tolerance of the `schedule`.
+pub fn verify_block_time(
+    schedule: &BlockTimeSchedule,
+    prev_time: Tai64,
+    time: Tai64,
+    now: Tai64,
+) -> anyhow::Result<()> {
+    let tolerance = schedule.tolerance.as_secs();
+    ensure!(
+        time.0 <= now.0.saturating_add(tolerance),
+        "The block time is {}s ahead of the local clock, more than the tolerance of {tolerance}s",
+        time.0.saturating_sub(now.0)
+    );
+
+    if let Some(block_time) = schedule.block_time {
+        let block_time = block_time.as_secs();
+        let interval = time.0.saturating_sub(prev_time.0);
+        ensure!(
+            interval >= block_time.saturating_sub(tolerance),
+            "The block is produced {interval}s after the previous block, earlier than the block time of {block_time}s with the tolerance of {tolerance}s"
+        );
+    }
+
+    Ok(())
+}
+
 pub fn verify_block_fields<D: Database>(
     database: &D,
     block: &Block,
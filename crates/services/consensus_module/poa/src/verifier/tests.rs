@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 use super::*;
 use crate::ports::MockDatabase;
 use fuel_core_types::{
@@ -8,9 +10,17 @@ use fuel_core_types::{
         GeneratedConsensusFields,
         PartialBlockHeader,
     },
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
     fuel_tx::Transaction,
     tai64::Tai64,
 };
+use rand::{
+    rngs::StdRng,
+    SeedableRng,
+};
 use test_case::test_case;
 
 struct Input {
@@ -115,3 +125,104 @@ fn test_verify_genesis_block_fields(input: Input) -> anyhow::Result<()> {
     *b.transactions_mut() = txs;
     verify_block_fields(&d, &b)
 }
+
+fn sealed_header(secret_key: &SecretKey, height: u32, time: Tai64) -> SealedBlockHeader {
+    let mut header = BlockHeader::default();
+    header.set_block_height(height.into());
+    header.set_time(time);
+    header.recalculate_metadata();
+    let message = header.id().into_message();
+    let signature = Signature::sign(secret_key, &message);
+    SealedBlockHeader {
+        entity: header,
+        consensus: Consensus::PoA(PoAConsensus::new(signature)),
+    }
+}
+
+fn authority() -> (SecretKey, ConsensusConfig) {
+    let secret_key = SecretKey::random(&mut StdRng::seed_from_u64(1234));
+    let signing_key = fuel_core_types::fuel_tx::Input::owner(&secret_key.public_key());
+    (secret_key, ConsensusConfig::PoA { signing_key })
+}
+
+#[test]
+fn detect_equivocation__finds_two_blocks_sealed_at_the_same_height() {
+    // Given
+    let (secret_key, config) = authority();
+    let known = sealed_header(&secret_key, 5, Tai64(1));
+    let received = sealed_header(&secret_key, 5, Tai64(2));
+
+    // When
+    let proof = detect_equivocation(&config, &known, &received);
+
+    // Then
+    let proof = proof.expect("Should detect the equivocation");
+    assert_eq!(proof.height(), 5u32.into());
+    assert_eq!(proof.first, known);
+    assert_eq!(proof.second, received);
+}
+
+#[test]
+fn detect_equivocation__ignores_the_same_block() {
+    // Given
+    let (secret_key, config) = authority();
+    let known = sealed_header(&secret_key, 5, Tai64(1));
+
+    // When
+    let proof = detect_equivocation(&config, &known, &known.clone());
+
+    // Then
+    assert!(proof.is_none());
+}
+
+#[test]
+fn detect_equivocation__ignores_the_different_heights() {
+    // Given
+    let (secret_key, config) = authority();
+    let known = sealed_header(&secret_key, 5, Tai64(1));
+    let received = sealed_header(&secret_key, 6, Tai64(1));
+
+    // When
+    let proof = detect_equivocation(&config, &known, &received);
+
+    // Then
+    assert!(proof.is_none());
+}
+
+#[test]
+fn detect_equivocation__ignores_the_block_not_sealed_by_the_authority() {
+    // Given
+    let (secret_key, config) = authority();
+    let other_key = SecretKey::random(&mut StdRng::seed_from_u64(4321));
+    let known = sealed_header(&secret_key, 5, Tai64(1));
+    let received = sealed_header(&other_key, 5, Tai64(2));
+
+    // When
+    let proof = detect_equivocation(&config, &known, &received);
+
+    // Then
+    assert!(proof.is_none());
+}
+
+fn schedule(block_time: Option<u64>, tolerance: u64) -> BlockTimeSchedule {
+    BlockTimeSchedule {
+        block_time: block_time.map(Duration::from_secs),
+        tolerance: Duration::from_secs(tolerance),
+    }
+}
+
+#[test_case(schedule(None, 2), 10, 12, 10 => matches Ok(_) ; "ahead of the clock within the tolerance")]
+#[test_case(schedule(None, 2), 10, 13, 10 => matches Err(_) ; "ahead of the clock beyond the tolerance")]
+#[test_case(schedule(None, 2), 10, 10, 100 => matches Ok(_) ; "behind the clock")]
+#[test_case(schedule(Some(10), 2), 10, 20, 20 => matches Ok(_) ; "on the slot")]
+#[test_case(schedule(Some(10), 2), 10, 18, 20 => matches Ok(_) ; "before the slot within the tolerance")]
+#[test_case(schedule(Some(10), 2), 10, 17, 20 => matches Err(_) ; "before the slot beyond the tolerance")]
+#[test_case(schedule(Some(10), 2), 10, 45, 50 => matches Ok(_) ; "after the skipped slots")]
+fn test_verify_block_time(
+    schedule: BlockTimeSchedule,
+    prev_time: u64,
+    time: u64,
+    now: u64,
+) -> anyhow::Result<()> {
+    verify_block_time(&schedule, Tai64(prev_time), Tai64(time), Tai64(now))
+}
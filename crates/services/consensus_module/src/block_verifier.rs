@@ -8,8 +8,12 @@ use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
     blockchain::{
         block::Block,
-        consensus::Consensus,
+        consensus::{
+            poa::EquivocationProof,
+            Consensus,
+        },
         header::BlockHeader,
+        primitives::DaBlockHeight,
         SealedBlockHeader,
     },
     fuel_types::{
@@ -55,23 +59,56 @@ where
     ) -> anyhow::Result<()> {
         match consensus {
             Consensus::Genesis(_) => {
-                let expected_genesis_height = self
-                    .config
-                    .chain_config
-                    .initial_state
-                    .as_ref()
+                let initial_state = self.config.chain_config.initial_state.as_ref();
+                let expected_genesis_height = initial_state
                     .map(|config| config.height.unwrap_or_else(|| 0u32.into()))
                     .unwrap_or_else(|| 0u32.into());
-                verify_genesis_block_fields(expected_genesis_height, block.header())
+                let expected_genesis_da_height = initial_state
+                    .and_then(|config| config.da_block_height)
+                    .unwrap_or_default();
+                verify_genesis_block_fields(
+                    expected_genesis_height,
+                    expected_genesis_da_height,
+                    self.genesis_time(),
+                    block.header(),
+                )
             }
             Consensus::PoA(_) => {
                 let view = self.view_provider.latest_view();
-                fuel_core_poa::verifier::verify_block_fields(&view, block)
+                fuel_core_poa::verifier::verify_block_fields(&view, block)?;
+                verify_block_time_after_genesis(self.genesis_time(), block.header())?;
+
+                if let Some(schedule) = &self.config.block_time_schedule {
+                    let header = block.header();
+                    let prev_height = header
+                        .height()
+                        .pred()
+                        .expect("The PoA block height was checked above");
+                    let prev_header = view.block_header(&prev_height)?;
+                    fuel_core_poa::verifier::verify_block_time(
+                        schedule,
+                        prev_header.time(),
+                        header.time(),
+                        Tai64::now(),
+                    )?;
+                }
+
+                Ok(())
             }
             _ => Err(anyhow::anyhow!("Unsupported consensus: {:?}", consensus)),
         }
     }
 
+    /// The timestamp of the genesis block from the chain config.
+    fn genesis_time(&self) -> Tai64 {
+        self.config
+            .chain_config
+            .initial_state
+            .as_ref()
+            .and_then(|config| config.timestamp)
+            .unwrap_or(Tai64::UNIX_EPOCH)
+    }
+
     /// Verifies the consensus of the block header.
     pub fn verify_consensus(&self, header: &SealedBlockHeader) -> bool {
         let SealedBlockHeader {
@@ -88,10 +125,42 @@ where
             _ => false,
         }
     }
+
+    /// Compares the header with the local block at the same height.
+    ///
+    /// Returns the equivocation proof if the authority sealed both of them.
+    pub fn detect_equivocation(
+        &self,
+        header: &SealedBlockHeader,
+    ) -> anyhow::Result<Option<EquivocationProof>> {
+        let view = self.view_provider.latest_view();
+        let Some(known) = view.sealed_block_header(header.entity.height())? else {
+            return Ok(None)
+        };
+
+        Ok(fuel_core_poa::verifier::detect_equivocation(
+            &self.config.chain_config.consensus,
+            &known,
+            header,
+        ))
+    }
+}
+
+fn verify_block_time_after_genesis(
+    genesis_time: Tai64,
+    header: &BlockHeader,
+) -> anyhow::Result<()> {
+    ensure!(
+        header.time() >= genesis_time,
+        "The block time can't be lower than the genesis time"
+    );
+    Ok(())
 }
 
 fn verify_genesis_block_fields(
     expected_genesis_height: BlockHeight,
+    expected_genesis_da_height: DaBlockHeight,
+    expected_genesis_time: Tai64,
     header: &BlockHeader,
 ) -> anyhow::Result<()> {
     let actual_genesis_height = *header.height();
@@ -101,12 +170,11 @@ fn verify_genesis_block_fields(
         "The genesis previous root should be zeroed"
     );
     ensure!(
-        header.time() == Tai64::UNIX_EPOCH,
-        "The genesis time should be unix epoch time"
+        header.time() == expected_genesis_time,
+        "The genesis time is not as expected"
     );
     ensure!(
-        // TODO: Set `da_height` based on the chain config.
-        header.da_height == Default::default(),
+        header.da_height == expected_genesis_da_height,
         "The genesis `da_height` is not as expected"
     );
     ensure!(
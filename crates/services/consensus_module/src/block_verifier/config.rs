@@ -1,16 +1,23 @@
 //! The config of the block verifier.
 
 use fuel_core_chain_config::ChainConfig;
+use fuel_core_poa::verifier::BlockTimeSchedule;
 
 /// The config of the block verifier.
 pub struct Config {
     /// The chain configuration.
     pub chain_config: ChainConfig,
+    /// The schedule of the PoA block timestamps. The timestamps are only checked
+    /// to be non-decreasing if it is not set.
+    pub block_time_schedule: Option<BlockTimeSchedule>,
 }
 
 impl Config {
     /// Creates the verifier config for all possible consensuses.
     pub fn new(chain_config: ChainConfig) -> Self {
-        Self { chain_config }
+        Self {
+            chain_config,
+            block_time_schedule: None,
+        }
     }
 }
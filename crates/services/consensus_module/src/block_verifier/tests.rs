@@ -51,6 +51,17 @@ use test_case::test_case;
     },
     0 => matches Err(_) ; "wrong time"
 )]
+#[test_case(
+    {
+        let mut h = BlockHeader::default();
+        h.set_previous_root(Bytes32::zeroed());
+        h.set_time(Tai64::UNIX_EPOCH);
+        h.set_block_height(0u32.into());
+        h.set_da_height(10u64.into());
+        h
+    },
+    0 => matches Err(_) ; "wrong da height"
+)]
 #[test_case(
     {
         let mut h = BlockHeader::default();
@@ -65,5 +76,26 @@ fn test_verify_genesis_block_fields(
     header: BlockHeader,
     expected_genesis_height: u32,
 ) -> anyhow::Result<()> {
-    verify_genesis_block_fields(expected_genesis_height.into(), &header)
+    verify_genesis_block_fields(
+        expected_genesis_height.into(),
+        0u64.into(),
+        Tai64::UNIX_EPOCH,
+        &header,
+    )
+}
+
+#[test_case(Tai64(100), 99 => matches Err(_) ; "before the genesis time")]
+#[test_case(Tai64(100), 100 => matches Ok(_) ; "at the genesis time")]
+#[test_case(Tai64(100), 101 => matches Ok(_) ; "after the genesis time")]
+fn test_verify_block_time_after_genesis(
+    genesis_time: Tai64,
+    block_time: u64,
+) -> anyhow::Result<()> {
+    // Given
+    let mut header = BlockHeader::default();
+    header.set_block_height(1u32.into());
+    header.set_time(Tai64(block_time));
+
+    // When
+    verify_block_time_after_genesis(genesis_time, &header)
 }
@@ -0,0 +1,492 @@
+//! The storage wrapper that records the state accessed by the VM during the dry run.
+
+use alloc::{
+    borrow::Cow,
+    collections::BTreeSet,
+    sync::Arc,
+    vec::Vec,
+};
+use fuel_core_storage::{
+    tables::{
+        ContractsAssets,
+        ContractsRawCode,
+        ContractsState,
+        FuelBlocks,
+    },
+    ContractsAssetKey,
+    ContractsStateKey,
+    Error as StorageError,
+    Mappable,
+    MerkleRoot,
+    MerkleRootStorage,
+    Result as StorageResult,
+    StorageBatchMutate,
+    StorageInspect,
+    StorageMutate,
+    StorageRead,
+    StorageSize,
+    StorageWrite,
+};
+use fuel_core_types::{
+    fuel_tx::{
+        Input,
+        Output,
+        TxId,
+        UtxoId,
+    },
+    fuel_types::{
+        ContractId,
+        Nonce,
+    },
+    services::executor::{
+        AccessList,
+        ContractAsset,
+        ContractSlot,
+    },
+};
+use parking_lot::Mutex;
+
+/// The state accessed by one transaction.
+#[derive(Default, Debug)]
+struct Accesses {
+    contracts: BTreeSet<ContractId>,
+    read_slots: BTreeSet<ContractSlot>,
+    written_slots: BTreeSet<ContractSlot>,
+    contract_balances: BTreeSet<ContractAsset>,
+}
+
+/// The shared handle to the accesses recorded by the [`RecordingStorage`].
+#[derive(Default, Clone, Debug)]
+pub struct AccessRecorder(Arc<Mutex<Accesses>>);
+
+impl AccessRecorder {
+    fn contract(&self, contract_id: &ContractId) {
+        self.0.lock().contracts.insert(*contract_id);
+    }
+
+    fn balance(&self, key: &ContractsAssetKey) {
+        let mut accesses = self.0.lock();
+        accesses.contracts.insert(*key.contract_id());
+        accesses.contract_balances.insert(ContractAsset {
+            contract_id: *key.contract_id(),
+            asset_id: *key.asset_id(),
+        });
+    }
+
+    fn read_slot(&self, key: &ContractsStateKey) {
+        let mut accesses = self.0.lock();
+        accesses.contracts.insert(*key.contract_id());
+        accesses.read_slots.insert(ContractSlot {
+            contract_id: *key.contract_id(),
+            key: *key.state_key(),
+        });
+    }
+
+    fn written_slot(&self, key: &ContractsStateKey) {
+        let mut accesses = self.0.lock();
+        accesses.contracts.insert(*key.contract_id());
+        accesses.written_slots.insert(ContractSlot {
+            contract_id: *key.contract_id(),
+            key: *key.state_key(),
+        });
+    }
+
+    /// Builds the access list of the transaction from the recorded accesses
+    /// and the inputs and outputs of the transaction.
+    pub fn into_access_list(
+        self,
+        tx_id: &TxId,
+        inputs: &[Input],
+        outputs: &[Output],
+    ) -> AccessList {
+        let accesses = core::mem::take(&mut *self.0.lock());
+
+        let mut contracts = accesses.contracts;
+        let mut coins = BTreeSet::new();
+        let mut messages = BTreeSet::<Nonce>::new();
+        for input in inputs {
+            match input {
+                Input::Contract(contract) => {
+                    contracts.insert(contract.contract_id);
+                }
+                _ => {
+                    if let Some(utxo_id) = input.utxo_id() {
+                        coins.insert(*utxo_id);
+                    }
+                    if let Some(nonce) = input.nonce() {
+                        messages.insert(*nonce);
+                    }
+                }
+            }
+        }
+        for (index, output) in outputs.iter().enumerate() {
+            match output {
+                Output::Coin { .. } | Output::Change { .. } | Output::Variable { .. } => {
+                    let index = u8::try_from(index)
+                        .expect("The number of outputs is limited by `u8`");
+                    coins.insert(UtxoId::new(*tx_id, index));
+                }
+                Output::ContractCreated { contract_id, .. } => {
+                    contracts.insert(*contract_id);
+                }
+                _ => {}
+            }
+        }
+
+        AccessList {
+            contracts: contracts.into_iter().collect(),
+            read_slots: accesses.read_slots.into_iter().collect(),
+            written_slots: accesses.written_slots.into_iter().collect(),
+            contract_balances: accesses.contract_balances.into_iter().collect(),
+            coins: coins.into_iter().collect(),
+            messages: messages.into_iter().collect(),
+        }
+    }
+}
+
+/// The wrapper around the storage used by the VM. It records the contracts, storage slots
+/// and balances accessed by the VM if the `recorder` is set.
+#[derive(Clone, Debug)]
+pub struct RecordingStorage<D> {
+    database: D,
+    recorder: Option<AccessRecorder>,
+}
+
+impl<D> RecordingStorage<D> {
+    pub fn new(database: D, recorder: Option<AccessRecorder>) -> Self {
+        Self { database, recorder }
+    }
+
+    fn record(&self, f: impl FnOnce(&AccessRecorder)) {
+        if let Some(recorder) = &self.recorder {
+            f(recorder)
+        }
+    }
+}
+
+impl<D> StorageInspect<FuelBlocks> for RecordingStorage<D>
+where
+    D: StorageInspect<FuelBlocks, Error = StorageError>,
+{
+    type Error = StorageError;
+
+    fn get(
+        &self,
+        key: &<FuelBlocks as Mappable>::Key,
+    ) -> StorageResult<Option<Cow<<FuelBlocks as Mappable>::OwnedValue>>> {
+        StorageInspect::<FuelBlocks>::get(&self.database, key)
+    }
+
+    fn contains_key(&self, key: &<FuelBlocks as Mappable>::Key) -> StorageResult<bool> {
+        StorageInspect::<FuelBlocks>::contains_key(&self.database, key)
+    }
+}
+
+impl<D> StorageInspect<ContractsRawCode> for RecordingStorage<D>
+where
+    D: StorageInspect<ContractsRawCode, Error = StorageError>,
+{
+    type Error = StorageError;
+
+    fn get(
+        &self,
+        key: &ContractId,
+    ) -> StorageResult<Option<Cow<<ContractsRawCode as Mappable>::OwnedValue>>> {
+        self.record(|recorder| recorder.contract(key));
+        StorageInspect::<ContractsRawCode>::get(&self.database, key)
+    }
+
+    fn contains_key(&self, key: &ContractId) -> StorageResult<bool> {
+        self.record(|recorder| recorder.contract(key));
+        StorageInspect::<ContractsRawCode>::contains_key(&self.database, key)
+    }
+}
+
+impl<D> StorageMutate<ContractsRawCode> for RecordingStorage<D>
+where
+    D: StorageMutate<ContractsRawCode, Error = StorageError>,
+{
+    fn insert(
+        &mut self,
+        key: &ContractId,
+        value: &<ContractsRawCode as Mappable>::Value,
+    ) -> StorageResult<Option<<ContractsRawCode as Mappable>::OwnedValue>> {
+        self.record(|recorder| recorder.contract(key));
+        StorageMutate::<ContractsRawCode>::insert(&mut self.database, key, value)
+    }
+
+    fn remove(
+        &mut self,
+        key: &ContractId,
+    ) -> StorageResult<Option<<ContractsRawCode as Mappable>::OwnedValue>> {
+        self.record(|recorder| recorder.contract(key));
+        StorageMutate::<ContractsRawCode>::remove(&mut self.database, key)
+    }
+}
+
+impl<D> StorageSize<ContractsRawCode> for RecordingStorage<D>
+where
+    D: StorageSize<ContractsRawCode, Error = StorageError>,
+{
+    fn size_of_value(&self, key: &ContractId) -> StorageResult<Option<usize>> {
+        self.record(|recorder| recorder.contract(key));
+        StorageSize::<ContractsRawCode>::size_of_value(&self.database, key)
+    }
+}
+
+impl<D> StorageRead<ContractsRawCode> for RecordingStorage<D>
+where
+    D: StorageRead<ContractsRawCode, Error = StorageError>,
+{
+    fn read(&self, key: &ContractId, buf: &mut [u8]) -> StorageResult<Option<usize>> {
+        self.record(|recorder| recorder.contract(key));
+        StorageRead::<ContractsRawCode>::read(&self.database, key, buf)
+    }
+
+    fn read_alloc(&self, key: &ContractId) -> StorageResult<Option<Vec<u8>>> {
+        self.record(|recorder| recorder.contract(key));
+        StorageRead::<ContractsRawCode>::read_alloc(&self.database, key)
+    }
+}
+
+impl<D> StorageWrite<ContractsRawCode> for RecordingStorage<D>
+where
+    D: StorageWrite<ContractsRawCode, Error = StorageError>,
+{
+    fn write(&mut self, key: &ContractId, buf: &[u8]) -> StorageResult<usize> {
+        self.record(|recorder| recorder.contract(key));
+        StorageWrite::<ContractsRawCode>::write(&mut self.database, key, buf)
+    }
+
+    fn replace(
+        &mut self,
+        key: &ContractId,
+        buf: &[u8],
+    ) -> StorageResult<(usize, Option<Vec<u8>>)> {
+        self.record(|recorder| recorder.contract(key));
+        StorageWrite::<ContractsRawCode>::replace(&mut self.database, key, buf)
+    }
+
+    fn take(&mut self, key: &ContractId) -> StorageResult<Option<Vec<u8>>> {
+        self.record(|recorder| recorder.contract(key));
+        StorageWrite::<ContractsRawCode>::take(&mut self.database, key)
+    }
+}
+
+impl<D> StorageInspect<ContractsAssets> for RecordingStorage<D>
+where
+    D: StorageInspect<ContractsAssets, Error = StorageError>,
+{
+    type Error = StorageError;
+
+    fn get(
+        &self,
+        key: &ContractsAssetKey,
+    ) -> StorageResult<Option<Cow<<ContractsAssets as Mappable>::OwnedValue>>> {
+        self.record(|recorder| recorder.balance(key));
+        StorageInspect::<ContractsAssets>::get(&self.database, key)
+    }
+
+    fn contains_key(&self, key: &ContractsAssetKey) -> StorageResult<bool> {
+        self.record(|recorder| recorder.balance(key));
+        StorageInspect::<ContractsAssets>::contains_key(&self.database, key)
+    }
+}
+
+impl<D> StorageMutate<ContractsAssets> for RecordingStorage<D>
+where
+    D: StorageMutate<ContractsAssets, Error = StorageError>,
+{
+    fn insert(
+        &mut self,
+        key: &ContractsAssetKey,
+        value: &<ContractsAssets as Mappable>::Value,
+    ) -> StorageResult<Option<<ContractsAssets as Mappable>::OwnedValue>> {
+        self.record(|recorder| recorder.balance(key));
+        StorageMutate::<ContractsAssets>::insert(&mut self.database, key, value)
+    }
+
+    fn remove(
+        &mut self,
+        key: &ContractsAssetKey,
+    ) -> StorageResult<Option<<ContractsAssets as Mappable>::OwnedValue>> {
+        self.record(|recorder| recorder.balance(key));
+        StorageMutate::<ContractsAssets>::remove(&mut self.database, key)
+    }
+}
+
+impl<D> MerkleRootStorage<ContractId, ContractsAssets> for RecordingStorage<D>
+where
+    D: MerkleRootStorage<ContractId, ContractsAssets, Error = StorageError>,
+{
+    fn root(&self, key: &ContractId) -> StorageResult<MerkleRoot> {
+        self.record(|recorder| recorder.contract(key));
+        MerkleRootStorage::<ContractId, ContractsAssets>::root(&self.database, key)
+    }
+}
+
+impl<D> StorageInspect<ContractsState> for RecordingStorage<D>
+where
+    D: StorageInspect<ContractsState, Error = StorageError>,
+{
+    type Error = StorageError;
+
+    fn get(
+        &self,
+        key: &ContractsStateKey,
+    ) -> StorageResult<Option<Cow<<ContractsState as Mappable>::OwnedValue>>> {
+        self.record(|recorder| recorder.read_slot(key));
+        StorageInspect::<ContractsState>::get(&self.database, key)
+    }
+
+    fn contains_key(&self, key: &ContractsStateKey) -> StorageResult<bool> {
+        self.record(|recorder| recorder.read_slot(key));
+        StorageInspect::<ContractsState>::contains_key(&self.database, key)
+    }
+}
+
+impl<D> StorageMutate<ContractsState> for RecordingStorage<D>
+where
+    D: StorageMutate<ContractsState, Error = StorageError>,
+{
+    fn insert(
+        &mut self,
+        key: &ContractsStateKey,
+        value: &<ContractsState as Mappable>::Value,
+    ) -> StorageResult<Option<<ContractsState as Mappable>::OwnedValue>> {
+        self.record(|recorder| recorder.written_slot(key));
+        StorageMutate::<ContractsState>::insert(&mut self.database, key, value)
+    }
+
+    fn remove(
+        &mut self,
+        key: &ContractsStateKey,
+    ) -> StorageResult<Option<<ContractsState as Mappable>::OwnedValue>> {
+        self.record(|recorder| recorder.written_slot(key));
+        StorageMutate::<ContractsState>::remove(&mut self.database, key)
+    }
+}
+
+impl<D> StorageSize<ContractsState> for RecordingStorage<D>
+where
+    D: StorageSize<ContractsState, Error = StorageError>,
+{
+    fn size_of_value(&self, key: &ContractsStateKey) -> StorageResult<Option<usize>> {
+        self.record(|recorder| recorder.read_slot(key));
+        StorageSize::<ContractsState>::size_of_value(&self.database, key)
+    }
+}
+
+impl<D> StorageRead<ContractsState> for RecordingStorage<D>
+where
+    D: StorageRead<ContractsState, Error = StorageError>,
+{
+    fn read(
+        &self,
+        key: &ContractsStateKey,
+        buf: &mut [u8],
+    ) -> StorageResult<Option<usize>> {
+        self.record(|recorder| recorder.read_slot(key));
+        StorageRead::<ContractsState>::read(&self.database, key, buf)
+    }
+
+    fn read_alloc(&self, key: &ContractsStateKey) -> StorageResult<Option<Vec<u8>>> {
+        self.record(|recorder| recorder.read_slot(key));
+        StorageRead::<ContractsState>::read_alloc(&self.database, key)
+    }
+}
+
+impl<D> StorageWrite<ContractsState> for RecordingStorage<D>
+where
+    D: StorageWrite<ContractsState, Error = StorageError>,
+{
+    fn write(&mut self, key: &ContractsStateKey, buf: &[u8]) -> StorageResult<usize> {
+        self.record(|recorder| recorder.written_slot(key));
+        StorageWrite::<ContractsState>::write(&mut self.database, key, buf)
+    }
+
+    fn replace(
+        &mut self,
+        key: &ContractsStateKey,
+        buf: &[u8],
+    ) -> StorageResult<(usize, Option<Vec<u8>>)> {
+        self.record(|recorder| recorder.written_slot(key));
+        StorageWrite::<ContractsState>::replace(&mut self.database, key, buf)
+    }
+
+    fn take(&mut self, key: &ContractsStateKey) -> StorageResult<Option<Vec<u8>>> {
+        self.record(|recorder| recorder.written_slot(key));
+        StorageWrite::<ContractsState>::take(&mut self.database, key)
+    }
+}
+
+impl<D> StorageBatchMutate<ContractsState> for RecordingStorage<D>
+where
+    D: StorageBatchMutate<ContractsState, Error = StorageError>,
+{
+    fn init_storage<'a, Iter>(&mut self, set: Iter) -> StorageResult<()>
+    where
+        Iter: 'a
+            + Iterator<
+                Item = (
+                    &'a <ContractsState as Mappable>::Key,
+                    &'a <ContractsState as Mappable>::Value,
+                ),
+            >,
+        <ContractsState as Mappable>::Key: 'a,
+        <ContractsState as Mappable>::Value: 'a,
+    {
+        let recorder = self.recorder.clone();
+        let set = set.inspect(move |(key, _)| {
+            if let Some(recorder) = &recorder {
+                recorder.written_slot(key)
+            }
+        });
+        StorageBatchMutate::<ContractsState>::init_storage(&mut self.database, set)
+    }
+
+    fn insert_batch<'a, Iter>(&mut self, set: Iter) -> StorageResult<()>
+    where
+        Iter: 'a
+            + Iterator<
+                Item = (
+                    &'a <ContractsState as Mappable>::Key,
+                    &'a <ContractsState as Mappable>::Value,
+                ),
+            >,
+        <ContractsState as Mappable>::Key: 'a,
+        <ContractsState as Mappable>::Value: 'a,
+    {
+        let recorder = self.recorder.clone();
+        let set = set.inspect(move |(key, _)| {
+            if let Some(recorder) = &recorder {
+                recorder.written_slot(key)
+            }
+        });
+        StorageBatchMutate::<ContractsState>::insert_batch(&mut self.database, set)
+    }
+
+    fn remove_batch<'a, Iter>(&mut self, set: Iter) -> StorageResult<()>
+    where
+        Iter: 'a + Iterator<Item = &'a <ContractsState as Mappable>::Key>,
+        <ContractsState as Mappable>::Key: 'a,
+    {
+        let recorder = self.recorder.clone();
+        let set = set.inspect(move |key| {
+            if let Some(recorder) = &recorder {
+                recorder.written_slot(key)
+            }
+        });
+        StorageBatchMutate::<ContractsState>::remove_batch(&mut self.database, set)
+    }
+}
+
+impl<D> MerkleRootStorage<ContractId, ContractsState> for RecordingStorage<D>
+where
+    D: MerkleRootStorage<ContractId, ContractsState, Error = StorageError>,
+{
+    fn root(&self, key: &ContractId) -> StorageResult<MerkleRoot> {
+        self.record(|recorder| recorder.contract(key));
+        MerkleRootStorage::<ContractId, ContractsState>::root(&self.database, key)
+    }
+}
@@ -1,9 +1,16 @@
-use fuel_core_types::fuel_tx::{
-    ConsensusParameters,
-    ContractId,
+use fuel_core_chain_config::{
+    BlobPricing,
+    FeeFreeAllowance,
+};
+use fuel_core_types::{
+    fuel_asm::Word,
+    fuel_tx::{
+        ConsensusParameters,
+        ContractId,
+    },
 };
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct Config {
     /// Network-wide common parameters used for validating the chain
     pub consensus_parameters: ConsensusParameters,
@@ -13,4 +20,41 @@ pub struct Config {
     pub backtrace: bool,
     /// Default mode for utxo_validation
     pub utxo_validation_default: bool,
+    /// Report the first mismatching header field instead of the invalid block id
+    /// when the validated block doesn't match the result of the execution.
+    pub strict_header_verification: bool,
+    /// The maximum amount of gas used by all transactions of the block.
+    /// The block production and the block validation enforce the same limit.
+    pub block_gas_limit: Word,
+    /// The whitelisted transactions executed with zero gas price.
+    pub fee_free_allowance: Option<FeeFreeAllowance>,
+    /// The price and the block budget of the payloads of the `Create` transactions.
+    pub blob_pricing: Option<BlobPricing>,
+    /// Executes each produced or validated block the second time with the same
+    /// sequential executor and fails if the results differ. It detects the
+    /// nondeterminism between two runs, not between the sequential and the parallel
+    /// schedulers, because there is no parallel scheduler yet. Doubles the cost of
+    /// the execution, so it is intended for testing only.
+    pub reexecution_audit: bool,
+    /// Counts the instructions executed by the VM and the memory used by each
+    /// transaction of the produced or validated block. The VM runs instruction
+    /// by instruction, so it slows down the execution.
+    pub vm_metrics: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            consensus_parameters: Default::default(),
+            coinbase_recipient: Default::default(),
+            backtrace: false,
+            utxo_validation_default: false,
+            strict_header_verification: false,
+            block_gas_limit: Word::MAX,
+            fee_free_allowance: None,
+            blob_pricing: None,
+            reexecution_audit: false,
+            vm_metrics: false,
+        }
+    }
 }
@@ -1,4 +1,9 @@
 use crate::{
+    access_list::{
+        AccessRecorder,
+        RecordingStorage,
+    },
+    gas_profiler::GasProfiler,
     ports::{
         ExecutorDatabaseTrait,
         MaybeCheckedTransaction,
@@ -6,14 +11,26 @@ use crate::{
         TransactionsSource,
     },
     refs::ContractRef,
+    vm_meter::VmMeter,
     Config,
 };
+use alloc::{
+    borrow::Cow,
+    format,
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
 use block_component::*;
+use fuel_core_chain_config::BlobPricing;
 use fuel_core_storage::{
     tables::{
         Coins,
+        ContractsAssets,
         ContractsInfo,
         ContractsLatestUtxo,
+        ContractsRawCode,
+        ContractsState,
         FuelBlocks,
         Messages,
         ProcessedTransactions,
@@ -25,6 +42,8 @@ use fuel_core_storage::{
         Transactional,
     },
     vm_storage::VmStorage,
+    ContractsAssetKey,
+    ContractsStateKey,
     StorageAsMut,
     StorageAsRef,
 };
@@ -36,6 +55,10 @@ use fuel_core_types::{
         },
         header::PartialBlockHeader,
         primitives::DaBlockHeight,
+        rules::{
+            LimitError,
+            Limits,
+        },
     },
     entities::{
         coins::coin::{
@@ -51,12 +74,15 @@ use fuel_core_types::{
     fuel_tx::{
         field::{
             InputContract,
+            Inputs,
             MaxFeeLimit,
             MintAmount,
             MintAssetId,
             MintGasPrice,
             OutputContract,
+            Outputs,
             Salt,
+            Tip,
             TxPointer as TxPointerField,
         },
         input,
@@ -79,6 +105,7 @@ use fuel_core_types::{
         Bytes32,
         Cacheable,
         Chargeable,
+        Create,
         Input,
         Mint,
         Output,
@@ -92,6 +119,7 @@ use fuel_core_types::{
         BlockHeight,
         ContractId,
         MessageId,
+        Nonce,
     },
     fuel_vm,
     fuel_vm::{
@@ -116,13 +144,22 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            AccessList,
+            ChangeRefund,
+            ContractStateDiff,
+            DryRunResult,
             Error as ExecutorError,
             Event as ExecutorEvent,
+            ExecutionDivergence,
             ExecutionKind,
             ExecutionResult,
             ExecutionType,
             ExecutionTypes,
+            FeeBreakdown,
+            GasProfile,
             Result as ExecutorResult,
+            StateDiff,
+            StateOverrides,
             TransactionExecutionResult,
             TransactionExecutionStatus,
             TransactionValidityError,
@@ -132,12 +169,9 @@ use fuel_core_types::{
     },
 };
 use parking_lot::Mutex as ParkingMutex;
-use std::{
-    borrow::Cow,
-    sync::Arc,
-};
 use tracing::{
     debug,
+    error,
     warn,
 };
 
@@ -194,6 +228,7 @@ where
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            profile_gas: false,
         };
         executor.execute_and_commit(block)
     }
@@ -213,6 +248,7 @@ where
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            profile_gas: false,
         };
         let mut block_transaction = executor.database.transaction();
         executor.execute_block(block_transaction.as_mut(), block)
@@ -225,20 +261,133 @@ where
     where
         TxSource: TransactionsSource,
     {
+        self.execute_without_commit_on(self.database_view_provider.latest_view(), block)
+    }
+
+    /// Executes the block like [`Self::execute_without_commit`], but on top of the `view`
+    /// instead of the latest view of the database. The `view` may contain the changes
+    /// of the previous block that are not committed yet.
+    pub fn execute_without_commit_on<TxSource>(
+        &self,
+        view: View,
+        block: ExecutionBlockWithSource<TxSource>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<View>>>
+    where
+        TxSource: TransactionsSource,
+    {
+        let audit =
+            self.config.reexecution_audit && !matches!(block, ExecutionTypes::DryRun(_));
         let executor = ExecutionInstance {
-            database: self.database_view_provider.latest_view(),
+            database: view.clone(),
+            relayer: self.relayer_view_provider.latest_view(),
+            config: self.config.clone(),
+            options: self.config.as_ref().into(),
+            profile_gas: false,
+        };
+        let result = executor.execute_inner(block)?;
+        if audit {
+            self.audit_reexecution::<TxSource>(view, result.result())?;
+        }
+        Ok(result)
+    }
+
+    /// Validates the executed block on top of the same state the second time
+    /// and compares the results with the `expected` ones.
+    fn audit_reexecution<TxSource>(
+        &self,
+        view: View,
+        expected: &ExecutionResult,
+    ) -> ExecutorResult<()>
+    where
+        TxSource: TransactionsSource,
+    {
+        let height = *expected.block.header().height();
+        let executor = ExecutionInstance {
+            database: view,
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options: self.config.as_ref().into(),
+            profile_gas: false,
+        };
+        let block =
+            ExecutionBlockWithSource::<TxSource>::Validation(expected.block.clone());
+        let actual = executor
+            .execute_inner(block)
+            .map_err(|err| {
+                error!("The re-execution of the block {height} failed: {err}");
+                err
+            })?
+            .into_result();
+
+        let divergence = if actual.block != expected.block {
+            Some(ExecutionDivergence::Block)
+        } else if actual.tx_status != expected.tx_status {
+            Some(ExecutionDivergence::TransactionsStatus)
+        } else if actual.events != expected.events {
+            Some(ExecutionDivergence::Events)
+        } else {
+            None
+        };
+
+        if let Some(divergence) = divergence {
+            error!("The re-execution of the block {height} diverged in the {divergence}");
+            return Err(ExecutorError::NondeterministicExecution(divergence))
+        }
+        Ok(())
+    }
+
+    /// Executes the block like [`Self::execute_without_commit`], but skips the verification
+    /// of signatures, predicates and the existence and ownership of the spent UTXOs.
+    ///
+    /// **Never use it for blocks from untrusted sources.** The method exists to measure
+    /// the pure cost of the VM execution and storage access in benchmarks, and is
+    /// available only with the non-default `benchmarking` feature.
+    #[cfg(feature = "benchmarking")]
+    pub fn execute_without_commit_unchecked<TxSource>(
+        &self,
+        block: ExecutionBlockWithSource<TxSource>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<View>>>
+    where
+        TxSource: TransactionsSource,
+    {
+        self.execute_without_commit_unchecked_on(
+            self.database_view_provider.latest_view(),
+            block,
+        )
+    }
+
+    /// Executes the block like [`Self::execute_without_commit_unchecked`],
+    /// but on top of the `view`.
+    #[cfg(feature = "benchmarking")]
+    pub fn execute_without_commit_unchecked_on<TxSource>(
+        &self,
+        view: View,
+        block: ExecutionBlockWithSource<TxSource>,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<View>>>
+    where
+        TxSource: TransactionsSource,
+    {
+        let executor = ExecutionInstance {
+            database: view,
+            relayer: self.relayer_view_provider.latest_view(),
+            config: self.config.clone(),
+            options: ExecutionOptions {
+                utxo_validation: false,
+            },
+            profile_gas: false,
         };
         executor.execute_inner(block)
     }
 
+    /// The gas spent by the transactions is profiled per contract and opcode
+    /// if `profile_gas` is set. The profiling slows down the execution.
     pub fn dry_run(
         &self,
         component: Components<Vec<Transaction>>,
         utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
+        state_overrides: StateOverrides,
+        profile_gas: bool,
+    ) -> ExecutorResult<Vec<DryRunResult>> {
         // fallback to service config value if no utxo_validation override is provided
         let utxo_validation =
             utxo_validation.unwrap_or(self.config.utxo_validation_default);
@@ -250,8 +399,9 @@ where
             relayer: self.relayer_view_provider.latest_view(),
             config: self.config.clone(),
             options,
+            profile_gas,
         };
-        executor.dry_run(component)
+        executor.dry_run(component, state_overrides)
     }
 }
 
@@ -260,14 +410,28 @@ where
 pub struct ExecutionData {
     coinbase: u64,
     used_gas: u64,
+    /// The max gas of the whitelisted transactions executed with zero gas price.
+    fee_free_gas: u64,
+    /// The size of the payloads of the `Create` transactions.
+    blob_bytes: u64,
     tx_count: u16,
     found_mint: bool,
     message_ids: Vec<MessageId>,
     tx_status: Vec<TransactionExecutionStatus>,
     events: Vec<ExecutorEvent>,
+    dry_run_records: DryRunRecords,
     pub skipped_transactions: Vec<(TxId, ExecutorError)>,
 }
 
+/// The per-transaction data recorded only during the dry run.
+#[derive(Default)]
+struct DryRunRecords {
+    /// The state accessed by the transactions.
+    access_lists: Vec<(TxId, AccessList)>,
+    /// The gas spent by the transactions, if the profiling is requested.
+    gas_profiles: Vec<(TxId, GasProfile)>,
+}
+
 /// Per-block execution options
 #[derive(Copy, Clone, Default, Debug)]
 pub struct ExecutionOptions {
@@ -293,6 +457,8 @@ struct ExecutionInstance<R, D> {
     pub database: D,
     pub config: Arc<Config>,
     pub options: ExecutionOptions,
+    /// Profiles the gas spent by the transactions during the dry run.
+    pub profile_gas: bool,
 }
 
 impl<R, D> ExecutionInstance<R, D>
@@ -330,6 +496,7 @@ where
     R: RelayerPort,
     D: ExecutorDatabaseTrait<D>,
 {
+    #[cfg(any(test, feature = "test-helpers"))]
     pub fn execute_without_commit<TxSource>(
         self,
         block: ExecutionBlockWithSource<TxSource>,
@@ -340,10 +507,14 @@ where
         self.execute_inner(block)
     }
 
+    /// Executes the bundle of transactions in order on top of the shared temporary
+    /// state, so each transaction sees the changes of the previous ones.
+    /// The `state_overrides` are applied to the temporary state before the execution.
     pub fn dry_run(
         self,
         component: Components<Vec<Transaction>>,
-    ) -> ExecutorResult<Vec<TransactionExecutionStatus>> {
+        state_overrides: StateOverrides,
+    ) -> ExecutorResult<Vec<DryRunResult>> {
         let component = Components {
             header_to_produce: component.header_to_produce,
             transactions_source: OnceTransactionsSource::new(
@@ -353,27 +524,140 @@ where
             gas_limit: component.gas_limit,
         };
 
+        let mut temporary_db = self.database.transaction();
+        apply_state_overrides(temporary_db.as_mut(), state_overrides)?;
+        let (result, records) =
+            self.execute_in_transaction(ExecutionTypes::DryRun(component), temporary_db)?;
         let (
             ExecutionResult {
+                block,
                 skipped_transactions,
                 tx_status,
-                ..
+                events,
             },
             _temporary_db,
-        ) = self
-            .execute_without_commit(ExecutionTypes::DryRun(component))?
-            .into();
+        ) = result.into();
 
         // If one of the transactions fails, return an error.
         if let Some((_, err)) = skipped_transactions.into_iter().next() {
             return Err(err)
         }
 
-        Ok(tx_status)
+        let results = block
+            .transactions()
+            .iter()
+            .zip(tx_status)
+            .map(|(tx, status)| DryRunResult {
+                state_diff: state_diff(tx, &status.id, &events),
+                access_list: records
+                    .access_lists
+                    .iter()
+                    .find(|(tx_id, _)| tx_id == &status.id)
+                    .map(|(_, access_list)| access_list.clone())
+                    .unwrap_or_default(),
+                gas_profile: records
+                    .gas_profiles
+                    .iter()
+                    .find(|(tx_id, _)| tx_id == &status.id)
+                    .map(|(_, gas_profile)| gas_profile.clone()),
+                status,
+            })
+            .collect();
+
+        Ok(results)
         // drop `_temporary_db` without committing to avoid altering state.
     }
 }
 
+/// Collects the changes of the state made by the executed transaction `tx` from
+/// the `events` of the whole block.
+fn state_diff(tx: &Transaction, tx_id: &TxId, events: &[ExecutorEvent]) -> StateDiff {
+    let (inputs, outputs) = match tx {
+        Transaction::Script(script) => (script.inputs(), script.outputs()),
+        Transaction::Create(create) => (create.inputs(), create.outputs()),
+        Transaction::Mint(_) => return StateDiff::default(),
+    };
+    let spends_utxo =
+        |utxo_id: &UtxoId| inputs.iter().any(|input| input.utxo_id() == Some(utxo_id));
+    let spends_message =
+        |nonce: &Nonce| inputs.iter().any(|input| input.nonce() == Some(nonce));
+
+    let mut diff = StateDiff::default();
+    for event in events {
+        match event {
+            ExecutorEvent::CoinCreated(coin) if coin.utxo_id.tx_id() == tx_id => {
+                diff.created_coins.push(*coin);
+            }
+            ExecutorEvent::CoinConsumed(coin) if spends_utxo(&coin.utxo_id) => {
+                diff.consumed_coins.push(*coin);
+            }
+            ExecutorEvent::MessageConsumed(message)
+                if spends_message(message.nonce()) =>
+            {
+                diff.consumed_messages.push(message.clone());
+            }
+            _ => {}
+        }
+    }
+
+    diff.contracts = outputs
+        .iter()
+        .filter_map(|output| match output {
+            Output::Contract(contract) => {
+                match inputs.get(usize::from(contract.input_index)) {
+                    Some(Input::Contract(input)) => Some(ContractStateDiff {
+                        contract_id: input.contract_id,
+                        balance_root: contract.balance_root,
+                        state_root: contract.state_root,
+                    }),
+                    _ => None,
+                }
+            }
+            _ => None,
+        })
+        .collect();
+
+    diff
+}
+
+/// Applies the `overrides` of the dry run to the temporary state.
+fn apply_state_overrides<D>(db: &mut D, overrides: StateOverrides) -> ExecutorResult<()>
+where
+    D: ExecutorDatabaseTrait<D>,
+{
+    for coin in overrides.coins {
+        let utxo_id = coin.utxo_id;
+        db.storage::<Coins>().insert(&utxo_id, &coin.compress())?;
+    }
+
+    for balance in overrides.contract_balances {
+        let key = ContractsAssetKey::new(&balance.contract_id, &balance.asset_id);
+        db.storage::<ContractsAssets>()
+            .insert(&key, &balance.amount)?;
+    }
+
+    for slot in overrides.contract_slots {
+        let key = ContractsStateKey::new(&slot.contract_id, &slot.key);
+        db.storage::<ContractsState>()
+            .insert(&key, slot.value.as_ref())?;
+    }
+
+    for code in overrides.contract_code {
+        db.storage::<ContractsRawCode>()
+            .insert(&code.contract_id, code.bytecode.as_slice())?;
+        // The new contract needs the utxo to be used as an input with `utxo_validation`.
+        if !db
+            .storage::<ContractsLatestUtxo>()
+            .contains_key(&code.contract_id)?
+        {
+            db.storage::<ContractsLatestUtxo>()
+                .insert(&code.contract_id, &ContractUtxoInfo::default())?;
+        }
+    }
+
+    Ok(())
+}
+
 // TODO: Make this module private after moving unit tests from `fuel-core` here.
 pub mod block_component {
     use super::*;
@@ -436,19 +720,36 @@ where
         self,
         block: ExecutionBlockWithSource<TxSource>,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<D>>>
+    where
+        TxSource: TransactionsSource,
+    {
+        // Create a new storage transaction.
+        let block_st_transaction = self.database.transaction();
+        let (result, _) = self.execute_in_transaction(block, block_st_transaction)?;
+        Ok(result)
+    }
+
+    /// Executes the block on top of the `block_st_transaction`.
+    /// Also returns the data recorded for the transactions during the dry run.
+    fn execute_in_transaction<TxSource>(
+        self,
+        block: ExecutionBlockWithSource<TxSource>,
+        mut block_st_transaction: StorageTransaction<D>,
+    ) -> ExecutorResult<(UncommittedResult<StorageTransaction<D>>, DryRunRecords)>
     where
         TxSource: TransactionsSource,
     {
         // Compute the block id before execution if there is one.
         let pre_exec_block_id = block.id();
+        let pre_exec_header = match &block {
+            ExecutionTypes::Validation(block) => Some(block.header().clone()),
+            _ => None,
+        };
 
         // If there is full fuel block for validation then map it into
         // a partial header.
         let block = block.map_v(PartialFuelBlock::from);
 
-        // Create a new storage transaction.
-        let mut block_st_transaction = self.database.transaction();
-
         let (block, execution_data) = match block {
             ExecutionTypes::DryRun(component) => {
                 let mut block =
@@ -499,6 +800,7 @@ where
             tx_status,
             skipped_transactions,
             events,
+            dry_run_records,
             ..
         } = execution_data;
 
@@ -519,6 +821,13 @@ where
         if let Some(pre_exec_block_id) = pre_exec_block_id {
             // The block id comparison compares the whole blocks including all fields.
             if pre_exec_block_id != finalized_block_id {
+                if self.config.strict_header_verification {
+                    let field = pre_exec_header
+                        .and_then(|header| header.mismatched_field(block.header()));
+                    if let Some(field) = field {
+                        return Err(ExecutorError::BlockHeaderMismatch(field))
+                    }
+                }
                 return Err(ExecutorError::InvalidBlockId)
             }
         }
@@ -531,7 +840,10 @@ where
         };
 
         // Get the complete fuel block.
-        Ok(UncommittedResult::new(result, block_st_transaction))
+        Ok((
+            UncommittedResult::new(result, block_st_transaction),
+            dry_run_records,
+        ))
     }
 
     #[tracing::instrument(skip_all)]
@@ -547,11 +859,14 @@ where
         let mut data = ExecutionData {
             coinbase: 0,
             used_gas: 0,
+            fee_free_gas: 0,
+            blob_bytes: 0,
             tx_count: 0,
             found_mint: false,
             message_ids: Vec::new(),
             tx_status: Vec::new(),
             events: Vec::new(),
+            dry_run_records: DryRunRecords::default(),
             skipped_transactions: Vec::new(),
         };
         let execution_data = &mut data;
@@ -561,7 +876,9 @@ where
         let block = component.empty_block;
         let source = component.transactions_source;
         let gas_price = component.gas_price;
-        let mut remaining_gas_limit = component.gas_limit;
+        let limits = self.limits();
+        let block_gas_limit = component.gas_limit.min(limits.block_gas_limit);
+        let mut remaining_gas_limit = block_gas_limit;
         let block_height = *block.header.height();
 
         if self.relayer.enabled() {
@@ -624,17 +941,23 @@ where
             Ok(())
         };
 
-        while iter.peek().is_some() {
+        'transactions: while iter.peek().is_some() {
             for transaction in iter {
+                // The source keeps the transactions that are not included,
+                // so they can be included into the next block.
+                if execution_kind == ExecutionKind::Production && source.is_exhausted() {
+                    break 'transactions
+                }
                 execute_transaction(&mut *execution_data, transaction)?;
             }
 
-            remaining_gas_limit =
-                component.gas_limit.saturating_sub(execution_data.used_gas);
+            remaining_gas_limit = block_gas_limit.saturating_sub(execution_data.used_gas);
 
             iter = source.next(remaining_gas_limit).into_iter().peekable();
         }
 
+        limits.check_block_gas(execution_data.used_gas)?;
+
         // After the execution of all transactions in production mode, we can set the final fee.
         if execution_kind == ExecutionKind::Production {
             let amount_to_mint = if self.config.coinbase_recipient != ContractId::zeroed()
@@ -676,6 +999,62 @@ where
         Ok(data)
     }
 
+    /// Returns zero gas price for the whitelisted transaction while it fits into
+    /// the fee-free budget of the block, and the gas price of the block otherwise.
+    /// The second value is the gas to reserve from the budget after the execution.
+    /// The budget is reserved by the max gas of the transaction, so the block
+    /// production and the block validation come to the same decision.
+    fn tx_gas_price(
+        &self,
+        inputs: &[Input],
+        max_gas: Word,
+        gas_price: Word,
+        execution_data: &ExecutionData,
+    ) -> (Word, Word) {
+        let Some(allowance) = &self.config.fee_free_allowance else {
+            return (gas_price, 0)
+        };
+
+        if allowance.is_whitelisted(inputs)
+            && allowance.fits_budget(execution_data.fee_free_gas, max_gas)
+        {
+            (0, max_gas)
+        } else {
+            (gas_price, 0)
+        }
+    }
+
+    /// Checks that the `Create` transaction pays for its payload, and that the payload
+    /// fits into the budget of the block. Returns the size of the payload.
+    fn check_blob(
+        &self,
+        create: &Create,
+        execution_data: &ExecutionData,
+    ) -> ExecutorResult<u64> {
+        let Some(pricing) = &self.config.blob_pricing else {
+            return Ok(0)
+        };
+
+        let payload_size = BlobPricing::payload_size(create);
+        pricing.check_fee(payload_size, create.tip())?;
+        if !pricing.fits_budget(execution_data.blob_bytes, payload_size) {
+            return Err(LimitError::BlockBlobBytesLimitExceeded {
+                used_bytes: execution_data.blob_bytes.saturating_add(payload_size),
+                limit: pricing.max_bytes_per_block,
+            }
+            .into())
+        }
+        Ok(payload_size)
+    }
+
+    /// The limits of the transactions and the block, shared with the transaction pool.
+    fn limits(&self) -> Limits {
+        Limits::new(
+            &self.config.consensus_parameters,
+            self.config.block_gas_limit,
+        )
+    }
+
     fn process_da(
         &self,
         block_st_transaction: &mut D,
@@ -723,6 +1102,7 @@ where
     }
 
     #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip_all, fields(tx_id = %tx_id))]
     fn execute_transaction(
         &self,
         tx: MaybeCheckedTransaction,
@@ -754,7 +1134,35 @@ where
             MaybeCheckedTransaction::CheckedTransaction(checked_tx) => checked_tx,
         };
 
-        match checked_tx {
+        let mut blob_bytes = 0;
+        let (gas_price, fee_free_gas) = match &checked_tx {
+            CheckedTransaction::Script(script) => {
+                let max_gas = script.metadata().max_gas;
+                self.limits()
+                    .check_transaction(script.transaction(), max_gas)?;
+                self.tx_gas_price(
+                    script.transaction().inputs(),
+                    max_gas,
+                    gas_price,
+                    execution_data,
+                )
+            }
+            CheckedTransaction::Create(create) => {
+                let max_gas = create.metadata().max_gas;
+                self.limits()
+                    .check_transaction(create.transaction(), max_gas)?;
+                blob_bytes = self.check_blob(create.transaction(), execution_data)?;
+                self.tx_gas_price(
+                    create.transaction().inputs(),
+                    max_gas,
+                    gas_price,
+                    execution_data,
+                )
+            }
+            CheckedTransaction::Mint(_) => (gas_price, 0),
+        };
+
+        let tx = match checked_tx {
             CheckedTransaction::Script(script) => self.execute_create_or_script(
                 script,
                 header,
@@ -779,7 +1187,13 @@ where
                 tx_st_transaction,
                 execution_kind,
             ),
-        }
+        }?;
+
+        execution_data.fee_free_gas =
+            execution_data.fee_free_gas.saturating_add(fee_free_gas);
+        execution_data.blob_bytes = execution_data.blob_bytes.saturating_add(blob_bytes);
+
+        Ok(tx)
     }
 
     fn execute_mint(
@@ -921,7 +1335,10 @@ where
             result: TransactionExecutionResult::Success {
                 result: None,
                 receipts: vec![],
+                fee: FeeBreakdown::default(),
             },
+            // The `Mint` transaction is not executed by the VM.
+            vm_metrics: None,
         });
 
         if block_st_transaction
@@ -990,9 +1407,19 @@ where
         let mut sub_block_db_commit = tx_st_transaction.transaction();
         let sub_db_view = sub_block_db_commit.as_mut();
 
+        // The dry run records the state accessed by the transaction.
+        let access_recorder =
+            (execution_kind == ExecutionKind::DryRun).then(AccessRecorder::default);
+        let mut gas_profiler = (execution_kind == ExecutionKind::DryRun
+            && self.profile_gas)
+            .then(GasProfiler::default);
+        let mut vm_meter = (execution_kind != ExecutionKind::DryRun
+            && self.config.vm_metrics)
+            .then(VmMeter::default);
+
         // execution vm
         let vm_db = VmStorage::new(
-            sub_db_view.clone(),
+            RecordingStorage::new(sub_db_view.clone(), access_recorder.clone()),
             &header.consensus,
             self.config.coinbase_recipient,
         );
@@ -1009,13 +1436,16 @@ where
             .clone()
             .into_ready(gas_price, gas_costs, fee_params)?;
 
-        let vm_result: StateTransition<_> = vm
-            .transact(ready_tx)
+        let vm_result: StateTransition<_> =
+            match (gas_profiler.as_mut(), vm_meter.as_mut()) {
+                (Some(gas_profiler), _) => gas_profiler.transact(&mut vm, ready_tx),
+                (None, Some(vm_meter)) => vm_meter.transact(&mut vm, ready_tx),
+                (None, None) => vm.transact(ready_tx).map(Into::into),
+            }
             .map_err(|error| ExecutorError::VmExecution {
                 error: InterpreterError::Storage(anyhow::anyhow!(format!("{error:?}"))),
                 transaction_id: tx_id,
-            })?
-            .into();
+            })?;
         let reverted = vm_result.should_revert();
 
         let (state, mut tx, receipts): (_, Tx, _) = vm_result.into_inner();
@@ -1071,6 +1501,7 @@ where
         // update block commitment
         let (used_gas, tx_fee) =
             self.total_fee_paid(&tx, max_fee, &receipts, gas_price)?;
+        let fee = self.fee_breakdown(&tx, used_gas, max_fee, tx_fee, gas_price);
 
         // change the spent status of the tx inputs
         self.spend_input_utxos(
@@ -1122,6 +1553,9 @@ where
                 .insert(&contract_id, &(salt.into()))?;
         }
 
+        let access_list = access_recorder
+            .map(|recorder| recorder.into_access_list(&tx_id, tx.inputs(), tx.outputs()));
+
         let final_tx = tx.into();
 
         // Store tx into the block db transaction
@@ -1139,18 +1573,32 @@ where
         execution_data
             .message_ids
             .extend(receipts.iter().filter_map(|r| r.message_id()));
+        if let Some(access_list) = access_list {
+            execution_data
+                .dry_run_records
+                .access_lists
+                .push((tx_id, access_list));
+        }
+        if let Some(gas_profiler) = gas_profiler {
+            execution_data
+                .dry_run_records
+                .gas_profiles
+                .push((tx_id, gas_profiler.into_gas_profile()));
+        }
 
         let status = if reverted {
             self.log_backtrace(&vm, &receipts);
             TransactionExecutionResult::Failed {
                 result: Some(state),
                 receipts,
+                fee,
             }
         } else {
             // else tx was a success
             TransactionExecutionResult::Success {
                 result: Some(state),
                 receipts,
+                fee,
             }
         };
 
@@ -1158,6 +1606,7 @@ where
         execution_data.tx_status.push(TransactionExecutionStatus {
             id: tx_id,
             result: status,
+            vm_metrics: vm_meter.map(VmMeter::into_vm_metrics),
         });
 
         Ok(final_tx)
@@ -1344,6 +1793,52 @@ where
         ))
     }
 
+    /// Splits the fee paid by the transaction into its components.
+    fn fee_breakdown<Tx>(
+        &self,
+        tx: &Tx,
+        used_gas: Word,
+        max_fee: Word,
+        tx_fee: Word,
+        gas_price: Word,
+    ) -> FeeBreakdown
+    where
+        Tx: Chargeable + Outputs,
+    {
+        let params = &self.config.consensus_parameters;
+        let tip = tx.tip();
+        let refund = max_fee.saturating_sub(tx_fee);
+        // The VM returns the unused fee to the change output of the base asset.
+        let refunds = tx
+            .outputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(output_index, output)| match output {
+                Output::Change { asset_id, .. }
+                    if asset_id == params.base_asset_id() && refund > 0 =>
+                {
+                    Some(ChangeRefund {
+                        output_index: u16::try_from(output_index).ok()?,
+                        asset_id: *asset_id,
+                        amount: refund,
+                    })
+                }
+                _ => None,
+            })
+            .collect();
+
+        FeeBreakdown {
+            gas_used: tx
+                .min_gas(params.gas_costs(), params.fee_params())
+                .saturating_add(used_gas),
+            gas_price,
+            tip,
+            base_fee: tx_fee.saturating_sub(tip),
+            total_fee: tx_fee,
+            refunds,
+        }
+    }
+
     /// Computes all zeroed or variable inputs.
     /// In production mode, updates the inputs with computed values.
     /// In validation mode, compares the inputs with computed inputs.
@@ -1524,7 +2019,7 @@ where
     /// Log a VM backtrace if configured to do so
     fn log_backtrace<Tx>(
         &self,
-        vm: &Interpreter<VmStorage<D>, Tx>,
+        vm: &Interpreter<VmStorage<RecordingStorage<D>>, Tx>,
         receipts: &[Receipt],
     ) {
         if self.config.backtrace {
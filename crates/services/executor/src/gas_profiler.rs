@@ -0,0 +1,170 @@
+//! The profiler that aggregates the gas spent by the VM per contract and opcode during the dry run.
+
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{
+        String,
+        ToString,
+    },
+};
+use fuel_core_types::{
+    fuel_asm::{
+        Opcode,
+        RegId,
+    },
+    fuel_tx::ContractId,
+    fuel_vm::{
+        checked_transaction::{
+            IntoChecked,
+            Ready,
+        },
+        interpreter::{
+            CheckedMetadata,
+            ExecutableTransaction,
+        },
+        state::{
+            DebugEval,
+            ProgramState,
+            StateTransition,
+        },
+        Breakpoint,
+        Interpreter,
+        InterpreterError,
+        InterpreterStorage,
+    },
+    services::executor::{
+        GasProfile,
+        GasProfileEntry,
+    },
+};
+
+/// The key of the aggregated gas: the contract, or `None` for the script, and the opcode.
+type Location = (Option<ContractId>, String);
+
+/// Runs the VM instruction by instruction and attributes the decrease of the global gas
+/// to the executed instruction.
+#[derive(Default, Debug)]
+pub struct GasProfiler {
+    entries: BTreeMap<Location, (u64, u64)>,
+}
+
+impl GasProfiler {
+    /// Executes the transaction like [`Interpreter::transact`], but with single-stepping
+    /// to record the gas spent by each instruction.
+    pub fn transact<S, Tx>(
+        &mut self,
+        vm: &mut Interpreter<S, Tx>,
+        tx: Ready<Tx>,
+    ) -> Result<StateTransition<Tx>, InterpreterError<S::DataError>>
+    where
+        S: InterpreterStorage,
+        Tx: ExecutableTransaction,
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
+        vm.set_single_stepping(true);
+        let mut state = *vm.transact(tx)?.state();
+        let mut current: Option<(Location, u64)> = None;
+
+        loop {
+            let remaining_gas = vm.registers()[RegId::GGAS];
+            if let Some((location, gas_before)) = current.take() {
+                self.record(location, gas_before.saturating_sub(remaining_gas));
+            }
+
+            match state {
+                ProgramState::RunProgram(DebugEval::Breakpoint(breakpoint))
+                | ProgramState::VerifyPredicate(DebugEval::Breakpoint(breakpoint)) => {
+                    current = Some((location(vm, &breakpoint), remaining_gas));
+                    state = vm.resume()?;
+                }
+                _ => break,
+            }
+        }
+        vm.set_single_stepping(false);
+
+        Ok(StateTransition::new(
+            state,
+            vm.transaction().clone(),
+            vm.receipts().to_vec(),
+        ))
+    }
+
+    fn record(&mut self, location: Location, gas: u64) {
+        let (count, total_gas) = self.entries.entry(location).or_default();
+        *count = count.saturating_add(1);
+        *total_gas = total_gas.saturating_add(gas);
+    }
+
+    pub fn into_gas_profile(self) -> GasProfile {
+        let entries = self
+            .entries
+            .into_iter()
+            .map(|((contract_id, opcode), (count, gas))| GasProfileEntry {
+                contract_id,
+                opcode,
+                count,
+                gas,
+            })
+            .collect();
+        GasProfile { entries }
+    }
+}
+
+/// Returns the contract and the opcode of the instruction the VM stopped before.
+fn location<S, Tx>(vm: &Interpreter<S, Tx>, breakpoint: &Breakpoint) -> Location {
+    let contract_id = (breakpoint.contract() != &ContractId::zeroed())
+        .then_some(*breakpoint.contract());
+    let opcode = usize::try_from(vm.registers()[RegId::PC])
+        .ok()
+        .and_then(|pc| vm.memory().get(pc).copied())
+        .and_then(|byte| Opcode::try_from(byte).ok())
+        .map(|opcode| format!("{opcode:?}"))
+        .unwrap_or_else(|| "UNKNOWN".to_string());
+    (contract_id, opcode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn gas_profile_aggregates_gas_per_contract_and_opcode() {
+        // Given
+        let contract_id = ContractId::from([1; 32]);
+        let mut profiler = GasProfiler::default();
+
+        // When
+        profiler.record((None, "ADD".to_string()), 1);
+        profiler.record((None, "ADD".to_string()), 1);
+        profiler.record((Some(contract_id), "ADD".to_string()), 2);
+        profiler.record((Some(contract_id), "CALL".to_string()), 10);
+
+        // Then
+        let profile = profiler.into_gas_profile();
+        assert_eq!(
+            profile.entries,
+            vec![
+                GasProfileEntry {
+                    contract_id: None,
+                    opcode: "ADD".to_string(),
+                    count: 2,
+                    gas: 2,
+                },
+                GasProfileEntry {
+                    contract_id: Some(contract_id),
+                    opcode: "ADD".to_string(),
+                    count: 1,
+                    gas: 2,
+                },
+                GasProfileEntry {
+                    contract_id: Some(contract_id),
+                    opcode: "CALL".to_string(),
+                    count: 1,
+                    gas: 10,
+                },
+            ]
+        );
+    }
+}
@@ -1,9 +1,19 @@
+//! The crate `fuel-core-executor` contains the state transition function of the Fuel blockchain.
+//! The crate is `no_std` and accesses the state only through the storage traits, so it can be
+//! compiled into the `wasm32-unknown-unknown` blob.
+
 #![deny(clippy::arithmetic_side_effects)]
 #![deny(clippy::cast_possible_truncation)]
 #![deny(unused_crate_dependencies)]
 #![deny(warnings)]
+#![no_std]
+
+extern crate alloc;
 
+mod access_list;
 mod config;
+mod gas_profiler;
+mod vm_meter;
 
 pub mod executor;
 pub mod ports;
@@ -1,3 +1,4 @@
+use alloc::vec::Vec;
 use fuel_core_storage::{
     tables::{
         Coins,
@@ -60,6 +61,15 @@ impl MaybeCheckedTransaction {
 pub trait TransactionsSource {
     /// Returns the next batch of transactions to satisfy the `gas_limit`.
     fn next(&self, gas_limit: u64) -> Vec<MaybeCheckedTransaction>;
+
+    /// Returns `true` if the block production should stop including transactions
+    /// into the block, even if the source has more of them. For example,
+    /// because the time budget of the block is nearly exhausted.
+    ///
+    /// It is called before the execution of each transaction during the block production.
+    fn is_exhausted(&self) -> bool {
+        false
+    }
 }
 
 pub trait RelayerPort {
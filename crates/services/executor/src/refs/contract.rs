@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use core::fmt;
 use fuel_core_chain_config::GenesisCommitment;
 use fuel_core_storage::{
@@ -24,7 +25,6 @@ use fuel_core_types::{
         Result as ExecutorResult,
     },
 };
-use std::borrow::Cow;
 
 /// The wrapper around `contract_id` to simplify work with `Contract` in the database.
 pub struct ContractRef<Database> {
@@ -123,34 +123,48 @@ where
     anyhow::Error: From<Database::InnerError>,
 {
     fn root(&self) -> anyhow::Result<MerkleRoot> {
-        let contract_id = *self.contract_id();
-        let utxo = *self
-            .database()
-            .storage::<ContractsLatestUtxo>()
-            .get(&contract_id)?
-            .ok_or(not_found!(ContractsLatestUtxo))?
-            .into_owned()
-            .utxo_id();
-
-        let state_root = self
-            .database()
-            .storage::<ContractsState>()
-            .root(&contract_id)?;
-
-        let balance_root = self
-            .database()
-            .storage::<ContractsAssets>()
-            .root(&contract_id)?;
-
-        let contract_hash = *Hasher::default()
-            // `ContractId` already is based on contract's code and salt so we don't need it.
-            .chain(contract_id.as_ref())
-            .chain(utxo.tx_id().as_ref())
-            .chain([utxo.output_index()])
-            .chain(state_root.as_slice())
-            .chain(balance_root.as_slice())
-            .finalize();
-
-        Ok(contract_hash)
+        contract_root(&**self.database(), self.contract_id())
     }
 }
+
+/// Allows calculating the roots of the different contracts in parallel.
+impl<'a, Database> GenesisCommitment for ContractRef<&'a Database>
+where
+    Database: ContractStorageTrait,
+    anyhow::Error: From<Database::InnerError>,
+{
+    fn root(&self) -> anyhow::Result<MerkleRoot> {
+        contract_root(*self.database(), self.contract_id())
+    }
+}
+
+fn contract_root<Database>(
+    database: &Database,
+    contract_id: &ContractId,
+) -> anyhow::Result<MerkleRoot>
+where
+    Database: ContractStorageTrait,
+    anyhow::Error: From<Database::InnerError>,
+{
+    let utxo = *database
+        .storage::<ContractsLatestUtxo>()
+        .get(contract_id)?
+        .ok_or(not_found!(ContractsLatestUtxo))?
+        .into_owned()
+        .utxo_id();
+
+    let state_root = database.storage::<ContractsState>().root(contract_id)?;
+
+    let balance_root = database.storage::<ContractsAssets>().root(contract_id)?;
+
+    let contract_hash = *Hasher::default()
+        // `ContractId` already is based on contract's code and salt so we don't need it.
+        .chain(contract_id.as_ref())
+        .chain(utxo.tx_id().as_ref())
+        .chain([utxo.output_index()])
+        .chain(state_root.as_slice())
+        .chain(balance_root.as_slice())
+        .finalize();
+
+    Ok(contract_hash)
+}
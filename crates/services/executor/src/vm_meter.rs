@@ -0,0 +1,114 @@
+//! The meter of the instructions and the memory used by the VM during the execution
+//! of the transaction in the produced or validated block.
+
+use fuel_core_types::{
+    fuel_asm::{
+        RegId,
+        Word,
+    },
+    fuel_vm::{
+        checked_transaction::{
+            IntoChecked,
+            Ready,
+        },
+        consts::VM_MAX_RAM,
+        interpreter::{
+            CheckedMetadata,
+            ExecutableTransaction,
+        },
+        state::{
+            DebugEval,
+            ProgramState,
+            StateTransition,
+        },
+        Interpreter,
+        InterpreterError,
+        InterpreterStorage,
+    },
+    services::executor::VmMetrics,
+};
+
+/// Runs the VM instruction by instruction to count the executed instructions
+/// and to track the highest usage of the memory.
+#[derive(Default, Debug)]
+pub struct VmMeter {
+    instructions: u64,
+    memory_watermark: u64,
+}
+
+impl VmMeter {
+    /// Executes the transaction like [`Interpreter::transact`], but with single-stepping
+    /// to observe the VM after each instruction.
+    pub fn transact<S, Tx>(
+        &mut self,
+        vm: &mut Interpreter<S, Tx>,
+        tx: Ready<Tx>,
+    ) -> Result<StateTransition<Tx>, InterpreterError<S::DataError>>
+    where
+        S: InterpreterStorage,
+        Tx: ExecutableTransaction,
+        <Tx as IntoChecked>::Metadata: CheckedMetadata,
+    {
+        vm.set_single_stepping(true);
+        let mut state = *vm.transact(tx)?.state();
+
+        loop {
+            self.observe_memory(vm.registers());
+            match state {
+                ProgramState::RunProgram(DebugEval::Breakpoint(_))
+                | ProgramState::VerifyPredicate(DebugEval::Breakpoint(_)) => {
+                    self.instructions = self.instructions.saturating_add(1);
+                    state = vm.resume()?;
+                }
+                _ => break,
+            }
+        }
+        vm.set_single_stepping(false);
+
+        Ok(StateTransition::new(
+            state,
+            vm.transaction().clone(),
+            vm.receipts().to_vec(),
+        ))
+    }
+
+    /// The stack grows from the beginning of the memory, and the heap grows
+    /// from its end, so the used memory is defined by `$sp` and `$hp`.
+    fn observe_memory(&mut self, registers: &[Word]) {
+        let stack = registers[RegId::SP];
+        let heap = VM_MAX_RAM.saturating_sub(registers[RegId::HP]);
+        self.memory_watermark = self.memory_watermark.max(stack.saturating_add(heap));
+    }
+
+    pub fn into_vm_metrics(self) -> VmMetrics {
+        VmMetrics {
+            instructions: self.instructions,
+            memory_watermark: self.memory_watermark,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::fuel_vm::consts::VM_REGISTER_COUNT;
+
+    #[test]
+    fn memory_watermark_is_the_highest_usage_of_stack_and_heap() {
+        // Given
+        let mut meter = VmMeter::default();
+        let mut registers = [0; VM_REGISTER_COUNT];
+        registers[RegId::HP] = VM_MAX_RAM;
+
+        // When
+        registers[RegId::SP] = 100;
+        meter.observe_memory(&registers);
+        registers[RegId::HP] = VM_MAX_RAM.saturating_sub(50);
+        meter.observe_memory(&registers);
+        registers[RegId::SP] = 10;
+        meter.observe_memory(&registers);
+
+        // Then
+        assert_eq!(meter.into_vm_metrics().memory_watermark, 150);
+    }
+}
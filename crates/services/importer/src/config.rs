@@ -6,6 +6,9 @@ pub struct Config {
     pub max_block_notify_buffer: usize,
     pub metrics: bool,
     pub chain_id: ChainId,
+    /// Verifies the transactions root and count of the block before the execution
+    /// and reports the first mismatching header field after the execution.
+    pub strict_header_verification: bool,
 }
 
 impl Config {
@@ -14,6 +17,7 @@ impl Config {
             max_block_notify_buffer: 1 << 10,
             metrics: false,
             chain_id: chain_config.consensus_parameters.chain_id,
+            strict_header_verification: false,
         }
     }
 }
@@ -25,6 +29,7 @@ impl Default for Config {
             max_block_notify_buffer: 1,
             metrics: false,
             chain_id: ChainId::default(),
+            strict_header_verification: false,
         }
     }
 }
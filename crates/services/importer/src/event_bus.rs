@@ -0,0 +1,196 @@
+//! The events of the importer. Each type of the event has its own bounded channel,
+//! and each subscriber has its own position in it. The slow subscriber skips the
+//! oldest events instead of blocking the importer, and the number of the skipped
+//! events is reported by the importer metrics.
+//!
+//! The subscribers of the [`BlockCommitted`] events hold the import result, and the
+//! importer doesn't commit the next block until all of them release the previous one.
+//! The services that only need the changes of the UTXO state should subscribe to the
+//! [`StateDiffAvailable`] events, which don't hold the importer.
+
+use fuel_core_metrics::importer::importer_metrics;
+use fuel_core_types::services::block_importer::{
+    SharedImportResult,
+    SharedStateDiff,
+};
+use tokio::sync::broadcast::{
+    self,
+    error::{
+        RecvError,
+        TryRecvError,
+    },
+};
+
+/// The block is committed into the database.
+pub type BlockCommitted = SharedImportResult;
+
+/// The changes of the UTXO state made by the committed block.
+pub type StateDiffAvailable = SharedStateDiff;
+
+/// The event published by the importer.
+pub trait ImporterEvent: Clone + Send + Sync + 'static {
+    /// The name of the event used in the logs.
+    const NAME: &'static str;
+
+    /// Returns the channel of the event.
+    fn channel(bus: &EventBus) -> &broadcast::Sender<Self>;
+
+    /// Reports that the subscriber skipped `skipped` events.
+    fn lagged(skipped: u64);
+}
+
+impl ImporterEvent for BlockCommitted {
+    const NAME: &'static str = "block committed";
+
+    fn channel(bus: &EventBus) -> &broadcast::Sender<Self> {
+        &bus.block_committed
+    }
+
+    fn lagged(skipped: u64) {
+        importer_metrics()
+            .block_committed_lagged_events
+            .inc_by(skipped);
+    }
+}
+
+impl ImporterEvent for StateDiffAvailable {
+    const NAME: &'static str = "state diff";
+
+    fn channel(bus: &EventBus) -> &broadcast::Sender<Self> {
+        &bus.state_diff
+    }
+
+    fn lagged(skipped: u64) {
+        importer_metrics().state_diff_lagged_events.inc_by(skipped);
+    }
+}
+
+/// The channels of the importer events.
+#[derive(Clone)]
+pub struct EventBus {
+    block_committed: broadcast::Sender<BlockCommitted>,
+    state_diff: broadcast::Sender<StateDiffAvailable>,
+}
+
+impl EventBus {
+    /// Creates the bus where each channel keeps up to `capacity` events.
+    pub fn new(capacity: usize) -> Self {
+        let (block_committed, _) = broadcast::channel(capacity);
+        let (state_diff, _) = broadcast::channel(capacity);
+        Self {
+            block_committed,
+            state_diff,
+        }
+    }
+
+    pub fn subscribe<E: ImporterEvent>(&self) -> Subscription<E> {
+        Subscription {
+            receiver: E::channel(self).subscribe(),
+        }
+    }
+
+    /// Returns `true` if somebody is subscribed to the events of the type `E`.
+    pub fn has_subscribers<E: ImporterEvent>(&self) -> bool {
+        E::channel(self).receiver_count() > 0
+    }
+
+    pub fn publish<E: ImporterEvent>(&self, event: E) {
+        // The error means that there are no subscribers.
+        let _ = E::channel(self).send(event);
+    }
+}
+
+/// The subscription to the events of the type `E`.
+pub struct Subscription<E> {
+    receiver: broadcast::Receiver<E>,
+}
+
+impl<E: ImporterEvent> Subscription<E> {
+    /// Receives the next event. Returns `None` when the importer is dropped.
+    /// If the subscriber lagged behind, the skipped events are reported,
+    /// and the oldest available event is returned.
+    pub async fn recv(&mut self) -> Option<E> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(event) => return Some(event),
+                Err(RecvError::Lagged(skipped)) => report_lag::<E>(skipped),
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Receives the next event if it is available.
+    pub fn try_recv(&mut self) -> Result<E, TryRecvError> {
+        loop {
+            match self.receiver.try_recv() {
+                Err(TryRecvError::Lagged(skipped)) => report_lag::<E>(skipped),
+                result => return result,
+            }
+        }
+    }
+}
+
+fn report_lag<E: ImporterEvent>(skipped: u64) {
+    tracing::warn!(
+        "The subscriber of the {} events skipped {skipped} events",
+        E::NAME
+    );
+    E::lagged(skipped);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_types::services::block_importer::{
+        ImportResult,
+        StateDiff,
+    };
+    use std::sync::Arc;
+
+    fn state_diff(height: u32) -> StateDiffAvailable {
+        Arc::new(StateDiff {
+            block_height: height.into(),
+            block_id: Default::default(),
+            events: vec![],
+        })
+    }
+
+    #[tokio::test]
+    async fn slow_subscriber_skips_oldest_events() {
+        // Given
+        let bus = EventBus::new(2);
+        let mut slow = bus.subscribe::<StateDiffAvailable>();
+        let lagged_before = importer_metrics().state_diff_lagged_events.get();
+
+        // When
+        for height in 1..=3 {
+            bus.publish(state_diff(height));
+        }
+
+        // Then
+        let event = slow.recv().await.unwrap();
+        assert_eq!(event.block_height, 2u32.into());
+        let event = slow.try_recv().unwrap();
+        assert_eq!(event.block_height, 3u32.into());
+        assert_eq!(slow.try_recv().unwrap_err(), TryRecvError::Empty);
+        assert!(importer_metrics().state_diff_lagged_events.get() > lagged_before);
+    }
+
+    #[tokio::test]
+    async fn subscriptions_of_different_events_are_independent() {
+        // Given
+        let bus = EventBus::new(1);
+        let mut blocks = bus.subscribe::<BlockCommitted>();
+        let mut diffs = bus.subscribe::<StateDiffAvailable>();
+
+        // When
+        bus.publish(state_diff(1));
+        bus.publish(state_diff(2));
+        bus.publish::<BlockCommitted>(Arc::new(ImportResult::default()));
+
+        // Then
+        assert!(blocks.try_recv().is_ok());
+        assert_eq!(diffs.try_recv().unwrap().block_height, 2u32.into());
+        assert!(matches!(blocks.try_recv(), Err(TryRecvError::Empty)));
+    }
+}
@@ -1,4 +1,11 @@
 use crate::{
+    event_bus::{
+        BlockCommitted,
+        EventBus,
+        ImporterEvent,
+        StateDiffAvailable,
+        Subscription,
+    },
     ports,
     ports::{
         BlockVerifier,
@@ -19,6 +26,7 @@ use fuel_core_types::{
             Consensus,
             Sealed,
         },
+        header::HeaderField,
         primitives::BlockId,
         SealedBlock,
     },
@@ -29,7 +37,7 @@ use fuel_core_types::{
     services::{
         block_importer::{
             ImportResult,
-            SharedImportResult,
+            StateDiff,
             UncommittedResult,
         },
         executor,
@@ -49,10 +57,12 @@ use std::{
         UNIX_EPOCH,
     },
 };
-use tokio::sync::{
-    broadcast,
-    oneshot,
-    TryAcquireError,
+use tokio::{
+    sync::{
+        oneshot,
+        TryAcquireError,
+    },
+    task::JoinHandle,
 };
 
 #[cfg(test)]
@@ -83,6 +93,8 @@ pub enum Error {
     FailedVerification(anyhow::Error),
     #[display(fmt = "The execution of the block failed: {_0}.")]
     FailedExecution(executor::Error),
+    #[display(fmt = "The block header field `{_0}` doesn't match the recomputed value.")]
+    HeaderFieldMismatch(HeaderField),
     #[display(
         fmt = "It is not possible to skip transactions during importing of the block."
     )]
@@ -91,6 +103,8 @@ pub enum Error {
     ExecuteGenesis,
     #[display(fmt = "The database already contains the data at the height {_0}.")]
     NotUnique(BlockHeight),
+    #[display(fmt = "The commit of the previous block failed: {_0}")]
+    PreviousCommitFailed(String),
     #[from]
     StorageError(StorageError),
     UnsupportedConsensusVariant(String),
@@ -114,31 +128,44 @@ pub struct Importer<D, E, V> {
     executor: Arc<E>,
     verifier: Arc<V>,
     chain_id: ChainId,
-    broadcast: broadcast::Sender<SharedImportResult>,
+    strict_header_verification: bool,
+    events: EventBus,
     /// The channel to notify about the end of the processing of the previous block by all listeners.
     /// It is used to await until all receivers of the notification process the `SharedImportResult`
     /// before starting committing a new block.
-    prev_block_process_result: Mutex<Option<oneshot::Receiver<()>>>,
+    prev_block_process_result: Arc<Mutex<Option<oneshot::Receiver<()>>>>,
+    /// The block imported by the last [`Importer::execute_and_commit_pipelined`], which is committed
+    /// in the background while the next block is executed on top of its view.
+    pending_commit: Mutex<Option<PendingCommit<D>>>,
     guard: tokio::sync::Semaphore,
 }
 
+/// The block imported by the commit pipeline, which is not in the database yet.
+struct PendingCommit<Database> {
+    /// The view of the database with the changes of the block.
+    view: Database,
+    /// Resolves when the block is committed into the database and the listeners are notified.
+    committed: JoinHandle<Result<(), Error>>,
+}
+
 impl<D, E, V> Importer<D, E, V> {
     pub fn new(config: Config, database: D, executor: E, verifier: V) -> Self {
-        let (broadcast, _) = broadcast::channel(config.max_block_notify_buffer);
-
         Self {
             database,
             executor: Arc::new(executor),
             verifier: Arc::new(verifier),
             chain_id: config.chain_id,
-            broadcast,
+            strict_header_verification: config.strict_header_verification,
+            events: EventBus::new(config.max_block_notify_buffer),
             prev_block_process_result: Default::default(),
+            pending_commit: Default::default(),
             guard: tokio::sync::Semaphore::new(1),
         }
     }
 
-    pub fn subscribe(&self) -> broadcast::Receiver<SharedImportResult> {
-        self.broadcast.subscribe()
+    /// Subscribes to the events of the type `Event`, e.g. [`BlockCommitted`].
+    pub fn subscribe<Event: ImporterEvent>(&self) -> Subscription<Event> {
+        self.events.subscribe()
     }
 
     pub(crate) fn lock(&self) -> Result<tokio::sync::SemaphorePermit, Error> {
@@ -154,6 +181,25 @@ impl<D, E, V> Importer<D, E, V> {
             }
         }
     }
+
+    /// Waits until the block imported by the last [`Importer::execute_and_commit_pipelined`]
+    /// is committed into the database and the listeners are notified about it.
+    ///
+    /// Returns an error if the commit of that block failed.
+    pub async fn await_pending_commit(&self) -> Result<(), Error> {
+        let pending = self.pending_commit.lock().expect("poisoned").take();
+        match pending {
+            Some(pending) => await_commit(pending.committed).await,
+            None => Ok(()),
+        }
+    }
+}
+
+async fn await_commit(committed: JoinHandle<Result<(), Error>>) -> Result<(), Error> {
+    committed
+        .await
+        .map_err(|err| Error::PreviousCommitFailed(err.to_string()))?
+        .map_err(|err| Error::PreviousCommitFailed(err.to_string()))
 }
 
 impl<D, E, V> Importer<D, E, V>
@@ -181,19 +227,24 @@ where
         ExecutorDatabase: ports::ExecutorDatabase,
     {
         let _guard = self.lock()?;
-        // It is safe to unwrap the channel because we have the `_guard`.
+        self.await_pending_commit().await?;
+        self.await_prev_block_processing().await;
+
+        self._commit_result(result)
+    }
+
+    /// Awaits until all receivers of the notification process the previous block.
+    /// It should be called only with the lock acquired.
+    async fn await_prev_block_processing(&self) {
         let previous_block_result = self
             .prev_block_process_result
             .lock()
             .expect("poisoned")
             .take();
 
-        // Await until all receivers of the notification process the result.
         if let Some(channel) = previous_block_result {
             let _ = channel.await;
         }
-
-        self._commit_result(result)
     }
 
     /// The method commits the result of the block execution and notifies about a new imported block.
@@ -210,6 +261,19 @@ where
         &self,
         result: UncommittedResult<StorageTransaction<ExecutorDatabase>>,
     ) -> Result<(), Error>
+    where
+        ExecutorDatabase: ports::ExecutorDatabase,
+    {
+        let (result, db_tx) = self.prepare_commit(result)?;
+        commit_and_notify(result, db_tx, &self.events, &self.prev_block_process_result)
+    }
+
+    /// Checks that the result of the execution can be committed on top of the database,
+    /// and stores the block in the database transaction.
+    fn prepare_commit<ExecutorDatabase>(
+        &self,
+        result: UncommittedResult<StorageTransaction<ExecutorDatabase>>,
+    ) -> Result<(ImportResult, StorageTransaction<ExecutorDatabase>), Error>
     where
         ExecutorDatabase: ports::ExecutorDatabase,
     {
@@ -279,29 +343,7 @@ where
             return Err(Error::NotUnique(expected_next_height))
         }
 
-        db_tx.commit()?;
-
-        // update the importer metrics after the block is successfully committed
-        importer_metrics()
-            .block_height
-            .set(*actual_next_height.deref() as i64);
-        let current_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs_f64();
-        importer_metrics()
-            .latest_block_import_timestamp
-            .set(current_time);
-
-        tracing::info!("Committed block {:#x}", result.sealed_block.entity.id());
-
-        // The `tokio::sync::oneshot::Sender` is used to notify about the end
-        // of the processing of a new block by all listeners.
-        let (sender, receiver) = oneshot::channel();
-        let _ = self.broadcast.send(Arc::new(Awaiter::new(result, sender)));
-        *self.prev_block_process_result.lock().expect("poisoned") = Some(receiver);
-
-        Ok(())
+        Ok((result, db_tx))
     }
 
     /// Should only be called once after startup to set importer metrics to their initial values
@@ -353,14 +395,20 @@ where
         Self::verify_and_execute_block_inner(
             self.executor.clone(),
             self.verifier.clone(),
+            self.strict_header_verification,
             sealed_block,
+            None,
         )
     }
 
+    /// Executes the block on top of the `view`, if it is set,
+    /// or on top of the database otherwise.
     fn verify_and_execute_block_inner(
         executor: Arc<E>,
         verifier: Arc<V>,
+        strict_header_verification: bool,
         sealed_block: SealedBlock,
+        view: Option<E::Database>,
     ) -> Result<UncommittedResult<StorageTransaction<E::Database>>, Error> {
         let consensus = sealed_block.consensus;
         let block = sealed_block.entity;
@@ -378,6 +426,17 @@ where
             return Err(Error::ExecuteGenesis)
         }
 
+        // The transactions root and count don't require the execution,
+        // so verify them before spending resources on it.
+        if strict_header_verification {
+            let field = block
+                .header()
+                .mismatched_transactions_field(block.transactions());
+            if let Some(field) = field {
+                return Err(Error::HeaderFieldMismatch(field))
+            }
+        }
+
         // TODO: Pass `block` into `ExecutionBlock::Validation` by ref
         let (
             ExecutionResult {
@@ -387,10 +446,17 @@ where
                 events,
             },
             db_tx,
-        ) = executor
-            .execute_without_commit(block)
-            .map_err(Error::FailedExecution)?
-            .into();
+        ) = match view {
+            Some(view) => executor.execute_without_commit_on(view, block),
+            None => executor.execute_without_commit(block),
+        }
+        .map_err(|err| match err {
+            executor::Error::BlockHeaderMismatch(field) if strict_header_verification => {
+                Error::HeaderFieldMismatch(field)
+            }
+            err => Error::FailedExecution(err),
+        })?
+        .into();
 
         // If we skipped transaction, it means that the block is invalid.
         if !skipped_transactions.is_empty() {
@@ -417,51 +483,205 @@ where
 
 impl<IDatabase, E, V> Importer<IDatabase, E, V>
 where
-    IDatabase: ImporterDatabase + 'static,
-    E: Executor + 'static,
+    IDatabase: ports::ExecutorDatabase + 'static,
+    E: Executor<Database = IDatabase> + 'static,
     V: BlockVerifier + 'static,
 {
     /// The method validates the `Block` fields and commits the `SealedBlock`.
     /// It is a combination of the [`Importer::verify_and_execute_block`] and [`Importer::commit_result`].
+    ///
+    /// Returns once the block is committed into the database and the listeners are notified.
+    #[tracing::instrument(
+        skip_all,
+        fields(height = %sealed_block.entity.header().height()),
+        err
+    )]
     pub async fn execute_and_commit(
         &self,
         sealed_block: SealedBlock,
     ) -> Result<(), Error> {
         let _guard = self.lock()?;
+        self.await_pending_commit().await?;
 
+        let (result, execute_time) = self.execute_on(sealed_block, None).await;
+        let result = result?;
+
+        self.await_prev_block_processing().await;
+
+        let start = Instant::now();
+        let (result, db_tx) = self.prepare_commit(result)?;
+        let commit_result = commit_and_notify(
+            result,
+            db_tx,
+            &self.events,
+            &self.prev_block_process_result,
+        );
+        let time = execute_time + start.elapsed().as_secs_f64();
+        importer_metrics().execute_and_commit_duration.observe(time);
+        commit_result
+    }
+
+    /// Imports the block like [`Importer::execute_and_commit`], but pipelines the import
+    /// with the commit. Used by the synchronization of the blocks from the network.
+    ///
+    /// The method returns once the block is checked against the database, while its
+    /// changes are committed in the background. The next call executes its block on top
+    /// of the view of the pending changes, and commits it only after the previous block
+    /// is committed. The listeners are notified about the block after its commit. If the
+    /// commit fails, the next call returns the error. Use [`Importer::await_pending_commit`]
+    /// to wait for the commit of the last block, e.g. before the shutdown.
+    #[tracing::instrument(
+        skip_all,
+        fields(height = %sealed_block.entity.header().height()),
+        err
+    )]
+    pub async fn execute_and_commit_pipelined(
+        &self,
+        sealed_block: SealedBlock,
+    ) -> Result<(), Error> {
+        let _guard = self.lock()?;
+
+        let pending = self.pending_commit.lock().expect("poisoned").take();
+        let (view, committed) = match pending {
+            Some(PendingCommit { view, committed }) => (Some(view), Some(committed)),
+            None => (None, None),
+        };
+
+        let (result, execute_time) = self.execute_on(sealed_block, view).await;
+
+        // The block is executed on top of the previous one, so it can't be committed
+        // before the previous block is in the database, even if the execution failed.
+        if let Some(committed) = committed {
+            await_commit(committed).await?;
+        }
+        let result = result?;
+
+        self.await_prev_block_processing().await;
+
+        let start = Instant::now();
+        let (result, db_tx) = self.prepare_commit(result)?;
+        let view = self.executor.pending_view(db_tx.as_ref())?;
+        let prepare_time = start.elapsed().as_secs_f64();
+
+        let events = self.events.clone();
+        let prev_block_process_result = self.prev_block_process_result.clone();
+        let span = tracing::Span::current();
+        let committed = tokio::task::spawn_blocking(move || {
+            let _entered = span.enter();
+            let start = Instant::now();
+            let result =
+                commit_and_notify(result, db_tx, &events, &prev_block_process_result);
+            let time = execute_time + prepare_time + start.elapsed().as_secs_f64();
+            importer_metrics().execute_and_commit_duration.observe(time);
+            result
+        });
+        *self.pending_commit.lock().expect("poisoned") =
+            Some(PendingCommit { view, committed });
+
+        Ok(())
+    }
+
+    /// Verifies and executes the block on the rayon thread pool, on top of the `view`
+    /// if it is set. Returns the result with the time of the execution.
+    async fn execute_on(
+        &self,
+        sealed_block: SealedBlock,
+        view: Option<IDatabase>,
+    ) -> (
+        Result<UncommittedResult<StorageTransaction<IDatabase>>, Error>,
+        f64,
+    ) {
         let executor = self.executor.clone();
         let verifier = self.verifier.clone();
-        let (result, execute_time) = tokio_rayon::spawn_fifo(|| {
+        let strict_header_verification = self.strict_header_verification;
+        // The execution happens on the rayon thread pool, so it should enter
+        // the span of the import explicitly.
+        let span = tracing::Span::current();
+        tokio_rayon::spawn_fifo(move || {
+            let _entered = span.enter();
             let start = Instant::now();
-            let result =
-                Self::verify_and_execute_block_inner(executor, verifier, sealed_block);
+            let result = Self::verify_and_execute_block_inner(
+                executor,
+                verifier,
+                strict_header_verification,
+                sealed_block,
+                view,
+            );
             let execute_time = start.elapsed().as_secs_f64();
+            if let Ok(result) = &result {
+                observe_block_execution(result.result(), execute_time);
+            }
             (result, execute_time)
         })
-        .await;
+        .await
+    }
+}
 
-        let result = result?;
+/// Reports the execution time of the validated block, to spot the blocks
+/// with pathologically slow transactions.
+fn observe_block_execution(result: &ImportResult, execute_time: f64) {
+    let transactions = result.sealed_block.entity.transactions().len().max(1);
+    importer_metrics()
+        .block_execution_duration
+        .observe(execute_time);
+    importer_metrics()
+        .block_execution_duration_per_transaction
+        .observe(execute_time / transactions as f64);
+}
 
-        // It is safe to unwrap the channel because we have the `_guard`.
-        let previous_block_result = self
-            .prev_block_process_result
-            .lock()
-            .expect("poisoned")
-            .take();
+/// Commits the block into the database and notifies the listeners about it.
+fn commit_and_notify<ExecutorDatabase>(
+    result: ImportResult,
+    db_tx: StorageTransaction<ExecutorDatabase>,
+    events: &EventBus,
+    prev_block_process_result: &Mutex<Option<oneshot::Receiver<()>>>,
+) -> Result<(), Error> {
+    db_tx.commit()?;
+
+    // update the importer metrics after the block is successfully committed
+    let height = *result.sealed_block.entity.header().height();
+    importer_metrics().block_height.set(*height.deref() as i64);
+    let da_height = result.sealed_block.entity.header().da_height;
+    importer_metrics()
+        .da_height
+        .set(i64::try_from(da_height.0).unwrap_or(i64::MAX));
+    let current_time = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs_f64();
+    importer_metrics()
+        .latest_block_import_timestamp
+        .set(current_time);
+    let block_time = result.sealed_block.entity.header().time().to_unix() as f64;
+    importer_metrics()
+        .block_time_drift
+        .set(block_time - current_time);
+    for vm_metrics in result
+        .tx_status
+        .iter()
+        .filter_map(|status| status.vm_metrics)
+    {
+        importer_metrics()
+            .transaction_instructions
+            .observe(vm_metrics.instructions as f64);
+        importer_metrics()
+            .transaction_memory_watermark
+            .observe(vm_metrics.memory_watermark as f64);
+    }
 
-        // Await until all receivers of the notification process the result.
-        if let Some(channel) = previous_block_result {
-            let _ = channel.await;
-        }
+    tracing::info!("Committed block {:#x}", result.sealed_block.entity.id());
 
-        let start = Instant::now();
-        let commit_result = self._commit_result(result);
-        let commit_time = start.elapsed().as_secs_f64();
-        let time = execute_time + commit_time;
-        importer_metrics().execute_and_commit_duration.observe(time);
-        // return execution result
-        commit_result
+    if events.has_subscribers::<StateDiffAvailable>() {
+        events.publish::<StateDiffAvailable>(Arc::new(StateDiff::from(&result)));
     }
+
+    // The `tokio::sync::oneshot::Sender` is used to notify about the end
+    // of the processing of a new block by all listeners.
+    let (sender, receiver) = oneshot::channel();
+    events.publish::<BlockCommitted>(Arc::new(Awaiter::new(result, sender)));
+    *prev_block_process_result.lock().expect("poisoned") = Some(receiver);
+
+    Ok(())
 }
 
 trait ShouldBeUnique {
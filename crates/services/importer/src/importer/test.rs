@@ -1,4 +1,8 @@
 use crate::{
+    event_bus::{
+        BlockCommitted,
+        StateDiffAvailable,
+    },
     importer::Error,
     ports::{
         ExecutorDatabase,
@@ -6,6 +10,7 @@ use crate::{
         MockBlockVerifier,
         MockExecutor,
     },
+    Config,
     Importer,
 };
 use anyhow::anyhow;
@@ -21,6 +26,7 @@ use fuel_core_types::{
     blockchain::{
         block::Block,
         consensus::Consensus,
+        header::HeaderField,
         SealedBlock,
     },
     fuel_tx::TxId,
@@ -41,6 +47,13 @@ use fuel_core_types::{
         Uncommitted,
     },
 };
+use std::sync::{
+    atomic::{
+        AtomicU32,
+        Ordering,
+    },
+    Arc,
+};
 use test_case::test_case;
 use tokio::sync::{
     broadcast::error::TryRecvError,
@@ -366,7 +379,7 @@ async fn commit_result_assert(
         StorageTransaction::new(executor_db),
     );
 
-    let mut imported_blocks = importer.subscribe();
+    let mut imported_blocks = importer.subscribe::<BlockCommitted>();
     let result = importer.commit_result(uncommitted_result).await;
 
     if result.is_ok() {
@@ -391,7 +404,7 @@ async fn execute_and_commit_assert(
     let expected_to_broadcast = sealed_block.clone();
     let importer = Importer::new(Default::default(), underlying_db, executor, verifier);
 
-    let mut imported_blocks = importer.subscribe();
+    let mut imported_blocks = importer.subscribe::<BlockCommitted>();
     let result = importer.execute_and_commit(sealed_block).await;
 
     if result.is_ok() {
@@ -559,3 +572,170 @@ fn verify_and_execute_allowed_when_locked() {
     let _guard = importer.lock();
     assert!(importer.verify_and_execute_block(poa_block(13)).is_ok());
 }
+
+fn strict_importer(
+    executor: MockExecutor,
+) -> Importer<MockDatabase, MockExecutor, MockBlockVerifier> {
+    let config = Config {
+        strict_header_verification: true,
+        ..Default::default()
+    };
+    Importer::new(config, MockDatabase::default(), executor, verifier(ok(())))
+}
+
+#[test]
+fn strict_verification_rejects_block_with_invalid_transactions_root_before_execution() {
+    let mut block = poa_block(13);
+    block
+        .entity
+        .header_mut()
+        .set_transaction_root([1; 32].into());
+    block.entity.header_mut().recalculate_metadata();
+    // The executor panics if it is called, because it doesn't have any expectations.
+    let importer = strict_importer(MockExecutor::default());
+
+    let result = importer.verify_and_execute_block(block).map(|_| ());
+
+    assert_eq!(
+        result,
+        Err(Error::HeaderFieldMismatch(HeaderField::TransactionsRoot))
+    );
+}
+
+#[test]
+fn strict_verification_reports_mismatching_field_from_execution() {
+    // The block with valid transactions root and count passes the pre-execution checks.
+    let block = SealedBlock {
+        entity: Block::new(Default::default(), vec![], &[]),
+        consensus: Consensus::PoA(Default::default()),
+    };
+    let importer = strict_importer(executor(
+        || {
+            Err(ExecutorError::BlockHeaderMismatch(
+                HeaderField::MessageReceiptRoot,
+            ))
+        },
+        MockDatabase::default(),
+    ));
+
+    let result = importer.verify_and_execute_block(block).map(|_| ());
+
+    assert_eq!(
+        result,
+        Err(Error::HeaderFieldMismatch(HeaderField::MessageReceiptRoot))
+    );
+}
+
+#[tokio::test]
+async fn execute_and_commit_pipelined_executes_next_block_on_top_of_pending_commit() {
+    // Given
+    let committed_height = Arc::new(AtomicU32::new(0));
+    let executor_db = |previous_height: u32| {
+        let committed_height = committed_height.clone();
+        let mut db = MockDatabase::default();
+        db.expect_latest_block_height()
+            .returning(move || Ok(Some(previous_height.into())));
+        db.expect_store_new_block().returning(|_, _| Ok(true));
+        db.expect_commit().times(1).returning(move || {
+            committed_height.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        });
+        db
+    };
+    let execution_result = |height: u32,
+                            database: MockDatabase|
+     -> ExecutorResult<
+        Uncommitted<ExecutionResult, StorageTransaction<MockDatabase>>,
+    > {
+        Ok(Uncommitted::new(
+            ExecutionResult {
+                block: poa_block(height).entity,
+                skipped_transactions: vec![],
+                tx_status: vec![],
+                events: vec![],
+            },
+            StorageTransaction::new(database),
+        ))
+    };
+    let mut executor = MockExecutor::default();
+    let first_block_db = executor_db(0);
+    executor
+        .expect_execute_without_commit()
+        .times(1)
+        .return_once(move |_| execution_result(1, first_block_db));
+    let second_block_db = executor_db(1);
+    executor
+        .expect_execute_without_commit_on()
+        .times(1)
+        .return_once(move |_, _| execution_result(2, second_block_db));
+    executor
+        .expect_pending_view()
+        .times(2)
+        .returning(|_| Ok(MockDatabase::default()));
+    let mut verifier = MockBlockVerifier::default();
+    verifier
+        .expect_verify_block_fields()
+        .returning(|_, _| Ok(()));
+    let underlying_db = {
+        let committed_height = committed_height.clone();
+        let mut db = MockDatabase::default();
+        db.expect_latest_block_height()
+            .returning(move || Ok(Some(committed_height.load(Ordering::SeqCst).into())));
+        db
+    };
+    let importer = Importer::new(Default::default(), underlying_db, executor, verifier);
+    let mut imported_blocks = importer.subscribe::<BlockCommitted>();
+    // The next block is committed only after the listeners process the previous one
+    let imported_blocks = tokio::spawn(async move {
+        let mut blocks = vec![];
+        for _ in 0..2 {
+            let imported = imported_blocks.recv().await.unwrap();
+            blocks.push(imported.sealed_block.clone());
+        }
+        blocks
+    });
+
+    // When
+    importer
+        .execute_and_commit_pipelined(poa_block(1))
+        .await
+        .unwrap();
+    importer
+        .execute_and_commit_pipelined(poa_block(2))
+        .await
+        .unwrap();
+    importer.await_pending_commit().await.unwrap();
+
+    // Then
+    assert_eq!(committed_height.load(Ordering::SeqCst), 2);
+    let imported_blocks = imported_blocks.await.unwrap();
+    assert_eq!(imported_blocks, vec![poa_block(1), poa_block(2)]);
+}
+
+#[tokio::test]
+async fn state_diff_subscriber_does_not_hold_next_commit() {
+    // Given
+    let importer =
+        Importer::new(Default::default(), underlying_db(ok(Some(0)))(), (), ());
+    let mut diffs = importer.subscribe::<StateDiffAvailable>();
+    let result = |height: u32| {
+        UncommittedResult::new(
+            ImportResult::new_from_local(poa_block(height), vec![], vec![]),
+            StorageTransaction::new(executor_db(ok(Some(0)), ok(true), 1)()),
+        )
+    };
+    importer.commit_result(result(1)).await.unwrap();
+
+    // When
+    let next_commit = tokio::time::timeout(
+        std::time::Duration::from_secs(1),
+        importer.commit_result(result(1)),
+    )
+    .await;
+
+    // Then
+    assert!(next_commit.is_ok(), "The importer waits for the subscriber");
+    let diff = diffs.try_recv().unwrap();
+    assert_eq!(diff.block_height, 1u32.into());
+    assert_eq!(diff.block_id, poa_block(1).entity.id());
+}
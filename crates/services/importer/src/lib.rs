@@ -4,10 +4,15 @@
 #![deny(warnings)]
 
 pub mod config;
+pub mod event_bus;
 pub mod importer;
 pub mod ports;
 
 pub use config::Config;
+pub use event_bus::{
+    BlockCommitted,
+    StateDiffAvailable,
+};
 pub use importer::Importer;
 
 #[cfg(test)]
@@ -30,6 +30,19 @@ pub trait Executor: Send + Sync {
         &self,
         block: Block,
     ) -> ExecutorResult<UncommittedResult<StorageTransaction<Self::Database>>>;
+
+    /// Returns the view of the database with the `uncommitted` changes of the previous block
+    /// on top. The view stays consistent while the previous block is committed.
+    fn pending_view(&self, uncommitted: &Self::Database)
+        -> StorageResult<Self::Database>;
+
+    /// Executes the block like [`Executor::execute_without_commit`], but on top of the `view`
+    /// returned by [`Executor::pending_view`].
+    fn execute_without_commit_on(
+        &self,
+        view: Self::Database,
+        block: Block,
+    ) -> ExecutorResult<UncommittedResult<StorageTransaction<Self::Database>>>;
 }
 
 /// The database port used by the block importer.
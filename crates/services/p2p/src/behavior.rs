@@ -10,7 +10,9 @@ use crate::{
         topics::GossipTopic,
     },
     heartbeat,
+    peer_exchange,
     peer_report,
+    protocol_version::ProtocolVersions,
     request_response::messages::{
         RequestMessage,
         ResponseMessage,
@@ -37,6 +39,10 @@ use libp2p::{
     PeerId,
 };
 
+/// The prefix of the protocol version advertised with the identify protocol,
+/// followed by the versions of the gossip topics.
+pub const IDENTIFY_PROTOCOL: &str = "/fuel/1.0";
+
 /// Handles all p2p protocols needed for Fuel.
 #[derive(NetworkBehaviour)]
 pub struct FuelBehaviour {
@@ -60,6 +66,9 @@ pub struct FuelBehaviour {
     /// Node discovery
     discovery: discovery::Behaviour,
 
+    /// Exchanges the known-good addresses with the connected peers
+    peer_exchange: peer_exchange::Behaviour,
+
     /// RequestResponse protocol
     request_response: request_response::Behaviour<PostcardCodec>,
 }
@@ -97,7 +106,7 @@ impl FuelBehaviour {
 
         let identify = {
             let identify_config = identify::Config::new(
-                "/fuel/1.0".to_string(),
+                ProtocolVersions::local().to_string(),
                 p2p_config.keypair.public(),
             );
             if let Some(interval) = p2p_config.identify_interval {
@@ -112,8 +121,18 @@ impl FuelBehaviour {
             BlockHeight::default(),
         );
 
-        let req_res_protocol =
-            core::iter::once((codec.get_req_res_protocol(), ProtocolSupport::Full));
+        // The guarded node doesn't share the addresses of its reserved nodes
+        let peer_exchange = peer_exchange::Behaviour::new(
+            p2p_config.keypair.clone(),
+            p2p_config
+                .peer_exchange_interval
+                .filter(|_| !p2p_config.reserved_nodes_only_mode),
+        );
+
+        let req_res_protocol = codec
+            .get_req_res_protocols()
+            .into_iter()
+            .map(|protocol| (protocol, ProtocolSupport::Full));
 
         let req_res_config = request_response::Config::default();
         req_res_config
@@ -134,6 +153,7 @@ impl FuelBehaviour {
             blocked_peer: Default::default(),
             identify,
             heartbeat,
+            peer_exchange,
         }
     }
 
@@ -201,6 +221,14 @@ impl FuelBehaviour {
         None
     }
 
+    pub fn send_peer_exchange_sample(
+        &mut self,
+        channel: ResponseChannel<peer_exchange::EncodedSample>,
+        peers: &[PeerId],
+    ) {
+        self.peer_exchange.send_sample(channel, peers)
+    }
+
     pub fn update_block_height(&mut self, block_height: BlockHeight) {
         self.heartbeat.update_block_height(block_height);
     }
@@ -142,6 +142,9 @@ impl GossipsubCodec for PostcardCodec {
     fn encode(&self, data: Self::RequestMessage) -> Result<Vec<u8>, io::Error> {
         let encoded_data = match data {
             GossipsubBroadcastRequest::NewTx(tx) => postcard::to_stdvec(&*tx),
+            GossipsubBroadcastRequest::NewTxAnnouncement(tx_id) => {
+                postcard::to_stdvec(&tx_id)
+            }
         };
 
         encoded_data.map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
@@ -154,6 +157,9 @@ impl GossipsubCodec for PostcardCodec {
     ) -> Result<Self::ResponseMessage, io::Error> {
         let decoded_response = match gossipsub_tag {
             GossipTopicTag::NewTx => GossipsubMessage::NewTx(deserialize(encoded_data)?),
+            GossipTopicTag::NewTxAnnouncement => {
+                GossipsubMessage::NewTxAnnouncement(deserialize(encoded_data)?)
+            }
         };
 
         Ok(decoded_response)
@@ -161,17 +167,28 @@ impl GossipsubCodec for PostcardCodec {
 }
 
 impl NetworkCodec for PostcardCodec {
-    fn get_req_res_protocol(&self) -> <Self as request_response::Codec>::Protocol {
-        MessageExchangePostcardProtocol {}
+    fn get_req_res_protocols(&self) -> Vec<<Self as request_response::Codec>::Protocol> {
+        MessageExchangePostcardProtocol::SUPPORTED.to_vec()
     }
 }
 
-#[derive(Default, Debug, Clone)]
-pub struct MessageExchangePostcardProtocol;
+/// The versions of the request-response protocol.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageExchangePostcardProtocol {
+    #[default]
+    V1,
+}
+
+impl MessageExchangePostcardProtocol {
+    /// The versions supported by the node, the newest first.
+    pub const SUPPORTED: [Self; 1] = [Self::V1];
+}
 
 impl AsRef<str> for MessageExchangePostcardProtocol {
     fn as_ref(&self) -> &str {
-        REQUEST_RESPONSE_PROTOCOL_ID
+        match self {
+            Self::V1 => REQUEST_RESPONSE_PROTOCOL_ID,
+        }
     }
 }
 
@@ -1,15 +1,23 @@
 use crate::{
+    codecs::postcard::MessageExchangePostcardProtocol,
     gossipsub::config::default_gossipsub_config,
     heartbeat,
+    peer_exchange::PEER_EXCHANGE_PROTOCOL,
     peer_manager::ConnectionState,
+    protocol_version::ProtocolVersions,
+    Protocol,
     TryPeerId,
 };
 use fuel_core_types::blockchain::consensus::Genesis;
 
+use futures::future::Either;
 use libp2p::{
     core::{
         muxing::StreamMuxerBox,
-        transport::Boxed,
+        transport::{
+            timeout::TransportTimeout,
+            Boxed,
+        },
     },
     gossipsub,
     identity::{
@@ -17,6 +25,8 @@ use libp2p::{
         Keypair,
     },
     noise,
+    pnet::PnetConfig,
+    quic,
     tcp::{
         self,
         tokio,
@@ -42,10 +52,16 @@ use std::{
 
 use self::{
     connection_tracker::ConnectionTracker,
-    fuel_authenticated::FuelAuthenticated,
+    fuel_authenticated::{
+        authenticate_quic,
+        Approver,
+        FuelAuthenticated,
+    },
     fuel_upgrade::Checksum,
     guarded_node::GuardedNode,
 };
+pub use libp2p::pnet::PreSharedKey;
+
 mod connection_tracker;
 mod fuel_authenticated;
 pub(crate) mod fuel_upgrade;
@@ -86,6 +102,10 @@ pub struct Config<State = Initialized> {
     /// The TCP port that Swarm listens on
     pub tcp_port: u16,
 
+    /// The UDP port that Swarm listens on with the QUIC transport.
+    /// If it is not set, the QUIC transport is disabled.
+    pub quic_port: Option<u16>,
+
     /// Max Size of a Block in bytes
     pub max_block_size: usize,
     pub max_headers_per_request: u32,
@@ -95,6 +115,10 @@ pub struct Config<State = Initialized> {
     pub enable_mdns: bool,
     pub allow_private_addresses: bool,
     pub random_walk: Option<Duration>,
+    /// The interval of the peer exchange with the connected peers.
+    /// If it is not set, or the node is in the reserved nodes only mode,
+    /// the peer exchange protocol is disabled.
+    pub peer_exchange_interval: Option<Duration>,
     pub connection_idle_timeout: Option<Duration>,
 
     // 'Reserved Nodes' mode
@@ -103,6 +127,10 @@ pub struct Config<State = Initialized> {
     /// Should the node only accept connection requests from the Reserved Nodes
     pub reserved_nodes_only_mode: bool,
 
+    /// The pre-shared key of the private network. If it is set, the node only
+    /// connects to the nodes with the same key, see [`PreSharedKey`].
+    pub private_network_key: Option<PreSharedKey>,
+
     // `PeerManager` fields
     /// Max number of unique peers connected
     /// This number should be at least number of `mesh_n` from `Gossipsub` configuration.
@@ -120,6 +148,12 @@ pub struct Config<State = Initialized> {
 
     // `Gossipsub` config
     pub gossipsub_config: gossipsub::Config,
+    /// Gossip the ids of the transactions instead of the full transactions.
+    /// The peers request the bodies of the transactions they don't know yet.
+    pub tx_announcements: bool,
+    /// How long the gossiped transactions are kept to serve the requests
+    /// of the peers and to deduplicate them.
+    pub tx_gossip_window: Duration,
 
     pub heartbeat_config: heartbeat::Config,
 
@@ -139,6 +173,11 @@ pub struct Config<State = Initialized> {
     /// Enables prometheus metrics for this fuel-service
     pub metrics: bool,
 
+    /// Connects the node to the in-memory network instead of TCP.
+    /// Used by the tests running several nodes in the same process.
+    #[cfg(feature = "test-helpers")]
+    pub memory_network: Option<crate::memory_network::MemoryNetwork>,
+
     /// It is the state of the config initialization. Everyone can create an instance of the `Self`
     /// with the `NotInitialized` state. But it can be set into the `Initialized` state only with
     /// the `init` method.
@@ -157,6 +196,12 @@ impl Config<NotInitialized> {
     pub fn init(self, genesis: Genesis) -> anyhow::Result<Config<Initialized>> {
         use fuel_core_chain_config::GenesisCommitment;
 
+        if self.quic_port.is_some() && self.private_network_key.is_some() {
+            return Err(anyhow::anyhow!(
+                "The QUIC transport doesn't support the private network key"
+            ))
+        }
+
         Ok(Config {
             keypair: self.keypair,
             network_name: self.network_name,
@@ -164,6 +209,7 @@ impl Config<NotInitialized> {
             address: self.address,
             public_address: self.public_address,
             tcp_port: self.tcp_port,
+            quic_port: self.quic_port,
             max_block_size: self.max_block_size,
             max_headers_per_request: self.max_headers_per_request,
             bootstrap_nodes: self.bootstrap_nodes,
@@ -172,12 +218,16 @@ impl Config<NotInitialized> {
             max_connections_per_peer: self.max_connections_per_peer,
             allow_private_addresses: self.allow_private_addresses,
             random_walk: self.random_walk,
+            peer_exchange_interval: self.peer_exchange_interval,
             connection_idle_timeout: self.connection_idle_timeout,
             reserved_nodes: self.reserved_nodes,
             reserved_nodes_only_mode: self.reserved_nodes_only_mode,
+            private_network_key: self.private_network_key,
             identify_interval: self.identify_interval,
             info_interval: self.info_interval,
             gossipsub_config: self.gossipsub_config,
+            tx_announcements: self.tx_announcements,
+            tx_gossip_window: self.tx_gossip_window,
             heartbeat_config: self.heartbeat_config,
             set_request_timeout: self.set_request_timeout,
             set_connection_keep_alive: self.set_connection_keep_alive,
@@ -185,6 +235,8 @@ impl Config<NotInitialized> {
             heartbeat_max_avg_interval: self.heartbeat_max_time_since_last,
             heartbeat_max_time_since_last: self.heartbeat_max_time_since_last,
             metrics: self.metrics,
+            #[cfg(feature = "test-helpers")]
+            memory_network: self.memory_network,
             state: Initialized(()),
         })
     }
@@ -212,6 +264,7 @@ impl Config<NotInitialized> {
             address: IpAddr::V4(Ipv4Addr::from([0, 0, 0, 0])),
             public_address: None,
             tcp_port: 0,
+            quic_port: None,
             max_block_size: MAX_RESPONSE_SIZE,
             max_headers_per_request: MAX_HEADERS_PER_REQUEST,
             bootstrap_nodes: vec![],
@@ -220,10 +273,14 @@ impl Config<NotInitialized> {
             max_connections_per_peer: 3,
             allow_private_addresses: true,
             random_walk: Some(Duration::from_millis(500)),
+            peer_exchange_interval: Some(Duration::from_secs(60)),
             connection_idle_timeout: Some(Duration::from_secs(120)),
             reserved_nodes: vec![],
             reserved_nodes_only_mode: false,
+            private_network_key: None,
             gossipsub_config: default_gossipsub_config(),
+            tx_announcements: false,
+            tx_gossip_window: Duration::from_secs(60),
             heartbeat_config: heartbeat::Config::default(),
             set_request_timeout: REQ_RES_TIMEOUT,
             set_connection_keep_alive: REQ_RES_TIMEOUT,
@@ -233,11 +290,57 @@ impl Config<NotInitialized> {
             info_interval: Some(Duration::from_secs(3)),
             identify_interval: Some(Duration::from_secs(5)),
             metrics: false,
+            #[cfg(feature = "test-helpers")]
+            memory_network: None,
             state: NotInitialized,
         }
     }
 }
 
+impl<State> Config<State> {
+    /// The id of the node in the p2p network derived from the `keypair`.
+    pub fn peer_id(&self) -> PeerId {
+        self.keypair.public().to_peer_id()
+    }
+
+    /// The addresses of the node advertised to other nodes. It is the `public_address`
+    /// if it is set, otherwise the addresses that the node listens on.
+    pub fn advertised_addresses(&self) -> Vec<Multiaddr> {
+        if let Some(public_address) = &self.public_address {
+            return vec![public_address.clone()]
+        }
+
+        let mut tcp_multiaddr = Multiaddr::from(self.address);
+        tcp_multiaddr.push(Protocol::Tcp(self.tcp_port));
+        let mut addresses = vec![tcp_multiaddr];
+
+        if let Some(quic_port) = self.quic_port {
+            let mut quic_multiaddr = Multiaddr::from(self.address);
+            quic_multiaddr.push(Protocol::Udp(quic_port));
+            quic_multiaddr.push(Protocol::QuicV1);
+            addresses.push(quic_multiaddr);
+        }
+        addresses
+    }
+
+    /// The versions of the protocols that the node speaks with other nodes.
+    pub fn protocol_versions(&self) -> Vec<String> {
+        let mut protocols = vec![
+            ProtocolVersions::local().to_string(),
+            heartbeat::HEARTBEAT_PROTOCOL.to_string(),
+        ];
+        protocols.extend(
+            MessageExchangePostcardProtocol::SUPPORTED
+                .iter()
+                .map(|protocol| protocol.as_ref().to_string()),
+        );
+        if self.peer_exchange_interval.is_some() && !self.reserved_nodes_only_mode {
+            protocols.push(PEER_EXCHANGE_PROTOCOL.to_string());
+        }
+        protocols
+    }
+}
+
 #[cfg(any(feature = "test-helpers", test))]
 impl Config<Initialized> {
     pub fn default_initialized(network_name: &str) -> Self {
@@ -248,9 +351,10 @@ impl Config<Initialized> {
 }
 
 /// Transport for libp2p communication:
-/// TCP/IP, Websocket
+/// TCP/IP, Websocket, or the in-memory network if it is set with the `test-helpers` feature
 /// Noise as encryption layer
 /// mplex or yamux for multiplexing
+/// QUIC, if the `quic_port` is set
 pub(crate) fn build_transport_function(
     p2p_config: &Config,
 ) -> (
@@ -260,7 +364,7 @@ pub(crate) fn build_transport_function(
     let connection_state = ConnectionState::new();
     let kept_connection_state = connection_state.clone();
     let transport_function = move |keypair: &Keypair| {
-        let transport = {
+        let tcp_transport = || {
             let generate_tcp_transport = || {
                 tokio::Transport::new(tcp::Config::new().port_reuse(true).nodelay(true))
             };
@@ -270,9 +374,34 @@ pub(crate) fn build_transport_function(
             let ws_tcp = libp2p::websocket::WsConfig::new(generate_tcp_transport())
                 .or_transport(tcp);
 
-            libp2p::dns::tokio::Transport::system(ws_tcp).unwrap()
-        }
-        .upgrade(libp2p::core::upgrade::Version::V1Lazy);
+            let transport = libp2p::dns::tokio::Transport::system(ws_tcp).unwrap();
+
+            // The private network handshake happens before any other protocol,
+            // so the nodes without the key can't even negotiate the encryption.
+            match p2p_config.private_network_key {
+                Some(key) => transport
+                    .and_then(move |socket, _| PnetConfig::new(key).handshake(socket))
+                    .map(|socket, _| Either::Left(socket))
+                    .boxed(),
+                None => transport.map(|socket, _| Either::Right(socket)).boxed(),
+            }
+        };
+
+        #[cfg(feature = "test-helpers")]
+        let transport = if let Some(memory_network) = &p2p_config.memory_network {
+            memory_network
+                .transport()
+                .map(|socket, _| Either::Right(socket))
+                .boxed()
+        } else {
+            tcp_transport()
+                .map(|socket, _| Either::Left(socket))
+                .boxed()
+        };
+        #[cfg(not(feature = "test-helpers"))]
+        let transport = tcp_transport();
+
+        let transport = transport.upgrade(libp2p::core::upgrade::Version::V1Lazy);
 
         let noise_authenticated =
             noise::Config::new(keypair).expect("Noise key generation failed");
@@ -292,15 +421,17 @@ pub(crate) fn build_transport_function(
 
             let fuel_authenticated = FuelAuthenticated::new(
                 noise_authenticated,
-                guarded_node,
+                guarded_node.clone(),
                 p2p_config.checksum,
             );
 
-            transport
+            let transport = transport
                 .authenticate(fuel_authenticated)
                 .multiplex(multiplex_config)
                 .timeout(TRANSPORT_TIMEOUT)
-                .boxed()
+                .boxed();
+            let transport = with_memory_network(transport, p2p_config, keypair);
+            with_quic_transport(transport, p2p_config, keypair, guarded_node)
         } else {
             let connection_tracker = ConnectionTracker::new(
                 &p2p_config.reserved_nodes,
@@ -309,21 +440,78 @@ pub(crate) fn build_transport_function(
 
             let fuel_authenticated = FuelAuthenticated::new(
                 noise_authenticated,
-                connection_tracker,
+                connection_tracker.clone(),
                 p2p_config.checksum,
             );
 
-            transport
+            let transport = transport
                 .authenticate(fuel_authenticated)
                 .multiplex(multiplex_config)
                 .timeout(TRANSPORT_TIMEOUT)
-                .boxed()
+                .boxed();
+            let transport = with_memory_network(transport, p2p_config, keypair);
+            with_quic_transport(transport, p2p_config, keypair, connection_tracker)
         }
     };
 
     (transport_function, kept_connection_state)
 }
 
+/// Applies the conditions of the in-memory network to the connections, if it is used.
+#[cfg(feature = "test-helpers")]
+fn with_memory_network(
+    transport: Boxed<(PeerId, StreamMuxerBox)>,
+    p2p_config: &Config,
+    keypair: &Keypair,
+) -> Boxed<(PeerId, StreamMuxerBox)> {
+    match &p2p_config.memory_network {
+        Some(memory_network) => {
+            memory_network.apply(keypair.public().to_peer_id(), transport)
+        }
+        None => transport,
+    }
+}
+
+#[cfg(not(feature = "test-helpers"))]
+fn with_memory_network(
+    transport: Boxed<(PeerId, StreamMuxerBox)>,
+    _: &Config,
+    _: &Keypair,
+) -> Boxed<(PeerId, StreamMuxerBox)> {
+    transport
+}
+
+/// Adds the QUIC transport next to the `transport` if the `quic_port` is set.
+/// The QUIC connections are approved by the same `approver`.
+fn with_quic_transport<A>(
+    transport: Boxed<(PeerId, StreamMuxerBox)>,
+    p2p_config: &Config,
+    keypair: &Keypair,
+    approver: A,
+) -> Boxed<(PeerId, StreamMuxerBox)>
+where
+    A: Approver + Clone + Send + 'static,
+{
+    if p2p_config.quic_port.is_none() {
+        return transport
+    }
+
+    let checksum = p2p_config.checksum;
+    let quic_transport = quic::tokio::Transport::new(quic::Config::new(keypair))
+        .and_then(move |connection, endpoint| {
+            authenticate_quic(connection, approver, checksum, endpoint.is_dialer())
+        })
+        .map(|(peer_id, connection), _| (peer_id, StreamMuxerBox::new(connection)));
+    let quic_transport = TransportTimeout::new(quic_transport, TRANSPORT_TIMEOUT);
+
+    // The QUIC transport goes first because it rejects the TCP addresses as not supported,
+    // while the DNS transport reports the QUIC addresses as the dial errors.
+    quic_transport
+        .or_transport(transport)
+        .map(|output, _| output.into_inner())
+        .boxed()
+}
+
 fn peer_ids_set_from(multiaddr: &[Multiaddr]) -> HashSet<PeerId> {
     multiaddr
         .iter()
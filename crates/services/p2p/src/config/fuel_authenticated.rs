@@ -2,13 +2,16 @@ use crate::config::fuel_upgrade::Checksum;
 use futures::{
     future,
     AsyncRead,
+    AsyncReadExt,
     AsyncWrite,
+    AsyncWriteExt,
     Future,
     TryFutureExt,
 };
 use libp2p::{
     self,
     core::{
+        muxing::StreamMuxerExt,
         upgrade::{
             InboundConnectionUpgrade,
             OutboundConnectionUpgrade,
@@ -16,9 +19,13 @@ use libp2p::{
         UpgradeInfo,
     },
     noise,
+    quic,
     PeerId,
 };
-use std::pin::Pin;
+use std::{
+    io,
+    pin::Pin,
+};
 
 pub(crate) trait Approver {
     /// Allows Peer connection based on it's PeerId and the Approver's knowledge of the Connection State
@@ -108,3 +115,42 @@ where
         )
     }
 }
+
+/// QUIC brings its own encryption and multiplexing, so the connections bypass
+/// the [`FuelAuthenticated`] upgrade. Instead, the dialer opens the first stream
+/// of the connection, and both sides check the `approver` and exchange the `checksum`
+/// over it before handing over the connection to the swarm.
+pub(crate) async fn authenticate_quic<A: Approver>(
+    (remote_peer_id, mut connection): (PeerId, quic::Connection),
+    approver: A,
+    checksum: Checksum,
+    is_dialer: bool,
+) -> io::Result<(PeerId, quic::Connection)> {
+    if !approver.allow_peer(&remote_peer_id) {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("The peer `{remote_peer_id}` is not allowed to connect"),
+        ))
+    }
+
+    let mut stream = if is_dialer {
+        future::poll_fn(|cx| connection.poll_outbound_unpin(cx)).await
+    } else {
+        future::poll_fn(|cx| connection.poll_inbound_unpin(cx)).await
+    }
+    .map_err(|e| io::Error::new(io::ErrorKind::ConnectionAborted, e))?;
+
+    stream.write_all(checksum.as_ref()).await?;
+    stream.flush().await?;
+    let mut remote_checksum = [0u8; 32];
+    stream.read_exact(&mut remote_checksum).await?;
+    if remote_checksum != checksum.as_ref() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("The peer `{remote_peer_id}` has a different checksum"),
+        ))
+    }
+    stream.close().await?;
+
+    Ok((remote_peer_id, connection))
+}
@@ -13,7 +13,6 @@ use libp2p::gossipsub::{
     MetricsConfig,
     PeerScoreParams,
     PeerScoreThresholds,
-    Topic,
     TopicScoreParams,
 };
 use sha2::{
@@ -22,9 +21,9 @@ use sha2::{
 };
 use std::time::Duration;
 
-use super::topics::{
-    GossipTopic,
-    NEW_TX_GOSSIP_TOPIC,
+use super::{
+    messages::GossipTopicTag,
+    topics::versioned_topic,
 };
 
 // The number of slots in each epoch.
@@ -48,6 +47,9 @@ const MESH_SIZE: usize = 8;
 // The weight applied to the score for delivering new transactions.
 const NEW_TX_GOSSIP_WEIGHT: f64 = 0.05;
 
+// The weight applied to the score for delivering new transaction announcements.
+const NEW_TX_ANNOUNCEMENT_GOSSIP_WEIGHT: f64 = 0.05;
+
 // The threshold for a peer's score to be considered for greylisting.
 // If a peer's score falls below this value, they will be greylisted.
 // Greylisting is a lighter form of banning, where the peer's messages might be ignored or given lower priority,
@@ -56,8 +58,15 @@ pub const GRAYLIST_THRESHOLD: f64 = -16000.0;
 
 /// Creates `GossipsubConfigBuilder` with few of the Gossipsub values already defined
 pub fn default_gossipsub_builder() -> gossipsub::ConfigBuilder {
+    // The author is a part of the id, so the announcements of the same transaction
+    // by different peers are delivered, and the body can be requested from any of them.
     let gossip_message_id = move |message: &gossipsub::Message| {
-        MessageId::from(&Sha256::digest(&message.data)[..])
+        let mut hasher = Sha256::new();
+        if let Some(source) = &message.source {
+            hasher.update(source.to_bytes());
+        }
+        hasher.update(&message.data);
+        MessageId::from(&hasher.finalize()[..])
     };
 
     let mut builder = gossipsub::ConfigBuilder::default();
@@ -226,18 +235,26 @@ fn initialize_gossipsub(gossipsub: &mut gossipsub::Behaviour, p2p_config: &Confi
         .with_peer_score(peer_score_params, peer_score_thresholds)
         .expect("gossipsub initialized with peer score");
 
-    let topics = vec![(NEW_TX_GOSSIP_TOPIC, NEW_TX_GOSSIP_WEIGHT)];
+    let topics = vec![
+        (GossipTopicTag::NewTx, NEW_TX_GOSSIP_WEIGHT),
+        (
+            GossipTopicTag::NewTxAnnouncement,
+            NEW_TX_ANNOUNCEMENT_GOSSIP_WEIGHT,
+        ),
+    ];
 
-    // subscribe to gossipsub topics with the network name suffix
-    for (topic, weight) in topics {
-        let t: GossipTopic = Topic::new(format!("{}/{}", topic, p2p_config.network_name));
+    // subscribe to all supported versions of gossipsub topics with the network name suffix
+    for (tag, weight) in topics {
+        for version in tag.versions() {
+            let t = versioned_topic(tag.name(), version, &p2p_config.network_name);
 
-        gossipsub
-            .set_topic_params(t.clone(), initialize_topic_score_params(weight))
-            .expect("First time initializing Topic Score");
+            gossipsub
+                .set_topic_params(t.clone(), initialize_topic_score_params(weight))
+                .expect("First time initializing Topic Score");
 
-        gossipsub
-            .subscribe(&t)
-            .expect("Subscription to Topic: {topic} successful");
+            gossipsub
+                .subscribe(&t)
+                .expect("Subscription to Topic: {topic} successful");
+        }
     }
 }
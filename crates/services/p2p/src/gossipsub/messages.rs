@@ -1,6 +1,19 @@
-use std::sync::Arc;
+use std::{
+    ops::RangeInclusive,
+    sync::Arc,
+};
 
-use fuel_core_types::fuel_tx::Transaction;
+use super::topics::{
+    NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC,
+    NEW_TX_ANNOUNCEMENT_GOSSIP_VERSIONS,
+    NEW_TX_GOSSIP_TOPIC,
+    NEW_TX_GOSSIP_VERSIONS,
+};
+use crate::protocol_version::Version;
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    TxId,
+};
 
 use serde::{
     Deserialize,
@@ -12,6 +25,27 @@ use serde::{
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum GossipTopicTag {
     NewTx,
+    NewTxAnnouncement,
+}
+
+impl GossipTopicTag {
+    pub const ALL: [GossipTopicTag; 2] = [Self::NewTx, Self::NewTxAnnouncement];
+
+    /// The name of the topic without the version and the network name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::NewTx => NEW_TX_GOSSIP_TOPIC,
+            Self::NewTxAnnouncement => NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC,
+        }
+    }
+
+    /// The versions of the topic supported by the node.
+    pub fn versions(&self) -> RangeInclusive<Version> {
+        match self {
+            Self::NewTx => NEW_TX_GOSSIP_VERSIONS,
+            Self::NewTxAnnouncement => NEW_TX_ANNOUNCEMENT_GOSSIP_VERSIONS,
+        }
+    }
 }
 
 /// Takes `Arc<T>` and wraps it in a matching GossipsubBroadcastRequest
@@ -20,9 +54,23 @@ pub enum GossipTopicTag {
 #[derive(Debug, Clone)]
 pub enum GossipsubBroadcastRequest {
     NewTx(Arc<Transaction>),
+    /// Announces the id of the transaction instead of the whole transaction,
+    /// the peers request the body if they don't have it.
+    NewTxAnnouncement(TxId),
+}
+
+impl GossipsubBroadcastRequest {
+    /// The topic of the request.
+    pub fn tag(&self) -> GossipTopicTag {
+        match self {
+            Self::NewTx(_) => GossipTopicTag::NewTx,
+            Self::NewTxAnnouncement(_) => GossipTopicTag::NewTxAnnouncement,
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum GossipsubMessage {
     NewTx(Transaction),
+    NewTxAnnouncement(TxId),
 }
@@ -3,54 +3,85 @@ use libp2p::gossipsub::{
     Topic,
     TopicHash,
 };
+use std::{
+    collections::HashMap,
+    ops::RangeInclusive,
+};
 
 use super::messages::{
     GossipTopicTag,
     GossipsubBroadcastRequest,
 };
+use crate::protocol_version::{
+    Version,
+    LEGACY_VERSION,
+};
 
 pub type GossipTopic = Sha256Topic;
 pub const NEW_TX_GOSSIP_TOPIC: &str = "new_tx";
+pub const NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC: &str = "new_tx_announcement";
+
+/// The versions of the topics supported by the node.
+pub const NEW_TX_GOSSIP_VERSIONS: RangeInclusive<Version> =
+    LEGACY_VERSION..=LEGACY_VERSION;
+pub const NEW_TX_ANNOUNCEMENT_GOSSIP_VERSIONS: RangeInclusive<Version> =
+    LEGACY_VERSION..=LEGACY_VERSION;
+
+/// Returns the topic of the `version` with the network name suffix.
+/// The legacy version keeps the name used before the versioning.
+pub fn versioned_topic(name: &str, version: Version, network_name: &str) -> GossipTopic {
+    if version == LEGACY_VERSION {
+        Topic::new(format!("{name}/{network_name}"))
+    } else {
+        Topic::new(format!("{name}/v{version}/{network_name}"))
+    }
+}
 
 /// Holds used Gossipsub Topics
-/// Each field contains TopicHash and GossipTopic itself
+/// Maps the TopicHash of each supported version of the topics to its tag
 /// in order to avoid converting GossipTopic to TopicHash on each received message
 #[derive(Debug)]
 pub struct GossipsubTopics {
-    new_tx_topic: (TopicHash, GossipTopic),
+    network_name: String,
+    tags: HashMap<TopicHash, GossipTopicTag>,
 }
 
 impl GossipsubTopics {
     pub fn new(network_name: &str) -> Self {
-        let new_tx_topic = Topic::new(format!("{NEW_TX_GOSSIP_TOPIC}/{network_name}"));
+        let tags = GossipTopicTag::ALL
+            .into_iter()
+            .flat_map(|tag| {
+                tag.versions().map(move |version| {
+                    (
+                        versioned_topic(tag.name(), version, network_name).hash(),
+                        tag,
+                    )
+                })
+            })
+            .collect();
 
         Self {
-            new_tx_topic: (new_tx_topic.hash(), new_tx_topic),
+            network_name: network_name.to_string(),
+            tags,
         }
     }
 
-    /// Given a TopicHash it will return a matching GossipTopicTag
+    /// Given a TopicHash of any supported version it will return a matching GossipTopicTag
     pub fn get_gossipsub_tag(
         &self,
         incoming_topic: &TopicHash,
     ) -> Option<GossipTopicTag> {
-        let GossipsubTopics { new_tx_topic } = &self;
-
-        match incoming_topic {
-            hash if hash == &new_tx_topic.0 => Some(GossipTopicTag::NewTx),
-            _ => None,
-        }
+        self.tags.get(incoming_topic).copied()
     }
 
-    /// Given a `GossipsubBroadcastRequest` retruns a `GossipTopic`
+    /// Given a `GossipsubBroadcastRequest` retruns a `GossipTopic` of the `version`
     /// which is broadcast over the network with the serialized inner value of `GossipsubBroadcastRequest`
     pub fn get_gossipsub_topic(
         &self,
         outgoing_request: &GossipsubBroadcastRequest,
+        version: Version,
     ) -> GossipTopic {
-        match outgoing_request {
-            GossipsubBroadcastRequest::NewTx(_) => self.new_tx_topic.1.clone(),
-        }
+        versioned_topic(outgoing_request.tag().name(), version, &self.network_name)
     }
 }
 
@@ -69,9 +100,6 @@ mod tests {
 
         let gossipsub_topics = GossipsubTopics::new(network_name);
 
-        // Test matching Topic Hashes
-        assert_eq!(gossipsub_topics.new_tx_topic.0, new_tx_topic.hash());
-
         // Test given a TopicHash that `get_gossipsub_tag()` returns matching `GossipTopicTag`
         assert_eq!(
             gossipsub_topics.get_gossipsub_tag(&new_tx_topic.hash()),
@@ -82,8 +110,36 @@ mod tests {
         let broadcast_req =
             GossipsubBroadcastRequest::NewTx(Arc::new(Transaction::default_test_tx()));
         assert_eq!(
-            gossipsub_topics.get_gossipsub_topic(&broadcast_req).hash(),
+            gossipsub_topics
+                .get_gossipsub_topic(&broadcast_req, LEGACY_VERSION)
+                .hash(),
             new_tx_topic.hash()
         );
+
+        // Test the announcements of the transactions
+        let new_tx_announcement_topic: GossipTopic =
+            Topic::new(format!("{NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC}/{network_name}"));
+        assert_eq!(
+            gossipsub_topics.get_gossipsub_tag(&new_tx_announcement_topic.hash()),
+            Some(GossipTopicTag::NewTxAnnouncement)
+        );
+        let broadcast_req = GossipsubBroadcastRequest::NewTxAnnouncement([1; 32].into());
+        assert_eq!(
+            gossipsub_topics
+                .get_gossipsub_topic(&broadcast_req, LEGACY_VERSION)
+                .hash(),
+            new_tx_announcement_topic.hash()
+        );
+    }
+
+    #[test]
+    fn newer_versions_of_topics_have_own_names() {
+        let network_name = "fuel_test_network";
+
+        let legacy = versioned_topic(NEW_TX_GOSSIP_TOPIC, LEGACY_VERSION, network_name);
+        let second = versioned_topic(NEW_TX_GOSSIP_TOPIC, 2, network_name);
+
+        assert_eq!(legacy.to_string(), format!("new_tx/{network_name}"));
+        assert_eq!(second.to_string(), format!("new_tx/v2/{network_name}"));
     }
 }
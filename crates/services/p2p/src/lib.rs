@@ -7,10 +7,15 @@ pub mod config;
 pub mod discovery;
 pub mod gossipsub;
 pub mod heartbeat;
+#[cfg(feature = "test-helpers")]
+pub mod memory_network;
 pub mod p2p_service;
+pub mod peer_exchange;
 pub mod peer_manager;
 pub mod peer_report;
 pub mod ports;
+pub mod protocol_version;
+pub mod recent_transactions;
 pub mod request_response;
 pub mod service;
 
@@ -0,0 +1,338 @@
+//! The in-memory network of the nodes running in the same process, with the scripted
+//! partitions, latency and message loss. It is used instead of TCP to test the nodes
+//! against the network failures.
+//!
+//! The conditions are shared by all nodes of the network and can be changed at any time:
+//! - The peers in different partitions can't connect, and their existing connections
+//!   are closed within [`PARTITION_CHECK_INTERVAL`].
+//! - The data read from each connection is delayed by the latency.
+//! - The received gossip messages and requests are dropped with the probability of the loss.
+
+use futures::{
+    future,
+    ready,
+    AsyncRead,
+    AsyncWrite,
+    Future,
+};
+use libp2p::{
+    core::{
+        muxing::{
+            StreamMuxer,
+            StreamMuxerBox,
+            StreamMuxerEvent,
+            StreamMuxerExt,
+            SubstreamBox,
+        },
+        transport::{
+            Boxed,
+            MemoryTransport,
+        },
+    },
+    PeerId,
+    Transport,
+};
+use std::{
+    collections::HashMap,
+    io,
+    pin::Pin,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    task::{
+        Context,
+        Poll,
+    },
+    time::Duration,
+};
+use tokio::time::{
+    Interval,
+    Sleep,
+};
+
+/// How often the connections check that the peers are still reachable.
+pub const PARTITION_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The shared handle to the conditions of the in-memory network.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryNetwork {
+    conditions: Arc<Mutex<Conditions>>,
+}
+
+#[derive(Debug, Default)]
+struct Conditions {
+    /// The partition of each peer. The peers without the partition are in the same
+    /// implicit partition.
+    partitions: HashMap<PeerId, usize>,
+    latency: Duration,
+    loss: f64,
+}
+
+impl MemoryNetwork {
+    /// Splits the network into the `partitions`. The peers can connect only to the peers
+    /// of the same partition, and the peers missing from all partitions only to each other.
+    pub fn partition<P>(&self, partitions: impl IntoIterator<Item = P>)
+    where
+        P: IntoIterator<Item = PeerId>,
+    {
+        let partitions = partitions
+            .into_iter()
+            .enumerate()
+            .flat_map(|(index, peers)| peers.into_iter().map(move |peer| (peer, index)))
+            .collect();
+        self.update(|conditions| conditions.partitions = partitions);
+    }
+
+    /// Removes all partitions.
+    pub fn heal(&self) {
+        self.update(|conditions| conditions.partitions.clear());
+    }
+
+    /// Sets the delay of the data read from the connections.
+    pub fn set_latency(&self, latency: Duration) {
+        self.update(|conditions| conditions.latency = latency);
+    }
+
+    /// Sets the probability from `0.0` to `1.0` to lose each received message.
+    pub fn set_loss(&self, loss: f64) {
+        self.update(|conditions| conditions.loss = loss.clamp(0.0, 1.0));
+    }
+
+    /// Returns `true` if the peers can connect to each other.
+    pub fn is_reachable(&self, a: &PeerId, b: &PeerId) -> bool {
+        self.read(|conditions| {
+            conditions.partitions.get(a) == conditions.partitions.get(b)
+        })
+    }
+
+    /// Decides whether the received message is lost.
+    pub(crate) fn is_lost(&self) -> bool {
+        let loss = self.read(|conditions| conditions.loss);
+        loss > 0.0 && rand::random::<f64>() < loss
+    }
+
+    fn latency(&self) -> Duration {
+        self.read(|conditions| conditions.latency)
+    }
+
+    /// The transport of the raw connections inside the process.
+    pub(crate) fn transport(&self) -> MemoryTransport {
+        MemoryTransport::default()
+    }
+
+    /// Applies the conditions to the authenticated connections of the `local` peer.
+    pub(crate) fn apply(
+        &self,
+        local: PeerId,
+        transport: Boxed<(PeerId, StreamMuxerBox)>,
+    ) -> Boxed<(PeerId, StreamMuxerBox)> {
+        let network = self.clone();
+        transport
+            .and_then(move |(remote, muxer), _| {
+                let result = if network.is_reachable(&local, &remote) {
+                    let muxer = Muxer {
+                        inner: muxer,
+                        network: network.clone(),
+                        local,
+                        remote,
+                        check: tokio::time::interval(PARTITION_CHECK_INTERVAL),
+                    };
+                    Ok((remote, StreamMuxerBox::new(muxer)))
+                } else {
+                    Err(partitioned(&remote))
+                };
+                future::ready(result)
+            })
+            .boxed()
+    }
+
+    fn read<T>(&self, f: impl FnOnce(&Conditions) -> T) -> T {
+        let conditions = self.conditions.lock().expect("The lock is poisoned");
+        f(&conditions)
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Conditions)) {
+        let mut conditions = self.conditions.lock().expect("The lock is poisoned");
+        f(&mut conditions)
+    }
+}
+
+fn partitioned(remote: &PeerId) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::ConnectionRefused,
+        format!("The peer {remote} is in another partition"),
+    )
+}
+
+/// The connection closed when the peers are partitioned.
+struct Muxer {
+    inner: StreamMuxerBox,
+    network: MemoryNetwork,
+    local: PeerId,
+    remote: PeerId,
+    check: Interval,
+}
+
+impl Muxer {
+    fn ensure_reachable(&self) -> io::Result<()> {
+        if self.network.is_reachable(&self.local, &self.remote) {
+            Ok(())
+        } else {
+            Err(partitioned(&self.remote))
+        }
+    }
+
+    fn substream(&self, inner: SubstreamBox) -> Substream {
+        Substream {
+            inner,
+            network: self.network.clone(),
+            pending: vec![],
+            delay: None,
+        }
+    }
+}
+
+impl StreamMuxer for Muxer {
+    type Substream = Substream;
+    type Error = io::Error;
+
+    fn poll_inbound(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Self::Substream, Self::Error>> {
+        self.ensure_reachable()?;
+        let inner = ready!(self.inner.poll_inbound_unpin(cx))?;
+        Poll::Ready(Ok(self.substream(inner)))
+    }
+
+    fn poll_outbound(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<Self::Substream, Self::Error>> {
+        self.ensure_reachable()?;
+        let inner = ready!(self.inner.poll_outbound_unpin(cx))?;
+        Poll::Ready(Ok(self.substream(inner)))
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_close_unpin(cx)
+    }
+
+    fn poll(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<StreamMuxerEvent, Self::Error>> {
+        // Polls the interval until it is pending to be woken up for the next check.
+        while self.check.poll_tick(cx).is_ready() {
+            self.ensure_reachable()?;
+        }
+        self.inner.poll_unpin(cx)
+    }
+}
+
+/// The substream delaying the read data by the latency of the network.
+struct Substream {
+    inner: SubstreamBox,
+    network: MemoryNetwork,
+    /// The data read from the `inner` and not yet returned.
+    pending: Vec<u8>,
+    delay: Option<Pin<Box<Sleep>>>,
+}
+
+impl AsyncRead for Substream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = &mut *self;
+        loop {
+            if let Some(delay) = this.delay.as_mut() {
+                ready!(delay.as_mut().poll(cx));
+                this.delay = None;
+            }
+
+            if !this.pending.is_empty() {
+                let len = this.pending.len().min(buf.len());
+                buf[..len].copy_from_slice(&this.pending[..len]);
+                this.pending.drain(..len);
+                return Poll::Ready(Ok(len))
+            }
+
+            let latency = this.network.latency();
+            if latency.is_zero() {
+                return Pin::new(&mut this.inner).poll_read(cx, buf)
+            }
+
+            let mut chunk = vec![0; buf.len()];
+            let len = ready!(Pin::new(&mut this.inner).poll_read(cx, &mut chunk))?;
+            if len == 0 {
+                return Poll::Ready(Ok(0))
+            }
+            chunk.truncate(len);
+            this.pending = chunk;
+            this.delay = Some(Box::pin(tokio::time::sleep(latency)));
+        }
+    }
+}
+
+impl AsyncWrite for Substream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_close(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partitions_split_peers() {
+        // Given
+        let network = MemoryNetwork::default();
+        let [a, b, c, d] = [(); 4].map(|_| PeerId::random());
+
+        // When
+        network.partition([vec![a, b], vec![c]]);
+
+        // Then
+        assert!(network.is_reachable(&a, &b));
+        assert!(!network.is_reachable(&a, &c));
+        assert!(!network.is_reachable(&c, &d));
+
+        network.heal();
+        assert!(network.is_reachable(&a, &c));
+        assert!(network.is_reachable(&c, &d));
+    }
+
+    #[test]
+    fn loss_is_probability() {
+        let network = MemoryNetwork::default();
+        assert!(!network.is_lost());
+
+        network.set_loss(2.0);
+        assert!(network.is_lost());
+    }
+}
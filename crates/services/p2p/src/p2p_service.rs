@@ -19,11 +19,17 @@ use crate::{
         topics::GossipsubTopics,
     },
     heartbeat,
+    peer_exchange::{
+        self,
+        PeerAddresses,
+        INVALID_SAMPLE_PENALTY,
+    },
     peer_manager::{
         PeerManager,
         Punisher,
     },
     peer_report::PeerReportEvent,
+    protocol_version::ProtocolVersions,
     request_response::messages::{
         RequestError,
         RequestMessage,
@@ -41,6 +47,7 @@ use fuel_core_types::{
 };
 use futures::prelude::*;
 use libp2p::{
+    core::transport::ListenerId,
     gossipsub::{
         self,
         MessageAcceptance,
@@ -56,15 +63,27 @@ use libp2p::{
         OutboundRequestId,
         ResponseChannel,
     },
-    swarm::SwarmEvent,
+    swarm::{
+        dial_opts::{
+            DialOpts,
+            PeerCondition,
+        },
+        SwarmEvent,
+    },
     Multiaddr,
     PeerId,
     Swarm,
     SwarmBuilder,
 };
-use rand::seq::IteratorRandom;
+use rand::seq::{
+    IteratorRandom,
+    SliceRandom,
+};
 use std::{
-    collections::HashMap,
+    collections::{
+        HashMap,
+        HashSet,
+    },
     time::Duration,
 };
 use tokio::sync::broadcast;
@@ -94,6 +113,13 @@ pub struct FuelP2PService {
     /// The TCP port that Swarm listens on
     tcp_port: u16,
 
+    /// The UDP port for the QUIC transport, if it is enabled
+    quic_port: Option<u16>,
+
+    /// The in-memory network used instead of TCP and QUIC, if it is set
+    #[cfg(feature = "test-helpers")]
+    memory_network: Option<crate::memory_network::MemoryNetwork>,
+
     /// Swarm handler for FuelBehaviour
     swarm: Swarm<FuelBehaviour>,
 
@@ -120,6 +146,9 @@ pub struct FuelP2PService {
     /// Whether or not metrics collection is enabled
     metrics: bool,
 
+    /// Whether the node is a member of the private network
+    private_network: bool,
+
     /// Holds peers' information, and manages existing connections
     peer_manager: PeerManager,
 }
@@ -127,11 +156,16 @@ pub struct FuelP2PService {
 #[derive(Debug)]
 struct GossipsubData {
     topics: GossipsubTopics,
+    /// The versions of the topics supported by the node
+    versions: ProtocolVersions,
 }
 
 impl GossipsubData {
     pub fn with_topics(topics: GossipsubTopics) -> Self {
-        Self { topics }
+        Self {
+            topics,
+            versions: ProtocolVersions::local(),
+        }
     }
 }
 
@@ -151,6 +185,7 @@ pub enum FuelP2PEvent {
         message: FuelGossipsubMessage,
     },
     InboundRequestMessage {
+        peer_id: PeerId,
         request_id: InboundRequestId,
         request_message: RequestMessage,
     },
@@ -194,6 +229,13 @@ impl FuelP2PService {
         let local_peer_id = swarm.local_peer_id().to_owned();
 
         let metrics = config.metrics;
+        let private_network = config.private_network_key.is_some();
+        if let Some(key) = config.private_network_key {
+            tracing::info!(
+                "The p2p service only accepts the peers of the private network `{}`",
+                key.fingerprint()
+            );
+        }
 
         if let Some(public_address) = config.public_address.clone() {
             swarm.add_external_address(public_address);
@@ -209,12 +251,16 @@ impl FuelP2PService {
             local_peer_id,
             local_address: config.address,
             tcp_port: config.tcp_port,
+            quic_port: config.quic_port,
+            #[cfg(feature = "test-helpers")]
+            memory_network: config.memory_network.clone(),
             swarm,
             network_codec: codec,
             outbound_requests_table: HashMap::default(),
             inbound_requests_table: HashMap::default(),
             network_metadata,
             metrics,
+            private_network,
             peer_manager: PeerManager::new(
                 reserved_peers_updates,
                 reserved_peers,
@@ -226,7 +272,9 @@ impl FuelP2PService {
 
     pub async fn start(&mut self) -> anyhow::Result<()> {
         // set up node's address to listen on
-        let listen_multiaddr = {
+        let listen_multiaddr = if self.uses_memory_network() {
+            Multiaddr::from(Protocol::Memory(0))
+        } else {
             let mut m = Multiaddr::from(self.local_address);
             m.push(Protocol::Tcp(self.tcp_port));
             m
@@ -238,22 +286,35 @@ impl FuelP2PService {
         );
 
         // start listening at the given address
-        self.swarm.listen_on(listen_multiaddr)?;
+        let mut listeners = HashSet::from([self.swarm.listen_on(listen_multiaddr)?]);
+
+        if let Some(quic_port) = self.quic_port.filter(|_| !self.uses_memory_network()) {
+            let mut quic_multiaddr = Multiaddr::from(self.local_address);
+            quic_multiaddr.push(Protocol::Udp(quic_port));
+            quic_multiaddr.push(Protocol::QuicV1);
+
+            tracing::info!("The p2p service accepts QUIC on the `{quic_multiaddr}`");
+            listeners.insert(self.swarm.listen_on(quic_multiaddr)?);
+        }
 
         // Wait for listener addresses.
-        tokio::time::timeout(Duration::from_secs(5), self.await_listeners_address())
-            .await
-            .map_err(|_| {
-                anyhow::anyhow!("P2PService should get a new address within 5 seconds")
-            })?;
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            self.await_listeners_address(listeners),
+        )
+        .await
+        .map_err(|_| {
+            anyhow::anyhow!("P2PService should get a new address within 5 seconds")
+        })?;
         Ok(())
     }
 
-    async fn await_listeners_address(&mut self) {
-        loop {
-            if let SwarmEvent::NewListenAddr { .. } = self.swarm.select_next_some().await
+    async fn await_listeners_address(&mut self, mut listeners: HashSet<ListenerId>) {
+        while !listeners.is_empty() {
+            if let SwarmEvent::NewListenAddr { listener_id, .. } =
+                self.swarm.select_next_some().await
             {
-                break;
+                listeners.remove(&listener_id);
             }
         }
     }
@@ -279,11 +340,14 @@ impl FuelP2PService {
         &mut self,
         message: GossipsubBroadcastRequest,
     ) -> Result<MessageId, PublishError> {
-        let topic = self
-            .network_metadata
-            .gossipsub_data
-            .topics
-            .get_gossipsub_topic(&message);
+        let gossipsub_data = &self.network_metadata.gossipsub_data;
+        let tag = message.tag();
+        // Without the common version the oldest supported version reaches the most peers
+        let version = gossipsub_data
+            .versions
+            .negotiate(tag, self.peer_manager.get_protocol_versions())
+            .unwrap_or_else(|| *gossipsub_data.versions.topic(tag).start());
+        let topic = gossipsub_data.topics.get_gossipsub_topic(&message, version);
 
         match self.network_codec.encode(message) {
             Ok(encoded_data) => self
@@ -438,6 +502,26 @@ impl FuelP2PService {
                 );
                 None
             }
+            SwarmEvent::IncomingConnectionError {
+                send_back_addr,
+                error,
+                ..
+            } if self.private_network => {
+                tracing::warn!(
+                    "Rejected the connection from `{send_back_addr}` with `{error}`, \
+                    the peer may not have the pre-shared key of the private network"
+                );
+                None
+            }
+            SwarmEvent::OutgoingConnectionError { peer_id, error, .. }
+                if self.private_network =>
+            {
+                tracing::warn!(
+                    "Failed to connect to `{peer_id:?}` with `{error}`, \
+                    the peer may not have the pre-shared key of the private network"
+                );
+                None
+            }
             _ => None,
         }
     }
@@ -458,10 +542,39 @@ impl FuelP2PService {
             }
             FuelBehaviourEvent::Identify(event) => self.handle_identify_event(event),
             FuelBehaviourEvent::Heartbeat(event) => self.handle_heartbeat_event(event),
+            FuelBehaviourEvent::PeerExchange(event) => {
+                self.handle_peer_exchange_event(event)
+            }
             _ => None,
         }
     }
 
+    /// Returns `true` if the node is connected to the in-memory network.
+    fn uses_memory_network(&self) -> bool {
+        #[cfg(feature = "test-helpers")]
+        {
+            self.memory_network.is_some()
+        }
+        #[cfg(not(feature = "test-helpers"))]
+        {
+            false
+        }
+    }
+
+    /// Decides whether the received message is lost by the in-memory network.
+    fn is_lost(&self) -> bool {
+        #[cfg(feature = "test-helpers")]
+        {
+            self.memory_network
+                .as_ref()
+                .map_or(false, |network| network.is_lost())
+        }
+        #[cfg(not(feature = "test-helpers"))]
+        {
+            false
+        }
+    }
+
     fn handle_gossipsub_event(
         &mut self,
         event: gossipsub::Event,
@@ -472,6 +585,15 @@ impl FuelP2PService {
             message_id,
         } = event
         {
+            if self.is_lost() {
+                self.report_message_validation_result(
+                    &message_id,
+                    propagation_source,
+                    MessageAcceptance::Ignore,
+                );
+                return None
+            }
+
             if let Some(correct_topic) = self
                 .network_metadata
                 .gossipsub_data
@@ -559,9 +681,15 @@ impl FuelP2PService {
                     channel,
                     request_id,
                 } => {
+                    if self.is_lost() {
+                        // Dropping the channel closes the stream without the response
+                        return None
+                    }
+
                     self.inbound_requests_table.insert(request_id, channel);
 
                     return Some(FuelP2PEvent::InboundRequestMessage {
+                        peer_id: peer,
                         request_id,
                         request_message: request,
                     });
@@ -601,6 +729,18 @@ impl FuelP2PService {
                                 c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
                             }
                         },
+                        ResponseSender::TransactionsByIds(c) => match response {
+                            ResponseMessage::TransactionsByIds(v) => {
+                                c.send((peer, Ok(v))).is_ok()
+                            }
+                            _ => {
+                                warn!(
+                                    "Invalid response type received for request {:?}",
+                                    request_id
+                                );
+                                c.send((peer, Err(ResponseError::TypeMismatch))).is_ok()
+                            }
+                        },
                     };
 
                     if !send_ok {
@@ -633,6 +773,9 @@ impl FuelP2PService {
                         ResponseSender::Transactions(c) => {
                             let _ = c.send((peer, Err(ResponseError::P2P(error))));
                         }
+                        ResponseSender::TransactionsByIds(c) => {
+                            let _ = c.send((peer, Err(ResponseError::P2P(error))));
+                        }
                     };
                 }
             }
@@ -652,7 +795,7 @@ impl FuelP2PService {
                 let agent_version = info.agent_version;
 
                 if addresses.len() > MAX_IDENTIFY_ADDRESSES {
-                    let protocol_version = info.protocol_version;
+                    let protocol_version = &info.protocol_version;
                     debug!(
                         target: "fuel-p2p",
                         "Node {:?} has reported more than {} addresses; it is identified by {:?} and {:?}",
@@ -665,6 +808,7 @@ impl FuelP2PService {
                     &peer_id,
                     addresses.clone(),
                     agent_version,
+                    ProtocolVersions::from_identify(&info.protocol_version),
                 );
 
                 self.swarm
@@ -680,6 +824,57 @@ impl FuelP2PService {
         None
     }
 
+    fn handle_peer_exchange_event(
+        &mut self,
+        event: peer_exchange::Event,
+    ) -> Option<FuelP2PEvent> {
+        match event {
+            peer_exchange::Event::SampleRequested { peer_id, channel } => {
+                // The records of the reserved peers are never shared
+                let mut peers: Vec<_> = self
+                    .peer_manager
+                    .get_all_peers()
+                    .map(|(id, _)| *id)
+                    .filter(|id| *id != peer_id && !self.peer_manager.is_reserved(id))
+                    .collect();
+                peers.shuffle(&mut rand::thread_rng());
+
+                self.swarm
+                    .behaviour_mut()
+                    .send_peer_exchange_sample(channel, &peers);
+            }
+            peer_exchange::Event::SampleReceived { peer_id, sample } => {
+                debug!(target: "fuel-p2p", "Received {} peers from {:?} via peer exchange", sample.len(), peer_id);
+
+                for PeerAddresses { peer_id, addresses } in sample {
+                    if peer_id == self.local_peer_id
+                        || addresses.is_empty()
+                        || self.swarm.is_connected(&peer_id)
+                    {
+                        continue
+                    }
+
+                    self.swarm
+                        .behaviour_mut()
+                        .add_addresses_to_discovery(&peer_id, addresses.clone());
+
+                    if self.peer_manager.has_available_slot() {
+                        let opts = DialOpts::peer_id(peer_id)
+                            .addresses(addresses)
+                            .condition(PeerCondition::DisconnectedAndNotDialing)
+                            .build();
+                        let _ = self.swarm.dial(opts);
+                    }
+                }
+            }
+            peer_exchange::Event::InvalidSample { peer_id, error } => {
+                warn!(target: "fuel-p2p", "Invalid peer exchange sample from {:?}: {}", peer_id, error);
+                self.report_peer(peer_id, INVALID_SAMPLE_PENALTY, "peer_exchange");
+            }
+        }
+        None
+    }
+
     fn handle_heartbeat_event(
         &mut self,
         event: heartbeat::Event,
@@ -715,6 +910,7 @@ mod tests {
             },
             topics::{
                 GossipTopic,
+                NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC,
                 NEW_TX_GOSSIP_TOPIC,
             },
         },
@@ -740,7 +936,9 @@ mod tests {
         fuel_tx::{
             Transaction,
             TransactionBuilder,
+            UniqueIdentifier,
         },
+        fuel_types::ChainId,
         services::p2p::{
             GossipsubMessageAcceptance,
             Transactions,
@@ -1149,6 +1347,193 @@ mod tests {
         }
     }
 
+    // Simulates 2 p2p nodes that are members of the different private networks
+    // So the pnet handshake fails and they are not able to connect
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_cannot_connect_due_to_different_private_network_key() {
+        use crate::config::PreSharedKey;
+        use libp2p::TransportError;
+        // Node A
+        let mut p2p_config = Config::default_initialized(
+            "nodes_cannot_connect_due_to_different_private_network_key",
+        );
+        p2p_config.private_network_key = Some(PreSharedKey::new([1; 32]));
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // different private network key
+        p2p_config.private_network_key = Some(PreSharedKey::new([2; 32]));
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        // Node B
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.swarm.select_next_some() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                    if let SwarmEvent::IncomingConnectionError { error: ListenError::Transport(TransportError::Other(_)), .. } = node_a_event {
+                        break
+                    }
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(_)) = node_b_event {
+                        panic!("Node B should not connect to Node A!")
+                    }
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+
+            };
+        }
+    }
+
+    // Simulates 2 p2p nodes that share the pre-shared key of the private network
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_connect_with_same_private_network_key() {
+        use crate::config::PreSharedKey;
+        // Node A
+        let mut p2p_config =
+            Config::default_initialized("nodes_connect_with_same_private_network_key");
+        p2p_config.private_network_key = Some(PreSharedKey::new([1; 32]));
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // Node B
+        p2p_config.bootstrap_nodes = node_a.multiaddrs();
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(_)) = node_b_event {
+                        break
+                    }
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+            };
+        }
+    }
+
+    fn quic_multiaddrs(node: &P2PService) -> Vec<Multiaddr> {
+        node.multiaddrs()
+            .into_iter()
+            .filter(|address| {
+                address
+                    .iter()
+                    .any(|p| p == libp2p::multiaddr::Protocol::QuicV1)
+            })
+            .collect()
+    }
+
+    // Simulates 2 p2p nodes, Node B is bootstrapped only with the QUIC address of Node A
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_connect_via_quic() {
+        // Node A
+        let mut p2p_config = Config::default_initialized("nodes_connect_via_quic");
+        p2p_config.quic_port = Some(0);
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // Node B
+        p2p_config.bootstrap_nodes = quic_multiaddrs(&node_a);
+        assert!(!p2p_config.bootstrap_nodes.is_empty());
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(_)) = node_b_event {
+                        break
+                    }
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+            };
+        }
+    }
+
+    // Simulates 2 p2p nodes that are connecting via QUIC but their Fuel Upgrade checksum is different
+    // So they are not able to connect
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_cannot_connect_via_quic_due_to_different_checksum() {
+        // Node A
+        let mut p2p_config = Config::default_initialized(
+            "nodes_cannot_connect_via_quic_due_to_different_checksum",
+        );
+        p2p_config.quic_port = Some(0);
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+
+        // different checksum
+        p2p_config.checksum = [1u8; 32].into();
+        p2p_config.bootstrap_nodes = quic_multiaddrs(&node_a);
+        // Node B
+        let mut node_b = build_service_from_config(p2p_config).await;
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.swarm.select_next_some() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                    if let SwarmEvent::IncomingConnectionError { .. } = node_a_event {
+                        break
+                    }
+                },
+                node_b_event = node_b.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(_)) = node_b_event {
+                        panic!("Node B should not connect to Node A!")
+                    }
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+            };
+        }
+    }
+
+    // Simulates 3 p2p nodes without the DHT discovery, Node B & Node C only dial Node A
+    // Using Peer Exchange Protocol Node C should learn the address of Node B from Node A
+    #[tokio::test]
+    #[instrument]
+    async fn nodes_connected_via_peer_exchange() {
+        // Node A
+        let mut p2p_config =
+            Config::default_initialized("nodes_connected_via_peer_exchange");
+        p2p_config.random_walk = None;
+        p2p_config.peer_exchange_interval = Some(Duration::from_millis(200));
+        let mut node_a = build_service_from_config(p2p_config.clone()).await;
+        let node_a_address = node_a.multiaddrs().remove(0);
+
+        // Node B
+        let mut node_b = build_service_from_config(p2p_config.clone()).await;
+        node_b.swarm.dial(node_a_address.clone()).unwrap();
+        let node_b_id = node_b.local_peer_id;
+
+        // Node C
+        let mut node_c = build_service_from_config(p2p_config).await;
+        node_c.swarm.dial(node_a_address).unwrap();
+
+        loop {
+            tokio::select! {
+                node_a_event = node_a.next_event() => {
+                    tracing::info!("Node A Event: {:?}", node_a_event);
+                },
+                node_b_event = node_b.next_event() => {
+                    tracing::info!("Node B Event: {:?}", node_b_event);
+                },
+                node_c_event = node_c.next_event() => {
+                    if let Some(FuelP2PEvent::PeerConnected(peer_id)) = node_c_event {
+                        if peer_id == node_b_id {
+                            break
+                        }
+                    }
+                    tracing::info!("Node C Event: {:?}", node_c_event);
+                },
+            };
+        }
+    }
+
     // Simulates 3 p2p nodes, Node B & Node C are bootstrapped with Node A
     // Using Identify Protocol Node C should be able to identify and connect to Node B
     #[tokio::test]
@@ -1256,6 +1641,18 @@ mod tests {
         .await;
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn gossipsub_broadcast_tx_announcement_with_accept() {
+        gossipsub_broadcast(
+            GossipsubBroadcastRequest::NewTxAnnouncement(
+                Transaction::default_test_tx().id(&ChainId::default()),
+            ),
+            GossipsubMessageAcceptance::Accept,
+        )
+        .await;
+    }
+
     #[tokio::test]
     #[instrument]
     #[ignore]
@@ -1386,6 +1783,9 @@ mod tests {
         let selected_topic: GossipTopic = {
             let topic = match broadcast_request {
                 GossipsubBroadcastRequest::NewTx(_) => NEW_TX_GOSSIP_TOPIC,
+                GossipsubBroadcastRequest::NewTxAnnouncement(_) => {
+                    NEW_TX_ANNOUNCEMENT_GOSSIP_TOPIC
+                }
             };
 
             Topic::new(format!("{}/{}", topic, p2p_config.network_name))
@@ -1447,6 +1847,12 @@ mod tests {
                                     panic!("Wrong GossipsubMessage")
                                 }
                             }
+                            GossipsubMessage::NewTxAnnouncement(tx_id) => {
+                                if tx_id != &Transaction::default_test_tx().id(&ChainId::default()) {
+                                    tracing::error!("Wrong p2p message {:?}", message);
+                                    panic!("Wrong GossipsubMessage")
+                                }
+                            }
                         }
 
                         // Node B received the correct message
@@ -1572,6 +1978,23 @@ mod tests {
                                             }
                                         });
                                     }
+                                    RequestMessage::TransactionsByIds(tx_ids) => {
+                                        let (tx_orchestrator, rx_orchestrator) = oneshot::channel();
+                                        assert!(node_a.send_request_msg(None, request_msg.clone(), ResponseSender::TransactionsByIds(tx_orchestrator)).is_ok());
+                                        let tx_test_end = tx_test_end.clone();
+
+                                        tokio::spawn(async move {
+                                            let response_message = rx_orchestrator.await;
+
+                                            if let Ok((_, Ok(transactions))) = response_message {
+                                                let received: Vec<_> = transactions.iter().map(|tx| tx.id(&ChainId::default())).collect();
+                                                let _ = tx_test_end.send(received == tx_ids).await;
+                                            } else {
+                                                tracing::error!("Orchestrator failed to receive a message: {:?}", response_message);
+                                                let _ = tx_test_end.send(false).await;
+                                            }
+                                        });
+                                    }
                                 }
                             }
                         }
@@ -1581,7 +2004,7 @@ mod tests {
                 },
                 node_b_event = node_b.next_event() => {
                     // 2. Node B receives the RequestMessage from Node A initiated by the NetworkOrchestrator
-                    if let Some(FuelP2PEvent::InboundRequestMessage{ request_id, request_message: received_request_message }) = &node_b_event {
+                    if let Some(FuelP2PEvent::InboundRequestMessage{ request_id, request_message: received_request_message, .. }) = &node_b_event {
                         match received_request_message {
                             RequestMessage::SealedHeaders(range) => {
                                 let sealed_headers: Vec<_> = arbitrary_headers_for_range(range.clone());
@@ -1593,6 +2016,10 @@ mod tests {
                                 let transactions = vec![Transactions(txs)];
                                 let _ = node_b.send_response_msg(*request_id, ResponseMessage::Transactions(Some(transactions)));
                             }
+                            RequestMessage::TransactionsByIds(_) => {
+                                let txs = vec![Transaction::default_test_tx()];
+                                let _ = node_b.send_response_msg(*request_id, ResponseMessage::TransactionsByIds(txs));
+                            }
                         }
                     }
 
@@ -1609,6 +2036,13 @@ mod tests {
         request_response_works_with(RequestMessage::Transactions(arbitrary_range)).await
     }
 
+    #[tokio::test]
+    #[instrument]
+    async fn request_response_works_with_transactions_by_ids() {
+        let tx_id = Transaction::default_test_tx().id(&ChainId::default());
+        request_response_works_with(RequestMessage::TransactionsByIds(vec![tx_id])).await
+    }
+
     #[tokio::test]
     #[instrument]
     async fn request_response_works_with_sealed_headers_range_inclusive() {
@@ -1682,7 +2116,7 @@ mod tests {
                 },
                 node_b_event = node_b.next_event() => {
                     // 2. Node B receives the RequestMessage from Node A initiated by the NetworkOrchestrator
-                    if let Some(FuelP2PEvent::InboundRequestMessage{ request_id, request_message: _ }) = &node_b_event {
+                    if let Some(FuelP2PEvent::InboundRequestMessage{ request_id, .. }) = &node_b_event {
                         let sealed_headers: Vec<_> = arbitrary_headers_for_range(1..3);
                         let _ = node_b.send_response_msg(*request_id, ResponseMessage::SealedHeaders(Some(sealed_headers)));
                     }
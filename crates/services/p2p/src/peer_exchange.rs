@@ -0,0 +1,692 @@
+//! The peer exchange (PEX) protocol. Connected peers periodically ask each other for
+//! a sample of the known-good addresses, so the mesh heals even without the DHT.
+//! The addresses are shared only as the [`PeerRecord`]s signed by the peers that own
+//! them. The responder includes its own record, learned by the requester to share it
+//! further, and the records it learned from its connected peers. A sample with any
+//! record that can't be verified is rejected as a whole.
+
+use async_trait::async_trait;
+use fuel_core_types::services::p2p::peer_reputation::AppScore;
+use futures::{
+    AsyncRead,
+    AsyncReadExt,
+    AsyncWrite,
+    AsyncWriteExt,
+};
+use libp2p::{
+    core::{
+        peer_record,
+        signed_envelope,
+        Endpoint,
+        PeerRecord,
+        SignedEnvelope,
+    },
+    identity::Keypair,
+    request_response::{
+        self,
+        ProtocolSupport,
+        ResponseChannel,
+    },
+    swarm::{
+        ConnectionDenied,
+        ConnectionId,
+        FromSwarm,
+        NetworkBehaviour,
+        THandler,
+        THandlerInEvent,
+        THandlerOutEvent,
+        ToSwarm,
+    },
+    Multiaddr,
+    PeerId,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    io,
+    task::{
+        Context,
+        Poll,
+    },
+    time::Duration,
+};
+use tokio::time::{
+    self,
+    Interval,
+};
+
+pub const PEER_EXCHANGE_PROTOCOL: &str = "/fuel/pex/0.0.2";
+
+/// The maximum number of the peers in one sample.
+pub const MAX_SAMPLE_PEERS: usize = 16;
+
+/// The maximum number of the addresses per peer in one sample.
+pub const MAX_SAMPLE_ADDRESSES: usize = 4;
+
+/// The reputation penalty for the sample that can't be verified.
+pub const INVALID_SAMPLE_PENALTY: AppScore = -25.0;
+
+/// Enough for the [`MAX_SAMPLE_PEERS`] records with the [`MAX_SAMPLE_ADDRESSES`]
+/// and the record of the responder.
+const MAX_RESPONSE_SIZE: u64 = 32 * 1024;
+
+/// The addresses of the peer from its verified [`PeerRecord`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PeerAddresses {
+    pub peer_id: PeerId,
+    pub addresses: Vec<Multiaddr>,
+}
+
+impl From<&PeerRecord> for PeerAddresses {
+    fn from(record: &PeerRecord) -> Self {
+        Self {
+            peer_id: record.peer_id(),
+            addresses: record.addresses().to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SampleRequest;
+
+/// The encoded record of the responder and the records of the sample.
+#[derive(Debug, Clone)]
+pub struct EncodedSample(Vec<u8>);
+
+#[derive(Debug, thiserror::Error)]
+pub enum SampleError {
+    #[error("Failed to decode the envelope: {0}")]
+    Envelope(#[from] signed_envelope::DecodingError),
+    #[error("Invalid peer record: {0}")]
+    Record(#[from] peer_record::FromEnvelopeError),
+    #[error("The record of the peer is signed by {signer}")]
+    UnexpectedSigner { signer: PeerId },
+    #[error("Failed to decode the sample: {0}")]
+    Payload(String),
+}
+
+#[derive(Debug)]
+pub enum Event {
+    /// The peer asks for a sample of the known-good addresses.
+    /// The sample should be sent with the [`Behaviour::send_sample`].
+    SampleRequested {
+        peer_id: PeerId,
+        channel: ResponseChannel<EncodedSample>,
+    },
+    /// The verified addresses of other peers received from the peer.
+    SampleReceived {
+        peer_id: PeerId,
+        sample: Vec<PeerAddresses>,
+    },
+    /// The peer responded with a sample that can't be verified.
+    InvalidSample { peer_id: PeerId, error: SampleError },
+}
+
+#[derive(Serialize, Deserialize)]
+struct Sample {
+    /// The protobuf encoded [`SignedEnvelope`] of the responder's record.
+    responder: Vec<u8>,
+    /// The protobuf encoded [`SignedEnvelope`]s of other peers' records.
+    records: Vec<Vec<u8>>,
+}
+
+fn encode_sample<'a>(
+    responder: &PeerRecord,
+    records: impl Iterator<Item = &'a PeerRecord>,
+) -> io::Result<EncodedSample> {
+    let sample = Sample {
+        responder: responder.to_signed_envelope().into_protobuf_encoding(),
+        records: records
+            .take(MAX_SAMPLE_PEERS)
+            .map(|record| record.to_signed_envelope().into_protobuf_encoding())
+            .collect(),
+    };
+    postcard::to_stdvec(&sample)
+        .map(EncodedSample)
+        .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
+}
+
+fn decode_record(bytes: &[u8]) -> Result<PeerRecord, SampleError> {
+    let envelope = SignedEnvelope::from_protobuf_encoding(bytes)?;
+    let record = PeerRecord::from_signed_envelope(envelope)?;
+    if record.addresses().len() > MAX_SAMPLE_ADDRESSES {
+        return Err(SampleError::Payload(format!(
+            "The record of {} has {} addresses",
+            record.peer_id(),
+            record.addresses().len()
+        )))
+    }
+    Ok(record)
+}
+
+/// Verifies that the sample from the `peer_id` contains its own record
+/// and that each record is signed by the peer it describes.
+fn verify_sample(
+    peer_id: &PeerId,
+    sample: &EncodedSample,
+) -> Result<(PeerRecord, Vec<PeerRecord>), SampleError> {
+    let sample: Sample = postcard::from_bytes(&sample.0)
+        .map_err(|e| SampleError::Payload(e.to_string()))?;
+    if sample.records.len() > MAX_SAMPLE_PEERS {
+        return Err(SampleError::Payload(format!(
+            "The sample has {} peers",
+            sample.records.len()
+        )))
+    }
+
+    let responder = decode_record(&sample.responder)?;
+    if &responder.peer_id() != peer_id {
+        return Err(SampleError::UnexpectedSigner {
+            signer: responder.peer_id(),
+        })
+    }
+    let records = sample
+        .records
+        .iter()
+        .map(|bytes| decode_record(bytes))
+        .collect::<Result<_, _>>()?;
+    Ok((responder, records))
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PeerExchangeCodec;
+
+#[derive(Debug, Clone, Default)]
+pub struct PeerExchangeProtocol;
+
+impl AsRef<str> for PeerExchangeProtocol {
+    fn as_ref(&self) -> &str {
+        PEER_EXCHANGE_PROTOCOL
+    }
+}
+
+/// The request has no body, and the response is the signed sample
+/// until the end of the substream.
+#[async_trait]
+impl request_response::Codec for PeerExchangeCodec {
+    type Protocol = PeerExchangeProtocol;
+    type Request = SampleRequest;
+    type Response = EncodedSample;
+
+    async fn read_request<T>(
+        &mut self,
+        _: &Self::Protocol,
+        socket: &mut T,
+    ) -> io::Result<Self::Request>
+    where
+        T: AsyncRead + Unpin + Send,
+    {
+        let mut request = Vec::new();
+        socket.take(1).read_to_end(&mut request).await?;
+        if !request.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "The peer exchange request should be empty",
+            ))
+        }
+        Ok(SampleRequest)
+    }
+
+    async fn read_response<T>(
+        &mut self,
+        _: &Self::Protocol,
+        socket: &mut T,
+    ) -> io::Result<Self::Response>
+    where
+        T: AsyncRead + Unpin + Send,
+    {
+        let mut response = Vec::new();
+        socket
+            .take(MAX_RESPONSE_SIZE)
+            .read_to_end(&mut response)
+            .await?;
+        Ok(EncodedSample(response))
+    }
+
+    async fn write_request<T>(
+        &mut self,
+        _: &Self::Protocol,
+        _: &mut T,
+        _: Self::Request,
+    ) -> io::Result<()>
+    where
+        T: AsyncWrite + Unpin + Send,
+    {
+        Ok(())
+    }
+
+    async fn write_response<T>(
+        &mut self,
+        _: &Self::Protocol,
+        socket: &mut T,
+        response: Self::Response,
+    ) -> io::Result<()>
+    where
+        T: AsyncWrite + Unpin + Send,
+    {
+        socket.write_all(&response.0).await
+    }
+}
+
+/// Requests the samples from all connected peers every `interval` and
+/// answers the requests of the peers.
+pub struct Behaviour {
+    keypair: Keypair,
+    inner: request_response::Behaviour<PeerExchangeCodec>,
+    connected_peers: HashSet<PeerId>,
+    /// The latest records received from the connected peers.
+    records: HashMap<PeerId, PeerRecord>,
+    listen_addresses: Vec<Multiaddr>,
+    external_addresses: Vec<Multiaddr>,
+    interval: Option<Interval>,
+}
+
+impl Behaviour {
+    /// Creates the behaviour that doesn't support the protocol if the `interval` is `None`.
+    pub fn new(keypair: Keypair, interval: Option<Duration>) -> Self {
+        let protocols = interval
+            .is_some()
+            .then_some((PeerExchangeProtocol, ProtocolSupport::Full));
+        let inner = request_response::Behaviour::with_codec(
+            PeerExchangeCodec,
+            protocols,
+            request_response::Config::default(),
+        );
+
+        Self {
+            keypair,
+            inner,
+            connected_peers: HashSet::new(),
+            records: HashMap::new(),
+            listen_addresses: vec![],
+            external_addresses: vec![],
+            interval: interval.map(|period| {
+                let mut interval = time::interval(period);
+                interval.set_missed_tick_behavior(time::MissedTickBehavior::Delay);
+                interval
+            }),
+        }
+    }
+
+    /// Signs the record of the local peer with the confirmed external
+    /// addresses first, followed by the listen addresses.
+    fn local_record(&self) -> io::Result<PeerRecord> {
+        let mut addresses: Vec<Multiaddr> = vec![];
+        for address in self
+            .external_addresses
+            .iter()
+            .chain(self.listen_addresses.iter())
+        {
+            if !addresses.contains(address) {
+                addresses.push(address.clone());
+            }
+        }
+        addresses.truncate(MAX_SAMPLE_ADDRESSES);
+        PeerRecord::new(&self.keypair, addresses)
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
+    }
+
+    /// Sends the signed records of the `peers` to the peer that requested the sample.
+    /// The peers without the known record are skipped.
+    pub fn send_sample(
+        &mut self,
+        channel: ResponseChannel<EncodedSample>,
+        peers: &[PeerId],
+    ) {
+        let records = peers.iter().filter_map(|peer_id| self.records.get(peer_id));
+        let sample = self
+            .local_record()
+            .and_then(|local_record| encode_sample(&local_record, records));
+        match sample {
+            Ok(sample) => {
+                if self.inner.send_response(channel, sample).is_err() {
+                    tracing::debug!(target: "fuel-p2p", "Failed to send the peer exchange sample, the channel is closed");
+                }
+            }
+            Err(e) => {
+                tracing::error!(target: "fuel-p2p", "Failed to sign the peer exchange sample: {e}");
+            }
+        }
+    }
+
+    /// Stores the `record` of the connected peer if it is newer than the known one.
+    fn insert_record(&mut self, record: PeerRecord) {
+        let peer_id = record.peer_id();
+        if !self.connected_peers.contains(&peer_id) {
+            return
+        }
+        let is_newer = self
+            .records
+            .get(&peer_id)
+            .map_or(true, |known| known.seq() < record.seq());
+        if is_newer {
+            self.records.insert(peer_id, record);
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        event: request_response::Event<SampleRequest, EncodedSample>,
+    ) -> Option<Event> {
+        match event {
+            request_response::Event::Message { peer, message } => match message {
+                request_response::Message::Request { channel, .. } => {
+                    Some(Event::SampleRequested {
+                        peer_id: peer,
+                        channel,
+                    })
+                }
+                request_response::Message::Response { response, .. } => {
+                    match verify_sample(&peer, &response) {
+                        Ok((responder, records)) => {
+                            self.insert_record(responder);
+                            Some(Event::SampleReceived {
+                                peer_id: peer,
+                                sample: records.iter().map(Into::into).collect(),
+                            })
+                        }
+                        Err(error) => Some(Event::InvalidSample {
+                            peer_id: peer,
+                            error,
+                        }),
+                    }
+                }
+            },
+            request_response::Event::OutboundFailure { peer, error, .. } => {
+                tracing::debug!(target: "fuel-p2p", "Peer exchange request to {peer} failed: {error}");
+                None
+            }
+            request_response::Event::InboundFailure { peer, error, .. } => {
+                tracing::debug!(target: "fuel-p2p", "Peer exchange request from {peer} failed: {error}");
+                None
+            }
+            request_response::Event::ResponseSent { .. } => None,
+        }
+    }
+}
+
+impl NetworkBehaviour for Behaviour {
+    type ConnectionHandler =
+        <request_response::Behaviour<PeerExchangeCodec> as NetworkBehaviour>::ConnectionHandler;
+    type ToSwarm = Event;
+
+    fn handle_established_inbound_connection(
+        &mut self,
+        connection_id: ConnectionId,
+        peer: PeerId,
+        local_addr: &Multiaddr,
+        remote_addr: &Multiaddr,
+    ) -> Result<THandler<Self>, ConnectionDenied> {
+        self.inner.handle_established_inbound_connection(
+            connection_id,
+            peer,
+            local_addr,
+            remote_addr,
+        )
+    }
+
+    fn handle_pending_outbound_connection(
+        &mut self,
+        connection_id: ConnectionId,
+        maybe_peer: Option<PeerId>,
+        addresses: &[Multiaddr],
+        effective_role: Endpoint,
+    ) -> Result<Vec<Multiaddr>, ConnectionDenied> {
+        self.inner.handle_pending_outbound_connection(
+            connection_id,
+            maybe_peer,
+            addresses,
+            effective_role,
+        )
+    }
+
+    fn handle_established_outbound_connection(
+        &mut self,
+        connection_id: ConnectionId,
+        peer: PeerId,
+        addr: &Multiaddr,
+        role_override: Endpoint,
+    ) -> Result<THandler<Self>, ConnectionDenied> {
+        self.inner.handle_established_outbound_connection(
+            connection_id,
+            peer,
+            addr,
+            role_override,
+        )
+    }
+
+    fn on_swarm_event(&mut self, event: FromSwarm) {
+        match &event {
+            FromSwarm::ConnectionEstablished(connection_established) => {
+                self.connected_peers.insert(connection_established.peer_id);
+            }
+            FromSwarm::ConnectionClosed(connection_closed)
+                if connection_closed.remaining_established == 0 =>
+            {
+                self.connected_peers.remove(&connection_closed.peer_id);
+                self.records.remove(&connection_closed.peer_id);
+            }
+            FromSwarm::NewListenAddr(new_listen_addr) => {
+                self.listen_addresses.push(new_listen_addr.addr.clone());
+            }
+            FromSwarm::ExpiredListenAddr(expired_listen_addr) => {
+                self.listen_addresses
+                    .retain(|address| address != expired_listen_addr.addr);
+            }
+            FromSwarm::ExternalAddrConfirmed(external_addr_confirmed) => {
+                self.external_addresses
+                    .push(external_addr_confirmed.addr.clone());
+            }
+            FromSwarm::ExternalAddrExpired(external_addr_expired) => {
+                self.external_addresses
+                    .retain(|address| address != external_addr_expired.addr);
+            }
+            _ => {}
+        }
+        self.inner.on_swarm_event(event);
+    }
+
+    fn on_connection_handler_event(
+        &mut self,
+        peer_id: PeerId,
+        connection_id: ConnectionId,
+        event: THandlerOutEvent<Self>,
+    ) {
+        self.inner
+            .on_connection_handler_event(peer_id, connection_id, event)
+    }
+
+    fn poll(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<ToSwarm<Self::ToSwarm, THandlerInEvent<Self>>> {
+        if let Some(interval) = self.interval.as_mut() {
+            if interval.poll_tick(cx).is_ready() {
+                for peer_id in self.connected_peers.iter() {
+                    self.inner.send_request(peer_id, SampleRequest);
+                }
+            }
+        }
+
+        loop {
+            match self.inner.poll(cx) {
+                Poll::Ready(ToSwarm::GenerateEvent(event)) => {
+                    if let Some(event) = self.handle_event(event) {
+                        return Poll::Ready(ToSwarm::GenerateEvent(event))
+                    }
+                }
+                Poll::Ready(action) => {
+                    return Poll::Ready(action.map_out(|_| {
+                        unreachable!("The events are handled in the previous branch")
+                    }))
+                }
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::StreamExt;
+    use libp2p::{
+        swarm::SwarmEvent,
+        Swarm,
+    };
+    use libp2p_swarm_test::SwarmExt;
+
+    fn record(keypair: &Keypair) -> PeerRecord {
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/30333".parse().unwrap();
+        PeerRecord::new(keypair, vec![address]).unwrap()
+    }
+
+    fn sample(responder: Vec<u8>, records: Vec<Vec<u8>>) -> EncodedSample {
+        EncodedSample(postcard::to_stdvec(&Sample { responder, records }).unwrap())
+    }
+
+    #[test]
+    fn signed_records_are_verified() {
+        let keypair = Keypair::generate_secp256k1();
+        let responder = record(&keypair);
+        let other = record(&Keypair::generate_secp256k1());
+        let encoded = encode_sample(&responder, [&other].into_iter()).unwrap();
+
+        let (verified_responder, verified_records) =
+            verify_sample(&keypair.public().to_peer_id(), &encoded).unwrap();
+
+        assert_eq!(verified_responder, responder);
+        assert_eq!(verified_records, vec![other]);
+    }
+
+    #[test]
+    fn record_of_another_peer_as_the_responder_is_rejected() {
+        let keypair = Keypair::generate_secp256k1();
+        let encoded = encode_sample(&record(&keypair), [].into_iter()).unwrap();
+
+        let result = verify_sample(&PeerId::random(), &encoded);
+
+        assert!(matches!(result, Err(SampleError::UnexpectedSigner { .. })));
+    }
+
+    #[test]
+    fn tampered_record_is_rejected() {
+        let keypair = Keypair::generate_secp256k1();
+        let responder = record(&keypair)
+            .to_signed_envelope()
+            .into_protobuf_encoding();
+        let mut other = record(&Keypair::generate_secp256k1())
+            .to_signed_envelope()
+            .into_protobuf_encoding();
+        let last = other.len() - 1;
+        other[last] ^= 1;
+
+        let result = verify_sample(
+            &keypair.public().to_peer_id(),
+            &sample(responder, vec![other]),
+        );
+
+        assert!(matches!(result, Err(SampleError::Record(_))));
+    }
+
+    #[test]
+    fn record_with_too_many_addresses_is_rejected() {
+        let keypair = Keypair::generate_secp256k1();
+        let responder = record(&keypair)
+            .to_signed_envelope()
+            .into_protobuf_encoding();
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/30333".parse().unwrap();
+        let other = PeerRecord::new(
+            &Keypair::generate_secp256k1(),
+            vec![address; MAX_SAMPLE_ADDRESSES + 1],
+        )
+        .unwrap()
+        .to_signed_envelope()
+        .into_protobuf_encoding();
+
+        let result = verify_sample(
+            &keypair.public().to_peer_id(),
+            &sample(responder, vec![other]),
+        );
+
+        assert!(matches!(result, Err(SampleError::Payload(_))));
+    }
+
+    #[test]
+    fn sample_is_limited() {
+        let keypair = Keypair::generate_secp256k1();
+        let address: Multiaddr = "/ip4/127.0.0.1/tcp/30333".parse().unwrap();
+        let records = (0..MAX_SAMPLE_PEERS * 2)
+            .map(|_| {
+                PeerRecord::new(
+                    &Keypair::generate_secp256k1(),
+                    vec![address.clone(); MAX_SAMPLE_ADDRESSES],
+                )
+                .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        let encoded = encode_sample(&record(&keypair), records.iter()).unwrap();
+        let (_, verified) =
+            verify_sample(&keypair.public().to_peer_id(), &encoded).unwrap();
+
+        assert!((encoded.0.len() as u64) < MAX_RESPONSE_SIZE);
+        assert_eq!(verified.len(), MAX_SAMPLE_PEERS);
+    }
+
+    #[tokio::test]
+    async fn connected_peers_share_the_records_of_their_peers() {
+        let interval = Some(Duration::from_millis(100));
+        let mut requester =
+            Swarm::new_ephemeral(|keypair| Behaviour::new(keypair, interval));
+        let mut responder =
+            Swarm::new_ephemeral(|keypair| Behaviour::new(keypair, interval));
+        let mut other = Swarm::new_ephemeral(|keypair| Behaviour::new(keypair, interval));
+        responder.listen().with_memory_addr_external().await;
+        let (other_address, _) = other.listen().with_memory_addr_external().await;
+        responder.connect(&mut other).await;
+        requester.connect(&mut responder).await;
+        let responder_id = *responder.local_peer_id();
+        let other_id = *other.local_peer_id();
+
+        loop {
+            tokio::select! {
+                event = requester.select_next_some() => {
+                    match event {
+                        SwarmEvent::Behaviour(Event::SampleReceived { peer_id, sample }) if peer_id == responder_id && !sample.is_empty() => {
+                            assert_eq!(sample.len(), 1);
+                            assert_eq!(sample[0].peer_id, other_id);
+                            assert!(sample[0].addresses.contains(&other_address));
+                            break
+                        }
+                        SwarmEvent::Behaviour(Event::SampleRequested { channel, .. }) => {
+                            requester.behaviour_mut().send_sample(channel, &[]);
+                        }
+                        _ => {}
+                    }
+                },
+                event = responder.select_next_some() => {
+                    if let SwarmEvent::Behaviour(Event::SampleRequested { peer_id, channel }) = event {
+                        let peers = responder
+                            .connected_peers()
+                            .filter(|connected| **connected != peer_id)
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        responder.behaviour_mut().send_sample(channel, &peers);
+                    }
+                },
+                event = other.select_next_some() => {
+                    if let SwarmEvent::Behaviour(Event::SampleRequested { channel, .. }) = event {
+                        other.behaviour_mut().send_sample(channel, &[]);
+                    }
+                },
+            }
+        }
+    }
+}
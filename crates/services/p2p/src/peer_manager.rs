@@ -31,6 +31,7 @@ use tracing::{
 use crate::{
     gossipsub_config::GRAYLIST_THRESHOLD,
     peer_manager::heartbeat_data::HeartbeatData,
+    protocol_version::ProtocolVersions,
 };
 
 pub mod heartbeat_data;
@@ -43,6 +44,8 @@ const MIN_GOSSIPSUB_SCORE_BEFORE_BAN: AppScore = GRAYLIST_THRESHOLD;
 pub struct PeerInfo {
     pub peer_addresses: HashSet<Multiaddr>,
     pub client_version: Option<String>,
+    /// The versions of the gossip topics, known after the identification
+    pub protocol_versions: Option<ProtocolVersions>,
     pub heartbeat_data: HeartbeatData,
     pub score: AppScore,
 }
@@ -52,6 +55,7 @@ impl PeerInfo {
         Self {
             peer_addresses: HashSet::new(),
             client_version: None,
+            protocol_versions: None,
             heartbeat_data: HeartbeatData::new(heartbeat_avg_window),
             score: DEFAULT_APP_SCORE,
         }
@@ -143,10 +147,20 @@ impl PeerManager {
         peer_id: &PeerId,
         addresses: Vec<Multiaddr>,
         agent_version: String,
+        protocol_versions: ProtocolVersions,
     ) {
         let peers = self.get_assigned_peer_table_mut(peer_id);
         insert_client_version(peers, peer_id, agent_version);
         insert_peer_addresses(peers, peer_id, addresses);
+        if let Some(peer) = peers.get_mut(peer_id) {
+            peer.protocol_versions = Some(protocol_versions);
+        }
+    }
+
+    /// Returns the versions of the gossip topics of the identified peers.
+    pub fn get_protocol_versions(&self) -> impl Iterator<Item = &ProtocolVersions> {
+        self.get_all_peers()
+            .filter_map(|(_, info)| info.protocol_versions.as_ref())
     }
 
     pub fn batch_update_score_with_decay(&mut self) {
@@ -177,6 +191,11 @@ impl PeerManager {
         }
     }
 
+    /// Returns `true` if the node can connect to more non-reserved peers
+    pub fn has_available_slot(&self) -> bool {
+        self.non_reserved_connected_peers.len() < self.max_non_reserved_peers
+    }
+
     pub fn total_peers_connected(&self) -> usize {
         self.reserved_connected_peers
             .len()
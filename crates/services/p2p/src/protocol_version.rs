@@ -0,0 +1,149 @@
+//! The versions of the gossip topics and the request-response protocol.
+//!
+//! The request-response protocol is negotiated for each stream by libp2p: the node
+//! offers all supported versions, the newest first, and the peers use the newest common one.
+//!
+//! The gossip topics don't have the negotiation, so the node advertises the supported
+//! versions of each topic in the protocol version of the identify protocol:
+//!
+//! ```text
+//! /fuel/1.0 new_tx=1-2 new_tx_announcement=1
+//! ```
+//!
+//! The node subscribes to all supported versions of the topics and publishes to the newest
+//! version supported by all identified peers, so the new version of the topic is used only
+//! after the peers are upgraded. The nodes without the versioning advertise only `/fuel/1.0`
+//! and are treated as supporting the [`LEGACY_VERSION`] of all topics.
+
+use crate::{
+    behavior::IDENTIFY_PROTOCOL,
+    gossipsub::messages::GossipTopicTag,
+};
+use std::{
+    collections::BTreeMap,
+    fmt,
+    ops::RangeInclusive,
+};
+
+/// The version of the gossip topic.
+pub type Version = u32;
+
+/// The version of the topics used by the nodes before the versioning.
+pub const LEGACY_VERSION: Version = 1;
+
+/// The versions of the gossip topics supported by the node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProtocolVersions {
+    topics: BTreeMap<String, RangeInclusive<Version>>,
+}
+
+impl ProtocolVersions {
+    /// The versions supported by this node.
+    pub fn local() -> Self {
+        let topics = GossipTopicTag::ALL
+            .iter()
+            .map(|tag| (tag.name().to_string(), tag.versions()))
+            .collect();
+        Self { topics }
+    }
+
+    /// Parses the protocol version received with the identify protocol.
+    /// The unknown and malformed entries are ignored.
+    pub fn from_identify(protocol_version: &str) -> Self {
+        let topics = protocol_version
+            .split_whitespace()
+            .filter_map(|entry| {
+                let (topic, versions) = entry.split_once('=')?;
+                let (min, max) = versions.split_once('-').unwrap_or((versions, versions));
+                let min: Version = min.parse().ok()?;
+                let max: Version = max.parse().ok()?;
+                (min <= max).then(|| (topic.to_string(), min..=max))
+            })
+            .collect();
+        Self { topics }
+    }
+
+    /// Returns the supported versions of the `topic`.
+    pub fn topic(&self, topic: GossipTopicTag) -> RangeInclusive<Version> {
+        self.topics
+            .get(topic.name())
+            .cloned()
+            .unwrap_or(LEGACY_VERSION..=LEGACY_VERSION)
+    }
+
+    /// Returns the newest version of the `topic` supported by this node and all `peers`.
+    pub fn negotiate<'a>(
+        &self,
+        topic: GossipTopicTag,
+        peers: impl IntoIterator<Item = &'a ProtocolVersions>,
+    ) -> Option<Version> {
+        let local = self.topic(topic);
+        let (mut min, mut max) = (*local.start(), *local.end());
+        for peer in peers {
+            let versions = peer.topic(topic);
+            min = min.max(*versions.start());
+            max = max.min(*versions.end());
+        }
+        (min <= max).then_some(max)
+    }
+}
+
+impl fmt::Display for ProtocolVersions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{IDENTIFY_PROTOCOL}")?;
+        for (topic, versions) in &self.topics {
+            if versions.start() == versions.end() {
+                write!(f, " {topic}={}", versions.start())?;
+            } else {
+                write!(f, " {topic}={}-{}", versions.start(), versions.end())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_versions_round_trip_through_identify() {
+        let local = ProtocolVersions::local();
+
+        let parsed = ProtocolVersions::from_identify(&local.to_string());
+
+        assert_eq!(parsed, local);
+    }
+
+    #[test]
+    fn peers_without_versioning_support_only_legacy_version() {
+        // Given
+        let legacy = ProtocolVersions::from_identify(IDENTIFY_PROTOCOL);
+        let upgraded = ProtocolVersions::from_identify("/fuel/1.0 new_tx=1-3");
+
+        // Then
+        assert_eq!(legacy.topic(GossipTopicTag::NewTx), 1..=1);
+        assert_eq!(
+            upgraded.negotiate(GossipTopicTag::NewTx, [&upgraded]),
+            Some(3)
+        );
+        assert_eq!(
+            upgraded.negotiate(GossipTopicTag::NewTx, [&upgraded, &legacy]),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn negotiation_fails_without_common_version() {
+        // Given
+        let local = ProtocolVersions::from_identify("/fuel/1.0 new_tx=2-3");
+        let peer = ProtocolVersions::from_identify("/fuel/1.0 new_tx=1 unknown=x-y");
+
+        // Then
+        assert_eq!(local.negotiate(GossipTopicTag::NewTx, [&peer]), None);
+        assert_eq!(
+            local.negotiate(GossipTopicTag::NewTxAnnouncement, [&peer]),
+            Some(1)
+        );
+    }
+}
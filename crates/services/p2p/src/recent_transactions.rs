@@ -0,0 +1,402 @@
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    TxId,
+};
+use libp2p::PeerId;
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+        VecDeque,
+    },
+    sync::Arc,
+    time::Duration,
+};
+use tokio::time::Instant;
+
+/// The maximum number of the cached transactions.
+pub const MAX_RECENT_TRANSACTIONS: usize = 4096;
+
+/// The maximum number of the other peers that announced the pending transaction.
+pub const MAX_ANNOUNCERS: usize = 4;
+
+/// The peer that announced the transaction and the id of its gossipsub message.
+pub type Announcer = (PeerId, Vec<u8>);
+
+/// Recently gossiped transactions kept for the deduplication window.
+///
+/// Transactions are announced to the network by their ids, and the peers request the bodies
+/// they don't know yet. The cache serves these requests with the transactions accepted
+/// by the transaction pool and tracks which transactions were already requested by us
+/// or sent to a peer, so the same body is not transferred twice within the window.
+#[derive(Debug)]
+pub struct RecentTransactions {
+    window: Duration,
+    capacity: usize,
+    transactions: HashMap<TxId, Arc<Transaction>>,
+    /// The cached transactions in the order of insertion.
+    order: VecDeque<(Instant, TxId)>,
+    /// The transactions requested by us that are not yet validated,
+    /// with the other peers that announced them.
+    pending: HashMap<TxId, VecDeque<Announcer>>,
+    /// The received transactions waiting for the validation by the transaction pool,
+    /// by the id of the gossipsub message.
+    validating: HashMap<Vec<u8>, (TxId, Arc<Transaction>)>,
+    /// The transactions already sent to the peer.
+    sent: HashMap<PeerId, HashSet<TxId>>,
+    expiration: VecDeque<(Instant, Entry)>,
+}
+
+#[derive(Debug)]
+enum Entry {
+    Pending(TxId),
+    Validating(Vec<u8>),
+    Sent(PeerId, TxId),
+}
+
+impl RecentTransactions {
+    pub fn new(window: Duration, capacity: usize) -> Self {
+        Self {
+            window,
+            capacity,
+            transactions: HashMap::new(),
+            order: VecDeque::new(),
+            pending: HashMap::new(),
+            validating: HashMap::new(),
+            sent: HashMap::new(),
+            expiration: VecDeque::new(),
+        }
+    }
+
+    /// Caches the accepted transaction for the duration of the window,
+    /// evicting the oldest transactions above the capacity.
+    /// Returns `false` if the transaction was already known.
+    pub fn insert(&mut self, now: Instant, tx_id: TxId, tx: Arc<Transaction>) -> bool {
+        if self.transactions.contains_key(&tx_id) {
+            return false
+        }
+        self.transactions.insert(tx_id, tx);
+        self.order.push_back((now, tx_id));
+        while self.transactions.len() > self.capacity {
+            let Some((_, tx_id)) = self.order.pop_front() else {
+                break
+            };
+            self.transactions.remove(&tx_id);
+        }
+        true
+    }
+
+    /// Returns `true` if the transaction is cached or already requested.
+    pub fn is_known(&self, tx_id: &TxId) -> bool {
+        self.transactions.contains_key(tx_id) || self.pending.contains_key(tx_id)
+    }
+
+    /// Marks the transaction as requested. Returns `false` if it is already known,
+    /// and the body shouldn't be requested again.
+    pub fn start_request(&mut self, now: Instant, tx_id: TxId) -> bool {
+        if self.is_known(&tx_id) {
+            return false
+        }
+        self.pending.insert(tx_id, VecDeque::new());
+        self.expiration.push_back((now, Entry::Pending(tx_id)));
+        true
+    }
+
+    /// Remembers another peer that announced the pending transaction, so the body
+    /// can be requested from it if the first peer doesn't serve it.
+    /// Returns `false` if the transaction is not pending or has enough announcers.
+    pub fn add_announcer(&mut self, tx_id: &TxId, announcer: Announcer) -> bool {
+        let Some(announcers) = self.pending.get_mut(tx_id) else {
+            return false
+        };
+        if announcers.len() >= MAX_ANNOUNCERS
+            || announcers
+                .iter()
+                .any(|(peer_id, _)| *peer_id == announcer.0)
+        {
+            return false
+        }
+        announcers.push_back(announcer);
+        true
+    }
+
+    /// Returns the next peer to request the pending transaction from.
+    /// Clears the pending request if there are no more announcers,
+    /// allowing the transaction to be requested again.
+    pub fn next_announcer(&mut self, tx_id: &TxId) -> Option<Announcer> {
+        let announcer = self.pending.get_mut(tx_id)?.pop_front();
+        if announcer.is_none() {
+            self.pending.remove(tx_id);
+        }
+        announcer
+    }
+
+    /// Keeps the received transaction until the transaction pool validates it.
+    pub fn start_validation(
+        &mut self,
+        now: Instant,
+        message_id: Vec<u8>,
+        tx_id: TxId,
+        tx: Arc<Transaction>,
+    ) {
+        self.validating.insert(message_id.clone(), (tx_id, tx));
+        self.expiration
+            .push_back((now, Entry::Validating(message_id)));
+    }
+
+    /// Caches the transaction if the transaction pool `accepted` it and finishes its request.
+    /// Returns the other announcers of the transaction that are no longer needed.
+    pub fn finish_validation(
+        &mut self,
+        now: Instant,
+        message_id: &[u8],
+        accepted: bool,
+    ) -> Vec<Announcer> {
+        let Some((tx_id, tx)) = self.validating.remove(message_id) else {
+            return vec![]
+        };
+        let announcers = self.pending.remove(&tx_id).unwrap_or_default();
+        if accepted {
+            self.insert(now, tx_id, tx);
+        }
+        announcers.into()
+    }
+
+    /// Returns the cached transactions requested by the `peer`, skipping unknown ones
+    /// and the ones already sent to the `peer` within the window.
+    pub fn take_for_peer(
+        &mut self,
+        now: Instant,
+        peer: PeerId,
+        tx_ids: &[TxId],
+    ) -> Vec<Transaction> {
+        let mut result = Vec::new();
+        for tx_id in tx_ids {
+            let Some(tx) = self.transactions.get(tx_id) else {
+                continue
+            };
+            if !self.sent.entry(peer).or_default().insert(*tx_id) {
+                continue
+            }
+            self.expiration.push_back((now, Entry::Sent(peer, *tx_id)));
+            result.push(tx.as_ref().clone());
+        }
+        result
+    }
+
+    /// Removes entries older than the window.
+    pub fn prune(&mut self, now: Instant) {
+        while let Some((inserted_at, _)) = self.order.front() {
+            if now.saturating_duration_since(*inserted_at) < self.window {
+                break
+            }
+            let Some((_, tx_id)) = self.order.pop_front() else {
+                break
+            };
+            self.transactions.remove(&tx_id);
+        }
+
+        while let Some((inserted_at, _)) = self.expiration.front() {
+            if now.saturating_duration_since(*inserted_at) < self.window {
+                break
+            }
+            let Some((_, entry)) = self.expiration.pop_front() else {
+                break
+            };
+            match entry {
+                Entry::Pending(tx_id) => {
+                    self.pending.remove(&tx_id);
+                }
+                Entry::Validating(message_id) => {
+                    self.validating.remove(&message_id);
+                }
+                Entry::Sent(peer, tx_id) => {
+                    if let Some(sent) = self.sent.get_mut(&peer) {
+                        sent.remove(&tx_id);
+                        if sent.is_empty() {
+                            self.sent.remove(&peer);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Forgets everything sent to the disconnected `peer`.
+    pub fn remove_peer(&mut self, peer: &PeerId) {
+        self.sent.remove(peer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    use super::*;
+    use fuel_core_types::{
+        fuel_tx::UniqueIdentifier,
+        fuel_types::ChainId,
+    };
+
+    const WINDOW: Duration = Duration::from_secs(60);
+
+    fn tx() -> (TxId, Arc<Transaction>) {
+        let tx = Transaction::default_test_tx();
+        (tx.id(&ChainId::default()), Arc::new(tx))
+    }
+
+    #[test]
+    fn insert__returns_false_for_known_transaction() {
+        let mut recent = RecentTransactions::new(WINDOW, MAX_RECENT_TRANSACTIONS);
+        let now = Instant::now();
+        let (tx_id, tx) = tx();
+
+        assert!(recent.insert(now, tx_id, tx.clone()));
+        assert!(!recent.insert(now, tx_id, tx));
+        assert!(recent.is_known(&tx_id));
+    }
+
+    #[test]
+    fn insert__evicts_oldest_transaction_above_capacity() {
+        let mut recent = RecentTransactions::new(WINDOW, 2);
+        let now = Instant::now();
+        let (_, tx) = tx();
+        let tx_ids = [
+            TxId::from([1; 32]),
+            TxId::from([2; 32]),
+            TxId::from([3; 32]),
+        ];
+
+        for tx_id in tx_ids {
+            recent.insert(now, tx_id, tx.clone());
+        }
+
+        assert!(!recent.is_known(&tx_ids[0]));
+        assert!(recent.is_known(&tx_ids[1]));
+        assert!(recent.is_known(&tx_ids[2]));
+    }
+
+    #[test]
+    fn start_request__requests_transaction_only_once() {
+        let mut recent = RecentTransactions::new(WINDOW, MAX_RECENT_TRANSACTIONS);
+        let now = Instant::now();
+        let (tx_id, tx) = tx();
+
+        assert!(recent.start_request(now, tx_id));
+        assert!(!recent.start_request(now, tx_id));
+
+        assert_eq!(recent.next_announcer(&tx_id), None);
+        assert!(recent.start_request(now, tx_id));
+
+        recent.start_validation(now, vec![1], tx_id, tx);
+        recent.finish_validation(now, &[1], true);
+        assert!(!recent.start_request(now, tx_id));
+    }
+
+    #[test]
+    fn next_announcer__returns_other_announcers_in_order() {
+        let mut recent = RecentTransactions::new(WINDOW, MAX_RECENT_TRANSACTIONS);
+        let now = Instant::now();
+        let (tx_id, _) = tx();
+        let first = (PeerId::random(), vec![1]);
+        let second = (PeerId::random(), vec![2]);
+        assert!(!recent.add_announcer(&tx_id, first.clone()));
+
+        recent.start_request(now, tx_id);
+        assert!(recent.add_announcer(&tx_id, first.clone()));
+        assert!(!recent.add_announcer(&tx_id, first.clone()));
+        assert!(recent.add_announcer(&tx_id, second.clone()));
+
+        assert_eq!(recent.next_announcer(&tx_id), Some(first));
+        assert_eq!(recent.next_announcer(&tx_id), Some(second));
+        assert!(recent.is_known(&tx_id));
+        assert_eq!(recent.next_announcer(&tx_id), None);
+        assert!(!recent.is_known(&tx_id));
+    }
+
+    #[test]
+    fn add_announcer__limits_number_of_announcers() {
+        let mut recent = RecentTransactions::new(WINDOW, MAX_RECENT_TRANSACTIONS);
+        let (tx_id, _) = tx();
+        recent.start_request(Instant::now(), tx_id);
+
+        for _ in 0..MAX_ANNOUNCERS {
+            assert!(recent.add_announcer(&tx_id, (PeerId::random(), vec![])));
+        }
+
+        assert!(!recent.add_announcer(&tx_id, (PeerId::random(), vec![])));
+    }
+
+    #[test]
+    fn finish_validation__caches_only_accepted_transaction() {
+        let mut recent = RecentTransactions::new(WINDOW, MAX_RECENT_TRANSACTIONS);
+        let now = Instant::now();
+        let (tx_id, tx) = tx();
+        let peer = PeerId::random();
+        let announcer = (PeerId::random(), vec![2]);
+        recent.start_request(now, tx_id);
+        recent.add_announcer(&tx_id, announcer.clone());
+
+        recent.start_validation(now, vec![1], tx_id, tx.clone());
+        assert!(recent.take_for_peer(now, peer, &[tx_id]).is_empty());
+        assert_eq!(recent.finish_validation(now, &[1], false), vec![announcer]);
+        assert!(!recent.is_known(&tx_id));
+
+        recent.start_validation(now, vec![3], tx_id, tx.clone());
+        assert!(recent.finish_validation(now, &[3], true).is_empty());
+        assert_eq!(
+            recent.take_for_peer(now, peer, &[tx_id]),
+            vec![tx.as_ref().clone()]
+        );
+    }
+
+    #[test]
+    fn take_for_peer__sends_transaction_once_per_peer() {
+        let mut recent = RecentTransactions::new(WINDOW, MAX_RECENT_TRANSACTIONS);
+        let now = Instant::now();
+        let (tx_id, tx) = tx();
+        let unknown = TxId::from([1; 32]);
+        recent.insert(now, tx_id, tx.clone());
+        let first = PeerId::random();
+        let second = PeerId::random();
+
+        assert_eq!(
+            recent.take_for_peer(now, first, &[tx_id, unknown]),
+            vec![tx.as_ref().clone()]
+        );
+        assert!(recent.take_for_peer(now, first, &[tx_id]).is_empty());
+        assert_eq!(
+            recent.take_for_peer(now, second, &[tx_id]),
+            vec![tx.as_ref().clone()]
+        );
+    }
+
+    #[test]
+    fn prune__removes_entries_outside_of_window() {
+        let mut recent = RecentTransactions::new(WINDOW, MAX_RECENT_TRANSACTIONS);
+        let now = Instant::now();
+        let (tx_id, tx) = tx();
+        let pending = TxId::from([1; 32]);
+        let peer = PeerId::random();
+        recent.insert(now, tx_id, tx.clone());
+        recent.start_request(now, pending);
+        recent.start_validation(now, vec![1], pending, tx.clone());
+        recent.take_for_peer(now, peer, &[tx_id]);
+
+        recent.prune(now + WINDOW / 2);
+        assert!(recent.is_known(&tx_id));
+        assert!(recent.is_known(&pending));
+
+        recent.prune(now + WINDOW);
+        assert!(!recent.is_known(&tx_id));
+        assert!(!recent.is_known(&pending));
+        assert!(recent.finish_validation(now, &[1], true).is_empty());
+        assert!(!recent.is_known(&pending));
+
+        let later = now + WINDOW;
+        recent.insert(later, tx_id, tx.clone());
+        assert_eq!(
+            recent.take_for_peer(later, peer, &[tx_id]),
+            vec![tx.as_ref().clone()]
+        );
+    }
+}
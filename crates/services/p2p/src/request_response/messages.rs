@@ -1,5 +1,9 @@
 use fuel_core_types::{
     blockchain::SealedBlockHeader,
+    fuel_tx::{
+        Transaction,
+        TxId,
+    },
     services::p2p::Transactions,
 };
 use libp2p::{
@@ -14,7 +18,10 @@ use std::ops::Range;
 use thiserror::Error;
 use tokio::sync::oneshot;
 
-pub(crate) const REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.1";
+pub const REQUEST_RESPONSE_PROTOCOL_ID: &str = "/fuel/req_res/0.0.1";
+
+/// Max number of the transactions requested by their ids in a single request
+pub const MAX_TRANSACTIONS_BY_IDS_PER_REQUEST: usize = 64;
 
 /// Max Size in Bytes of the Request Message
 #[cfg(test)]
@@ -24,12 +31,16 @@ pub(crate) const MAX_REQUEST_SIZE: usize = core::mem::size_of::<RequestMessage>(
 pub enum RequestMessage {
     SealedHeaders(Range<u32>),
     Transactions(Range<u32>),
+    /// The bodies of the announced transactions
+    TransactionsByIds(Vec<TxId>),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ResponseMessage {
     SealedHeaders(Option<Vec<SealedBlockHeader>>),
     Transactions(Option<Vec<Transactions>>),
+    /// The requested transactions known to the peer, the unknown ones are skipped
+    TransactionsByIds(Vec<Transaction>),
 }
 
 pub type OnResponse<T> = oneshot::Sender<(PeerId, Result<T, ResponseError>)>;
@@ -38,6 +49,7 @@ pub type OnResponse<T> = oneshot::Sender<(PeerId, Result<T, ResponseError>)>;
 pub enum ResponseSender {
     SealedHeaders(OnResponse<Option<Vec<SealedBlockHeader>>>),
     Transactions(OnResponse<Option<Vec<Transactions>>>),
+    TransactionsByIds(OnResponse<Vec<Transaction>>),
 }
 
 #[derive(Debug, Error)]
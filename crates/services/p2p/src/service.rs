@@ -17,11 +17,17 @@ use crate::{
         BlockHeightImporter,
         P2pDb,
     },
+    recent_transactions::{
+        RecentTransactions,
+        MAX_RECENT_TRANSACTIONS,
+    },
     request_response::messages::{
         OnResponse,
         RequestMessage,
+        ResponseError,
         ResponseMessage,
         ResponseSender,
+        MAX_TRANSACTIONS_BY_IDS_PER_REQUEST,
     },
 };
 use anyhow::anyhow;
@@ -37,6 +43,7 @@ use fuel_core_types::{
     blockchain::SealedBlockHeader,
     fuel_tx::{
         Transaction,
+        TxId,
         UniqueIdentifier,
     },
     fuel_types::{
@@ -59,6 +66,8 @@ use fuel_core_types::{
 };
 use futures::{
     future::BoxFuture,
+    stream::FuturesUnordered,
+    FutureExt,
     StreamExt,
 };
 use libp2p::{
@@ -67,6 +76,10 @@ use libp2p::{
     PeerId,
 };
 use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
     fmt::Debug,
     ops::Range,
     sync::Arc,
@@ -76,6 +89,7 @@ use tokio::{
         broadcast,
         mpsc,
         oneshot,
+        oneshot::error::RecvError,
     },
     time::{
         Duration,
@@ -141,6 +155,19 @@ impl Debug for TaskRequest {
     }
 }
 
+/// The reputation penalty for the peer that announced the transaction but didn't serve it.
+const UNSERVED_ANNOUNCEMENT_PENALTY: AppScore = -5.;
+
+/// The announcement of the transaction with the id of the gossipsub message.
+type Announcement = (TxId, Vec<u8>);
+
+/// The bodies of the announced transactions requested from the announcing peer.
+struct AnnouncedTransactions {
+    peer_id: PeerId,
+    announcements: Vec<Announcement>,
+    response: Result<(PeerId, Result<Vec<Transaction>, ResponseError>), RecvError>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HeartBeatPeerReportReason {
     OldHeartBeat,
@@ -325,6 +352,13 @@ pub struct Task<P, V, B> {
     heartbeat_max_time_since_last: Duration,
     next_check_time: Instant,
     heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig,
+    tx_announcements: bool,
+    recent_transactions: RecentTransactions,
+    /// The announcements waiting for the in-flight request to the peer to finish.
+    queued_announcements: HashMap<PeerId, Vec<Announcement>>,
+    /// Only one request for the announced transactions is in-flight per peer.
+    announced_transactions: FuturesUnordered<BoxFuture<'static, AnnouncedTransactions>>,
+    requested_peers: HashSet<PeerId>,
 }
 
 #[derive(Clone)]
@@ -410,6 +444,212 @@ impl<P: TaskP2PService, V, B: Broadcast> Task<P, V, B> {
             .report_peer(peer_id, app_score, reporting_service)?;
         Ok(())
     }
+
+    fn request_announced_transaction(
+        &mut self,
+        tx_id: TxId,
+        peer_id: PeerId,
+        message_id: Vec<u8>,
+    ) -> anyhow::Result<()> {
+        if self
+            .recent_transactions
+            .start_request(Instant::now(), tx_id)
+        {
+            return self.queue_announcement(peer_id, tx_id, message_id)
+        }
+
+        // The announcement is kept in the case if the requested peer doesn't serve the body
+        if self
+            .recent_transactions
+            .add_announcer(&tx_id, (peer_id, message_id.clone()))
+        {
+            return Ok(())
+        }
+
+        self.report_announcement(peer_id, message_id, GossipsubMessageAcceptance::Ignore)
+    }
+
+    fn queue_announcement(
+        &mut self,
+        peer_id: PeerId,
+        tx_id: TxId,
+        message_id: Vec<u8>,
+    ) -> anyhow::Result<()> {
+        self.queued_announcements
+            .entry(peer_id)
+            .or_default()
+            .push((tx_id, message_id));
+
+        if self.requested_peers.contains(&peer_id) {
+            return Ok(())
+        }
+        self.request_queued_announcements(peer_id)
+    }
+
+    /// Requests the pending transaction from the next peer that announced it, if any.
+    fn request_from_next_announcer(&mut self, tx_id: TxId) -> anyhow::Result<()> {
+        match self.recent_transactions.next_announcer(&tx_id) {
+            Some((peer_id, message_id)) => {
+                self.queue_announcement(peer_id, tx_id, message_id)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn request_queued_announcements(&mut self, peer_id: PeerId) -> anyhow::Result<()> {
+        let Some(queue) = self.queued_announcements.get_mut(&peer_id) else {
+            return Ok(())
+        };
+        let len = queue.len().min(MAX_TRANSACTIONS_BY_IDS_PER_REQUEST);
+        let announcements: Vec<_> = queue.drain(..len).collect();
+        if queue.is_empty() {
+            self.queued_announcements.remove(&peer_id);
+        }
+        if announcements.is_empty() {
+            return Ok(())
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        let tx_ids = announcements.iter().map(|(tx_id, _)| *tx_id).collect();
+        let request_msg = RequestMessage::TransactionsByIds(tx_ids);
+        let channel = ResponseSender::TransactionsByIds(sender);
+        if let Err(e) =
+            self.p2p_service
+                .send_request_msg(Some(peer_id), request_msg, channel)
+        {
+            tracing::warn!(
+                "Failed to request the announced transactions from {peer_id}: {e}"
+            );
+            for (tx_id, message_id) in announcements {
+                self.report_announcement(
+                    peer_id,
+                    message_id,
+                    GossipsubMessageAcceptance::Ignore,
+                )?;
+                self.request_from_next_announcer(tx_id)?;
+            }
+            return Ok(())
+        }
+
+        self.requested_peers.insert(peer_id);
+        self.announced_transactions.push(
+            async move {
+                AnnouncedTransactions {
+                    peer_id,
+                    announcements,
+                    response: receiver.await,
+                }
+            }
+            .boxed(),
+        );
+        Ok(())
+    }
+
+    fn handle_announced_transactions(
+        &mut self,
+        announced: AnnouncedTransactions,
+    ) -> anyhow::Result<()> {
+        let AnnouncedTransactions {
+            peer_id,
+            announcements,
+            response,
+        } = announced;
+        self.requested_peers.remove(&peer_id);
+
+        let transactions = match response {
+            Ok((_, Ok(transactions))) => transactions,
+            Ok((_, Err(e))) => {
+                tracing::debug!(
+                    "Failed to receive the announced transactions from {peer_id}: {e:?}"
+                );
+                vec![]
+            }
+            Err(_) => vec![],
+        };
+        let mut transactions: HashMap<_, _> = transactions
+            .into_iter()
+            .map(|tx| (tx.id(&self.chain_id), tx))
+            .collect();
+
+        let mut unserved = false;
+        for (tx_id, message_id) in announcements {
+            if let Some(transaction) = transactions.remove(&tx_id) {
+                self.recent_transactions.start_validation(
+                    Instant::now(),
+                    message_id.clone(),
+                    tx_id,
+                    Arc::new(transaction.clone()),
+                );
+                // The validation of the transaction reports the acceptance of the announcement.
+                let next_transaction = GossipData::new(transaction, peer_id, message_id);
+                let _ = self.broadcast.tx_broadcast(next_transaction);
+            } else {
+                unserved = true;
+                self.report_announcement(
+                    peer_id,
+                    message_id,
+                    GossipsubMessageAcceptance::Ignore,
+                )?;
+                self.request_from_next_announcer(tx_id)?;
+            }
+        }
+
+        if unserved {
+            tracing::debug!("Peer {peer_id} didn't serve the announced transactions");
+            self.p2p_service.report_peer(
+                peer_id,
+                UNSERVED_ANNOUNCEMENT_PENALTY,
+                "p2p",
+            )?;
+        }
+
+        if !transactions.is_empty() {
+            tracing::warn!(
+                "Peer {peer_id} responded with {} not requested transactions",
+                transactions.len()
+            );
+        }
+
+        self.request_queued_announcements(peer_id)
+    }
+
+    /// Reports the acceptance of the gossiped transaction. The transactions accepted by
+    /// the transaction pool are cached to serve the announcements, and the other
+    /// announcements of the same transaction are ignored.
+    fn report_transaction_acceptance(
+        &mut self,
+        message: GossipsubMessageInfo,
+        acceptance: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        let announcers = self.recent_transactions.finish_validation(
+            Instant::now(),
+            &message.message_id,
+            acceptance == GossipsubMessageAcceptance::Accept,
+        );
+        self.p2p_service.report_message(message, acceptance)?;
+
+        for (peer_id, message_id) in announcers {
+            self.report_announcement(
+                peer_id,
+                message_id,
+                GossipsubMessageAcceptance::Ignore,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn report_announcement(
+        &mut self,
+        peer_id: PeerId,
+        message_id: Vec<u8>,
+        acceptance: GossipsubMessageAcceptance,
+    ) -> anyhow::Result<()> {
+        let message = GossipsubMessageInfo {
+            message_id,
+            peer_id: convert_peer_id(&peer_id)?,
+        };
+        self.p2p_service.report_message(message, acceptance)
+    }
 }
 
 fn convert_peer_id(peer_id: &PeerId) -> anyhow::Result<FuelPeerId> {
@@ -456,6 +696,8 @@ where
             heartbeat_check_interval,
             heartbeat_max_avg_interval,
             heartbeat_max_time_since_last,
+            tx_announcements,
+            tx_gossip_window,
             ..
         } = config;
 
@@ -491,6 +733,14 @@ where
             heartbeat_max_time_since_last,
             next_check_time,
             heartbeat_peer_reputation_config,
+            tx_announcements,
+            recent_transactions: RecentTransactions::new(
+                tx_gossip_window,
+                MAX_RECENT_TRANSACTIONS,
+            ),
+            queued_announcements: HashMap::new(),
+            announced_transactions: FuturesUnordered::new(),
+            requested_peers: HashSet::new(),
         };
         Ok(task)
     }
@@ -521,7 +771,13 @@ where
                 match next_service_request {
                     Some(TaskRequest::BroadcastTransaction(transaction)) => {
                         let tx_id = transaction.id(&self.chain_id);
-                        let broadcast = GossipsubBroadcastRequest::NewTx(transaction);
+                        let broadcast = if self.tx_announcements {
+                            // The transaction pool broadcasts only the accepted transactions
+                            self.recent_transactions.insert(Instant::now(), tx_id, transaction);
+                            GossipsubBroadcastRequest::NewTxAnnouncement(tx_id)
+                        } else {
+                            GossipsubBroadcastRequest::NewTx(transaction)
+                        };
                         let result = self.p2p_service.publish_message(broadcast);
                         if let Err(e) = result {
                             tracing::error!("Got an error during transaction {} broadcasting {}", tx_id, e);
@@ -549,8 +805,7 @@ where
                         self.p2p_service.send_request_msg(Some(from_peer), request_msg, channel).expect("We always a peer here, so send has a target");
                     }
                     Some(TaskRequest::RespondWithGossipsubMessageReport((message, acceptance))) => {
-                        // report_message(&mut self.p2p_service, message, acceptance);
-                        self.p2p_service.report_message(message, acceptance)?;
+                        self.report_transaction_acceptance(message, acceptance)?;
                     }
                     Some(TaskRequest::RespondWithPeerReport { peer_id, score, reporting_service }) => {
                         let _ = self.p2p_service.report_peer(peer_id, score, reporting_service);
@@ -584,13 +839,31 @@ where
 
                         match message {
                             GossipsubMessage::NewTx(transaction) => {
+                                if self.tx_announcements {
+                                    let tx_id = transaction.id(&self.chain_id);
+                                    self.recent_transactions.start_validation(Instant::now(), message_id.clone(), tx_id, Arc::new(transaction.clone()));
+                                }
                                 let next_transaction = GossipData::new(transaction, peer_id, message_id);
                                 let _ = self.broadcast.tx_broadcast(next_transaction);
                             },
+                            GossipsubMessage::NewTxAnnouncement(tx_id) => {
+                                self.request_announced_transaction(tx_id, peer_id, message_id)?;
+                            },
+                        }
+                    },
+                    Some(FuelP2PEvent::PeerDisconnected(peer_id)) => {
+                        self.recent_transactions.remove_peer(&peer_id);
+                        for (tx_id, _) in self.queued_announcements.remove(&peer_id).unwrap_or_default() {
+                            self.request_from_next_announcer(tx_id)?;
                         }
                     },
-                    Some(FuelP2PEvent::InboundRequestMessage { request_message, request_id }) => {
+                    Some(FuelP2PEvent::InboundRequestMessage { peer_id, request_message, request_id }) => {
                         match request_message {
+                            RequestMessage::TransactionsByIds(mut tx_ids) => {
+                                tx_ids.truncate(MAX_TRANSACTIONS_BY_IDS_PER_REQUEST);
+                                let transactions = self.recent_transactions.take_for_peer(Instant::now(), peer_id, &tx_ids);
+                                let _ = self.p2p_service.send_response_msg(request_id, ResponseMessage::TransactionsByIds(transactions));
+                            }
                             RequestMessage::Transactions(range) => {
                                 let view = self.view_provider.latest_view();
                                 match view.get_transactions(range.clone()) {
@@ -633,6 +906,10 @@ where
                     _ => (),
                 }
             },
+            Some(announced) = self.announced_transactions.next() => {
+                should_continue = true;
+                self.handle_announced_transactions(announced)?;
+            },
             _  = tokio::time::sleep_until(self.next_check_time) => {
                 should_continue = true;
                 let res = self.peer_heartbeat_reputation_checks();
@@ -642,6 +919,7 @@ where
                         tracing::error!("Failed to perform peer heartbeat reputation checks: {:?}", e);
                     }
                 }
+                self.recent_transactions.prune(Instant::now());
                 self.next_check_time += self.heartbeat_check_interval;
             },
             latest_block_height = self.next_block_height.next() => {
@@ -949,8 +1227,12 @@ pub mod tests {
         assert!(service.stop_and_await().await.unwrap().stopped());
     }
 
+    #[derive(Default)]
     struct FakeP2PService {
         peer_info: Vec<(PeerId, PeerInfo)>,
+        requested_peers: Vec<PeerId>,
+        reported_messages: Vec<(Vec<u8>, GossipsubMessageAcceptance)>,
+        reported_peers: Vec<(PeerId, AppScore)>,
     }
 
     impl TaskP2PService for FakeP2PService {
@@ -979,11 +1261,12 @@ pub mod tests {
 
         fn send_request_msg(
             &mut self,
-            _peer_id: Option<PeerId>,
+            peer_id: Option<PeerId>,
             _request_msg: RequestMessage,
             _on_response: ResponseSender,
         ) -> anyhow::Result<()> {
-            todo!()
+            self.requested_peers.extend(peer_id);
+            Ok(())
         }
 
         fn send_response_msg(
@@ -996,19 +1279,22 @@ pub mod tests {
 
         fn report_message(
             &mut self,
-            _message: GossipsubMessageInfo,
-            _acceptance: GossipsubMessageAcceptance,
+            message: GossipsubMessageInfo,
+            acceptance: GossipsubMessageAcceptance,
         ) -> anyhow::Result<()> {
-            todo!()
+            self.reported_messages
+                .push((message.message_id, acceptance));
+            Ok(())
         }
 
         fn report_peer(
             &mut self,
-            _peer_id: PeerId,
-            _score: AppScore,
+            peer_id: PeerId,
+            score: AppScore,
             _reporting_service: &str,
         ) -> anyhow::Result<()> {
-            todo!()
+            self.reported_peers.push((peer_id, score));
+            Ok(())
         }
 
         fn update_block_height(&mut self, _height: BlockHeight) -> anyhow::Result<()> {
@@ -1110,11 +1396,15 @@ pub mod tests {
         let peer_info = PeerInfo {
             peer_addresses: Default::default(),
             client_version: None,
+            protocol_versions: None,
             heartbeat_data,
             score: 100.0,
         };
         let peer_info = vec![(peer_id, peer_info)];
-        let p2p_service = FakeP2PService { peer_info };
+        let p2p_service = FakeP2PService {
+            peer_info,
+            ..Default::default()
+        };
         let (_request_sender, request_receiver) = mpsc::channel(100);
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
@@ -1146,6 +1436,14 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            tx_announcements: false,
+            recent_transactions: RecentTransactions::new(
+                Duration::from_secs(60),
+                MAX_RECENT_TRANSACTIONS,
+            ),
+            queued_announcements: HashMap::new(),
+            announced_transactions: FuturesUnordered::new(),
+            requested_peers: HashSet::new(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1191,11 +1489,15 @@ pub mod tests {
         let peer_info = PeerInfo {
             peer_addresses: Default::default(),
             client_version: None,
+            protocol_versions: None,
             heartbeat_data,
             score: 100.0,
         };
         let peer_info = vec![(peer_id, peer_info)];
-        let p2p_service = FakeP2PService { peer_info };
+        let p2p_service = FakeP2PService {
+            peer_info,
+            ..Default::default()
+        };
         let (_request_sender, request_receiver) = mpsc::channel(100);
 
         let (report_sender, mut report_receiver) = mpsc::channel(100);
@@ -1227,6 +1529,14 @@ pub mod tests {
             heartbeat_max_time_since_last,
             next_check_time: Instant::now(),
             heartbeat_peer_reputation_config: heartbeat_peer_reputation_config.clone(),
+            tx_announcements: false,
+            recent_transactions: RecentTransactions::new(
+                Duration::from_secs(60),
+                MAX_RECENT_TRANSACTIONS,
+            ),
+            queued_announcements: HashMap::new(),
+            announced_transactions: FuturesUnordered::new(),
+            requested_peers: HashSet::new(),
         };
         let (watch_sender, watch_receiver) = tokio::sync::watch::channel(State::Started);
         let mut watcher = StateWatcher::from(watch_receiver);
@@ -1250,4 +1560,112 @@ pub mod tests {
         );
         assert_eq!(reporting_service, "p2p");
     }
+
+    fn announcements_task() -> Task<FakeP2PService, FakeDB, FakeBroadcast> {
+        let (_request_sender, request_receiver) = mpsc::channel(100);
+        let (report_sender, _report_receiver) = mpsc::channel(100);
+        Task {
+            chain_id: Default::default(),
+            p2p_service: FakeP2PService::default(),
+            view_provider: FakeDB,
+            next_block_height: FakeBlockImporter.next_block_height(),
+            request_receiver,
+            broadcast: FakeBroadcast {
+                peer_reports: report_sender,
+            },
+            max_headers_per_request: 0,
+            heartbeat_check_interval: Duration::from_secs(0),
+            heartbeat_max_avg_interval: Duration::from_secs(0),
+            heartbeat_max_time_since_last: Duration::from_secs(0),
+            next_check_time: Instant::now(),
+            heartbeat_peer_reputation_config: HeartbeatPeerReputationConfig {
+                old_heartbeat_penalty: 0.,
+                low_heartbeat_frequency_penalty: 0.,
+            },
+            tx_announcements: true,
+            recent_transactions: RecentTransactions::new(
+                Duration::from_secs(60),
+                MAX_RECENT_TRANSACTIONS,
+            ),
+            queued_announcements: HashMap::new(),
+            announced_transactions: FuturesUnordered::new(),
+            requested_peers: HashSet::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn handle_announced_transactions__requests_unserved_transaction_from_next_announcer(
+    ) {
+        // given
+        let mut task = announcements_task();
+        let tx_id = TxId::from([1; 32]);
+        let first = PeerId::random();
+        let second = PeerId::random();
+        task.request_announced_transaction(tx_id, first, vec![1])
+            .unwrap();
+        task.request_announced_transaction(tx_id, second, vec![2])
+            .unwrap();
+        assert_eq!(task.p2p_service.requested_peers, vec![first]);
+
+        // when
+        // The fake service drops the response channel, as if the peer didn't respond
+        let announced = task.announced_transactions.next().await.unwrap();
+        task.handle_announced_transactions(announced).unwrap();
+
+        // then
+        assert_eq!(task.p2p_service.requested_peers, vec![first, second]);
+        assert_eq!(
+            task.p2p_service.reported_peers,
+            vec![(first, UNSERVED_ANNOUNCEMENT_PENALTY)]
+        );
+        assert_eq!(
+            task.p2p_service.reported_messages,
+            vec![(vec![1], GossipsubMessageAcceptance::Ignore)]
+        );
+    }
+
+    #[tokio::test]
+    async fn report_transaction_acceptance__caches_only_accepted_transaction() {
+        // given
+        let mut task = announcements_task();
+        let transaction = Transaction::default_test_tx();
+        let tx_id = transaction.id(&task.chain_id);
+        let peer_id = PeerId::random();
+        let other_announcer = PeerId::random();
+        task.request_announced_transaction(tx_id, peer_id, vec![1])
+            .unwrap();
+        task.request_announced_transaction(tx_id, other_announcer, vec![2])
+            .unwrap();
+        task.recent_transactions.start_validation(
+            Instant::now(),
+            vec![1],
+            tx_id,
+            Arc::new(transaction.clone()),
+        );
+        let message = GossipsubMessageInfo {
+            message_id: vec![1],
+            peer_id: convert_peer_id(&peer_id).unwrap(),
+        };
+
+        // when
+        task.report_transaction_acceptance(message, GossipsubMessageAcceptance::Accept)
+            .unwrap();
+
+        // then
+        assert_eq!(
+            task.p2p_service.reported_messages,
+            vec![
+                (vec![1], GossipsubMessageAcceptance::Accept),
+                (vec![2], GossipsubMessageAcceptance::Ignore),
+            ]
+        );
+        assert_eq!(
+            task.recent_transactions.take_for_peer(
+                Instant::now(),
+                PeerId::random(),
+                &[tx_id]
+            ),
+            vec![transaction]
+        );
+    }
 }
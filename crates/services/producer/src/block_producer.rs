@@ -29,13 +29,17 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
-            TransactionExecutionStatus,
+            DryRunResult,
+            StateOverrides,
             UncommittedResult,
         },
     },
     tai64::Tai64,
 };
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::Instant,
+};
 use tokio::sync::Mutex;
 use tracing::debug;
 
@@ -82,6 +86,7 @@ where
     ViewProvider::View: BlockProducerDatabase,
 {
     /// Produces and execute block for the specified height.
+    #[tracing::instrument(skip_all, fields(height = %height), err)]
     async fn produce_and_execute<TxSource, ExecutorDB>(
         &self,
         height: BlockHeight,
@@ -147,7 +152,13 @@ where
         self.produce_and_execute(
             height,
             block_time,
-            |height| self.txpool.get_source(height),
+            |height| {
+                let deadline = self
+                    .config
+                    .block_production_budget
+                    .and_then(|budget| Instant::now().checked_add(budget));
+                self.txpool.get_source(height, deadline)
+            },
             max_gas,
         )
         .await
@@ -188,7 +199,9 @@ where
         transactions: Vec<Transaction>,
         height: Option<BlockHeight>,
         utxo_validation: Option<bool>,
-    ) -> anyhow::Result<Vec<TransactionExecutionStatus>> {
+        state_overrides: StateOverrides,
+        profile_gas: bool,
+    ) -> anyhow::Result<Vec<DryRunResult>> {
         let height = height.unwrap_or_else(|| {
             self.view_provider
                 .latest_height()
@@ -213,23 +226,27 @@ where
         let executor = self.executor.clone();
 
         // use the blocking threadpool for dry_run to avoid clogging up the main async runtime
-        let tx_statuses = tokio_rayon::spawn_fifo(
-            move || -> anyhow::Result<Vec<TransactionExecutionStatus>> {
-                Ok(executor.dry_run(component, utxo_validation)?)
-            },
-        )
-        .await?;
+        let results =
+            tokio_rayon::spawn_fifo(move || -> anyhow::Result<Vec<DryRunResult>> {
+                Ok(executor.dry_run(
+                    component,
+                    utxo_validation,
+                    state_overrides,
+                    profile_gas,
+                )?)
+            })
+            .await?;
 
         if transactions
             .iter()
-            .zip(tx_statuses.iter())
-            .any(|(transaction, tx_status)| {
-                transaction.is_script() && tx_status.result.receipts().is_empty()
+            .zip(results.iter())
+            .any(|(transaction, result)| {
+                transaction.is_script() && result.status.result.receipts().is_empty()
             })
         {
             Err(anyhow!("Expected at least one set of receipts"))
         } else {
-            Ok(tx_statuses)
+            Ok(results)
         }
     }
 }
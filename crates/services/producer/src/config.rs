@@ -1,4 +1,5 @@
 use fuel_core_types::fuel_types::ContractId;
+use std::time::Duration;
 
 #[derive(Clone, Debug, Default)]
 pub struct Config {
@@ -6,4 +7,8 @@ pub struct Config {
     pub coinbase_recipient: Option<ContractId>,
     pub gas_price: u64,
     pub metrics: bool,
+    /// The wall-clock budget for producing the block from the `TxPool`.
+    /// The producer stops including transactions when the budget is nearly
+    /// exhausted instead of missing the slot. The time is not limited if it is `None`.
+    pub block_production_budget: Option<Duration>,
 }
@@ -46,6 +46,7 @@ use std::{
         Arc,
         Mutex,
     },
+    time::Instant,
 };
 
 // TODO: Replace mocks with `mockall`.
@@ -74,7 +75,7 @@ pub struct MockTxPool(pub Vec<ArcPoolTx>);
 impl TxPool for MockTxPool {
     type TxSource = Vec<ArcPoolTx>;
 
-    fn get_source(&self, _: BlockHeight) -> Self::TxSource {
+    fn get_source(&self, _: BlockHeight, _: Option<Instant>) -> Self::TxSource {
         self.0.clone()
     }
 }
@@ -16,13 +16,17 @@ use fuel_core_types::{
     services::{
         block_producer::Components,
         executor::{
+            DryRunResult,
             Result as ExecutorResult,
-            TransactionExecutionStatus,
+            StateOverrides,
             UncommittedResult,
         },
     },
 };
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    time::Instant,
+};
 
 pub trait BlockProducerDatabase: Send + Sync {
     /// Gets the committed block at the `height`.
@@ -38,10 +42,12 @@ pub trait TxPool: Send + Sync {
     type TxSource;
 
     /// Returns the source of includable transactions.
+    /// The source stops providing transactions close to the `deadline` if it is set.
     fn get_source(
         &self,
         // could be used by the txpool to filter txs based on maturity
         block_height: BlockHeight,
+        deadline: Option<Instant>,
     ) -> Self::TxSource;
 }
 
@@ -70,10 +76,14 @@ pub trait Executor<TxSource>: Send + Sync {
 pub trait DryRunner: Send + Sync {
     /// Executes the block without committing it to the database. During execution collects the
     /// receipts to return them. The `utxo_validation` field can be used to disable the validation
-    /// of utxos during execution.
+    /// of utxos during execution. The `state_overrides` are applied only to the state of the
+    /// dry run. If `profile_gas` is set, the gas spent by each transaction is profiled
+    /// per contract and opcode.
     fn dry_run(
         &self,
         block: Components<Vec<Transaction>>,
         utxo_validation: Option<bool>,
-    ) -> ExecutorResult<Vec<TransactionExecutionStatus>>;
+        state_overrides: StateOverrides,
+        profile_gas: bool,
+    ) -> ExecutorResult<Vec<DryRunResult>>;
 }
@@ -37,6 +37,10 @@ pub struct Config {
     /// syncing.
     pub syncing_log_frequency: Duration,
 
+    /// The relayer logs a warning when it is behind the finalized
+    /// DA height by more than this number of blocks.
+    pub lag_warning_threshold: u64,
+
     /// Enables metrics on this fuel service
     pub metrics: bool,
 }
@@ -48,6 +52,7 @@ impl Config {
     pub const DEFAULT_SYNC_MINIMUM_DURATION: Duration = Duration::from_secs(5);
     pub const DEFAULT_SYNCING_CALL_FREQ: Duration = Duration::from_secs(5);
     pub const DEFAULT_SYNCING_LOG_FREQ: Duration = Duration::from_secs(60);
+    pub const DEFAULT_LAG_WARNING_THRESHOLD: u64 = 100;
 }
 
 impl Default for Config {
@@ -63,6 +68,7 @@ impl Default for Config {
             sync_minimum_duration: Self::DEFAULT_SYNC_MINIMUM_DURATION,
             syncing_call_frequency: Self::DEFAULT_SYNCING_CALL_FREQ,
             syncing_log_frequency: Self::DEFAULT_SYNCING_LOG_FREQ,
+            lag_warning_threshold: Self::DEFAULT_LAG_WARNING_THRESHOLD,
             metrics: false,
         }
     }
@@ -9,7 +9,10 @@ use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
     entities::message::Message,
     fuel_types::Nonce,
-    services::relayer::Event,
+    services::relayer::{
+        Event,
+        MessageOrigin,
+    },
 };
 use std::{
     collections::{
@@ -26,6 +29,7 @@ use std::{
 pub struct Data {
     pub messages: BTreeMap<DaBlockHeight, HashMap<Nonce, Message>>,
     pub finalized_da_height: Option<DaBlockHeight>,
+    pub message_origins: HashMap<Nonce, MessageOrigin>,
 }
 
 // TODO: Maybe remove `Arc<Mutex<>>`
@@ -70,6 +74,15 @@ impl RelayerDb for MockDb {
         Ok(())
     }
 
+    fn insert_message_origins(
+        &mut self,
+        origins: &[(Nonce, MessageOrigin)],
+    ) -> StorageResult<()> {
+        let mut m = self.data.lock().unwrap();
+        m.message_origins.extend(origins.iter().cloned());
+        Ok(())
+    }
+
     fn set_finalized_da_height_to_at_least(
         &mut self,
         height: &DaBlockHeight,
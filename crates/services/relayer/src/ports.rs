@@ -4,7 +4,11 @@ use async_trait::async_trait;
 use fuel_core_storage::Result as StorageResult;
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    services::relayer::Event,
+    fuel_types::Nonce,
+    services::relayer::{
+        Event,
+        MessageOrigin,
+    },
 };
 
 #[cfg(test)]
@@ -21,6 +25,12 @@ pub trait RelayerDb: Send + Sync {
         events: &[Event],
     ) -> StorageResult<()>;
 
+    /// Add the origins of the messages on the DA layer. The origins are not revertible.
+    fn insert_message_origins(
+        &mut self,
+        origins: &[(Nonce, MessageOrigin)],
+    ) -> StorageResult<()>;
+
     /// Set finalized da height that represent last block from da layer that got finalized.
     /// This will only set the value if it is greater than the current.
     fn set_finalized_da_height_to_at_least(
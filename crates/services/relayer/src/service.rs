@@ -21,6 +21,7 @@ use ethers_providers::{
     Provider,
     ProviderError,
 };
+use fuel_core_metrics::relayer_metrics::relayer_metrics;
 use fuel_core_services::{
     RunnableService,
     RunnableTask,
@@ -117,6 +118,34 @@ where
             .set_finalized_da_height_to_at_least(&self.config.da_deploy_height)
             .expect("Should be able to set the finalized da height");
     }
+
+    /// Warns if the relayer is too far behind the DA layer and updates the metrics.
+    fn report_progress(&self, state: &state::EthState) {
+        let lag = state.lag();
+        if lag > self.config.lag_warning_threshold {
+            tracing::warn!(
+                "The relayer is {} blocks behind the finalized DA height {}",
+                lag,
+                state.remote()
+            );
+        }
+
+        if self.config.metrics {
+            let metrics = relayer_metrics();
+            metrics.da_finalized_height.set(gauge_value(state.remote()));
+            metrics.da_lag.set(gauge_value(lag));
+            if let Ok(downloaded) = self.database.get_finalized_da_height() {
+                metrics.downloaded_da_height.set(gauge_value(*downloaded));
+            }
+            if let Some(synced) = *self.synced.borrow() {
+                metrics.synced_height.set(gauge_value(*synced));
+            }
+        }
+    }
+}
+
+fn gauge_value(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
 }
 
 #[async_trait]
@@ -153,11 +182,18 @@ where
             self.config.log_page_size,
         );
         let logs = logs.take_until(self.shutdown.while_started());
-        write_logs(&mut self.database, logs).await
+        let written = write_logs(&mut self.database, logs).await?;
+        if self.config.metrics {
+            let metrics = relayer_metrics();
+            metrics.events.inc_by(written as u64);
+            metrics.events_per_sync_cycle.observe(written as f64);
+        }
+        Ok(())
     }
 
     fn update_synced(&self, state: &state::EthState) {
-        update_synced(&self.synced, state)
+        update_synced(&self.synced, state);
+        self.report_progress(state);
     }
 }
 
@@ -219,6 +255,14 @@ where
 
         let result = run::run(self).await;
 
+        if self.config.metrics {
+            let metrics = relayer_metrics();
+            metrics.sync_cycles.inc();
+            if result.is_err() {
+                metrics.failed_sync_cycles.inc();
+            }
+        }
+
         if self.shutdown.borrow_and_update().started()
             && (result.is_err() | self.synced.borrow().is_some())
         {
@@ -1,5 +1,11 @@
 use super::*;
-use fuel_core_types::services::relayer::Event;
+use fuel_core_types::{
+    fuel_types::Bytes32,
+    services::relayer::{
+        Event,
+        MessageOrigin,
+    },
+};
 use futures::TryStreamExt;
 use std::collections::BTreeMap;
 
@@ -55,41 +61,48 @@ where
     )
 }
 
-/// Write the logs to the database.
-pub(crate) async fn write_logs<D, S>(database: &mut D, logs: S) -> anyhow::Result<()>
+/// Write the logs to the database and return the number of the written events.
+pub(crate) async fn write_logs<D, S>(database: &mut D, logs: S) -> anyhow::Result<usize>
 where
     D: RelayerDb,
     S: futures::Stream<Item = Result<(u64, Vec<Log>), ProviderError>>,
 {
     tokio::pin!(logs);
+    let mut written = 0usize;
     while let Some((last_height, events)) = logs.try_next().await? {
         let last_height = last_height.into();
         let mut ordered_events = BTreeMap::<DaBlockHeight, Vec<Event>>::new();
-        let fuel_events =
-            events
-                .into_iter()
-                .filter_map(|event| match EthEventLog::try_from(&event) {
-                    Ok(event) => {
-                        match event {
-                            EthEventLog::Message(m) => {
-                                Some(Ok(Event::Message(Message::from(&m))))
-                            }
-                            // TODO: Log out ignored messages.
-                            EthEventLog::Ignored => None,
-                        }
-                    }
-                    Err(e) => Some(Err(e)),
-                });
+        let mut origins = vec![];
 
-        for event in fuel_events {
-            let event = event?;
+        for log in events {
+            let event = match EthEventLog::try_from(&log)? {
+                EthEventLog::Message(m) => {
+                    if let Some(transaction_hash) = log.transaction_hash {
+                        let origin = MessageOrigin {
+                            da_height: m.da_height,
+                            transaction_hash: Bytes32::from(transaction_hash.0),
+                        };
+                        origins.push((m.nonce, origin));
+                    }
+                    Event::Message(Message::from(&m))
+                }
+                // TODO: Log out ignored messages.
+                EthEventLog::Ignored => continue,
+            };
             let height = event.da_height();
             ordered_events.entry(height).or_default().push(event);
         }
 
+        // The origins are inserted before the events, so the origins
+        // are available when the messages are included into the block.
+        if !origins.is_empty() {
+            database.insert_message_origins(&origins)?;
+        }
+
         let mut inserted_last_height = false;
         for (height, events) in ordered_events {
             database.insert_events(&height, &events)?;
+            written = written.saturating_add(events.len());
             if height == last_height {
                 inserted_last_height = true;
             }
@@ -99,5 +112,5 @@ where
             database.insert_events(&last_height, &[])?;
         }
     }
-    Ok(())
+    Ok(written)
 }
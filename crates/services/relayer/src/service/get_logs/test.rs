@@ -181,3 +181,44 @@ async fn test_da_height_updates(
 
     *mock_db.get_finalized_da_height().unwrap()
 }
+
+#[tokio::test]
+async fn write_logs_returns_number_of_written_events() {
+    let mut mock_db = crate::mock_db::MockDb::default();
+    let logs = futures::stream::iter(vec![
+        Ok((3, messages_n(3, 0))),
+        Ok((4, vec![])),
+        Ok((5, messages_n(1, 4))),
+    ]);
+
+    let written = write_logs(&mut mock_db, logs).await.unwrap();
+
+    assert_eq!(written, 6);
+}
+
+#[tokio::test]
+async fn write_logs_stores_origins_of_messages() {
+    let mut mock_db = crate::mock_db::MockDb::default();
+    let mut with_hash = message(1, 7, 0);
+    with_hash.transaction_hash = Some([3; 32].into());
+    let without_hash = message(2, 7, 0);
+    let logs = futures::stream::iter(vec![Ok((7, vec![with_hash, without_hash]))]);
+
+    write_logs(&mut mock_db, logs).await.unwrap();
+
+    let origins = mock_db.data.lock().unwrap().message_origins.clone();
+    let mut nonce = fuel_core_types::fuel_types::Nonce::zeroed();
+    U256::from(1).to_big_endian(nonce.as_mut());
+    assert_eq!(
+        origins,
+        [(
+            nonce,
+            MessageOrigin {
+                da_height: 7u64.into(),
+                transaction_hash: [3; 32].into(),
+            }
+        )]
+        .into_iter()
+        .collect()
+    );
+}
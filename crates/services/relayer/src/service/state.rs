@@ -51,6 +51,19 @@ impl EthState {
         self.local.filter(|local| *local >= self.remote)
     }
 
+    /// The finalized height of the Ethereum node.
+    pub fn remote(&self) -> u64 {
+        self.remote
+    }
+
+    /// The number of finalized blocks the relayer is behind the Ethereum node.
+    pub fn lag(&self) -> u64 {
+        match self.local {
+            Some(local) => self.remote.saturating_sub(local),
+            None => self.remote.saturating_add(1),
+        }
+    }
+
     /// Get the gap between the relayer and the Ethereum node if
     /// a sync is required.
     pub fn needs_to_sync_eth(&self) -> Option<EthSyncGap> {
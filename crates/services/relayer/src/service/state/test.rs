@@ -46,6 +46,29 @@ async fn test_eth_state_needs_to_sync_eth(
         .map(Into::into)
 }
 
+#[test_case(
+    TestDataSource {
+        eth_remote_finalized: 200,
+        eth_local_finalized: None,
+    } => 201; "empty is behind by all blocks"
+)]
+#[test_case(
+    TestDataSource {
+        eth_remote_finalized: 200,
+        eth_local_finalized: Some(50),
+    } => 150; "behind by the gap"
+)]
+#[test_case(
+    TestDataSource {
+        eth_remote_finalized: 200,
+        eth_local_finalized: Some(201),
+    } => 0; "ahead has no lag"
+)]
+#[tokio::test]
+async fn test_eth_state_lag(state: TestDataSource) -> u64 {
+    build_eth(&state).await.unwrap().lag()
+}
+
 #[test_case(EthSyncGap::new(0, 0), 0 => None; "0 page size results in no page with no gap")]
 #[test_case(EthSyncGap::new(0, 0), 1 => Some((0, 0)); "page includes 0 to 0 when size is 1")]
 #[test_case(EthSyncGap::new(0, 1), 0 => None; "0 page size results in no page with gap")]
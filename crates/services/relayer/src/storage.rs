@@ -6,6 +6,7 @@ use fuel_core_storage::{
     codec::{
         postcard::Postcard,
         primitive::Primitive,
+        raw::Raw,
     },
     kv_store::StorageColumn,
     structured_storage::TableWithBlueprint,
@@ -19,7 +20,11 @@ use fuel_core_storage::{
 };
 use fuel_core_types::{
     blockchain::primitives::DaBlockHeight,
-    services::relayer::Event,
+    fuel_types::Nonce,
+    services::relayer::{
+        Event,
+        MessageOrigin,
+    },
 };
 
 /// GraphQL database tables column ids to the corresponding [`fuel_core_storage::Mappable`] table.
@@ -42,6 +47,8 @@ pub enum Column {
     History = 1,
     /// The column that tracks the da height of the relayer.
     RelayerHeight = 2,
+    /// The column of the table that stores the origins of the messages on the DA.
+    MessageOrigins = 3,
 }
 
 impl Column {
@@ -108,13 +115,35 @@ impl TableWithBlueprint for EventsHistory {
     }
 }
 
+/// The table contains the origins of the messages on the DA,
+/// so users can track the deposits from the DA transaction to the Fuel.
+pub struct MessageOrigins;
+
+impl Mappable for MessageOrigins {
+    /// The key is the nonce of the message.
+    type Key = Self::OwnedKey;
+    type OwnedKey = Nonce;
+    type Value = Self::OwnedValue;
+    type OwnedValue = MessageOrigin;
+}
+
+impl TableWithBlueprint for MessageOrigins {
+    type Blueprint = Plain<Raw, Postcard>;
+    type Column = Column;
+
+    fn column() -> Column {
+        Column::MessageOrigins
+    }
+}
+
 impl<T, Storage> RelayerDb for T
 where
     T: Send + Sync,
     T: Transactional<Storage = Storage>,
     T: StorageMutate<DaHeightTable, Error = StorageError>,
     Storage: StorageMutate<EventsHistory, Error = StorageError>
-        + StorageMutate<DaHeightTable, Error = StorageError>,
+        + StorageMutate<DaHeightTable, Error = StorageError>
+        + StorageMutate<MessageOrigins, Error = StorageError>,
 {
     fn insert_events(
         &mut self,
@@ -145,6 +174,21 @@ where
         Ok(())
     }
 
+    fn insert_message_origins(
+        &mut self,
+        origins: &[(Nonce, MessageOrigin)],
+    ) -> StorageResult<()> {
+        let mut db_tx = self.transaction();
+        let db = db_tx.as_mut();
+
+        for (nonce, origin) in origins {
+            db.storage::<MessageOrigins>().insert(nonce, origin)?;
+        }
+
+        db_tx.commit()?;
+        Ok(())
+    }
+
     fn set_finalized_da_height_to_at_least(
         &mut self,
         height: &DaBlockHeight,
@@ -205,4 +249,10 @@ mod tests {
         <EventsHistory as Mappable>::Key::default(),
         vec![Event::Message(Default::default())]
     );
+
+    fuel_core_storage::basic_storage_tests!(
+        MessageOrigins,
+        <MessageOrigins as Mappable>::Key::default(),
+        <MessageOrigins as Mappable>::Value::default()
+    );
 }
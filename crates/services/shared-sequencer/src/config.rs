@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+/// The configuration of the publisher of the sealed block headers.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The endpoint that accepts the sealed block headers.
+    pub endpoint: url::Url,
+    /// The delay before the first retry of the failed publication.
+    pub min_retry_backoff: Duration,
+    /// The delay between retries doubles after each failure until it reaches this value.
+    pub max_retry_backoff: Duration,
+}
+
+impl Config {
+    /// The default delay before the first retry.
+    pub const DEFAULT_MIN_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+    /// The default maximum delay between retries.
+    pub const DEFAULT_MAX_RETRY_BACKOFF: Duration = Duration::from_secs(60);
+
+    /// Creates the configuration for the `endpoint` with the default backoff.
+    pub fn new(endpoint: url::Url) -> Self {
+        Self {
+            endpoint,
+            min_retry_backoff: Self::DEFAULT_MIN_RETRY_BACKOFF,
+            max_retry_backoff: Self::DEFAULT_MAX_RETRY_BACKOFF,
+        }
+    }
+}
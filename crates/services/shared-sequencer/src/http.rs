@@ -0,0 +1,40 @@
+//! The sink that posts the sealed block headers to the HTTP endpoint.
+
+use crate::ports::BlockSink;
+use anyhow::Context;
+use fuel_core_types::blockchain::SealedBlockHeader;
+
+/// Posts each sealed block header as a JSON body to the endpoint.
+/// Any response with a non-success status is treated as a failure.
+#[derive(Clone, Debug)]
+pub struct HttpSink {
+    client: reqwest::Client,
+    endpoint: url::Url,
+}
+
+impl HttpSink {
+    /// Creates the sink for the `endpoint`.
+    pub fn new(endpoint: url::Url) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            endpoint,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl BlockSink for HttpSink {
+    async fn publish(&self, header: &SealedBlockHeader) -> anyhow::Result<()> {
+        let body = serde_json::to_vec(header)?;
+        self.client
+            .post(self.endpoint.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await
+            .context("failed to send the block header")?
+            .error_for_status()
+            .context("the endpoint rejected the block header")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+#![deny(clippy::arithmetic_side_effects)]
+#![deny(clippy::cast_possible_truncation)]
+#![deny(unused_crate_dependencies)]
+#![deny(missing_docs)]
+#![deny(warnings)]
+//! # Shared Sequencer Publisher
+//! Publishes the headers of the imported blocks with their consensus
+//! to the external shared sequencer or settlement endpoint.
+
+mod config;
+pub mod http;
+pub mod ports;
+pub mod service;
+
+pub use config::Config;
+pub use service::new_service;
@@ -0,0 +1,41 @@
+//! Ports used by the publisher to access the outside world.
+
+use fuel_core_services::stream::BoxStream;
+use fuel_core_types::{
+    blockchain::SealedBlockHeader,
+    fuel_types::BlockHeight,
+    services::block_importer::SharedImportResult,
+};
+
+/// The destination of the sealed block headers,
+/// for example, the shared sequencer or the settlement layer.
+#[async_trait::async_trait]
+pub trait BlockSink: Send + Sync {
+    /// Publishes the `header`. The publication is retried on error,
+    /// so the sink can receive the same header more than once.
+    async fn publish(&self, header: &SealedBlockHeader) -> anyhow::Result<()>;
+}
+
+/// The source of the imported blocks.
+pub trait BlockImporter: Send + Sync {
+    /// Returns a stream of imported blocks.
+    fn block_events(&self) -> BoxStream<SharedImportResult>;
+}
+
+/// The storage of the imported blocks and of the progress of the publisher.
+pub trait Database: Send + Sync {
+    /// Returns the height of the latest imported block.
+    fn latest_height(&self) -> anyhow::Result<BlockHeight>;
+
+    /// Returns the sealed header of the imported block at the `height`.
+    fn sealed_block_header(
+        &self,
+        height: &BlockHeight,
+    ) -> anyhow::Result<Option<SealedBlockHeader>>;
+
+    /// Returns the height of the last header accepted by the sink.
+    fn published_height(&self) -> anyhow::Result<Option<BlockHeight>>;
+
+    /// Stores the height of the last header accepted by the sink.
+    fn set_published_height(&mut self, height: &BlockHeight) -> anyhow::Result<()>;
+}
@@ -0,0 +1,455 @@
+//! The service publishing the sealed headers of the imported blocks.
+
+use crate::{
+    ports::{
+        BlockImporter,
+        BlockSink,
+        Database,
+    },
+    Config,
+};
+use fuel_core_services::{
+    stream::BoxStream,
+    EmptyShared,
+    RunnableService,
+    RunnableTask,
+    ServiceRunner,
+    StateWatcher,
+};
+use fuel_core_types::{
+    blockchain::{
+        consensus::Sealed,
+        SealedBlockHeader,
+    },
+    fuel_types::BlockHeight,
+    services::block_importer::SharedImportResult,
+};
+use futures::StreamExt;
+
+/// The task publishes the header of each imported block to the sink in the order
+/// of the import. The failed publication is retried with the exponential backoff
+/// until the sink accepts the header, so each header is delivered at least once.
+///
+/// The height of the last accepted header is persisted. The headers of the blocks
+/// imported while the node was stopped, or skipped because the task lagged behind
+/// the importer, are read from the database and published before the new ones.
+pub struct Task<Sink, D> {
+    config: Config,
+    sink: Sink,
+    database: D,
+    block_importer: BoxStream<SharedImportResult>,
+    published_height: Option<BlockHeight>,
+}
+
+impl<Sink, D> Task<Sink, D>
+where
+    Sink: BlockSink,
+    D: Database,
+{
+    /// Returns the height of the next header to publish from the database,
+    /// if the publisher is behind the latest imported block.
+    fn next_missed_height(&self) -> anyhow::Result<Option<BlockHeight>> {
+        let Some(published_height) = self.published_height else {
+            return Ok(None)
+        };
+        let Some(next_height) = published_height.succ() else {
+            return Ok(None)
+        };
+        let latest_height = self.database.latest_height()?;
+        Ok((next_height <= latest_height).then_some(next_height))
+    }
+
+    /// Returns `false` if the service was stopped before the sink accepted the `header`.
+    async fn publish(
+        &mut self,
+        header: &SealedBlockHeader,
+        watcher: &mut StateWatcher,
+    ) -> anyhow::Result<bool> {
+        if !self.publish_with_retry(header, watcher).await {
+            return Ok(false)
+        }
+        let height = *header.entity.height();
+        self.database.set_published_height(&height)?;
+        self.published_height = Some(height);
+        Ok(true)
+    }
+
+    /// Returns `false` if the service was stopped before the sink accepted the `header`.
+    async fn publish_with_retry(
+        &self,
+        header: &SealedBlockHeader,
+        watcher: &mut StateWatcher,
+    ) -> bool {
+        let mut backoff = self.config.min_retry_backoff;
+        loop {
+            match self.sink.publish(header).await {
+                Ok(()) => return true,
+                Err(err) => {
+                    tracing::warn!(
+                        "Failed to publish the header of the block {}, retrying in {:?}: {:?}",
+                        header.entity.height(),
+                        backoff,
+                        err
+                    );
+                }
+            }
+
+            tokio::select! {
+                biased;
+
+                _ = watcher.while_started() => {
+                    tracing::warn!(
+                        "The header of the block {} is not published because of shutdown",
+                        header.entity.height(),
+                    );
+                    return false
+                }
+                _ = tokio::time::sleep(backoff) => {}
+            }
+            backoff = backoff.saturating_mul(2).min(self.config.max_retry_backoff);
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<Sink, D> RunnableService for Task<Sink, D>
+where
+    Sink: BlockSink + 'static,
+    D: Database + 'static,
+{
+    const NAME: &'static str = "SharedSequencerPublisher";
+    type SharedData = EmptyShared;
+    type Task = Self;
+    type TaskParams = ();
+
+    fn shared_data(&self) -> Self::SharedData {
+        EmptyShared
+    }
+
+    async fn into_task(
+        mut self,
+        _: &StateWatcher,
+        _: Self::TaskParams,
+    ) -> anyhow::Result<Self::Task> {
+        self.published_height = self.database.published_height()?;
+        Ok(self)
+    }
+}
+
+#[async_trait::async_trait]
+impl<Sink, D> RunnableTask for Task<Sink, D>
+where
+    Sink: BlockSink + 'static,
+    D: Database + 'static,
+{
+    async fn run(&mut self, watcher: &mut StateWatcher) -> anyhow::Result<bool> {
+        if let Some(height) = self.next_missed_height()? {
+            let header = self
+                .database
+                .sealed_block_header(&height)?
+                .ok_or_else(|| anyhow::anyhow!("The block {height} is not found"))?;
+            return self.publish(&header, watcher).await
+        }
+
+        let result = tokio::select! {
+            biased;
+
+            _ = watcher.while_started() => {
+                return Ok(false)
+            }
+            result = self.block_importer.next() => result,
+        };
+
+        let Some(result) = result else {
+            return Ok(false)
+        };
+        let header = Sealed {
+            entity: result.sealed_block.entity.header().clone(),
+            consensus: result.sealed_block.consensus.clone(),
+        };
+        // The importer waits until all subscribers drop the result,
+        // so it must not be held during the retries.
+        drop(result);
+
+        if let Some(published_height) = self.published_height {
+            // The header is already published from the database, or the earlier
+            // headers are missed and the next iteration publishes them first.
+            if *header.entity.height() != published_height.succ().unwrap_or_default() {
+                return Ok(true)
+            }
+        }
+        self.publish(&header, watcher).await
+    }
+
+    async fn shutdown(self) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+/// Creates the publisher of the headers of the blocks from the `block_importer` to the `sink`.
+pub fn new_service<I, Sink, D>(
+    config: Config,
+    block_importer: I,
+    sink: Sink,
+    database: D,
+) -> ServiceRunner<Task<Sink, D>>
+where
+    I: BlockImporter,
+    Sink: BlockSink + 'static,
+    D: Database + 'static,
+{
+    ServiceRunner::new(Task {
+        config,
+        sink,
+        database,
+        block_importer: block_importer.block_events(),
+        published_height: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_core_services::{
+        stream::IntoBoxStream,
+        Service,
+    };
+    use fuel_core_types::{
+        blockchain::{
+            block::Block,
+            SealedBlock,
+        },
+        services::block_importer::ImportResult,
+    };
+    use std::{
+        collections::BTreeMap,
+        sync::{
+            atomic::{
+                AtomicUsize,
+                Ordering,
+            },
+            Arc,
+            Mutex,
+        },
+        time::Duration,
+    };
+    use tokio::sync::mpsc;
+
+    /// Rejects the first `failures` publications and sends
+    /// the heights of the accepted headers to the channel.
+    struct TestSink {
+        failures: AtomicUsize,
+        published: mpsc::UnboundedSender<BlockHeight>,
+    }
+
+    #[async_trait::async_trait]
+    impl BlockSink for TestSink {
+        async fn publish(&self, header: &SealedBlockHeader) -> anyhow::Result<()> {
+            let failures = self.failures.load(Ordering::SeqCst);
+            if failures > 0 {
+                self.failures
+                    .store(failures.saturating_sub(1), Ordering::SeqCst);
+                anyhow::bail!("The sink is unavailable");
+            }
+            self.published.send(*header.entity.height())?;
+            Ok(())
+        }
+    }
+
+    fn import_result(height: u32) -> SharedImportResult {
+        let mut block = Block::default();
+        block.header_mut().set_block_height(height.into());
+        let result = ImportResult::new_from_local(
+            SealedBlock {
+                entity: block,
+                consensus: Default::default(),
+            },
+            vec![],
+            vec![],
+        );
+        Arc::new(result)
+    }
+
+    struct TestImporter(Vec<SharedImportResult>);
+
+    impl BlockImporter for TestImporter {
+        fn block_events(&self) -> BoxStream<SharedImportResult> {
+            futures::stream::iter(self.0.clone())
+                .chain(futures::stream::pending())
+                .into_boxed()
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct TestDatabase {
+        headers: Arc<Mutex<BTreeMap<BlockHeight, SealedBlockHeader>>>,
+        published_height: Arc<Mutex<Option<BlockHeight>>>,
+    }
+
+    impl TestDatabase {
+        fn with_blocks(heights: impl IntoIterator<Item = u32>) -> Self {
+            let database = Self::default();
+            for height in heights {
+                let result = import_result(height);
+                let header = Sealed {
+                    entity: result.sealed_block.entity.header().clone(),
+                    consensus: result.sealed_block.consensus.clone(),
+                };
+                database
+                    .headers
+                    .lock()
+                    .unwrap()
+                    .insert(height.into(), header);
+            }
+            database
+        }
+    }
+
+    impl Database for TestDatabase {
+        fn latest_height(&self) -> anyhow::Result<BlockHeight> {
+            let headers = self.headers.lock().unwrap();
+            Ok(headers.keys().last().copied().unwrap_or_default())
+        }
+
+        fn sealed_block_header(
+            &self,
+            height: &BlockHeight,
+        ) -> anyhow::Result<Option<SealedBlockHeader>> {
+            Ok(self.headers.lock().unwrap().get(height).cloned())
+        }
+
+        fn published_height(&self) -> anyhow::Result<Option<BlockHeight>> {
+            Ok(*self.published_height.lock().unwrap())
+        }
+
+        fn set_published_height(&mut self, height: &BlockHeight) -> anyhow::Result<()> {
+            *self.published_height.lock().unwrap() = Some(*height);
+            Ok(())
+        }
+    }
+
+    fn service(
+        results: Vec<SharedImportResult>,
+        database: TestDatabase,
+        failures: usize,
+    ) -> (
+        ServiceRunner<Task<TestSink, TestDatabase>>,
+        mpsc::UnboundedReceiver<BlockHeight>,
+    ) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let mut config = Config::new("http://localhost:4000".parse().unwrap());
+        config.min_retry_backoff = Duration::from_millis(1);
+        config.max_retry_backoff = Duration::from_millis(4);
+        let sink = TestSink {
+            failures: AtomicUsize::new(failures),
+            published: sender,
+        };
+        let service = new_service(config, TestImporter(results), sink, database);
+        (service, receiver)
+    }
+
+    fn imported(
+        heights: impl IntoIterator<Item = u32> + Clone,
+    ) -> (Vec<SharedImportResult>, TestDatabase) {
+        let results = heights.clone().into_iter().map(import_result).collect();
+        (results, TestDatabase::with_blocks(heights))
+    }
+
+    async fn next(receiver: &mut mpsc::UnboundedReceiver<BlockHeight>) -> BlockHeight {
+        tokio::time::timeout(Duration::from_secs(5), receiver.recv())
+            .await
+            .expect("The header should be published")
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn headers_are_published_in_the_order_of_import() {
+        let (results, database) = imported(1..=3);
+        let (service, mut published) = service(results, database.clone(), 0);
+        service.start_and_await().await.unwrap();
+
+        assert_eq!(next(&mut published).await, 1u32.into());
+        assert_eq!(next(&mut published).await, 2u32.into());
+        assert_eq!(next(&mut published).await, 3u32.into());
+        assert_eq!(database.published_height().unwrap(), Some(3u32.into()));
+
+        service.stop_and_await().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn failed_publication_is_retried_until_accepted() {
+        let (results, database) = imported(1..=2);
+        let (service, mut published) = service(results, database, 5);
+        service.start_and_await().await.unwrap();
+
+        assert_eq!(next(&mut published).await, 1u32.into());
+        assert_eq!(next(&mut published).await, 2u32.into());
+
+        service.stop_and_await().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn service_stops_while_retrying() {
+        let (results, database) = imported(1..=1);
+        let (service, mut published) = service(results, database, usize::MAX);
+        service.start_and_await().await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        service.stop_and_await().await.unwrap();
+
+        assert!(published.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn import_result_is_not_held_while_retrying() {
+        let (results, database) = imported(1..=1);
+        let result = Arc::downgrade(&results[0]);
+        let (service, _published) = service(results, database, usize::MAX);
+        service.start_and_await().await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(result.upgrade().is_none());
+
+        service.stop_and_await().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn missed_headers_are_published_from_the_database_after_restart() {
+        // Given
+        let database = TestDatabase::with_blocks(1..=5);
+        *database.published_height.lock().unwrap() = Some(2u32.into());
+        let (service, mut published) = service(vec![], database.clone(), 0);
+
+        // When
+        service.start_and_await().await.unwrap();
+
+        // Then
+        assert_eq!(next(&mut published).await, 3u32.into());
+        assert_eq!(next(&mut published).await, 4u32.into());
+        assert_eq!(next(&mut published).await, 5u32.into());
+        assert_eq!(database.published_height().unwrap(), Some(5u32.into()));
+
+        service.stop_and_await().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn headers_skipped_by_the_stream_are_published_from_the_database() {
+        // Given
+        let database = TestDatabase::with_blocks(1..=4);
+        let results = vec![import_result(1), import_result(4)];
+        let (service, mut published) = service(results, database, 0);
+
+        // When
+        service.start_and_await().await.unwrap();
+
+        // Then
+        assert_eq!(next(&mut published).await, 1u32.into());
+        assert_eq!(next(&mut published).await, 2u32.into());
+        assert_eq!(next(&mut published).await, 3u32.into());
+        assert_eq!(next(&mut published).await, 4u32.into());
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(published.try_recv().is_err());
+
+        service.stop_and_await().await.unwrap();
+    }
+}
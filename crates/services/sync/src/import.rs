@@ -31,13 +31,22 @@ use std::{
         Range,
         RangeInclusive,
     },
-    sync::Arc,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
 };
+use throttle::PeerThrottle;
 use tokio::sync::Notify;
 use tracing::Instrument;
 
 use crate::{
     ports::{
+        BlockArchivePort,
         BlockImporterPort,
         ConsensusPort,
         PeerReportReason,
@@ -52,6 +61,8 @@ use crate::{
 /// or benchmarking.
 pub mod test_helpers;
 
+mod throttle;
+
 #[cfg(test)]
 mod tests;
 
@@ -65,6 +76,22 @@ pub struct Config {
     pub block_stream_buffer_size: usize,
     /// The maximum number of headers to request in a single batch.
     pub header_batch_size: usize,
+    /// The maximum number of blocks to request the transactions for in a single batch.
+    /// The batch of headers is split into the batches of this size.
+    pub body_batch_size: usize,
+    /// The number of the batches of headers requested concurrently.
+    pub header_stream_concurrency: usize,
+    /// The maximum number of the get transaction requests in flight to a single peer.
+    pub max_in_flight_per_peer: usize,
+    /// The response of the peer slower than the threshold delays the next requests
+    /// to this peer. The delay doubles with each slow response, and the fast response resets it.
+    pub slow_peer_threshold: Duration,
+    /// The maximum delay of the requests to the slow peer.
+    pub max_peer_backoff: Duration,
+    /// The number of blocks below the failed block searched for the fork
+    /// sealed by the authority. The node never follows the fork because
+    /// committed blocks can't be reverted. Zero disables the search.
+    pub max_fork_depth: u32,
 }
 
 impl Default for Config {
@@ -72,10 +99,19 @@ impl Default for Config {
         Self {
             block_stream_buffer_size: 10,
             header_batch_size: 100,
+            body_batch_size: 100,
+            header_stream_concurrency: 1,
+            max_in_flight_per_peer: 10,
+            slow_peer_threshold: Duration::from_secs(5),
+            max_peer_backoff: Duration::from_secs(10),
+            max_fork_depth: 10,
         }
     }
 }
 
+/// The archive of the sealed blocks shared between the tasks.
+pub type SharedArchive = Arc<dyn BlockArchivePort + Send + Sync>;
+
 /// The combination of shared state, configuration, and services that define
 /// import behavior.
 pub struct Import<P, E, C> {
@@ -91,6 +127,10 @@ pub struct Import<P, E, C> {
     executor: Arc<E>,
     /// Consensus port.
     consensus: Arc<C>,
+    /// The limits of the requests to each peer.
+    throttle: Arc<PeerThrottle>,
+    /// The archive imported once before the sync with the network.
+    archive: Mutex<Option<SharedArchive>>,
 }
 
 impl<P, E, C> Import<P, E, C> {
@@ -107,10 +147,20 @@ impl<P, E, C> Import<P, E, C> {
         Self {
             state,
             notify,
+            throttle: Arc::new(PeerThrottle::new(&params)),
             params,
             p2p,
             executor,
             consensus,
+            archive: Default::default(),
+        }
+    }
+
+    /// Imports the blocks from the `archive` before the blocks from the network.
+    pub fn with_archive(self, archive: Option<SharedArchive>) -> Self {
+        Self {
+            archive: Mutex::new(archive),
+            ..self
         }
     }
 
@@ -153,11 +203,83 @@ where
     #[tracing::instrument(skip_all)]
     /// Execute imports until a shutdown is requested.
     pub async fn import(&self, shutdown: &mut StateWatcher) -> anyhow::Result<bool> {
+        let archive = self.archive.lock().expect("poisoned").take();
+        if let Some(archive) = archive {
+            if let Err(err) = self.import_archive(archive.as_ref(), shutdown).await {
+                tracing::error!(
+                    "Failed to import the blocks from the archive, \
+                    syncing the rest from the network: {:?}",
+                    err
+                );
+            }
+        }
+
         self.import_inner(shutdown).await?;
 
         Ok(wait_for_notify_or_shutdown(&self.notify, shutdown).await)
     }
 
+    /// Waits for the commit of the last imported block.
+    pub async fn await_pending_commit(&self) -> anyhow::Result<()> {
+        self.executor.await_pending_commit().await
+    }
+
+    /// Imports the blocks from the `archive` following the committed height,
+    /// until the archive doesn't have the next block or the shutdown is requested.
+    ///
+    /// The seal of each block is checked, and the block is executed
+    /// like the block from the network.
+    async fn import_archive(
+        &self,
+        archive: &(dyn BlockArchivePort + Send + Sync),
+        shutdown: &StateWatcher,
+    ) -> anyhow::Result<()> {
+        loop {
+            let next_height = match self.state.apply(|s| s.committed_height()) {
+                Some(height) => height.checked_add(1),
+                None => Some(0),
+            };
+            let Some(mut next_height) = next_height else {
+                return Ok(())
+            };
+
+            let blocks = archive.get_blocks(next_height.into()).await?;
+            if blocks.is_empty() {
+                tracing::info!(
+                    "The archive doesn't have the block at the height {}, \
+                    switching to the sync with the network",
+                    next_height
+                );
+                return Ok(())
+            }
+
+            for block in blocks {
+                if !shutdown.borrow().started() {
+                    return Ok(())
+                }
+                let height = **block.entity.header().height();
+                anyhow::ensure!(
+                    height == next_height,
+                    "The archive returned the block at the height {height} \
+                    instead of {next_height}"
+                );
+                let header = SealedBlockHeader {
+                    entity: block.entity.header().clone(),
+                    consensus: block.consensus.clone(),
+                };
+                anyhow::ensure!(
+                    self.consensus.check_sealed_header(&header)?,
+                    "The block at the height {height} from the archive has an invalid seal"
+                );
+                self.consensus
+                    .await_da_height(&header.entity.da_height)
+                    .await?;
+                execute_and_commit(self.executor.as_ref(), &self.state, block).await?;
+                next_height = next_height.saturating_add(1);
+            }
+        }
+    }
+
     async fn import_inner(&self, shutdown: &StateWatcher) -> anyhow::Result<()> {
         // If there is a range to process, launch the stream.
         if let Some(range) = self.state.apply(|s| s.process_range()) {
@@ -201,6 +323,7 @@ where
             p2p,
             executor,
             consensus,
+            throttle,
             ..
         } = &self;
 
@@ -208,8 +331,13 @@ where
         let (shutdown_guard, mut shutdown_guard_recv) =
             tokio::sync::mpsc::channel::<()>(1);
 
-        let block_stream =
-            get_block_stream(range.clone(), params, p2p.clone(), consensus.clone());
+        let block_stream = get_block_stream(
+            range.clone(),
+            params,
+            p2p.clone(),
+            consensus.clone(),
+            throttle.clone(),
+        );
         let result = block_stream
             .map(move |stream_block_batch| {
                 let shutdown_guard = shutdown_guard.clone();
@@ -253,6 +381,7 @@ where
 
                     let mut done = vec![];
                     for sealed_block in results {
+                        let height = **sealed_block.entity.header().height();
                         let res = execute_and_commit(executor.as_ref(), state, sealed_block).await;
 
                         match &res {
@@ -263,6 +392,7 @@ where
                                 // If this fails, then it means that consensus has approved a block that is invalid.
                                 // This would suggest a more serious issue than a bad peer, e.g. a fork or an out-of-date client.
                                 tracing::error!("Failed to execute and commit block from peer {:?}: {:?}", peer, e);
+                                detect_equivocation(height, params.max_fork_depth, p2p, consensus).await;
                                 break;
                             },
                         };
@@ -303,8 +433,11 @@ fn get_block_stream<
     params: &Config,
     p2p: Arc<P>,
     consensus: Arc<C>,
+    throttle: Arc<PeerThrottle>,
 ) -> impl Stream<Item = impl Future<Output = SealedBlockBatch>> + '_ {
-    let header_stream = get_header_batch_stream(range.clone(), params, p2p.clone());
+    let header_stream =
+        get_header_batch_stream(range.clone(), params, p2p.clone(), throttle.clone());
+    let body_batch_size = params.body_batch_size;
     header_stream
         .map({
             let consensus = consensus.clone();
@@ -324,9 +457,13 @@ fn get_block_stream<
                 Batch::new(peer, range, checked_headers)
             }
         })
+        .flat_map(move |headers| {
+            futures::stream::iter(split_batch(headers, body_batch_size))
+        })
         .map(move |headers| {
             let consensus = consensus.clone();
             let p2p = p2p.clone();
+            let throttle = throttle.clone();
             async move {
                 let Batch {
                     peer,
@@ -344,7 +481,7 @@ fn get_block_stream<
                     )
                     .await;
                     let headers = SealedHeaderBatch::new(peer, range, results);
-                    get_blocks(&p2p, headers).await
+                    get_blocks(&p2p, &throttle, headers).await
                 }
             }
             .instrument(tracing::debug_span!("consensus_and_transactions"))
@@ -356,15 +493,42 @@ fn get_header_batch_stream<P: PeerToPeerPort + Send + Sync + 'static>(
     range: RangeInclusive<u32>,
     params: &Config,
     p2p: Arc<P>,
+    throttle: Arc<PeerThrottle>,
 ) -> impl Stream<Item = SealedHeaderBatch> {
     let Config {
-        header_batch_size, ..
+        header_batch_size,
+        header_stream_concurrency,
+        ..
     } = params;
     let ranges = range_chunks(range, *header_batch_size);
-    futures::stream::iter(ranges).then(move |range| {
-        let p2p = p2p.clone();
-        async move { get_headers_batch(range, &p2p).await }
-    })
+    futures::stream::iter(ranges)
+        .map(move |range| {
+            let p2p = p2p.clone();
+            let throttle = throttle.clone();
+            async move { get_headers_batch(range, &p2p, &throttle).await }
+        })
+        .buffered((*header_stream_concurrency).max(1))
+}
+
+/// Splits the batch of headers into the batches of at most `size` headers.
+/// The headers missing in the batch are missing in the last batches.
+fn split_batch(batch: SealedHeaderBatch, size: usize) -> Vec<SealedHeaderBatch> {
+    let Batch {
+        peer,
+        range,
+        results,
+    } = batch;
+    if range.is_empty() || size >= range.len() {
+        return vec![Batch::new(peer, range, results)]
+    }
+
+    let mut results = results.into_iter();
+    range_chunks(range.start..=range.end.saturating_sub(1), size)
+        .map(|range| {
+            let results = results.by_ref().take(range.len()).collect();
+            Batch::new(peer.clone(), range, results)
+        })
+        .collect()
 }
 
 fn range_chunks(
@@ -468,7 +632,11 @@ where
     }
 }
 
-async fn get_headers_batch<P>(range: Range<u32>, p2p: &Arc<P>) -> SealedHeaderBatch
+async fn get_headers_batch<P>(
+    range: Range<u32>,
+    p2p: &Arc<P>,
+    throttle: &PeerThrottle,
+) -> SealedHeaderBatch
 where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
@@ -477,11 +645,15 @@ where
         range.start,
         range.end
     );
+    let start = Instant::now();
     let sourced_headers = get_sealed_block_headers(range.clone(), p2p).await;
     let SourcePeer {
         peer_id,
         data: headers,
     } = sourced_headers;
+    // The peer is chosen by the network, so its headers request can't be delayed,
+    // but the slow response delays the next transactions requests to it.
+    throttle.record(&peer_id, start.elapsed());
     let heights = range.clone().map(BlockHeight::from);
     let headers = headers
         .into_iter()
@@ -510,9 +682,79 @@ where
         .trace_err(&format!("Failed to report peer {:?}", peer_id));
 }
 
+/// Searches the `max_fork_depth` headers below the failed block for the blocks
+/// that conflict with the local chain. If the authority sealed the conflicting
+/// block, the consensus port records the equivocation proof.
+///
+/// The node stays on the local chain regardless of the result.
+async fn detect_equivocation<P, C>(
+    failed_height: u32,
+    max_fork_depth: u32,
+    p2p: &Arc<P>,
+    consensus: &Arc<C>,
+) where
+    P: PeerToPeerPort + Send + Sync + 'static,
+    C: ConsensusPort + Send + Sync + 'static,
+{
+    if max_fork_depth == 0 {
+        return
+    }
+    let range = failed_height.saturating_sub(max_fork_depth)..failed_height;
+    if range.is_empty() {
+        return
+    }
+
+    let SourcePeer {
+        peer_id,
+        data: headers,
+    } = get_sealed_block_headers(range.clone(), p2p).await;
+
+    let mut fork_height = None;
+    for header in headers {
+        let height = *header.entity.height();
+        if !range.contains(&*height) {
+            continue
+        }
+        let is_valid = consensus
+            .check_sealed_header(&header)
+            .trace_err("Failed to check consensus on header")
+            .unwrap_or(false);
+        if !is_valid {
+            report_peer(p2p, peer_id.clone(), PeerReportReason::BadBlockHeader);
+            break
+        }
+
+        let proof = consensus
+            .check_equivocation(&header)
+            .trace_err("Failed to check the header for the equivocation")
+            .ok()
+            .flatten();
+        if proof.is_some() {
+            tracing::error!(
+                "The authority sealed two different blocks at the height {}, \
+                refusing to follow the fork",
+                height
+            );
+            fork_height.get_or_insert(height);
+        }
+    }
+
+    if fork_height == Some(range.start.into()) {
+        tracing::warn!(
+            "The fork is deeper than {} blocks below the height {}",
+            max_fork_depth,
+            failed_height
+        );
+    }
+}
+
 /// Get blocks correlating to the headers from a specific peer
-#[tracing::instrument(skip(p2p, headers))]
-async fn get_blocks<P>(p2p: &Arc<P>, headers: SealedHeaderBatch) -> SealedBlockBatch
+#[tracing::instrument(skip(p2p, throttle, headers))]
+async fn get_blocks<P>(
+    p2p: &Arc<P>,
+    throttle: &PeerThrottle,
+    headers: SealedHeaderBatch,
+) -> SealedBlockBatch
 where
     P: PeerToPeerPort + Send + Sync + 'static,
 {
@@ -521,8 +763,14 @@ where
         peer,
         range,
     } = headers;
-    let Some(transaction_data) = get_transactions(peer.clone(), range.clone(), p2p).await
-    else {
+    let transaction_data = {
+        let _permit = throttle.acquire(&peer).await;
+        let start = Instant::now();
+        let transaction_data = get_transactions(peer.clone(), range.clone(), p2p).await;
+        throttle.record(&peer, start.elapsed());
+        transaction_data
+    };
+    let Some(transaction_data) = transaction_data else {
         return Batch::new(peer, range, vec![])
     };
 
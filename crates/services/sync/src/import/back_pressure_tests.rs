@@ -24,6 +24,8 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        max_fork_depth: 0,
+        ..Default::default()
     }
     => Count::default() ; "Empty sanity test"
 )]
@@ -36,6 +38,8 @@ struct Input {
     Config{
         block_stream_buffer_size: 1,
         header_batch_size: 1,
+        max_fork_depth: 0,
+        ..Default::default()
     }
     => is less_or_equal_than Count{ headers: 1, consensus: 1, transactions: 1, executes: 1, blocks: 1 }
     ; "Single with slow headers"
@@ -49,6 +53,8 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow headers"
@@ -62,6 +68,8 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "100 headers with max 10 with slow transactions"
@@ -75,6 +83,8 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 with slow executes"
@@ -88,6 +98,8 @@ struct Input {
     Config{
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     }
     => is less_or_equal_than Count{ headers: 10, consensus: 10, transactions: 10, executes: 1, blocks: 21 }
     ; "50 headers with max 10 size and max 10 requests"
@@ -112,6 +124,8 @@ async fn test_back_pressure(input: Input, state: State, params: Config) -> Count
         p2p,
         executor,
         consensus,
+        throttle: Arc::new(PeerThrottle::new(&params)),
+        archive: Default::default(),
     };
 
     import.notify.notify_one();
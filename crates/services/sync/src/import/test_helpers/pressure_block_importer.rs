@@ -29,12 +29,17 @@ impl BlockImporterPort for PressureBlockImporter {
         });
         self.0.execute_and_commit(block).await
     }
+
+    async fn await_pending_commit(&self) -> anyhow::Result<()> {
+        self.0.await_pending_commit().await
+    }
 }
 
 impl PressureBlockImporter {
     pub fn new(counts: SharedCounts, delays: Duration) -> Self {
         let mut mock = MockBlockImporterPort::default();
         mock.expect_execute_and_commit().returning(move |_| Ok(()));
+        mock.expect_await_pending_commit().returning(|| Ok(()));
         Self(mock, delays, counts)
     }
 }
@@ -6,6 +6,7 @@ use crate::{
     },
 };
 use fuel_core_types::blockchain::{
+    consensus::poa::EquivocationProof,
     primitives::DaBlockHeight,
     SealedBlockHeader,
 };
@@ -19,6 +20,13 @@ impl ConsensusPort for PressureConsensus {
         self.0.check_sealed_header(header)
     }
 
+    fn check_equivocation(
+        &self,
+        header: &SealedBlockHeader,
+    ) -> anyhow::Result<Option<EquivocationProof>> {
+        self.0.check_equivocation(header)
+    }
+
     async fn await_da_height(&self, da_height: &DaBlockHeight) -> anyhow::Result<()> {
         self.2.apply(|c| c.inc_consensus());
         tokio::time::sleep(self.1).await;
@@ -32,6 +40,7 @@ impl PressureConsensus {
         let mut mock = MockConsensusPort::default();
         mock.expect_await_da_height().returning(|_| Ok(()));
         mock.expect_check_sealed_header().returning(|_| Ok(true));
+        mock.expect_check_equivocation().returning(|_| Ok(None));
         Self(mock, delays, counts)
     }
 }
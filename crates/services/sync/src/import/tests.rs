@@ -7,13 +7,20 @@ use crate::{
         random_peer,
     },
     ports::{
+        MockBlockArchivePort,
         MockBlockImporterPort,
         MockConsensusPort,
         MockPeerToPeerPort,
         PeerReportReason,
     },
 };
-use fuel_core_types::services::p2p::Transactions;
+use fuel_core_types::{
+    blockchain::consensus::{
+        poa::EquivocationProof,
+        Sealed,
+    },
+    services::p2p::Transactions,
+};
 
 use super::*;
 
@@ -53,6 +60,8 @@ async fn test_import_0_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
     let mocks = Mocks {
         consensus_port,
@@ -99,6 +108,8 @@ async fn test_import_3_to_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
     let mocks = Mocks {
         consensus_port,
@@ -165,6 +176,8 @@ async fn test_import_0_to_499() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size,
+        max_fork_depth: 0,
+        ..Default::default()
     };
     let mocks = Mocks {
         consensus_port,
@@ -179,6 +192,60 @@ async fn test_import_0_to_499() {
     assert_eq!(v, expected);
 }
 
+#[tokio::test]
+async fn import__header_batch_is_split_into_body_batches() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(10)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(3)
+        .returning(|_| Ok(()));
+    let mut p2p = MockPeerToPeerPort::default();
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .returning(|range| {
+            let peer = random_peer();
+            let headers = Some(range.map(empty_header).collect());
+            let headers = peer.bind(headers);
+            Ok(headers)
+        });
+    let requested = Arc::new(std::sync::Mutex::new(vec![]));
+    p2p.expect_get_transactions().times(3).returning({
+        let requested = requested.clone();
+        move |block_ids| {
+            let data = block_ids.data;
+            requested.lock().unwrap().push(data.clone());
+            let v = data.into_iter().map(|_| Transactions::default()).collect();
+            Ok(Some(v))
+        }
+    });
+
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        body_batch_size: 4,
+        max_fork_depth: 0,
+        ..Default::default()
+    };
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor: DefaultMocks::times([10]),
+    };
+
+    // when
+    let state = State::new(None, 9).into();
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(9, None), true), res);
+    assert_eq!(*requested.lock().unwrap(), vec![0..4, 4..8, 8..10]);
+}
+
 #[tokio::test]
 async fn import__signature_fails_on_header_5_only() {
     // given
@@ -217,6 +284,8 @@ async fn import__signature_fails_on_header_5_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -265,6 +334,8 @@ async fn import__signature_fails_on_header_4_only() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -296,6 +367,8 @@ async fn import__header_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -327,6 +400,8 @@ async fn import__header_response_incomplete() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -366,6 +441,8 @@ async fn import__header_5_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -398,6 +475,8 @@ async fn import__header_4_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -442,6 +521,8 @@ async fn import__transactions_not_found() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -496,6 +577,8 @@ async fn import__transactions_not_found_for_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -541,6 +624,8 @@ async fn import__transactions_not_found_for_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -568,6 +653,8 @@ async fn import__p2p_error() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -612,6 +699,8 @@ async fn import__p2p_error_on_4_transactions() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -660,6 +749,8 @@ async fn import__consensus_error_on_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -714,6 +805,8 @@ async fn import__consensus_error_on_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -774,6 +867,8 @@ async fn import__execution_error_on_header_4() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -834,6 +929,8 @@ async fn import__execution_error_on_header_5() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -843,6 +940,88 @@ async fn import__execution_error_on_header_5() {
     assert_eq!((State::new(4, None), false), res);
 }
 
+#[tokio::test]
+async fn import__execution_error_searches_for_the_equivocation_below() {
+    // given
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(4)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(1)
+        .returning(|_| Ok(()));
+    consensus_port
+        .expect_check_equivocation()
+        .times(2)
+        .returning(|header| {
+            if **header.entity.height() == 3 {
+                Ok(Some(EquivocationProof {
+                    first: header.clone(),
+                    second: header.clone(),
+                }))
+            } else {
+                Ok(None)
+            }
+        });
+
+    let mut p2p = MockPeerToPeerPort::default();
+    let mut seq = mockall::Sequence::new();
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .with(mockall::predicate::eq(4..6))
+        .in_sequence(&mut seq)
+        .returning(|range| {
+            let peer = random_peer();
+            let headers = Some(range.map(empty_header).collect());
+            let headers = peer.bind(headers);
+            Ok(headers)
+        });
+    p2p.expect_get_sealed_block_headers()
+        .times(1)
+        .with(mockall::predicate::eq(2..4))
+        .in_sequence(&mut seq)
+        .returning(|range| {
+            let peer = random_peer();
+            let headers = Some(range.map(empty_header).collect());
+            let headers = peer.bind(headers);
+            Ok(headers)
+        });
+    p2p.expect_get_transactions()
+        .times(1)
+        .returning(|block_ids| {
+            let data = block_ids.data;
+            let v = data.into_iter().map(|_| Transactions::default()).collect();
+            Ok(Some(v))
+        });
+
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_execute_and_commit()
+        .times(1)
+        .returning(|_| Err(anyhow::anyhow!("Some execution error")));
+
+    let state = State::new(3, 5).into();
+    let mocks = Mocks {
+        consensus_port,
+        p2p,
+        executor,
+    };
+    let params = Config {
+        block_stream_buffer_size: 10,
+        header_batch_size: 10,
+        max_fork_depth: 2,
+        ..Default::default()
+    };
+
+    // when
+    let res = test_import_inner(state, mocks, None, params).await;
+
+    // then
+    assert_eq!((State::new(3, None), false), res);
+}
+
 #[tokio::test]
 async fn signature_always_fails() {
     // given
@@ -862,6 +1041,8 @@ async fn signature_always_fails() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -914,6 +1095,8 @@ async fn import__can_work_in_two_loops() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
 
     // when
@@ -923,6 +1106,102 @@ async fn import__can_work_in_two_loops() {
     assert_eq!((State::new(6, None), true), res);
 }
 
+fn archived_block(height: u32) -> SealedBlock {
+    let Sealed { entity, consensus } = empty_header(height);
+    SealedBlock {
+        entity: Block::try_from_executed(entity, vec![]).unwrap(),
+        consensus,
+    }
+}
+
+#[tokio::test]
+async fn import__imports_blocks_from_archive_before_network() {
+    // given
+    let mut archive = MockBlockArchivePort::default();
+    archive.expect_get_blocks().times(2).returning(|height| {
+        let blocks = (*height..=3).map(archived_block).collect();
+        Ok(blocks)
+    });
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(3)
+        .returning(|_| Ok(true));
+    consensus_port
+        .expect_await_da_height()
+        .times(3)
+        .returning(|_| Ok(()));
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_execute_and_commit()
+        .times(3)
+        .returning(|_| Ok(()));
+    let state: SharedMutex<State> = State::new(0, None).into();
+    let import = Import::new(
+        state.clone(),
+        Arc::new(Notify::new()),
+        Config::default(),
+        Arc::new(MockPeerToPeerPort::default()),
+        Arc::new(executor),
+        Arc::new(consensus_port),
+    )
+    .with_archive(Some(Arc::new(archive)));
+    let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
+    let mut watcher = shutdown.into();
+
+    // when
+    import.notify_one();
+    let res = import.import(&mut watcher).await;
+
+    // then
+    assert!(res.is_ok());
+    assert_eq!(state.apply(|s| s.clone()), State::new(3, None));
+}
+
+#[tokio::test]
+async fn import__stops_archive_import_on_invalid_seal() {
+    // given
+    let mut archive = MockBlockArchivePort::default();
+    archive
+        .expect_get_blocks()
+        .times(1)
+        .returning(|height| Ok((*height..=3).map(archived_block).collect()));
+    let mut consensus_port = MockConsensusPort::default();
+    consensus_port
+        .expect_check_sealed_header()
+        .times(2)
+        .returning(|h| Ok(**h.entity.height() != 2));
+    consensus_port
+        .expect_await_da_height()
+        .times(1)
+        .returning(|_| Ok(()));
+    let mut executor = MockBlockImporterPort::default();
+    executor
+        .expect_execute_and_commit()
+        .times(1)
+        .returning(|_| Ok(()));
+    let state: SharedMutex<State> = State::new(0, None).into();
+    let import = Import::new(
+        state.clone(),
+        Arc::new(Notify::new()),
+        Config::default(),
+        Arc::new(MockPeerToPeerPort::default()),
+        Arc::new(executor),
+        Arc::new(consensus_port),
+    )
+    .with_archive(Some(Arc::new(archive)));
+    let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
+    let mut watcher = shutdown.into();
+
+    // when
+    import.notify_one();
+    let res = import.import(&mut watcher).await;
+
+    // then
+    assert!(res.is_ok());
+    assert_eq!(state.apply(|s| s.clone()), State::new(1, None));
+}
+
 async fn test_import_inner(
     state: SharedMutex<State>,
     mocks: Mocks,
@@ -948,6 +1227,8 @@ async fn test_import_inner(
         p2p,
         executor,
         consensus,
+        throttle: Arc::new(PeerThrottle::new(&params)),
+        archive: Default::default(),
     };
     let (_tx, shutdown) = tokio::sync::watch::channel(fuel_core_services::State::Started);
     let mut watcher = shutdown.into();
@@ -1101,6 +1382,8 @@ impl PeerReportTestBuilder {
         let params = Config {
             block_stream_buffer_size: 10,
             header_batch_size: 10,
+            max_fork_depth: 0,
+            ..Default::default()
         };
 
         let import = Import {
@@ -1110,6 +1393,8 @@ impl PeerReportTestBuilder {
             p2p,
             executor,
             consensus,
+            throttle: Arc::new(PeerThrottle::new(&params)),
+            archive: Default::default(),
         };
         let (_tx, shutdown) =
             tokio::sync::watch::channel(fuel_core_services::State::Started);
@@ -0,0 +1,182 @@
+//! The limits of the requests to a single peer.
+
+use super::Config;
+use fuel_core_types::services::p2p::PeerId;
+use std::{
+    collections::HashMap,
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Duration,
+};
+use tokio::sync::{
+    OwnedSemaphorePermit,
+    Semaphore,
+};
+
+/// The delay after the first slow response of the peer.
+const MIN_PEER_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Limits the number of the requests in flight to each peer and delays
+/// the requests to the peers that respond slowly.
+///
+/// The response slower than `slow_peer_threshold` doubles the delay before
+/// the next request to the peer, up to `max_peer_backoff`. The fast response
+/// resets the delay.
+#[derive(Debug)]
+pub(crate) struct PeerThrottle {
+    max_in_flight: usize,
+    slow_threshold: Duration,
+    max_backoff: Duration,
+    peers: Mutex<HashMap<PeerId, PeerState>>,
+}
+
+#[derive(Debug)]
+struct PeerState {
+    in_flight: Arc<Semaphore>,
+    backoff: Duration,
+}
+
+impl PeerThrottle {
+    pub(crate) fn new(params: &Config) -> Self {
+        Self {
+            max_in_flight: params.max_in_flight_per_peer.max(1),
+            slow_threshold: params.slow_peer_threshold,
+            max_backoff: params.max_peer_backoff,
+            peers: Default::default(),
+        }
+    }
+
+    /// Waits until the `peer` has the free slot for the request and its backoff passes.
+    /// The slot is released when the permit is dropped.
+    pub(crate) async fn acquire(&self, peer: &PeerId) -> OwnedSemaphorePermit {
+        let (in_flight, backoff) =
+            self.apply(peer, |state| (state.in_flight.clone(), state.backoff));
+        let permit = in_flight
+            .acquire_owned()
+            .await
+            .expect("The semaphore is never closed; qed");
+        if !backoff.is_zero() {
+            tokio::time::sleep(backoff).await;
+        }
+        permit
+    }
+
+    /// Records the response time of the `peer`.
+    pub(crate) fn record(&self, peer: &PeerId, elapsed: Duration) {
+        let slow_threshold = self.slow_threshold;
+        let max_backoff = self.max_backoff;
+        self.apply(peer, |state| {
+            if elapsed > slow_threshold {
+                state.backoff = state
+                    .backoff
+                    .saturating_mul(2)
+                    .max(MIN_PEER_BACKOFF)
+                    .min(max_backoff);
+                tracing::debug!(
+                    "The peer {:?} responded in {:?}, delaying its requests by {:?}",
+                    peer,
+                    elapsed,
+                    state.backoff
+                );
+            } else {
+                state.backoff = Duration::ZERO;
+            }
+        })
+    }
+
+    fn apply<R>(&self, peer: &PeerId, f: impl FnOnce(&mut PeerState) -> R) -> R {
+        let mut peers = self.peers.lock().expect("poisoned");
+        let state = peers.entry(peer.clone()).or_insert_with(|| PeerState {
+            in_flight: Arc::new(Semaphore::new(self.max_in_flight)),
+            backoff: Duration::ZERO,
+        });
+        f(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::FutureExt;
+
+    fn throttle(max_in_flight_per_peer: usize) -> PeerThrottle {
+        PeerThrottle::new(&Config {
+            max_in_flight_per_peer,
+            slow_peer_threshold: Duration::from_secs(1),
+            max_peer_backoff: Duration::from_millis(300),
+            ..Default::default()
+        })
+    }
+
+    fn backoff(throttle: &PeerThrottle, peer: &PeerId) -> Duration {
+        throttle.apply(peer, |state| state.backoff)
+    }
+
+    #[test]
+    fn slow_responses_double_backoff_up_to_max() {
+        // Given
+        let throttle = throttle(1);
+        let peer = PeerId::from(vec![1]);
+
+        // When
+        let mut backoffs = vec![];
+        for _ in 0..4 {
+            throttle.record(&peer, Duration::from_secs(2));
+            backoffs.push(backoff(&throttle, &peer));
+        }
+
+        // Then
+        assert_eq!(
+            backoffs,
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+                Duration::from_millis(300),
+                Duration::from_millis(300),
+            ]
+        );
+    }
+
+    #[test]
+    fn fast_response_resets_backoff_of_the_peer_only() {
+        // Given
+        let throttle = throttle(1);
+        let slow = PeerId::from(vec![1]);
+        let fast = PeerId::from(vec![2]);
+        throttle.record(&slow, Duration::from_secs(2));
+        throttle.record(&fast, Duration::from_secs(2));
+
+        // When
+        throttle.record(&fast, Duration::from_millis(10));
+
+        // Then
+        assert_eq!(backoff(&throttle, &slow), Duration::from_millis(100));
+        assert_eq!(backoff(&throttle, &fast), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn acquire_limits_requests_in_flight_per_peer() {
+        // Given
+        let throttle = throttle(2);
+        let peer = PeerId::from(vec![1]);
+        let other = PeerId::from(vec![2]);
+        let _first = throttle.acquire(&peer).await;
+        let second = throttle.acquire(&peer).await;
+
+        // When
+        let third =
+            tokio::time::timeout(Duration::from_millis(10), throttle.acquire(&peer))
+                .await;
+        let other_peer =
+            tokio::time::timeout(Duration::from_millis(10), throttle.acquire(&other))
+                .await;
+
+        // Then
+        assert!(third.is_err());
+        assert!(other_peer.is_ok());
+        drop(second);
+        assert!(throttle.acquire(&peer).now_or_never().is_some());
+    }
+}
@@ -3,6 +3,7 @@
 use fuel_core_services::stream::BoxStream;
 use fuel_core_types::{
     blockchain::{
+        consensus::poa::EquivocationProof,
         primitives::DaBlockHeight,
         SealedBlock,
         SealedBlockHeader,
@@ -64,6 +65,12 @@ pub trait PeerToPeerPort {
 pub trait ConsensusPort {
     /// Check if the given sealed block header is valid.
     fn check_sealed_header(&self, header: &SealedBlockHeader) -> anyhow::Result<bool>;
+    /// Compare the sealed block header with the local block at the same height.
+    /// Returns and records the proof if the authority sealed both of them.
+    fn check_equivocation(
+        &self,
+        header: &SealedBlockHeader,
+    ) -> anyhow::Result<Option<EquivocationProof>>;
     /// await for this DA height to be sync'd.
     async fn await_da_height(&self, da_height: &DaBlockHeight) -> anyhow::Result<()>;
 }
@@ -77,5 +84,20 @@ pub trait BlockImporterPort {
 
     /// Execute the given sealed block
     /// and commit it to the database.
+    ///
+    /// The commit may finish in the background after the method returns. If it fails,
+    /// the error is returned by the next call or by [`Self::await_pending_commit`].
     async fn execute_and_commit(&self, block: SealedBlock) -> anyhow::Result<()>;
+
+    /// Waits for the commit of the last block passed to [`Self::execute_and_commit`].
+    async fn await_pending_commit(&self) -> anyhow::Result<()>;
+}
+
+#[cfg_attr(any(test, feature = "benchmarking"), mockall::automock)]
+#[async_trait::async_trait]
+/// Port for the archive of the sealed blocks, imported before the sync with the network.
+pub trait BlockArchivePort {
+    /// Returns the consecutive blocks of the archive starting at the `height`.
+    /// The empty result means that the archive doesn't have the block at the `height`.
+    async fn get_blocks(&self, height: BlockHeight) -> anyhow::Result<Vec<SealedBlock>>;
 }
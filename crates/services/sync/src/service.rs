@@ -5,6 +5,7 @@ use crate::{
     import::{
         Config,
         Import,
+        SharedArchive,
     },
     ports::{
         self,
@@ -41,6 +42,7 @@ pub fn new_service<P, E, C>(
     p2p: P,
     executor: E,
     consensus: C,
+    archive: Option<SharedArchive>,
     params: Config,
 ) -> anyhow::Result<ServiceRunner<SyncTask<P, E, C>>>
 where
@@ -59,6 +61,7 @@ where
         p2p,
         executor,
         consensus,
+        archive,
     )?))
 }
 
@@ -82,6 +85,7 @@ where
     E: BlockImporterPort + Send + Sync + 'static,
     C: ConsensusPort + Send + Sync + 'static,
 {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         height_stream: BoxStream<BlockHeight>,
         committed_height_stream: BoxStream<BlockHeight>,
@@ -90,6 +94,7 @@ where
         p2p: P,
         executor: E,
         consensus: C,
+        archive: Option<SharedArchive>,
     ) -> anyhow::Result<Self> {
         let notify = Arc::new(Notify::new());
         let state = SharedMutex::new(state);
@@ -102,7 +107,8 @@ where
             state.clone(),
             notify.clone(),
         );
-        let import = Import::new(state, notify, params, p2p, executor, consensus);
+        let import = Import::new(state, notify, params, p2p, executor, consensus)
+            .with_archive(archive);
         let import_task_handle = ServiceRunner::new(ImportTask(import));
         Ok(Self {
             sync_heights,
@@ -181,9 +187,8 @@ where
     }
 
     async fn shutdown(self) -> anyhow::Result<()> {
-        // Nothing to shut down because we don't have any temporary state that should be dumped,
-        // and we don't spawn any sub-tasks that we need to finish or await.
-        Ok(())
+        // The commit of the last imported block may still be in progress.
+        self.0.await_pending_commit().await
     }
 }
 
@@ -58,6 +58,10 @@ async fn test_new_service() {
         tx.try_send(**h.entity.header().height()).unwrap();
         Ok(())
     });
+    importer
+        .expect_await_pending_commit()
+        .times(1)
+        .returning(|| Ok(()));
     let mut consensus = MockConsensusPort::default();
     consensus
         .expect_check_sealed_header()
@@ -66,8 +70,10 @@ async fn test_new_service() {
     let params = Config {
         block_stream_buffer_size: 10,
         header_batch_size: 10,
+        max_fork_depth: 0,
+        ..Default::default()
     };
-    let s = new_service(4u32.into(), p2p, importer, consensus, params).unwrap();
+    let s = new_service(4u32.into(), p2p, importer, consensus, None, params).unwrap();
 
     assert_eq!(
         s.start_and_await().await.unwrap(),
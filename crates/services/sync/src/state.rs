@@ -78,6 +78,15 @@ impl State {
         }
     }
 
+    /// Get the height of the last committed block, if it is known.
+    pub fn committed_height(&self) -> Option<u32> {
+        match &self.status {
+            Status::Committed(height) => Some(*height),
+            Status::Processing(range) => range.start().checked_sub(1),
+            Status::Uninitialized => None,
+        }
+    }
+
     #[tracing::instrument]
     /// Record that a block has been committed.
     pub fn commit(&mut self, height: u32) {
@@ -12,6 +12,14 @@ fn test_new(state: State) -> Status {
     state.status
 }
 
+#[test_case(State::new(None, None) => None)]
+#[test_case(State::new(10, None) => Some(10))]
+#[test_case(State::new(None, 10) => None)]
+#[test_case(State::new(1, 10) => Some(1))]
+fn test_committed_height(state: State) -> Option<u32> {
+    state.committed_height()
+}
+
 #[test_case(State::new(None, None), 0 => Status::Committed(0))]
 #[test_case(State::new(0, None), 0 => Status::Committed(0))]
 #[test_case(State::new(1, None), 0 => Status::Committed(1))]
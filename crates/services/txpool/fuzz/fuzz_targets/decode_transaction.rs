@@ -0,0 +1,21 @@
+#![no_main]
+
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    fuel_types::canonical::{
+        Deserialize,
+        Serialize,
+    },
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(tx) = Transaction::from_bytes(data) else {
+        return
+    };
+
+    let encoded = tx.to_bytes();
+    let decoded = Transaction::from_bytes(&encoded)
+        .expect("The encoded transaction should be decodable");
+    assert_eq!(tx, decoded);
+});
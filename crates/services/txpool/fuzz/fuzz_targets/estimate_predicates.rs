@@ -0,0 +1,27 @@
+#![no_main]
+
+use fuel_core_types::{
+    fuel_tx::{
+        ConsensusParameters,
+        Transaction,
+    },
+    fuel_types::canonical::Deserialize,
+    fuel_vm::checked_transaction::{
+        CheckPredicateParams,
+        EstimatePredicates,
+    },
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(tx) = Transaction::from_bytes(data) else {
+        return
+    };
+    let params = CheckPredicateParams::from(&ConsensusParameters::default());
+
+    let _ = match tx {
+        Transaction::Script(mut script) => script.estimate_predicates(&params),
+        Transaction::Create(mut create) => create.estimate_predicates(&params),
+        _ => return,
+    };
+});
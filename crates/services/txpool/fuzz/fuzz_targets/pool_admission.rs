@@ -0,0 +1,54 @@
+#![no_main]
+
+use fuel_core_txpool::{
+    mock_db::MockDBProvider,
+    service::TxStatusChange,
+    txpool::check_single_tx,
+    Config,
+    TxPool,
+};
+use fuel_core_txpool_fuzz::state_for;
+use fuel_core_types::{
+    fuel_tx::Transaction,
+    fuel_types::canonical::Deserialize,
+};
+use libfuzzer_sys::fuzz_target;
+use std::{
+    sync::OnceLock,
+    time::Duration,
+};
+use tokio::runtime::Runtime;
+
+fn runtime() -> &'static Runtime {
+    static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .expect("The runtime should be created")
+    })
+}
+
+// The first byte enables the UTXO validation, the rest is the encoded transaction.
+fuzz_target!(|data: &[u8]| {
+    let Some((flags, data)) = data.split_first() else {
+        return
+    };
+    let Ok(tx) = Transaction::from_bytes(data) else {
+        return
+    };
+    let config = Config {
+        utxo_validation: flags & 1 == 1,
+        ..Default::default()
+    };
+    let database = state_for(&tx);
+
+    runtime().block_on(async move {
+        let Ok(checked) = check_single_tx(tx, Default::default(), &config).await else {
+            return
+        };
+        let tx_status_sender = TxStatusChange::new(1, Duration::from_secs(1));
+        let mut txpool = TxPool::new(config, MockDBProvider(database));
+        let _ = txpool.insert(&tx_status_sender, vec![checked]);
+    });
+});
@@ -0,0 +1,73 @@
+//! Seeds the corpus of the fuzzing targets with the latest transactions of the network:
+//!
+//! ```shell
+//! cargo run --bin seed_corpus -- <GRAPHQL_URL> <NUMBER_OF_TRANSACTIONS>
+//! ```
+
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    FuelClient,
+};
+use fuel_core_txpool_fuzz::TARGETS;
+use fuel_core_types::{
+    fuel_crypto::Hasher,
+    fuel_types::canonical::Serialize,
+};
+use std::path::Path;
+
+const PAGE_SIZE: i32 = 100;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> anyhow::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let url = args
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("The GraphQL URL of the node is required"))?;
+    let limit: usize = args.next().map(|n| n.parse()).transpose()?.unwrap_or(1000);
+
+    let client = FuelClient::new(url)?;
+    let corpus = Path::new(env!("CARGO_MANIFEST_DIR")).join("corpus");
+    let mut cursor = None;
+    let mut written = 0;
+    while written < limit {
+        let page = client
+            .transactions(PaginationRequest {
+                cursor,
+                results: PAGE_SIZE,
+                direction: PageDirection::Backward,
+            })
+            .await?;
+
+        for response in page.results {
+            let bytes = response.transaction.to_bytes();
+            let name = hex(Hasher::hash(&bytes).as_ref());
+            for target in TARGETS {
+                let directory = corpus.join(target);
+                std::fs::create_dir_all(&directory)?;
+                // The first byte of the `pool_admission` input enables the UTXO validation.
+                let input = if target == "pool_admission" {
+                    [&[1u8][..], &bytes].concat()
+                } else {
+                    bytes.clone()
+                };
+                std::fs::write(directory.join(&name), input)?;
+            }
+            written += 1;
+        }
+
+        if !page.has_next_page || page.cursor.is_none() {
+            break
+        }
+        cursor = page.cursor;
+    }
+
+    println!("Seeded the corpus with {written} transactions");
+    Ok(())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
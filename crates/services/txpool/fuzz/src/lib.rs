@@ -0,0 +1,50 @@
+//! The shared helpers of the fuzzing targets.
+
+use fuel_core_txpool::MockDb;
+use fuel_core_types::{
+    entities::coins::coin::CompressedCoin,
+    fuel_tx::{
+        field::Inputs,
+        AssetId,
+        Input,
+        Transaction,
+    },
+};
+
+/// The fuzzing targets fed with the canonically encoded transactions.
+pub const TARGETS: [&str; 3] = [
+    "decode_transaction",
+    "estimate_predicates",
+    "pool_admission",
+];
+
+/// Returns the inputs of the transaction types accepted by the pool.
+pub fn inputs(tx: &Transaction) -> &[Input] {
+    match tx {
+        Transaction::Script(script) => script.inputs(),
+        Transaction::Create(create) => create.inputs(),
+        _ => &[],
+    }
+}
+
+/// Returns the tiny state with the coins spent by the transaction, so the admission
+/// reaches the dependency checks instead of stopping at the unknown inputs.
+pub fn state_for(tx: &Transaction) -> MockDb {
+    let database = MockDb::default();
+    for input in inputs(tx) {
+        let (Some(utxo_id), Some(owner), Some(amount), Some(asset_id)) = (
+            input.utxo_id(),
+            input.input_owner(),
+            input.amount(),
+            input.asset_id(&AssetId::BASE),
+        ) else {
+            continue
+        };
+        let mut coin = CompressedCoin::default();
+        coin.set_owner(*owner);
+        coin.set_amount(amount);
+        coin.set_asset_id(*asset_id);
+        database.data.lock().unwrap().coins.insert(*utxo_id, coin);
+    }
+    database
+}
@@ -5,6 +5,11 @@ use std::time::Duration;
 pub struct Config {
     /// Maximum number of transactions inside the pool
     pub max_tx: usize,
+    /// Maximum total size in bytes of the transactions inside the pool
+    pub max_tx_bytes: usize,
+    /// Maximum total size in bytes of the `Create` transactions inside the pool.
+    /// They carry the bytecode of the contracts and are not counted in `max_tx_bytes`.
+    pub max_blob_tx_bytes: usize,
     /// max depth of connected UTXO excluding contracts
     pub max_depth: usize,
     /// The minimum allowed gas price
@@ -24,6 +29,8 @@ pub struct Config {
 impl Default for Config {
     fn default() -> Self {
         let max_tx = 4064;
+        let max_tx_bytes = usize::MAX;
+        let max_blob_tx_bytes = usize::MAX;
         let max_depth = 10;
         let min_gas_price = 0;
         let utxo_validation = true;
@@ -33,6 +40,8 @@ impl Default for Config {
         let number_of_active_subscription = max_tx;
         Self::new(
             max_tx,
+            max_tx_bytes,
+            max_blob_tx_bytes,
             max_depth,
             ChainConfig::default(),
             min_gas_price,
@@ -48,6 +57,8 @@ impl Config {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         max_tx: usize,
+        max_tx_bytes: usize,
+        max_blob_tx_bytes: usize,
         max_depth: usize,
         chain_config: ChainConfig,
         min_gas_price: u64,
@@ -60,6 +71,8 @@ impl Config {
         //  in all places where `new` is used.
         Self {
             max_tx,
+            max_tx_bytes,
+            max_blob_tx_bytes,
             max_depth,
             min_gas_price,
             utxo_validation,
@@ -0,0 +1,113 @@
+use crate::{
+    types::*,
+    Error,
+    TxInfo,
+};
+use fuel_core_types::{
+    fuel_tx::Address,
+    services::txpool::ArcPoolTx,
+};
+use std::collections::{
+    btree_map,
+    BTreeMap,
+    HashMap,
+    HashSet,
+};
+
+/// The pending transactions with the ordering hint, grouped by their owner.
+/// The transactions of one owner are included into the blocks in the order of their hints,
+/// similar to the nonce of the account.
+#[derive(Debug, Clone, Default)]
+pub struct OrderingHints {
+    /// owner -> the chain of the pending transactions sorted by the hint.
+    chains: HashMap<Address, BTreeMap<u64, ArcPoolTx>>,
+    /// tx id -> the owner and the hint of the transaction.
+    by_tx: HashMap<TxId, (Address, u64)>,
+}
+
+impl OrderingHints {
+    /// The owner of the transaction is the owner of its first coin or message input.
+    pub fn owner(tx: &PoolTransaction) -> Option<Address> {
+        tx.inputs()
+            .iter()
+            .find_map(|input| input.input_owner())
+            .copied()
+    }
+
+    /// Checks that the owner doesn't have another pending transaction with the same hint.
+    pub fn check(&self, owner: &Address, hint: u64) -> Result<(), Error> {
+        match self.chains.get(owner).and_then(|chain| chain.get(&hint)) {
+            Some(tx) => Err(Error::NotInsertedOrderingHintTaken(tx.id(), hint)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn insert(&mut self, info: &TxInfo) {
+        let Some(hint) = info.ordering_hint() else {
+            return
+        };
+        let Some(owner) = Self::owner(info.tx()) else {
+            return
+        };
+        self.chains
+            .entry(owner)
+            .or_default()
+            .insert(hint, info.tx().clone());
+        self.by_tx.insert(info.id(), (owner, hint));
+    }
+
+    pub fn remove(&mut self, tx_id: &TxId) {
+        let Some((owner, hint)) = self.by_tx.remove(tx_id) else {
+            return
+        };
+        if let Some(chain) = self.chains.get_mut(&owner) {
+            chain.remove(&hint);
+            if chain.is_empty() {
+                self.chains.remove(&owner);
+            }
+        }
+    }
+
+    /// Returns the pending transactions of the owner in the order of their hints.
+    pub fn chain(&self, owner: &Address) -> Vec<ArcPoolTx> {
+        self.chains
+            .get(owner)
+            .map(|chain| chain.values().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Reorders the transactions of each owner by their hints. The transactions keep
+    /// the positions occupied by the transactions of the same owner, so the
+    /// transactions of other owners are not affected.
+    pub fn reorder(&self, txs: impl Iterator<Item = ArcPoolTx>) -> Vec<ArcPoolTx> {
+        let mut chains: HashMap<Address, btree_map::Values<u64, ArcPoolTx>> =
+            HashMap::new();
+        txs.map(|tx| {
+            let Some((owner, _)) = self.by_tx.get(&tx.id()) else {
+                return tx
+            };
+            chains
+                .entry(*owner)
+                .or_insert_with(|| self.chains[owner].values())
+                .next()
+                .cloned()
+                .unwrap_or(tx)
+        })
+        .collect()
+    }
+
+    /// Removes the transactions from the `selected` if the transaction of the same
+    /// owner with the lower hint is not selected.
+    pub fn retain_ordered(&self, mut selected: Vec<ArcPoolTx>) -> Vec<ArcPoolTx> {
+        let selected_ids: HashSet<_> = selected.iter().map(|tx| tx.id()).collect();
+        selected.retain(|tx| {
+            let Some((owner, hint)) = self.by_tx.get(&tx.id()) else {
+                return true
+            };
+            self.chains[owner]
+                .range(..*hint)
+                .all(|(_, tx)| selected_ids.contains(&tx.id()))
+        });
+        selected
+    }
+}
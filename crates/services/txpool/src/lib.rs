@@ -48,6 +48,7 @@ pub struct TxInfo {
     tx: ArcPoolTx,
     submitted_time: Duration,
     creation_instant: tokio::time::Instant,
+    ordering_hint: Option<u64>,
 }
 
 #[allow(missing_docs)]
@@ -61,9 +62,16 @@ impl TxInfo {
             tx,
             submitted_time: since_epoch,
             creation_instant: tokio::time::Instant::now(),
+            ordering_hint: None,
         }
     }
 
+    /// Sets the hint supplied by the client to order the transactions of one owner.
+    pub fn with_ordering_hint(mut self, ordering_hint: Option<u64>) -> Self {
+        self.ordering_hint = ordering_hint;
+        self
+    }
+
     pub fn tx(&self) -> &ArcPoolTx {
         &self.tx
     }
@@ -75,6 +83,10 @@ impl TxInfo {
     pub fn created(&self) -> tokio::time::Instant {
         self.creation_instant
     }
+
+    pub fn ordering_hint(&self) -> Option<u64> {
+        self.ordering_hint
+    }
 }
 
 impl Deref for TxInfo {
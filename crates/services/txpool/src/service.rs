@@ -4,7 +4,6 @@ use crate::{
         PeerToPeer,
         TxPoolDb,
     },
-    transaction_selector::select_transactions,
     txpool::{
         check_single_tx,
         check_transactions,
@@ -24,6 +23,7 @@ use fuel_core_services::{
 };
 use fuel_core_types::{
     fuel_tx::{
+        Address,
         ConsensusParameters,
         Transaction,
         TxId,
@@ -43,6 +43,8 @@ use fuel_core_types::{
         txpool::{
             ArcPoolTx,
             Error,
+            EvictedTransaction,
+            EvictionReason,
             InsertionResult,
             TransactionStatus,
         },
@@ -77,15 +79,18 @@ pub type Service<P2P, DB> = ServiceRunner<Task<P2P, DB>>;
 #[derive(Clone)]
 pub struct TxStatusChange {
     new_tx_notification_sender: broadcast::Sender<TxId>,
+    evicted_tx_sender: broadcast::Sender<EvictedTransaction>,
     update_sender: UpdateSender,
 }
 
 impl TxStatusChange {
     pub fn new(capacity: usize, ttl: Duration) -> Self {
         let (new_tx_notification_sender, _) = broadcast::channel(capacity);
+        let (evicted_tx_sender, _) = broadcast::channel(capacity);
         let update_sender = UpdateSender::new(capacity, ttl);
         Self {
             new_tx_notification_sender,
+            evicted_tx_sender,
             update_sender,
         }
     }
@@ -109,13 +114,22 @@ impl TxStatusChange {
         ));
     }
 
-    pub fn send_squeezed_out(&self, id: Bytes32, reason: TxPoolError) {
+    pub fn send_squeezed_out(
+        &self,
+        id: Bytes32,
+        reason: TxPoolError,
+        eviction_reason: EvictionReason,
+    ) {
         tracing::info!("Transaction {id} squeezed out because {reason}");
+        let message = reason.to_string();
+        let _ = self.evicted_tx_sender.send(EvictedTransaction {
+            tx_id: id,
+            reason: eviction_reason,
+            message: message.clone(),
+        });
         self.update_sender.send(TxUpdate::new(
             id,
-            TxStatusMessage::Status(TransactionStatus::SqueezedOut {
-                reason: reason.to_string(),
-            }),
+            TxStatusMessage::Status(TransactionStatus::SqueezedOut { reason: message }),
         ));
     }
 }
@@ -196,7 +210,11 @@ where
             _ = self.ttl_timer.tick() => {
                 let removed = self.shared.txpool.lock().prune_old_txs();
                 for tx in removed {
-                    self.shared.tx_status_sender.send_squeezed_out(tx.id(), Error::TTLReason);
+                    self.shared.tx_status_sender.send_squeezed_out(
+                        tx.id(),
+                        Error::TTLReason,
+                        EvictionReason::Expired,
+                    );
                 }
 
                 should_continue = true
@@ -294,6 +312,10 @@ impl<P2P, ViewProvider> SharedState<P2P, ViewProvider> {
         self.txpool.lock().pending_number()
     }
 
+    pub fn pending_bytes(&self) -> usize {
+        self.txpool.lock().pending_bytes()
+    }
+
     pub fn total_consumable_gas(&self) -> u64 {
         self.txpool.lock().consumable_gas()
     }
@@ -310,19 +332,24 @@ impl<P2P, ViewProvider> SharedState<P2P, ViewProvider> {
         self.txpool.lock().find_one(&id)
     }
 
+    pub fn pending_transactions(&self) -> Vec<TxInfo> {
+        let mut txs: Vec<_> = self.txpool.lock().txs().values().cloned().collect();
+        txs.sort_by_key(|info| info.id());
+        txs
+    }
+
     pub fn find_dependent(&self, ids: Vec<TxId>) -> Vec<ArcPoolTx> {
         self.txpool.lock().find_dependent(&ids)
     }
 
     pub fn select_transactions(&self, max_gas: u64) -> Vec<ArcPoolTx> {
-        let mut guard = self.txpool.lock();
-        let txs = guard.includable();
-        let sorted_txs = select_transactions(txs, max_gas);
+        self.txpool.lock().select_transactions(max_gas)
+    }
 
-        for tx in sorted_txs.iter() {
-            guard.remove_committed_tx(&tx.id());
-        }
-        sorted_txs
+    /// Returns the pending transactions of the owner with the ordering hint,
+    /// in the order of their inclusion into the blocks.
+    pub fn pending_chain(&self, owner: &Address) -> Vec<TxInfo> {
+        self.txpool.lock().pending_chain(owner)
     }
 
     pub fn remove(&self, ids: Vec<(TxId, String)>) -> Vec<ArcPoolTx> {
@@ -333,6 +360,10 @@ impl<P2P, ViewProvider> SharedState<P2P, ViewProvider> {
         self.tx_status_sender.new_tx_notification_sender.subscribe()
     }
 
+    pub fn evicted_tx_subscribe(&self) -> broadcast::Receiver<EvictedTransaction> {
+        self.tx_status_sender.evicted_tx_sender.subscribe()
+    }
+
     pub fn tx_update_subscribe(&self, tx_id: Bytes32) -> anyhow::Result<TxStatusStream> {
         self.tx_status_sender
             .update_sender
@@ -360,11 +391,23 @@ where
     ViewProvider: AtomicView<View = View>,
     View: TxPoolDb,
 {
-    #[tracing::instrument(name = "insert_submitted_txn", skip_all)]
     pub async fn insert(
         &self,
         txs: Vec<Arc<Transaction>>,
     ) -> Vec<Result<InsertionResult, Error>> {
+        let txs = txs.into_iter().map(|tx| (tx, None)).collect();
+        self.insert_with_ordering_hints(txs).await
+    }
+
+    /// Inserts the transactions with the optional hints ordering the transactions
+    /// of one owner. The hints are not broadcasted to the network.
+    #[tracing::instrument(name = "insert_submitted_txn", skip_all)]
+    pub async fn insert_with_ordering_hints(
+        &self,
+        txs: Vec<(Arc<Transaction>, Option<u64>)>,
+    ) -> Vec<Result<InsertionResult, Error>> {
+        let (txs, ordering_hints): (Vec<_>, Vec<_>) = txs.into_iter().unzip();
+
         // verify txs
         let current_height = *self.current_height.lock();
 
@@ -374,9 +417,10 @@ where
 
         let checked_txs: Vec<_> = checked_txs
             .into_iter()
-            .map(|tx_check| match tx_check {
+            .zip(ordering_hints)
+            .map(|(tx_check, ordering_hint)| match tx_check {
                 Ok(tx) => {
-                    valid_txs.push(tx);
+                    valid_txs.push((tx, ordering_hint));
                     None
                 }
                 Err(err) => Some(err),
@@ -384,7 +428,11 @@ where
             .collect();
 
         // insert txs
-        let insertion = { self.txpool.lock().insert(&self.tx_status_sender, valid_txs) };
+        let insertion = {
+            self.txpool
+                .lock()
+                .insert_with_ordering_hints(&self.tx_status_sender, valid_txs)
+        };
 
         for (ret, tx) in insertion.iter().zip(txs.into_iter()) {
             match ret {
@@ -279,3 +279,47 @@ async fn simple_insert_removal_subscription() {
 
     service.stop_and_await().await.unwrap();
 }
+
+#[tokio::test(start_paused = true)]
+async fn evicted_tx_subscription_receives_expired_and_removed_txs() {
+    const TIMEOUT: u64 = 10;
+
+    let config = Config {
+        transaction_ttl: Duration::from_secs(TIMEOUT),
+        ..Default::default()
+    };
+    let ctx = TestContextBuilder::new()
+        .with_config(config)
+        .build_and_start()
+        .await;
+
+    let tx1 = Arc::new(ctx.setup_script_tx(10));
+    let tx2 = Arc::new(ctx.setup_script_tx(20));
+    let service = ctx.service();
+    let mut evicted = service.shared.evicted_tx_subscribe();
+
+    let out = service.shared.insert(vec![tx1.clone()]).await;
+    assert!(out[0].is_ok(), "Tx1 should be OK, got err:{out:?}");
+    service.shared.remove(vec![(
+        tx1.cached_id().unwrap(),
+        "Because of the test purposes".to_string(),
+    )]);
+
+    let event = evicted.recv().await.unwrap();
+    assert_eq!(event.tx_id, tx1.cached_id().unwrap());
+    assert_eq!(event.reason, EvictionReason::Invalid);
+    assert_eq!(
+        event.message,
+        "Transaction squeezed out because Because of the test purposes"
+    );
+
+    let out = service.shared.insert(vec![tx2.clone()]).await;
+    assert!(out[0].is_ok(), "Tx2 should be OK, got err:{out:?}");
+    tokio::time::sleep(Duration::from_secs(TIMEOUT * 2)).await;
+
+    let event = evicted.recv().await.unwrap();
+    assert_eq!(event.tx_id, tx2.cached_id().unwrap());
+    assert_eq!(event.reason, EvictionReason::Expired);
+
+    service.stop_and_await().await.unwrap();
+}
@@ -1,6 +1,13 @@
+use fuel_core_chain_config::{
+    BlobPricing,
+    FeeFreeAllowance,
+};
 use fuel_core_types::{
     fuel_types::Word,
-    services::txpool::ArcPoolTx,
+    services::txpool::{
+        ArcPoolTx,
+        PoolTransaction,
+    },
 };
 
 // transaction selection could use a plugin based approach in the
@@ -10,12 +17,21 @@ use fuel_core_types::{
 pub fn select_transactions(
     includable_txs: impl Iterator<Item = ArcPoolTx>,
     max_gas: u64,
+    fee_free_allowance: Option<&FeeFreeAllowance>,
+    blob_pricing: Option<&BlobPricing>,
 ) -> Vec<ArcPoolTx> {
     // Select all txs that fit into the block, preferring ones with higher gas price.
     //
     // Future improvements to this algorithm may take into account the parallel nature of
     // transactions to maximize throughput.
     let mut used_block_space: Word = 0;
+    // The whitelisted transactions over the fee-free budget of the block wait for the
+    // next block. The executor follows the same order, so it executes all of the
+    // selected whitelisted transactions with zero gas price.
+    let mut used_fee_free_space: Word = 0;
+    // The payloads over the budget of the block wait for the next block,
+    // the executor rejects the block otherwise.
+    let mut used_blob_bytes: u64 = 0;
     // The type of the index for the transaction is `u16`, so we need to
     // limit it to `MAX` value minus 1(because of the `Mint` transaction).
     let takes_txs = u16::MAX - 1;
@@ -24,9 +40,34 @@ pub fn select_transactions(
     includable_txs
         .filter(|tx| {
             let tx_block_space = tx.max_gas();
+            let is_fee_free = fee_free_allowance
+                .filter(|allowance| allowance.is_whitelisted(tx.inputs()))
+                .map(|allowance| {
+                    allowance.fits_budget(used_fee_free_space, tx_block_space)
+                });
+            if is_fee_free == Some(false) {
+                return false
+            }
+            let payload_size = match tx.as_ref() {
+                PoolTransaction::Create(create) => {
+                    BlobPricing::payload_size(create.transaction())
+                }
+                PoolTransaction::Script(_) => 0,
+            };
+            let fits_blob_budget = blob_pricing
+                .map(|pricing| pricing.fits_budget(used_blob_bytes, payload_size))
+                .unwrap_or(true);
+            if !fits_blob_budget {
+                return false
+            }
             if let Some(new_used_space) = used_block_space.checked_add(tx_block_space) {
                 if new_used_space <= max_gas {
                     used_block_space = new_used_space;
+                    if is_fee_free == Some(true) {
+                        used_fee_free_space =
+                            used_fee_free_space.saturating_add(tx_block_space);
+                    }
+                    used_blob_bytes = used_blob_bytes.saturating_add(payload_size);
                     true
                 } else {
                     false
@@ -54,6 +95,7 @@ mod tests {
         fuel_tx::{
             FeeParameters,
             GasCosts,
+            Input,
             Output,
             TransactionBuilder,
         },
@@ -114,7 +156,7 @@ mod tests {
             .collect::<Vec<ArcPoolTx>>();
         txs.sort_by_key(|a| core::cmp::Reverse(a.tip()));
 
-        select_transactions(txs.into_iter(), block_gas_limit)
+        select_transactions(txs.into_iter(), block_gas_limit, None, None)
             .into_iter()
             .map(|tx| TxGas {
                 limit: tx.script_gas_limit().unwrap_or_default(),
@@ -202,4 +244,48 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn selector_limits_whitelisted_txs_by_fee_free_budget() {
+        let mut rng = thread_rng();
+        let predicate: Vec<u8> = vec![op::ret(RegId::ONE)].into_iter().collect();
+        let owner = Input::predicate_owner(&predicate);
+        let allowance = FeeFreeAllowance {
+            predicate_roots: vec![owner],
+            gas_budget_per_block: 2000,
+        };
+
+        let txs = (0..3)
+            .map(|_| {
+                TransactionBuilder::script(
+                    vec![op::ret(RegId::ONE)].into_iter().collect(),
+                    vec![],
+                )
+                .script_gas_limit(1000)
+                .add_input(Input::coin_predicate(
+                    rng.gen(),
+                    owner,
+                    1_000_000,
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                    predicate.clone(),
+                    vec![],
+                ))
+                .with_fee_params(FeeParameters {
+                    gas_price_factor: 1,
+                    gas_per_byte: 0,
+                })
+                .with_gas_costs(GasCosts::free())
+                .finalize_checked_basic(Default::default())
+                .into()
+            })
+            .map(Arc::new)
+            .collect::<Vec<ArcPoolTx>>();
+
+        let selected =
+            select_transactions(txs.into_iter(), 10_000, Some(&allowance), None);
+
+        assert_eq!(selected.len(), 2);
+    }
 }
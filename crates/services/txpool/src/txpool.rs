@@ -1,18 +1,31 @@
 use crate::{
     containers::{
         dependency::Dependency,
+        ordering_hint::OrderingHints,
         price_sort::TipSort,
         time_sort::TimeSort,
     },
     ports::TxPoolDb,
     service::TxStatusChange,
+    transaction_selector::select_transactions,
     types::*,
     Config,
     Error,
     TxInfo,
 };
 use fuel_core_types::{
-    fuel_tx::Transaction,
+    blockchain::rules::Limits,
+    fuel_tx::{
+        field::{
+            Inputs,
+            Tip,
+            Witnesses,
+        },
+        Address,
+        Input,
+        Transaction,
+        UniqueIdentifier,
+    },
     fuel_types::BlockHeight,
     fuel_vm::{
         checked_transaction::{
@@ -27,11 +40,16 @@ use fuel_core_types::{
     },
     services::txpool::{
         ArcPoolTx,
+        EvictionReason,
         InsertionResult,
     },
     tai64::Tai64,
 };
 
+use fuel_core_chain_config::{
+    multisig_predicate::MultisigPredicate,
+    BlobPricing,
+};
 use fuel_core_metrics::txpool_metrics::txpool_metrics;
 use fuel_core_storage::transactional::AtomicView;
 use fuel_core_types::{
@@ -52,6 +70,12 @@ pub struct TxPool<ViewProvider> {
     by_tip: TipSort,
     by_time: TimeSort,
     by_dependency: Dependency,
+    by_ordering_hint: OrderingHints,
+    /// The total size in bytes of the transactions inside the pool,
+    /// except for the `Create` transactions.
+    total_bytes: usize,
+    /// The total size in bytes of the `Create` transactions inside the pool.
+    blob_bytes: usize,
     config: Config,
     database: ViewProvider,
 }
@@ -65,6 +89,9 @@ impl<ViewProvider> TxPool<ViewProvider> {
             by_tip: TipSort::default(),
             by_time: TimeSort::default(),
             by_dependency: Dependency::new(max_depth, config.utxo_validation),
+            by_ordering_hint: OrderingHints::default(),
+            total_bytes: 0,
+            blob_bytes: 0,
             config,
             database,
         }
@@ -112,11 +139,23 @@ impl<ViewProvider> TxPool<ViewProvider> {
         if let Some(info) = &info {
             self.by_time.remove(info);
             self.by_tip.remove(info);
+            self.by_ordering_hint.remove(tx_id);
+            let tx_bytes = info.tx().metered_bytes_size();
+            let bytes = self.bytes_budget_mut(info.tx());
+            *bytes = bytes.saturating_sub(tx_bytes);
         }
 
         info
     }
 
+    /// Returns the budget of the pool taken by the transaction.
+    fn bytes_budget_mut(&mut self, tx: &PoolTransaction) -> &mut usize {
+        match tx {
+            PoolTransaction::Create(_) => &mut self.blob_bytes,
+            PoolTransaction::Script(_) => &mut self.total_bytes,
+        }
+    }
+
     /// Removes transaction from `TxPool` with assumption that it is committed into the blockchain.
     // TODO: Don't remove recursively dependent transactions on block commit.
     //  The same logic should be fixed in the `select_transactions`.
@@ -160,20 +199,58 @@ impl<ViewProvider> TxPool<ViewProvider> {
         list
     }
 
+    /// Returns the pending transactions of the owner with the ordering hint,
+    /// in the order of their inclusion into the blocks.
+    pub fn pending_chain(&self, owner: &Address) -> Vec<TxInfo> {
+        self.by_ordering_hint
+            .chain(owner)
+            .iter()
+            .filter_map(|tx| self.by_hash.get(&tx.id()).cloned())
+            .collect()
+    }
+
     /// The number of pending transaction in the pool.
     pub fn pending_number(&self) -> usize {
         self.by_hash.len()
     }
 
+    /// The total size in bytes of the transactions inside the pool,
+    /// including the `Create` transactions.
+    pub fn pending_bytes(&self) -> usize {
+        self.total_bytes.saturating_add(self.blob_bytes)
+    }
+
     /// The amount of gas in all includable transactions combined
     pub fn consumable_gas(&self) -> u64 {
         self.by_hash.values().map(|tx| tx.max_gas()).sum()
     }
 
     /// Return all sorted transactions that are includable in next block.
+    /// The transactions of one owner with the ordering hints follow the order of the hints.
     /// This is going to be heavy operation, use it only when needed.
     pub fn includable(&mut self) -> impl Iterator<Item = ArcPoolTx> + '_ {
-        self.sorted_includable()
+        self.by_ordering_hint
+            .reorder(self.sorted_includable())
+            .into_iter()
+    }
+
+    /// Selects the transactions for the next block and removes them from the pool.
+    /// The transaction with the ordering hint is not selected
+    /// if the previous transaction of the same owner doesn't fit into the block.
+    pub fn select_transactions(&mut self, max_gas: u64) -> Vec<ArcPoolTx> {
+        let includable = self.by_ordering_hint.reorder(self.sorted_includable());
+        let selected = select_transactions(
+            includable.into_iter(),
+            max_gas,
+            self.config.chain_config.fee_free_allowance.as_ref(),
+            self.config.chain_config.blob_pricing.as_ref(),
+        );
+        let selected = self.by_ordering_hint.retain_ordered(selected);
+
+        for tx in selected.iter() {
+            self.remove_committed_tx(&tx.id());
+        }
+        selected
     }
 
     /// When block is updated we need to receive all spend outputs and remove them from txpool.
@@ -197,14 +274,19 @@ impl<ViewProvider> TxPool<ViewProvider> {
         let mut removed = Vec::new();
         for (tx_id, reason) in tx_ids.into_iter() {
             let rem = self.remove_by_tx_id(&tx_id);
-            tx_status_sender.send_squeezed_out(tx_id, Error::SqueezedOut(reason.clone()));
+            tx_status_sender.send_squeezed_out(
+                tx_id,
+                Error::SqueezedOut(reason.clone()),
+                EvictionReason::Invalid,
+            );
             for dependent_tx in rem.iter() {
                 if tx_id != dependent_tx.id() {
                     tx_status_sender.send_squeezed_out(
                         dependent_tx.id(),
                         Error::SqueezedOut(
                             format!("Parent transaction with {tx_id}, was removed because of the {reason}")
-                        )
+                        ),
+                        EvictionReason::Invalid,
                     );
                 }
             }
@@ -249,16 +331,18 @@ where
         tx: Checked<Transaction>,
     ) -> Result<InsertionResult, Error> {
         let view = self.database.latest_view();
-        self.insert_inner(tx, &view)
+        self.insert_inner(tx, None, &view).map(|(result, _)| result)
     }
 
     #[tracing::instrument(level = "info", skip_all, fields(tx_id = %tx.id()), ret, err)]
     // this is atomic operation. Return removed(pushed out/replaced) transactions
+    // with the reason of their removal.
     fn insert_inner(
         &mut self,
         tx: Checked<Transaction>,
+        ordering_hint: Option<u64>,
         view: &View,
-    ) -> Result<InsertionResult, Error> {
+    ) -> Result<(InsertionResult, EvictionReason), Error> {
         let tx: CheckedTransaction = tx.into();
 
         let tx = Arc::new(match tx {
@@ -271,18 +355,30 @@ where
             return Err(Error::NoMetadata)
         }
 
-        // verify max gas is less than block limit
-        if tx.max_gas() > self.config.chain_config.block_gas_limit {
-            return Err(Error::NotInsertedMaxGasLimit {
-                tx_gas: tx.max_gas(),
-                block_limit: self.config.chain_config.block_gas_limit,
-            })
+        // verify the transaction fits into the same limits as the block validation
+        let limits = Limits::new(
+            &self.config.chain_config.consensus_parameters,
+            self.config.chain_config.block_gas_limit,
+        );
+        tx.check_limits(&limits)?;
+        if let (PoolTransaction::Create(create), Some(pricing)) =
+            (tx.as_ref(), &self.config.chain_config.blob_pricing)
+        {
+            let create = create.transaction();
+            pricing.check_fee(BlobPricing::payload_size(create), create.tip())?;
         }
 
         if self.by_hash.contains_key(&tx.id()) {
             return Err(Error::NotInsertedTxKnown)
         }
 
+        let owner = OrderingHints::owner(&tx);
+        // the hint is ignored for the transactions without the owner
+        let ordering_hint = ordering_hint.filter(|_| owner.is_some());
+        if let (Some(hint), Some(owner)) = (ordering_hint, owner) {
+            self.by_ordering_hint.check(&owner, hint)?;
+        }
+
         let mut max_limit_hit = false;
         // check if we are hitting limit of pool
         if self.by_hash.len() >= self.config.max_tx {
@@ -293,20 +389,37 @@ where
                 return Err(Error::NotInsertedLimitHit)
             }
         }
+        let tx_bytes = tx.metered_bytes_size();
+        let (used_bytes, max_bytes) = match tx.as_ref() {
+            PoolTransaction::Create(_) => {
+                (self.blob_bytes, self.config.max_blob_tx_bytes)
+            }
+            PoolTransaction::Script(_) => (self.total_bytes, self.config.max_tx_bytes),
+        };
+        if used_bytes.saturating_add(tx_bytes) > max_bytes {
+            return Err(Error::NotInsertedLimitHit)
+        }
         if self.config.metrics {
-            txpool_metrics()
-                .tx_size_histogram
-                .observe(tx.metered_bytes_size() as f64);
+            txpool_metrics().tx_size_histogram.observe(tx_bytes as f64);
         }
         // check and insert dependency
         let rem = self.by_dependency.insert(&self.by_hash, view, &tx)?;
-        let info = TxInfo::new(tx.clone());
+        let info = TxInfo::new(tx.clone()).with_ordering_hint(ordering_hint);
         let submitted_time = info.submitted_time();
         self.by_tip.insert(&info);
         self.by_time.insert(&info);
+        self.by_ordering_hint.insert(&info);
         self.by_hash.insert(tx.id(), info);
+        let bytes = self.bytes_budget_mut(&tx);
+        *bytes = bytes.saturating_add(tx_bytes);
 
         // if some transaction were removed so we don't need to check limit
+        // without conflicts the only possible removal is the push out of the lowest tip
+        let reason = if rem.is_empty() {
+            EvictionReason::LowFee
+        } else {
+            EvictionReason::Conflict
+        };
         let removed = if rem.is_empty() {
             if max_limit_hit {
                 // remove last tx from sort
@@ -325,41 +438,60 @@ where
             rem
         };
 
-        Ok(InsertionResult {
-            inserted: tx,
-            submitted_time,
-            removed,
-        })
+        Ok((
+            InsertionResult {
+                inserted: tx,
+                submitted_time,
+                removed,
+            },
+            reason,
+        ))
     }
 
-    #[tracing::instrument(level = "info", skip_all)]
     /// Import a set of transactions from network gossip or GraphQL endpoints.
     pub fn insert(
         &mut self,
         tx_status_sender: &TxStatusChange,
         txs: Vec<Checked<Transaction>>,
+    ) -> Vec<Result<InsertionResult, Error>> {
+        let txs = txs.into_iter().map(|tx| (tx, None)).collect();
+        self.insert_with_ordering_hints(tx_status_sender, txs)
+    }
+
+    #[tracing::instrument(level = "info", skip_all, fields(tx_count = txs.len()))]
+    /// Import a set of transactions with the optional ordering hints supplied by the client.
+    /// The hints are local to the node and are not gossiped with the transactions.
+    pub fn insert_with_ordering_hints(
+        &mut self,
+        tx_status_sender: &TxStatusChange,
+        txs: Vec<(Checked<Transaction>, Option<u64>)>,
     ) -> Vec<Result<InsertionResult, Error>> {
         // Check if that data is okay (witness match input/output, and if recovered signatures ara valid).
         // should be done before transaction comes to txpool, or before it enters RwLocked region.
         let mut res = Vec::new();
         let view = self.database.latest_view();
 
-        for tx in txs.into_iter() {
-            res.push(self.insert_inner(tx, &view));
+        for (tx, ordering_hint) in txs.into_iter() {
+            res.push(self.insert_inner(tx, ordering_hint, &view));
         }
 
         // announce to subscribers
         for ret in res.iter() {
             match ret {
-                Ok(InsertionResult {
-                    removed,
-                    inserted,
-                    submitted_time,
-                }) => {
+                Ok((
+                    InsertionResult {
+                        removed,
+                        inserted,
+                        submitted_time,
+                    },
+                    reason,
+                )) => {
                     for removed in removed {
-                        // small todo there is possibility to have removal reason (ReplacedByHigherGas, DependencyRemoved)
-                        // but for now it is okay to just use Error::Removed.
-                        tx_status_sender.send_squeezed_out(removed.id(), Error::Removed);
+                        tx_status_sender.send_squeezed_out(
+                            removed.id(),
+                            Error::Removed,
+                            *reason,
+                        );
                     }
                     tx_status_sender.send_submitted(
                         inserted.id(),
@@ -371,7 +503,9 @@ where
                 }
             }
         }
-        res
+        res.into_iter()
+            .map(|result| result.map(|(result, _)| result))
+            .collect()
     }
 }
 
@@ -390,6 +524,12 @@ pub async fn check_transactions(
     checked_txs
 }
 
+#[tracing::instrument(
+    level = "info",
+    skip_all,
+    fields(tx_id = %tx.id(&config.chain_config.consensus_parameters.chain_id)),
+    err
+)]
 pub async fn check_single_tx(
     tx: Transaction,
     current_height: BlockHeight,
@@ -398,6 +538,7 @@ pub async fn check_single_tx(
     if tx.is_mint() {
         return Err(Error::NotSupportedTransactionType)
     }
+    verify_multisig_inputs(&tx)?;
 
     let tx: Checked<Transaction> = if config.utxo_validation {
         let consensus_params = &config.chain_config.consensus_parameters;
@@ -424,12 +565,54 @@ pub async fn check_single_tx(
     Ok(tx)
 }
 
+/// Checks the witness layout of the coins owned by the multisig predicate shipped
+/// with the node, so the malformed transactions are rejected before the predicates
+/// are executed.
+fn verify_multisig_inputs(tx: &Transaction) -> Result<(), Error> {
+    let (inputs, witnesses) = match tx {
+        Transaction::Script(script) => (script.inputs(), script.witnesses()),
+        Transaction::Create(create) => (create.inputs(), create.witnesses()),
+        Transaction::Mint(_) => return Ok(()),
+    };
+    for input in inputs {
+        if let Input::CoinPredicate(coin) = input {
+            if let Some(multisig) = MultisigPredicate::from_bytecode(&coin.predicate) {
+                multisig
+                    .check_witnesses(&coin.predicate_data, witnesses)
+                    .map_err(|e| Error::NotInsertedInvalidMultisig(e.to_string()))?;
+            }
+        }
+    }
+    Ok(())
+}
+
 fn verify_tx_min_gas_price(
     tx: Checked<Transaction>,
     config: &Config,
 ) -> Result<Checked<Transaction>, Error> {
     let tx: CheckedTransaction = tx.into();
-    let min_gas_price = config.min_gas_price;
+    // The whitelisted transactions may be executed with zero gas price.
+    let is_fee_free = |inputs: &[Input]| {
+        config
+            .chain_config
+            .fee_free_allowance
+            .as_ref()
+            .map(|allowance| allowance.is_whitelisted(inputs))
+            .unwrap_or(false)
+    };
+    let min_gas_price = match &tx {
+        CheckedTransaction::Script(script)
+            if is_fee_free(script.transaction().inputs()) =>
+        {
+            0
+        }
+        CheckedTransaction::Create(create)
+            if is_fee_free(create.transaction().inputs()) =>
+        {
+            0
+        }
+        _ => config.min_gas_price,
+    };
     let gas_costs = &config.chain_config.consensus_parameters.gas_costs;
     let fee_parameters = &config.chain_config.consensus_parameters.fee_params;
     let read = match tx {
@@ -470,6 +653,8 @@ impl ParallelExecutor for TokioWithRayon {
     }
 }
 
+#[cfg(test)]
+mod scenario;
 #[cfg(test)]
 mod test_helpers;
 #[cfg(test)]
@@ -0,0 +1,277 @@
+//! The scripted scenarios for the transaction pool on top of the mock storage.
+//!
+//! The time of the pool is the tokio clock, so the scenarios should run with the paused clock
+//! (`#[tokio::test(start_paused = true)]`) to advance it deterministically:
+//!
+//! ```ignore
+//! let mut scenario = Scenario::default();
+//! let tx = scenario.script_tx(1);
+//! scenario.insert(tx.clone()).await.expect(Ok(()));
+//! scenario.advance_time(ttl).await.expect_evicted(&tx);
+//! ```
+
+use crate::{
+    mock_db::MockDBProvider,
+    service::TxStatusChange,
+    test_helpers::{
+        add_coin_to_state,
+        IntoEstimated,
+        TextContext,
+        TEST_COIN_AMOUNT,
+    },
+    txpool::check_single_tx,
+    Config,
+    Error,
+    MockDb,
+    TxPool,
+};
+use fuel_core_types::{
+    fuel_asm::op,
+    fuel_tx::{
+        Address,
+        AssetId,
+        Input,
+        Transaction,
+        TransactionBuilder,
+        TxId,
+        UniqueIdentifier,
+        UtxoId,
+    },
+    fuel_types::{
+        BlockHeight,
+        Word,
+    },
+    services::txpool::InsertionResult,
+};
+use std::{
+    collections::HashSet,
+    time::Duration,
+};
+
+const GAS_LIMIT: Word = 1000;
+
+pub(crate) struct Scenario {
+    context: TextContext,
+    database: MockDb,
+    txpool: TxPool<MockDBProvider>,
+    tx_status_sender: TxStatusChange,
+    height: BlockHeight,
+    evicted: HashSet<TxId>,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self::new(Config::default())
+    }
+}
+
+impl Scenario {
+    pub(crate) fn new(config: Config) -> Self {
+        let mut context = TextContext::default();
+        let database = context.database_mut().clone();
+        let tx_status_sender = TxStatusChange::new(
+            config.number_of_active_subscription,
+            config.transaction_ttl,
+        );
+        let txpool = TxPool::new(config, MockDBProvider(database.clone()));
+        Self {
+            context,
+            database,
+            txpool,
+            tx_status_sender,
+            height: Default::default(),
+            evicted: Default::default(),
+        }
+    }
+
+    /// The storage used by the pool to look up the inputs.
+    pub(crate) fn database(&self) -> &MockDb {
+        &self.database
+    }
+
+    /// Adds the new coin to the storage and returns the input spending it.
+    pub(crate) fn setup_coin(&mut self) -> Input {
+        self.context.setup_coin().1
+    }
+
+    /// Adds the new coin of the predicate `owner` to the storage and returns the input
+    /// spending it. The coins with the same `owner` belong to the same address.
+    pub(crate) fn setup_owned_coin(&mut self, owner: u8) -> Input {
+        let mut predicate_code: Vec<u8> = vec![op::ret(1)].into_iter().collect();
+        predicate_code.push(owner);
+        let input = self
+            .context
+            .custom_predicate(AssetId::BASE, TEST_COIN_AMOUNT, predicate_code, None)
+            .into_default_estimated();
+        add_coin_to_state(input, Some(&self.database)).1
+    }
+
+    /// Returns the script transaction with the `tip` spending the new coin.
+    pub(crate) fn script_tx(&mut self, tip: Word) -> Transaction {
+        let coin = self.setup_coin();
+        TransactionBuilder::script(vec![], vec![])
+            .tip(tip)
+            .max_fee_limit(tip)
+            .script_gas_limit(GAS_LIMIT)
+            .add_input(coin)
+            .finalize_as_transaction()
+    }
+
+    /// Returns the pair of the script transactions with the `tip`,
+    /// where the child spends the output of the parent.
+    pub(crate) fn dependent_txs(&mut self, tip: Word) -> (Transaction, Transaction) {
+        let (output, unset_input) = self.context.create_output_and_input(1);
+        let parent = TransactionBuilder::script(vec![], vec![])
+            .tip(tip)
+            .max_fee_limit(tip)
+            .script_gas_limit(GAS_LIMIT)
+            .add_input(self.setup_coin())
+            .add_output(output)
+            .finalize_as_transaction();
+        let input = unset_input.into_input(UtxoId::new(self.tx_id(&parent), 0));
+        let child = TransactionBuilder::script(vec![], vec![])
+            .tip(tip)
+            .max_fee_limit(tip)
+            .script_gas_limit(GAS_LIMIT)
+            .add_input(input)
+            .add_input(self.setup_coin())
+            .finalize_as_transaction();
+        (parent, child)
+    }
+
+    /// Checks the transaction at the current height and inserts it into the pool.
+    pub(crate) async fn insert(&mut self, tx: Transaction) -> Insertion<'_> {
+        self.insert_with_ordering_hint(tx, None).await
+    }
+
+    /// Inserts the transaction like [`Self::insert`] with the `ordering_hint`.
+    pub(crate) async fn insert_with_ordering_hint(
+        &mut self,
+        tx: Transaction,
+        ordering_hint: Option<u64>,
+    ) -> Insertion<'_> {
+        let result = match check_single_tx(tx, self.height, self.txpool.config()).await {
+            Ok(checked) => self
+                .txpool
+                .insert_with_ordering_hints(
+                    &self.tx_status_sender,
+                    vec![(checked, ordering_hint)],
+                )
+                .pop()
+                .expect("One result per transaction; qed"),
+            Err(err) => Err(err),
+        };
+        if let Ok(result) = &result {
+            self.evicted.extend(result.removed.iter().map(|tx| tx.id()));
+        }
+        Insertion {
+            scenario: self,
+            result,
+        }
+    }
+
+    /// Increases the height used to check the inserted transactions.
+    pub(crate) fn advance_height(&mut self, blocks: u32) -> &mut Self {
+        self.height = (*self.height).saturating_add(blocks).into();
+        self
+    }
+
+    /// Advances the paused clock and prunes the expired transactions,
+    /// like the TTL timer of the service.
+    pub(crate) async fn advance_time(&mut self, duration: Duration) -> &mut Self {
+        tokio::time::advance(duration).await;
+        let removed = self.txpool.prune_old_txs();
+        self.evicted.extend(removed.iter().map(|tx| tx.id()));
+        self
+    }
+
+    /// Removes the transactions included into the next block.
+    pub(crate) fn commit(&mut self, txs: &[&Transaction]) -> &mut Self {
+        for tx in txs {
+            let tx_id = self.tx_id(tx);
+            self.txpool.remove_committed_tx(&tx_id);
+        }
+        self.advance_height(1)
+    }
+
+    /// Selects the transactions for the next block and removes them from the pool.
+    pub(crate) fn select(&mut self, max_gas: Word) -> Vec<TxId> {
+        self.txpool
+            .select_transactions(max_gas)
+            .iter()
+            .map(|tx| tx.id())
+            .collect()
+    }
+
+    /// The ids of the pending transactions of the owner with the ordering hint.
+    pub(crate) fn pending_chain(&self, owner: &Address) -> Vec<TxId> {
+        self.txpool
+            .pending_chain(owner)
+            .iter()
+            .map(|info| info.id())
+            .collect()
+    }
+
+    /// The number of the transactions in the pool.
+    pub(crate) fn pending_number(&self) -> usize {
+        self.txpool.pending_number()
+    }
+
+    /// Asserts that the transaction was evicted from the pool.
+    pub(crate) fn expect_evicted(&mut self, tx: &Transaction) -> &mut Self {
+        let tx_id = self.tx_id(tx);
+        assert!(
+            self.evicted.contains(&tx_id),
+            "The transaction {tx_id} wasn't evicted"
+        );
+        assert!(
+            self.txpool.find_one(&tx_id).is_none(),
+            "The evicted transaction {tx_id} is in the pool"
+        );
+        self
+    }
+
+    /// Asserts that the transaction is in the pool.
+    pub(crate) fn expect_pending(&mut self, tx: &Transaction) -> &mut Self {
+        let tx_id = self.tx_id(tx);
+        assert!(
+            self.txpool.find_one(&tx_id).is_some(),
+            "The transaction {tx_id} isn't in the pool"
+        );
+        self
+    }
+
+    pub(crate) fn tx_id(&self, tx: &Transaction) -> TxId {
+        tx.id(&self
+            .txpool
+            .config()
+            .chain_config
+            .consensus_parameters
+            .chain_id)
+    }
+}
+
+/// The result of the insertion into the [`Scenario`].
+#[must_use]
+pub(crate) struct Insertion<'a> {
+    scenario: &'a mut Scenario,
+    result: Result<InsertionResult, Error>,
+}
+
+impl<'a> Insertion<'a> {
+    /// Asserts the result of the insertion. The errors are compared by their messages.
+    pub(crate) fn expect(self, expected: Result<(), Error>) -> &'a mut Scenario {
+        match (&self.result, &expected) {
+            (Ok(_), Ok(())) => {}
+            (Err(actual), Err(expected)) => {
+                assert_eq!(actual.to_string(), expected.to_string())
+            }
+            (actual, expected) => panic!(
+                "Expected {:?}, got {:?}",
+                expected,
+                actual.as_ref().map(|_| ())
+            ),
+        }
+        self.scenario
+    }
+}
@@ -4,16 +4,24 @@ use crate::{
         TextContext,
         TEST_COIN_AMOUNT,
     },
-    txpool::test_helpers::{
-        create_coin_output,
-        create_contract_input,
-        create_contract_output,
-        create_message_predicate_from_message,
+    txpool::{
+        scenario::Scenario,
+        test_helpers::{
+            create_coin_output,
+            create_contract_input,
+            create_contract_output,
+            create_message_predicate_from_message,
+        },
     },
     Config,
     Error,
 };
+use fuel_core_chain_config::{
+    multisig_predicate::MultisigPredicate,
+    BlobPricing,
+};
 use fuel_core_types::{
+    blockchain::rules::LimitError,
     fuel_asm::{
         op,
         RegId,
@@ -24,12 +32,14 @@ use fuel_core_types::{
         Address,
         AssetId,
         Contract,
+        Create,
         Input,
         Output,
         Transaction,
         TransactionBuilder,
         UniqueIdentifier,
         UtxoId,
+        ValidityError,
     },
     fuel_types::ChainId,
     fuel_vm::checked_transaction::{
@@ -40,6 +50,7 @@ use fuel_core_types::{
 use std::{
     cmp::Reverse,
     collections::HashMap,
+    time::Duration,
     vec,
 };
 
@@ -579,6 +590,131 @@ async fn tx_limit_hit() {
     assert!(matches!(err, Error::NotInsertedLimitHit));
 }
 
+#[tokio::test]
+async fn tx_bytes_limit_hit() {
+    let mut context = TextContext::default().config(Config {
+        max_tx_bytes: 1,
+        ..Default::default()
+    });
+
+    let (_, gas_coin) = context.setup_coin();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let mut txpool = context.build();
+    let tx = check_unwrap_tx(tx, &txpool.config).await;
+
+    let err = txpool
+        .insert_single(tx)
+        .expect_err("Tx should be Err, got Ok");
+    assert!(matches!(err, Error::NotInsertedLimitHit));
+}
+
+#[tokio::test]
+async fn pending_bytes_follow_inserted_and_removed_txs() {
+    // Given
+    let mut context = TextContext::default();
+    let (_, gas_coin) = context.setup_coin();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let mut txpool = context.build();
+    let tx = check_unwrap_tx(tx, &txpool.config).await;
+
+    // When
+    let inserted = txpool
+        .insert_single(tx)
+        .expect("Tx should be Ok, got Err")
+        .inserted;
+
+    // Then
+    assert_eq!(txpool.pending_bytes(), inserted.metered_bytes_size());
+    txpool.remove_by_tx_id(&inserted.id());
+    assert_eq!(txpool.pending_bytes(), 0);
+}
+
+/// The transaction deploying the contract with the bytecode of the `payload_size`.
+fn deployment(payload_size: usize) -> TransactionBuilder<Create> {
+    let bytecode = vec![0; payload_size];
+    let contract = Contract::from(bytecode.clone());
+    let contract_id = contract.id(
+        &Default::default(),
+        &contract.root(),
+        &Contract::default_state_root(),
+    );
+    let mut builder = TransactionBuilder::create(
+        bytecode.into(),
+        Default::default(),
+        Default::default(),
+    );
+    builder.add_output(create_contract_output(contract_id));
+    builder
+}
+
+#[tokio::test]
+async fn blob_bytes_limit_doesnt_affect_script_txs() {
+    let mut context = TextContext::default().config(Config {
+        max_blob_tx_bytes: 1,
+        ..Default::default()
+    });
+
+    let (_, gas_coin) = context.setup_coin();
+    let create = deployment(100)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+    let (_, gas_coin) = context.setup_coin();
+    let script = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let mut txpool = context.build();
+    let create = check_unwrap_tx(create, &txpool.config).await;
+    let script = check_unwrap_tx(script, &txpool.config).await;
+
+    let err = txpool
+        .insert_single(create)
+        .expect_err("Create should be Err, got Ok");
+    assert!(matches!(err, Error::NotInsertedLimitHit));
+    txpool
+        .insert_single(script)
+        .expect("Script should be OK, got Err");
+}
+
+#[tokio::test]
+async fn create_tx_not_paying_for_payload_not_inserted() {
+    let mut config = Config::default();
+    config.chain_config.blob_pricing = Some(BlobPricing {
+        byte_price: 2,
+        max_bytes_per_block: u64::MAX,
+    });
+    let mut context = TextContext::default().config(config);
+
+    let (_, gas_coin) = context.setup_coin();
+    let tx = deployment(100)
+        .tip(199)
+        .max_fee_limit(199)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let mut txpool = context.build();
+    let tx = check_unwrap_tx(tx, &txpool.config).await;
+
+    let err = txpool
+        .insert_single(tx)
+        .expect_err("Tx should be Err, got Ok");
+    assert!(matches!(
+        err,
+        Error::NotInsertedLimitExceeded(LimitError::BlobFeeTooLow {
+            tip: 199,
+            required: 200
+        })
+    ));
+}
+
 #[tokio::test]
 async fn tx_depth_hit() {
     let mut context = TextContext::default().config(Config {
@@ -626,6 +762,66 @@ async fn tx_depth_hit() {
     assert!(matches!(err, Error::NotInsertedMaxDepth));
 }
 
+#[tokio::test]
+async fn tx_exceeding_block_gas_limit_not_inserted() {
+    let mut config = Config::default();
+    config.chain_config.block_gas_limit = GAS_LIMIT;
+    let mut context = TextContext::default().config(config);
+
+    let (_, gas_coin) = context.setup_coin();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .finalize_as_transaction();
+
+    let mut txpool = context.build();
+    let tx = check_unwrap_tx(tx, &txpool.config).await;
+
+    let err = txpool
+        .insert_single(tx)
+        .expect_err("Tx should be Err, got Ok");
+    assert!(matches!(
+        err,
+        Error::NotInsertedLimitExceeded(
+            LimitError::TransactionExceedsBlockGasLimit { block_limit, .. }
+        ) if block_limit == GAS_LIMIT
+    ));
+}
+
+#[tokio::test]
+async fn tx_exceeding_max_outputs_not_inserted() {
+    let mut context = TextContext::default();
+
+    let (_, gas_coin) = context.setup_coin();
+    let (output, _) = context.create_output_and_input(1);
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(gas_coin)
+        .add_output(output)
+        .add_output(output)
+        .finalize_as_transaction();
+
+    let mut txpool = context.build();
+    let tx = check_unwrap_tx(tx, &txpool.config).await;
+    txpool
+        .config
+        .chain_config
+        .consensus_parameters
+        .tx_params
+        .max_outputs = 1;
+
+    let err = txpool
+        .insert_single(tx)
+        .expect_err("Tx should be Err, got Ok");
+    assert!(matches!(
+        err,
+        Error::NotInsertedLimitExceeded(LimitError::TooManyOutputs {
+            count: 2,
+            limit: 1
+        })
+    ));
+}
+
 #[tokio::test]
 async fn sorted_out_tx1_2_4() {
     let mut context = TextContext::default();
@@ -1093,3 +1289,285 @@ async fn predicate_that_returns_false_is_invalid() {
         "unexpected error: {err}",
     )
 }
+
+#[tokio::test]
+async fn multisig_input_without_enough_signatures_is_invalid() {
+    let mut context = TextContext::default();
+    let signers = vec![Address::new([1; 32]), Address::new([2; 32])];
+    let multisig = MultisigPredicate::new(2, signers).unwrap();
+    let mut coin = context.custom_predicate(
+        AssetId::BASE,
+        TEST_COIN_AMOUNT,
+        multisig.bytecode(),
+        None,
+    );
+    // Given
+    if let Input::CoinPredicate(CoinPredicate { predicate_data, .. }) = &mut coin {
+        *predicate_data = multisig.predicate_data(&[Some(0), None]).unwrap();
+    }
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin)
+        .add_witness(vec![0; 64].into())
+        .finalize_as_transaction();
+
+    // When
+    let err = check_tx(tx, &Default::default())
+        .await
+        .expect_err("Transaction should be err, got ok");
+
+    // Then
+    assert!(
+        matches!(err, Error::NotInsertedInvalidMultisig(_)),
+        "unexpected error: {err}",
+    )
+}
+
+#[tokio::test(start_paused = true)]
+async fn expired_parent_evicts_its_fresh_child() {
+    let mut scenario = Scenario::new(Config {
+        transaction_ttl: Duration::from_secs(10),
+        ..Default::default()
+    });
+    let (parent, child) = scenario.dependent_txs(1);
+
+    // Given
+    scenario.insert(parent.clone()).await.expect(Ok(()));
+    scenario.advance_time(Duration::from_secs(5)).await;
+    scenario.insert(child.clone()).await.expect(Ok(()));
+
+    // When
+    scenario.advance_time(Duration::from_secs(5)).await;
+
+    // Then
+    scenario.expect_evicted(&parent).expect_evicted(&child);
+}
+
+#[tokio::test(start_paused = true)]
+async fn tx_is_not_expired_before_ttl() {
+    let mut scenario = Scenario::new(Config {
+        transaction_ttl: Duration::from_secs(10),
+        ..Default::default()
+    });
+    let tx = scenario.script_tx(1);
+
+    // Given
+    scenario.insert(tx.clone()).await.expect(Ok(()));
+
+    // When
+    scenario.advance_time(Duration::from_secs(9)).await;
+
+    // Then
+    scenario.expect_pending(&tx);
+}
+
+#[tokio::test]
+async fn immature_tx_is_accepted_at_maturity_height() {
+    let mut scenario = Scenario::default();
+    let coin = scenario.setup_coin();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .maturity(5.into())
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin)
+        .finalize_as_transaction();
+
+    // Given
+    scenario
+        .insert(tx.clone())
+        .await
+        .expect(Err(Error::ConsensusValidity(CheckError::Validity(
+            ValidityError::TransactionMaturity,
+        ))));
+
+    // When
+    scenario.advance_height(5);
+
+    // Then
+    scenario.insert(tx.clone()).await.expect(Ok(()));
+    scenario.expect_pending(&tx);
+}
+
+#[tokio::test]
+async fn full_pool_pushes_out_the_lowest_tip() {
+    let mut scenario = Scenario::new(Config {
+        max_tx: 1,
+        ..Default::default()
+    });
+    let cheap = scenario.script_tx(1);
+    let expensive = scenario.script_tx(2);
+    let cheapest = scenario.script_tx(0);
+
+    // Given
+    scenario.insert(cheap.clone()).await.expect(Ok(()));
+
+    // When
+    scenario.insert(expensive.clone()).await.expect(Ok(()));
+
+    // Then
+    scenario.expect_evicted(&cheap).expect_pending(&expensive);
+    scenario
+        .insert(cheapest)
+        .await
+        .expect(Err(Error::NotInsertedLimitHit));
+}
+
+#[tokio::test]
+async fn tx_spending_coin_missing_from_storage_is_rejected() {
+    let mut scenario = Scenario::default();
+    let coin = scenario.setup_coin();
+    let utxo_id = *coin.utxo_id().unwrap();
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(GAS_LIMIT)
+        .add_input(coin)
+        .finalize_as_transaction();
+
+    // Given
+    scenario
+        .database()
+        .data
+        .lock()
+        .unwrap()
+        .coins
+        .remove(&utxo_id);
+
+    // When
+    let insertion = scenario.insert(tx).await;
+
+    // Then
+    insertion.expect(Err(Error::NotInsertedInputUtxoIdNotDoesNotExist(utxo_id)));
+}
+
+#[tokio::test]
+async fn committed_parent_is_removed_with_child() {
+    let mut scenario = Scenario::default();
+    let (parent, child) = scenario.dependent_txs(1);
+
+    // Given
+    scenario.insert(parent.clone()).await.expect(Ok(()));
+    scenario.insert(child.clone()).await.expect(Ok(()));
+
+    // When
+    scenario.commit(&[&parent]);
+
+    // Then
+    assert_eq!(scenario.pending_number(), 0);
+}
+
+fn owned_script_tx(coin: Input, tip: Word, gas_limit: Word) -> Transaction {
+    TransactionBuilder::script(vec![], vec![])
+        .tip(tip)
+        .max_fee_limit(tip)
+        .script_gas_limit(gas_limit)
+        .add_input(coin)
+        .finalize_as_transaction()
+}
+
+#[tokio::test]
+async fn txs_of_owner_are_selected_in_order_of_ordering_hints() {
+    let mut scenario = Scenario::default();
+    let second = owned_script_tx(scenario.setup_owned_coin(1), 10, GAS_LIMIT);
+    let first = owned_script_tx(scenario.setup_owned_coin(1), 1, GAS_LIMIT);
+    let other = scenario.script_tx(5);
+
+    // Given
+    scenario
+        .insert_with_ordering_hint(second.clone(), Some(2))
+        .await
+        .expect(Ok(()));
+    scenario
+        .insert_with_ordering_hint(first.clone(), Some(1))
+        .await
+        .expect(Ok(()));
+    scenario.insert(other.clone()).await.expect(Ok(()));
+
+    // When
+    let selected = scenario.select(Word::MAX);
+
+    // Then
+    let expected = vec![
+        scenario.tx_id(&first),
+        scenario.tx_id(&other),
+        scenario.tx_id(&second),
+    ];
+    assert_eq!(selected, expected);
+}
+
+#[tokio::test]
+async fn tx_is_not_selected_before_tx_of_owner_with_lower_ordering_hint() {
+    let mut scenario = Scenario::default();
+    let first = owned_script_tx(scenario.setup_owned_coin(1), 1, 100 * GAS_LIMIT);
+    let second = owned_script_tx(scenario.setup_owned_coin(1), 10, GAS_LIMIT);
+    let other = scenario.script_tx(5);
+
+    // Given
+    scenario
+        .insert_with_ordering_hint(first.clone(), Some(1))
+        .await
+        .expect(Ok(()));
+    scenario
+        .insert_with_ordering_hint(second.clone(), Some(2))
+        .await
+        .expect(Ok(()));
+    scenario.insert(other.clone()).await.expect(Ok(()));
+
+    // When
+    let selected = scenario.select(50 * GAS_LIMIT);
+
+    // Then
+    assert_eq!(selected, vec![scenario.tx_id(&other)]);
+    scenario.expect_pending(&first).expect_pending(&second);
+}
+
+#[tokio::test]
+async fn ordering_hint_taken_by_pending_tx_of_owner_is_rejected() {
+    let mut scenario = Scenario::default();
+    let first = owned_script_tx(scenario.setup_owned_coin(1), 1, GAS_LIMIT);
+    let duplicate = owned_script_tx(scenario.setup_owned_coin(1), 2, GAS_LIMIT);
+    let of_other_owner = owned_script_tx(scenario.setup_owned_coin(2), 1, GAS_LIMIT);
+
+    // Given
+    scenario
+        .insert_with_ordering_hint(first.clone(), Some(1))
+        .await
+        .expect(Ok(()));
+
+    // When
+    let first_id = scenario.tx_id(&first);
+    let insertion = scenario.insert_with_ordering_hint(duplicate, Some(1)).await;
+
+    // Then
+    insertion.expect(Err(Error::NotInsertedOrderingHintTaken(first_id, 1)));
+    scenario
+        .insert_with_ordering_hint(of_other_owner, Some(1))
+        .await
+        .expect(Ok(()));
+}
+
+#[tokio::test]
+async fn pending_chain_follows_ordering_hints() {
+    let mut scenario = Scenario::default();
+    let coin = scenario.setup_owned_coin(1);
+    let owner = *coin.input_owner().unwrap();
+    let third = owned_script_tx(coin, 1, GAS_LIMIT);
+    let first = owned_script_tx(scenario.setup_owned_coin(1), 1, GAS_LIMIT);
+    let unordered = owned_script_tx(scenario.setup_owned_coin(1), 1, GAS_LIMIT);
+
+    // Given
+    scenario
+        .insert_with_ordering_hint(third.clone(), Some(7))
+        .await
+        .expect(Ok(()));
+    scenario
+        .insert_with_ordering_hint(first.clone(), Some(3))
+        .await
+        .expect(Ok(()));
+    scenario.insert(unordered).await.expect(Ok(()));
+
+    // When
+    let chain = scenario.pending_chain(&owner);
+
+    // Then
+    assert_eq!(chain, vec![scenario.tx_id(&first), scenario.tx_id(&third)]);
+    scenario.commit(&[&first]);
+    assert_eq!(scenario.pending_chain(&owner), vec![scenario.tx_id(&third)]);
+}
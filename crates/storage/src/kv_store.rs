@@ -106,7 +106,7 @@ pub trait KeyValueStore {
 }
 
 /// The operation to write into the storage.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum WriteOperation {
     /// Insert the value into the storage.
     Insert(Value),
@@ -10,6 +10,7 @@ pub mod block;
 pub mod consensus;
 pub mod header;
 pub mod primitives;
+pub mod rules;
 
 /// Block header and the associated consensus info
 pub type SealedBlockHeader = Sealed<BlockHeader>;
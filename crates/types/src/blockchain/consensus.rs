@@ -81,6 +81,8 @@ pub struct Genesis {
     pub coins_root: Bytes32,
     /// The Binary Merkle Tree root of state, balances, contracts code hash of each contract.
     pub contracts_root: Bytes32,
-    /// The Binary Merkle Tree root of all genesis messages.
+    /// The Binary Merkle Tree root of all genesis messages. If the genesis carries
+    /// the spent messages over the regenesis, the root is hashed together with
+    /// the Binary Merkle Tree root of their nonces.
     pub messages_root: Bytes32,
 }
@@ -1,6 +1,10 @@
 //! Proof of authority
 
-use crate::fuel_crypto::Signature;
+use crate::{
+    blockchain::SealedBlockHeader,
+    fuel_crypto::Signature,
+    fuel_types::BlockHeight,
+};
 
 #[derive(Default, Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -17,3 +21,23 @@ impl PoAConsensus {
         Self { signature }
     }
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(any(test, feature = "test-helpers"), derive(Default))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// The evidence that the authority sealed two different blocks at the same height.
+/// Both headers are signed by the authority, so anyone can verify the proof
+/// without trusting the node that recorded it.
+pub struct EquivocationProof {
+    /// The header known to the node first, usually the one from the local chain.
+    pub first: SealedBlockHeader,
+    /// The conflicting header received later.
+    pub second: SealedBlockHeader,
+}
+
+impl EquivocationProof {
+    /// The height of the conflicting headers.
+    pub fn height(&self) -> BlockHeight {
+        *self.first.entity.height()
+    }
+}
@@ -136,6 +136,12 @@ impl BlockHeader {
         self.recalculate_metadata();
     }
 
+    /// Set the message receipt root for the header
+    pub fn set_message_receipt_root(&mut self, root: Bytes32) {
+        self.application_mut().generated.message_receipt_root = root;
+        self.recalculate_metadata();
+    }
+
     /// Set the DA height for the header
     pub fn set_da_height(&mut self, da_height: DaBlockHeight) {
         self.application_mut().da_height = da_height;
@@ -329,14 +335,112 @@ impl BlockHeader {
 
     /// Validate the transactions match the header.
     pub fn validate_transactions(&self, transactions: &[Transaction]) -> bool {
+        self.mismatched_transactions_field(transactions).is_none()
+    }
+
+    /// Returns the first field of the header that doesn't match the
+    /// values recomputed from the `transactions`.
+    pub fn mismatched_transactions_field(
+        &self,
+        transactions: &[Transaction],
+    ) -> Option<HeaderField> {
+        if transactions.len() as u64 != self.application().transactions_count {
+            return Some(HeaderField::TransactionsCount)
+        }
+
         // Generate the transaction merkle root.
         let transactions_root = generate_txns_root(transactions);
+        if transactions_root != self.application().transactions_root {
+            return Some(HeaderField::TransactionsRoot)
+        }
 
-        transactions_root == self.application().transactions_root
-            && transactions.len() as u64 == self.application().transactions_count
+        None
+    }
+
+    /// Returns the first field that differs from the `other` header.
+    /// The application fields are compared before the consensus fields.
+    pub fn mismatched_field(&self, other: &BlockHeader) -> Option<HeaderField> {
+        let application = self.application();
+        let other_application = other.application();
+        let consensus = self.consensus();
+        let other_consensus = other.consensus();
+
+        let fields = [
+            (
+                HeaderField::DaHeight,
+                application.da_height == other_application.da_height,
+            ),
+            (
+                HeaderField::TransactionsCount,
+                application.transactions_count == other_application.transactions_count,
+            ),
+            (
+                HeaderField::TransactionsRoot,
+                application.transactions_root == other_application.transactions_root,
+            ),
+            (
+                HeaderField::MessageReceiptCount,
+                application.message_receipt_count
+                    == other_application.message_receipt_count,
+            ),
+            (
+                HeaderField::MessageReceiptRoot,
+                application.message_receipt_root
+                    == other_application.message_receipt_root,
+            ),
+            (
+                HeaderField::PrevRoot,
+                consensus.prev_root == other_consensus.prev_root,
+            ),
+            (
+                HeaderField::Height,
+                consensus.height == other_consensus.height,
+            ),
+            (HeaderField::Time, consensus.time == other_consensus.time),
+            (
+                HeaderField::ApplicationHash,
+                consensus.application_hash == other_consensus.application_hash,
+            ),
+        ];
+
+        fields
+            .into_iter()
+            .find_map(|(field, equal)| (!equal).then_some(field))
     }
 }
 
+/// The field of the [`BlockHeader`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, derive_more::Display)]
+pub enum HeaderField {
+    /// The DA height of the block.
+    #[display(fmt = "da_height")]
+    DaHeight,
+    /// The number of transactions.
+    #[display(fmt = "transactions_count")]
+    TransactionsCount,
+    /// The merkle root of transactions.
+    #[display(fmt = "transactions_root")]
+    TransactionsRoot,
+    /// The number of message receipts.
+    #[display(fmt = "message_receipt_count")]
+    MessageReceiptCount,
+    /// The root of the messages sent from the block, also known as the message outbox root.
+    #[display(fmt = "message_receipt_root")]
+    MessageReceiptRoot,
+    /// The merkle root of all previous block header hashes.
+    #[display(fmt = "prev_root")]
+    PrevRoot,
+    /// The height of the block.
+    #[display(fmt = "height")]
+    Height,
+    /// The block producer time.
+    #[display(fmt = "time")]
+    Time,
+    /// The hash of the application header.
+    #[display(fmt = "application_hash")]
+    ApplicationHash,
+}
+
 impl PartialBlockHeader {
     /// Generate all fields to create a full [`BlockHeader`]
     /// after running the transactions.
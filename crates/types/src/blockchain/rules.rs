@@ -0,0 +1,121 @@
+//! The limits on the size and the gas of transactions and blocks.
+//!
+//! The transaction pool, the block production and the block validation use the same
+//! checks, so a transaction admitted into the pool fits into a block, and a produced
+//! block passes the validation on other nodes.
+
+use crate::{
+    fuel_asm::Word,
+    fuel_tx::{
+        field::{
+            Inputs,
+            Outputs,
+            Witnesses,
+        },
+        ConsensusParameters,
+        TxParameters,
+    },
+    fuel_types::canonical::Serialize,
+};
+
+/// The limit violated by a transaction or a block.
+#[allow(missing_docs)]
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum LimitError {
+    #[error("The transaction size {size} exceeds the limit {limit}")]
+    TransactionSizeLimitExceeded { size: u64, limit: u64 },
+    #[error("The transaction has {count} inputs, but the limit is {limit}")]
+    TooManyInputs { count: u64, limit: u64 },
+    #[error("The transaction has {count} outputs, but the limit is {limit}")]
+    TooManyOutputs { count: u64, limit: u64 },
+    #[error("The transaction has {count} witnesses, but the limit is {limit}")]
+    TooManyWitnesses { count: u64, limit: u64 },
+    #[error(
+        "The transaction max gas {tx_gas} exceeds the block gas limit {block_limit}"
+    )]
+    TransactionExceedsBlockGasLimit { tx_gas: Word, block_limit: Word },
+    #[error("The block used {used_gas} gas, but the block gas limit is {block_limit}")]
+    BlockGasLimitExceeded { used_gas: Word, block_limit: Word },
+    #[error("The transaction tip {tip} doesn't pay for its payload, the minimal tip is {required}")]
+    BlobFeeTooLow { tip: Word, required: Word },
+    #[error("The block payloads take {used_bytes} bytes, but the limit is {limit}")]
+    BlockBlobBytesLimitExceeded { used_bytes: u64, limit: u64 },
+}
+
+/// The limits derived from the consensus parameters and the block gas limit of the chain.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Limits {
+    /// The limits on the transaction.
+    pub tx_params: TxParameters,
+    /// The maximum amount of gas used by all transactions of the block.
+    pub block_gas_limit: Word,
+}
+
+impl Limits {
+    /// Creates the limits from the consensus parameters and the block gas limit.
+    pub fn new(consensus_params: &ConsensusParameters, block_gas_limit: Word) -> Self {
+        Self {
+            tx_params: *consensus_params.tx_params(),
+            block_gas_limit,
+        }
+    }
+
+    /// Checks that the transaction with the `max_gas` fits into the limits.
+    pub fn check_transaction<Tx>(&self, tx: &Tx, max_gas: Word) -> Result<(), LimitError>
+    where
+        Tx: Inputs + Outputs + Witnesses + Serialize,
+    {
+        let size = tx.size() as u64;
+        if size > self.tx_params.max_size {
+            return Err(LimitError::TransactionSizeLimitExceeded {
+                size,
+                limit: self.tx_params.max_size,
+            })
+        }
+
+        let inputs = tx.inputs().len() as u64;
+        if inputs > u64::from(self.tx_params.max_inputs) {
+            return Err(LimitError::TooManyInputs {
+                count: inputs,
+                limit: self.tx_params.max_inputs.into(),
+            })
+        }
+
+        let outputs = tx.outputs().len() as u64;
+        if outputs > u64::from(self.tx_params.max_outputs) {
+            return Err(LimitError::TooManyOutputs {
+                count: outputs,
+                limit: self.tx_params.max_outputs.into(),
+            })
+        }
+
+        let witnesses = tx.witnesses().len() as u64;
+        if witnesses > u64::from(self.tx_params.max_witnesses) {
+            return Err(LimitError::TooManyWitnesses {
+                count: witnesses,
+                limit: self.tx_params.max_witnesses.into(),
+            })
+        }
+
+        if max_gas > self.block_gas_limit {
+            return Err(LimitError::TransactionExceedsBlockGasLimit {
+                tx_gas: max_gas,
+                block_limit: self.block_gas_limit,
+            })
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the gas used by the block fits into the block gas limit.
+    pub fn check_block_gas(&self, used_gas: Word) -> Result<(), LimitError> {
+        if used_gas > self.block_gas_limit {
+            return Err(LimitError::BlockGasLimitExceeded {
+                used_gas,
+                block_limit: self.block_gas_limit,
+            })
+        }
+
+        Ok(())
+    }
+}
@@ -3,8 +3,10 @@
 use crate::{
     blockchain::{
         header::BlockHeader,
+        primitives::BlockId,
         SealedBlock,
     },
+    fuel_types::BlockHeight,
     services::{
         executor::{
             Event,
@@ -85,6 +87,31 @@ impl ImportResult {
     }
 }
 
+/// The changes of the UTXO state made by the imported block.
+#[derive(Debug, Clone)]
+pub struct StateDiff {
+    /// The height of the block.
+    pub block_height: BlockHeight,
+    /// The id of the block.
+    pub block_id: BlockId,
+    /// The coins and messages created and consumed by the block.
+    pub events: Vec<Event>,
+}
+
+/// The alias for the `StateDiff` that can be shared between threads.
+pub type SharedStateDiff = Arc<StateDiff>;
+
+impl From<&ImportResult> for StateDiff {
+    fn from(result: &ImportResult) -> Self {
+        let block = &result.sealed_block.entity;
+        Self {
+            block_height: *block.header().height(),
+            block_id: block.id(),
+            events: result.events.clone(),
+        }
+    }
+}
+
 /// The block import info.
 #[derive(Debug, Clone, PartialEq)]
 pub struct BlockImportInfo {
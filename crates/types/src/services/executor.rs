@@ -6,7 +6,9 @@ use crate::{
             Block,
             PartialFuelBlock,
         },
+        header::HeaderField,
         primitives::BlockId,
+        rules::LimitError,
     },
     entities::{
         coins::coin::Coin,
@@ -19,9 +21,11 @@ use crate::{
         ValidityError,
     },
     fuel_types::{
+        AssetId,
         Bytes32,
         ContractId,
         Nonce,
+        Word,
     },
     fuel_vm::{
         checked_transaction::CheckError,
@@ -54,7 +58,7 @@ pub struct ExecutionResult {
 }
 
 /// The event represents some internal state changes caused by the block execution.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Event {
     /// Imported a new spendable message from the relayer.
     MessageImported(Message),
@@ -67,16 +71,29 @@ pub enum Event {
 }
 
 /// The status of a transaction after it is executed.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransactionExecutionStatus {
     /// The id of the transaction.
     pub id: Bytes32,
     /// The result of the executed transaction.
     pub result: TransactionExecutionResult,
+    /// The resources used by the VM to execute the transaction.
+    /// It is `None` if the metering of the VM is disabled.
+    pub vm_metrics: Option<VmMetrics>,
+}
+
+/// The resources used by the VM to execute the transaction.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct VmMetrics {
+    /// The number of the instructions executed by the VM.
+    pub instructions: u64,
+    /// The highest number of bytes of the VM memory used by the stack and the heap.
+    pub memory_watermark: u64,
 }
 
 /// The result of transaction execution.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionExecutionResult {
     /// Transaction was successfully executed.
     Success {
@@ -84,6 +101,8 @@ pub enum TransactionExecutionResult {
         result: Option<ProgramState>,
         /// The receipts generated by the executed transaction.
         receipts: Vec<Receipt>,
+        /// The fee charged for the transaction.
+        fee: FeeBreakdown,
     },
     /// The execution of the transaction failed.
     Failed {
@@ -91,6 +110,8 @@ pub enum TransactionExecutionResult {
         result: Option<ProgramState>,
         /// The receipts generated by the executed transaction.
         receipts: Vec<Receipt>,
+        /// The fee charged for the transaction.
+        fee: FeeBreakdown,
     },
 }
 
@@ -103,6 +124,14 @@ impl TransactionExecutionResult {
         }
     }
 
+    /// Get the fee charged for the executed transaction.
+    pub fn fee(&self) -> &FeeBreakdown {
+        match self {
+            TransactionExecutionResult::Success { fee, .. }
+            | TransactionExecutionResult::Failed { fee, .. } => fee,
+        }
+    }
+
     /// Get the reason of the failed transaction execution.
     pub fn reason(receipts: &[Receipt], state: &Option<ProgramState>) -> String {
         receipts
@@ -118,6 +147,176 @@ impl TransactionExecutionResult {
     }
 }
 
+/// The breakdown of the fee charged for the executed transaction.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FeeBreakdown {
+    /// The total gas used by the transaction, including the intrinsic gas
+    /// charged for the size, inputs and VM initialization.
+    pub gas_used: Word,
+    /// The gas price used to calculate the fee.
+    pub gas_price: Word,
+    /// The tip paid to the block producer.
+    pub tip: Word,
+    /// The fee charged for the used gas, without the tip.
+    pub base_fee: Word,
+    /// The total fee charged for the transaction, `base_fee + tip`.
+    pub total_fee: Word,
+    /// The unused part of the max fee returned to the change outputs.
+    pub refunds: Vec<ChangeRefund>,
+}
+
+/// The refund of the unused fee returned to the change output.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ChangeRefund {
+    /// The index of the change output in the transaction.
+    pub output_index: u16,
+    /// The asset id of the change output.
+    pub asset_id: AssetId,
+    /// The refunded amount.
+    pub amount: Word,
+}
+
+/// The result of the dry run of one transaction from the bundle.
+#[derive(Debug, Clone)]
+pub struct DryRunResult {
+    /// The status of the transaction execution.
+    pub status: TransactionExecutionStatus,
+    /// The changes of the state made by the transaction.
+    pub state_diff: StateDiff,
+    /// The state read or written by the transaction.
+    pub access_list: AccessList,
+    /// The gas spent by the transaction per contract and opcode.
+    /// It is recorded only if the profiling is requested.
+    pub gas_profile: Option<GasProfile>,
+}
+
+/// The gas spent by the VM, aggregated per contract and opcode.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct GasProfile {
+    /// The entries sorted by the contract and the opcode. The script goes first.
+    pub entries: Vec<GasProfileEntry>,
+}
+
+/// The gas spent by the instructions with the same opcode in the same contract.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GasProfileEntry {
+    /// The contract that executed the instructions, or `None` for the script.
+    pub contract_id: Option<ContractId>,
+    /// The name of the opcode, e.g. `CALL`.
+    pub opcode: String,
+    /// The number of the executed instructions.
+    pub count: u64,
+    /// The gas spent by the instructions.
+    pub gas: u64,
+}
+
+/// The state read or written by one transaction. It includes the state accessed
+/// by the reverted transactions.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AccessList {
+    /// The contracts used by the transaction.
+    pub contracts: Vec<ContractId>,
+    /// The storage slots read by the transaction.
+    pub read_slots: Vec<ContractSlot>,
+    /// The storage slots written by the transaction.
+    pub written_slots: Vec<ContractSlot>,
+    /// The balances of the contracts read or written by the transaction.
+    pub contract_balances: Vec<ContractAsset>,
+    /// The coins spent or created by the transaction.
+    pub coins: Vec<UtxoId>,
+    /// The messages spent by the transaction.
+    pub messages: Vec<Nonce>,
+}
+
+/// The storage slot of the contract.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContractSlot {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The key of the slot.
+    pub key: Bytes32,
+}
+
+/// The balance of the contract.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContractAsset {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The asset of the balance.
+    pub asset_id: AssetId,
+}
+
+/// The changes of the state made by one transaction.
+#[derive(Debug, Default, Clone)]
+pub struct StateDiff {
+    /// The coins spent by the transaction.
+    pub consumed_coins: Vec<Coin>,
+    /// The coins created by the transaction.
+    pub created_coins: Vec<Coin>,
+    /// The messages spent by the transaction.
+    pub consumed_messages: Vec<Message>,
+    /// The contracts used by the transaction with their roots after the execution.
+    pub contracts: Vec<ContractStateDiff>,
+}
+
+/// The roots of the contract after the execution of the transaction.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ContractStateDiff {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The root of the contract balances.
+    pub balance_root: Bytes32,
+    /// The root of the contract storage.
+    pub state_root: Bytes32,
+}
+
+/// The changes applied to the state before the dry run. They are visible only to the
+/// transactions of the dry run and are never committed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct StateOverrides {
+    /// The coins inserted into the state. They replace the coins with the same `utxo_id`.
+    pub coins: Vec<Coin>,
+    /// The balances of the contracts.
+    pub contract_balances: Vec<ContractBalanceOverride>,
+    /// The storage slots of the contracts.
+    pub contract_slots: Vec<ContractSlotOverride>,
+    /// The bytecode of the contracts. The contract is created if it doesn't exist.
+    pub contract_code: Vec<ContractCodeOverride>,
+}
+
+/// The balance of the contract set by the dry run.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ContractBalanceOverride {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The asset of the balance.
+    pub asset_id: AssetId,
+    /// The new balance.
+    pub amount: Word,
+}
+
+/// The storage slot of the contract set by the dry run.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ContractSlotOverride {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The key of the slot.
+    pub key: Bytes32,
+    /// The new value of the slot.
+    pub value: Bytes32,
+}
+
+/// The bytecode of the contract set by the dry run.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ContractCodeOverride {
+    /// The id of the contract.
+    pub contract_id: ContractId,
+    /// The new bytecode of the contract.
+    pub bytecode: Vec<u8>,
+}
+
 /// Execution wrapper where the types
 /// depend on the type of execution.
 #[derive(Debug, Clone, Copy)]
@@ -333,6 +532,8 @@ pub enum Error {
     },
     #[display(fmt = "{_0:?}")]
     InvalidTransaction(CheckError),
+    #[from]
+    LimitExceeded(LimitError),
     #[display(fmt = "Execution error with backtrace")]
     Backtrace(Box<Backtrace>),
     #[display(fmt = "Transaction doesn't match expected result: {transaction_id:#x}")]
@@ -341,6 +542,8 @@ pub enum Error {
     InvalidFeeAmount,
     #[display(fmt = "Block id is invalid")]
     InvalidBlockId,
+    #[display(fmt = "The block header field `{_0}` doesn't match the executed block")]
+    BlockHeaderMismatch(HeaderField),
     #[display(fmt = "No matching utxo for contract id ${_0:#x}")]
     ContractUtxoMissing(ContractId),
     #[display(fmt = "message already spent {_0:#x}")]
@@ -355,6 +558,22 @@ pub enum Error {
     PreviousBlockIsNotFound,
     #[display(fmt = "The relayer gives incorrect messages for the requested da height")]
     RelayerGivesIncorrectMessages,
+    #[display(fmt = "The re-execution of the block diverged in the {_0}")]
+    NondeterministicExecution(ExecutionDivergence),
+}
+
+/// The part of the execution result that differs between two executions of the same block.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, derive_more::Display)]
+pub enum ExecutionDivergence {
+    /// The executed block, including the header and the roots of the contract outputs.
+    #[display(fmt = "block")]
+    Block,
+    /// The results and the receipts of the transactions.
+    #[display(fmt = "transactions status")]
+    TransactionsStatus,
+    /// The events generated during the execution.
+    #[display(fmt = "events")]
+    Events,
 }
 
 impl From<Error> for anyhow::Error {
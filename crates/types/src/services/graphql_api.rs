@@ -1,9 +1,16 @@
 //! Types related to GraphQL API service.
 
-use crate::fuel_types::{
-    Address,
-    AssetId,
-    ContractId,
+use crate::{
+    fuel_types::{
+        Address,
+        AssetId,
+        ContractId,
+    },
+    services::txpool,
+};
+use core::{
+    fmt,
+    str::FromStr,
 };
 
 /// The cumulative balance(`amount`) of the `Owner` of `asset_id`.
@@ -21,3 +28,218 @@ pub type AddressBalance = Balance<Address>;
 
 /// The alias for the `Balance` of the contract.
 pub type ContractBalance = Balance<ContractId>;
+
+/// The machine-readable code of the error returned by the API.
+/// The codes are stable between the versions of the node, so the clients
+/// can branch on them instead of parsing the error messages.
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "SCREAMING_SNAKE_CASE")
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// The request is malformed or has invalid arguments.
+    InvalidRequest,
+    /// The requested entity doesn't exist.
+    NotFound,
+    /// The request is not authorized or not allowed by the node.
+    Forbidden,
+    /// The transaction is invalid and can't be included into any block.
+    InvalidTransaction,
+    /// The transaction is already known to the node.
+    TransactionKnown,
+    /// The gas price of the transaction is below the minimal gas price of the node.
+    GasPriceTooLow,
+    /// The transaction conflicts with a higher priced transaction in the pool.
+    Conflict,
+    /// The input of the transaction is already spent.
+    InputSpent,
+    /// The input of the transaction doesn't exist, possibly not yet synced by the node.
+    InputNotFound,
+    /// The transaction exceeds the limits of the pool or the block.
+    LimitExceeded,
+    /// The transaction pool is full.
+    PoolFull,
+    /// The transaction was removed from the pool before the inclusion into a block.
+    Evicted,
+    /// The node can't serve the request at the moment.
+    Unavailable,
+    /// The request took longer than allowed by the node.
+    Timeout,
+    /// An unexpected error of the node.
+    Internal,
+}
+
+/// The category of the [`ErrorCode`].
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "SCREAMING_SNAKE_CASE")
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// The request itself is invalid.
+    Request,
+    /// The transaction is rejected by the node.
+    Transaction,
+    /// The node failed to process the request.
+    Node,
+}
+
+impl ErrorCode {
+    /// All error codes.
+    pub const ALL: [ErrorCode; 15] = [
+        ErrorCode::InvalidRequest,
+        ErrorCode::NotFound,
+        ErrorCode::Forbidden,
+        ErrorCode::InvalidTransaction,
+        ErrorCode::TransactionKnown,
+        ErrorCode::GasPriceTooLow,
+        ErrorCode::Conflict,
+        ErrorCode::InputSpent,
+        ErrorCode::InputNotFound,
+        ErrorCode::LimitExceeded,
+        ErrorCode::PoolFull,
+        ErrorCode::Evicted,
+        ErrorCode::Unavailable,
+        ErrorCode::Timeout,
+        ErrorCode::Internal,
+    ];
+
+    /// Returns the string representation of the code used by the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::InvalidRequest => "INVALID_REQUEST",
+            ErrorCode::NotFound => "NOT_FOUND",
+            ErrorCode::Forbidden => "FORBIDDEN",
+            ErrorCode::InvalidTransaction => "INVALID_TRANSACTION",
+            ErrorCode::TransactionKnown => "TRANSACTION_KNOWN",
+            ErrorCode::GasPriceTooLow => "GAS_PRICE_TOO_LOW",
+            ErrorCode::Conflict => "CONFLICT",
+            ErrorCode::InputSpent => "INPUT_SPENT",
+            ErrorCode::InputNotFound => "INPUT_NOT_FOUND",
+            ErrorCode::LimitExceeded => "LIMIT_EXCEEDED",
+            ErrorCode::PoolFull => "POOL_FULL",
+            ErrorCode::Evicted => "EVICTED",
+            ErrorCode::Unavailable => "UNAVAILABLE",
+            ErrorCode::Timeout => "TIMEOUT",
+            ErrorCode::Internal => "INTERNAL",
+        }
+    }
+
+    /// Returns the category of the code.
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            ErrorCode::InvalidRequest | ErrorCode::NotFound | ErrorCode::Forbidden => {
+                ErrorCategory::Request
+            }
+            ErrorCode::InvalidTransaction
+            | ErrorCode::TransactionKnown
+            | ErrorCode::GasPriceTooLow
+            | ErrorCode::Conflict
+            | ErrorCode::InputSpent
+            | ErrorCode::InputNotFound
+            | ErrorCode::LimitExceeded
+            | ErrorCode::Evicted => ErrorCategory::Transaction,
+            ErrorCode::PoolFull
+            | ErrorCode::Unavailable
+            | ErrorCode::Timeout
+            | ErrorCode::Internal => ErrorCategory::Node,
+        }
+    }
+
+    /// Returns `true` if the same request may succeed later without changes.
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            ErrorCode::InputNotFound
+                | ErrorCode::PoolFull
+                | ErrorCode::Evicted
+                | ErrorCode::Unavailable
+                | ErrorCode::Timeout
+        )
+    }
+}
+
+impl ErrorCategory {
+    /// Returns the string representation of the category used by the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCategory::Request => "REQUEST",
+            ErrorCategory::Transaction => "TRANSACTION",
+            ErrorCategory::Node => "NODE",
+        }
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Display for ErrorCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The error of parsing an unknown [`ErrorCode`].
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Unknown error code {0}")]
+pub struct UnknownErrorCode(pub String);
+
+impl FromStr for ErrorCode {
+    type Err = UnknownErrorCode;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ErrorCode::ALL
+            .into_iter()
+            .find(|code| code.as_str() == s)
+            .ok_or_else(|| UnknownErrorCode(s.to_string()))
+    }
+}
+
+impl From<&txpool::Error> for ErrorCode {
+    fn from(error: &txpool::Error) -> Self {
+        use txpool::Error;
+        match error {
+            Error::NotSupportedTransactionType
+            | Error::NotInsertedIoCoinMismatch
+            | Error::NotInsertedIoWrongOwner
+            | Error::NotInsertedIoWrongAmount
+            | Error::NotInsertedIoWrongAssetId
+            | Error::NotInsertedIoMessageMismatch
+            | Error::NotInsertedIoContractOutput
+            | Error::NotInsertedInvalidMultisig(_)
+            | Error::ConsensusValidity(_)
+            | Error::MintIsDisallowed => ErrorCode::InvalidTransaction,
+            Error::NotInsertedTxKnown => ErrorCode::TransactionKnown,
+            Error::NotInsertedLimitHit => ErrorCode::PoolFull,
+            Error::NotInsertedGasPriceTooLow => ErrorCode::GasPriceTooLow,
+            Error::NotInsertedCollision(_, _)
+            | Error::NotInsertedCollisionContractId(_)
+            | Error::NotInsertedCollisionMessageId(_, _)
+            | Error::NotInsertedContractIdAlreadyTaken(_)
+            | Error::NotInsertedOrderingHintTaken(_, _)
+            | Error::NotInsertedContractPricedLower(_) => ErrorCode::Conflict,
+            Error::NotInsertedOutputDoesNotExist(_)
+            | Error::NotInsertedInputContractDoesNotExist(_)
+            | Error::NotInsertedInputUtxoIdNotDoesNotExist(_)
+            | Error::NotInsertedInputMessageUnknown(_) => ErrorCode::InputNotFound,
+            Error::NotInsertedInputUtxoIdSpent(_)
+            | Error::NotInsertedInputMessageSpent(_) => ErrorCode::InputSpent,
+            Error::NotInsertedMaxDepth | Error::NotInsertedLimitExceeded(_) => {
+                ErrorCode::LimitExceeded
+            }
+            Error::Removed | Error::TTLReason | Error::SqueezedOut(_) => {
+                ErrorCode::Evicted
+            }
+            Error::NoMetadata | Error::Database(_) | Error::Other(_) => {
+                ErrorCode::Internal
+            }
+        }
+    }
+}
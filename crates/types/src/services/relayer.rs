@@ -3,6 +3,7 @@
 use crate::{
     blockchain::primitives::DaBlockHeight,
     entities::message::Message,
+    fuel_types::Bytes32,
 };
 
 /// The event that may come from the relayer.
@@ -27,3 +28,13 @@ impl From<Message> for Event {
         Event::Message(message)
     }
 }
+
+/// The origin of the message on the DA layer.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MessageOrigin {
+    /// The height of the DA block with the transaction that sent the message.
+    pub da_height: DaBlockHeight,
+    /// The hash of the DA transaction that sent the message.
+    pub transaction_hash: Bytes32,
+}
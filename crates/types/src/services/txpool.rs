@@ -1,7 +1,13 @@
 //! Types for interoperability with the txpool service
 
 use crate::{
-    blockchain::block::Block,
+    blockchain::{
+        block::Block,
+        rules::{
+            LimitError,
+            Limits,
+        },
+    },
     fuel_asm::Word,
     fuel_tx::{
         field::{
@@ -83,6 +89,18 @@ impl PoolTransaction {
             PoolTransaction::Create(create) => create.metadata().max_gas,
         }
     }
+
+    /// Checks that the transaction fits into the limits of the transaction and the block.
+    pub fn check_limits(&self, limits: &Limits) -> core::result::Result<(), LimitError> {
+        match self {
+            PoolTransaction::Script(script) => {
+                limits.check_transaction(script.transaction(), self.max_gas())
+            }
+            PoolTransaction::Create(create) => {
+                limits.check_transaction(create.transaction(), self.max_gas())
+            }
+        }
+    }
 }
 
 #[allow(missing_docs)]
@@ -171,6 +189,35 @@ pub struct InsertionResult {
     pub removed: Vec<ArcPoolTx>,
 }
 
+/// The reason why the transaction was evicted from the txpool without
+/// the inclusion into the block.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EvictionReason {
+    /// Replaced by the conflicting transaction with a higher tip that spends the same inputs.
+    Conflict,
+    /// Exceeded the time to live in the txpool.
+    Expired,
+    /// Pushed out by the transaction with a higher tip because the txpool is full.
+    LowFee,
+    /// Became invalid against the latest state of the chain.
+    Invalid,
+}
+
+/// The transaction evicted from the txpool. The dependent transactions are
+/// evicted with the reason of their parent.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EvictedTransaction {
+    /// The id of the evicted transaction.
+    pub tx_id: TxId,
+    /// The reason of the eviction.
+    pub reason: EvictionReason,
+    /// The human-readable description of the eviction,
+    /// the same as in the `SqueezedOut` status.
+    pub message: String,
+}
+
 /// The status of the transaction during its life from the tx pool until the block.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -217,22 +264,22 @@ pub fn from_executor_to_status(
     let time = block.header().time();
     let block_height = *block.header().height();
     match result {
-        TransactionExecutionResult::Success { result, receipts } => {
-            TransactionStatus::Success {
-                block_height,
-                time,
-                result,
-                receipts,
-            }
-        }
-        TransactionExecutionResult::Failed { result, receipts } => {
-            TransactionStatus::Failed {
-                block_height,
-                time,
-                result,
-                receipts,
-            }
-        }
+        TransactionExecutionResult::Success {
+            result, receipts, ..
+        } => TransactionStatus::Success {
+            block_height,
+            time,
+            result,
+            receipts,
+        },
+        TransactionExecutionResult::Failed {
+            result, receipts, ..
+        } => TransactionStatus::Failed {
+            block_height,
+            time,
+            result,
+            receipts,
+        },
     }
 }
 
@@ -296,10 +343,16 @@ pub enum Error {
         "Transaction is not inserted. Input output mismatch. Expected coin but output is contract"
     )]
     NotInsertedIoContractOutput,
+    #[error(
+        "Transaction is not inserted. The tx {0:#x} of the same owner already has the ordering hint {1}"
+    )]
+    NotInsertedOrderingHintTaken(TxId, u64),
     #[error("Transaction is not inserted. Maximum depth of dependent transaction chain reached")]
     NotInsertedMaxDepth,
-    #[error("Transaction exceeds the max gas per block limit. Tx gas: {tx_gas}, block limit {block_limit}")]
-    NotInsertedMaxGasLimit { tx_gas: Word, block_limit: Word },
+    #[error("Transaction is not inserted. {0}")]
+    NotInsertedLimitExceeded(LimitError),
+    #[error("Transaction is not inserted. The multisig input is invalid: {0}")]
+    NotInsertedInvalidMultisig(String),
     // small todo for now it can pass but in future we should include better messages
     #[error("Transaction removed.")]
     Removed,
@@ -323,3 +376,9 @@ impl From<CheckError> for Error {
         Error::ConsensusValidity(e)
     }
 }
+
+impl From<LimitError> for Error {
+    fn from(e: LimitError) -> Self {
+        Error::NotInsertedLimitExceeded(e)
+    }
+}
@@ -1,9 +1,9 @@
 use fuel_core::{
     chain_config::{
         ChainConfig,
+        ChainConfigBuilder,
         CoinConfig,
         ContractConfig,
-        StateConfig,
     },
     service::{
         Config,
@@ -25,7 +25,6 @@ use fuel_core_types::{
     },
     fuel_types::BlockHeight,
 };
-use itertools::Itertools;
 use rand::{
     rngs::StdRng,
     Rng,
@@ -180,17 +179,22 @@ impl TestSetupBuilder {
 
     // setup chainspec and spin up a fuel-node
     pub async fn finalize(&mut self) -> TestContext {
-        let mut chain_config = ChainConfig {
-            initial_state: Some(StateConfig {
-                coins: Some(self.initial_coins.clone()),
-                contracts: Some(self.contracts.values().cloned().collect_vec()),
-                height: self.starting_block,
-                ..StateConfig::default()
-            }),
+        let mut builder = ChainConfigBuilder::from_config(ChainConfig {
+            initial_state: None,
             ..ChainConfig::local_testnet()
-        };
-        chain_config.consensus_parameters.tx_params.max_gas_per_tx = self.gas_limit;
-        chain_config.block_gas_limit = self.gas_limit;
+        })
+        .with_block_gas_limit(self.gas_limit)
+        .with_consensus_param(|params| params.tx_params.max_gas_per_tx = self.gas_limit);
+        if let Some(height) = self.starting_block {
+            builder = builder.with_height(height);
+        }
+        for coin in &self.initial_coins {
+            builder = builder.with_coin_config(coin.clone());
+        }
+        for contract in self.contracts.values() {
+            builder = builder.with_contract_config(contract.clone());
+        }
+        let chain_config = builder.build();
         let config = Config {
             utxo_validation: self.utxo_validation,
             txpool: fuel_core_txpool::Config {
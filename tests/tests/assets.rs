@@ -0,0 +1,154 @@
+#![allow(non_snake_case)]
+
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
+use fuel_core_client::client::types::TransactionStatus;
+use fuel_core_types::{
+    fuel_asm::{
+        op,
+        GTFArgs,
+        RegId,
+    },
+    fuel_tx::{
+        AssetId,
+        Bytes32,
+        ContractIdExt,
+        Input,
+        Output,
+        TransactionBuilder,
+        TxPointer,
+        UtxoId,
+    },
+    fuel_types::{
+        bytes::WORD_SIZE,
+        canonical::Serialize,
+        ContractId,
+        Word,
+    },
+    fuel_vm::CallFrame,
+};
+
+const SEED: u64 = 2322;
+
+/// The contract mints the amount from the first parameter of the call
+/// and burns the amount from the second, both with the zero sub id.
+fn mint_and_burn_contract() -> Vec<u8> {
+    [
+        // Allocate 32 zeroed bytes for the sub id
+        op::movi(0x10, Bytes32::LEN as u32),
+        op::aloc(0x10),
+        // Load the call parameters
+        op::lw(0x11, RegId::FP, (CallFrame::a_offset() / WORD_SIZE) as u16),
+        op::lw(0x12, RegId::FP, (CallFrame::b_offset() / WORD_SIZE) as u16),
+        op::mint(0x11, RegId::HP),
+        op::burn(0x12, RegId::HP),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect()
+}
+
+async fn mint_and_burn(
+    ctx: &TestContext,
+    contract_id: ContractId,
+    mint: Word,
+    burn: Word,
+) -> TransactionStatus {
+    let script = [
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::call(0x10, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ]
+    .into_iter()
+    .collect();
+    let script_data = contract_id
+        .to_bytes()
+        .into_iter()
+        .chain(mint.to_be_bytes())
+        .chain(burn.to_be_bytes())
+        .collect();
+
+    let tx = TransactionBuilder::script(script, script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            UtxoId::default(),
+            Bytes32::zeroed(),
+            Bytes32::zeroed(),
+            TxPointer::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Bytes32::zeroed(), Bytes32::zeroed()))
+        .finalize_as_transaction();
+
+    ctx.client.submit_and_await_commit(&tx).await.unwrap()
+}
+
+#[tokio::test]
+async fn asset_info__returns_minted_and_burned_totals() {
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    test_builder.utxo_validation = false;
+    let (_, contract_id) =
+        test_builder.setup_contract(mint_and_burn_contract(), None, None, None);
+    let ctx = test_builder.finalize().await;
+    let asset_id = contract_id.asset_id(&Bytes32::zeroed());
+
+    // When
+    let status = mint_and_burn(&ctx, contract_id, 100, 30).await;
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    let status = mint_and_burn(&ctx, contract_id, 50, 70).await;
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    // Then
+    let info = ctx
+        .client
+        .asset_info(&asset_id)
+        .await
+        .unwrap()
+        .expect("The asset should be indexed");
+    assert_eq!(info.asset_id, asset_id);
+    assert_eq!(info.contract_id, contract_id);
+    assert_eq!(info.sub_id, Bytes32::zeroed());
+    assert_eq!(info.minted, 150);
+    assert_eq!(info.burned, 100);
+    assert_eq!(info.total_supply, 50);
+}
+
+#[tokio::test]
+async fn asset_info__ignores_reverted_transactions() {
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    test_builder.utxo_validation = false;
+    let (_, contract_id) =
+        test_builder.setup_contract(mint_and_burn_contract(), None, None, None);
+    let ctx = test_builder.finalize().await;
+    let asset_id = contract_id.asset_id(&Bytes32::zeroed());
+
+    // When
+    let status = mint_and_burn(&ctx, contract_id, 100, 30).await;
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    // The contract can't burn more than its balance
+    let status = mint_and_burn(&ctx, contract_id, 0, 1000).await;
+    assert!(matches!(status, TransactionStatus::Failure { .. }));
+
+    // Then
+    let info = ctx.client.asset_info(&asset_id).await.unwrap().unwrap();
+    assert_eq!(info.minted, 100);
+    assert_eq!(info.burned, 30);
+    assert_eq!(info.total_supply, 70);
+}
+
+#[tokio::test]
+async fn asset_info__returns_none_for_unknown_asset() {
+    // Given
+    let ctx = TestSetupBuilder::new(SEED).finalize().await;
+
+    // When
+    let info = ctx.client.asset_info(&AssetId::BASE).await.unwrap();
+
+    // Then
+    assert!(info.is_none());
+}
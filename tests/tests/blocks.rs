@@ -10,7 +10,10 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        Consensus as ClientConsensus,
+        TransactionStatus,
+    },
     FuelClient,
 };
 use fuel_core_poa::Trigger;
@@ -191,6 +194,68 @@ async fn produce_block_custom_time() {
     assert_eq!(db.block_time(&5u32.into()).unwrap().0, start_timestamp + 40);
 }
 
+#[tokio::test]
+async fn produce_block_custom_interval() {
+    let db = Database::default();
+
+    let config = Config::local_node();
+
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+
+    let client = FuelClient::from(srv.bound_address);
+    let start_timestamp = Tai64::UNIX_EPOCH.0 + 100u64;
+    let new_height = client
+        .produce_blocks_with_interval(3, Some(start_timestamp), Some(1000))
+        .await
+        .unwrap();
+
+    assert_eq!(3, *new_height);
+
+    assert_eq!(db.block_time(&1u32.into()).unwrap().0, start_timestamp);
+    assert_eq!(
+        db.block_time(&2u32.into()).unwrap().0,
+        start_timestamp + 1000
+    );
+    assert_eq!(
+        db.block_time(&3u32.into()).unwrap().0,
+        start_timestamp + 2000
+    );
+}
+
+#[tokio::test]
+async fn produce_block_with_overridden_next_block_timestamp() {
+    let db = Database::default();
+
+    let config = Config::local_node();
+
+    let srv = FuelService::from_database(db.clone(), config)
+        .await
+        .unwrap();
+
+    let client = FuelClient::from(srv.bound_address);
+    let next_timestamp = Tai64::now().0 + 1_000_000u64;
+    client
+        .set_next_block_timestamp(next_timestamp)
+        .await
+        .unwrap();
+    let new_height = client.produce_blocks(2, None).await.unwrap();
+
+    assert_eq!(2, *new_height);
+    assert_eq!(db.block_time(&1u32.into()).unwrap().0, next_timestamp);
+    assert!(db.block_time(&2u32.into()).unwrap().0 >= next_timestamp);
+
+    // the timestamp can't go back in time
+    let err = client
+        .set_next_block_timestamp(next_timestamp - 1)
+        .await
+        .expect_err("Should fail with a lower timestamp");
+    assert!(err
+        .to_string()
+        .contains("can't be lower than the latest block timestamp"));
+}
+
 #[tokio::test]
 async fn produce_block_bad_start_time() {
     let db = Database::default();
@@ -297,6 +362,95 @@ async fn block_connection_5(
     };
 }
 
+#[tokio::test]
+async fn block_commitments_match_blocks_in_the_height_range() {
+    let srv = FuelService::from_database(Default::default(), Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    client.produce_blocks(9, None).await.unwrap();
+
+    // request the heights from 3 to 6
+    let commitments = client
+        .block_commitments(PaginationRequest {
+            cursor: Some("2".to_string()),
+            results: 4,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(
+        commitments.results.iter().map(|c| c.height).collect_vec(),
+        (3..7).collect_vec()
+    );
+    assert!(commitments.has_next_page);
+    for commitment in commitments.results {
+        let block = client
+            .block_by_height(commitment.height.into())
+            .await
+            .unwrap()
+            .unwrap();
+        let compressed = client
+            .da_compressed_block(commitment.height.into())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(commitment.block_id, block.id);
+        assert_eq!(
+            commitment.message_receipt_root,
+            block.header.message_receipt_root
+        );
+        assert_eq!(
+            commitment.da_compressed_block_digest,
+            fuel_core_types::fuel_crypto::Hasher::hash(compressed)
+        );
+        assert!(matches!(
+            commitment.consensus,
+            ClientConsensus::PoAConsensus(_)
+        ));
+    }
+}
+
+#[tokio::test]
+async fn chain_statistics_aggregate_the_blocks_in_the_range() {
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+    let charlie = Address::from([3; 32]);
+
+    // Given
+    let mut context = crate::helpers::TestContext::new(100).await;
+    context.transfer(alice, bob, 1).await.unwrap();
+    context.transfer(bob, charlie, 2).await.unwrap();
+    context.transfer(charlie, alice, 3).await.unwrap();
+    let client = context.client;
+
+    // When
+    let all = client
+        .chain_statistics(1u32.into(), 3u32.into())
+        .await
+        .unwrap()
+        .unwrap();
+    let last_two = client
+        .chain_statistics(2u32.into(), 3u32.into())
+        .await
+        .unwrap()
+        .unwrap();
+    let not_indexed = client
+        .chain_statistics(2u32.into(), 10u32.into())
+        .await
+        .unwrap();
+
+    // Then
+    // Each block contains the transfer and the `Mint` transaction.
+    assert_eq!(all.tx_count, 6);
+    assert_eq!(all.new_addresses, 3);
+    assert_eq!(last_two.tx_count, 4);
+    assert_eq!(last_two.new_addresses, 1);
+    assert_eq!(last_two.total_addresses, 3);
+    assert!(not_indexed.is_none());
+}
+
 mod full_block {
     use super::*;
     use cynic::QueryBuilder;
@@ -0,0 +1,39 @@
+use fuel_core::p2p_test_helpers::chaos::{
+    Cluster,
+    PRODUCER,
+};
+use std::time::Duration;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn validators_catch_up_after_partition_heals() {
+    // Given
+    let cluster = Cluster::new(line!() as u64, 3).await;
+    cluster.produce_blocks(3).await;
+    cluster.eventually_consistent(Duration::from_secs(20)).await;
+
+    // When
+    cluster.partition(&[&[PRODUCER, "validator:0"], &["validator:1", "validator:2"]]);
+    // The existing connections are closed by the periodic check
+    tokio::time::sleep(Duration::from_millis(500)).await;
+    cluster.produce_blocks(5).await;
+    let tips = cluster.tips();
+    assert_ne!(tips[PRODUCER], tips["validator:1"]);
+    cluster.heal();
+
+    // Then
+    cluster.eventually_consistent(Duration::from_secs(30)).await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn validators_sync_with_latency_and_message_loss() {
+    // Given
+    let cluster = Cluster::new(line!() as u64, 2).await;
+    cluster.network.set_latency(Duration::from_millis(50));
+    cluster.network.set_loss(0.2);
+
+    // When
+    cluster.produce_blocks(10).await;
+
+    // Then
+    cluster.eventually_consistent(Duration::from_secs(30)).await;
+}
@@ -6,6 +6,7 @@ use fuel_core::{
         StateConfig,
     },
     coins_query::CoinsQueryError,
+    fuel_core_graphql_api::AdminToken,
     service::{
         Config,
         FuelService,
@@ -20,6 +21,7 @@ use rand::{
     prelude::StdRng,
     SeedableRng,
 };
+use std::time::Duration;
 
 mod coin {
     use super::*;
@@ -59,6 +61,7 @@ mod coin {
                 .collect(),
             ),
             messages: None,
+            ..Default::default()
         });
 
         // setup server & client
@@ -311,6 +314,7 @@ mod message_coin {
                     })
                     .collect(),
             ),
+            ..Default::default()
         });
 
         // setup server & client
@@ -530,6 +534,7 @@ mod all_coins {
                     })
                     .collect(),
             ),
+            ..Default::default()
         });
 
         // setup server & client
@@ -708,6 +713,7 @@ async fn empty_setup() -> TestContext {
         contracts: None,
         coins: None,
         messages: None,
+        ..Default::default()
     });
 
     // setup server & client
@@ -767,6 +773,119 @@ async fn coins_to_spend_error_duplicate_asset_query(
     );
 }
 
+#[tokio::test]
+async fn coins_to_spend_skips_coins_locked_by_other_leases() {
+    // Given
+    let owner = Address::from([7; 32]);
+    let asset_id = AssetId::new([1u8; 32]);
+    let mut config = Config::local_node();
+    config.admin_token = Some(AdminToken::new("secret"));
+    config.api_coin_lease_max_ttl = Some(Duration::from_secs(60));
+    config.chain_conf.initial_state = Some(StateConfig {
+        coins: Some(
+            [50, 100]
+                .into_iter()
+                .map(|amount| CoinConfig {
+                    tx_id: None,
+                    output_index: None,
+                    tx_pointer_block_height: None,
+                    tx_pointer_tx_idx: None,
+                    owner,
+                    amount,
+                    asset_id,
+                })
+                .collect(),
+        ),
+        ..Default::default()
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address).with_admin_token("secret");
+    let coins = client
+        .coins_to_spend(&owner, vec![(asset_id, 150, None)], None)
+        .await
+        .unwrap();
+    let utxos = coins[0]
+        .iter()
+        .filter_map(|coin| match coin {
+            CoinType::Coin(coin) => Some(coin.utxo_id),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    let other_owner = Address::from([8; 32]);
+
+    // When
+    let by_other_owner = client
+        .lock_coins(&other_owner, utxos.clone(), vec![], 30)
+        .await;
+    let lease_id = client.lock_coins(&owner, utxos, vec![], 30).await.unwrap();
+
+    // Then
+    assert!(by_other_owner.is_err());
+    let for_others = client
+        .coins_to_spend(&owner, vec![(asset_id, 1, None)], None)
+        .await;
+    assert!(for_others.is_err());
+    let for_holder = client
+        .coins_to_spend_with_lease(
+            &owner,
+            vec![(asset_id, 150, None)],
+            None,
+            Some(lease_id),
+        )
+        .await
+        .unwrap();
+    assert_eq!(for_holder[0].amount(), 150);
+    assert!(client.unlock_coins(&lease_id).await.unwrap());
+    let after_unlock = client
+        .coins_to_spend(&owner, vec![(asset_id, 1, None)], None)
+        .await;
+    assert!(after_unlock.is_ok());
+}
+
+#[tokio::test]
+async fn lock_coins_requires_admin_token() {
+    // Given
+    let owner = Address::from([7; 32]);
+    let mut config = Config::local_node();
+    config.admin_token = Some(AdminToken::new("secret"));
+    config.api_coin_lease_max_ttl = Some(Duration::from_secs(60));
+    config.chain_conf.initial_state = Some(StateConfig {
+        coins: Some(vec![CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            owner,
+            amount: 100,
+            asset_id: AssetId::BASE,
+        }]),
+        ..Default::default()
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let third_party = FuelClient::from(srv.bound_address);
+    let coins = third_party
+        .coins_to_spend(&owner, vec![(AssetId::BASE, 100, None)], None)
+        .await
+        .unwrap();
+    let utxos = coins[0]
+        .iter()
+        .filter_map(|coin| match coin {
+            CoinType::Coin(coin) => Some(coin.utxo_id),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // When
+    let result = third_party.lock_coins(&owner, utxos, vec![], 30).await;
+
+    // Then
+    assert!(result.is_err());
+    let coins = third_party
+        .coins_to_spend(&owner, vec![(AssetId::BASE, 100, None)], None)
+        .await;
+    assert!(coins.is_ok());
+}
+
 trait ToStdErrorString {
     fn to_str_error_string(self) -> String;
 }
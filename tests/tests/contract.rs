@@ -7,6 +7,7 @@ use fuel_core::{
         database_description::on_chain::OnChain,
         Database,
     },
+    fuel_core_graphql_api::AdminToken,
     service::{
         Config,
         FuelService,
@@ -412,3 +413,116 @@ async fn can_get_message_proof() {
     assert_eq!(log[1].rb().unwrap(), 1);
     assert_eq!(logd.data().unwrap(), db_data);
 }
+
+fn contract_create_tx(bytecode: Witness, salt: Salt) -> (ContractId, Transaction) {
+    let contract = Contract::from(bytecode.as_ref());
+    let state_root = Contract::default_state_root();
+    let contract_id = contract.id(&salt, &contract.root(), &state_root);
+    let create_tx = TransactionBuilder::create(bytecode, salt, vec![])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+    (contract_id, create_tx)
+}
+
+#[tokio::test]
+async fn contract_deployment_is_indexed() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let bytecode: Witness = op::ret(RegId::ONE).to_bytes().to_vec().into();
+    let salt = Salt::new([7; 32]);
+    let (contract_id, create_tx) = contract_create_tx(bytecode.clone(), salt);
+
+    // When
+    let status = client.submit_and_await_commit(&create_tx).await.unwrap();
+
+    // Then
+    let TransactionStatus::Success { block_height, .. } = status else {
+        panic!("The deployment should succeed")
+    };
+    let deployment = client
+        .contract_deployment(&contract_id)
+        .await
+        .unwrap()
+        .expect("The deployment should be indexed");
+    assert_eq!(deployment.transaction_id, create_tx.id(&Default::default()));
+    assert_eq!(deployment.block_height, block_height);
+    assert_eq!(
+        deployment.bytecode_hash,
+        fuel_core_types::fuel_crypto::Hasher::hash(bytecode.as_ref())
+    );
+    assert_eq!(deployment.salt, salt);
+}
+
+#[tokio::test]
+async fn contract_deployment_is_none_for_genesis_contract() {
+    // Given
+    let mut test_builder = TestSetupBuilder::new(SEED);
+    let (_, contract_id) = test_builder.setup_contract(vec![], None, None, None);
+    let TestContext {
+        client,
+        srv: _dont_drop,
+        ..
+    } = test_builder.finalize().await;
+
+    // When
+    let deployment = client.contract_deployment(&contract_id).await.unwrap();
+
+    // Then
+    assert_eq!(deployment, None);
+}
+
+#[tokio::test]
+async fn contract_verification_can_be_set_by_admin() {
+    // Given
+    let mut config = Config::local_node();
+    config.admin_token = Some(AdminToken::new("secret"));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let admin = FuelClient::from(srv.bound_address).with_admin_token("secret");
+    let (contract_id, create_tx) = contract_create_tx(
+        op::ret(RegId::ONE).to_bytes().to_vec().into(),
+        Salt::zeroed(),
+    );
+    client.submit_and_await_commit(&create_tx).await.unwrap();
+    let metadata = br#"{"compiler":"forc"}"#.to_vec();
+
+    // When
+    let unauthorized = client
+        .set_contract_verification(&contract_id, metadata.clone())
+        .await;
+    admin
+        .set_contract_verification(&contract_id, metadata.clone())
+        .await
+        .unwrap();
+
+    // Then
+    assert!(unauthorized.is_err());
+    assert_eq!(
+        client.contract_verification(&contract_id).await.unwrap(),
+        Some(metadata)
+    );
+}
+
+#[tokio::test]
+async fn contract_verification_is_rejected_for_unknown_contract() {
+    // Given
+    let mut config = Config::local_node();
+    config.admin_token = Some(AdminToken::new("secret"));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let admin = FuelClient::from(srv.bound_address).with_admin_token("secret");
+    let contract_id = ContractId::new([3; 32]);
+
+    // When
+    let result = admin
+        .set_contract_verification(&contract_id, vec![1, 2, 3])
+        .await;
+
+    // Then
+    assert!(result.is_err());
+    assert_eq!(
+        admin.contract_verification(&contract_id).await.unwrap(),
+        None
+    );
+}
@@ -0,0 +1,57 @@
+use fuel_core::service::{
+    Config,
+    FuelService,
+};
+use fuel_core_client::client::{
+    types::TransactionStatus,
+    FuelClient,
+};
+use fuel_core_compression::ports::TransactionLookup;
+use fuel_core_types::fuel_tx::{
+    Transaction,
+    TxId,
+    TxPointer,
+};
+
+/// The transactions of the test spend only coins from the genesis,
+/// so the compressed blocks don't reference the previous blocks.
+struct NoTransactions;
+
+impl TransactionLookup for NoTransactions {
+    fn transaction_id(&self, _: &TxPointer) -> anyhow::Result<Option<TxId>> {
+        Ok(None)
+    }
+}
+
+#[tokio::test]
+async fn da_compressed_block_decompresses_into_the_same_block() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let tx = Transaction::default_test_tx();
+    let status = client.submit_and_await_commit(&tx).await.unwrap();
+    let TransactionStatus::Success { block_height, .. } = status else {
+        panic!("Wrong tx status");
+    };
+
+    let bytes = client
+        .da_compressed_block(block_height)
+        .await
+        .unwrap()
+        .expect("The block should exist");
+    let block = fuel_core_compression::decompress(&bytes, &NoTransactions).unwrap();
+
+    let expected = client.block_by_height(block_height).await.unwrap().unwrap();
+    assert_eq!(block.id(), expected.id.into());
+    assert_eq!(block.transactions().len(), expected.transactions.len());
+}
+
+#[tokio::test]
+async fn da_compressed_block_is_none_for_unknown_height() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let bytes = client.da_compressed_block(100u32.into()).await.unwrap();
+
+    assert_eq!(bytes, None);
+}
@@ -320,3 +320,30 @@ async fn missing_variable_output() {
     let asset_balance = ctx.client.balance(&ctx.address, None).await.unwrap();
     assert_eq!(asset_balance, 0);
 }
+
+#[tokio::test]
+async fn block_fees_report_tips_minted_to_coinbase() {
+    let rng = &mut StdRng::seed_from_u64(0);
+
+    let ctx = setup(rng).await;
+    make_block_with_fee(rng, &ctx).await;
+
+    let height = ctx
+        .client
+        .chain_info()
+        .await
+        .unwrap()
+        .latest_block
+        .header
+        .height;
+    let fees = ctx
+        .client
+        .block_fees(height.into())
+        .await
+        .unwrap()
+        .expect("The fees of the block should be indexed");
+
+    assert_eq!(fees.tips, TIP);
+    assert_eq!(fees.coinbase, fees.tips + fees.base_fees);
+    assert_eq!(fees.burned, 0);
+}
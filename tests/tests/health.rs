@@ -1,5 +1,6 @@
 use fuel_core::{
     database::Database,
+    fuel_core_graphql_api::AdminToken,
     service::{
         Config,
         FuelService,
@@ -41,3 +42,247 @@ async fn can_restart_node() {
             .unwrap();
     }
 }
+
+#[tokio::test]
+async fn graphql_server_allows_only_configured_cors_origins() {
+    // Given
+    let mut config = Config::local_node();
+    config.graphql_http.cors_origins = vec!["https://allowed.example".to_string()];
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let url = format!("http://{}/v1/health", srv.bound_address);
+    let client = reqwest::Client::new();
+
+    // When
+    let allowed = client
+        .get(&url)
+        .header("Origin", "https://allowed.example")
+        .send()
+        .await
+        .unwrap();
+    let denied = client
+        .get(&url)
+        .header("Origin", "https://denied.example")
+        .send()
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(
+        allowed.headers()["access-control-allow-origin"],
+        "https://allowed.example"
+    );
+    assert!(denied
+        .headers()
+        .get("access-control-allow-origin")
+        .is_none());
+}
+
+#[tokio::test]
+async fn graphql_server_rejects_too_large_request_body() {
+    // Given
+    let mut config = Config::local_node();
+    config.graphql_http.max_body_size = Some(1024);
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let url = format!("http://{}/v1/graphql", srv.bound_address);
+    let query = format!("{{ \"query\": \"{}\" }}", " ".repeat(2048));
+
+    // When
+    let response = reqwest::Client::new()
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .body(query)
+        .send()
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(response.status(), reqwest::StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn health_is_not_resent_while_etag_matches() {
+    // Given
+    let srv = FuelService::from_database(Database::default(), Config::local_node())
+        .await
+        .unwrap();
+    let url = format!("http://{}/v1/health", srv.bound_address);
+    let client = reqwest::Client::new();
+    let first = client.get(&url).send().await.unwrap();
+    let etag = first.headers()["etag"].clone();
+
+    // When
+    let second = client
+        .get(&url)
+        .header("If-None-Match", etag.clone())
+        .send()
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(first.headers()["cache-control"], "no-cache");
+    assert_eq!(second.status(), reqwest::StatusCode::NOT_MODIFIED);
+    assert_eq!(second.headers()["etag"], etag);
+}
+
+async fn post_graphql(
+    url: &str,
+    request: serde_json::Value,
+    admin_token: Option<&str>,
+) -> serde_json::Value {
+    let mut builder = reqwest::Client::new()
+        .post(url)
+        .header("Content-Type", "application/json")
+        .body(request.to_string());
+    if let Some(token) = admin_token {
+        builder = builder.bearer_auth(token);
+    }
+    let response = builder.send().await.unwrap().text().await.unwrap();
+    serde_json::from_str(&response).unwrap()
+}
+
+#[tokio::test]
+async fn graphql_server_accepts_only_persisted_queries_in_allow_list_mode() {
+    // Given
+    let mut config = Config::local_node();
+    config.admin_token = Some(AdminToken::new("secret"));
+    config.api_persisted_queries_only = true;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let url = format!("http://{}/v1/graphql", srv.bound_address);
+    let admin = FuelClient::from(srv.bound_address).with_admin_token("secret");
+    let persisted = "{ chain { name } }";
+    let hash = admin.register_persisted_query(persisted).await.unwrap();
+
+    // When
+    let by_text =
+        post_graphql(&url, serde_json::json!({ "query": persisted }), None).await;
+    let by_hash = post_graphql(
+        &url,
+        serde_json::json!({
+            "extensions": { "persistedQuery": { "sha256Hash": format!("{hash:x}") } }
+        }),
+        None,
+    )
+    .await;
+    let unknown = post_graphql(
+        &url,
+        serde_json::json!({ "query": "{ nodeInfo { nodeVersion } }" }),
+        None,
+    )
+    .await;
+    let unknown_by_admin = post_graphql(
+        &url,
+        serde_json::json!({ "query": "{ nodeInfo { nodeVersion } }" }),
+        Some("secret"),
+    )
+    .await;
+
+    // Then
+    assert!(by_text["data"]["chain"]["name"].is_string());
+    assert!(by_hash["data"]["chain"]["name"].is_string());
+    assert!(unknown["errors"].is_array());
+    assert!(unknown["data"].is_null());
+    assert!(unknown_by_admin["data"]["nodeInfo"]["nodeVersion"].is_string());
+}
+
+#[tokio::test]
+async fn removed_persisted_query_is_rejected() {
+    // Given
+    let mut config = Config::local_node();
+    config.admin_token = Some(AdminToken::new("secret"));
+    config.api_persisted_queries_only = true;
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let url = format!("http://{}/v1/graphql", srv.bound_address);
+    let admin = FuelClient::from(srv.bound_address).with_admin_token("secret");
+    let persisted = "{ chain { name } }";
+    let hash = admin.register_persisted_query(persisted).await.unwrap();
+
+    // When
+    let removed = admin.remove_persisted_query(&hash).await.unwrap();
+    let response =
+        post_graphql(&url, serde_json::json!({ "query": persisted }), None).await;
+
+    // Then
+    assert!(removed);
+    assert!(response["errors"].is_array());
+}
+
+#[tokio::test]
+async fn persisted_queries_are_restored_after_restart() {
+    use fuel_core::service::ServiceTrait;
+    use tempfile::TempDir;
+
+    // Given
+    let tmp_dir = TempDir::new().unwrap();
+    let mut config = Config::local_node();
+    config.admin_token = Some(AdminToken::new("secret"));
+    config.api_persisted_queries_only = true;
+    config.api_persisted_queries_path = Some(tmp_dir.path().join("queries.json"));
+    let persisted = "{ chain { name } }";
+    let hash = {
+        let srv = FuelService::from_database(Database::default(), config.clone())
+            .await
+            .unwrap();
+        let admin = FuelClient::from(srv.bound_address).with_admin_token("secret");
+        let hash = admin.register_persisted_query(persisted).await.unwrap();
+        srv.stop_and_await().await.unwrap();
+        hash
+    };
+
+    // When
+    let srv = FuelService::from_database(Database::default(), config)
+        .await
+        .unwrap();
+    let url = format!("http://{}/v1/graphql", srv.bound_address);
+    let by_hash = post_graphql(
+        &url,
+        serde_json::json!({
+            "extensions": { "persistedQuery": { "sha256Hash": format!("{hash:x}") } }
+        }),
+        None,
+    )
+    .await;
+
+    // Then
+    assert!(by_hash["data"]["chain"]["name"].is_string());
+}
+
+#[cfg(feature = "default")]
+#[tokio::test]
+async fn database_statistics_are_available_to_admin() {
+    use fuel_core::combined_database::CombinedDatabase;
+    use tempfile::TempDir;
+
+    // Given
+    let tmp_dir = TempDir::new().unwrap();
+    let database = CombinedDatabase::open(tmp_dir.path(), 1024 * 1024).unwrap();
+    let mut config = Config::local_node();
+    config.admin_token = Some(AdminToken::new("secret"));
+    let srv = FuelService::from_combined_database(database, config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let admin = FuelClient::from(srv.bound_address).with_admin_token("secret");
+
+    // When
+    let unauthorized = client.database_statistics().await;
+    let statistics = admin.database_statistics().await.unwrap();
+
+    // Then
+    assert!(unauthorized.is_err());
+    let names = statistics
+        .iter()
+        .map(|database| database.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["on_chain", "off_chain", "relayer"]);
+    assert!(statistics
+        .iter()
+        .all(|database| !database.columns.is_empty()));
+}
@@ -1,12 +1,16 @@
 #![deny(unused_must_use)]
 #![deny(warnings)]
 
+mod assets;
 mod balances;
 mod blocks;
 mod chain;
+#[cfg(feature = "p2p")]
+mod chaos;
 mod coin;
 mod coins;
 mod contract;
+mod da_compression;
 mod dap;
 mod debugger;
 mod deployment;
@@ -15,6 +19,8 @@ mod fee_collection_contract;
 mod gas_price;
 mod health;
 mod helpers;
+#[cfg(feature = "p2p")]
+mod localnet;
 mod messages;
 mod metrics;
 mod node_info;
@@ -24,6 +30,7 @@ mod relayer;
 mod snapshot;
 #[cfg(feature = "p2p")]
 mod sync;
+mod transfers;
 mod trigger_integration;
 mod tx;
 #[cfg(feature = "p2p")]
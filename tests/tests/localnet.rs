@@ -0,0 +1,79 @@
+use fuel_core::service::{
+    config::Trigger,
+    localnet::{
+        Localnet,
+        LocalnetConfig,
+        PRODUCER,
+    },
+};
+use fuel_core_poa::service::Mode;
+use std::{
+    net::TcpListener,
+    time::Duration,
+};
+
+fn free_port() -> u16 {
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("The OS should assign the port")
+        .port()
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn full_nodes_of_localnet_sync_blocks_from_producer() {
+    // Given
+    let localnet = Localnet::start(LocalnetConfig {
+        full_nodes: 2,
+        graphql_port: 0,
+        p2p_port: free_port(),
+        block_production: Trigger::Never,
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+
+    // When
+    localnet
+        .node(PRODUCER)
+        .unwrap()
+        .service
+        .shared
+        .poa_adapter
+        .manually_produce_blocks(
+            None,
+            Mode::Blocks {
+                number_of_blocks: 3,
+                interval: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    // Then
+    let synced = async {
+        loop {
+            let status = localnet.status().await.unwrap();
+            if status.iter().all(|node| *node.height == 3) {
+                return status
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    };
+    let status = tokio::time::timeout(Duration::from_secs(20), synced)
+        .await
+        .expect("The full nodes should sync the blocks");
+    assert_eq!(status.len(), 3);
+    assert!(status.iter().all(|node| node.peers > 0));
+    localnet.stop().await.unwrap();
+}
+
+#[tokio::test]
+async fn localnet_rejects_multiple_producers() {
+    let result = Localnet::start(LocalnetConfig {
+        validators: 2,
+        ..Default::default()
+    })
+    .await;
+
+    assert!(result.is_err());
+}
@@ -6,6 +6,7 @@ use fuel_core::{
         StateConfig,
     },
     service::{
+        config::ReceiptsPruningConfig,
         Config,
         FuelService,
     },
@@ -16,7 +17,10 @@ use fuel_core_client::client::{
         PaginationRequest,
     },
     types::{
-        message::MessageStatus,
+        message::{
+            MessageStatus,
+            MessageStatusDetails,
+        },
         TransactionStatus,
     },
     FuelClient,
@@ -275,6 +279,40 @@ async fn message_status__can_get_spent() {
     assert_eq!(status, MessageStatus::Spent);
 }
 
+#[tokio::test]
+async fn message_status_details__unspent_message_has_da_height() {
+    // Given
+    let nonce = 1.into();
+    let msg = MessageConfig {
+        nonce,
+        amount: 1_000,
+        da_height: 5u64.into(),
+        ..Default::default()
+    };
+
+    let mut config = Config::local_node();
+    config.chain_conf.initial_state = Some(StateConfig {
+        messages: Some(vec![msg]),
+        ..Default::default()
+    });
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let details = client.message_status_details(&nonce).await.unwrap();
+
+    // Then
+    assert_eq!(
+        details,
+        MessageStatusDetails {
+            status: MessageStatus::Unspent,
+            da_height: Some(5),
+            da_transaction_hash: None,
+        }
+    );
+}
+
 #[tokio::test]
 async fn message_status__can_get_notfound() {
     // Given
@@ -569,6 +607,65 @@ async fn can_get_message_proof() {
     }
 }
 
+#[tokio::test]
+async fn message_proof__is_available_after_receipts_are_pruned() {
+    // Given
+    let mut config = Config::local_node();
+    config.receipts_pruning = Some(ReceiptsPruningConfig {
+        retention: 1,
+        archive: None,
+    });
+    let recipient = Address::from([1; 32]);
+    let message_data = vec![7u8; 8];
+    let script = vec![
+        // The recipient is at the start of the script data, the message data follows it.
+        op::gtf_args(0x10, 0x00, GTFArgs::ScriptData),
+        op::addi(0x11, 0x10, Address::LEN as u16),
+        op::movi(0x12, message_data.len() as u32),
+        op::smo(0x10, 0x11, 0x12, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = recipient.iter().copied().chain(message_data).collect();
+    let transaction =
+        TransactionBuilder::script(script.into_iter().collect(), script_data)
+            .script_gas_limit(1_000_000)
+            .add_random_fee_input()
+            .finalize_as_transaction();
+    let transaction_id = transaction.id(&ChainId::default());
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let status = client.submit_and_await_commit(&transaction).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+    let receipts_before = client.receipts(&transaction_id).await.unwrap().unwrap();
+    let nonce = *receipts_before.iter().find_map(|r| r.nonce()).unwrap();
+
+    // When
+    let last_height = client.produce_blocks(2, None).await.unwrap();
+    // The pruning lags behind the blocks, because the off-chain worker is asynchronous.
+    let mut receipts_after = receipts_before.clone();
+    for _ in 0..100 {
+        receipts_after = client.receipts(&transaction_id).await.unwrap().unwrap();
+        if receipts_after.len() < receipts_before.len() {
+            break
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    let proof = client
+        .message_proof(&transaction_id, &nonce, None, Some(last_height))
+        .await
+        .unwrap();
+
+    // Then
+    assert!(matches!(
+        receipts_after.as_slice(),
+        [Receipt::MessageOut { .. }]
+    ));
+    let proof = proof.expect("The proof should be available after the pruning");
+    assert_eq!(proof.nonce, nonce);
+    assert_eq!(proof.recipient, recipient);
+}
+
 // TODO: Others test:  Data missing etc.
 fn verify_merkle<D: AsRef<[u8]>>(
     root: Bytes32,
@@ -1,11 +1,16 @@
-use fuel_core::service::{
-    Config,
-    FuelService,
+use fuel_core::{
+    chain_config::GenesisCommitment,
+    fingerprint::NodeFingerprint,
+    service::{
+        Config,
+        FuelService,
+    },
 };
 use fuel_core_client::client::{
     types::NodeInfo,
     FuelClient,
 };
+use fuel_core_types::fuel_types::Bytes32;
 
 #[tokio::test]
 async fn node_info() {
@@ -29,6 +34,58 @@ async fn node_info() {
     assert_eq!(max_tx, node_config.txpool.max_tx as u64);
 }
 
+#[tokio::test]
+async fn node_info_exposes_identity_and_enabled_features() {
+    // Given
+    let node_config = Config::local_node();
+    let srv = FuelService::new_node(node_config.clone()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let node_info = client.node_info().await.unwrap();
+
+    // Then
+    assert!(!node_info.relayer_enabled);
+    assert_eq!(
+        node_info.consensus_parameters_root,
+        Bytes32::from(node_config.chain_conf.consensus_parameters.root().unwrap())
+    );
+    #[cfg(feature = "p2p")]
+    {
+        let p2p_config = node_config.p2p.as_ref().unwrap();
+        assert_eq!(node_info.peer_id, Some(p2p_config.peer_id().to_string()));
+        assert!(!node_info.advertised_addresses.is_empty());
+        assert_eq!(node_info.protocol_versions, p2p_config.protocol_versions());
+    }
+}
+
+#[tokio::test]
+async fn node_info_exposes_fingerprint_of_block_producer() {
+    // Given
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // When
+    let height = client.produce_blocks(1, None).await.unwrap();
+    let node_fingerprint = client.node_info().await.unwrap().fingerprint;
+    let block_fingerprint = client.block_producer_fingerprint(height).await.unwrap();
+
+    // Then
+    let expected = NodeFingerprint::current();
+    assert_eq!(node_fingerprint.id, expected.id());
+    assert_eq!(node_fingerprint.version, expected.version);
+    assert_eq!(node_fingerprint.git_commit, expected.git_commit);
+    assert_eq!(node_fingerprint.features, expected.features);
+    let block_fingerprint = block_fingerprint.expect("The block is produced locally");
+    assert_eq!(block_fingerprint.id, expected.id());
+    // The genesis block is not produced by the node.
+    assert!(client
+        .block_producer_fingerprint(0u32.into())
+        .await
+        .unwrap()
+        .is_none());
+}
+
 #[cfg(feature = "p2p")]
 #[tokio::test(flavor = "multi_thread")]
 async fn test_peer_info() {
@@ -1,19 +1,51 @@
 use fuel_core::{
     combined_database::CombinedDatabase,
+    fuel_core_graphql_api::{
+        storage::equivocations::EquivocationProofs,
+        AdminToken,
+    },
     service::{
         Config,
         FuelService,
     },
 };
 use fuel_core_client::client::{
-    types::TransactionStatus,
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::{
+        Consensus as ClientConsensus,
+        PauseReason,
+        TransactionStatus,
+    },
     FuelClient,
 };
+use fuel_core_poa::service::{
+    PauseReason as PoAPauseReason,
+    Paused,
+};
+use fuel_core_storage::StorageAsMut;
 use fuel_core_types::{
-    blockchain::consensus::Consensus,
-    fuel_crypto::SecretKey,
+    blockchain::{
+        consensus::{
+            poa::{
+                EquivocationProof,
+                PoAConsensus,
+            },
+            Consensus,
+        },
+        header::BlockHeader,
+        SealedBlockHeader,
+    },
+    fuel_crypto::{
+        SecretKey,
+        Signature,
+    },
     fuel_tx::Transaction,
+    fuel_types::Bytes32,
     secrecy::Secret,
+    tai64::Tai64,
 };
 use rand::{
     rngs::StdRng,
@@ -185,3 +217,176 @@ mod p2p {
         .expect("The first should reborn and sync with the second");
     }
 }
+
+#[tokio::test]
+async fn block_production_can_be_paused_and_resumed_by_admin() {
+    let mut config = Config::local_node();
+    config.admin_token = Some(AdminToken::new("secret"));
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let admin = FuelClient::from(srv.bound_address).with_admin_token("secret");
+
+    // the admin endpoints require the token
+    assert!(client
+        .pause_block_production(PauseReason::Incident)
+        .await
+        .is_err());
+    assert!(FuelClient::from(srv.bound_address)
+        .with_admin_token("wrong")
+        .pause_block_production(PauseReason::Incident)
+        .await
+        .is_err());
+
+    admin
+        .pause_block_production(PauseReason::Incident)
+        .await
+        .unwrap();
+    let status = client.block_production_status().await.unwrap();
+    assert!(matches!(status.paused, Some((PauseReason::Incident, _))));
+
+    // the transaction is not included while paused
+    let tx = Transaction::default_test_tx();
+    let tx_id = client.submit(&tx).await.unwrap();
+    let heartbeat_height = admin.produce_heartbeat_block().await.unwrap();
+    assert_eq!(heartbeat_height, 1u32.into());
+    let block = client
+        .block_by_height(heartbeat_height)
+        .await
+        .unwrap()
+        .expect("the heartbeat block should exist");
+    // only the mint transaction
+    assert_eq!(block.transactions.len(), 1);
+    assert!(matches!(
+        client.transaction_status(&tx_id).await.unwrap(),
+        TransactionStatus::Submitted { .. }
+    ));
+
+    admin.resume_block_production().await.unwrap();
+    let status = client.block_production_status().await.unwrap();
+    assert!(status.paused.is_none());
+    let status = client.await_transaction_commit(&tx_id).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+}
+
+#[tokio::test]
+async fn admin_endpoints_are_disabled_without_token() {
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let admin = FuelClient::from(srv.bound_address).with_admin_token("secret");
+
+    let result = admin.pause_block_production(PauseReason::Incident).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn block_production_pause_is_persisted() {
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let pause_path = tmp_dir.path().join("pause.json");
+    let mut config = Config::local_node();
+    config.admin_token = Some(AdminToken::new("secret"));
+    config.poa_pause_path = Some(pause_path.clone());
+    let srv = FuelService::new_node(config).await.unwrap();
+    let admin = FuelClient::from(srv.bound_address).with_admin_token("secret");
+
+    admin
+        .pause_block_production(PauseReason::Maintenance)
+        .await
+        .unwrap();
+    let paused: Paused =
+        serde_json::from_slice(&std::fs::read(&pause_path).unwrap()).unwrap();
+    assert_eq!(paused.reason, PoAPauseReason::Maintenance);
+
+    admin.resume_block_production().await.unwrap();
+    assert!(!pause_path.exists());
+}
+
+#[tokio::test]
+async fn block_production_pause_is_restored_on_startup() {
+    let tmp_dir = tempfile::TempDir::new().unwrap();
+    let pause_path = tmp_dir.path().join("pause.json");
+    let paused = Paused {
+        reason: PoAPauseReason::Upgrade,
+        since: Tai64::now(),
+    };
+    std::fs::write(&pause_path, serde_json::to_vec(&paused).unwrap()).unwrap();
+    let mut config = Config::local_node();
+    config.poa_pause_path = Some(pause_path);
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let status = client.block_production_status().await.unwrap();
+    assert!(matches!(status.paused, Some((PauseReason::Upgrade, _))));
+    let tx_id = client
+        .submit(&Transaction::default_test_tx())
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    assert!(matches!(
+        client.transaction_status(&tx_id).await.unwrap(),
+        TransactionStatus::Submitted { .. }
+    ));
+}
+
+#[tokio::test]
+async fn equivocations_are_returned_with_both_signed_headers() {
+    let mut rng = StdRng::seed_from_u64(10);
+    let poa_secret = SecretKey::random(&mut rng);
+    let sealed_header = |time: u64| {
+        let mut header = BlockHeader::default();
+        header.set_block_height(5u32.into());
+        header.set_time(Tai64(time));
+        header.recalculate_metadata();
+        let signature = Signature::sign(&poa_secret, &header.id().into_message());
+        SealedBlockHeader {
+            entity: header,
+            consensus: Consensus::PoA(PoAConsensus::new(signature)),
+        }
+    };
+    let proof = EquivocationProof {
+        first: sealed_header(1),
+        second: sealed_header(2),
+    };
+    let mut db = CombinedDatabase::default();
+    db.off_chain_mut()
+        .storage_as_mut::<EquivocationProofs>()
+        .insert(&proof.height(), &proof)
+        .unwrap();
+
+    let srv = FuelService::from_combined_database(db, Config::local_node())
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let equivocations = client
+        .equivocations(PaginationRequest {
+            cursor: None,
+            results: 10,
+            direction: PageDirection::Forward,
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(equivocations.results.len(), 1);
+    let equivocation = &equivocations.results[0];
+    assert_eq!(equivocation.height, 5);
+    assert_eq!(
+        equivocation.first.header.id,
+        Bytes32::from(proof.first.entity.id())
+    );
+    assert_eq!(
+        equivocation.second.header.id,
+        Bytes32::from(proof.second.entity.id())
+    );
+    for (sealed, expected) in [
+        (&equivocation.first, &proof.first),
+        (&equivocation.second, &proof.second),
+    ] {
+        let ClientConsensus::PoAConsensus(poa) = &sealed.consensus else {
+            panic!("Expected the PoA consensus");
+        };
+        let Consensus::PoA(expected) = &expected.consensus else {
+            unreachable!()
+        };
+        assert_eq!(poa.signature, expected.signature);
+    }
+}
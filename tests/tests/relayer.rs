@@ -15,15 +15,29 @@ use fuel_core::{
         ServiceTrait,
     },
 };
+use fuel_core_benches::fixtures::{
+    self,
+    Fixture,
+    ReplayMode,
+};
 use fuel_core_client::client::{
     pagination::{
         PageDirection,
         PaginationRequest,
     },
-    types::TransactionStatus,
+    types::{
+        message::{
+            MessageStatus,
+            MessageStatusDetails,
+        },
+        TransactionStatus,
+    },
     FuelClient,
 };
-use fuel_core_poa::service::Mode;
+use fuel_core_poa::{
+    service::Mode,
+    Trigger,
+};
 use fuel_core_relayer::{
     test_helpers::{
         middleware::MockMiddleware,
@@ -41,6 +55,7 @@ use fuel_core_types::{
     fuel_crypto::*,
     fuel_tx::*,
     fuel_types::Nonce,
+    services::relayer::Event,
 };
 use hyper::{
     service::{
@@ -117,6 +132,7 @@ async fn relayer_can_download_logs() {
             None,
             Mode::Blocks {
                 number_of_blocks: 1,
+                interval: None,
             },
         )
         .await
@@ -192,6 +208,7 @@ async fn messages_are_spendable_after_relayer_is_synced() {
             None,
             Mode::Blocks {
                 number_of_blocks: 1,
+                interval: None,
             },
         )
         .await
@@ -250,6 +267,144 @@ async fn messages_are_spendable_after_relayer_is_synced() {
     eth_node_handle.shutdown.send(()).unwrap();
 }
 
+#[tokio::test(flavor = "multi_thread")]
+async fn message_status_details_include_da_origin() {
+    let mut config = Config::local_node();
+    config.relayer = Some(relayer::Config::default());
+    let relayer_config = config.relayer.as_mut().expect("Expected relayer config");
+    let eth_node = MockMiddleware::default();
+    let contract_address = relayer_config.eth_v2_listening_contracts[0];
+    let nonce = Nonce::from(3u64);
+    let transaction_hash = [7; 32];
+    let mut log =
+        make_message_event(nonce, 5, contract_address, None, None, Some(10), None);
+    log.transaction_hash = Some(transaction_hash.into());
+    eth_node.update_data(|data| data.logs_batch = vec![vec![log]]);
+    eth_node.update_data(|data| data.best_block.number = Some(200.into()));
+    let eth_node = Arc::new(eth_node);
+    let eth_node_handle = spawn_eth_node(eth_node).await;
+
+    relayer_config.relayer = Some(
+        format!("http://{}", eth_node_handle.address)
+            .as_str()
+            .try_into()
+            .unwrap(),
+    );
+    config.block_production = Trigger::Never;
+    config.debug = true;
+
+    let srv = FuelService::from_database(Database::in_memory(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    srv.await_relayer_synced().await.unwrap();
+
+    // The message is downloaded by the relayer, but it is not included into a block yet.
+    let details = client.message_status_details(&nonce).await.unwrap();
+    assert_eq!(
+        details,
+        MessageStatusDetails {
+            status: MessageStatus::NotFound,
+            da_height: Some(5),
+            da_transaction_hash: Some(transaction_hash.into()),
+        }
+    );
+
+    client.produce_blocks(1, None).await.unwrap();
+
+    let details = client.message_status_details(&nonce).await.unwrap();
+    assert_eq!(details.status, MessageStatus::Unspent);
+    assert_eq!(details.da_height, Some(5));
+    assert_eq!(details.da_transaction_hash, Some(transaction_hash.into()));
+
+    srv.stop_and_await().await.unwrap();
+    eth_node_handle.shutdown.send(()).unwrap();
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn fixture_replays_blocks_that_spend_messages_from_relayer() {
+    let mut rng = StdRng::seed_from_u64(1234);
+    let mut config = Config::local_node();
+    config.relayer = Some(relayer::Config::default());
+    config.utxo_validation = true;
+    let relayer_config = config.relayer.as_mut().expect("Expected relayer config");
+    let eth_node = MockMiddleware::default();
+    let contract_address = relayer_config.eth_v2_listening_contracts[0];
+
+    let secret_key: SecretKey = SecretKey::random(&mut rng);
+    let recipient = Input::owner(&secret_key.public_key());
+    let sender = Address::zeroed();
+    let amount = 100;
+    let nonce = Nonce::from(2u64);
+    let logs = vec![make_message_event(
+        nonce,
+        5,
+        contract_address,
+        Some(sender.into()),
+        Some(recipient.into()),
+        Some(amount),
+        None,
+    )];
+    eth_node.update_data(|data| data.logs_batch = vec![logs]);
+    eth_node.update_data(|data| data.best_block.number = Some(200.into()));
+    let eth_node_handle = spawn_eth_node(Arc::new(eth_node)).await;
+    relayer_config.relayer = Some(
+        format!("http://{}", eth_node_handle.address)
+            .as_str()
+            .try_into()
+            .unwrap(),
+    );
+    let snapshot = config.chain_conf.clone();
+
+    let srv = FuelService::from_database(Database::in_memory(), config)
+        .await
+        .unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    srv.await_relayer_synced().await.unwrap();
+
+    // The first block imports the message, the second block spends it.
+    srv.shared
+        .poa_adapter
+        .manually_produce_blocks(
+            None,
+            Mode::Blocks {
+                number_of_blocks: 1,
+                interval: None,
+            },
+        )
+        .await
+        .unwrap();
+    let tx = TransactionBuilder::script(vec![op::ret(0)].into_iter().collect(), vec![])
+        .script_gas_limit(10_000)
+        .add_unsigned_message_input(secret_key, sender, nonce, amount, vec![])
+        .add_output(Output::change(rng.gen(), 0, AssetId::BASE))
+        .finalize();
+    let status = client.submit_and_await_commit(&tx.into()).await.unwrap();
+    assert!(matches!(status, TransactionStatus::Success { .. }));
+
+    let url = format!("http://{}", srv.bound_address);
+    let fixture = fixtures::record(&url, snapshot, 2).await.unwrap();
+    srv.stop_and_await().await.unwrap();
+    eth_node_handle.shutdown.send(()).unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("fixture.json");
+    fixture.write(&path).unwrap();
+    let fixture = Fixture::read(&path).unwrap();
+    assert_eq!(fixture.blocks.len(), 2);
+    assert_eq!(fixture.relayer_events.len(), 1);
+    let Event::Message(message) = &fixture.relayer_events[0];
+    assert_eq!(message.nonce(), &nonce);
+    assert_eq!(message.amount(), amount);
+
+    for mode in [ReplayMode::Checked, ReplayMode::Unchecked] {
+        let stats = fixtures::replay(&fixture, mode).await.unwrap();
+        let heights: Vec<_> = stats.blocks.iter().map(|block| block.height).collect();
+        assert_eq!(heights, vec![1, 2]);
+        assert_eq!(stats.total_transactions(), 3);
+    }
+}
+
 fn make_message_event(
     nonce: Nonce,
     block_number: u64,
@@ -79,6 +79,7 @@ async fn snapshot_state_config() {
             data: vec![],
             da_height: DaBlockHeight(rng.gen_range(0..1000)),
         }]),
+        ..Default::default()
     };
 
     config.chain_conf.initial_state = Some(starting_state.clone());
@@ -0,0 +1,223 @@
+#![allow(non_snake_case)]
+
+use fuel_core::{
+    chain_config::{
+        CoinConfig,
+        StateConfig,
+    },
+    service::{
+        Config,
+        FuelService,
+    },
+};
+use fuel_core_client::client::{
+    pagination::{
+        PageDirection,
+        PaginationRequest,
+    },
+    types::{
+        primitives::{
+            Address,
+            AssetId,
+        },
+        ActivityKind,
+        CoinType,
+        TransferDirection,
+        TransferSource,
+    },
+    FuelClient,
+};
+use fuel_core_types::fuel_tx::{
+    Input,
+    Output,
+    Transaction,
+    TransactionBuilder,
+    UniqueIdentifier,
+};
+use futures::StreamExt;
+use std::time::Duration;
+
+const OWNER: Address = Address::new([10; 32]);
+const RECIPIENT: Address = Address::new([20; 32]);
+
+async fn setup() -> (FuelService, FuelClient) {
+    let mut config = Config::local_node();
+    config.chain_conf.initial_state = Some(StateConfig {
+        coins: Some(vec![CoinConfig {
+            tx_id: None,
+            output_index: None,
+            tx_pointer_block_height: None,
+            tx_pointer_tx_idx: None,
+            owner: OWNER,
+            amount: 1000,
+            asset_id: AssetId::BASE,
+        }]),
+        ..Default::default()
+    });
+
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    (srv, client)
+}
+
+async fn transfer(client: &FuelClient, amount: u64) -> Transaction {
+    let CoinType::Coin(coin) = client
+        .coins_to_spend(&OWNER, vec![(AssetId::BASE, 1, None)], None)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap()
+        .pop()
+        .unwrap()
+    else {
+        panic!("The owner has only coins")
+    };
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(1_000_000)
+        .add_input(Input::coin_signed(
+            coin.utxo_id,
+            OWNER,
+            coin.amount,
+            AssetId::BASE,
+            Default::default(),
+            0,
+        ))
+        .add_output(Output::Coin {
+            to: RECIPIENT,
+            amount,
+            asset_id: AssetId::BASE,
+        })
+        .add_output(Output::Change {
+            to: OWNER,
+            amount: 0,
+            asset_id: AssetId::BASE,
+        })
+        .add_witness(Default::default())
+        .finalize_as_transaction();
+    client.submit_and_await_commit(&tx).await.unwrap();
+    tx
+}
+
+fn all_events() -> PaginationRequest<String> {
+    PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    }
+}
+
+#[tokio::test]
+async fn transfer_events__returns_incoming_and_outgoing_transfers() {
+    // Given
+    let (_srv, client) = setup().await;
+    let tx = transfer(&client, 100).await;
+    let tx_id = tx.id(&Default::default());
+
+    // When
+    let recipient_events = client
+        .transfer_events(&RECIPIENT, None, all_events())
+        .await
+        .unwrap()
+        .results;
+    let owner_events = client
+        .transfer_events(&OWNER, Some(&AssetId::BASE), all_events())
+        .await
+        .unwrap()
+        .results;
+
+    // Then
+    assert_eq!(recipient_events.len(), 1);
+    let event = &recipient_events[0];
+    assert_eq!(event.owner, RECIPIENT);
+    assert_eq!(event.asset_id, AssetId::BASE);
+    assert_eq!(event.amount, 100);
+    assert_eq!(event.direction, TransferDirection::Incoming);
+    assert_eq!(event.source, TransferSource::Coin);
+    assert_eq!(event.transaction_id, Some(tx_id));
+
+    let spent = owner_events
+        .iter()
+        .find(|event| event.direction == TransferDirection::Outgoing)
+        .expect("The spent coin should be reported");
+    assert_eq!(spent.amount, 1000);
+    assert_eq!(spent.transaction_id, Some(tx_id));
+    let change = owner_events
+        .iter()
+        .find(|event| event.direction == TransferDirection::Incoming)
+        .expect("The change should be reported");
+    assert_eq!(change.amount, 900);
+    assert_eq!(change.transaction_id, Some(tx_id));
+}
+
+#[tokio::test]
+async fn transfer_events__filters_by_asset_id() {
+    // Given
+    let (_srv, client) = setup().await;
+    transfer(&client, 100).await;
+
+    // When
+    let events = client
+        .transfer_events(&RECIPIENT, Some(&AssetId::new([1; 32])), all_events())
+        .await
+        .unwrap()
+        .results;
+
+    // Then
+    assert!(events.is_empty());
+}
+
+#[tokio::test]
+async fn subscribe_transfer_events__streams_transfers_of_new_blocks() {
+    // Given
+    let (_srv, client) = setup().await;
+    let mut stream = client
+        .subscribe_transfer_events(&RECIPIENT, None)
+        .await
+        .unwrap();
+    // The subscription is established on the first poll of the stream
+    let mut next_event = tokio::spawn(async move { stream.next().await });
+
+    // When
+    let mut tx_ids = vec![];
+    let event = loop {
+        let tx = transfer(&client, 100).await;
+        tx_ids.push(tx.id(&Default::default()));
+        if let Ok(event) =
+            tokio::time::timeout(Duration::from_millis(500), &mut next_event).await
+        {
+            break event.unwrap().unwrap().unwrap();
+        }
+    };
+
+    // Then
+    assert_eq!(event.owner, RECIPIENT);
+    assert_eq!(event.amount, 100);
+    assert_eq!(event.direction, TransferDirection::Incoming);
+    assert!(tx_ids.contains(&event.transaction_id.unwrap()));
+}
+
+#[tokio::test]
+async fn subscribe_address_activity__streams_transactions_touching_the_address() {
+    // Given
+    let (_srv, client) = setup().await;
+    let mut stream = client.subscribe_address_activity(&RECIPIENT).await.unwrap();
+    // The subscription is established on the first poll of the stream
+    let mut next_activity = tokio::spawn(async move { stream.next().await });
+
+    // When
+    let mut tx_ids = vec![];
+    let activity = loop {
+        let tx = transfer(&client, 100).await;
+        tx_ids.push(tx.id(&Default::default()));
+        if let Ok(activity) =
+            tokio::time::timeout(Duration::from_millis(500), &mut next_activity).await
+        {
+            break activity.unwrap().unwrap().unwrap();
+        }
+    };
+
+    // Then
+    assert_eq!(activity.address, RECIPIENT);
+    assert_eq!(activity.kind, ActivityKind::CoinCreated);
+    assert!(tx_ids.contains(&activity.transaction_id));
+}
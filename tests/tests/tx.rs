@@ -1,7 +1,14 @@
-use crate::helpers::TestContext;
+use crate::helpers::{
+    TestContext,
+    TestSetupBuilder,
+};
 use fuel_core::{
     schema::tx::receipt::all_receipts,
     service::{
+        config::{
+            ReceiptsArchiveSink,
+            ReceiptsPruningConfig,
+        },
         Config,
         FuelService,
     },
@@ -11,6 +18,10 @@ use fuel_core_client::client::{
         PageDirection,
         PaginationRequest,
     },
+    schema::tx::transparent_receipt::{
+        ReceiptType,
+        ReceiptsFilter,
+    },
     types::TransactionStatus,
     FuelClient,
 };
@@ -19,7 +30,16 @@ use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::SecretKey,
     fuel_tx::*,
-    fuel_types::ChainId,
+    fuel_types::{
+        canonical::Serialize,
+        ChainId,
+    },
+    fuel_vm::Call,
+    services::executor::{
+        ContractSlot,
+        StateOverrides,
+        TransactionExecutionResult,
+    },
 };
 use itertools::Itertools;
 use rand::{
@@ -29,6 +49,7 @@ use rand::{
 };
 use std::io::ErrorKind::NotFound;
 
+mod multisig;
 mod predicates;
 mod tx_pointer;
 mod txn_status_subscription;
@@ -52,6 +73,71 @@ fn basic_script_snapshot() {
     insta::assert_snapshot!(format!("{script:?}"));
 }
 
+#[tokio::test]
+async fn estimate_and_provision_sets_script_gas_limit_and_max_fee() {
+    use fuel_core_types::fuel_tx::field::{
+        MaxFeeLimit,
+        ScriptGasLimit,
+    };
+
+    let gas_price = 1;
+    let mut config = Config::local_node();
+    config.txpool.min_gas_price = gas_price;
+    config.block_producer.gas_price = gas_price;
+    let params = config.chain_conf.consensus_parameters.clone();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let script = [
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::addi(0x11, RegId::ZERO, 0xba),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut tx = TransactionBuilder::script(script, vec![])
+        .add_unsigned_coin_input(
+            SecretKey::random(&mut rng),
+            rng.gen(),
+            1_000_000_000,
+            *params.base_asset_id(),
+            Default::default(),
+        )
+        .finalize_as_transaction();
+
+    client.estimate_and_provision(&mut tx).await.unwrap();
+
+    let Transaction::Script(script) = &tx else {
+        panic!("Expected a script transaction")
+    };
+    assert!(*script.script_gas_limit() > 0);
+    let expected_max_fee = TransactionFee::checked_from_tx(
+        params.gas_costs(),
+        params.fee_params(),
+        script,
+        gas_price,
+    )
+    .unwrap()
+    .max_fee();
+    assert_eq!(script.max_fee_limit(), expected_max_fee);
+
+    // The provisioned transaction has enough gas to execute the script
+    let tx_statuses = client.dry_run(&[tx.clone()]).await.unwrap();
+    let receipts = tx_statuses[0].result.receipts();
+    assert!(matches!(
+        receipts.last(),
+        Some(Receipt::ScriptResult {
+            result: ScriptExecutionResult::Success,
+            gas_used,
+        }) if gas_used == script.script_gas_limit()
+    ));
+}
+
 #[tokio::test]
 async fn dry_run_script() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -140,6 +226,229 @@ async fn dry_run_create() {
     assert_eq!(err.kind(), NotFound);
 }
 
+#[tokio::test]
+async fn dry_run_transactions_share_state_and_return_state_diffs() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let config = Config::local_node();
+    let params = config.chain_conf.consensus_parameters.clone();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let amount = 1_000;
+    let first_owner = SecretKey::random(&mut rng);
+    let second_owner = SecretKey::random(&mut rng);
+    let second_owner_address = Input::owner(&second_owner.public_key());
+
+    // Given
+    let first_tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            first_owner,
+            rng.gen(),
+            amount,
+            *params.base_asset_id(),
+            Default::default(),
+        )
+        .add_output(Output::coin(
+            second_owner_address,
+            amount,
+            *params.base_asset_id(),
+        ))
+        .finalize_as_transaction();
+    let created_utxo = UtxoId::new(first_tx.id(&params.chain_id()), 0);
+    let second_tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            second_owner,
+            created_utxo,
+            amount,
+            *params.base_asset_id(),
+            Default::default(),
+        )
+        .add_output(Output::change(
+            second_owner_address,
+            0,
+            *params.base_asset_id(),
+        ))
+        .finalize_as_transaction();
+
+    // When
+    let results = client
+        .dry_run_with_state_diff(&[first_tx.clone(), second_tx.clone()], Some(false))
+        .await
+        .unwrap();
+
+    // Then
+    assert_eq!(results.len(), 2);
+    let first = &results[0].state_diff;
+    let second = &results[1].state_diff;
+    assert_eq!(first.created_coins.len(), 1);
+    assert_eq!(first.created_coins[0].utxo_id, created_utxo);
+    assert_eq!(first.created_coins[0].owner, second_owner_address);
+    assert_eq!(second.consumed_coins.len(), 1);
+    assert_eq!(second.consumed_coins[0].utxo_id, created_utxo);
+    assert_eq!(second.consumed_coins[0].amount, amount);
+
+    // ensure the state of the dry run isn't persisted
+    let err = client
+        .transaction_status(&first_tx.id(&params.chain_id()))
+        .await
+        .unwrap_err();
+    assert_eq!(err.kind(), NotFound);
+}
+
+#[tokio::test]
+async fn dry_run_spends_coin_from_state_overrides() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let config = Config::local_node();
+    let params = config.chain_conf.consensus_parameters.clone();
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    let amount = 1_000;
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let utxo_id: UtxoId = rng.gen();
+
+    // Given
+    let tx = TransactionBuilder::script(vec![], vec![])
+        .script_gas_limit(10_000)
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            amount,
+            *params.base_asset_id(),
+            Default::default(),
+        )
+        .add_output(Output::change(owner, 0, *params.base_asset_id()))
+        .finalize_as_transaction();
+    let state_overrides = StateOverrides {
+        coins: vec![fuel_core_types::entities::coins::coin::Coin {
+            utxo_id,
+            owner,
+            amount,
+            asset_id: *params.base_asset_id(),
+            tx_pointer: Default::default(),
+        }],
+        ..Default::default()
+    };
+
+    // When
+    let results = client
+        .dry_run_with_state_overrides(&[tx.clone()], Some(true), Some(state_overrides))
+        .await
+        .unwrap();
+
+    // Then
+    assert!(matches!(
+        results[0].status.result,
+        TransactionExecutionResult::Success { .. }
+    ));
+    assert_eq!(results[0].state_diff.consumed_coins.len(), 1);
+    assert_eq!(results[0].state_diff.consumed_coins[0].utxo_id, utxo_id);
+    // the coin doesn't exist without the overrides
+    client
+        .dry_run_opt(&[tx], Some(true))
+        .await
+        .expect_err("The coin doesn't exist");
+    // the overrides aren't persisted
+    assert!(client.coin(&utxo_id).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn dry_run_returns_access_list() {
+    let mut rng = StdRng::seed_from_u64(2322);
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let salt: Salt = rng.gen();
+    let slot = StorageSlot::new(rng.gen(), rng.gen());
+    let contract = Contract::from(vec![]);
+    let root = contract.root();
+    let state_root = Contract::initial_state_root(std::iter::once(&slot));
+    let contract_id = contract.id(&salt, &root, &state_root);
+    let tx = TransactionBuilder::create(vec![].into(), salt, vec![slot.clone()])
+        .add_random_fee_input()
+        .add_output(Output::contract_created(contract_id, state_root))
+        .finalize_as_transaction();
+
+    // When
+    let results = client
+        .dry_run_with_state_diff(&[tx.clone()], None)
+        .await
+        .unwrap();
+
+    // Then
+    let access_list = &results[0].access_list;
+    assert_eq!(access_list.contracts, vec![contract_id]);
+    assert_eq!(
+        access_list.written_slots,
+        vec![ContractSlot {
+            contract_id,
+            key: *slot.key(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn profile_gas_aggregates_gas_per_opcode_of_script() {
+    let mut config = Config::local_node();
+    config.debug = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+
+    // Given
+    let script = [
+        op::addi(0x10, RegId::ZERO, 0xca),
+        op::addi(0x11, RegId::ZERO, 0xba),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let script: Vec<u8> = script
+        .iter()
+        .flat_map(|op| u32::from(*op).to_be_bytes())
+        .collect();
+    let tx = TransactionBuilder::script(script, vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+
+    // When
+    let profile = client.profile_gas(&[tx], None, None).await.unwrap();
+
+    // Then
+    assert_eq!(profile.contracts.len(), 1);
+    let script_profile = &profile.contracts[0];
+    assert_eq!(script_profile.contract_id, None);
+    assert_eq!(script_profile.instructions, 4);
+    let count_of = |opcode: &str| {
+        script_profile
+            .opcodes
+            .iter()
+            .find(|profile| profile.opcode == opcode)
+            .map(|profile| profile.count)
+    };
+    assert_eq!(count_of("ADDI"), Some(2));
+    assert_eq!(count_of("LOG"), Some(1));
+    assert_eq!(count_of("RET"), Some(1));
+    assert!(profile.total_gas > 0);
+    assert_eq!(profile.total_gas, script_profile.gas);
+}
+
+#[tokio::test]
+async fn profile_gas_requires_debug_mode() {
+    let mut config = Config::local_node();
+    config.debug = false;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let tx = Transaction::default_test_tx();
+
+    let result = client.profile_gas(&[tx], None, None).await;
+
+    assert!(result.is_err());
+}
+
 #[tokio::test]
 async fn submit() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -204,6 +513,176 @@ async fn receipts() {
     assert!(receipts.is_some());
 }
 
+#[tokio::test]
+async fn receipts_are_archived_and_pruned_after_the_retention_window() {
+    // Given
+    let archive = tempfile::tempdir().unwrap();
+    let mut config = Config::local_node();
+    config.receipts_pruning = Some(ReceiptsPruningConfig {
+        retention: 1,
+        archive: Some(ReceiptsArchiveSink::Directory(archive.path().into())),
+    });
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    client.submit_and_await_commit(&transaction).await.unwrap();
+    let receipts_before = client.receipts(&id).await.unwrap().unwrap();
+
+    // When
+    client.produce_blocks(2, None).await.unwrap();
+
+    // Then
+    // The receipts are archived by a separate task, so the pruning lags behind the blocks.
+    let mut receipts_after = receipts_before.clone();
+    for _ in 0..100 {
+        receipts_after = client.receipts(&id).await.unwrap().unwrap();
+        if receipts_after.is_empty() {
+            break
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    }
+    assert!(!receipts_before.is_empty());
+    assert!(receipts_after.is_empty());
+    assert!(archive.path().join("0000000001.json").exists());
+}
+
+#[tokio::test]
+async fn transaction_status_reports_fee() {
+    let transaction = Transaction::default_test_tx();
+    let id = transaction.id(&ChainId::default());
+    // setup server & client
+    let srv = FuelService::new_node(Config::local_node()).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    // submit tx
+    let status = client
+        .submit_and_await_commit(&transaction)
+        .await
+        .expect("transaction should insert");
+
+    // the fee is available for the status from the subscription and from the query
+    let TransactionStatus::Success {
+        fee: Some(subscription_fee),
+        ..
+    } = status
+    else {
+        panic!("Expected the success status with the fee, got {status:?}")
+    };
+    let status = client.transaction_status(&id).await.unwrap();
+    let TransactionStatus::Success {
+        fee: Some(query_fee),
+        ..
+    } = status
+    else {
+        panic!("Expected the success status with the fee, got {status:?}")
+    };
+    assert!(query_fee.gas_used > 0);
+    assert_eq!(subscription_fee, query_fee);
+}
+
+#[tokio::test]
+async fn transaction_status_reports_vm_metrics_when_enabled() {
+    // The VM doesn't run the empty script of the default transaction.
+    let script = [op::addi(0x10, RegId::ZERO, 1), op::ret(RegId::ONE)];
+    let transaction = TransactionBuilder::script(script.into_iter().collect(), vec![])
+        .script_gas_limit(1_000_000)
+        .add_random_fee_input()
+        .finalize_as_transaction();
+    let id = transaction.id(&ChainId::default());
+    // setup server & client
+    let mut config = Config::local_node();
+    config.vm.metrics = true;
+    let srv = FuelService::new_node(config).await.unwrap();
+    let client = FuelClient::from(srv.bound_address);
+    // submit tx
+    client
+        .submit_and_await_commit(&transaction)
+        .await
+        .expect("transaction should insert");
+
+    let status = client.transaction_status(&id).await.unwrap();
+    let TransactionStatus::Success {
+        vm_metrics: Some(vm_metrics),
+        ..
+    } = status
+    else {
+        panic!("Expected the success status with the VM metrics, got {status:?}")
+    };
+    assert!(vm_metrics.instructions > 0);
+    assert!(vm_metrics.memory_watermark > 0);
+}
+
+#[tokio::test]
+async fn submit_and_await_returns_only_receipts_matching_the_filter() {
+    // Given
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.utxo_validation = false;
+    let contract = [
+        op::movi(0x10, 0xca),
+        op::movi(0x11, 0xba),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::ret(RegId::ONE),
+    ];
+    let (_, contract_id) =
+        test_builder.setup_contract(contract.into_iter().collect(), None, None, None);
+    let ctx = test_builder.finalize().await;
+
+    // The script logs the same values before calling the contract
+    let script = [
+        op::movi(0x10, 0xca),
+        op::movi(0x11, 0xba),
+        op::log(0x10, 0x11, RegId::ZERO, RegId::ZERO),
+        op::gtf_args(0x12, 0x00, GTFArgs::ScriptData),
+        op::call(0x12, RegId::ZERO, RegId::ZERO, RegId::CGAS),
+        op::ret(RegId::ONE),
+    ];
+    let script_data = Call::new(contract_id, 0, 0).to_bytes();
+    let tx = TransactionBuilder::script(script.into_iter().collect(), script_data)
+        .script_gas_limit(1_000_000)
+        .add_input(Input::contract(
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            contract_id,
+        ))
+        .add_random_fee_input()
+        .add_output(Output::contract(0, Default::default(), Default::default()))
+        .finalize_as_transaction();
+    let filter = ReceiptsFilter {
+        contract_id: Some(contract_id.into()),
+        receipt_types: Some(vec![ReceiptType::Log]),
+    };
+
+    // When
+    let status = ctx
+        .client
+        .submit_and_await_commit_with_receipts_filter(&tx, Some(filter))
+        .await
+        .unwrap();
+
+    // Then
+    let TransactionStatus::Success { receipts, .. } = status else {
+        panic!("Expected the success status, got {status:?}")
+    };
+    assert_eq!(receipts.len(), 1);
+    assert!(matches!(receipts[0],
+        Receipt::Log {
+            id, ra, rb, ..
+        } if id == contract_id && ra == 0xca && rb == 0xba));
+
+    // The filter is not applied to the status queried afterwards
+    let status = ctx
+        .client
+        .transaction_status(&tx.id(&ChainId::default()))
+        .await
+        .unwrap();
+    let TransactionStatus::Success { receipts, .. } = status else {
+        panic!("Expected the success status, got {status:?}")
+    };
+    assert_eq!(receipts.len(), 6);
+}
+
 #[tokio::test]
 async fn receipts_decoding() {
     let srv = FuelService::new_node(Config::local_node()).await.unwrap();
@@ -646,6 +1125,44 @@ async fn get_owned_transactions() {
     assert_eq!(&charlie_txs, &[tx1, tx2, tx3]);
 }
 
+#[tokio::test]
+async fn spending_transaction_is_indexed_by_utxo_id() {
+    use fuel_core_types::fuel_tx::field::Inputs;
+
+    let alice = Address::from([1; 32]);
+    let bob = Address::from([2; 32]);
+
+    // Given
+    let mut context = TestContext::new(100).await;
+    let tx_id = context.transfer(alice, bob, 1).await.unwrap();
+    let client = context.client;
+    let tx = client
+        .transaction(&tx_id)
+        .await
+        .unwrap()
+        .unwrap()
+        .transaction;
+    let utxo_id = *tx
+        .as_script()
+        .unwrap()
+        .inputs()
+        .iter()
+        .find_map(|input| input.utxo_id())
+        .unwrap();
+
+    // When
+    let spending_tx = client.spending_transaction(&utxo_id).await.unwrap();
+    let unknown = client
+        .spending_transaction(&UtxoId::new([7; 32].into(), 0))
+        .await
+        .unwrap();
+
+    // Then
+    let spending_tx = spending_tx.unwrap();
+    assert_eq!(spending_tx.transaction.id(&ChainId::default()), tx_id);
+    assert!(unknown.is_none());
+}
+
 // add random val for unique tx
 fn create_mock_tx(val: u64) -> Transaction {
     let mut rng = StdRng::seed_from_u64(val);
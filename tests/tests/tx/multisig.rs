@@ -0,0 +1,112 @@
+// Tests related to the multisig predicate shipped with the node
+
+use crate::helpers::TestSetupBuilder;
+use fuel_core::chain_config::multisig_predicate::MultisigPredicate;
+use fuel_core_types::{
+    fuel_crypto::{
+        Message,
+        SecretKey,
+        Signature,
+    },
+    fuel_tx::{
+        field::Witnesses,
+        *,
+    },
+    fuel_types::ChainId,
+    fuel_vm::checked_transaction::{
+        CheckPredicateParams,
+        EstimatePredicates,
+    },
+};
+use rand::{
+    rngs::StdRng,
+    Rng,
+    SeedableRng,
+};
+
+/// Returns the transaction spending the coin of the 2-of-3 multisig
+/// signed by the `signing` signers.
+fn multisig_tx(rng: &mut StdRng, signing: &[usize]) -> Script {
+    let secrets: Vec<SecretKey> = (0..3).map(|_| SecretKey::random(rng)).collect();
+    let signers = secrets
+        .iter()
+        .map(|secret| Input::owner(&secret.public_key()))
+        .collect();
+    let multisig = MultisigPredicate::new(2, signers).unwrap();
+
+    let mut witness_indexes = vec![None; secrets.len()];
+    for (witness_index, signer) in signing.iter().enumerate() {
+        witness_indexes[*signer] = Some(u8::try_from(witness_index).unwrap());
+    }
+    let predicate_data = multisig.predicate_data(&witness_indexes).unwrap();
+
+    let amount = 500;
+    let asset_id = rng.gen();
+    let mut tx = TransactionBuilder::script(Default::default(), Default::default())
+        .add_input(Input::coin_predicate(
+            rng.gen(),
+            multisig.address(),
+            amount,
+            asset_id,
+            Default::default(),
+            Default::default(),
+            multisig.bytecode(),
+            predicate_data,
+        ))
+        .add_output(Output::change(rng.gen(), 0, asset_id))
+        .script_gas_limit(1000)
+        .finalize();
+
+    let message = Message::from_bytes(*tx.id(&ChainId::default()));
+    for signer in signing {
+        let signature = Signature::sign(&secrets[*signer], &message);
+        tx.witnesses_mut().push(signature.as_ref().to_vec().into());
+    }
+    // The predicate reads the witnesses at the offsets cached by the transaction.
+    tx.precompute(&ChainId::default())
+        .expect("Should be able to cache the offsets");
+    tx.estimate_predicates(&CheckPredicateParams::default())
+        .expect("Predicate check failed");
+    tx
+}
+
+#[tokio::test]
+async fn multisig_coin_is_spent_with_threshold_signatures() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    // Given
+    let tx = multisig_tx(&mut rng, &[2, 0]);
+    let context = TestSetupBuilder::default()
+        .config_coin_inputs_from_transactions(&[&tx])
+        .finalize()
+        .await;
+
+    // When
+    let result = context.client.submit_and_await_commit(&tx.into()).await;
+
+    // Then
+    assert!(result.is_ok(), "{result:?}");
+}
+
+#[tokio::test]
+async fn multisig_coin_below_threshold_is_rejected() {
+    let mut rng = StdRng::seed_from_u64(2322);
+
+    // Given
+    let mut tx = multisig_tx(&mut rng, &[0, 1]);
+    tx.witnesses_mut().pop();
+    let context = TestSetupBuilder::default()
+        .config_coin_inputs_from_transactions(&[&tx])
+        .finalize()
+        .await;
+
+    // When
+    let result = context.client.submit(&tx.into()).await;
+
+    // Then
+    let err = result.expect_err("Transaction should be rejected");
+    assert!(
+        err.to_string().contains("multisig input is invalid"),
+        "unexpected error: {err}"
+    );
+}
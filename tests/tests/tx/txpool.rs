@@ -1,15 +1,29 @@
 //! Tests the behaviour of the tx pool.
+#![allow(non_snake_case)]
 
 use crate::helpers::{
     TestContext,
     TestSetupBuilder,
 };
+use fuel_core_client::client::pagination::{
+    PageDirection,
+    PaginationRequest,
+};
+use fuel_core_poa::Trigger;
 use fuel_core_types::{
     fuel_asm::*,
     fuel_crypto::*,
     fuel_tx,
-    fuel_tx::*,
+    fuel_tx::{
+        field::{
+            Outputs,
+            Tip,
+        },
+        *,
+    },
+    services::txpool::EvictionReason,
 };
+use futures::StreamExt;
 use itertools::Itertools;
 use rand::{
     rngs::StdRng,
@@ -73,3 +87,176 @@ async fn txs_max_script_gas_limit() {
         transactions.len() + 1 // coinbase
     )
 }
+
+#[tokio::test]
+async fn mempool_returns_pending_transactions_filtered_by_owner() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.trigger = Trigger::Never;
+    let owner: Address = rng.gen();
+    let transactions = (1..=3)
+        .map(|i| {
+            let to = if i == 3 { rng.gen() } else { owner };
+            TransactionBuilder::script(
+                op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+                vec![],
+            )
+            .script_gas_limit(10_000)
+            .tip(i)
+            .max_fee_limit(i)
+            .add_unsigned_coin_input(
+                SecretKey::random(&mut rng),
+                rng.gen(),
+                1000 + i,
+                Default::default(),
+                Default::default(),
+            )
+            .add_output(Output::Change {
+                amount: 0,
+                asset_id: Default::default(),
+                to,
+            })
+            .finalize()
+        })
+        .collect_vec();
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    let TestContext { client, srv, .. } = test_builder.finalize().await;
+    let txs = transactions
+        .clone()
+        .into_iter()
+        .map(|script| Arc::new(fuel_tx::Transaction::from(script)))
+        .collect::<Vec<_>>();
+    srv.shared.txpool.insert(txs).await;
+    let request = PaginationRequest {
+        cursor: None,
+        results: 10,
+        direction: PageDirection::Forward,
+    };
+
+    // When
+    let all = client.mempool(None, None, request.clone()).await.unwrap();
+    let owned = client.mempool(Some(&owner), None, request).await.unwrap();
+
+    // Then
+    assert_eq!(all.results.len(), 3);
+    assert_eq!(owned.results.len(), 2);
+    for pending in owned.results {
+        let script = pending.transaction.as_script().expect("It is a script");
+        assert_eq!(pending.tip, script.tip());
+        assert!(script
+            .outputs()
+            .iter()
+            .any(|output| output.to() == Some(&owner)));
+    }
+}
+
+#[tokio::test]
+async fn pending_chain_returns_transactions_of_owner_in_order_of_ordering_hints() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.trigger = Trigger::Never;
+    let secret = SecretKey::random(&mut rng);
+    let owner = Input::owner(&secret.public_key());
+    let transactions = (1..=2)
+        .map(|i| {
+            TransactionBuilder::script(
+                op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+                vec![],
+            )
+            .script_gas_limit(10_000)
+            .tip(i)
+            .max_fee_limit(i)
+            .add_unsigned_coin_input(
+                secret,
+                rng.gen(),
+                1000 + i,
+                Default::default(),
+                Default::default(),
+            )
+            .finalize()
+        })
+        .collect_vec();
+    test_builder.config_coin_inputs_from_transactions(&transactions.iter().collect_vec());
+    let TestContext {
+        client, srv: _srv, ..
+    } = test_builder.finalize().await;
+    for (tx, ordering_hint) in transactions.iter().zip([2, 1]) {
+        client
+            .submit_with_ordering_hint(&tx.clone().into(), ordering_hint)
+            .await
+            .unwrap();
+    }
+
+    // When
+    let chain = client.pending_chain(&owner).await.unwrap();
+
+    // Then
+    let hints = chain
+        .iter()
+        .map(|pending| pending.ordering_hint)
+        .collect_vec();
+    assert_eq!(hints, vec![Some(1), Some(2)]);
+    let tips = chain
+        .iter()
+        .map(|pending| pending.transaction.as_script().unwrap().tip())
+        .collect_vec();
+    assert_eq!(tips, vec![2, 1]);
+}
+
+#[tokio::test]
+async fn subscribe_evicted_transactions__streams_replaced_transactions() {
+    // Given
+    let mut rng = StdRng::seed_from_u64(2322);
+    let mut test_builder = TestSetupBuilder::new(2322);
+    test_builder.trigger = Trigger::Never;
+    let secret = SecretKey::random(&mut rng);
+    let utxo_id = rng.gen();
+    let script_with_tip = |tip: u64| {
+        TransactionBuilder::script(
+            op::ret(RegId::ONE).to_bytes().into_iter().collect(),
+            vec![],
+        )
+        .script_gas_limit(10_000)
+        .tip(tip)
+        .max_fee_limit(tip)
+        .add_unsigned_coin_input(
+            secret,
+            utxo_id,
+            1000,
+            Default::default(),
+            Default::default(),
+        )
+        .finalize()
+    };
+    test_builder.config_coin_inputs_from_transactions(&[&script_with_tip(0)]);
+    let TestContext { client, srv, .. } = test_builder.finalize().await;
+    let mut stream = client.subscribe_evicted_transactions().await.unwrap();
+    // The subscription is established on the first poll of the stream
+    let mut next_event = tokio::spawn(async move { stream.next().await });
+
+    // When
+    let mut tx_ids = vec![];
+    let mut tip = 1;
+    let event = loop {
+        // Every transaction spends the same coin with a higher tip than the previous one
+        let tx = script_with_tip(tip);
+        tx_ids.push(tx.id(&Default::default()));
+        srv.shared
+            .txpool
+            .insert(vec![Arc::new(fuel_tx::Transaction::from(tx))])
+            .await;
+        tip += 1;
+        if let Ok(event) =
+            tokio::time::timeout(Duration::from_millis(500), &mut next_event).await
+        {
+            break event.unwrap().unwrap().unwrap();
+        }
+    };
+
+    // Then
+    assert_eq!(event.reason, EvictionReason::Conflict);
+    assert!(tx_ids.contains(&event.tx_id));
+    assert_ne!(tx_ids.last(), Some(&event.tx_id));
+}